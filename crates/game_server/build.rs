@@ -0,0 +1,46 @@
+//! Build script for game_server crate.
+//!
+//! This build script generates the server and client of the gRPC management
+//! API. The service is declared here rather than compiled from
+//! `proto/management.proto` so building the server doesn't need `protoc`;
+//! the messages are written out by hand in `src/grpc/proto.rs`.
+
+use tonic_build::manual::{Builder, Method, Service};
+
+fn main() {
+    let methods = [
+        ("list_players", "ListPlayers", "ListPlayersRequest", "ListPlayersResponse", "Lists connected players"),
+        ("kick_player", "KickPlayer", "KickPlayerRequest", "KickPlayerResponse", "Disconnects a player"),
+        ("list_plugins", "ListPlugins", "ListPluginsRequest", "ListPluginsResponse", "Lists loaded plugins with their version and health"),
+        ("reload_plugin", "ReloadPlugin", "ReloadPluginRequest", "ReloadPluginResponse", "Restarts a plugin from its library"),
+        ("get_gorc_stats", "GetGorcStats", "GetGorcStatsRequest", "GorcStats", "Gets replication statistics of the GORC instance manager"),
+        ("drain", "Drain", "DrainRequest", "DrainResponse", "Starts or stops refusing new players"),
+        ("shutdown", "Shutdown", "ShutdownRequest", "ShutdownResponse", "Drains the server and then shuts it down"),
+    ];
+
+    let service = methods
+        .into_iter()
+        .fold(
+            Service::builder()
+                .name("Management")
+                .package("horizon.management.v1")
+                .comment("Programmatic management of a running server"),
+            |service, (name, route_name, input, output, comment)| {
+                service.method(
+                    Method::builder()
+                        .name(name)
+                        .route_name(route_name)
+                        .comment(comment)
+                        .input_type(format!("crate::grpc::proto::{input}"))
+                        .output_type(format!("crate::grpc::proto::{output}"))
+                        .codec_path("tonic_prost::ProstCodec")
+                        .build(),
+                )
+            },
+        )
+        .build();
+
+    Builder::new().compile(&[service]);
+
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,577 @@
+//! Admin HTTP endpoint for inspecting and operating a live server.
+//!
+//! The admin listener is a minimal HTTP/1.1 server meant for developers and
+//! operators, bound to a separate address from the game socket. It serves:
+//!
+//! * `GET /debug/gorc/zones` - every object's zones with their subscribers
+//!   and every player's subscriptions, as JSON
+//! * `GET /debug/gorc/zones?player=<uuid>` - the same dump restricted to one
+//!   player and the objects they are subscribed to
+//! * `GET /admin/players` - connected players
+//! * `POST /admin/players/<uuid>/kick` - disconnects a player
+//! * `POST /admin/players/<uuid>/ban` - bans a player with its IP address
+//!   and client hardware, and disconnects them
+//! * `GET /admin/bans` - bans in effect
+//! * `POST /admin/bans` - bans one of `{"ip": ...}`, `{"player_id": ...}` or
+//!   `{"hardware_id": ...}`, whether or not it is connected
+//! * `DELETE /admin/bans/<ip>` - lifts an address ban
+//! * `DELETE /admin/bans/players/<uuid>` - lifts a player ban
+//! * `DELETE /admin/bans/hardware/<id>` - lifts a hardware ban
+//! * `GET /admin/ip-filter` - IP allow and deny lists
+//! * `PUT /admin/ip-filter` - replaces the lists with
+//!   `{"allow": [...], "deny": [...]}` of addresses or CIDR ranges
+//! * `POST /admin/broadcast` - sends `{"message": "..."}` to every client
+//! * `GET /admin/plugins` - loaded plugins with their version and health
+//! * `POST /admin/plugins/<name>/reload` - restarts a plugin from its library
+//!
+//! Kick and ban accept an optional `{"reason": "..."}` body, and bans an
+//! optional `"duration_secs"` after which they expire. Routes under
+//! `/admin/` need an admin token, sent as `Authorization: Bearer <token>`,
+//! and are refused when none is configured. Once a token is configured the
+//! debug routes need it as well. Every request to an `/admin/` route,
+//! refused or not, and every kick, ban, unban and IP filter change is
+//! recorded as a `security_audit` core event.
+//!
+//! Each connection handles a single request and is then closed.
+
+use crate::connection::ConnectionManager;
+use crate::audit::record_audit;
+use crate::security::bans::{BanEntry, BanTarget};
+use crate::security::ip_filter::IpFilterLists;
+use horizon_event_system::gorc::GorcInstanceManager;
+use horizon_event_system::{current_timestamp, AuditAction, EventSystem, PlayerId, SecurityAuditEvent, ShutdownState};
+use plugin_system::PluginManager;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info, warn};
+
+/// Route serving the GORC zone dump
+pub const ZONES_ROUTE: &str = "/debug/gorc/zones";
+
+/// Prefix of the routes operating the server
+pub const ADMIN_ROUTE_PREFIX: &str = "/admin/";
+
+/// Actor admin API actions are recorded under in the audit log
+const ADMIN_ACTOR: &str = "admin_api";
+
+/// Largest request head the admin listener reads
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+/// Largest request body the admin listener reads
+const MAX_REQUEST_BODY: usize = 64 * 1024;
+
+/// An HTTP response produced by an admin route
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdminResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// JSON response body
+    pub body: String,
+}
+
+impl AdminResponse {
+    fn json(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+
+    fn ok(body: serde_json::Value) -> Self {
+        Self::json(200, body.to_string())
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, serde_json::json!({ "error": message }).to_string())
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// Encodes the response as an HTTP/1.1 message
+    pub fn to_http(&self) -> Vec<u8> {
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body
+        )
+        .into_bytes()
+    }
+}
+
+/// A parsed admin request
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AdminRequest {
+    /// HTTP method
+    pub method: String,
+    /// Request target (path and optional query string)
+    pub target: String,
+    /// Value of the `Authorization` header
+    pub authorization: Option<String>,
+    /// Request body
+    pub body: Vec<u8>,
+}
+
+impl AdminRequest {
+    /// Creates a request without headers or body
+    pub fn new(method: &str, target: &str) -> Self {
+        Self {
+            method: method.to_string(),
+            target: target.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a bearer token to the request
+    pub fn with_token(mut self, token: &str) -> Self {
+        self.authorization = Some(format!("Bearer {token}"));
+        self
+    }
+
+    /// Sets the request body
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Reads a field from the JSON body, if the body has one
+    fn body_value(&self, field: &str) -> Result<Option<serde_json::Value>, AdminResponse> {
+        if self.body.iter().all(u8::is_ascii_whitespace) {
+            return Ok(None);
+        }
+        let mut body: serde_json::Value =
+            serde_json::from_slice(&self.body).map_err(|_| AdminResponse::error(400, "Body is not valid JSON"))?;
+        Ok(body.get_mut(field).map(serde_json::Value::take))
+    }
+
+    /// Reads a string field from the JSON body, if the body has one
+    fn body_field(&self, field: &str) -> Result<Option<String>, AdminResponse> {
+        Ok(self.body_value(field)?.and_then(|value| value.as_str().map(str::to_string)))
+    }
+
+    /// Reads a non-negative integer field from the JSON body, if the body has one
+    fn body_number(&self, field: &str) -> Result<Option<u64>, AdminResponse> {
+        match self.body_value(field)? {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(value) => value
+                .as_u64()
+                .map(Some)
+                .ok_or_else(|| AdminResponse::error(400, &format!("{field} must be a non-negative integer"))),
+        }
+    }
+}
+
+/// Server state the admin routes operate on
+pub struct AdminApi {
+    connection_manager: Arc<ConnectionManager>,
+    events: Arc<EventSystem>,
+    gorc_instances: Option<Arc<GorcInstanceManager>>,
+    plugin_manager: Option<Arc<PluginManager>>,
+    token: Option<String>,
+}
+
+impl AdminApi {
+    /// Creates the admin API for a server's connections and event system.
+    ///
+    /// The zone dump uses the event system's GORC instance manager, if it
+    /// has one.
+    pub fn new(connection_manager: Arc<ConnectionManager>, events: Arc<EventSystem>) -> Self {
+        Self {
+            gorc_instances: events.get_gorc_instances(),
+            connection_manager,
+            events,
+            plugin_manager: None,
+            token: None,
+        }
+    }
+
+    /// Sets the GORC instance manager the zone dump inspects
+    pub fn with_gorc_instances(mut self, gorc_instances: Arc<GorcInstanceManager>) -> Self {
+        self.gorc_instances = Some(gorc_instances);
+        self
+    }
+
+    /// Sets the plugin manager the plugin routes operate on
+    pub fn with_plugin_manager(mut self, plugin_manager: Arc<PluginManager>) -> Self {
+        self.plugin_manager = Some(plugin_manager);
+        self
+    }
+
+    /// Sets the token requests must present
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Routes an admin request.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The parsed request
+    ///
+    /// # Returns
+    ///
+    /// The response to send to the client.
+    pub async fn route(&self, request: &AdminRequest) -> AdminResponse {
+        let (path, query) = request.target.split_once('?').unwrap_or((&request.target, ""));
+        let response = match self.authorize(path, request) {
+            Some(rejection) => rejection,
+            None => self.dispatch(request, path, query).await,
+        };
+        if path.starts_with(ADMIN_ROUTE_PREFIX) {
+            let detail = format!("{} {} -> {}", request.method, path, response.status);
+            record_audit(&self.events, SecurityAuditEvent::new(AuditAction::AdminRequest, ADMIN_ACTOR).with_detail(detail)).await;
+        }
+        response
+    }
+
+    async fn dispatch(&self, request: &AdminRequest, path: &str, query: &str) -> AdminResponse {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let method = request.method.as_str();
+        let result = match (method, segments.as_slice()) {
+            ("GET", ["debug", "gorc", "zones"]) => self.zones(query).await,
+            ("GET", ["admin", "players"]) => Ok(self.players().await),
+            ("POST", ["admin", "players", player_id, "kick"]) => self.kick(player_id, request).await,
+            ("POST", ["admin", "players", player_id, "ban"]) => self.ban(player_id, request).await,
+            ("GET", ["admin", "bans"]) => Ok(AdminResponse::ok(serde_json::json!({
+                "banned_ips": self.connection_manager.banned_ips(),
+                "bans": self.connection_manager.bans().entries(),
+            }))),
+            ("POST", ["admin", "bans"]) => self.add_ban(request).await,
+            ("DELETE", ["admin", "bans", ip]) => match parse_ip(ip) {
+                Ok(ip) => self.unban(BanTarget::Ip(ip)).await,
+                Err(rejection) => Err(rejection),
+            },
+            ("DELETE", ["admin", "bans", "players", player_id]) => match parse_player_id(player_id) {
+                Ok(player_id) => self.unban(BanTarget::Player(player_id)).await,
+                Err(rejection) => Err(rejection),
+            },
+            ("DELETE", ["admin", "bans", "hardware", hardware_id]) => self.unban(BanTarget::Hardware(hardware_id.to_string())).await,
+            ("GET", ["admin", "ip-filter"]) => Ok(AdminResponse::ok(serde_json::json!(self.connection_manager.ip_filter().lists()))),
+            ("PUT", ["admin", "ip-filter"]) => self.replace_ip_filter(request).await,
+            ("POST", ["admin", "broadcast"]) => self.broadcast(request).await,
+            ("GET", ["admin", "plugins"]) => self.plugins(),
+            ("POST", ["admin", "plugins", plugin_name, "reload"]) => self.reload_plugin(plugin_name).await,
+            (_, ["debug", "gorc", "zones"])
+            | (_, ["admin", "players"])
+            | (_, ["admin", "players", _, "kick" | "ban"])
+            | (_, ["admin", "bans", ..])
+            | (_, ["admin", "ip-filter"])
+            | (_, ["admin", "broadcast"])
+            | (_, ["admin", "plugins", ..]) => Err(AdminResponse::error(405, "Method not supported by this route")),
+            _ => Err(AdminResponse::error(404, "Unknown route")),
+        };
+        result.unwrap_or_else(|rejection| rejection)
+    }
+
+    /// Rejects requests without the admin token where one is needed
+    fn authorize(&self, path: &str, request: &AdminRequest) -> Option<AdminResponse> {
+        let Some(token) = &self.token else {
+            return path
+                .starts_with(ADMIN_ROUTE_PREFIX)
+                .then(|| AdminResponse::error(403, "Admin routes are disabled until an admin token is configured"));
+        };
+
+        let presented = request
+            .authorization
+            .as_deref()
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim);
+        match presented {
+            Some(presented) if constant_time_eq(presented.as_bytes(), token.as_bytes()) => None,
+            _ => Some(AdminResponse::error(401, "Missing or invalid admin token")),
+        }
+    }
+
+    async fn zones(&self, query: &str) -> Result<AdminResponse, AdminResponse> {
+        let gorc_instances = self
+            .gorc_instances
+            .as_ref()
+            .ok_or_else(|| AdminResponse::error(503, "GORC instance manager not available"))?;
+        let player = query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("player="))
+            .map(|id| id.parse::<PlayerId>());
+        let inspection = gorc_instances.inspect_zones().await;
+        let inspection = match player {
+            None => inspection,
+            Some(Ok(player_id)) => inspection.for_player(player_id),
+            Some(Err(_)) => return Err(AdminResponse::error(400, "Invalid player ID")),
+        };
+
+        serde_json::to_string_pretty(&inspection)
+            .map(|body| AdminResponse::json(200, body))
+            .map_err(|e| AdminResponse::error(500, &e.to_string()))
+    }
+
+    async fn players(&self) -> AdminResponse {
+        let mut players = self.connection_manager.connected_players().await;
+        players.sort_by_key(|(_, connection_id, ..)| *connection_id);
+        let players: Vec<serde_json::Value> = players
+            .into_iter()
+            .map(|(player_id, connection_id, remote_addr, connected_at, auth_status)| {
+                serde_json::json!({
+                    "player_id": player_id,
+                    "connection_id": connection_id,
+                    "remote_addr": remote_addr,
+                    "connected_at": connected_at.duration_since(UNIX_EPOCH).map(|since| since.as_secs()).unwrap_or_default(),
+                    "auth_status": auth_status,
+                })
+            })
+            .collect();
+        AdminResponse::ok(serde_json::json!({ "players": players }))
+    }
+
+    async fn kick(&self, player_id: &str, request: &AdminRequest) -> Result<AdminResponse, AdminResponse> {
+        let player_id = parse_player_id(player_id)?;
+        let reason = request.body_field("reason")?;
+        self.disconnect(player_id, reason.clone()).await?;
+        info!("🛠️ Admin: Kicked player {}", player_id);
+        self.audit(AuditAction::Kick, player_id, reason).await;
+        Ok(AdminResponse::ok(serde_json::json!({ "kicked": player_id })))
+    }
+
+    async fn ban(&self, player_id: &str, request: &AdminRequest) -> Result<AdminResponse, AdminResponse> {
+        let player_id = parse_player_id(player_id)?;
+        let reason = request.body_field("reason")?;
+        let duration_secs = request.body_number("duration_secs")?;
+        let targets = self
+            .connection_manager
+            .ban_player(player_id, reason.clone(), duration_secs)
+            .await
+            .ok_or_else(|| AdminResponse::error(404, "Player not connected"))?;
+        let ip = targets.iter().find_map(|target| match target {
+            BanTarget::Ip(ip) => Some(*ip),
+            _ => None,
+        });
+
+        self.disconnect(player_id, Some(reason.clone().unwrap_or_else(|| "Banned by server".to_string())))
+            .await?;
+        info!("🛠️ Admin: Banned player {} ({} targets)", player_id, targets.len());
+        for target in &targets {
+            self.audit(AuditAction::Ban, target, reason.clone()).await;
+        }
+        Ok(AdminResponse::ok(serde_json::json!({ "banned": player_id, "ip": ip, "targets": targets })))
+    }
+
+    async fn add_ban(&self, request: &AdminRequest) -> Result<AdminResponse, AdminResponse> {
+        let ip = request.body_field("ip")?;
+        let player_id = request.body_field("player_id")?;
+        let hardware_id = request.body_field("hardware_id")?;
+        let target = match (ip, player_id, hardware_id) {
+            (Some(ip), None, None) => BanTarget::Ip(parse_ip(&ip)?),
+            (None, Some(player_id), None) => BanTarget::Player(parse_player_id(&player_id)?),
+            (None, None, Some(hardware_id)) if !hardware_id.is_empty() => BanTarget::Hardware(hardware_id),
+            _ => return Err(AdminResponse::error(400, "Body needs exactly one of ip, player_id or hardware_id")),
+        };
+
+        let entry = BanEntry::new(target, request.body_field("reason")?, request.body_number("duration_secs")?);
+        info!("🛠️ Admin: Banned {}", entry.target);
+        self.connection_manager.bans().ban(entry.clone());
+        self.audit(AuditAction::Ban, &entry.target, entry.reason.clone()).await;
+        Ok(AdminResponse::ok(serde_json::json!({ "banned": entry })))
+    }
+
+    async fn unban(&self, target: BanTarget) -> Result<AdminResponse, AdminResponse> {
+        if !self.connection_manager.bans().unban(&target) {
+            return Err(AdminResponse::error(404, "Not banned"));
+        }
+        info!("🛠️ Admin: Lifted ban on {}", target);
+        self.audit(AuditAction::Unban, &target, None).await;
+        Ok(AdminResponse::ok(serde_json::json!({ "unbanned": target })))
+    }
+
+    async fn replace_ip_filter(&self, request: &AdminRequest) -> Result<AdminResponse, AdminResponse> {
+        let lists: IpFilterLists = serde_json::from_slice(&request.body)
+            .map_err(|e| AdminResponse::error(400, &format!("Invalid IP filter lists: {e}")))?;
+        let (allowed, denied) = (lists.allow.len(), lists.deny.len());
+        self.connection_manager
+            .ip_filter()
+            .replace(lists)
+            .map_err(|e| AdminResponse::error(500, &format!("Lists are in effect but weren't saved: {e}")))?;
+        info!("🛠️ Admin: Replaced IP filter with {} allowed and {} denied ranges", allowed, denied);
+        let detail = format!("{allowed} allowed and {denied} denied ranges");
+        record_audit(&self.events, SecurityAuditEvent::new(AuditAction::IpFilterChanged, ADMIN_ACTOR).with_detail(detail)).await;
+        Ok(AdminResponse::ok(serde_json::json!(self.connection_manager.ip_filter().lists())))
+    }
+
+    /// Records a moderation action taken through the API in the audit log
+    async fn audit(&self, action: AuditAction, target: impl ToString, reason: Option<String>) {
+        let mut event = SecurityAuditEvent::new(action, ADMIN_ACTOR).with_target(target);
+        event.detail = reason;
+        record_audit(&self.events, event).await;
+    }
+
+    async fn broadcast(&self, request: &AdminRequest) -> Result<AdminResponse, AdminResponse> {
+        let message = request
+            .body_field("message")?
+            .ok_or_else(|| AdminResponse::error(400, "Body needs a message"))?;
+        let payload = serde_json::json!({
+            "event": "server_message",
+            "message": message,
+            "timestamp": current_timestamp(),
+        });
+        let recipients = self.connection_manager.broadcast_to_all(payload.to_string().into_bytes()).await;
+        info!("🛠️ Admin: Broadcast a message to {} clients", recipients);
+        Ok(AdminResponse::ok(serde_json::json!({ "recipients": recipients })))
+    }
+
+    fn plugins(&self) -> Result<AdminResponse, AdminResponse> {
+        let plugin_manager = self.plugin_manager()?;
+        let plugins: Vec<serde_json::Value> = plugin_manager
+            .plugin_health_snapshots()
+            .into_iter()
+            .map(|snapshot| {
+                let version = plugin_manager.plugin_manifest(&snapshot.name).map(|manifest| manifest.version);
+                serde_json::json!({
+                    "name": snapshot.name,
+                    "version": version,
+                    "health": snapshot.health,
+                    "detail": snapshot.detail,
+                })
+            })
+            .collect();
+        Ok(AdminResponse::ok(serde_json::json!({ "plugins": plugins })))
+    }
+
+    async fn reload_plugin(&self, plugin_name: &str) -> Result<AdminResponse, AdminResponse> {
+        let plugin_manager = self.plugin_manager()?;
+        if !plugin_manager.is_plugin_loaded(plugin_name) {
+            return Err(AdminResponse::error(404, "Plugin not loaded"));
+        }
+        plugin_manager
+            .restart_plugin(plugin_name)
+            .await
+            .map_err(|e| AdminResponse::error(500, &e.to_string()))?;
+        info!("🛠️ Admin: Reloaded plugin {}", plugin_name);
+        Ok(AdminResponse::ok(serde_json::json!({ "reloaded": plugin_name })))
+    }
+
+    fn plugin_manager(&self) -> Result<&PluginManager, AdminResponse> {
+        self.plugin_manager
+            .as_deref()
+            .ok_or_else(|| AdminResponse::error(503, "Plugin manager not available"))
+    }
+
+    /// Disconnects a player on whichever transport they use
+    async fn disconnect(&self, player_id: PlayerId, reason: Option<String>) -> Result<(), AdminResponse> {
+        let result = match self.events.get_client_response_sender() {
+            Some(sender) => sender.kick(player_id, reason).await,
+            None => self.connection_manager.kick_player(player_id, reason).await,
+        };
+        result.map_err(|e| AdminResponse::error(404, &e))
+    }
+}
+
+fn parse_player_id(player_id: &str) -> Result<PlayerId, AdminResponse> {
+    player_id.parse().map_err(|_| AdminResponse::error(400, "Invalid player ID"))
+}
+
+fn parse_ip(ip: &str) -> Result<IpAddr, AdminResponse> {
+    ip.parse().map_err(|_| AdminResponse::error(400, "Invalid IP address"))
+}
+
+/// Compares two byte strings in time independent of where they differ
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |difference, (x, y)| difference | (x ^ y)) == 0
+}
+
+/// Accepts admin connections until shutdown is initiated.
+///
+/// # Arguments
+///
+/// * `listener` - Bound admin listener
+/// * `api` - Admin API the requests are routed to
+/// * `shutdown_state` - Optional shutdown state for coordinated shutdown
+pub async fn serve_admin(listener: TcpListener, api: Arc<AdminApi>, shutdown_state: Option<ShutdownState>) {
+    loop {
+        if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+            break;
+        }
+
+        let (stream, addr) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                warn!("🛠️ Admin: Failed to accept connection: {}", e);
+                continue;
+            }
+        };
+
+        let api = api.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &api).await {
+                debug!("🛠️ Admin: Connection from {} failed: {}", addr, e);
+            }
+        });
+    }
+}
+
+/// Reads one request from a connection and writes its response
+async fn handle_connection(mut stream: TcpStream, api: &AdminApi) -> std::io::Result<()> {
+    let mut data = Vec::with_capacity(1024);
+    let mut buffer = [0u8; 1024];
+    let head_len = loop {
+        if let Some(position) = data.windows(4).position(|window| window == b"\r\n\r\n") {
+            break position + 4;
+        }
+        if data.len() >= MAX_REQUEST_HEAD {
+            let response = AdminResponse::error(400, "Request head too large");
+            return stream.write_all(&response.to_http()).await;
+        }
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break data.len();
+        }
+        data.extend_from_slice(&buffer[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&data[..head_len]).into_owned();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        let response = AdminResponse::error(400, "Malformed request line");
+        return stream.write_all(&response.to_http()).await;
+    };
+
+    let mut request = AdminRequest::new(method, target);
+    let mut content_length = 0;
+    for (name, value) in lines.filter_map(|line| line.split_once(':')) {
+        if name.eq_ignore_ascii_case("authorization") {
+            request.authorization = Some(value.trim().to_string());
+        } else if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+    if content_length > MAX_REQUEST_BODY {
+        let response = AdminResponse::error(413, "Request body too large");
+        return stream.write_all(&response.to_http()).await;
+    }
+
+    let mut body = data.split_off(head_len.min(data.len()));
+    while body.len() < content_length {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&buffer[..read]);
+    }
+    body.truncate(content_length);
+    request.body = body;
+
+    let response = api.route(&request).await;
+    stream.write_all(&response.to_http()).await?;
+    stream.shutdown().await
+}
@@ -0,0 +1,414 @@
+//! Webhook alerts for security events that pile up.
+//!
+//! The [`Alerter`] subscribes to `security_audit` core events and counts
+//! rate-limit trips, ban evasion attempts and oversized messages over a
+//! sliding window. Once one kind reaches its configured threshold, a
+//! [`SecurityAlert`] is posted to every webhook, shaped for Discord, Slack or
+//! the PagerDuty Events API, and that kind then stays quiet for the cooldown
+//! so a storm raises one alert rather than thousands.
+
+use crate::audit::SECURITY_AUDIT_EVENT;
+use crate::config::{AlertWebhookConfig, AlertWebhookFormat, AlertingConfig};
+use horizon_event_system::{AuditAction, EventError, EventSystem, SecurityAuditEvent};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// How long a webhook has to answer
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Number of most involved targets listed in an alert
+const TOP_TARGETS: usize = 5;
+
+/// Kinds of security alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    /// Messages dropped for exceeding rate limits
+    RateLimitStorm,
+    /// Banned players or hardware connecting from unbanned addresses
+    BanEvasion,
+    /// Messages dropped for exceeding the size limit
+    OversizedMessages,
+}
+
+impl AlertKind {
+    /// Gets the kind of alert an audited action counts towards, if any
+    pub fn of(action: AuditAction) -> Option<Self> {
+        match action {
+            AuditAction::RateLimited => Some(Self::RateLimitStorm),
+            AuditAction::BanEvasion => Some(Self::BanEvasion),
+            AuditAction::OversizedMessage => Some(Self::OversizedMessages),
+            _ => None,
+        }
+    }
+
+    /// Gets the name the kind is serialized as, such as `"rate_limit_storm"`
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::RateLimitStorm => "rate_limit_storm",
+            Self::BanEvasion => "ban_evasion",
+            Self::OversizedMessages => "oversized_messages",
+        }
+    }
+
+    /// Gets the heading alerts of this kind are posted under
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::RateLimitStorm => "Rate-limit storm",
+            Self::BanEvasion => "Ban evasion",
+            Self::OversizedMessages => "Oversized messages",
+        }
+    }
+
+    /// Gets the PagerDuty severity of the kind
+    fn severity(self) -> &'static str {
+        match self {
+            Self::RateLimitStorm => "error",
+            Self::BanEvasion | Self::OversizedMessages => "warning",
+        }
+    }
+}
+
+/// A threshold of security events crossed within the alerting window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecurityAlert {
+    /// What piled up
+    pub kind: AlertKind,
+    /// Events counted within the window
+    pub count: u32,
+    /// Seconds the events were counted over
+    pub window_secs: u64,
+    /// Players, addresses or hardware involved most often, with their event counts
+    pub top_targets: Vec<(String, u32)>,
+    /// Unix timestamp the threshold was crossed at
+    pub timestamp: u64,
+}
+
+impl SecurityAlert {
+    /// Describes the alert in one line
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{}: {} events in the last {}s", self.kind.title(), self.count, self.window_secs);
+        if !self.top_targets.is_empty() {
+            let targets: Vec<String> = self.top_targets.iter().map(|(target, count)| format!("{target} ({count})")).collect();
+            summary.push_str(&format!(", mostly {}", targets.join(", ")));
+        }
+        summary
+    }
+}
+
+/// Posts security alerts to the configured webhooks
+#[derive(Debug)]
+pub struct Alerter {
+    config: AlertingConfig,
+    http: reqwest::Client,
+    windows: Mutex<HashMap<AlertKind, AlertWindow>>,
+}
+
+/// Recent events of one kind and when it last raised an alert
+#[derive(Debug, Default)]
+struct AlertWindow {
+    /// Timestamp and target of each event within the window, oldest first
+    events: VecDeque<(u64, Option<String>)>,
+    last_alert: Option<u64>,
+}
+
+impl Alerter {
+    /// Creates an alerter with empty windows
+    pub fn new(config: AlertingConfig) -> Self {
+        Self {
+            config,
+            http: reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build().unwrap_or_default(),
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Subscribes the alerter to `security_audit` core events.
+    ///
+    /// Alerts are posted from their own task, so slow webhooks never hold up
+    /// the events that raised them.
+    pub async fn register(self: Arc<Self>, events: &EventSystem) -> Result<(), EventError> {
+        events
+            .on_core(SECURITY_AUDIT_EVENT, move |event: SecurityAuditEvent| {
+                if let Some(alert) = self.observe(&event) {
+                    let alerter = self.clone();
+                    tokio::spawn(async move { alerter.send(&alert).await });
+                }
+                Ok(())
+            })
+            .await
+    }
+
+    /// Counts an audited event towards its kind of alert.
+    ///
+    /// # Returns
+    ///
+    /// The alert to send if the event took its kind to the threshold outside
+    /// the cooldown, or `None`.
+    pub fn observe(&self, event: &SecurityAuditEvent) -> Option<SecurityAlert> {
+        let kind = AlertKind::of(event.action)?;
+        let threshold = match kind {
+            AlertKind::RateLimitStorm => self.config.rate_limit_storm_threshold,
+            AlertKind::BanEvasion => self.config.ban_evasion_threshold,
+            AlertKind::OversizedMessages => self.config.oversized_message_threshold,
+        };
+        if threshold == 0 {
+            return None;
+        }
+
+        let now = event.timestamp;
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        let window = windows.entry(kind).or_default();
+        window.events.push_back((now, event.target.clone()));
+        while window.events.front().is_some_and(|(at, _)| at + self.config.window_secs <= now) {
+            window.events.pop_front();
+        }
+        if window.events.len() < threshold as usize
+            || window.last_alert.is_some_and(|at| now < at + self.config.cooldown_secs)
+        {
+            return None;
+        }
+
+        let mut targets: HashMap<String, u32> = HashMap::new();
+        for target in window.events.iter().filter_map(|(_, target)| target.clone()) {
+            *targets.entry(target).or_default() += 1;
+        }
+        let mut top_targets: Vec<(String, u32)> = targets.into_iter().collect();
+        top_targets.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_targets.truncate(TOP_TARGETS);
+
+        let alert = SecurityAlert {
+            kind,
+            count: window.events.len() as u32,
+            window_secs: self.config.window_secs,
+            top_targets,
+            timestamp: now,
+        };
+        window.events.clear();
+        window.last_alert = Some(now);
+        Some(alert)
+    }
+
+    /// Posts an alert to every webhook.
+    ///
+    /// Webhooks that can't be reached or answer with an error status are
+    /// logged and skipped.
+    pub async fn send(&self, alert: &SecurityAlert) {
+        info!("🚨 {}", alert.summary());
+        for (index, webhook) in self.config.webhooks.iter().enumerate() {
+            if let Err(e) = self.post(webhook, alert).await {
+                // Webhook URLs carry their credentials, so they never reach the logs
+                warn!(
+                    "🚨 Failed to post {} alert to webhook #{} ({:?}): {}",
+                    alert.kind.name(),
+                    index,
+                    webhook.format,
+                    e.without_url()
+                );
+            }
+        }
+    }
+
+    async fn post(&self, webhook: &AlertWebhookConfig, alert: &SecurityAlert) -> Result<(), reqwest::Error> {
+        self.http
+            .post(&webhook.url)
+            .json(&webhook_payload(webhook, alert))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+/// Shapes an alert for the service behind a webhook.
+///
+/// # Returns
+///
+/// A Discord message with an embed, a Slack message with blocks, or a
+/// PagerDuty Events API v2 trigger event deduplicated by alert kind.
+pub fn webhook_payload(webhook: &AlertWebhookConfig, alert: &SecurityAlert) -> Value {
+    let targets = alert
+        .top_targets
+        .iter()
+        .map(|(target, count)| format!("{target} ({count})"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    match webhook.format {
+        AlertWebhookFormat::Discord => {
+            let mut fields = vec![
+                json!({ "name": "Events", "value": alert.count.to_string(), "inline": true }),
+                json!({ "name": "Window", "value": format!("{}s", alert.window_secs), "inline": true }),
+            ];
+            if !targets.is_empty() {
+                fields.push(json!({ "name": "Top targets", "value": targets, "inline": false }));
+            }
+            json!({
+                "username": "Horizon",
+                "embeds": [{
+                    "title": format!("🚨 {}", alert.kind.title()),
+                    "description": alert.summary(),
+                    "color": 0xE74C3C,
+                    "fields": fields,
+                }],
+            })
+        }
+        AlertWebhookFormat::Slack => {
+            let mut fields = vec![
+                json!({ "type": "mrkdwn", "text": format!("*Events*\n{}", alert.count) }),
+                json!({ "type": "mrkdwn", "text": format!("*Window*\n{}s", alert.window_secs) }),
+            ];
+            if !targets.is_empty() {
+                fields.push(json!({ "type": "mrkdwn", "text": format!("*Top targets*\n{targets}") }));
+            }
+            json!({
+                "text": format!(":rotating_light: {}", alert.summary()),
+                "blocks": [
+                    { "type": "header", "text": { "type": "plain_text", "text": alert.kind.title() } },
+                    { "type": "section", "fields": fields },
+                ],
+            })
+        }
+        AlertWebhookFormat::PagerDuty => json!({
+            "routing_key": webhook.routing_key.clone().unwrap_or_default(),
+            "event_action": "trigger",
+            "dedup_key": format!("horizon-{}", alert.kind.name()),
+            "payload": {
+                "summary": alert.summary(),
+                "source": "horizon",
+                "severity": alert.kind.severity(),
+                "component": "game_server",
+                "class": alert.kind.name(),
+                "custom_details": alert,
+            },
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::record_audit;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn event(action: AuditAction, target: &str, timestamp: u64) -> SecurityAuditEvent {
+        SecurityAuditEvent {
+            timestamp,
+            ..SecurityAuditEvent::new(action, "server").with_target(target)
+        }
+    }
+
+    fn alert() -> SecurityAlert {
+        SecurityAlert {
+            kind: AlertKind::BanEvasion,
+            count: 3,
+            window_secs: 60,
+            top_targets: vec![("hardware abc".to_string(), 2), ("player p1".to_string(), 1)],
+            timestamp: 1_000,
+        }
+    }
+
+    #[test]
+    fn test_thresholds_raise_one_alert_per_cooldown() {
+        let alerter = Alerter::new(AlertingConfig {
+            rate_limit_storm_threshold: 3,
+            oversized_message_threshold: 0,
+            ..Default::default()
+        });
+
+        // Events that fall out of the window don't count
+        assert!(alerter.observe(&event(AuditAction::RateLimited, "p1", 1_000)).is_none());
+        assert!(alerter.observe(&event(AuditAction::RateLimited, "p1", 1_000)).is_none());
+        assert!(alerter.observe(&event(AuditAction::RateLimited, "p1", 1_060)).is_none());
+        assert!(alerter.observe(&event(AuditAction::RateLimited, "p2", 1_061)).is_none());
+        let storm = alerter.observe(&event(AuditAction::RateLimited, "p1", 1_062)).unwrap();
+        assert_eq!((storm.kind, storm.count, storm.timestamp), (AlertKind::RateLimitStorm, 3, 1_062));
+        assert_eq!(storm.top_targets, vec![("p1".to_string(), 2), ("p2".to_string(), 1)]);
+
+        // Quiet during the cooldown, then alerting again
+        for timestamp in 1_070..1_080 {
+            assert!(alerter.observe(&event(AuditAction::RateLimited, "p1", timestamp)).is_none());
+        }
+        for _ in 0..2 {
+            assert!(alerter.observe(&event(AuditAction::RateLimited, "p3", 1_362)).is_none());
+        }
+        assert!(alerter.observe(&event(AuditAction::RateLimited, "p3", 1_362)).is_some());
+
+        // Other actions and disabled thresholds never alert
+        for _ in 0..50 {
+            assert!(alerter.observe(&event(AuditAction::Kick, "p1", 1_400)).is_none());
+            assert!(alerter.observe(&event(AuditAction::OversizedMessage, "p1", 1_400)).is_none());
+        }
+    }
+
+    #[test]
+    fn test_payloads_match_each_service() {
+        let webhook = |format| AlertWebhookConfig {
+            url: "https://hooks.example.com/alerts".to_string(),
+            format,
+            routing_key: Some("R0UT1NG".to_string()),
+        };
+
+        let discord = webhook_payload(&webhook(AlertWebhookFormat::Discord), &alert());
+        assert_eq!(discord["embeds"][0]["title"], "🚨 Ban evasion");
+        assert_eq!(discord["embeds"][0]["fields"][2]["value"], "hardware abc (2)\nplayer p1 (1)");
+
+        let slack = webhook_payload(&webhook(AlertWebhookFormat::Slack), &alert());
+        assert_eq!(
+            slack["text"],
+            ":rotating_light: Ban evasion: 3 events in the last 60s, mostly hardware abc (2), player p1 (1)"
+        );
+        assert_eq!(slack["blocks"][0]["text"]["text"], "Ban evasion");
+
+        let pagerduty = webhook_payload(&webhook(AlertWebhookFormat::PagerDuty), &alert());
+        assert_eq!((pagerduty["routing_key"].as_str(), pagerduty["event_action"].as_str()), (Some("R0UT1NG"), Some("trigger")));
+        assert_eq!(pagerduty["dedup_key"], "horizon-ban_evasion");
+        assert_eq!(pagerduty["payload"]["severity"], "warning");
+        assert_eq!(pagerduty["payload"]["custom_details"]["count"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_alerts_are_posted_to_webhooks() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks/security", listener.local_addr().unwrap());
+        let (body_tx, body_rx) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 4096];
+            // Read until the whole body named by content-length has arrived
+            let body = loop {
+                let read = stream.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                let Some((head, body)) = text.split_once("\r\n\r\n") else { continue };
+                let length = head
+                    .lines()
+                    .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length:").map(|n| n.trim().parse::<usize>().unwrap()))
+                    .unwrap_or_default();
+                if body.len() >= length || read == 0 {
+                    break (head.lines().next().unwrap_or_default().to_string(), body.to_string());
+                }
+            };
+            let _ = stream.write_all(b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n").await;
+            let _ = body_tx.send(body);
+        });
+
+        let events = horizon_event_system::create_horizon_event_system();
+        let alerter = Arc::new(Alerter::new(AlertingConfig {
+            webhooks: vec![AlertWebhookConfig { url, format: AlertWebhookFormat::Slack, routing_key: None }],
+            ban_evasion_threshold: 1,
+            ..Default::default()
+        }));
+        alerter.register(&events).await.unwrap();
+
+        record_audit(&events, SecurityAuditEvent::new(AuditAction::BanEvasion, "server").with_target("hardware abc")).await;
+
+        let (request_line, body) = tokio::time::timeout(Duration::from_secs(5), body_rx).await.unwrap().unwrap();
+        assert_eq!(request_line, "POST /hooks/security HTTP/1.1");
+        let payload: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(payload["blocks"][0]["text"]["text"], "Ban evasion");
+    }
+}
@@ -0,0 +1,254 @@
+//! Append-only audit log of security-relevant actions.
+//!
+//! Bans, kicks, rate-limit trips, unsafe plugin overrides and admin API calls
+//! are announced as `security_audit` core events carrying a
+//! [`SecurityAuditEvent`], which moderation plugins can subscribe to. The
+//! [`AuditLog`] subscribes too and appends each one to a file as a line of
+//! JSON, rotating the file once it reaches the configured size.
+//!
+//! A client hammering a rate limit trips it on every message, so repeated
+//! `rate_limited` records for the same player and limit are written at most
+//! once per [`RATE_LIMIT_COALESCE_SECS`], with the number held back noted on
+//! the next one written.
+
+use crate::config::AuditLogConfig;
+use horizon_event_system::{AuditAction, EventError, EventSystem, SecurityAuditEvent};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::warn;
+
+/// Core event security-relevant actions are announced under
+pub const SECURITY_AUDIT_EVENT: &str = "security_audit";
+
+/// Seconds repeated `rate_limited` records for the same player and limit are folded into one
+pub const RATE_LIMIT_COALESCE_SECS: u64 = 60;
+
+/// Announces a security-relevant action as a `security_audit` core event.
+///
+/// Failing to emit the event is logged rather than returned, as it should
+/// never stop the action itself.
+pub async fn record_audit(events: &EventSystem, event: SecurityAuditEvent) {
+    if let Err(e) = events.emit_core(SECURITY_AUDIT_EVENT, &event).await {
+        warn!("📜 Failed to emit security_audit event: {}", e);
+    }
+}
+
+/// Audit log file written from `security_audit` core events
+#[derive(Debug)]
+pub struct AuditLog {
+    config: AuditLogConfig,
+    state: Mutex<AuditLogState>,
+}
+
+#[derive(Debug, Default)]
+struct AuditLogState {
+    /// The open log file and its size in bytes
+    file: Option<(File, u64)>,
+    /// Recent `rate_limited` records by target and detail, with when one was
+    /// last written and how many were held back since
+    rate_limited: HashMap<(Option<String>, Option<String>), (u64, u64)>,
+}
+
+impl AuditLog {
+    /// Creates a log that opens its file on the first record
+    pub fn new(config: AuditLogConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(AuditLogState::default()),
+        }
+    }
+
+    /// Subscribes the log to `security_audit` core events.
+    ///
+    /// Records that can't be written are logged and dropped.
+    pub async fn register(self: Arc<Self>, events: &EventSystem) -> Result<(), EventError> {
+        events
+            .on_core(SECURITY_AUDIT_EVENT, move |event: SecurityAuditEvent| {
+                if let Err(e) = self.append(&event) {
+                    warn!("📜 Failed to write {:?} to the audit log: {}", event.action, e);
+                }
+                Ok(())
+            })
+            .await
+    }
+
+    /// Appends a record to the log file, rotating the file first if it would
+    /// outgrow `max_file_bytes`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the record is written or coalesced, or without a
+    /// configured path, or the I/O error that prevented writing it.
+    pub fn append(&self, event: &SecurityAuditEvent) -> std::io::Result<()> {
+        let Some(path) = &self.config.path else {
+            return Ok(());
+        };
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut event = event.clone();
+        if event.action == AuditAction::RateLimited {
+            let now = event.timestamp;
+            let key = (event.target.clone(), event.detail.clone());
+            match state.rate_limited.get_mut(&key) {
+                Some((written_at, held_back)) if now < *written_at + RATE_LIMIT_COALESCE_SECS => {
+                    *held_back += 1;
+                    return Ok(());
+                }
+                Some((_, held_back)) if *held_back > 0 => {
+                    let detail = event.detail.take().unwrap_or_default();
+                    event.detail = Some(format!("{detail} (+{held_back} more)").trim_start().to_string());
+                }
+                _ => {}
+            }
+            state.rate_limited.retain(|_, (written_at, _)| now < *written_at + RATE_LIMIT_COALESCE_SECS);
+            state.rate_limited.insert(key, (now, 0));
+        }
+
+        let mut line = serde_json::to_vec(&event)?;
+        line.push(b'\n');
+        let (file, size) = match state.file.take() {
+            Some(open) => open,
+            None => open_log(path)?,
+        };
+        let (mut file, mut size) = if size > 0 && size + line.len() as u64 > self.config.max_file_bytes {
+            drop(file);
+            self.rotate(path)?;
+            open_log(path)?
+        } else {
+            (file, size)
+        };
+
+        file.write_all(&line)?;
+        size += line.len() as u64;
+        state.file = Some((file, size));
+        Ok(())
+    }
+
+    /// Shifts `<path>.N` to `<path>.N+1` and `<path>` to `<path>.1`, deleting
+    /// the oldest file beyond `max_files`
+    fn rotate(&self, path: &Path) -> std::io::Result<()> {
+        if self.config.max_files == 0 {
+            return std::fs::remove_file(path).or_else(ignore_missing);
+        }
+        std::fs::remove_file(rotated_path(path, self.config.max_files)).or_else(ignore_missing)?;
+        for index in (1..self.config.max_files).rev() {
+            std::fs::rename(rotated_path(path, index), rotated_path(path, index + 1)).or_else(ignore_missing)?;
+        }
+        std::fs::rename(path, rotated_path(path, 1)).or_else(ignore_missing)
+    }
+}
+
+/// Opens the log file for appending, creating it and its directory if needed
+fn open_log(path: &Path) -> std::io::Result<(File, u64)> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let size = file.metadata()?.len();
+    Ok((file, size))
+}
+
+/// Gets the path of the `index`th rotated log file
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(format!(".{index}"));
+    PathBuf::from(rotated)
+}
+
+fn ignore_missing(e: std::io::Error) -> std::io::Result<()> {
+    match e.kind() {
+        std::io::ErrorKind::NotFound => Ok(()),
+        _ => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_records(path: &Path) -> Vec<SecurityAuditEvent> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_log_rotates_and_keeps_max_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit").join("audit.jsonl");
+        let log = AuditLog::new(AuditLogConfig {
+            path: Some(path.clone()),
+            max_file_bytes: 300,
+            max_files: 2,
+        });
+
+        for index in 0..12 {
+            let event = SecurityAuditEvent::new(AuditAction::Kick, "admin_api").with_target(format!("player-{index:02}"));
+            log.append(&event).unwrap();
+        }
+
+        let current = read_records(&path);
+        assert_eq!(current.last().unwrap().target.as_deref(), Some("player-11"));
+        assert!(std::fs::metadata(&path).unwrap().len() <= 300);
+        let older = read_records(&rotated_path(&path, 1));
+        let oldest = read_records(&rotated_path(&path, 2));
+        assert!(oldest.last().unwrap().target < older.first().unwrap().target);
+        assert!(!rotated_path(&path, 3).exists());
+        // Everything in the kept files is in order, with nothing lost between them
+        let kept: Vec<_> = [oldest, older, current].concat().into_iter().map(|event| event.target.unwrap()).collect();
+        let first: usize = kept[0]["player-".len()..].parse().unwrap();
+        assert_eq!(kept, (first..12).map(|index| format!("player-{index:02}")).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_repeated_rate_limit_trips_are_coalesced() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let log = AuditLog::new(AuditLogConfig {
+            path: Some(path.clone()),
+            ..Default::default()
+        });
+
+        let mut trip = SecurityAuditEvent::new(AuditAction::RateLimited, "server").with_target("p1").with_detail("chat:send");
+        for _ in 0..5 {
+            log.append(&trip).unwrap();
+        }
+        log.append(&SecurityAuditEvent::new(AuditAction::RateLimited, "server").with_target("p2").with_detail("chat:send")).unwrap();
+        trip.timestamp += RATE_LIMIT_COALESCE_SECS;
+        log.append(&trip).unwrap();
+
+        let details: Vec<_> = read_records(&path).into_iter().map(|event| (event.target.unwrap(), event.detail.unwrap())).collect();
+        assert_eq!(
+            details,
+            [
+                ("p1".to_string(), "chat:send".to_string()),
+                ("p2".to_string(), "chat:send".to_string()),
+                ("p1".to_string(), "chat:send (+4 more)".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_core_events_reach_the_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let events = horizon_event_system::create_horizon_event_system();
+        let log = Arc::new(AuditLog::new(AuditLogConfig {
+            path: Some(path.clone()),
+            ..Default::default()
+        }));
+        log.register(&events).await.unwrap();
+
+        record_audit(&events, SecurityAuditEvent::new(AuditAction::Ban, "moderation").with_target("10.0.0.7").with_detail("cheating")).await;
+
+        let records = read_records(&path);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].action, AuditAction::Ban);
+        assert_eq!(records[0].detail.as_deref(), Some("cheating"));
+    }
+}
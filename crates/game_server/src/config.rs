@@ -3,8 +3,12 @@
 //! This module contains the server configuration structure and default values
 //! used to initialize and customize the game server behavior.
 
-use horizon_event_system::RegionBounds;
+use crate::health::circuit_breaker::CircuitBreakerConfig;
+use crate::security::ip_filter::IpNetwork;
+use horizon_event_system::gorc::{GorcNetworkConfig, DEFAULT_OBJECT_SHARDS};
+use horizon_event_system::{EmissionQueueConfig, HandlerSpanConfig, RegionBounds, SpatialIndexConfig};
 use plugin_system::PluginSafetyConfig;
+use std::collections::HashMap;
 use std::net::{IpAddr, SocketAddr};
 use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
@@ -30,31 +34,815 @@ pub struct ServerConfig {
     /// Connection timeout in seconds
     pub connection_timeout: u64,
     
+    /// Seconds a dropped WebSocket client can resume its player with its session token (0 disables resumption)
+    pub session_resume_window_secs: u64,
+    
+    /// Server-driven WebSocket pings and the idle timeout they enforce
+    pub heartbeat: HeartbeatConfig,
+    
+    /// Limits on the messages queued for a client that isn't reading them
+    pub outbound_queue: OutboundQueueConfig,
+    
+    /// Limits on new WebSocket connections, applied as they are accepted
+    pub connection_throttle: ConnectionThrottleConfig,
+    
+    /// Wire protocol and client versions WebSocket clients must connect with
+    pub protocol: ProtocolConfig,
+    
+    /// JWT authentication of clients and the namespaces open to unauthenticated players
+    pub auth: AuthConfig,
+    
     /// Whether to use SO_REUSEPORT for multi-threaded accept loops
     pub use_reuse_port: bool,
     
+    /// Accept loops per listener (0 uses one per CPU core with `use_reuse_port`, else one)
+    pub accept_threads: usize,
+    
+    /// Further addresses the WebSocket server listens on next to `bind_address`,
+    /// such as a LAN interface or an IPv6 endpoint
+    pub listeners: Vec<ListenerConfig>,
+    
     /// Server tick interval in milliseconds (0 to disable)
     pub tick_interval_ms: u64,
     
+    /// GORC zone exit margin as a fraction of the zone radius (0.1 = exit at 1.1x)
+    pub zone_exit_margin: f64,
+    
+    /// How far ahead GORC dead-reckons player positions for zone checks, in milliseconds (0 to disable)
+    pub dead_reckoning_horizon_ms: u64,
+    
+    /// Number of locked shards GORC partitions objects into
+    pub object_shards: usize,
+    
+    /// GORC spatial index implementation and tuning
+    pub spatial_index: SpatialIndexConfig,
+    
+    /// GORC replication batching, channel frequency and compression settings
+    pub gorc_network: GorcNetworkConfig,
+    
     /// Security configuration settings
     pub security: SecurityConfig,
     
     /// Plugin safety configuration settings
     pub plugin_safety: PluginSafetyConfig,
+    
+    /// GORC world snapshot settings
+    pub snapshot: SnapshotConfig,
+    
+    /// Cross-server region federation settings
+    pub federation: FederationConfig,
+    
+    /// Server-to-server channel for `s2s:*` events between Horizon servers
+    pub s2s: S2sConfig,
+    
+    /// Chat filter plugins reach through `ServerContext::content_filter`
+    pub content_filter: ContentFilterConfig,
+    
+    /// Append-only log of bans, kicks, rate-limit trips and admin calls
+    pub audit_log: AuditLogConfig,
+    
+    /// Country rules and per-region connection caps from a GeoIP database
+    pub geoip: GeoIpConfig,
+    
+    /// Webhooks alerted when security events pile up
+    pub alerting: AlertingConfig,
+    
+    /// Address of the admin HTTP endpoint (`None` disables it)
+    pub admin_address: Option<SocketAddr>,
+    
+    /// Bearer token admin requests must present (`None` disables the `/admin/` routes)
+    pub admin_token: Option<String>,
+    
+    /// Address of the gRPC management API, which requires the admin token (`None` disables it)
+    pub grpc_address: Option<SocketAddr>,
+    
+    /// Address serving `/healthz`, `/readyz` and `/metrics` (`None` disables it)
+    pub health_address: Option<SocketAddr>,
+    
+    /// Address of the UDP endpoint for channel 0 replication (`None` keeps all traffic on WebSocket)
+    pub udp_address: Option<SocketAddr>,
+    
+    /// QUIC listener alongside the WebSocket server
+    pub quic: QuicConfig,
+    
+    /// TLS termination for WebSocket clients (`wss://`)
+    pub tls: TlsConfig,
+    
+    /// PROXY protocol headers from load balancers in front of the WebSocket listener
+    pub proxy_protocol: ProxyProtocolConfig,
+    
+    /// Bounds and overflow policy of the per-category event emission queues
+    pub event_queue: EmissionQueueConfig,
+    
+    /// Tracing spans around event handler invocations
+    pub handler_spans: HandlerSpanConfig,
+    
+    /// Priority lanes incoming client messages are routed through
+    pub message_lanes: MessageLaneConfig,
+    
+    /// Circuit breakers around each plugin's handler for an event key
+    pub plugin_circuit_breaker: CircuitBreakerConfig,
+}
+
+/// World snapshot persistence for GORC objects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotConfig {
+    /// Snapshot file path (`None` disables snapshots)
+    pub path: Option<PathBuf>,
+    
+    /// Seconds between autosaves (0 to disable autosave)
+    pub autosave_interval_secs: u64,
+    
+    /// Whether to restore the snapshot when the server starts
+    pub restore_on_start: bool,
+}
+
+/// Cross-server replication with neighboring regions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FederationConfig {
+    /// Name this region uses when talking to its neighbors
+    pub region_name: String,
+    
+    /// Address to accept neighbor connections on (`None` only sends)
+    pub listen_address: Option<SocketAddr>,
+    
+    /// Neighboring regions (empty disables federation)
+    pub neighbors: Vec<NeighborConfig>,
+    
+    /// Width of the border band mirrored to neighbors as ghost copies
+    pub overlap_band: f64,
+    
+    /// Milliseconds between federation scans
+    pub scan_interval_ms: u64,
+}
+
+/// QUIC listener for clients that want unreliable datagrams next to a reliable stream
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuicConfig {
+    /// Address to accept QUIC connections on (`None` disables the listener)
+    pub listen_address: Option<SocketAddr>,
+    
+    /// PEM certificate chain presented to clients
+    pub cert_path: Option<PathBuf>,
+    
+    /// PEM private key of the certificate
+    pub key_path: Option<PathBuf>,
+}
+
+/// TLS termination for WebSocket clients, so `wss://` needs no reverse proxy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TlsConfig {
+    /// Whether the WebSocket listener only accepts TLS connections
+    pub enabled: bool,
+    
+    /// PEM certificate chain served when no SNI certificate matches
+    pub cert_path: Option<PathBuf>,
+    
+    /// PEM private key of the default certificate
+    pub key_path: Option<PathBuf>,
+    
+    /// Certificates selected by the server name clients request
+    pub sni: Vec<SniCertificateConfig>,
+    
+    /// Seconds between checks for renewed certificate files (0 disables reloading)
+    pub reload_interval_secs: u64,
+}
+
+/// An additional address the WebSocket server accepts clients on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListenerConfig {
+    /// Address to listen on, e.g. `"192.168.1.10:8080"` or `"[::]:8080"`
+    pub address: SocketAddr,
+    
+    /// Whether to use SO_REUSEPORT for one accept loop per CPU core on this address
+    #[serde(default)]
+    pub use_reuse_port: bool,
+    
+    /// For IPv6 addresses, whether to refuse IPv4 clients. Leave off for a
+    /// dual-stack `[::]` listener; turn on to run next to a separate IPv4
+    /// listener on the same port.
+    #[serde(default)]
+    pub ipv6_only: bool,
+}
+
+impl ListenerConfig {
+    /// Creates a listener on `address` with a single accept loop
+    pub fn new(address: SocketAddr) -> Self {
+        Self {
+            address,
+            use_reuse_port: false,
+            ipv6_only: false,
+        }
+    }
+}
+
+/// PROXY protocol (v1 or v2) headers naming the client a load balancer relays a connection for
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProxyProtocolConfig {
+    /// Whether connections from trusted proxies start with a PROXY protocol header
+    pub enabled: bool,
+    
+    /// Address ranges of the proxies, such as `"10.0.0.0/8"`; connections from
+    /// elsewhere are taken as direct (empty trusts no proxy)
+    pub trusted_proxies: Vec<IpNetwork>,
+    
+    /// Milliseconds a proxy has to send the header before its connection is dropped
+    pub header_timeout_ms: u64,
+}
+
+impl Default for ProxyProtocolConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            trusted_proxies: Vec::new(),
+            header_timeout_ms: 5_000,
+        }
+    }
+}
+
+/// Certificate served to clients requesting a particular server name (SNI)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SniCertificateConfig {
+    /// Server name, e.g. `eu.example.com`, or a wildcard such as `*.example.com`
+    pub server_name: String,
+    
+    /// PEM certificate chain for the name
+    pub cert_path: PathBuf,
+    
+    /// PEM private key of the certificate
+    pub key_path: PathBuf,
+}
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cert_path: None,
+            key_path: None,
+            sni: Vec::new(),
+            // Certificates renewed by an ACME client are picked up within the hour
+            reload_interval_secs: 3600,
+        }
+    }
+}
+
+/// Priority of an incoming client message.
+///
+/// Under load, messages in a higher lane are routed before any message
+/// waiting in a lower one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageLane {
+    /// Latency-critical traffic such as movement and channel-0 GORC events
+    Critical,
+    /// Regular gameplay traffic such as chat
+    Normal,
+    /// Bulky, latency-tolerant traffic such as scans and metadata
+    Bulk,
+}
+
+impl MessageLane {
+    /// Every lane, highest priority first
+    pub const ALL: [MessageLane; 3] = [Self::Critical, Self::Normal, Self::Bulk];
+
+    /// Lowercase name of the lane, as used in configuration files
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Critical => "critical",
+            Self::Normal => "normal",
+            Self::Bulk => "bulk",
+        }
+    }
+
+    /// Lane of a GORC replication channel (0 critical, 1 normal, 2+ bulk)
+    pub fn for_channel(channel: u8) -> Self {
+        match channel {
+            0 => Self::Critical,
+            1 => Self::Normal,
+            _ => Self::Bulk,
+        }
+    }
+}
+
+/// WebSocket pings the server sends to measure round-trip time and detect dead clients
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeartbeatConfig {
+    /// Milliseconds between pings (0 disables heartbeats)
+    pub interval_ms: u64,
+    
+    /// Pings in a row a client may leave unanswered before it is disconnected
+    pub max_missed: u32,
+}
+
+/// Limits on new WebSocket connections, enforced by the accept loops.
+///
+/// Connections over either limit are closed as soon as they are accepted,
+/// before any handshake or per-address check, so a flood of connections
+/// can't exhaust file descriptors or keep the runtime busy with handshakes.
+/// A connection is pending from being accepted until it has a player, and
+/// is closed if its TLS and WebSocket handshakes take longer than
+/// `handshake_timeout_ms`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConnectionThrottleConfig {
+    /// New connections accepted per second across every listener (0 disables the limit)
+    pub max_new_connections_per_sec: u32,
+    
+    /// Connections that may be pending at once (0 disables the limit)
+    pub max_pending_handshakes: usize,
+    
+    /// Milliseconds a pending connection has to finish its TLS and WebSocket handshakes (0 disables the timeout)
+    pub handshake_timeout_ms: u64,
+}
+
+impl Default for ConnectionThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_new_connections_per_sec: 0,
+            max_pending_handshakes: 0,
+            handshake_timeout_ms: 10_000,
+        }
+    }
+}
+
+/// Backlog policy for clients that don't keep up with the messages sent to them.
+///
+/// Once a client has `drop_cosmetic_at` undelivered messages, replication
+/// updates on its `cosmetic_channels` are dropped instead of queued. If the
+/// backlog still reaches `disconnect_at`, the client is disconnected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OutboundQueueConfig {
+    /// Undelivered messages from which cosmetic updates are dropped (0 never drops them)
+    pub drop_cosmetic_at: u64,
+    
+    /// Undelivered messages at which the client is disconnected (0 never disconnects it)
+    pub disconnect_at: u64,
+    
+    /// GORC replication channels whose updates may be dropped
+    pub cosmetic_channels: Vec<u8>,
+}
+
+/// Versions WebSocket clients must connect with.
+///
+/// Clients offer the wire protocol they speak as a `horizon.v<N>`
+/// subprotocol and declare their game client version as `?client_version=`
+/// or in an `X-Horizon-Client-Version` header.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProtocolConfig {
+    /// Refuse clients that don't offer a `horizon.v<N>` subprotocol, instead
+    /// of treating them as speaking version 1
+    pub require_subprotocol: bool,
+    
+    /// Oldest client version allowed to connect, such as `"1.4.0"` (`None` allows any)
+    pub min_client_version: Option<String>,
+}
+
+/// Authentication of clients and the namespaces they may use before authenticating
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthConfig {
+    /// Keys client tokens may be signed with. With at least one key, WebSocket
+    /// and QUIC clients must present a JWT as `{"type": "auth", "token": "..."}`
+    /// right after connecting.
+    pub jwt_keys: Vec<JwtKeyConfig>,
+    
+    /// Required `iss` claim of client tokens
+    pub issuer: Option<String>,
+    
+    /// Required `aud` claim of client tokens
+    pub audience: Option<String>,
+    
+    /// Seconds of clock skew tolerated when checking `exp` and `nbf`
+    pub leeway_secs: u64,
+    
+    /// Milliseconds a client has to present its token before it is disconnected
+    pub handshake_timeout_ms: u64,
+    
+    /// Refuse messages from players that aren't authenticated, except in `public_namespaces`
+    pub require_authentication: bool,
+    
+    /// Namespaces unauthenticated players may send messages to
+    pub public_namespaces: Vec<String>,
+    
+    /// Let clients that must present a token play as anonymous guests with
+    /// `{"type": "guest"}` instead, and authenticate later without reconnecting
+    pub allow_guests: bool,
+    
+    /// Namespaces guests may send messages to
+    pub guest_namespaces: Vec<String>,
+    
+    /// External identity providers clients may log in through with
+    /// `{"type": "login", "provider": "<name>", "token": "..."}` right after connecting
+    pub providers: Vec<IdentityProviderConfig>,
+    
+    /// File linking external identities to players (`None` keeps links in memory)
+    pub account_links_path: Option<PathBuf>,
+}
+
+/// An external identity provider clients can log in through
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IdentityProviderConfig {
+    /// OpenID Connect provider whose ID tokens clients present
+    Oidc {
+        /// Name clients log in with
+        name: String,
+        /// Required `iss` claim, also where the provider's discovery document is looked up
+        issuer: String,
+        /// Client ID of the game, the required `aud` claim
+        client_id: String,
+        /// JWKS URL of the provider's signing keys; found through discovery when unset
+        #[serde(default)]
+        jwks_uri: Option<String>,
+        /// JWKS file of the provider's signing keys, used instead of fetching them
+        #[serde(default)]
+        jwks_path: Option<PathBuf>,
+    },
+    /// Discord OAuth2; clients present an access token with the `identify` scope
+    Discord {
+        /// Name clients log in with
+        #[serde(default = "default_discord_name")]
+        name: String,
+        /// Client ID of the game's Discord application, which access tokens
+        /// must have been issued to
+        client_id: String,
+        /// Base URL of the Discord API
+        #[serde(default = "default_discord_api_url")]
+        api_url: String,
+    },
+    /// Steam; clients present a hex-encoded session ticket for the game's app
+    Steam {
+        /// Name clients log in with
+        #[serde(default = "default_steam_name")]
+        name: String,
+        /// Steam app ID of the game
+        app_id: u32,
+        /// Steam Web API publisher key
+        api_key: String,
+        /// Base URL of the Steam Web API
+        #[serde(default = "default_steam_api_url")]
+        api_url: String,
+    },
+}
+
+impl IdentityProviderConfig {
+    /// Gets the name clients log in with
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Oidc { name, .. } | Self::Discord { name, .. } | Self::Steam { name, .. } => name,
+        }
+    }
+}
+
+fn default_discord_name() -> String {
+    "discord".to_string()
+}
+
+fn default_discord_api_url() -> String {
+    "https://discord.com/api/v10".to_string()
+}
+
+fn default_steam_name() -> String {
+    "steam".to_string()
+}
+
+fn default_steam_api_url() -> String {
+    "https://partner.steam-api.com".to_string()
+}
+
+/// A key client tokens can be verified with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JwtKeyConfig {
+    /// Key ID matched against a token's `kid` header; a key without one
+    /// verifies tokens with any `kid`
+    #[serde(default)]
+    pub kid: Option<String>,
+    
+    /// Signing algorithm, such as `HS256`, `RS256`, `ES256` or `EdDSA`
+    pub algorithm: jsonwebtoken::Algorithm,
+    
+    /// Shared secret of `HS*` keys
+    #[serde(default)]
+    pub secret: Option<String>,
+    
+    /// PEM public key file of `RS*`, `PS*`, `ES*` and `EdDSA` keys
+    #[serde(default)]
+    pub public_key_path: Option<PathBuf>,
+}
+
+/// Priority lanes incoming client messages are routed through
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MessageLaneConfig {
+    /// Router tasks; each connection's messages always go to the same one
+    pub shards: usize,
+    
+    /// Messages each lane of a shard holds before readers wait for room
+    pub capacity: usize,
+    
+    /// Lane of client messages by namespace (others use the normal lane)
+    pub namespaces: HashMap<String, MessageLane>,
+}
+
+/// A neighboring region server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeighborConfig {
+    /// Name the neighbor uses for itself
+    pub name: String,
+    
+    /// Federation address of the neighbor
+    pub address: SocketAddr,
+    
+    /// Spatial bounds owned by the neighbor
+    pub bounds: RegionBounds,
+    
+    /// Secret shared with the neighbor, which both sides prove they hold
+    /// when connecting (federation refuses to start without one)
+    #[serde(default)]
+    pub secret: String,
+    
+    /// WebSocket URL clients reconnect to when handed off to the neighbor
+    /// (`None` leaves moving the client to plugins)
+    #[serde(default)]
+    pub client_url: Option<String>,
+}
+
+/// Authenticated channel region servers, matchmakers and chat services
+/// built on Horizon exchange `s2s:*` events over.
+/// 
+/// Servers connect over WebSocket and present the shared secret, or the
+/// peer's own secret, as `Authorization: Bearer <secret>` along with their
+/// name. Only configured peers are accepted. The channel is plain
+/// WebSocket, so keep it on a private network.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct S2sConfig {
+    /// Name this server authenticates to other servers as
+    pub name: String,
+    
+    /// Address to accept server connections on (`None` only connects out)
+    pub listen_address: Option<SocketAddr>,
+    
+    /// Secret shared by every server on the channel (`None` disables it)
+    pub secret: Option<String>,
+    
+    /// Servers this one exchanges events with; no other server is accepted
+    pub peers: Vec<S2sPeerConfig>,
+}
+
+impl Default for S2sConfig {
+    fn default() -> Self {
+        Self {
+            name: "horizon".to_string(),
+            listen_address: None,
+            secret: None,
+            peers: Vec::new(),
+        }
+    }
+}
+
+/// A server on the server-to-server channel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct S2sPeerConfig {
+    /// Name the server authenticates as
+    pub name: String,
+    
+    /// WebSocket URL of its server-to-server listener, such as `ws://10.0.0.12:9500`
+    /// (`None` for servers that only connect in)
+    #[serde(default)]
+    pub url: Option<String>,
+    
+    /// Secret used with this server instead of the shared one
+    #[serde(default)]
+    pub secret: Option<String>,
+}
+
+/// Word lists and rules chat messages are checked against.
+/// 
+/// A message breaking several rules gets the most severe action of them:
+/// `mute` over `drop` over `censor`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContentFilterConfig {
+    /// Rules messages are checked against (none disables the filter)
+    pub rules: Vec<ContentFilterRule>,
+    
+    /// Seconds a `mute` rule silences a player for
+    pub mute_duration_secs: u64,
+    
+    /// Character censored words are masked with
+    pub mask: char,
+}
+
+impl Default for ContentFilterConfig {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            mute_duration_secs: 300,
+            mask: '*',
+        }
+    }
+}
+
+/// A list of words and patterns and what to do with messages containing them
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContentFilterRule {
+    /// Name the rule is reported under
+    pub name: String,
+    
+    /// Words matched whole and ignoring case
+    pub words: Vec<String>,
+    
+    /// File of further words, one per line (`#` starts a comment)
+    pub word_list_path: Option<PathBuf>,
+    
+    /// Regular expressions matched ignoring case, such as `"fr[e3]{2}\\s*gold"`
+    pub patterns: Vec<String>,
+    
+    /// What to do with a message that matches
+    pub action: ContentFilterAction,
+    
+    /// Locales the rule applies to, such as `"en"` or `"pt-BR"` (empty applies
+    /// it to every locale). `"pt"` also covers `"pt-BR"`.
+    pub locales: Vec<String>,
+}
+
+/// What a content filter rule does with a message that matches it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentFilterAction {
+    /// Mask the matching text
+    #[default]
+    Censor,
+    /// Drop the message
+    Drop,
+    /// Drop the message and mute the player
+    Mute,
+}
+
+/// Security audit log settings
+/// 
+/// Every `security_audit` core event is appended to the log as a line of
+/// JSON. When the file would grow past `max_file_bytes` it is renamed to
+/// `<path>.1`, older files shift up by one, and the oldest beyond
+/// `max_files` is deleted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditLogConfig {
+    /// File the log is written to (`None` only emits the core events)
+    pub path: Option<PathBuf>,
+    
+    /// Size in bytes a log file may reach before it is rotated
+    pub max_file_bytes: u64,
+    
+    /// Rotated files kept besides the current one
+    pub max_files: usize,
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            path: None,
+            max_file_bytes: 10 * 1024 * 1024,
+            max_files: 5,
+        }
+    }
+}
+
+/// GeoIP connection policy settings
+/// 
+/// Client addresses are looked up in a MaxMind database (GeoLite2 or
+/// GeoIP2, Country or City edition). Countries and continents are ISO codes
+/// such as `"DE"` and `"EU"`. Addresses the database doesn't know, such as
+/// LAN addresses, pass the country rules unless `refuse_unknown` is set, and
+/// count against no cap.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GeoIpConfig {
+    /// MaxMind `.mmdb` database file (`None` disables the policy)
+    pub database_path: Option<PathBuf>,
+    
+    /// Countries clients may connect from (empty allows every country)
+    pub allow_countries: Vec<String>,
+    
+    /// Countries whose clients are refused, even if allowed
+    pub deny_countries: Vec<String>,
+    
+    /// Refuse addresses the database has no country for
+    pub refuse_unknown: bool,
+    
+    /// Most concurrent connections from each listed country
+    pub country_connection_caps: HashMap<String, u32>,
+    
+    /// Most concurrent connections from each listed continent
+    pub continent_connection_caps: HashMap<String, u32>,
+}
+
+/// Security alert webhook settings
+/// 
+/// Security audit events are counted over the last `window_secs`. Once
+/// rate-limit trips, ban evasion attempts or oversized messages reach their
+/// threshold, an alert is posted to every webhook, and no further alert of
+/// that kind is sent for `cooldown_secs`. A threshold of 0 disables its alert.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertingConfig {
+    /// Webhooks alerts are posted to (empty disables alerting)
+    pub webhooks: Vec<AlertWebhookConfig>,
+    
+    /// Seconds of events counted towards each threshold
+    pub window_secs: u64,
+    
+    /// Seconds after an alert before another of the same kind is sent
+    pub cooldown_secs: u64,
+    
+    /// Messages dropped for exceeding a rate limit that make a rate-limit storm
+    pub rate_limit_storm_threshold: u32,
+    
+    /// Connections refused to banned players or hardware from unbanned addresses
+    pub ban_evasion_threshold: u32,
+    
+    /// Messages dropped for exceeding the size limit
+    pub oversized_message_threshold: u32,
+}
+
+impl Default for AlertingConfig {
+    fn default() -> Self {
+        Self {
+            webhooks: Vec::new(),
+            window_secs: 60,
+            cooldown_secs: 300,
+            rate_limit_storm_threshold: 500,
+            ban_evasion_threshold: 3,
+            oversized_message_threshold: 20,
+        }
+    }
+}
+
+/// A webhook security alerts are posted to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertWebhookConfig {
+    /// URL the alert is POSTed to
+    pub url: String,
+    
+    /// Service the payload is shaped for
+    #[serde(default)]
+    pub format: AlertWebhookFormat,
+    
+    /// PagerDuty Events API v2 integration key (required by the `pagerduty` format)
+    #[serde(default)]
+    pub routing_key: Option<String>,
+}
+
+/// Payload shape of a security alert webhook
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertWebhookFormat {
+    /// Discord webhook message
+    #[default]
+    Discord,
+    /// Slack incoming webhook message
+    Slack,
+    /// PagerDuty Events API v2 trigger event
+    PagerDuty,
 }
 
 /// Security configuration for input validation and protection
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SecurityConfig {
     /// Enable rate limiting
     pub enable_rate_limiting: bool,
     
-    /// Maximum requests per minute per IP
-    pub max_requests_per_minute: u32,
+    /// Messages per second every client together may send (0 disables the limit)
+    pub global_message_rate_limit: u32,
+    
+    /// Messages per second each IP address may send (0 disables the limit)
+    pub ip_message_rate_limit: u32,
+    
+    /// Messages per second each player may send across all events (0 disables the limit)
+    pub player_message_rate_limit: u32,
+    
+    /// Removed per-minute limit, replaced by the per-second limits above.
+    /// Configs that still set it are rejected at startup rather than
+    /// silently running with different limits.
+    #[serde(skip_serializing)]
+    pub max_requests_per_minute: Option<u32>,
     
     /// Maximum message size in bytes
     pub max_message_size: usize,
     
+    /// Maximum payload size in bytes of a raw binary client message
+    /// (0 refuses raw binary messages)
+    pub max_binary_message_size: usize,
+    
     /// Maximum allowed nesting depth for JSON messages
     pub max_json_depth: usize,
     
@@ -67,12 +855,60 @@ pub struct SecurityConfig {
     /// Enable DDoS protection
     pub enable_ddos_protection: bool,
     
-    /// Banned IP addresses
+    /// IP addresses banned permanently, added to the ban list on startup
     pub banned_ips: Vec<IpAddr>,
     
+    /// JSON file the ban list is persisted to, so bans issued at runtime
+    /// survive restarts. Without one, runtime bans only last until shutdown.
+    pub ban_list_path: Option<PathBuf>,
+    
+    /// Address ranges clients may connect from, such as `"10.0.0.0/8"`
+    /// (empty allows every address)
+    pub ip_allow_list: Vec<IpNetwork>,
+    
+    /// Address ranges whose connections are refused, even if allowed
+    pub ip_deny_list: Vec<IpNetwork>,
+    
+    /// JSON file of `{"allow": [...], "deny": [...]}` that replaces the two
+    /// lists above once it exists, and is reloaded whenever it changes
+    pub ip_filter_path: Option<PathBuf>,
+    
+    /// Seconds between checks for a changed IP filter file (0 disables reloading)
+    pub ip_filter_reload_interval_secs: u64,
+    
     /// Maximum concurrent connections per IP
     pub max_connections_per_ip: u32,
     
+    /// Messages per second each player may send, keyed by `namespace` or
+    /// `namespace:event` (native GORC events use the `gorc` namespace).
+    /// An event's own limit takes precedence over its namespace's, and a
+    /// message must pass it before the player, IP and global limits.
+    pub message_rate_limits: HashMap<String, u32>,
+    
+    /// Nonce and timestamp checks that stop captured messages being re-sent
+    pub replay_protection: ReplayProtectionConfig,
+}
+
+/// Replay protection for client messages.
+///
+/// When enabled, every JSON client message must carry a unique `nonce` and
+/// the Unix `timestamp` in seconds it was sent at, next to its `namespace`
+/// and `event`. Messages sent too long ago, or whose nonce was already used,
+/// are refused, so a captured payload can't be re-sent to repeat an attack
+/// or a purchase. Raw binary frames are not covered.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplayProtectionConfig {
+    /// Refuse messages without a fresh, unused nonce
+    pub enabled: bool,
+    
+    /// Seconds a message's timestamp may differ from the server clock
+    pub max_clock_skew_secs: u64,
+    
+    /// Nonces remembered at most per player. A player whose cache is full
+    /// has further messages refused until their oldest nonces expire, so
+    /// size it for a player's message rate times twice the clock skew.
+    pub nonce_cache_size: usize,
 }
 
 impl Default for ServerConfig {
@@ -90,10 +926,125 @@ impl Default for ServerConfig {
             plugin_directory: PathBuf::from("plugins"),
             max_connections: 1000,
             connection_timeout: 60,
+            session_resume_window_secs: 0,
+            heartbeat: HeartbeatConfig::default(),
+            outbound_queue: OutboundQueueConfig::default(),
+            connection_throttle: ConnectionThrottleConfig::default(),
+            protocol: ProtocolConfig::default(),
+            auth: AuthConfig::default(),
             use_reuse_port: false,
+            accept_threads: 0,
+            listeners: Vec::new(),
             tick_interval_ms: 50, // 20 ticks per second by default
+            zone_exit_margin: 0.1,
+            dead_reckoning_horizon_ms: 200,
+            object_shards: DEFAULT_OBJECT_SHARDS,
+            spatial_index: SpatialIndexConfig::default(),
+            gorc_network: GorcNetworkConfig::default(),
             security: SecurityConfig::default(),
             plugin_safety: PluginSafetyConfig::default(),
+            snapshot: SnapshotConfig::default(),
+            federation: FederationConfig::default(),
+            s2s: S2sConfig::default(),
+            content_filter: ContentFilterConfig::default(),
+            audit_log: AuditLogConfig::default(),
+            geoip: GeoIpConfig::default(),
+            alerting: AlertingConfig::default(),
+            admin_address: None,
+            admin_token: None,
+            grpc_address: None,
+            health_address: None,
+            udp_address: None,
+            quic: QuicConfig::default(),
+            tls: TlsConfig::default(),
+            proxy_protocol: ProxyProtocolConfig::default(),
+            event_queue: EmissionQueueConfig::default(),
+            handler_spans: HandlerSpanConfig::default(),
+            message_lanes: MessageLaneConfig::default(),
+            plugin_circuit_breaker: CircuitBreakerConfig::default(),
+        }
+    }
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            path: None,
+            autosave_interval_secs: 300, // 5 minutes
+            restore_on_start: true,
+        }
+    }
+}
+
+impl Default for ReplayProtectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_clock_skew_secs: 30,
+            nonce_cache_size: 1_000,
+        }
+    }
+}
+
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self {
+            interval_ms: 15_000,
+            max_missed: 3,
+        }
+    }
+}
+
+impl Default for OutboundQueueConfig {
+    fn default() -> Self {
+        Self {
+            drop_cosmetic_at: 256,
+            disconnect_at: 1024,
+            cosmetic_channels: vec![2, 3],
+        }
+    }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            jwt_keys: Vec::new(),
+            issuer: None,
+            audience: None,
+            leeway_secs: 30,
+            handshake_timeout_ms: 10_000,
+            require_authentication: false,
+            public_namespaces: vec!["system".to_string()],
+            allow_guests: false,
+            guest_namespaces: vec!["system".to_string()],
+            providers: Vec::new(),
+            account_links_path: None,
+        }
+    }
+}
+
+impl Default for MessageLaneConfig {
+    fn default() -> Self {
+        Self {
+            shards: 4,
+            capacity: 1024,
+            namespaces: HashMap::from([
+                ("movement".to_string(), MessageLane::Critical),
+                ("chat".to_string(), MessageLane::Normal),
+                ("scan".to_string(), MessageLane::Bulk),
+            ]),
+        }
+    }
+}
+
+impl Default for FederationConfig {
+    fn default() -> Self {
+        Self {
+            region_name: "default".to_string(),
+            listen_address: None,
+            neighbors: Vec::new(),
+            overlap_band: 50.0,
+            scan_interval_ms: 200,
         }
     }
 }
@@ -102,14 +1053,25 @@ impl Default for SecurityConfig {
     fn default() -> Self {
         Self {
             enable_rate_limiting: true,
-            max_requests_per_minute: 60,
+            global_message_rate_limit: 50_000,
+            ip_message_rate_limit: 400,
+            player_message_rate_limit: 200,
+            max_requests_per_minute: None,
             max_message_size: 64 * 1024, // 64KB
+            max_binary_message_size: 16 * 1024, // 16KB
             max_json_depth: 10,
             max_string_length: 1024,
             max_collection_size: 100,
             enable_ddos_protection: true,
             banned_ips: Vec::new(),
+            ban_list_path: None,
+            ip_allow_list: Vec::new(),
+            ip_deny_list: Vec::new(),
+            ip_filter_path: None,
+            ip_filter_reload_interval_secs: 5,
             max_connections_per_ip: 10,
+            message_rate_limits: HashMap::new(),
+            replay_protection: ReplayProtectionConfig::default(),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,390 @@
+//! JWT authentication of client connections.
+//!
+//! With signing keys configured, a WebSocket client presents a token right
+//! after the upgrade as `{"type": "auth", "token": "<jwt>"}`. A valid token
+//! marks the connection authenticated in the authentication status system
+//! and announces the token's subject to plugins. An invalid token, or none
+//! within the handshake timeout, gets the client disconnected.
+//!
+//! With guests allowed, a client may send `{"type": "guest"}` instead and
+//! play with its messages restricted to the guest namespaces. A guest
+//! upgrades by sending its token later in the session; it keeps its player,
+//! and with it its GORC object, position and subscriptions.
+//!
+//! Independently of tokens, the namespace policy keeps players that aren't
+//! authenticated, whether by token or by a plugin setting their status, out
+//! of every namespace but the public ones.
+
+use crate::config::AuthConfig;
+use horizon_event_system::AuthenticationStatus;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Errors raised while setting up authentication or verifying a token
+#[derive(Debug, Error)]
+pub enum AuthError {
+    /// A configured key can't be used
+    #[error("Invalid JWT key: {0}")]
+    InvalidKey(String),
+    /// No configured key matches the token's algorithm and key ID
+    #[error("No key to verify {0:?} tokens with")]
+    UnknownKey(Algorithm),
+    /// The token's signature or claims are invalid
+    #[error("Invalid token: {0}")]
+    InvalidToken(#[from] jsonwebtoken::errors::Error),
+}
+
+/// Message a client authenticates with
+#[derive(Debug, Deserialize)]
+pub struct AuthRequest {
+    /// `"auth"` for a JWT, `"login"` for an identity provider's token, or `"guest"`
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Identity provider a `"login"` token was issued by
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// The client's JWT or provider token
+    #[serde(default)]
+    pub token: String,
+}
+
+/// Claims of a verified token
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenClaims {
+    /// Subject the token was issued to, usually an account ID
+    pub sub: String,
+    /// Every claim of the token, including the registered ones
+    #[serde(flatten)]
+    pub claims: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A key tokens can be verified with
+struct VerificationKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    key: DecodingKey,
+}
+
+impl std::fmt::Debug for VerificationKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VerificationKey")
+            .field("kid", &self.kid)
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Token verification and namespace policy of client connections
+#[derive(Debug)]
+pub struct ClientAuthentication {
+    keys: Vec<VerificationKey>,
+    issuer: Option<String>,
+    audience: Option<String>,
+    leeway_secs: u64,
+    handshake_timeout: Duration,
+    require_authentication: bool,
+    public_namespaces: HashSet<String>,
+    allow_guests: bool,
+    guest_namespaces: HashSet<String>,
+}
+
+impl Default for ClientAuthentication {
+    /// Lets every client in without a token, with the default handshake timeout and namespaces
+    fn default() -> Self {
+        let config = AuthConfig::default();
+        Self {
+            keys: Vec::new(),
+            issuer: None,
+            audience: None,
+            leeway_secs: config.leeway_secs,
+            handshake_timeout: Duration::from_millis(config.handshake_timeout_ms),
+            require_authentication: config.require_authentication,
+            public_namespaces: config.public_namespaces.into_iter().collect(),
+            allow_guests: config.allow_guests,
+            guest_namespaces: config.guest_namespaces.into_iter().collect(),
+        }
+    }
+}
+
+impl ClientAuthentication {
+    /// Loads the configured keys and namespace policy
+    ///
+    /// # Returns
+    ///
+    /// The authentication, or an `AuthError` if a key can't be loaded.
+    pub fn new(config: &AuthConfig) -> Result<Self, AuthError> {
+        let keys = config
+            .jwt_keys
+            .iter()
+            .map(|key| {
+                Ok(VerificationKey {
+                    kid: key.kid.clone(),
+                    algorithm: key.algorithm,
+                    key: decoding_key(key.algorithm, key.secret.as_deref(), key.public_key_path.as_deref())?,
+                })
+            })
+            .collect::<Result<_, AuthError>>()?;
+
+        Ok(Self {
+            keys,
+            issuer: config.issuer.clone(),
+            audience: config.audience.clone(),
+            leeway_secs: config.leeway_secs,
+            handshake_timeout: Duration::from_millis(config.handshake_timeout_ms),
+            require_authentication: config.require_authentication,
+            public_namespaces: config.public_namespaces.iter().cloned().collect(),
+            allow_guests: config.allow_guests,
+            guest_namespaces: config.guest_namespaces.iter().cloned().collect(),
+        })
+    }
+
+    /// Checks whether clients must present a token after connecting
+    pub fn requires_token(&self) -> bool {
+        !self.keys.is_empty()
+    }
+
+    /// Checks whether clients may play as guests instead of presenting a token
+    pub fn allows_guests(&self) -> bool {
+        self.allow_guests
+    }
+
+    /// Gets how long a client has to present its token
+    pub fn handshake_timeout(&self) -> Duration {
+        self.handshake_timeout
+    }
+
+    /// Verifies a token's signature and claims
+    ///
+    /// # Returns
+    ///
+    /// The token's claims, or an `AuthError` describing why it was rejected.
+    pub fn verify(&self, token: &str) -> Result<TokenClaims, AuthError> {
+        let header = jsonwebtoken::decode_header(token)?;
+        let key = self
+            .keys
+            .iter()
+            .find(|key| key.algorithm == header.alg && (key.kid.is_none() || key.kid == header.kid))
+            .ok_or(AuthError::UnknownKey(header.alg))?;
+
+        let mut validation = Validation::new(key.algorithm);
+        validation.leeway = self.leeway_secs;
+        validation.set_required_spec_claims(&["exp", "sub"]);
+        if let Some(issuer) = &self.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        match &self.audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+        Ok(jsonwebtoken::decode::<TokenClaims>(token, &key.key, &validation)?.claims)
+    }
+
+    /// Checks whether a player with the given status may send messages to a namespace
+    pub fn permits(&self, namespace: &str, status: AuthenticationStatus) -> bool {
+        match status {
+            AuthenticationStatus::Guest => self.guest_namespaces.contains(namespace),
+            _ => {
+                !self.require_authentication
+                    || status == AuthenticationStatus::Authenticated
+                    || self.public_namespaces.contains(namespace)
+            }
+        }
+    }
+}
+
+/// Loads the key a configured algorithm verifies with
+fn decoding_key(algorithm: Algorithm, secret: Option<&str>, public_key_path: Option<&std::path::Path>) -> Result<DecodingKey, AuthError> {
+    if let Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 = algorithm {
+        let secret = secret.ok_or_else(|| AuthError::InvalidKey(format!("{algorithm:?} keys need a secret")))?;
+        return Ok(DecodingKey::from_secret(secret.as_bytes()));
+    }
+
+    let path = public_key_path.ok_or_else(|| AuthError::InvalidKey(format!("{algorithm:?} keys need a public_key_path")))?;
+    let pem = std::fs::read(path).map_err(|e| AuthError::InvalidKey(format!("{}: {e}", path.display())))?;
+    let key = match algorithm {
+        Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(&pem),
+        Algorithm::EdDSA => DecodingKey::from_ed_pem(&pem),
+        _ => DecodingKey::from_rsa_pem(&pem),
+    };
+    key.map_err(|e| AuthError::InvalidKey(format!("{}: {e}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::JwtKeyConfig;
+    use horizon_event_system::current_timestamp;
+    use jsonwebtoken::{EncodingKey, Header};
+
+    fn token(secret: &str, claims: serde_json::Value) -> String {
+        jsonwebtoken::encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(secret.as_bytes())).unwrap()
+    }
+
+    #[test]
+    fn test_tokens_are_verified_against_keys_and_claims() {
+        let authentication = ClientAuthentication::new(&AuthConfig {
+            jwt_keys: vec![JwtKeyConfig {
+                kid: None,
+                algorithm: Algorithm::HS256,
+                secret: Some("shared-secret".to_string()),
+                public_key_path: None,
+            }],
+            issuer: Some("accounts".to_string()),
+            ..AuthConfig::default()
+        })
+        .unwrap();
+        let exp = current_timestamp() + 60;
+
+        let claims = authentication
+            .verify(&token("shared-secret", serde_json::json!({ "sub": "account-7", "iss": "accounts", "exp": exp, "role": "admin" })))
+            .unwrap();
+        assert_eq!(claims.sub, "account-7");
+        assert_eq!(claims.claims["role"], "admin");
+
+        let forged = token("wrong-secret", serde_json::json!({ "sub": "account-7", "iss": "accounts", "exp": exp }));
+        assert!(matches!(authentication.verify(&forged), Err(AuthError::InvalidToken(_))));
+        let other_issuer = token("shared-secret", serde_json::json!({ "sub": "account-7", "iss": "elsewhere", "exp": exp }));
+        assert!(authentication.verify(&other_issuer).is_err());
+        let expired = token("shared-secret", serde_json::json!({ "sub": "account-7", "iss": "accounts", "exp": exp - 600 }));
+        assert!(authentication.verify(&expired).is_err());
+        assert!(authentication.verify("not-a-token").is_err());
+
+        assert!(ClientAuthentication::new(&AuthConfig {
+            jwt_keys: vec![JwtKeyConfig { kid: None, algorithm: Algorithm::RS256, secret: None, public_key_path: None }],
+            ..AuthConfig::default()
+        })
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_clients_authenticate_after_connecting() {
+        use crate::config::MessageLaneConfig;
+        use crate::connection::ConnectionManager;
+        use crate::messaging::MessageLanes;
+        use crate::server::handlers::handle_connection;
+        use futures::{SinkExt, StreamExt};
+        use horizon_event_system::{EventSystem, PlayerAuthenticatedEvent};
+        use std::sync::Arc;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let authentication = ClientAuthentication::new(&AuthConfig {
+            jwt_keys: vec![JwtKeyConfig {
+                kid: None,
+                algorithm: Algorithm::HS256,
+                secret: Some("shared-secret".to_string()),
+                public_key_path: None,
+            }],
+            allow_guests: true,
+            ..AuthConfig::default()
+        })
+        .unwrap();
+        let connection_manager = Arc::new(ConnectionManager::new().with_authentication(Arc::new(authentication)));
+        let events = Arc::new(EventSystem::new());
+        let (authenticated_tx, mut authenticated) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("player_authenticated", move |event: PlayerAuthenticatedEvent| {
+                let _ = authenticated_tx.send((event.player_id, event.subject, event.upgraded_from_guest));
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server_connections = connection_manager.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, addr)) = listener.accept().await {
+                let (connection_manager, events, message_lanes) = (server_connections.clone(), events.clone(), message_lanes.clone());
+                tokio::spawn(handle_connection(stream, addr, connection_manager, events, message_lanes, None));
+            }
+        });
+        async fn next_event<S>(client: &mut tokio_tungstenite::WebSocketStream<S>) -> serde_json::Value
+        where
+            S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+        {
+            match client.next().await {
+                Some(Ok(Message::Text(text))) => serde_json::from_str(&text).unwrap(),
+                other => panic!("expected a text message, got {other:?}"),
+            }
+        }
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{address}/")).await.unwrap();
+        let valid = token("shared-secret", serde_json::json!({ "sub": "account-7", "exp": current_timestamp() + 60 }));
+        client
+            .send(Message::Text(serde_json::json!({ "type": "auth", "token": valid }).to_string().into()))
+            .await
+            .unwrap();
+        let reply = next_event(&mut client).await;
+        assert_eq!(reply["event"], "authenticated");
+        let (player_id, subject, _) = authenticated.recv().await.unwrap();
+        assert_eq!(subject, "account-7");
+        assert_eq!(reply["player_id"], player_id.to_string());
+        assert_eq!(
+            connection_manager.get_auth_status_by_player(player_id).await,
+            Some(AuthenticationStatus::Authenticated)
+        );
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{address}/")).await.unwrap();
+        let forged = token("wrong-secret", serde_json::json!({ "sub": "account-7", "exp": current_timestamp() + 60 }));
+        client
+            .send(Message::Text(serde_json::json!({ "type": "auth", "token": forged }).to_string().into()))
+            .await
+            .unwrap();
+        assert_eq!(next_event(&mut client).await["event"], "auth_failed");
+        assert!(matches!(client.next().await, Some(Ok(Message::Close(Some(frame)))) if frame.reason == "Authentication failed"));
+
+        // Guests upgrade in-session and keep their player
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{address}/")).await.unwrap();
+        client.send(Message::Text(r#"{"type": "guest"}"#.into())).await.unwrap();
+        let guest = next_event(&mut client).await;
+        assert_eq!(guest["event"], "guest");
+        client
+            .send(Message::Text(serde_json::json!({ "type": "auth", "token": forged }).to_string().into()))
+            .await
+            .unwrap();
+        assert_eq!(next_event(&mut client).await["event"], "upgrade_failed");
+        client
+            .send(Message::Text(serde_json::json!({ "type": "auth", "token": valid }).to_string().into()))
+            .await
+            .unwrap();
+        let upgraded = next_event(&mut client).await;
+        assert_eq!((&upgraded["event"], &upgraded["upgraded"]), (&"authenticated".into(), &true.into()));
+        assert_eq!(upgraded["player_id"], guest["player_id"]);
+        let (player_id, _, upgraded_from_guest) = authenticated.recv().await.unwrap();
+        assert!(upgraded_from_guest);
+        assert_eq!(guest["player_id"], player_id.to_string());
+        assert_eq!(
+            connection_manager.get_auth_status_by_player(player_id).await,
+            Some(AuthenticationStatus::Authenticated)
+        );
+    }
+
+    #[test]
+    fn test_namespace_policy() {
+        let open = ClientAuthentication::new(&AuthConfig::default()).unwrap();
+        assert!(!open.requires_token());
+        assert_eq!(ClientAuthentication::default().handshake_timeout(), open.handshake_timeout());
+        assert!(open.permits("movement", AuthenticationStatus::Unauthenticated));
+
+        let strict = ClientAuthentication::new(&AuthConfig { require_authentication: true, ..AuthConfig::default() }).unwrap();
+        assert!(!strict.permits("movement", AuthenticationStatus::Unauthenticated));
+        assert!(!strict.permits("movement", AuthenticationStatus::AuthenticationFailed));
+        assert!(strict.permits("movement", AuthenticationStatus::Authenticated));
+        assert!(strict.permits("system", AuthenticationStatus::Unauthenticated));
+
+        let guests = ClientAuthentication::new(&AuthConfig {
+            allow_guests: true,
+            guest_namespaces: vec!["lobby".to_string()],
+            ..AuthConfig::default()
+        })
+        .unwrap();
+        // Guests are restricted even where unauthenticated players aren't
+        assert!(guests.permits("lobby", AuthenticationStatus::Guest));
+        assert!(!guests.permits("movement", AuthenticationStatus::Guest));
+        assert!(guests.permits("movement", AuthenticationStatus::Unauthenticated));
+    }
+}
@@ -3,9 +3,14 @@
 //! This module defines the structure and behavior of individual client
 //! connections, tracking their state and metadata.
 
-use horizon_event_system::{PlayerId, AuthenticationStatus};
+use crate::security::geoip::GeoTag;
+use horizon_event_system::{current_timestamp_millis, ClientVersion, ConnectionStats, PlayerId, AuthenticationStatus};
+use std::collections::HashMap;
 use std::net::SocketAddr;
-use std::time::SystemTime;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use tokio::sync::Notify;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 /// Represents an individual client connection to the server.
 /// 
@@ -19,6 +24,7 @@ use std::time::SystemTime;
 /// * `remote_addr` - The network address of the connected client
 /// * `connected_at` - Timestamp when the connection was established
 /// * `auth_status` - Current authentication status of the connection
+/// * `round_trip_time` - Latest heartbeat round-trip time
 #[derive(Debug)]
 pub struct ClientConnection {
     /// The player ID assigned to this connection (None until assigned)
@@ -32,6 +38,24 @@ pub struct ClientConnection {
     
     /// Current authentication status of this connection
     pub auth_status: AuthenticationStatus,
+    
+    /// Round-trip time measured by the latest answered heartbeat (None until one is answered)
+    pub round_trip_time: Option<Duration>,
+    
+    /// Hardware identifier the client sent on connect, used for hardware bans
+    pub hardware_id: Option<String>,
+    
+    /// Protocol version the client negotiated and the client version it declared
+    pub client_version: ClientVersion,
+    
+    /// Values plugins attached to this connection, by key
+    pub metadata: HashMap<String, serde_json::Value>,
+    
+    /// Where the client's address is located, if the GeoIP policy admitted it
+    pub geo: Option<GeoTag>,
+    
+    /// Traffic counters, shared with the tasks serving the connection
+    pub counters: Arc<ConnectionCounters>,
 }
 
 impl ClientConnection {
@@ -53,6 +77,12 @@ impl ClientConnection {
             remote_addr,
             connected_at: SystemTime::now(),
             auth_status: AuthenticationStatus::default(),
+            round_trip_time: None,
+            hardware_id: None,
+            client_version: ClientVersion::default(),
+            metadata: HashMap::new(),
+            geo: None,
+            counters: Arc::new(ConnectionCounters::new()),
         }
     }
 
@@ -65,4 +95,108 @@ impl ClientConnection {
     pub fn set_auth_status(&mut self, status: AuthenticationStatus) {
         self.auth_status = status;
     }
-}
\ No newline at end of file
+}
+
+/// Traffic counters of a connection.
+/// 
+/// The counters are atomics so the tasks reading from and writing to the
+/// client update them without locking the connection table.
+#[derive(Debug, Default)]
+pub struct ConnectionCounters {
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    messages_sent: AtomicU64,
+    messages_received: AtomicU64,
+    /// Messages ever queued for the connection
+    queued: AtomicU64,
+    /// Messages ever taken off the queue, delivered or not
+    dequeued: AtomicU64,
+    /// Messages never queued because the backlog was too long
+    dropped: AtomicU64,
+    last_activity_ms: AtomicU64,
+    /// Set once the backlog grew so long the client must be disconnected
+    overflowed: AtomicBool,
+    overflow: Notify,
+}
+
+impl ConnectionCounters {
+    /// Creates counters for a connection that is active now
+    pub fn new() -> Self {
+        let counters = Self::default();
+        counters.touch();
+        counters
+    }
+
+    /// Records a message received from the client
+    pub fn record_received(&self, bytes: usize) {
+        self.bytes_received.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.touch();
+    }
+
+    /// Records a frame from the client that isn't a message, such as a pong
+    pub fn touch(&self) {
+        self.last_activity_ms.store(current_timestamp_millis(), Ordering::Relaxed);
+    }
+
+    /// Records a message queued for delivery to the client
+    pub fn record_queued(&self) {
+        self.queued.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a message taken off the queue, and its size if it was delivered
+    pub fn record_dequeued(&self, delivered_bytes: Option<usize>) {
+        self.dequeued.fetch_add(1, Ordering::Relaxed);
+        if let Some(bytes) = delivered_bytes {
+            self.bytes_sent.fetch_add(bytes as u64, Ordering::Relaxed);
+            self.messages_sent.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Gets the number of messages queued that haven't been taken off the queue
+    pub fn queue_depth(&self) -> u64 {
+        self.queued.load(Ordering::Relaxed).saturating_sub(self.dequeued.load(Ordering::Relaxed))
+    }
+
+    /// Records a message dropped instead of queued
+    pub fn record_dropped(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Marks the backlog as too long, waking the task waiting in [`overflowed`](Self::overflowed)
+    ///
+    /// # Returns
+    ///
+    /// `true` the first time the backlog overflows.
+    pub fn mark_overflowed(&self) -> bool {
+        let first = !self.overflowed.swap(true, Ordering::Relaxed);
+        if first {
+            self.overflow.notify_one();
+        }
+        first
+    }
+
+    /// Waits until the backlog overflows and the client must be disconnected
+    pub async fn overflowed(&self) {
+        self.overflow.notified().await
+    }
+
+    /// Empties the queue, for when the connection starts listening for its
+    /// messages and anything queued before can no longer be delivered
+    pub fn clear_queue(&self) {
+        self.dequeued.store(self.queued.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+
+    /// Takes a snapshot of the counters
+    pub fn snapshot(&self) -> ConnectionStats {
+        ConnectionStats {
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            queue_depth: self.queue_depth(),
+            messages_dropped: self.dropped.load(Ordering::Relaxed),
+            last_activity_ms: self.last_activity_ms.load(Ordering::Relaxed),
+        }
+    }
+}
@@ -0,0 +1,362 @@
+//! Server context implementation handed to plugins.
+//!
+//! This module provides the `ServerContext` that plugins receive during every
+//! lifecycle phase. Player messaging is backed by the connection manager, so
+//! plugins can reach clients from `pre_init`/`init` onwards rather than only
+//! through event handlers.
+
+use super::manager::ConnectionManager;
+use horizon_event_system::context::ServerError;
+use horizon_event_system::gorc::GorcInstanceManager;
+use horizon_event_system::{ClientVersion, ConnectionStats, ContentFilter, EventSystem, LogLevel, PlayerId, RegionId, ServerContext, TimerHandle};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Implementation of `ServerContext` for the game server.
+///
+/// Wraps the server's event system, region and connection manager so that
+/// plugins get the same capabilities as the server core itself.
+#[derive(Clone)]
+pub struct GameServerContext {
+    /// Event system shared with the server core
+    event_system: Arc<EventSystem>,
+    /// Region this server instance manages
+    region_id: RegionId,
+    /// Connection manager used to deliver player messages
+    connection_manager: Arc<ConnectionManager>,
+    /// Luminal runtime handle for cross-DLL async execution
+    luminal_handle: luminal::Handle,
+    /// GORC instance manager for object replication
+    gorc_instance_manager: Option<Arc<GorcInstanceManager>>,
+    /// Chat filter shared by every plugin
+    content_filter: Option<Arc<dyn ContentFilter>>,
+}
+
+impl std::fmt::Debug for GameServerContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GameServerContext")
+            .field("region_id", &self.region_id)
+            .finish()
+    }
+}
+
+impl GameServerContext {
+    /// Creates a new server context.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_system` - The event system plugins communicate through
+    /// * `region_id` - The region this server manages
+    /// * `connection_manager` - The connection manager used for player messaging
+    ///
+    /// # Returns
+    ///
+    /// A new `GameServerContext` ready to be handed to plugins.
+    pub fn new(event_system: Arc<EventSystem>, region_id: RegionId, connection_manager: Arc<ConnectionManager>) -> Self {
+        let luminal_rt = luminal::Runtime::new().expect("Failed to create luminal runtime");
+        Self {
+            event_system,
+            region_id,
+            connection_manager,
+            luminal_handle: luminal_rt.handle().clone(),
+            gorc_instance_manager: None,
+            content_filter: None,
+        }
+    }
+
+    /// Attaches the GORC instance manager exposed to plugins.
+    pub fn with_gorc(mut self, gorc_instance_manager: Arc<GorcInstanceManager>) -> Self {
+        self.gorc_instance_manager = Some(gorc_instance_manager);
+        self
+    }
+
+    /// Attaches the chat content filter exposed to plugins.
+    pub fn with_content_filter(mut self, content_filter: Arc<dyn ContentFilter>) -> Self {
+        self.content_filter = Some(content_filter);
+        self
+    }
+}
+
+#[horizon_event_system::async_trait]
+impl ServerContext for GameServerContext {
+    fn events(&self) -> Arc<EventSystem> {
+        self.event_system.clone()
+    }
+
+    fn region_id(&self) -> RegionId {
+        self.region_id
+    }
+
+    fn log(&self, level: LogLevel, message: &str) {
+        // Use async logger to prevent blocking hot threads
+        let async_logger = horizon_event_system::async_logging::global_async_logger();
+        async_logger.log_with_target(level, message, Some("game_server"));
+    }
+
+    async fn send_to_player(&self, player_id: PlayerId, data: &[u8]) -> Result<(), ServerError> {
+        match self.connection_manager.get_connection_id_by_player(player_id).await {
+            Some(connection_id) => {
+                self.connection_manager.send_to_connection(connection_id, data.to_vec()).await;
+                Ok(())
+            }
+            None => Err(ServerError::Network(format!(
+                "Player {} not found or not connected",
+                player_id
+            ))),
+        }
+    }
+
+    async fn broadcast(&self, data: &[u8]) -> Result<(), ServerError> {
+        let client_count = self.connection_manager.broadcast_to_all(data.to_vec()).await;
+        tracing::debug!("📡 Plugin broadcast sent to {} clients", client_count);
+        Ok(())
+    }
+
+    async fn broadcast_to_players(&self, players: &[PlayerId], data: &[u8]) -> Result<usize, ServerError> {
+        Ok(self.connection_manager.broadcast_to_players(players, data.to_vec()).await)
+    }
+
+    async fn broadcast_except(&self, excluded: PlayerId, data: &[u8]) -> Result<usize, ServerError> {
+        Ok(self.connection_manager.broadcast_except(excluded, data.to_vec()).await)
+    }
+
+    fn luminal_handle(&self) -> luminal::Handle {
+        self.luminal_handle.clone()
+    }
+
+    fn gorc_instance_manager(&self) -> Option<Arc<GorcInstanceManager>> {
+        self.gorc_instance_manager.clone()
+    }
+
+    fn content_filter(&self) -> Option<Arc<dyn ContentFilter>> {
+        self.content_filter.clone()
+    }
+
+    fn schedule_interval(&self, name: &str, period: Duration, event_name: &str) -> Result<TimerHandle, ServerError> {
+        self.event_system
+            .schedule_interval(name, period, event_name)
+            .map_err(|e| ServerError::Internal(e.to_string()))
+    }
+
+    async fn connection_stats(&self, player_id: PlayerId) -> Option<ConnectionStats> {
+        self.connection_manager.get_stats_by_player(player_id).await
+    }
+
+    async fn client_version(&self, player_id: PlayerId) -> Option<ClientVersion> {
+        self.connection_manager.get_client_version_by_player(player_id).await
+    }
+
+    async fn connection_metadata(&self, player_id: PlayerId, key: &str) -> Option<serde_json::Value> {
+        self.connection_manager.get_metadata_by_player(player_id, key).await
+    }
+
+    async fn set_connection_metadata(&self, player_id: PlayerId, key: &str, value: Option<serde_json::Value>) -> Result<(), ServerError> {
+        if self.connection_manager.set_metadata_by_player(player_id, key, value).await {
+            Ok(())
+        } else {
+            Err(ServerError::Network(format!("Player {} not found or not connected", player_id)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_player_messaging() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let context = GameServerContext::new(
+            Arc::new(EventSystem::new()),
+            RegionId::new(),
+            connection_manager.clone(),
+        );
+        let mut outgoing = connection_manager.subscribe();
+
+        let player_id = PlayerId::new();
+        assert!(matches!(
+            context.send_to_player(player_id, b"hello").await,
+            Err(ServerError::Network(_))
+        ));
+
+        let connection_id = connection_manager
+            .add_connection("127.0.0.1:9000".parse().unwrap())
+            .await;
+        connection_manager.set_player_id(connection_id, player_id).await;
+
+        context.send_to_player(player_id, b"hello").await.unwrap();
+        assert_eq!(outgoing.recv().await.unwrap(), (connection_id, b"hello".to_vec()));
+
+        context.broadcast(b"everyone").await.unwrap();
+        assert_eq!(outgoing.recv().await.unwrap(), (connection_id, b"everyone".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_filtered_broadcasts() {
+        use horizon_event_system::Vec3;
+
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let gorc_instances = Arc::new(GorcInstanceManager::new());
+        let context = GameServerContext::new(Arc::new(EventSystem::new()), RegionId::new(), connection_manager.clone())
+            .with_gorc(gorc_instances.clone());
+        let mut outgoing = connection_manager.subscribe();
+
+        let mut players = Vec::new();
+        for (port, x) in [(9000, 0.0), (9001, 10.0), (9002, 500.0)] {
+            let connection_id = connection_manager.add_connection(format!("127.0.0.1:{port}").parse().unwrap()).await;
+            let player_id = PlayerId::new();
+            connection_manager.set_player_id(connection_id, player_id).await;
+            gorc_instances.add_player(player_id, Vec3::new(x, 0.0, 0.0)).await;
+            gorc_instances.update_player_position(player_id, Vec3::new(x, 0.0, 0.0)).await;
+            players.push((player_id, connection_id));
+        }
+        let [(caster, caster_connection), (near, near_connection), (far, far_connection)] = players[..] else {
+            unreachable!()
+        };
+        let mut recipients = |count| {
+            let mut recipients: Vec<_> = (0..count).map(|_| outgoing.try_recv().unwrap().0).collect();
+            recipients.sort();
+            assert!(outgoing.try_recv().is_err());
+            recipients
+        };
+
+        assert_eq!(context.broadcast_to_players(&[near, far, PlayerId::new()], b"party").await.unwrap(), 2);
+        assert_eq!(recipients(2), [near_connection, far_connection]);
+        assert_eq!(context.broadcast_except(caster, b"cast").await.unwrap(), 2);
+        assert_eq!(recipients(2), [near_connection, far_connection]);
+        assert_eq!(context.broadcast_to_radius(Vec3::new(0.0, 0.0, 0.0), 50.0, b"boom").await.unwrap(), 2);
+        assert_eq!(recipients(2), [caster_connection, near_connection]);
+
+        let without_gorc = GameServerContext::new(Arc::new(EventSystem::new()), RegionId::new(), connection_manager);
+        assert!(without_gorc.broadcast_to_radius(Vec3::new(0.0, 0.0, 0.0), 50.0, b"boom").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_connection_stats() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let context = GameServerContext::new(Arc::new(EventSystem::new()), RegionId::new(), connection_manager.clone());
+        let player_id = PlayerId::new();
+        assert_eq!(context.connection_stats(player_id).await, None);
+
+        let connection_id = connection_manager.add_connection("127.0.0.1:9000".parse().unwrap()).await;
+        connection_manager.set_player_id(connection_id, player_id).await;
+        let counters = connection_manager.counters(connection_id).await.unwrap();
+        counters.record_received(12);
+        counters.record_received(30);
+
+        // Messages pile up until the client's connection takes them off the queue
+        context.send_to_player(player_id, b"hello").await.unwrap();
+        context.broadcast(b"everyone").await.unwrap();
+        let stats = context.connection_stats(player_id).await.unwrap();
+        assert_eq!((stats.messages_received, stats.bytes_received), (2, 42));
+        assert_eq!((stats.queue_depth, stats.messages_sent), (2, 0));
+        assert!(stats.last_activity_ms > 0);
+
+        counters.record_dequeued(Some(5));
+        counters.record_dequeued(None);
+        let stats = context.connection_stats(player_id).await.unwrap();
+        assert_eq!((stats.queue_depth, stats.messages_sent, stats.bytes_sent), (0, 1, 5));
+    }
+
+    #[tokio::test]
+    async fn test_slow_clients_lose_cosmetic_updates_then_their_connection() {
+        use crate::config::OutboundQueueConfig;
+        use crate::connection::GameServerResponseSender;
+        use horizon_event_system::{ClientResponseSender, GorcObjectId};
+
+        let connection_manager = Arc::new(ConnectionManager::new().with_outbound_queue(OutboundQueueConfig {
+            drop_cosmetic_at: 2,
+            disconnect_at: 4,
+            cosmetic_channels: vec![2, 3],
+        }));
+        let sender = GameServerResponseSender::new(connection_manager.clone());
+        let player_id = PlayerId::new();
+        let connection_id = connection_manager.add_connection("127.0.0.1:9000".parse().unwrap()).await;
+        connection_manager.set_player_id(connection_id, player_id).await;
+        let counters = connection_manager.counters(connection_id).await.unwrap();
+        let object_id = GorcObjectId::new();
+
+        for channel in [2, 0] {
+            sender.send_replication(player_id, object_id, channel, b"update".to_vec()).await.unwrap();
+        }
+        // The backlog is long enough to drop cosmetic updates, but not others
+        sender.send_replication(player_id, object_id, 3, b"cosmetic".to_vec()).await.unwrap();
+        sender.send_replication(player_id, object_id, 1, b"detailed".to_vec()).await.unwrap();
+        connection_manager.send_to_connection(connection_id, b"reply".to_vec()).await;
+        let stats = counters.snapshot();
+        assert_eq!((stats.queue_depth, stats.messages_dropped), (4, 1));
+
+        // Nothing is queued for a client past the disconnect limit, which is told to go
+        assert_eq!(connection_manager.broadcast_to_all(b"everyone".to_vec()).await, 0);
+        assert_eq!(counters.snapshot().messages_dropped, 2);
+        tokio::time::timeout(std::time::Duration::from_secs(1), counters.overflowed()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connection_metadata_is_shared() {
+        use crate::connection::GameServerResponseSender;
+        use horizon_event_system::{AuthenticationStatus, ClientConnectionRef};
+
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let context = GameServerContext::new(Arc::new(EventSystem::new()), RegionId::new(), connection_manager.clone());
+        let player_id = PlayerId::new();
+        assert!(context.set_connection_metadata(player_id, "locale", Some("en-GB".into())).await.is_err());
+
+        let address = "127.0.0.1:9000".parse().unwrap();
+        let connection_id = connection_manager.add_connection(address).await;
+        connection_manager.set_player_id(connection_id, player_id).await;
+        let client = ClientConnectionRef::new(
+            player_id,
+            address,
+            connection_id.to_string(),
+            0,
+            AuthenticationStatus::Unauthenticated,
+            Arc::new(GameServerResponseSender::new(connection_manager.clone())),
+        );
+
+        context.set_connection_metadata(player_id, "locale", Some("en-GB".into())).await.unwrap();
+        client.set_metadata("anti_cheat_score", &0.25f32).await.unwrap();
+        assert_eq!(client.metadata::<String>("locale").await.as_deref(), Some("en-GB"));
+        assert_eq!(client.metadata::<u32>("locale").await, None);
+        assert_eq!(context.connection_metadata(player_id, "anti_cheat_score").await, Some(0.25.into()));
+
+        client.remove_metadata("locale").await.unwrap();
+        assert_eq!(context.connection_metadata(player_id, "locale").await, None);
+
+        // Metadata goes away with the connection
+        connection_manager.remove_connection(connection_id).await;
+        assert_eq!(client.metadata::<f32>("anti_cheat_score").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_interval_timer() {
+        let events = Arc::new(EventSystem::new());
+        let context = GameServerContext::new(events.clone(), RegionId::new(), Arc::new(ConnectionManager::new()));
+
+        let (fired, mut fired_rx) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("summary_due", move |event: horizon_event_system::TimerEvent| {
+                let _ = fired.send(event);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        assert!(context.schedule_interval("summary", Duration::ZERO, "summary_due").is_err());
+        let timer = context
+            .schedule_interval("summary", Duration::from_millis(10), "summary_due")
+            .unwrap();
+        for expected_tick in 1..=3 {
+            let event = fired_rx.recv().await.unwrap();
+            assert_eq!((event.name.as_str(), event.tick, event.period_ms), ("summary", expected_tick, 10));
+        }
+        assert_eq!(events.active_intervals(), vec!["summary".to_string()]);
+
+        assert!(context.cancel_interval("summary"));
+        assert!(timer.is_cancelled());
+        assert!(!context.cancel_interval("summary"));
+        while fired_rx.try_recv().is_ok() {}
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(fired_rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,172 @@
+//! Server-driven heartbeats for client WebSocket connections.
+//!
+//! Every heartbeat interval the server pings each client with a fresh nonce.
+//! The matching pong gives the connection's round-trip time, which plugins
+//! read through `ClientConnectionRef::round_trip_time` for lag compensation.
+//! A client that leaves too many pings in a row unanswered is considered
+//! dead and disconnected, so half-open connections don't hold players forever.
+
+use std::time::{Duration, Instant};
+
+/// Heartbeat state of one connection
+#[derive(Debug)]
+pub struct Heartbeat {
+    max_missed: u32,
+    missed: u32,
+    next_nonce: u64,
+    outstanding: Option<(u64, Instant)>,
+}
+
+impl Heartbeat {
+    /// Creates the heartbeat state of a new connection
+    ///
+    /// # Arguments
+    ///
+    /// * `max_missed` - Pings in a row the client may leave unanswered
+    pub fn new(max_missed: u32) -> Self {
+        Self {
+            max_missed: max_missed.max(1),
+            missed: 0,
+            next_nonce: 0,
+            outstanding: None,
+        }
+    }
+
+    /// Starts the next heartbeat.
+    ///
+    /// A ping still unanswered from the previous heartbeat counts as missed.
+    ///
+    /// # Returns
+    ///
+    /// The payload of the ping to send, or `None` once the client missed
+    /// too many pings and should be disconnected.
+    pub fn next_ping(&mut self) -> Option<Vec<u8>> {
+        if self.outstanding.is_some() {
+            self.missed += 1;
+        }
+        if self.missed >= self.max_missed {
+            return None;
+        }
+
+        let nonce = self.next_nonce;
+        self.next_nonce = self.next_nonce.wrapping_add(1);
+        self.outstanding = Some((nonce, Instant::now()));
+        Some(nonce.to_be_bytes().to_vec())
+    }
+
+    /// Records a pong from the client.
+    ///
+    /// # Returns
+    ///
+    /// The round-trip time if the pong answers the outstanding ping.
+    /// Unsolicited pongs and answers to older pings are ignored.
+    pub fn on_pong(&mut self, payload: &[u8]) -> Option<Duration> {
+        let (nonce, sent_at) = self.outstanding?;
+        if payload != nonce.to_be_bytes() {
+            return None;
+        }
+
+        self.outstanding = None;
+        self.missed = 0;
+        Some(sent_at.elapsed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{HeartbeatConfig, MessageLaneConfig};
+    use crate::connection::ConnectionManager;
+    use crate::messaging::MessageLanes;
+    use crate::server::handlers::handle_connection;
+    use futures::StreamExt;
+    use horizon_event_system::{DisconnectReason, EventSystem, PlayerConnectedEvent, PlayerDisconnectedEvent, PlayerLatencyEvent};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_unanswered_pings_are_counted() {
+        let mut heartbeat = Heartbeat::new(2);
+
+        let ping = heartbeat.next_ping().unwrap();
+        assert_eq!(heartbeat.on_pong(b"unsolicited"), None);
+        assert!(heartbeat.on_pong(&ping).is_some());
+        assert_eq!(heartbeat.on_pong(&ping), None);
+
+        let stale = heartbeat.next_ping().unwrap();
+        // One missed ping is tolerated, the second isn't
+        assert!(heartbeat.next_ping().is_some());
+        assert_eq!(heartbeat.on_pong(&stale), None);
+        assert_eq!(heartbeat.next_ping(), None);
+    }
+
+    #[tokio::test]
+    async fn test_silent_clients_are_disconnected() {
+        let connection_manager = Arc::new(ConnectionManager::new().with_heartbeat(HeartbeatConfig {
+            interval_ms: 50,
+            max_missed: 2,
+        }));
+        let events = Arc::new(EventSystem::new());
+        let (connected_tx, mut connected) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("player_connected", move |event: PlayerConnectedEvent| {
+                let _ = connected_tx.send(event.player_id);
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (disconnected_tx, mut disconnected) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("player_disconnected", move |event: PlayerDisconnectedEvent| {
+                let _ = disconnected_tx.send((event.player_id, event.reason));
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (latency_tx, mut latency) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("player_latency", move |event: PlayerLatencyEvent| {
+                let _ = latency_tx.send(event.player_id);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server_connections = connection_manager.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, addr)) = listener.accept().await {
+                let (connection_manager, events, message_lanes) = (server_connections.clone(), events.clone(), message_lanes.clone());
+                tokio::spawn(handle_connection(stream, addr, connection_manager, events, message_lanes, None));
+            }
+        });
+
+        // Reading lets the client answer pings, so the server learns its round-trip time
+        let (mut responsive, _) = tokio_tungstenite::connect_async(format!("ws://{address}/")).await.unwrap();
+        let responsive_player = connected.recv().await.unwrap();
+        let reader = tokio::spawn(async move { while responsive.next().await.is_some() {} });
+        let mut round_trip_time = None;
+        for _ in 0..50 {
+            round_trip_time = connection_manager.get_round_trip_time_by_player(responsive_player).await;
+            if round_trip_time.is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert!(round_trip_time.is_some());
+        assert_eq!(latency.recv().await, Some(responsive_player));
+
+        // A client that never reads never answers, and is dropped after two missed pings
+        let (_silent, _) = tokio_tungstenite::connect_async(format!("ws://{address}/")).await.unwrap();
+        let silent_player = connected.recv().await.unwrap();
+        let (player_id, reason) = tokio::time::timeout(std::time::Duration::from_secs(5), disconnected.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(player_id, silent_player);
+        assert!(matches!(reason, DisconnectReason::Timeout));
+        assert!(connection_manager.get_connection_id_by_player(responsive_player).await.is_some());
+        reader.abort();
+    }
+}
@@ -3,13 +3,18 @@
 //! This module provides the central management system for all client connections,
 //! handling connection lifecycle, player ID assignment, and message broadcasting.
 
-use super::{client::ClientConnection, ConnectionId};
-use horizon_event_system::{PlayerId, AuthenticationStatus};
-use std::collections::HashMap;
-use std::net::SocketAddr;
+use super::{client::{ClientConnection, ConnectionCounters}, ClientAuthentication, ClientStream, ConnectionId, SessionResumption};
+use crate::config::{HeartbeatConfig, OutboundQueueConfig, ProtocolConfig, ProxyProtocolConfig};
+use crate::identity::IdentityService;
+use crate::security::bans::{BanEntry, BanStore, BanTarget};
+use crate::security::geoip::{GeoPolicy, GeoTag};
+use crate::security::ip_filter::IpFilter;
+use horizon_event_system::{ClientVersion, ConnectionStats, PlayerId, AuthenticationStatus, RegionFederation};
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
-use tracing::info;
+use tracing::{info, trace, warn};
 use futures_util::sink::SinkExt;
 use futures_util::stream::SplitSink;
 use tokio_tungstenite::{WebSocketStream, tungstenite::Message};
@@ -31,13 +36,49 @@ use tokio_tungstenite::{WebSocketStream, tungstenite::Message};
 pub struct ConnectionManager {
     /// Map of connection ID to client connection information
     connections: Arc<RwLock<HashMap<ConnectionId, ClientConnection>>>,
-    ws_senders: Arc<RwLock<HashMap<ConnectionId, Arc<tokio::sync::Mutex<SplitSink<WebSocketStream<tokio::net::TcpStream>, Message>>>>>>,
+    ws_senders: Arc<RwLock<HashMap<ConnectionId, Arc<tokio::sync::Mutex<SplitSink<WebSocketStream<ClientStream>, Message>>>>>>,
     
     /// Atomic counter for generating unique connection IDs
     next_id: Arc<std::sync::atomic::AtomicUsize>,
     
     /// Broadcast sender for outgoing messages to specific connections
     sender: broadcast::Sender<(ConnectionId, Vec<u8>)>,
+    
+    /// Addresses, players and client hardware whose connections are refused
+    bans: Arc<BanStore>,
+    
+    /// Address ranges connections are allowed from or refused
+    ip_filter: Arc<IpFilter>,
+    
+    /// Session tokens that let dropped clients resume their player
+    resumption: Arc<SessionResumption>,
+    
+    /// Federation with neighboring regions, once started
+    region_federation: std::sync::OnceLock<Arc<RegionFederation>>,
+    
+    /// Pings sent to WebSocket clients and the idle timeout they enforce
+    heartbeat: HeartbeatConfig,
+    
+    /// Backlog at which slow clients lose cosmetic updates and then their connection
+    outbound_queue: OutboundQueueConfig,
+    
+    /// Protocol and client versions WebSocket clients must connect with
+    protocol: ProtocolConfig,
+    
+    /// Which peers are proxies that name the client they relay for
+    proxy_protocol: ProxyProtocolConfig,
+    
+    /// Token verification and namespace policy of clients
+    authentication: Arc<ClientAuthentication>,
+    
+    /// Identity providers clients log in through and their linked players
+    identity: Arc<IdentityService>,
+    
+    /// Country rules and region caps connections are admitted by, if a GeoIP database is configured
+    geo_policy: Option<Arc<GeoPolicy>>,
+    
+    /// Whether new players are refused while connected ones finish their sessions
+    draining: std::sync::atomic::AtomicBool,
 }
 
 impl ConnectionManager {
@@ -56,9 +97,202 @@ impl ConnectionManager {
             ws_senders: Arc::new(RwLock::new(HashMap::new())),
             next_id: Arc::new(std::sync::atomic::AtomicUsize::new(1)),
             sender,
+            bans: Arc::new(BanStore::new()),
+            ip_filter: Arc::new(IpFilter::default()),
+            resumption: Arc::new(SessionResumption::new(std::time::Duration::ZERO)),
+            region_federation: std::sync::OnceLock::new(),
+            heartbeat: HeartbeatConfig::default(),
+            outbound_queue: OutboundQueueConfig::default(),
+            protocol: ProtocolConfig::default(),
+            proxy_protocol: ProxyProtocolConfig::default(),
+            authentication: Arc::new(ClientAuthentication::default()),
+            identity: Arc::new(IdentityService::default()),
+            geo_policy: None,
+            draining: std::sync::atomic::AtomicBool::new(false),
         }
     }
 
+    /// Sets how often WebSocket clients are pinged and how many pings they may miss.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `heartbeat` - Heartbeat interval and miss limit (an interval of 0 disables heartbeats)
+    pub fn with_heartbeat(mut self, heartbeat: HeartbeatConfig) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
+    /// Sets the backlog policy for clients that don't keep up with their messages.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `outbound_queue` - Backlogs at which cosmetic updates are dropped and clients disconnected
+    pub fn with_outbound_queue(mut self, outbound_queue: OutboundQueueConfig) -> Self {
+        self.outbound_queue = outbound_queue;
+        self
+    }
+
+    /// Gets the backlog policy for clients that don't keep up with their messages
+    pub fn outbound_queue(&self) -> &OutboundQueueConfig {
+        &self.outbound_queue
+    }
+
+    /// Sets the protocol and client versions WebSocket clients must connect with.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `protocol` - Whether a versioned subprotocol is required and the oldest allowed client
+    pub fn with_protocol(mut self, protocol: ProtocolConfig) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Gets the protocol and client versions WebSocket clients must connect with
+    pub fn protocol(&self) -> &ProtocolConfig {
+        &self.protocol
+    }
+
+    /// Sets how clients authenticate and which namespaces they may use before they do.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `authentication` - Token verification keys and namespace policy
+    pub fn with_authentication(mut self, authentication: Arc<ClientAuthentication>) -> Self {
+        self.authentication = authentication;
+        self
+    }
+
+    /// Gets how clients authenticate and which namespaces they may use before they do
+    pub fn authentication(&self) -> &Arc<ClientAuthentication> {
+        &self.authentication
+    }
+
+    /// Sets the identity providers clients log in through.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `identity` - Providers and the links of their identities to players
+    pub fn with_identity(mut self, identity: Arc<IdentityService>) -> Self {
+        self.identity = identity;
+        self
+    }
+
+    /// Gets the identity providers clients log in through
+    pub fn identity(&self) -> &Arc<IdentityService> {
+        &self.identity
+    }
+
+    /// Sets the ban list connections are checked against.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bans` - Ban list, typically opened from persistent storage
+    pub fn with_ban_store(mut self, bans: Arc<BanStore>) -> Self {
+        self.bans = bans;
+        self
+    }
+
+    /// Gets the ban list connections are checked against
+    pub fn bans(&self) -> &Arc<BanStore> {
+        &self.bans
+    }
+
+    /// Sets the IP allow and deny lists connections are checked against.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `ip_filter` - Filter whose lists can be replaced at runtime
+    pub fn with_ip_filter(mut self, ip_filter: Arc<IpFilter>) -> Self {
+        self.ip_filter = ip_filter;
+        self
+    }
+
+    /// Gets the IP allow and deny lists connections are checked against
+    pub fn ip_filter(&self) -> &Arc<IpFilter> {
+        &self.ip_filter
+    }
+
+    /// Sets the GeoIP policy connections are admitted by.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `geo_policy` - Country rules and per-region caps, backed by a GeoIP database
+    pub fn with_geo_policy(mut self, geo_policy: Arc<GeoPolicy>) -> Self {
+        self.geo_policy = Some(geo_policy);
+        self
+    }
+
+    /// Gets the GeoIP policy connections are admitted by, if one is set
+    pub fn geo_policy(&self) -> Option<&Arc<GeoPolicy>> {
+        self.geo_policy.as_ref()
+    }
+
+    /// Starts or stops draining the server.
+    /// 
+    /// While draining, new players are refused and readiness probes fail,
+    /// while connected players keep playing and may resume dropped sessions.
+    /// 
+    /// # Returns
+    /// 
+    /// Whether the server was draining before.
+    pub fn set_draining(&self, draining: bool) -> bool {
+        self.draining.swap(draining, std::sync::atomic::Ordering::AcqRel)
+    }
+
+    /// Returns true while new players are refused
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(std::sync::atomic::Ordering::Acquire)
+    }
+
+    /// Reads the client address of connections from trusted proxies off their PROXY protocol header.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `proxy_protocol` - Whether headers are expected and which proxies are trusted
+    pub fn with_proxy_protocol(mut self, proxy_protocol: ProxyProtocolConfig) -> Self {
+        self.proxy_protocol = proxy_protocol;
+        self
+    }
+
+    /// Gets which peers are proxies that name the client they relay for
+    pub fn proxy_protocol(&self) -> &ProxyProtocolConfig {
+        &self.proxy_protocol
+    }
+
+    /// Gets the heartbeat settings connections are handled with
+    pub fn heartbeat(&self) -> &HeartbeatConfig {
+        &self.heartbeat
+    }
+
+    /// Lets clients whose connection drops resume their player within a window.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `window` - How long a dropped player is held for its client (zero disables resumption)
+    pub fn with_resume_window(mut self, window: std::time::Duration) -> Self {
+        self.resumption = Arc::new(SessionResumption::new(window));
+        self
+    }
+
+    /// Gets the session tokens and players awaiting a reconnect
+    pub fn session_resumption(&self) -> &Arc<SessionResumption> {
+        &self.resumption
+    }
+
+    /// Sets the federation whose handed-off players connecting clients can claim.
+    /// 
+    /// # Returns
+    /// 
+    /// `false` if a federation was already set.
+    pub fn set_region_federation(&self, federation: Arc<RegionFederation>) -> bool {
+        self.region_federation.set(federation).is_ok()
+    }
+
+    /// Gets the federation with neighboring regions, if it was started
+    pub fn region_federation(&self) -> Option<&Arc<RegionFederation>> {
+        self.region_federation.get()
+    }
+
     /// Adds a new connection and returns its unique ID.
     /// 
     /// Creates a new connection entry with the provided remote address
@@ -83,7 +317,7 @@ impl ConnectionManager {
     }
 
     /// Register the WebSocket sender for a connection
-    pub async fn register_ws_sender(&self, connection_id: ConnectionId, ws_sender: Arc<tokio::sync::Mutex<SplitSink<WebSocketStream<tokio::net::TcpStream>, Message>>>) {
+    pub async fn register_ws_sender(&self, connection_id: ConnectionId, ws_sender: Arc<tokio::sync::Mutex<SplitSink<WebSocketStream<ClientStream>, Message>>>) {
         let mut senders = self.ws_senders.write().await;
         senders.insert(connection_id, ws_sender);
     }
@@ -94,15 +328,55 @@ impl ConnectionManager {
         senders.remove(&connection_id);
     }
 
-    /// Kick (disconnect) a connection by ID, sending a close frame
+    /// Kick (disconnect) a connection by ID, sending a close frame.
+    /// 
+    /// The player's session token is revoked so the client can't resume.
     pub async fn kick_connection(&self, connection_id: ConnectionId, reason: Option<String>) -> Result<(), String> {
+        if let Some(player_id) = self.get_player_id(connection_id).await {
+            self.resumption.revoke(player_id);
+        }
+        self.close_connection(connection_id, reason).await
+    }
+
+    /// Kicks a player, first sending its client a notice saying why.
+    /// 
+    /// The notice is written right before the close frame, so the client
+    /// receives it even though messages queued earlier may be cut off.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - The player to disconnect
+    /// * `notice` - Text message sent to the client before closing
+    /// * `reason` - Reason in the close frame
+    /// 
+    /// # Returns
+    /// 
+    /// `Ok(())` if the player was connected, or an error string otherwise.
+    pub async fn kick_player_with_notice(&self, player_id: PlayerId, notice: String, reason: String) -> Result<(), String> {
+        let connection_id = self
+            .get_connection_id_by_player(player_id)
+            .await
+            .ok_or_else(|| "Player not connected".to_string())?;
+        self.resumption.revoke(player_id);
+        self.close_with_notice(connection_id, Some(notice), Some(reason)).await
+    }
+
+    /// Closes a connection with a close frame and forgets it, leaving its player's session intact
+    pub async fn close_connection(&self, connection_id: ConnectionId, reason: Option<String>) -> Result<(), String> {
+        self.close_with_notice(connection_id, None, reason).await
+    }
+
+    async fn close_with_notice(&self, connection_id: ConnectionId, notice: Option<String>, reason: Option<String>) -> Result<(), String> {
         let senders = self.ws_senders.read().await;
         if let Some(ws_sender) = senders.get(&connection_id) {
             let mut ws_sender = ws_sender.lock().await;
+            if let Some(notice) = notice {
+                let _ = ws_sender.send(Message::Text(notice.into())).await;
+            }
             use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
             let close_msg = Message::Close(Some(tokio_tungstenite::tungstenite::protocol::CloseFrame {
                 code: CloseCode::Normal,
-                reason: reason.unwrap_or_else(|| "Kicked by server".into()).into(),
+                reason: truncate_close_reason(reason.unwrap_or_else(|| "Kicked by server".into())).into(),
             }));
             let _ = ws_sender.send(close_msg).await;
         }
@@ -136,6 +410,9 @@ impl ConnectionManager {
                 "❌ Connection {} from {} disconnected",
                 connection_id, connection.remote_addr
             );
+            if let (Some(geo_policy), Some(geo)) = (&self.geo_policy, &connection.geo) {
+                geo_policy.release(geo);
+            }
         }
     }
 
@@ -170,25 +447,98 @@ impl ConnectionManager {
         connections.get(&connection_id).and_then(|c| c.player_id)
     }
 
+    /// Gets the remote address of a connection.
+    /// 
+    /// # Returns
+    /// 
+    /// The client's address, or `None` if the connection doesn't exist.
+    pub async fn get_remote_addr(&self, connection_id: ConnectionId) -> Option<SocketAddr> {
+        let connections = self.connections.read().await;
+        connections.get(&connection_id).map(|c| c.remote_addr)
+    }
+
     /// Sends a message to a specific connection.
     /// 
     /// Queues a message for delivery to the specified connection through
-    /// the internal broadcast channel.
+    /// the internal broadcast channel, unless the connection's backlog is so
+    /// long that it is being disconnected.
     /// 
     /// # Arguments
     /// 
     /// * `connection_id` - The target connection
     /// * `message` - The message data to send
     pub async fn send_to_connection(&self, connection_id: ConnectionId, message: Vec<u8>) {
+        self.queue_message(connection_id, None, message).await;
+    }
+
+    /// Sends a GORC replication update to a specific connection.
+    /// 
+    /// Updates on cosmetic channels are dropped once the connection's backlog
+    /// reaches the configured limit, so a slow client keeps receiving the
+    /// updates that matter.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `connection_id` - The target connection
+    /// * `channel` - Replication channel of the update
+    /// * `message` - The encoded update
+    /// 
+    /// # Returns
+    /// 
+    /// `true` if the update was queued, `false` if it was dropped.
+    pub async fn send_replication_to_connection(&self, connection_id: ConnectionId, channel: u8, message: Vec<u8>) -> bool {
+        self.queue_message(connection_id, Some(channel), message).await
+    }
+
+    /// Queues a message for a connection if its backlog policy admits it
+    async fn queue_message(&self, connection_id: ConnectionId, channel: Option<u8>, message: Vec<u8>) -> bool {
+        if let Some(connection) = self.connections.read().await.get(&connection_id) {
+            if !self.admit(connection_id, &connection.counters, channel) {
+                return false;
+            }
+        }
         if let Err(e) = self.sender.send((connection_id, message)) {
             tracing::error!("Failed to send message to connection {}: {:?}", connection_id, e);
         }
+        true
+    }
+
+    /// Applies the backlog policy to a message for a connection.
+    /// 
+    /// Messages on cosmetic channels are dropped from `drop_cosmetic_at`
+    /// undelivered messages on, and every message from `disconnect_at` on,
+    /// which also tells the connection's tasks to disconnect the client.
+    /// 
+    /// # Returns
+    /// 
+    /// `true` if the message was counted as queued and should be sent.
+    fn admit(&self, connection_id: ConnectionId, counters: &ConnectionCounters, channel: Option<u8>) -> bool {
+        let policy = &self.outbound_queue;
+        let depth = counters.queue_depth();
+        if policy.disconnect_at > 0 && depth >= policy.disconnect_at {
+            counters.record_dropped();
+            if counters.mark_overflowed() {
+                warn!("🐌 Connection {} has {} undelivered messages, disconnecting it", connection_id, depth);
+            }
+            return false;
+        }
+
+        let cosmetic = channel.is_some_and(|channel| policy.cosmetic_channels.contains(&channel));
+        if cosmetic && policy.drop_cosmetic_at > 0 && depth >= policy.drop_cosmetic_at {
+            counters.record_dropped();
+            trace!("🐌 Dropped channel {:?} update for connection {} with {} undelivered messages", channel, connection_id, depth);
+            return false;
+        }
+
+        counters.record_queued();
+        true
     }
 
     /// Broadcasts a message to all currently connected clients.
     /// 
     /// Sends the same message to every active connection. The message is
-    /// cloned for each connection to ensure proper delivery.
+    /// cloned for each connection to ensure proper delivery. Connections
+    /// being disconnected for their backlog are skipped.
     /// 
     /// # Arguments
     /// 
@@ -198,17 +548,55 @@ impl ConnectionManager {
     /// 
     /// The number of connections that the message was queued for.
     pub async fn broadcast_to_all(&self, message: Vec<u8>) -> usize {
+        self.broadcast_where(message, |_| true).await
+    }
+
+    /// Broadcasts a message to the connections of the given players.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `players` - Players to send to; those not connected are skipped
+    /// * `message` - The message data to send
+    /// 
+    /// # Returns
+    /// 
+    /// The number of connections that the message was queued for.
+    pub async fn broadcast_to_players(&self, players: &[PlayerId], message: Vec<u8>) -> usize {
+        let players: HashSet<PlayerId> = players.iter().copied().collect();
+        self.broadcast_where(message, |player_id| player_id.is_some_and(|player_id| players.contains(&player_id)))
+            .await
+    }
+
+    /// Broadcasts a message to every connection except the excluded player's.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `excluded` - Player that doesn't receive the message
+    /// * `message` - The message data to broadcast
+    /// 
+    /// # Returns
+    /// 
+    /// The number of connections that the message was queued for.
+    pub async fn broadcast_except(&self, excluded: PlayerId, message: Vec<u8>) -> usize {
+        self.broadcast_where(message, |player_id| player_id != Some(excluded)).await
+    }
+
+    /// Queues a message for every connection whose player matches `filter`
+    async fn broadcast_where(&self, message: Vec<u8>, filter: impl Fn(Option<PlayerId>) -> bool) -> usize {
         let connections = self.connections.read().await;
-        let connection_count = connections.len();
-        
-        for &connection_id in connections.keys() {
+        let mut sent = 0;
+        for (&connection_id, connection) in connections.iter() {
+            if !filter(connection.player_id) || !self.admit(connection_id, &connection.counters, None) {
+                continue;
+            }
             if let Err(e) = self.sender.send((connection_id, message.clone())) {
                 tracing::error!("Failed to broadcast message to connection {}: {:?}", connection_id, e);
             }
+            sent += 1;
         }
         
-        tracing::debug!("📡 Broadcasted message to {} connections", connection_count);
-        connection_count
+        tracing::debug!("📡 Broadcasted message to {} connections", sent);
+        sent
     }
 
     /// Creates a new receiver for outgoing messages.
@@ -318,6 +706,155 @@ impl ConnectionManager {
         false
     }
 
+    /// Records the round-trip time measured by a connection's heartbeat.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `connection_id` - The connection that answered the heartbeat
+    /// * `round_trip_time` - Time between sending the ping and receiving its pong
+    pub async fn set_round_trip_time(&self, connection_id: ConnectionId, round_trip_time: std::time::Duration) {
+        let mut connections = self.connections.write().await;
+        if let Some(connection) = connections.get_mut(&connection_id) {
+            connection.round_trip_time = Some(round_trip_time);
+        }
+    }
+
+    /// Gets the latest heartbeat round-trip time of a player's connection.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - The player to query
+    /// 
+    /// # Returns
+    /// 
+    /// The round-trip time, or `None` if the player is not connected or hasn't
+    /// answered a heartbeat yet.
+    pub async fn get_round_trip_time_by_player(&self, player_id: PlayerId) -> Option<std::time::Duration> {
+        let connections = self.connections.read().await;
+        connections
+            .values()
+            .find(|connection| connection.player_id == Some(player_id))
+            .and_then(|connection| connection.round_trip_time)
+    }
+
+    /// Gets the traffic counters of a connection, for the tasks serving it.
+    /// 
+    /// # Returns
+    /// 
+    /// The counters, or `None` if the connection doesn't exist.
+    pub async fn counters(&self, connection_id: ConnectionId) -> Option<Arc<ConnectionCounters>> {
+        let connections = self.connections.read().await;
+        connections.get(&connection_id).map(|connection| connection.counters.clone())
+    }
+
+    /// Gets the traffic statistics of a player's connection.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - The player to query
+    /// 
+    /// # Returns
+    /// 
+    /// The statistics, or `None` if the player is not connected.
+    pub async fn get_stats_by_player(&self, player_id: PlayerId) -> Option<ConnectionStats> {
+        let connections = self.connections.read().await;
+        connections
+            .values()
+            .find(|connection| connection.player_id == Some(player_id))
+            .map(|connection| connection.counters.snapshot())
+    }
+
+    /// Records the versions a connection negotiated during its upgrade
+    pub async fn set_client_version(&self, connection_id: ConnectionId, client_version: ClientVersion) {
+        if let Some(connection) = self.connections.write().await.get_mut(&connection_id) {
+            connection.client_version = client_version;
+        }
+    }
+
+    /// Gets the protocol version a player's client negotiated and the client version it declared
+    /// 
+    /// # Returns
+    /// 
+    /// The versions, or `None` if the player is not connected.
+    pub async fn get_client_version_by_player(&self, player_id: PlayerId) -> Option<ClientVersion> {
+        let connections = self.connections.read().await;
+        connections
+            .values()
+            .find(|connection| connection.player_id == Some(player_id))
+            .map(|connection| connection.client_version.clone())
+    }
+
+    /// Gets a value plugins attached to a player's connection.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - The player to query
+    /// * `key` - Key the value was stored under
+    /// 
+    /// # Returns
+    /// 
+    /// The value, or `None` if the player is not connected or nothing is stored under the key.
+    pub async fn get_metadata_by_player(&self, player_id: PlayerId, key: &str) -> Option<serde_json::Value> {
+        let connections = self.connections.read().await;
+        connections
+            .values()
+            .find(|connection| connection.player_id == Some(player_id))
+            .and_then(|connection| connection.metadata.get(key).cloned())
+    }
+
+    /// Attaches a value to a player's connection, or removes it.
+    /// 
+    /// Values live as long as the connection, so they are gone once the
+    /// player disconnects.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - The player whose connection to update
+    /// * `key` - Key to store the value under
+    /// * `value` - The value, or `None` to remove the key
+    /// 
+    /// # Returns
+    /// 
+    /// `true` if the player was found and updated, `false` otherwise.
+    pub async fn set_metadata_by_player(&self, player_id: PlayerId, key: &str, value: Option<serde_json::Value>) -> bool {
+        let mut connections = self.connections.write().await;
+        let Some(connection) = connections.values_mut().find(|connection| connection.player_id == Some(player_id)) else {
+            return false;
+        };
+        match value {
+            Some(value) => connection.metadata.insert(key.to_string(), value),
+            None => connection.metadata.remove(key),
+        };
+        true
+    }
+
+    /// Records where a connection's client is located, as admitted by the GeoIP policy.
+    /// 
+    /// The location is exposed to plugins as the connection's `geo` metadata,
+    /// and released from the policy's region caps when the connection is removed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `connection_id` - The connection the location belongs to
+    /// * `geo` - Location the policy admitted the client's address with
+    pub async fn set_geo_tag(&self, connection_id: ConnectionId, geo: GeoTag) {
+        let mut connections = self.connections.write().await;
+        match connections.get_mut(&connection_id) {
+            Some(connection) => {
+                if let Ok(value) = serde_json::to_value(&geo) {
+                    connection.metadata.insert("geo".to_string(), value);
+                }
+                connection.geo = Some(geo);
+            }
+            // The connection closed before its location was recorded
+            None => {
+                if let Some(geo_policy) = &self.geo_policy {
+                    geo_policy.release(&geo);
+                }
+            }
+        }
+    }
+
     /// Gets detailed connection information for a player.
     /// 
     /// # Arguments
@@ -336,4 +873,116 @@ impl ConnectionManager {
         }
         None
     }
-}
\ No newline at end of file
+
+    /// Gets every connection that has a player assigned.
+    /// 
+    /// # Returns
+    /// 
+    /// The player ID, connection ID, remote address, connection time and
+    /// authentication status of each connected player.
+    pub async fn connected_players(&self) -> Vec<(PlayerId, ConnectionId, SocketAddr, std::time::SystemTime, AuthenticationStatus)> {
+        let connections = self.connections.read().await;
+        connections
+            .iter()
+            .filter_map(|(conn_id, connection)| {
+                let player_id = connection.player_id?;
+                Some((player_id, *conn_id, connection.remote_addr, connection.connected_at, connection.auth_status()))
+            })
+            .collect()
+    }
+
+    /// Records the hardware identifier a connection's client sent on connect.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `connection_id` - The connection the identifier belongs to
+    /// * `hardware_id` - Identifier of the client machine
+    pub async fn set_hardware_id(&self, connection_id: ConnectionId, hardware_id: String) {
+        let mut connections = self.connections.write().await;
+        if let Some(connection) = connections.get_mut(&connection_id) {
+            connection.hardware_id = Some(hardware_id);
+        }
+    }
+
+    /// Refuses further connections from an IP address.
+    /// 
+    /// Existing connections from the address are not closed.
+    /// 
+    /// # Returns
+    /// 
+    /// `true` if the address wasn't banned yet.
+    pub fn ban_ip(&self, ip: IpAddr) -> bool {
+        self.bans.ban(BanEntry::new(BanTarget::Ip(ip), None, None))
+    }
+
+    /// Bans a connected player along with its address and client hardware.
+    /// 
+    /// The player's connection is not closed.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - The player to ban
+    /// * `reason` - Why the player is banned
+    /// * `duration_secs` - How long the bans last, or `None` for permanent bans
+    /// 
+    /// # Returns
+    /// 
+    /// What was banned, or `None` if the player is not connected.
+    pub async fn ban_player(&self, player_id: PlayerId, reason: Option<String>, duration_secs: Option<u64>) -> Option<Vec<BanTarget>> {
+        let (remote_addr, hardware_id) = {
+            let connections = self.connections.read().await;
+            let connection = connections.values().find(|connection| connection.player_id == Some(player_id))?;
+            (connection.remote_addr, connection.hardware_id.clone())
+        };
+
+        let mut targets = vec![BanTarget::Player(player_id), BanTarget::Ip(remote_addr.ip())];
+        targets.extend(hardware_id.map(BanTarget::Hardware));
+        for target in &targets {
+            self.bans.ban(BanEntry::new(target.clone(), reason.clone(), duration_secs));
+        }
+        Some(targets)
+    }
+
+    /// Accepts connections from a banned IP address again
+    /// 
+    /// # Returns
+    /// 
+    /// `true` if the address was banned.
+    pub fn unban_ip(&self, ip: IpAddr) -> bool {
+        self.bans.unban(&BanTarget::Ip(ip))
+    }
+
+    /// Checks whether connections from an IP address are refused
+    pub fn is_banned(&self, ip: IpAddr) -> bool {
+        self.bans.is_banned(&BanTarget::Ip(ip))
+    }
+
+    /// Gets the banned IP addresses
+    pub fn banned_ips(&self) -> Vec<IpAddr> {
+        let mut banned: Vec<IpAddr> = self
+            .bans
+            .entries()
+            .into_iter()
+            .filter_map(|entry| match entry.target {
+                BanTarget::Ip(ip) => Some(ip),
+                _ => None,
+            })
+            .collect();
+        banned.sort();
+        banned
+    }
+
+}
+
+/// Cuts a close reason down to the 123 bytes a WebSocket close frame can carry
+fn truncate_close_reason(mut reason: String) -> String {
+    const MAX_CLOSE_REASON: usize = 123;
+    if reason.len() > MAX_CLOSE_REASON {
+        let mut end = MAX_CLOSE_REASON;
+        while !reason.is_char_boundary(end) {
+            end -= 1;
+        }
+        reason.truncate(end);
+    }
+    reason
+}
@@ -3,12 +3,25 @@
 //! This module handles the lifecycle of client connections, including
 //! connection tracking, player ID assignment, and message routing.
 
+pub mod auth;
 pub mod client;
+pub mod context;
+pub mod heartbeat;
 pub mod manager;
+pub mod moderation;
 pub mod response;
+pub mod resume;
+pub mod stream;
+pub mod version;
 
+pub use auth::ClientAuthentication;
+pub use context::GameServerContext;
+pub use heartbeat::Heartbeat;
 pub use manager::ConnectionManager;
+pub use moderation::register_moderation_handlers;
 pub use response::GameServerResponseSender;
+pub use resume::{hardware_id_from_query, SessionClaim, SessionResumption};
+pub use stream::ClientStream;
 
 /// Type alias for connection identifiers.
 /// 
@@ -0,0 +1,209 @@
+//! Kicks and bans requested by plugins through core events.
+//!
+//! Moderation and anti-cheat plugins emit `kick_player` or `ban_player` core
+//! events rather than holding on to client connections. Both are listed in
+//! [`PLUGIN_CORE_EVENTS`](horizon_event_system::PLUGIN_CORE_EVENTS), so the
+//! namespace rules let plugins emit them even though `core` is reserved.
+//! The server tells a
+//! WebSocket client why it is being removed with a
+//! `{"event": "kicked" | "banned", "reason": ..., "message": ...}` notice
+//! right before the close frame. QUIC clients get the reason code in the
+//! connection close instead. Each kick and ban carried out is recorded as a
+//! `security_audit` core event.
+
+use super::ConnectionManager;
+use crate::audit::record_audit;
+use crate::server::quic::QuicSessions;
+use horizon_event_system::{AuditAction, BanPlayerEvent, EventError, EventSystem, KickPlayerEvent, PlayerId, SecurityAuditEvent};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Disconnects players named in `kick_player` and `ban_player` core events.
+///
+/// # Arguments
+///
+/// * `events` - Event system plugins emit the requests on
+/// * `connection_manager` - Manager of the players' connections
+/// * `quic` - Clients connected over QUIC
+pub async fn register_moderation_handlers(
+    events: &Arc<EventSystem>,
+    connection_manager: Arc<ConnectionManager>,
+    quic: Arc<QuicSessions>,
+) -> Result<(), EventError> {
+    let (kick_connections, kick_quic, kick_events) = (connection_manager.clone(), quic.clone(), events.clone());
+    events
+        .on_core("kick_player", move |event: KickPlayerEvent| {
+            let (connection_manager, quic, events) = (kick_connections.clone(), kick_quic.clone(), kick_events.clone());
+            tokio::spawn(async move {
+                match remove_player(&connection_manager, &quic, event.player_id, "kicked", &event.reason_code, event.message).await {
+                    Ok(()) => {
+                        info!(
+                            "🥾 Kicked player {} ({}) at the request of {}",
+                            event.player_id, event.reason_code, event.requested_by
+                        );
+                        let audit = SecurityAuditEvent::new(AuditAction::Kick, event.requested_by)
+                            .with_target(event.player_id)
+                            .with_detail(event.reason_code);
+                        record_audit(&events, audit).await;
+                    }
+                    Err(e) => warn!("🥾 Couldn't kick player {} for {}: {}", event.player_id, event.requested_by, e),
+                }
+            });
+            Ok(())
+        })
+        .await?;
+
+    let ban_events = events.clone();
+    events
+        .on_core("ban_player", move |event: BanPlayerEvent| {
+            let (connection_manager, quic, events) = (connection_manager.clone(), quic.clone(), ban_events.clone());
+            tokio::spawn(async move {
+                let reason = Some(event.reason_code.clone());
+                let Some(targets) = connection_manager.ban_player(event.player_id, reason, event.duration_secs).await else {
+                    warn!("🔨 Couldn't ban player {} for {}: player not connected", event.player_id, event.requested_by);
+                    return;
+                };
+
+                info!(
+                    "🔨 Banned player {} ({} targets, {}) at the request of {}",
+                    event.player_id,
+                    targets.len(),
+                    event.reason_code,
+                    event.requested_by
+                );
+                for target in &targets {
+                    let audit = SecurityAuditEvent::new(AuditAction::Ban, &event.requested_by)
+                        .with_target(target)
+                        .with_detail(event.reason_code.clone());
+                    record_audit(&events, audit).await;
+                }
+                if let Err(e) = remove_player(&connection_manager, &quic, event.player_id, "banned", &event.reason_code, event.message).await {
+                    warn!("🔨 Couldn't disconnect banned player {}: {}", event.player_id, e);
+                }
+            });
+            Ok(())
+        })
+        .await
+}
+
+/// Tells a player's client why it is removed and disconnects it
+async fn remove_player(
+    connection_manager: &ConnectionManager,
+    quic: &QuicSessions,
+    player_id: PlayerId,
+    notice_event: &str,
+    reason_code: &str,
+    message: Option<String>,
+) -> Result<(), String> {
+    if quic.close(player_id, reason_code) {
+        return connection_manager.kick_player(player_id, Some(reason_code.to_string())).await;
+    }
+
+    let notice = serde_json::json!({
+        "event": notice_event,
+        "reason": reason_code,
+        "message": message,
+    });
+    connection_manager
+        .kick_player_with_notice(player_id, notice.to_string(), reason_code.to_string())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MessageLaneConfig;
+    use crate::messaging::MessageLanes;
+    use crate::security::bans::BanTarget;
+    use crate::server::handlers::handle_connection;
+    use futures::StreamExt;
+    use horizon_event_system::{current_timestamp, PlayerConnectedEvent};
+    use tokio_tungstenite::tungstenite::Message;
+
+    /// Connects a client, resolving to the last notice it receives and its close reason
+    async fn connect_client(address: std::net::SocketAddr) -> impl std::future::Future<Output = (serde_json::Value, String)> {
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{address}/")).await.unwrap();
+        async move {
+            let mut notice = serde_json::Value::Null;
+            while let Some(Ok(message)) = client.next().await {
+                match message {
+                    Message::Text(text) => notice = serde_json::from_str(&text).unwrap(),
+                    Message::Close(Some(frame)) => return (notice, frame.reason.to_string()),
+                    _ => {}
+                }
+            }
+            panic!("connection ended without a close frame");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_plugins_kick_and_ban_players() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let events = Arc::new(EventSystem::new());
+        register_moderation_handlers(&events, connection_manager.clone(), Arc::new(QuicSessions::new()))
+            .await
+            .unwrap();
+        let (connected_tx, mut connected) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("player_connected", move |event: PlayerConnectedEvent| {
+                let _ = connected_tx.send(event.player_id);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (server_connections, server_events) = (connection_manager.clone(), events.clone());
+        tokio::spawn(async move {
+            while let Ok((stream, addr)) = listener.accept().await {
+                let (connection_manager, events, message_lanes) = (server_connections.clone(), server_events.clone(), message_lanes.clone());
+                tokio::spawn(handle_connection(stream, addr, connection_manager, events, message_lanes, None));
+            }
+        });
+
+        let kicked_client = connect_client(address).await;
+        let kicked = connected.recv().await.unwrap();
+        events
+            .emit_core(
+                "kick_player",
+                &KickPlayerEvent {
+                    player_id: kicked,
+                    reason_code: "afk".to_string(),
+                    message: Some("Idle for too long".to_string()),
+                    requested_by: "afk_monitor".to_string(),
+                    timestamp: current_timestamp(),
+                },
+            )
+            .await
+            .unwrap();
+        let (notice, reason) = kicked_client.await;
+        assert_eq!(notice, serde_json::json!({ "event": "kicked", "reason": "afk", "message": "Idle for too long" }));
+        assert_eq!(reason, "afk");
+        assert!(!connection_manager.is_banned("127.0.0.1".parse().unwrap()));
+
+        let banned_client = connect_client(address).await;
+        let banned = connected.recv().await.unwrap();
+        events
+            .emit_core(
+                "ban_player",
+                &BanPlayerEvent {
+                    player_id: banned,
+                    reason_code: "speed_hack".to_string(),
+                    message: None,
+                    requested_by: "anti_cheat".to_string(),
+                    duration_secs: Some(3600),
+                    timestamp: current_timestamp(),
+                },
+            )
+            .await
+            .unwrap();
+        let (notice, reason) = banned_client.await;
+        assert_eq!(notice["event"], "banned");
+        assert_eq!(reason, "speed_hack");
+        assert!(connection_manager.is_banned("127.0.0.1".parse().unwrap()));
+        let ban = connection_manager.bans().get(&BanTarget::Player(banned)).unwrap();
+        assert_eq!(ban.expires_at, Some(ban.banned_at + 3600));
+    }
+}
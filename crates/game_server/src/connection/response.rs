@@ -5,7 +5,9 @@
 //! responses back to clients.
 
 use super::manager::ConnectionManager;
-use horizon_event_system::{ClientResponseSender, PlayerId, AuthenticationStatus};
+use crate::server::quic::QuicSessions;
+use crate::udp::UdpTransport;
+use horizon_event_system::{ClientResponseSender, ClientVersion, ConnectionStats, GorcObjectId, PlayerId, AuthenticationStatus};
 use std::sync::Arc;
 
 /// Implementation of `ClientResponseSender` for the game server.
@@ -23,6 +25,12 @@ use std::sync::Arc;
 pub struct GameServerResponseSender {
     /// Reference to the connection manager for looking up and messaging connections
     connection_manager: Arc<ConnectionManager>,
+
+    /// UDP transport for latency-critical replication channels, if enabled
+    udp: Option<Arc<UdpTransport>>,
+
+    /// Players connected over QUIC, who receive those channels as datagrams
+    quic: Option<Arc<QuicSessions>>,
 }
 
 impl GameServerResponseSender {
@@ -36,7 +44,19 @@ impl GameServerResponseSender {
     /// 
     /// A new `GameServerResponseSender` instance ready to handle responses.
     pub fn new(connection_manager: Arc<ConnectionManager>) -> Self {
-        Self { connection_manager }
+        Self { connection_manager, udp: None, quic: None }
+    }
+
+    /// Sends latency-critical replication channels over UDP when the player has bound a session.
+    pub fn with_udp(mut self, udp: Arc<UdpTransport>) -> Self {
+        self.udp = Some(udp);
+        self
+    }
+
+    /// Sends latency-critical replication channels as datagrams to players connected over QUIC.
+    pub fn with_quic(mut self, quic: Arc<QuicSessions>) -> Self {
+        self.quic = Some(quic);
+        self
     }
 }
 
@@ -45,6 +65,9 @@ impl ClientResponseSender for GameServerResponseSender {
     fn kick(&self, player_id: PlayerId, reason: Option<String>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + '_>> {
         let connection_manager = self.connection_manager.clone();
         Box::pin(async move {
+            if let Some(quic) = &self.quic {
+                quic.close(player_id, reason.as_deref().unwrap_or("Kicked by server"));
+            }
             connection_manager.kick_player(player_id, reason).await
         })
     }
@@ -78,6 +101,38 @@ impl ClientResponseSender for GameServerResponseSender {
         })
     }
 
+    /// Sends a GORC replication message to a client.
+    /// 
+    /// Channel 0 updates go over the player's UDP session when one is bound,
+    /// or as a QUIC datagram when the player connected over QUIC, as long as
+    /// the update fits a datagram. Everything else is sent over the player's
+    /// connection, where updates on cosmetic channels are dropped while the
+    /// client has a long backlog of undelivered messages.
+    fn send_replication(&self, player_id: PlayerId, object_id: GorcObjectId, channel: u8, data: Vec<u8>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + '_>> {
+        Box::pin(async move {
+            if UdpTransport::carries_channel(channel) {
+                if let Some(udp) = &self.udp {
+                    if udp.send_update(player_id, object_id, &data).await {
+                        return Ok(());
+                    }
+                }
+                if let Some(quic) = &self.quic {
+                    if quic.send_update(player_id, object_id, &data) {
+                        return Ok(());
+                    }
+                }
+            }
+            let connection_id = self
+                .connection_manager
+                .get_connection_id_by_player(player_id)
+                .await
+                .ok_or_else(|| format!("Player {} not found or not connected", player_id))?;
+            // Dropped cosmetic updates are superseded by later ones, so they aren't an error
+            self.connection_manager.send_replication_to_connection(connection_id, channel, data).await;
+            Ok(())
+        })
+    }
+
     /// Checks if a player connection is currently active.
     /// 
     /// This method verifies whether a player is currently connected
@@ -148,6 +203,51 @@ impl ClientResponseSender for GameServerResponseSender {
         })
     }
 
+    /// Gets the round-trip time measured by a player's latest heartbeat.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - The ID of the player to query
+    /// 
+    /// # Returns
+    /// 
+    /// A future that resolves to the round-trip time, or `None` if the player
+    /// is not connected or hasn't answered a heartbeat yet.
+    fn get_round_trip_time(&self, player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<std::time::Duration>> + Send + '_>> {
+        let connection_manager = self.connection_manager.clone();
+        Box::pin(async move { connection_manager.get_round_trip_time_by_player(player_id).await })
+    }
+
+    /// Gets the traffic statistics of a player's connection.
+    fn get_connection_stats(&self, player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<ConnectionStats>> + Send + '_>> {
+        let connection_manager = self.connection_manager.clone();
+        Box::pin(async move { connection_manager.get_stats_by_player(player_id).await })
+    }
+
+    /// Gets the protocol version a player's client negotiated and the client version it declared.
+    fn get_client_version(&self, player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<ClientVersion>> + Send + '_>> {
+        let connection_manager = self.connection_manager.clone();
+        Box::pin(async move { connection_manager.get_client_version_by_player(player_id).await })
+    }
+
+    /// Gets a value plugins attached to a player's connection.
+    fn get_connection_metadata(&self, player_id: PlayerId, key: String) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<serde_json::Value>> + Send + '_>> {
+        let connection_manager = self.connection_manager.clone();
+        Box::pin(async move { connection_manager.get_metadata_by_player(player_id, &key).await })
+    }
+
+    /// Attaches a value to a player's connection, or removes it when `value` is `None`.
+    fn set_connection_metadata(&self, player_id: PlayerId, key: String, value: Option<serde_json::Value>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + '_>> {
+        let connection_manager = self.connection_manager.clone();
+        Box::pin(async move {
+            if connection_manager.set_metadata_by_player(player_id, &key, value).await {
+                Ok(())
+            } else {
+                Err(format!("Player {} not found or not connected", player_id))
+            }
+        })
+    }
+
     /// Broadcasts data to all currently connected clients.
     /// 
     /// This method sends the provided data to every client currently connected
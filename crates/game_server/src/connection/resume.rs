@@ -0,0 +1,384 @@
+//! Session resumption for clients whose connection drops.
+//!
+//! With a resume window configured, every WebSocket client is sent a signed
+//! session token right after connecting:
+//!
+//! ```json
+//! { "event": "session", "player_id": "<uuid>", "token": "<token>", "resume_window_secs": 30, "resumed": false }
+//! ```
+//!
+//! When the connection drops, its player isn't announced as disconnected
+//! straight away. A client that reconnects within the window with
+//! `?resume=<token>` in its WebSocket URL is bound to its existing player
+//! ID, so its GORC subscriptions and player object are still in place, and
+//! plugins see `player_reconnected` instead of `player_connected`. Only when
+//! the window passes without a reconnect is `player_disconnected` emitted.
+//!
+//! Tokens are single use: a resumed session is sent a fresh token, and
+//! kicking a player revokes theirs. They are signed with a key generated at
+//! startup, so they don't outlive the server process.
+//!
+//! A client moved here from a neighboring region presents its handoff
+//! ticket the same way, with `?handoff=<ticket>`; see [`SessionClaim`].
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use horizon_event_system::PlayerId;
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Bytes of a token before it is encoded: player ID, nonce and signature
+const TOKEN_LEN: usize = 16 + 8 + 32;
+
+/// A player whose client holds a session token
+#[derive(Debug)]
+struct ResumableSession {
+    /// Nonce of the token that is currently valid, `None` once it was used
+    nonce: Option<u64>,
+    /// Park number while the player's connection is down
+    parked: Option<u64>,
+}
+
+/// Issues and checks session tokens and tracks players awaiting a reconnect
+pub struct SessionResumption {
+    window: Duration,
+    key: hmac::Key,
+    rng: SystemRandom,
+    sessions: Mutex<HashMap<PlayerId, ResumableSession>>,
+    next_park: std::sync::atomic::AtomicU64,
+}
+
+impl std::fmt::Debug for SessionResumption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionResumption")
+            .field("window", &self.window)
+            .field("sessions", &self.lock_sessions().len())
+            .finish()
+    }
+}
+
+impl SessionResumption {
+    /// Creates session resumption with a fresh signing key
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - How long a dropped player can be resumed (zero disables resumption)
+    pub fn new(window: Duration) -> Self {
+        let rng = SystemRandom::new();
+        let key = hmac::Key::generate(hmac::HMAC_SHA256, &rng).expect("system randomness is available");
+        Self {
+            window,
+            key,
+            rng,
+            sessions: Mutex::new(HashMap::new()),
+            next_park: std::sync::atomic::AtomicU64::new(1),
+        }
+    }
+
+    /// Checks whether clients can resume their sessions
+    pub fn is_enabled(&self) -> bool {
+        !self.window.is_zero()
+    }
+
+    /// Gets how long a dropped player can be resumed
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Issues a session token for a player, invalidating any earlier one.
+    ///
+    /// # Returns
+    ///
+    /// The token, or `None` if resumption is disabled.
+    pub fn issue(&self, player_id: PlayerId) -> Option<String> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let mut nonce = [0u8; 8];
+        self.rng.fill(&mut nonce).ok()?;
+        let mut token = Vec::with_capacity(TOKEN_LEN);
+        token.extend_from_slice(player_id.0.as_bytes());
+        token.extend_from_slice(&nonce);
+        let tag = hmac::sign(&self.key, &token);
+        token.extend_from_slice(tag.as_ref());
+
+        let session = ResumableSession {
+            nonce: Some(u64::from_be_bytes(nonce)),
+            parked: None,
+        };
+        self.lock_sessions().insert(player_id, session);
+        Some(URL_SAFE_NO_PAD.encode(token))
+    }
+
+    /// Redeems a session token.
+    ///
+    /// The token is used up, and a parked player is no longer waiting to
+    /// expire.
+    ///
+    /// # Returns
+    ///
+    /// The player to bind the connection to, or `None` if the token is
+    /// malformed, forged, already used or its session expired.
+    pub fn resume(&self, token: &str) -> Option<PlayerId> {
+        let token = URL_SAFE_NO_PAD.decode(token).ok()?;
+        if token.len() != TOKEN_LEN {
+            return None;
+        }
+        let (payload, tag) = token.split_at(16 + 8);
+        hmac::verify(&self.key, payload, tag).ok()?;
+
+        let player_id = PlayerId(Uuid::from_slice(&payload[..16]).ok()?);
+        let nonce = u64::from_be_bytes(payload[16..].try_into().ok()?);
+        let mut sessions = self.lock_sessions();
+        let session = sessions.get_mut(&player_id)?;
+        if session.nonce != Some(nonce) {
+            return None;
+        }
+        session.nonce = None;
+        session.parked = None;
+        Some(player_id)
+    }
+
+    /// Holds a player whose connection dropped until the window passes.
+    ///
+    /// # Returns
+    ///
+    /// The park number to [`expire`](Self::expire) the player with, or
+    /// `None` if the player holds no token and is gone right away.
+    pub fn park(&self, player_id: PlayerId) -> Option<u64> {
+        let mut sessions = self.lock_sessions();
+        let session = sessions.get_mut(&player_id)?;
+        let park = self.next_park.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        session.parked = Some(park);
+        Some(park)
+    }
+
+    /// Forgets a parked player once the window passed.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player was still parked under this park number, i.e.
+    /// didn't resume in the meantime.
+    pub fn expire(&self, player_id: PlayerId, park: u64) -> bool {
+        let mut sessions = self.lock_sessions();
+        match sessions.get(&player_id) {
+            Some(session) if session.parked == Some(park) => {
+                sessions.remove(&player_id);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Invalidates a player's session token
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player held one.
+    pub fn revoke(&self, player_id: PlayerId) -> bool {
+        self.lock_sessions().remove(&player_id).is_some()
+    }
+
+    /// Checks whether a player's connection is down and awaiting a reconnect
+    pub fn is_parked(&self, player_id: PlayerId) -> bool {
+        self.lock_sessions()
+            .get(&player_id)
+            .is_some_and(|session| session.parked.is_some())
+    }
+
+    fn lock_sessions(&self) -> std::sync::MutexGuard<'_, HashMap<PlayerId, ResumableSession>> {
+        self.sessions.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// What a connecting client presented to continue an existing player
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionClaim {
+    /// Session token issued by this server (`?resume=<token>`)
+    Resume(String),
+    /// Ticket of a player handed off by a neighboring region (`?handoff=<ticket>`)
+    Handoff(String),
+}
+
+impl SessionClaim {
+    /// Extracts the claim from a WebSocket request's query string
+    pub fn from_query(query: &str) -> Option<Self> {
+        resume_token_from_query(query)
+            .map(Self::Resume)
+            .or_else(|| query_param(query, "handoff").map(Self::Handoff))
+    }
+}
+
+/// Extracts the session token from a WebSocket request's query string
+pub fn resume_token_from_query(query: &str) -> Option<String> {
+    query_param(query, "resume")
+}
+
+/// Extracts the hardware identifier a client sends as `?hwid=<id>`
+pub fn hardware_id_from_query(query: &str) -> Option<String> {
+    query_param(query, "hwid")
+}
+
+/// Gets a non-empty query string parameter
+pub(super) fn query_param(query: &str, name: &str) -> Option<String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MessageLaneConfig;
+    use crate::connection::ConnectionManager;
+    use crate::messaging::MessageLanes;
+    use crate::server::handlers::handle_connection;
+    use futures::{SinkExt, StreamExt};
+    use horizon_event_system::{EventSystem, PlayerConnectedEvent, PlayerDisconnectedEvent, PlayerReconnectedEvent};
+    use std::sync::Arc;
+    use tokio::sync::mpsc::UnboundedReceiver;
+    use tokio_tungstenite::tungstenite::Message;
+
+    #[test]
+    fn test_tokens_resume_once() {
+        let resumption = SessionResumption::new(Duration::from_secs(30));
+        let player_id = PlayerId::new();
+        let token = resumption.issue(player_id).unwrap();
+
+        let park = resumption.park(player_id).unwrap();
+        assert!(resumption.is_parked(player_id));
+        assert_eq!(resumption.resume(&token), Some(player_id));
+        assert!(!resumption.is_parked(player_id));
+        assert!(!resumption.expire(player_id, park));
+        assert_eq!(resumption.resume(&token), None);
+
+        // A fresh token replaces the used one
+        let renewed = resumption.issue(player_id).unwrap();
+        assert_ne!(renewed, token);
+        assert_eq!(resumption.resume(&renewed), Some(player_id));
+    }
+
+    #[test]
+    fn test_forged_and_expired_tokens_are_rejected() {
+        let resumption = SessionResumption::new(Duration::from_secs(30));
+        let player_id = PlayerId::new();
+        let token = resumption.issue(player_id).unwrap();
+
+        let mut forged = URL_SAFE_NO_PAD.decode(&token).unwrap();
+        forged[..16].copy_from_slice(PlayerId::new().0.as_bytes());
+        assert_eq!(resumption.resume(&URL_SAFE_NO_PAD.encode(forged)), None);
+        assert_eq!(resumption.resume("not a token"), None);
+        let other_server = SessionResumption::new(Duration::from_secs(30));
+        assert_eq!(other_server.resume(&token), None);
+
+        let park = resumption.park(player_id).unwrap();
+        assert!(resumption.expire(player_id, park));
+        assert_eq!(resumption.resume(&token), None);
+        assert_eq!(resumption.park(player_id), None);
+
+        let token = resumption.issue(player_id).unwrap();
+        assert!(resumption.revoke(player_id));
+        assert_eq!(resumption.resume(&token), None);
+
+        let disabled = SessionResumption::new(Duration::ZERO);
+        assert_eq!(disabled.issue(player_id), None);
+        assert_eq!(resume_token_from_query("a=1&resume=abc"), Some("abc".to_string()));
+        assert_eq!(resume_token_from_query("resume="), None);
+        assert_eq!(SessionClaim::from_query("handoff=xyz"), Some(SessionClaim::Handoff("xyz".to_string())));
+    }
+
+    /// Starts a WebSocket server and returns its address with the core events it emitted
+    async fn start_server(window: Duration) -> (std::net::SocketAddr, UnboundedReceiver<(&'static str, PlayerId)>) {
+        let connection_manager = Arc::new(ConnectionManager::new().with_resume_window(window));
+        let events = Arc::new(EventSystem::new());
+        let (sender, received) = tokio::sync::mpsc::unbounded_channel();
+        let connected = sender.clone();
+        events
+            .on_core("player_connected", move |event: PlayerConnectedEvent| {
+                let _ = connected.send(("connected", event.player_id));
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let reconnected = sender.clone();
+        events
+            .on_core("player_reconnected", move |event: PlayerReconnectedEvent| {
+                let _ = reconnected.send(("reconnected", event.player_id));
+                Ok(())
+            })
+            .await
+            .unwrap();
+        events
+            .on_core("player_disconnected", move |event: PlayerDisconnectedEvent| {
+                let _ = sender.send(("disconnected", event.player_id));
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, addr)) = listener.accept().await {
+                let (connection_manager, events, message_lanes) = (connection_manager.clone(), events.clone(), message_lanes.clone());
+                tokio::spawn(handle_connection(stream, addr, connection_manager, events, message_lanes, None));
+            }
+        });
+        (address, received)
+    }
+
+    /// Connects a client and returns it with the session message it was sent
+    async fn connect(
+        url: String,
+    ) -> (
+        tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+        serde_json::Value,
+    ) {
+        let (mut websocket, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+        let session = loop {
+            if let Message::Text(text) = websocket.next().await.unwrap().unwrap() {
+                break serde_json::from_str::<serde_json::Value>(&text).unwrap();
+            }
+        };
+        assert_eq!(session["event"], "session");
+        (websocket, session)
+    }
+
+    #[tokio::test]
+    async fn test_dropped_client_resumes_its_player() {
+        let (address, mut events) = start_server(Duration::from_millis(300)).await;
+        let (mut first, session) = connect(format!("ws://{address}/")).await;
+        assert_eq!(session["resumed"], false);
+        let player_id: PlayerId = session["player_id"].as_str().unwrap().parse().unwrap();
+        let token = session["token"].as_str().unwrap().to_string();
+        assert_eq!(events.recv().await.unwrap(), ("connected", player_id));
+
+        first.close(None).await.unwrap();
+        drop(first);
+        let (mut second, session) = connect(format!("ws://{address}/?resume={token}")).await;
+        assert_eq!(session["resumed"], true);
+        assert_eq!(session["player_id"], player_id.to_string());
+        assert_eq!(events.recv().await.unwrap(), ("reconnected", player_id));
+
+        // The token was used up, so presenting it again starts a new player
+        let (_third, session) = connect(format!("ws://{address}/?resume={token}")).await;
+        assert_eq!(session["resumed"], false);
+        assert_ne!(session["player_id"], player_id.to_string());
+        assert!(matches!(events.recv().await.unwrap(), ("connected", other) if other != player_id));
+
+        // Without a reconnect the player leaves once the window passes
+        second.send(Message::Close(None)).await.unwrap();
+        let event = tokio::time::timeout(Duration::from_secs(5), events.recv()).await.unwrap().unwrap();
+        assert_eq!(event, ("disconnected", player_id));
+    }
+}
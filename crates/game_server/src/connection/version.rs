@@ -0,0 +1,254 @@
+//! Negotiation of the wire protocol and client versions of WebSocket clients.
+//!
+//! Clients offer the protocol version they speak as a `horizon.v<N>`
+//! subprotocol, optionally followed by the encoding of their messages, such
+//! as `horizon.v1.msgpack`. Clients offering only the unversioned encoding
+//! subprotocols, such as `horizon.msgpack`, or nothing at all speak version 1
+//! unless the server requires a versioned subprotocol.
+//!
+//! Clients declare their game client version as `?client_version=1.4.2` or
+//! in an `X-Horizon-Client-Version` header. Plugins read both versions back
+//! with [`ClientConnectionRef::client_version`](horizon_event_system::ClientConnectionRef::client_version)
+//! to gate features on them.
+//!
+//! Incompatible clients complete the WebSocket upgrade and are then closed
+//! with [`CLOSE_UNSUPPORTED_PROTOCOL`] or [`CLOSE_OUTDATED_CLIENT`], after a
+//! message saying what the server expects.
+
+use super::resume::query_param;
+use crate::config::ProtocolConfig;
+use crate::messaging::ClientEncoding;
+use horizon_event_system::ClientVersion;
+use thiserror::Error;
+use tokio_tungstenite::tungstenite::handshake::server::Request;
+
+/// Version of the wire protocol the server speaks
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Close code for clients that don't speak the server's protocol version
+pub const CLOSE_UNSUPPORTED_PROTOCOL: u16 = 4001;
+
+/// Close code for clients older than the oldest allowed client version
+pub const CLOSE_OUTDATED_CLIENT: u16 = 4002;
+
+/// Header clients can declare their version in instead of the query string
+pub const CLIENT_VERSION_HEADER: &str = "x-horizon-client-version";
+
+/// Reasons a client is incompatible with the server
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum VersionError {
+    /// The client only offered protocol versions the server doesn't speak
+    #[error("Unsupported protocol {0}, the server speaks horizon.v{PROTOCOL_VERSION}")]
+    UnsupportedProtocol(String),
+    /// The client offered no protocol version but the server requires one
+    #[error("No protocol offered, the server requires horizon.v{PROTOCOL_VERSION}")]
+    MissingProtocol,
+    /// The client is older than the oldest allowed version
+    #[error("Client version {0} is outdated, {1} or newer is required")]
+    OutdatedClient(String, String),
+    /// The client declared no version but a minimum is configured
+    #[error("No client version declared, {0} or newer is required")]
+    MissingClientVersion(String),
+}
+
+impl VersionError {
+    /// Gets the WebSocket close code the client is disconnected with
+    pub fn close_code(&self) -> u16 {
+        match self {
+            VersionError::UnsupportedProtocol(_) | VersionError::MissingProtocol => CLOSE_UNSUPPORTED_PROTOCOL,
+            VersionError::OutdatedClient(..) | VersionError::MissingClientVersion(_) => CLOSE_OUTDATED_CLIENT,
+        }
+    }
+}
+
+/// What a client and the server agreed on during the upgrade
+#[derive(Debug, Clone, PartialEq)]
+pub struct Negotiated {
+    /// Protocol version the client speaks and the client version it declared
+    pub version: ClientVersion,
+    /// Encoding of the client's messages
+    pub encoding: ClientEncoding,
+    /// Subprotocol echoed to the client, if it offered one the server accepted
+    pub subprotocol: Option<String>,
+}
+
+/// Gets the client version declared in an upgrade request's query string or header
+pub fn client_version_from_request(request: &Request) -> Option<String> {
+    request
+        .uri()
+        .query()
+        .and_then(|query| query_param(query, "client_version"))
+        .or_else(|| {
+            request
+                .headers()
+                .get(CLIENT_VERSION_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        })
+}
+
+/// Negotiates the protocol version and encoding of a client and checks its version.
+///
+/// # Arguments
+///
+/// * `offered` - The client's `Sec-WebSocket-Protocol` header, if it sent one
+/// * `client_version` - The client version it declared, if any
+/// * `config` - Versions the server requires
+///
+/// # Returns
+///
+/// What was negotiated, or a `VersionError` saying why the client is incompatible.
+pub fn negotiate(offered: Option<&str>, client_version: Option<String>, config: &ProtocolConfig) -> Result<Negotiated, VersionError> {
+    let protocols: Vec<&str> = offered.map(|offered| offered.split(',').map(str::trim).collect()).unwrap_or_default();
+
+    let versioned = protocols.iter().find_map(|&protocol| match versioned_protocol(protocol) {
+        Some((PROTOCOL_VERSION, Some(encoding))) => Some((protocol, encoding)),
+        _ => None,
+    });
+    let (encoding, subprotocol) = match versioned {
+        Some((protocol, encoding)) => (encoding, Some(protocol.to_string())),
+        None => {
+            let unsupported: Vec<&str> = protocols.iter().copied().filter(|&protocol| versioned_protocol(protocol).is_some()).collect();
+            if !unsupported.is_empty() {
+                return Err(VersionError::UnsupportedProtocol(unsupported.join(", ")));
+            }
+            if config.require_subprotocol {
+                return Err(VersionError::MissingProtocol);
+            }
+            match offered.and_then(ClientEncoding::negotiate) {
+                Some(encoding) => (encoding, Some(encoding.subprotocol().to_string())),
+                None => (ClientEncoding::Json, None),
+            }
+        }
+    };
+
+    let version = ClientVersion { protocol: PROTOCOL_VERSION, client: client_version };
+    if let Some(minimum) = &config.min_client_version {
+        match &version.client {
+            None => return Err(VersionError::MissingClientVersion(minimum.clone())),
+            Some(client) if !version.is_at_least(minimum) => {
+                return Err(VersionError::OutdatedClient(client.clone(), minimum.clone()))
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(Negotiated { version, encoding, subprotocol })
+}
+
+/// Parses a `horizon.v<N>[.<encoding>]` subprotocol.
+///
+/// # Returns
+///
+/// The protocol version and encoding, with no encoding if the server doesn't
+/// support it, or `None` if the subprotocol isn't versioned.
+fn versioned_protocol(protocol: &str) -> Option<(u32, Option<ClientEncoding>)> {
+    let rest = protocol.strip_prefix("horizon.v")?;
+    let (version, encoding) = match rest.split_once('.') {
+        Some((version, encoding)) => (version, Some(encoding)),
+        None => (rest, None),
+    };
+    let version = version.parse().ok()?;
+    let encoding = match encoding {
+        None => Some(ClientEncoding::Json),
+        Some(encoding) => ClientEncoding::ALL
+            .into_iter()
+            .find(|supported| supported.subprotocol().strip_prefix("horizon.") == Some(encoding)),
+    };
+    Some((version, encoding))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protocol_negotiation() {
+        let config = ProtocolConfig::default();
+        let negotiated = negotiate(Some("horizon.v2, horizon.v1.msgpack"), None, &config).unwrap();
+        assert_eq!((negotiated.encoding, negotiated.subprotocol.as_deref()), (ClientEncoding::MessagePack, Some("horizon.v1.msgpack")));
+        assert_eq!(negotiated.version, ClientVersion { protocol: 1, client: None });
+
+        // Unversioned clients speak version 1
+        let legacy = negotiate(Some("horizon.cbor"), None, &config).unwrap();
+        assert_eq!((legacy.encoding, legacy.subprotocol.as_deref()), (ClientEncoding::Cbor, Some("horizon.cbor")));
+        assert_eq!(negotiate(None, None, &config).unwrap().subprotocol, None);
+
+        let error = negotiate(Some("horizon.v2"), None, &config).unwrap_err();
+        assert_eq!((error.close_code(), error.to_string().contains("horizon.v2")), (CLOSE_UNSUPPORTED_PROTOCOL, true));
+        let strict = ProtocolConfig { require_subprotocol: true, ..ProtocolConfig::default() };
+        assert_eq!(negotiate(Some("horizon.json"), None, &strict), Err(VersionError::MissingProtocol));
+    }
+
+    #[test]
+    fn test_outdated_clients_are_refused() {
+        let config = ProtocolConfig { min_client_version: Some("1.4".to_string()), ..ProtocolConfig::default() };
+        let current = negotiate(None, Some("1.10.0-beta".to_string()), &config).unwrap();
+        assert!(current.version.is_at_least("1.9.2"));
+        assert!(!current.version.is_at_least("2.0"));
+
+        let error = negotiate(None, Some("1.3.9".to_string()), &config).unwrap_err();
+        assert_eq!(error.close_code(), CLOSE_OUTDATED_CLIENT);
+        assert_eq!(negotiate(None, None, &config), Err(VersionError::MissingClientVersion("1.4".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_negotiated_versions_reach_plugins() {
+        use crate::config::MessageLaneConfig;
+        use crate::connection::{ConnectionManager, GameServerContext};
+        use crate::messaging::MessageLanes;
+        use crate::server::handlers::handle_connection;
+        use futures::StreamExt;
+        use horizon_event_system::{EventSystem, RegionId, ServerContext};
+        use std::sync::Arc;
+        use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+        use tokio_tungstenite::tungstenite::http::header::{HeaderValue, SEC_WEBSOCKET_PROTOCOL};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let protocol = ProtocolConfig { min_client_version: Some("1.2".to_string()), ..ProtocolConfig::default() };
+        let connection_manager = Arc::new(ConnectionManager::new().with_protocol(protocol));
+        let events = Arc::new(EventSystem::new());
+        let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server_connections = connection_manager.clone();
+        let server_events = events.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, addr)) = listener.accept().await {
+                let (connection_manager, events, message_lanes) = (server_connections.clone(), server_events.clone(), message_lanes.clone());
+                tokio::spawn(handle_connection(stream, addr, connection_manager, events, message_lanes, None));
+            }
+        });
+        let connect = |protocols: &'static str, client_version: &'static str| async move {
+            let mut request = format!("ws://{address}/?client_version={client_version}").into_client_request().unwrap();
+            request.headers_mut().insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static(protocols));
+            tokio_tungstenite::connect_async(request).await.unwrap()
+        };
+
+        let (mut outdated, _) = connect("horizon.v1", "1.1.9").await;
+        assert!(matches!(outdated.next().await, Some(Ok(Message::Text(text))) if text.contains("incompatible_client")));
+        match outdated.next().await {
+            Some(Ok(Message::Close(Some(frame)))) => assert_eq!(u16::from(frame.code), CLOSE_OUTDATED_CLIENT),
+            other => panic!("expected a close frame, got {other:?}"),
+        }
+        let (mut future, _) = connect("horizon.v7", "1.2.0").await;
+        future.next().await;
+        match future.next().await {
+            Some(Ok(Message::Close(Some(frame)))) => assert_eq!(u16::from(frame.code), CLOSE_UNSUPPORTED_PROTOCOL),
+            other => panic!("expected a close frame, got {other:?}"),
+        }
+
+        let (_client, response) = connect("horizon.v1.msgpack", "1.3.0").await;
+        assert_eq!(response.headers().get(SEC_WEBSOCKET_PROTOCOL).unwrap(), "horizon.v1.msgpack");
+        let context = GameServerContext::new(events, RegionId::new(), connection_manager.clone());
+        let player_id = loop {
+            if let Some((player_id, ..)) = connection_manager.connected_players().await.pop() {
+                break player_id;
+            }
+            tokio::task::yield_now().await;
+        };
+        let version = context.client_version(player_id).await.unwrap();
+        assert_eq!(version, ClientVersion { protocol: PROTOCOL_VERSION, client: Some("1.3.0".to_string()) });
+    }
+}
@@ -0,0 +1,575 @@
+//! TCP transport for GORC region federation.
+//!
+//! Neighboring region servers exchange [`FederationMessage`]s as JSON lines
+//! over plain TCP connections. [`TcpRegionLink`] dials neighbors lazily and
+//! reconnects after failures; [`serve_federation`] accepts connections from
+//! neighbors and applies their messages to the local [`RegionFederation`].
+//!
+//! Every pair of neighbors shares a secret. A listener opens each connection
+//! with a random challenge, and the connecting region answers with its name
+//! and an HMAC-SHA256 of the challenge under the secret it shares with that
+//! name:
+//!
+//! ```json
+//! { "challenge": "<base64 bytes>" }
+//! { "region": "west", "proof": "<base64 HMAC>" }
+//! ```
+//!
+//! Messages on the connection are then attributed to the region that
+//! answered the challenge; nothing in a message names its sender. The
+//! channel isn't encrypted, so keep it on a private network.
+//!
+//! Players that cross into a neighbor with a `client_url` are handed off by
+//! [`hand_off_player`], which tells their client where to reconnect:
+//!
+//! ```json
+//! { "event": "region_handoff", "region": "east", "url": "wss://east.example.com/?handoff=<ticket>" }
+//! ```
+//!
+//! Connecting to that URL binds the client to its player on the neighbor.
+
+use crate::config::NeighborConfig;
+use crate::connection::ConnectionManager;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use horizon_event_system::{
+    FederationError, FederationMessage, PlayerId, PlayerSession, RegionFederation, RegionLink, ShutdownState, Vec3,
+};
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+
+/// Longest line read from a neighbor, which bounds the size of a player handoff
+const MAX_LINE_BYTES: u64 = 4 * 1024 * 1024;
+
+/// How long a connection may take to answer or present the challenge
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long connecting to a neighbor may take
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// First line a listener sends on a connection
+#[derive(Debug, Serialize, Deserialize)]
+struct Challenge {
+    /// Random bytes the connecting region signs
+    challenge: String,
+}
+
+/// A connecting region's answer to the challenge
+#[derive(Debug, Serialize, Deserialize)]
+struct Hello {
+    /// Name of the connecting region
+    region: String,
+    /// HMAC of the challenge and the name under the secret shared with the listener
+    proof: String,
+}
+
+/// Derives the key a pair of neighbors authenticate with from their shared secret
+fn neighbor_key(secret: &str) -> hmac::Key {
+    hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes())
+}
+
+/// Message a connecting region signs to answer a challenge
+fn proof_message(challenge: &str, region: &str) -> Vec<u8> {
+    format!("horizon-federation\n{challenge}\n{region}").into_bytes()
+}
+
+/// Reads one line of at most [`MAX_LINE_BYTES`].
+///
+/// # Returns
+///
+/// The line without its newline, or `None` at the end of the stream.
+async fn read_line<R: AsyncBufRead + Unpin>(reader: &mut R) -> std::io::Result<Option<Vec<u8>>> {
+    let mut line = Vec::new();
+    if (&mut *reader).take(MAX_LINE_BYTES + 1).read_until(b'\n', &mut line).await? == 0 {
+        return Ok(None);
+    }
+    if line.last() == Some(&b'\n') {
+        line.pop();
+    } else if line.len() as u64 > MAX_LINE_BYTES {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("line longer than {MAX_LINE_BYTES} bytes"),
+        ));
+    }
+    Ok(Some(line))
+}
+
+/// Serializes a value as one JSON line
+fn json_line<T: Serialize>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    let mut line = serde_json::to_vec(value)?;
+    line.push(b'\n');
+    Ok(line)
+}
+
+/// A neighbor this region dials
+#[derive(Debug)]
+struct NeighborAddress {
+    /// Federation address of the neighbor
+    address: SocketAddr,
+    /// Key derived from the secret shared with the neighbor
+    key: hmac::Key,
+}
+
+/// Region link that sends federation messages over TCP
+#[derive(Debug)]
+pub struct TcpRegionLink {
+    /// Name of this region, presented when connecting
+    source: String,
+    /// Neighbors to connect to, keyed by name
+    neighbors: HashMap<String, NeighborAddress>,
+    /// Open connections, keyed by neighbor name
+    connections: Mutex<HashMap<String, TcpStream>>,
+}
+
+impl TcpRegionLink {
+    /// Creates a link for the given region and neighbors
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Name of this region
+    /// * `neighbors` - Neighbors to connect to
+    pub fn new(source: impl Into<String>, neighbors: &[NeighborConfig]) -> Self {
+        Self {
+            source: source.into(),
+            neighbors: neighbors
+                .iter()
+                .map(|neighbor| {
+                    let address = NeighborAddress { address: neighbor.address, key: neighbor_key(&neighbor.secret) };
+                    (neighbor.name.clone(), address)
+                })
+                .collect(),
+            connections: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Connects to a neighbor and answers its challenge
+    async fn connect(&self, neighbor: &NeighborAddress) -> Result<TcpStream, FederationError> {
+        let address = neighbor.address;
+        let stream = tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect(address))
+            .await
+            .map_err(|_| FederationError::Transport(format!("Timed out connecting to {}", address)))?
+            .map_err(|e| FederationError::Transport(format!("Failed to connect to {}: {}", address, e)))?;
+        stream.set_nodelay(true).ok();
+
+        let mut stream = BufReader::new(stream);
+        let challenge = tokio::time::timeout(HANDSHAKE_TIMEOUT, read_line(&mut stream))
+            .await
+            .map_err(|_| FederationError::Transport(format!("No challenge from {}", address)))?
+            .map_err(|e| FederationError::Transport(format!("Failed to read challenge from {}: {}", address, e)))?
+            .ok_or_else(|| FederationError::Transport(format!("{} closed the connection", address)))?;
+        let Challenge { challenge } = serde_json::from_slice(&challenge)?;
+
+        let tag = hmac::sign(&neighbor.key, &proof_message(&challenge, &self.source));
+        let hello = Hello { region: self.source.clone(), proof: URL_SAFE_NO_PAD.encode(tag.as_ref()) };
+        stream
+            .write_all(&json_line(&hello)?)
+            .await
+            .map_err(|e| FederationError::Transport(format!("Failed to authenticate to {}: {}", address, e)))?;
+        Ok(stream.into_inner())
+    }
+}
+
+#[horizon_event_system::async_trait]
+impl RegionLink for TcpRegionLink {
+    async fn send(&self, neighbor: &str, message: FederationMessage) -> Result<(), FederationError> {
+        let target = self
+            .neighbors
+            .get(neighbor)
+            .ok_or_else(|| FederationError::UnknownNeighbor(neighbor.to_string()))?;
+        let line = json_line(&message)?;
+
+        let mut connections = self.connections.lock().await;
+        if !connections.contains_key(neighbor) {
+            // Connect without the lock so an unreachable neighbor doesn't hold up sends to the others
+            drop(connections);
+            let stream = self.connect(target).await?;
+            connections = self.connections.lock().await;
+            // Another send may have connected in the meantime; keep its connection
+            if !connections.contains_key(neighbor) {
+                info!("🌐 Federation: Connected to region {} at {}", neighbor, target.address);
+                connections.insert(neighbor.to_string(), stream);
+            }
+        }
+
+        let Some(stream) = connections.get_mut(neighbor) else {
+            return Err(FederationError::Transport(format!("No connection to {}", neighbor)));
+        };
+        if let Err(e) = stream.write_all(&line).await {
+            // Drop the broken connection so the next send reconnects
+            connections.remove(neighbor);
+            return Err(FederationError::Transport(format!("Failed to send to {}: {}", target.address, e)));
+        }
+        Ok(())
+    }
+}
+
+/// Challenges a connecting region to prove it holds its neighbor secret.
+///
+/// # Returns
+///
+/// The name the region authenticated as, or why it was refused.
+async fn authenticate<S>(stream: &mut BufReader<S>, keys: &HashMap<String, hmac::Key>) -> Result<String, String>
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    let mut challenge = [0u8; 32];
+    SystemRandom::new().fill(&mut challenge).map_err(|_| "System randomness unavailable".to_string())?;
+    let challenge = URL_SAFE_NO_PAD.encode(challenge);
+    let line = json_line(&Challenge { challenge: challenge.clone() }).map_err(|e| e.to_string())?;
+    stream.get_mut().write_all(&line).await.map_err(|e| e.to_string())?;
+
+    let hello = read_line(stream)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("Closed before authenticating")?;
+    let hello: Hello = serde_json::from_slice(&hello).map_err(|e| format!("Invalid hello: {e}"))?;
+    let key = keys.get(&hello.region).ok_or_else(|| format!("Unknown region {}", hello.region))?;
+    let proof = URL_SAFE_NO_PAD.decode(&hello.proof).map_err(|_| "Malformed proof".to_string())?;
+    hmac::verify(key, &proof_message(&challenge, &hello.region), &proof)
+        .map_err(|_| format!("Invalid proof for region {}", hello.region))?;
+    Ok(hello.region)
+}
+
+/// Accepts neighbor connections and applies their messages until shutdown.
+///
+/// Connections that don't answer the challenge with a configured neighbor's
+/// secret are closed before any message is read.
+///
+/// # Arguments
+///
+/// * `listener` - Listener bound to the federation address
+/// * `federation` - Local federation receiving the messages
+/// * `neighbors` - Neighbors allowed to connect, with their secrets
+/// * `shutdown_state` - Optional shutdown state that stops the accept loop
+pub async fn serve_federation(
+    listener: TcpListener,
+    federation: Arc<RegionFederation>,
+    neighbors: Vec<NeighborConfig>,
+    shutdown_state: Option<ShutdownState>,
+) {
+    let keys: Arc<HashMap<String, hmac::Key>> = Arc::new(
+        neighbors
+            .iter()
+            .map(|neighbor| (neighbor.name.clone(), neighbor_key(&neighbor.secret)))
+            .collect(),
+    );
+
+    loop {
+        if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+            break;
+        }
+
+        let (stream, addr) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                warn!("🌐 Federation: Failed to accept neighbor connection: {}", e);
+                continue;
+            }
+        };
+
+        let federation = federation.clone();
+        let keys = keys.clone();
+        tokio::spawn(async move {
+            let mut stream = BufReader::new(stream);
+            let source = match tokio::time::timeout(HANDSHAKE_TIMEOUT, authenticate(&mut stream, &keys)).await {
+                Ok(Ok(source)) => source,
+                Ok(Err(reason)) => {
+                    warn!("🌐 Federation: Refused connection from {}: {}", addr, reason);
+                    return;
+                }
+                Err(_) => {
+                    warn!("🌐 Federation: Refused connection from {}: handshake timed out", addr);
+                    return;
+                }
+            };
+            info!("🌐 Federation: Region {} connected from {}", source, addr);
+
+            loop {
+                let line = match read_line(&mut stream).await {
+                    Ok(Some(line)) => line,
+                    Ok(None) => break,
+                    Err(e) => {
+                        warn!("🌐 Federation: Connection from region {} failed: {}", source, e);
+                        break;
+                    }
+                };
+
+                let message: FederationMessage = match serde_json::from_slice(&line) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        warn!("🌐 Federation: Invalid message from region {}: {}", source, e);
+                        continue;
+                    }
+                };
+                if let Err(e) = federation.apply(&source, message).await {
+                    warn!("🌐 Federation: Rejected message from region {}: {}", source, e);
+                }
+            }
+            debug!("🌐 Federation: Region {} at {} disconnected", source, addr);
+        });
+    }
+}
+
+/// Hands a player that crossed into a neighbor off and redirects its client.
+///
+/// Sends the player's session to the neighbor, then the `region_handoff`
+/// message with the neighbor's URL and ticket to the client. The player's
+/// session token is revoked, so it leaves this region as soon as its client
+/// disconnects.
+///
+/// # Arguments
+///
+/// * `federation` - Local federation sending the handoff
+/// * `connection_manager` - Manager holding the player's connection
+/// * `neighbor` - Region the player entered
+/// * `player_id` - Player that crossed
+/// * `position` - Player position when the crossing was detected
+///
+/// # Returns
+///
+/// `true` if the client was redirected. Players without a connection here or
+/// crossing into a neighbor without a `client_url` are left alone.
+pub async fn hand_off_player(
+    federation: &RegionFederation,
+    connection_manager: &ConnectionManager,
+    neighbor: &NeighborConfig,
+    player_id: PlayerId,
+    position: Vec3,
+) -> bool {
+    let Some(client_url) = &neighbor.client_url else {
+        return false;
+    };
+    let Some((connection_id, _, _, auth_status)) = connection_manager.get_connection_info_by_player(player_id).await else {
+        return false;
+    };
+
+    let session = PlayerSession { player_id, position, auth_status };
+    let Some(ticket) = federation.hand_off_player(&neighbor.name, session).await else {
+        return false;
+    };
+
+    connection_manager.session_resumption().revoke(player_id);
+    let separator = if client_url.contains('?') { '&' } else { '?' };
+    let redirect = serde_json::json!({
+        "event": "region_handoff",
+        "region": neighbor.name,
+        "url": format!("{client_url}{separator}handoff={ticket}"),
+    });
+    connection_manager.send_to_connection(connection_id, redirect.to_string().into_bytes()).await;
+    info!("🌐 Federation: Redirected player {} to region {}", player_id, neighbor.name);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::SessionClaim;
+    use crate::server::handlers::connect_player;
+    use horizon_event_system::{
+        AuthenticationStatus, EventSystem, GorcInstanceManager, NeighborRegion, PlayerRegionArrivalEvent, RegionBounds,
+    };
+
+    /// Link that applies messages straight to the neighbor's federation
+    #[derive(Debug)]
+    struct DirectLink {
+        source: String,
+        neighbor: Arc<RegionFederation>,
+    }
+
+    #[horizon_event_system::async_trait]
+    impl RegionLink for DirectLink {
+        async fn send(&self, _neighbor: &str, message: FederationMessage) -> Result<(), FederationError> {
+            self.neighbor.apply(&self.source, message).await
+        }
+    }
+
+    fn bounds(min_x: f64, max_x: f64) -> RegionBounds {
+        RegionBounds { min_x, max_x, ..RegionBounds::default() }
+    }
+
+    #[tokio::test]
+    async fn test_handed_off_player_reconnects_to_neighbor() {
+        let east = Arc::new(RegionFederation::new(
+            bounds(0.0, 1000.0),
+            vec![NeighborRegion { name: "west".to_string(), bounds: bounds(-1000.0, 0.0) }],
+            Arc::new(GorcInstanceManager::new()),
+            Arc::new(TcpRegionLink::new("east", &[])),
+        ));
+        let west = RegionFederation::new(
+            bounds(-1000.0, 0.0),
+            vec![NeighborRegion { name: "east".to_string(), bounds: bounds(0.0, 1000.0) }],
+            Arc::new(GorcInstanceManager::new()),
+            Arc::new(DirectLink { source: "west".to_string(), neighbor: east.clone() }),
+        );
+
+        let west_connections = ConnectionManager::new();
+        let west_events = EventSystem::new();
+        let player = connect_player("10.0.0.1:4000".parse().unwrap(), &west_connections, &west_events, None, None)
+            .await
+            .unwrap();
+        west_connections.set_auth_status_by_player(player.player_id, AuthenticationStatus::Authenticated).await;
+        let mut outgoing = west_connections.subscribe();
+
+        let neighbor = NeighborConfig {
+            name: "east".to_string(),
+            address: "127.0.0.1:9100".parse().unwrap(),
+            bounds: bounds(0.0, 1000.0),
+            secret: "west-east".to_string(),
+            client_url: Some("ws://east.example.com:8080/".to_string()),
+        };
+        let crossed = Vec3::new(10.0, 0.0, 0.0);
+        assert!(hand_off_player(&west, &west_connections, &neighbor, player.player_id, crossed).await);
+
+        let (connection_id, redirect) = outgoing.recv().await.unwrap();
+        assert_eq!(connection_id, player.connection_id);
+        let redirect: serde_json::Value = serde_json::from_slice(&redirect).unwrap();
+        assert_eq!(redirect["event"], "region_handoff");
+        assert_eq!(redirect["region"], "east");
+        let url = redirect["url"].as_str().unwrap();
+        let ticket = url.strip_prefix("ws://east.example.com:8080/?handoff=").unwrap().to_string();
+
+        // The client reconnects to the east region with its ticket
+        let east_connections = ConnectionManager::new();
+        assert!(east_connections.set_region_federation(east.clone()));
+        let east_events = EventSystem::new();
+        let (arrived_tx, mut arrived_rx) = tokio::sync::mpsc::unbounded_channel();
+        east_events
+            .on_core("player_region_arrival", move |event: PlayerRegionArrivalEvent| {
+                let _ = arrived_tx.send(event);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let claim = SessionClaim::Handoff(ticket);
+        let arrived = connect_player("10.0.0.1:4001".parse().unwrap(), &east_connections, &east_events, Some(&claim), None)
+            .await
+            .unwrap();
+        assert!(arrived.arrived);
+        assert_eq!(arrived.player_id, player.player_id);
+        assert_eq!(
+            east_connections.get_auth_status_by_player(player.player_id).await,
+            Some(AuthenticationStatus::Authenticated)
+        );
+        let event = arrived_rx.recv().await.unwrap();
+        assert_eq!(event.source_region, "west");
+
+        // Tickets are single use
+        let again = connect_player("10.0.0.2:4002".parse().unwrap(), &east_connections, &east_events, Some(&claim), None)
+            .await
+            .unwrap();
+        assert!(!again.arrived);
+        assert_ne!(again.player_id, player.player_id);
+    }
+
+    #[tokio::test]
+    async fn test_neighbors_must_prove_their_secret() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let east = Arc::new(RegionFederation::new(
+            bounds(0.0, 1000.0),
+            vec![NeighborRegion { name: "west".to_string(), bounds: bounds(-1000.0, 0.0) }],
+            Arc::new(GorcInstanceManager::new()),
+            Arc::new(TcpRegionLink::new("east", &[])),
+        ));
+        let neighbor = |name: &str, secret: &str| NeighborConfig {
+            name: name.to_string(),
+            address,
+            bounds: bounds(-1000.0, 0.0),
+            secret: secret.to_string(),
+            client_url: None,
+        };
+        tokio::spawn(serve_federation(listener, east.clone(), vec![neighbor("west", "west-east")], None));
+        let handoff = || FederationMessage::PlayerHandoff {
+            ticket: "ticket".to_string(),
+            session: PlayerSession {
+                player_id: PlayerId::new(),
+                position: Vec3::new(10.0, 0.0, 0.0),
+                auth_status: AuthenticationStatus::Authenticated,
+            },
+            player_object: None,
+            objects: Vec::new(),
+        };
+
+        // A client naming itself "west" without the secret is cut off before any message is read
+        let mut impostor = BufReader::new(TcpStream::connect(address).await.unwrap());
+        assert!(read_line(&mut impostor).await.unwrap().is_some());
+        let hello = Hello { region: "west".to_string(), proof: URL_SAFE_NO_PAD.encode([0u8; 32]) };
+        impostor.write_all(&json_line(&hello).unwrap()).await.unwrap();
+        let _ = impostor.write_all(&json_line(&handoff()).unwrap()).await;
+        assert!(!matches!(read_line(&mut impostor).await, Ok(Some(_))));
+
+        // A link holding the wrong secret is refused as well
+        let guessed = TcpRegionLink::new("west", &[neighbor("east", "guessed")]);
+        let _ = guessed.send("east", handoff()).await;
+
+        let west = TcpRegionLink::new("west", &[neighbor("east", "west-east")]);
+        west.send("east", handoff()).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while east.get_stats().await.players_received == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(east.get_stats().await.players_received, 1);
+    }
+
+    #[tokio::test]
+    async fn test_stalled_neighbor_does_not_block_sends_to_others() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let east = Arc::new(RegionFederation::new(
+            bounds(0.0, 1000.0),
+            vec![NeighborRegion { name: "west".to_string(), bounds: bounds(-1000.0, 0.0) }],
+            Arc::new(GorcInstanceManager::new()),
+            Arc::new(TcpRegionLink::new("east", &[])),
+        ));
+        let neighbor = |name: &str, address: SocketAddr| NeighborConfig {
+            name: name.to_string(),
+            address,
+            bounds: bounds(-1000.0, 0.0),
+            secret: "west-east".to_string(),
+            client_url: None,
+        };
+        tokio::spawn(serve_federation(listener, east.clone(), vec![neighbor("west", address)], None));
+
+        // North accepts connections but never sends its challenge
+        let stalled = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let west = Arc::new(TcpRegionLink::new(
+            "west",
+            &[neighbor("east", address), neighbor("north", stalled.local_addr().unwrap())],
+        ));
+        let handoff = FederationMessage::PlayerHandoff {
+            ticket: "ticket".to_string(),
+            session: PlayerSession {
+                player_id: PlayerId::new(),
+                position: Vec3::new(10.0, 0.0, 0.0),
+                auth_status: AuthenticationStatus::Authenticated,
+            },
+            player_object: None,
+            objects: Vec::new(),
+        };
+        let stalled_send = tokio::spawn({
+            let (west, handoff) = (west.clone(), handoff.clone());
+            async move { west.send("north", handoff).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        tokio::time::timeout(Duration::from_secs(1), west.send("east", handoff))
+            .await
+            .expect("send to east waited on the stalled neighbor")
+            .unwrap();
+        assert!(stalled_send.await.unwrap().is_err());
+    }
+}
@@ -0,0 +1,361 @@
+//! gRPC management API for orchestration tooling and game backends.
+//!
+//! Serves the `horizon.management.v1.Management` service declared in
+//! `proto/management.proto` on its own address, next to the human-oriented
+//! [admin HTTP endpoint](crate::admin). Typed clients generated from the
+//! proto file can:
+//!
+//! * list and kick connected players
+//! * list loaded plugins and reload them
+//! * read the replication statistics of the GORC instance manager
+//! * drain the server, refusing new players while connected ones play on
+//! * shut the server down once its players leave or a drain timeout elapses
+//!
+//! Calls need the admin token as `authorization: Bearer <token>` metadata,
+//! and are refused while no admin token is configured. Refused calls and
+//! every call that changes the server are recorded as `security_audit` core
+//! events.
+
+pub mod proto;
+
+use self::proto::management_server::{Management, ManagementServer};
+use self::proto::{
+    AuthStatus, DrainRequest, DrainResponse, GetGorcStatsRequest, GorcStats, KickPlayerRequest, KickPlayerResponse,
+    ListPlayersRequest, ListPlayersResponse, ListPluginsRequest, ListPluginsResponse, Player, Plugin, PluginHealth,
+    ReloadPluginRequest, ReloadPluginResponse, ShutdownRequest, ShutdownResponse,
+};
+use crate::admin::constant_time_eq;
+use crate::audit::record_audit;
+use crate::connection::ConnectionManager;
+use horizon_event_system::gorc::GorcInstanceManager;
+use horizon_event_system::{AuditAction, AuthenticationStatus, EventSystem, PlayerId, SecurityAuditEvent, ShutdownState};
+use plugin_system::PluginManager;
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+use tokio::net::TcpListener;
+use tonic::transport::server::TcpIncoming;
+use tonic::{Request, Response, Status};
+use tracing::info;
+
+/// Actor management calls are recorded under in the audit log
+const GRPC_ACTOR: &str = "grpc";
+
+/// How often a shutdown checks whether the last player has left
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Server state the management calls operate on
+pub struct ManagementService {
+    connection_manager: Arc<ConnectionManager>,
+    events: Arc<EventSystem>,
+    gorc_instances: Option<Arc<GorcInstanceManager>>,
+    plugin_manager: Option<Arc<PluginManager>>,
+    shutdown_state: Option<ShutdownState>,
+}
+
+impl ManagementService {
+    /// Creates the management service for a server's connections and event system.
+    ///
+    /// GORC statistics come from the event system's GORC instance manager,
+    /// if it has one.
+    pub fn new(connection_manager: Arc<ConnectionManager>, events: Arc<EventSystem>) -> Self {
+        Self {
+            gorc_instances: events.get_gorc_instances(),
+            connection_manager,
+            events,
+            plugin_manager: None,
+            shutdown_state: None,
+        }
+    }
+
+    /// Sets the GORC instance manager statistics are read from
+    pub fn with_gorc_instances(mut self, gorc_instances: Arc<GorcInstanceManager>) -> Self {
+        self.gorc_instances = Some(gorc_instances);
+        self
+    }
+
+    /// Sets the plugin manager the plugin calls operate on
+    pub fn with_plugin_manager(mut self, plugin_manager: Arc<PluginManager>) -> Self {
+        self.plugin_manager = Some(plugin_manager);
+        self
+    }
+
+    /// Sets the shutdown state `Shutdown` initiates
+    pub fn with_shutdown_state(mut self, shutdown_state: ShutdownState) -> Self {
+        self.shutdown_state = Some(shutdown_state);
+        self
+    }
+
+    fn plugin_manager(&self) -> Result<&PluginManager, Status> {
+        self.plugin_manager
+            .as_deref()
+            .ok_or_else(|| Status::unavailable("Plugin manager not available"))
+    }
+
+    async fn connected_players(&self) -> u32 {
+        self.connection_manager.connected_players().await.len() as u32
+    }
+
+    /// Records a call that changed the server in the audit log
+    async fn audit(&self, call: &str) {
+        record_audit(&self.events, SecurityAuditEvent::new(AuditAction::AdminRequest, GRPC_ACTOR).with_detail(call)).await;
+    }
+}
+
+#[tonic::async_trait]
+impl Management for ManagementService {
+    async fn list_players(&self, _request: Request<ListPlayersRequest>) -> Result<Response<ListPlayersResponse>, Status> {
+        let mut players = self.connection_manager.connected_players().await;
+        players.sort_by_key(|(_, connection_id, ..)| *connection_id);
+        let players = players
+            .into_iter()
+            .map(|(player_id, connection_id, remote_addr, connected_at, auth_status)| Player {
+                player_id: player_id.to_string(),
+                connection_id: connection_id as u64,
+                remote_addr: remote_addr.to_string(),
+                connected_at: connected_at.duration_since(UNIX_EPOCH).map(|since| since.as_secs()).unwrap_or_default(),
+                auth_status: auth_status_of(auth_status) as i32,
+            })
+            .collect();
+        Ok(Response::new(ListPlayersResponse { players }))
+    }
+
+    async fn kick_player(&self, request: Request<KickPlayerRequest>) -> Result<Response<KickPlayerResponse>, Status> {
+        let request = request.into_inner();
+        let player_id: PlayerId = request
+            .player_id
+            .parse()
+            .map_err(|_| Status::invalid_argument("Invalid player ID"))?;
+        let result = match self.events.get_client_response_sender() {
+            Some(sender) => sender.kick(player_id, request.reason.clone()).await,
+            None => self.connection_manager.kick_player(player_id, request.reason.clone()).await,
+        };
+        result.map_err(Status::not_found)?;
+        info!("🛠️ Management: Kicked player {}", player_id);
+        self.audit("KickPlayer").await;
+        let mut kick = SecurityAuditEvent::new(AuditAction::Kick, GRPC_ACTOR).with_target(player_id);
+        kick.detail = request.reason;
+        record_audit(&self.events, kick).await;
+        Ok(Response::new(KickPlayerResponse {}))
+    }
+
+    async fn list_plugins(&self, _request: Request<ListPluginsRequest>) -> Result<Response<ListPluginsResponse>, Status> {
+        let plugin_manager = self.plugin_manager()?;
+        let plugins = plugin_manager
+            .plugin_health_snapshots()
+            .into_iter()
+            .map(|snapshot| Plugin {
+                version: plugin_manager.plugin_manifest(&snapshot.name).map(|manifest| manifest.version),
+                health: plugin_health_of(&snapshot.health) as i32,
+                name: snapshot.name,
+                detail: snapshot.detail,
+            })
+            .collect();
+        Ok(Response::new(ListPluginsResponse { plugins }))
+    }
+
+    async fn reload_plugin(&self, request: Request<ReloadPluginRequest>) -> Result<Response<ReloadPluginResponse>, Status> {
+        let plugin_manager = self.plugin_manager()?;
+        let name = request.into_inner().name;
+        if !plugin_manager.is_plugin_loaded(&name) {
+            return Err(Status::not_found("Plugin not loaded"));
+        }
+        plugin_manager
+            .restart_plugin(&name)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        info!("🛠️ Management: Reloaded plugin {}", name);
+        self.audit(&format!("ReloadPlugin {name}")).await;
+        Ok(Response::new(ReloadPluginResponse {}))
+    }
+
+    async fn get_gorc_stats(&self, _request: Request<GetGorcStatsRequest>) -> Result<Response<GorcStats>, Status> {
+        let gorc_instances = self
+            .gorc_instances
+            .as_ref()
+            .ok_or_else(|| Status::unavailable("GORC instance manager not available"))?;
+        let stats = gorc_instances.get_stats().await;
+        Ok(Response::new(GorcStats {
+            total_objects: stats.total_objects as u64,
+            total_subscriptions: stats.total_subscriptions as u64,
+            replication_events_sent: stats.replication_events_sent,
+            total_bytes_transmitted: stats.total_bytes_transmitted,
+            avg_objects_per_type: stats.avg_objects_per_type,
+            large_zone_warnings: stats.large_zone_warnings as u64,
+            zone_thrash_events: stats.zone_thrash_events,
+            hysteresis_retained: stats.hysteresis_retained,
+            authority_transfers: stats.authority_transfers,
+            objects_expired: stats.objects_expired,
+        }))
+    }
+
+    async fn drain(&self, request: Request<DrainRequest>) -> Result<Response<DrainResponse>, Status> {
+        let draining = request.into_inner().draining;
+        let was_draining = self.connection_manager.set_draining(draining);
+        let connected_players = self.connected_players().await;
+        match draining {
+            true => info!("🛠️ Management: Draining, {} players still connected", connected_players),
+            false => info!("🛠️ Management: Stopped draining"),
+        }
+        self.audit(&format!("Drain draining={draining}")).await;
+        Ok(Response::new(DrainResponse { was_draining, connected_players }))
+    }
+
+    async fn shutdown(&self, request: Request<ShutdownRequest>) -> Result<Response<ShutdownResponse>, Status> {
+        let shutdown_state = self
+            .shutdown_state
+            .clone()
+            .ok_or_else(|| Status::failed_precondition("Shutdown is not coordinated on this server"))?;
+        let drain_timeout = Duration::from_secs(request.into_inner().drain_timeout_secs);
+        self.connection_manager.set_draining(true);
+        let connected_players = self.connected_players().await;
+        info!(
+            "🛠️ Management: Shutting down once {} players leave or within {:?}",
+            connected_players, drain_timeout
+        );
+        self.audit(&format!("Shutdown drain_timeout={}s", drain_timeout.as_secs())).await;
+
+        let connection_manager = self.connection_manager.clone();
+        tokio::spawn(async move {
+            let deadline = tokio::time::Instant::now() + drain_timeout;
+            loop {
+                let now = tokio::time::Instant::now();
+                if now >= deadline || connection_manager.connected_players().await.is_empty() {
+                    break;
+                }
+                tokio::time::sleep(DRAIN_POLL_INTERVAL.min(deadline - now)).await;
+            }
+            shutdown_state.initiate_shutdown();
+        });
+        Ok(Response::new(ShutdownResponse { connected_players }))
+    }
+}
+
+fn auth_status_of(status: AuthenticationStatus) -> AuthStatus {
+    match status {
+        AuthenticationStatus::Unauthenticated => AuthStatus::Unauthenticated,
+        AuthenticationStatus::Authenticating => AuthStatus::Authenticating,
+        AuthenticationStatus::Authenticated => AuthStatus::Authenticated,
+        AuthenticationStatus::AuthenticationFailed => AuthStatus::Failed,
+        AuthenticationStatus::Guest => AuthStatus::Guest,
+    }
+}
+
+fn plugin_health_of(health: &plugin_system::PluginHealth) -> PluginHealth {
+    match health {
+        plugin_system::PluginHealth::Healthy => PluginHealth::Healthy,
+        plugin_system::PluginHealth::Degraded => PluginHealth::Degraded,
+        plugin_system::PluginHealth::Unresponsive => PluginHealth::Unresponsive,
+        plugin_system::PluginHealth::Failed => PluginHealth::Failed,
+    }
+}
+
+/// Refuses calls without the admin token, or every call while none is configured
+fn authorize(token: Option<&str>, request: &Request<()>) -> Result<(), Status> {
+    let Some(token) = token else {
+        return Err(Status::permission_denied("Management API is disabled until an admin token is configured"));
+    };
+
+    let presented = request
+        .metadata()
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim);
+    match presented {
+        Some(presented) if constant_time_eq(presented.as_bytes(), token.as_bytes()) => Ok(()),
+        _ => Err(Status::unauthenticated("Missing or invalid admin token")),
+    }
+}
+
+/// Serves the management API until shutdown is initiated.
+///
+/// # Arguments
+///
+/// * `listener` - Bound gRPC listener
+/// * `service` - Management service the calls are handled by
+/// * `token` - Admin token calls must present (`None` refuses every call)
+/// * `shutdown_state` - Optional shutdown state for coordinated shutdown
+///
+/// # Returns
+///
+/// `Ok(())` once shutdown is initiated, or the transport error that stopped the server.
+pub async fn serve_grpc(
+    listener: TcpListener,
+    service: ManagementService,
+    token: Option<String>,
+    shutdown_state: Option<ShutdownState>,
+) -> Result<(), tonic::transport::Error> {
+    let events = service.events.clone();
+    let service = ManagementServer::with_interceptor(service, move |request: Request<()>| {
+        authorize(token.as_deref(), &request).map(|()| request).inspect_err(|status| {
+            let events = events.clone();
+            let refusal = SecurityAuditEvent::new(AuditAction::AdminRequest, GRPC_ACTOR).with_detail(format!("refused: {}", status.message()));
+            tokio::spawn(async move { record_audit(&events, refusal).await });
+        })
+    });
+    let shutdown = async move {
+        match shutdown_state {
+            Some(state) => state.initiated().await,
+            None => std::future::pending().await,
+        }
+    };
+
+    tonic::transport::Server::builder()
+        .add_service(service)
+        .serve_with_incoming_shutdown(TcpIncoming::from(listener), shutdown)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::proto::management_client::ManagementClient;
+    use super::*;
+
+    fn authorized<T>(message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        request.metadata_mut().insert("authorization", "Bearer secret".parse().unwrap());
+        request
+    }
+
+    #[tokio::test]
+    async fn test_management_calls() {
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let shutdown_state = ShutdownState::new();
+        let service = ManagementService::new(connection_manager.clone(), Arc::new(EventSystem::new()))
+            .with_gorc_instances(Arc::new(GorcInstanceManager::new()))
+            .with_shutdown_state(shutdown_state.clone());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(serve_grpc(listener, service, Some("secret".to_string()), Some(shutdown_state.clone())));
+        let mut client = ManagementClient::connect(format!("http://{address}")).await.unwrap();
+
+        let refused = client.list_players(ListPlayersRequest {}).await.unwrap_err();
+        assert_eq!(refused.code(), tonic::Code::Unauthenticated);
+
+        let player_addr = "127.0.0.1:40000".parse().unwrap();
+        let connection_id = connection_manager.add_connection(player_addr).await;
+        let player_id = PlayerId::new();
+        connection_manager.set_player_id(connection_id, player_id).await;
+        let players = client.list_players(authorized(ListPlayersRequest {})).await.unwrap().into_inner().players;
+        assert_eq!(players.len(), 1);
+        assert_eq!((players[0].player_id.clone(), players[0].remote_addr.as_str()), (player_id.to_string(), "127.0.0.1:40000"));
+
+        let stats = client.get_gorc_stats(authorized(GetGorcStatsRequest {})).await.unwrap().into_inner();
+        assert_eq!(stats.total_objects, 0);
+        let missing = client.list_plugins(authorized(ListPluginsRequest {})).await.unwrap_err();
+        assert_eq!(missing.code(), tonic::Code::Unavailable);
+
+        let drained = client.drain(authorized(DrainRequest { draining: true })).await.unwrap().into_inner();
+        assert_eq!((drained.was_draining, drained.connected_players), (false, 1));
+        assert!(connection_manager.is_draining());
+
+        // Shutdown waits for the last player to leave
+        let response = client.shutdown(authorized(ShutdownRequest { drain_timeout_secs: 60 })).await.unwrap().into_inner();
+        assert_eq!(response.connected_players, 1);
+        tokio::time::sleep(DRAIN_POLL_INTERVAL * 2).await;
+        assert!(!shutdown_state.is_shutdown_initiated());
+        connection_manager.remove_connection(connection_id).await;
+        tokio::time::timeout(Duration::from_secs(5), shutdown_state.initiated()).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), server).await.unwrap().unwrap().unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+//! Messages of the gRPC management API and its generated server and client.
+//!
+//! The messages mirror `proto/management.proto` field for field and are
+//! kept in sync with it by hand, so building the server doesn't need `protoc`.
+
+/// Request of `ListPlayers`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListPlayersRequest {}
+
+/// Response of `ListPlayers`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListPlayersResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub players: Vec<Player>,
+}
+
+/// A connected player
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Player {
+    #[prost(string, tag = "1")]
+    pub player_id: String,
+    #[prost(uint64, tag = "2")]
+    pub connection_id: u64,
+    #[prost(string, tag = "3")]
+    pub remote_addr: String,
+    /// Unix time in seconds
+    #[prost(uint64, tag = "4")]
+    pub connected_at: u64,
+    #[prost(enumeration = "AuthStatus", tag = "5")]
+    pub auth_status: i32,
+}
+
+/// Authentication status of a player
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum AuthStatus {
+    Unspecified = 0,
+    Unauthenticated = 1,
+    Authenticating = 2,
+    Authenticated = 3,
+    Failed = 4,
+    Guest = 5,
+}
+
+/// Request of `KickPlayer`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct KickPlayerRequest {
+    #[prost(string, tag = "1")]
+    pub player_id: String,
+    #[prost(string, optional, tag = "2")]
+    pub reason: Option<String>,
+}
+
+/// Response of `KickPlayer`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct KickPlayerResponse {}
+
+/// Request of `ListPlugins`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListPluginsRequest {}
+
+/// Response of `ListPlugins`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ListPluginsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub plugins: Vec<Plugin>,
+}
+
+/// A loaded plugin
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Plugin {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, optional, tag = "2")]
+    pub version: Option<String>,
+    #[prost(enumeration = "PluginHealth", tag = "3")]
+    pub health: i32,
+    /// Reason for a non-healthy state, if known
+    #[prost(string, optional, tag = "4")]
+    pub detail: Option<String>,
+}
+
+/// Health of a plugin
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum PluginHealth {
+    Unspecified = 0,
+    Healthy = 1,
+    Degraded = 2,
+    Unresponsive = 3,
+    Failed = 4,
+}
+
+/// Request of `ReloadPlugin`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReloadPluginRequest {
+    #[prost(string, tag = "1")]
+    pub name: String,
+}
+
+/// Response of `ReloadPlugin`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ReloadPluginResponse {}
+
+/// Request of `GetGorcStats`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetGorcStatsRequest {}
+
+/// Replication statistics of the GORC instance manager
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GorcStats {
+    #[prost(uint64, tag = "1")]
+    pub total_objects: u64,
+    #[prost(uint64, tag = "2")]
+    pub total_subscriptions: u64,
+    #[prost(uint64, tag = "3")]
+    pub replication_events_sent: u64,
+    #[prost(uint64, tag = "4")]
+    pub total_bytes_transmitted: u64,
+    #[prost(float, tag = "5")]
+    pub avg_objects_per_type: f32,
+    #[prost(uint64, tag = "6")]
+    pub large_zone_warnings: u64,
+    #[prost(uint64, tag = "7")]
+    pub zone_thrash_events: u64,
+    #[prost(uint64, tag = "8")]
+    pub hysteresis_retained: u64,
+    #[prost(uint64, tag = "9")]
+    pub authority_transfers: u64,
+    #[prost(uint64, tag = "10")]
+    pub objects_expired: u64,
+}
+
+/// Request of `Drain`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DrainRequest {
+    /// True to refuse new players, false to accept them again
+    #[prost(bool, tag = "1")]
+    pub draining: bool,
+}
+
+/// Response of `Drain`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DrainResponse {
+    #[prost(bool, tag = "1")]
+    pub was_draining: bool,
+    #[prost(uint32, tag = "2")]
+    pub connected_players: u32,
+}
+
+/// Request of `Shutdown`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ShutdownRequest {
+    /// How long connected players may keep playing before shutdown begins
+    #[prost(uint64, tag = "1")]
+    pub drain_timeout_secs: u64,
+}
+
+/// Response of `Shutdown`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ShutdownResponse {
+    #[prost(uint32, tag = "1")]
+    pub connected_players: u32,
+}
+
+include!(concat!(env!("OUT_DIR"), "/horizon.management.v1.Management.rs"));
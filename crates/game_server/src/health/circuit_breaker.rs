@@ -1,5 +1,9 @@
 //! Circuit breaker implementation for resilience patterns.
 
+use horizon_event_system::HandlerGate;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
@@ -18,6 +22,7 @@ pub enum CircuitBreakerState {
 
 /// Circuit breaker configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CircuitBreakerConfig {
     /// Number of failures before opening the circuit
     pub failure_threshold: u32,
@@ -206,6 +211,96 @@ pub struct CircuitBreakerStats {
     pub last_success_time: Option<Instant>,
 }
 
+/// Circuit breakers around plugin event handlers, one per (plugin, event key).
+///
+/// Installed as the event system's [`HandlerGate`]: once a plugin's handler
+/// for an event key fails `failure_threshold` times in a row, its invocations
+/// are skipped until `timeout_duration` has passed, after which it is tried
+/// again in the half-open state. Other plugins' handlers for the same key
+/// keep running.
+#[derive(Debug)]
+pub struct PluginCircuitBreakers {
+    config: CircuitBreakerConfig,
+    breakers: std::sync::RwLock<HashMap<(String, String), Arc<CircuitBreaker>>>,
+}
+
+impl PluginCircuitBreakers {
+    /// Creates an empty set of breakers that all use the given configuration
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            breakers: std::sync::RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Gets the breaker of a plugin's handler for an event key, if it has failed before
+    pub fn get(&self, plugin: &str, event_key: &str) -> Option<Arc<CircuitBreaker>> {
+        self.breakers
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&(plugin.to_string(), event_key.to_string()))
+            .cloned()
+    }
+
+    /// Gets statistics for every breaker, named `<plugin> <event key>`
+    pub async fn get_stats(&self) -> Vec<CircuitBreakerStats> {
+        let breakers: Vec<Arc<CircuitBreaker>> = self
+            .breakers
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .values()
+            .cloned()
+            .collect();
+        let mut stats = Vec::with_capacity(breakers.len());
+        for breaker in breakers {
+            stats.push(breaker.get_stats().await);
+        }
+        stats.sort_by(|a, b| a.name.cmp(&b.name));
+        stats
+    }
+
+    fn get_or_create(&self, plugin: &str, event_key: &str) -> Arc<CircuitBreaker> {
+        if let Some(breaker) = self.get(plugin, event_key) {
+            return breaker;
+        }
+        self.breakers
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry((plugin.to_string(), event_key.to_string()))
+            .or_insert_with(|| Arc::new(CircuitBreaker::new(format!("{} {}", plugin, event_key), self.config.clone())))
+            .clone()
+    }
+}
+
+impl HandlerGate for PluginCircuitBreakers {
+    fn allow<'a>(&'a self, plugin: &'a str, event_key: &'a str) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>> {
+        Box::pin(async move {
+            match self.get(plugin, event_key) {
+                Some(breaker) => breaker.can_execute().await,
+                None => true,
+            }
+        })
+    }
+
+    fn record<'a>(
+        &'a self,
+        plugin: &'a str,
+        event_key: &'a str,
+        succeeded: bool,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            if succeeded {
+                // Handlers that never failed don't need a breaker
+                if let Some(breaker) = self.get(plugin, event_key) {
+                    breaker.record_success().await;
+                }
+            } else {
+                self.get_or_create(plugin, event_key).record_failure().await;
+            }
+        })
+    }
+}
+
 /// Helper macro for executing operations with circuit breaker protection
 #[macro_export]
 macro_rules! with_circuit_breaker {
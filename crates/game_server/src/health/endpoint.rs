@@ -0,0 +1,254 @@
+//! HTTP endpoint serving health probes and metrics.
+//!
+//! A minimal HTTP/1.1 listener on its own address, for orchestrators and
+//! monitoring rather than players. It serves:
+//!
+//! * `GET /healthz` - liveness, `200` while the process can answer at all
+//! * `GET /readyz` - readiness, `200` once plugins and handlers are in place
+//!   and `503` before that, while draining or once shutdown has begun, so load
+//!   balancers stop routing players to a draining server
+//! * `GET /metrics` - health metrics in the Prometheus text format
+//!
+//! Each connection handles a single request and is then closed.
+
+use super::HealthManager;
+use crate::GameServer;
+use horizon_event_system::ShutdownState;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, warn};
+
+/// Route answering liveness probes
+pub const LIVENESS_ROUTE: &str = "/healthz";
+
+/// Route answering readiness probes
+pub const READINESS_ROUTE: &str = "/readyz";
+
+/// Route serving Prometheus metrics
+pub const METRICS_ROUTE: &str = "/metrics";
+
+/// Content type of the Prometheus text exposition format
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Largest request head the health listener reads
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+/// An HTTP response produced by a health route
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Value of the `Content-Type` header
+    pub content_type: &'static str,
+    /// Response body
+    pub body: String,
+}
+
+impl HealthResponse {
+    fn text(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: format!("{body}\n"),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// Encodes the response as an HTTP/1.1 message
+    pub fn to_http(&self) -> Vec<u8> {
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.content_type,
+            self.body.len(),
+            self.body
+        )
+        .into_bytes()
+    }
+}
+
+/// Routes a health request.
+///
+/// # Arguments
+///
+/// * `method` - HTTP method of the request
+/// * `target` - Request target (path and optional query string)
+/// * `health` - Health manager computing the results
+/// * `server` - Server being probed
+/// * `shutdown_state` - Optional shutdown state; the server isn't ready once shutdown began
+///
+/// # Returns
+///
+/// The response to send to the client.
+pub async fn route(
+    method: &str,
+    target: &str,
+    health: &HealthManager,
+    server: &GameServer,
+    shutdown_state: Option<&ShutdownState>,
+) -> HealthResponse {
+    let path = target.split_once('?').map_or(target, |(path, _)| path);
+    if ![LIVENESS_ROUTE, READINESS_ROUTE, METRICS_ROUTE].contains(&path) {
+        return HealthResponse::text(404, "unknown route");
+    }
+    if method != "GET" && method != "HEAD" {
+        return HealthResponse::text(405, "only GET is supported");
+    }
+
+    match path {
+        LIVENESS_ROUTE => match health.liveness_check().await {
+            true => HealthResponse::text(200, "ok"),
+            false => HealthResponse::text(503, "not alive"),
+        },
+        READINESS_ROUTE => {
+            if shutdown_state.is_some_and(|state| state.is_shutdown_initiated()) {
+                HealthResponse::text(503, "shutting down")
+            } else if server.get_connection_manager().is_draining() {
+                HealthResponse::text(503, "draining")
+            } else if health.readiness_check(server).await {
+                HealthResponse::text(200, "ready")
+            } else {
+                HealthResponse::text(503, "not ready")
+            }
+        }
+        _ => HealthResponse {
+            status: 200,
+            content_type: PROMETHEUS_CONTENT_TYPE,
+            body: health.get_prometheus_metrics(server).await,
+        },
+    }
+}
+
+/// Accepts health and metrics requests until shutdown completes.
+///
+/// Requests keep being answered while shutdown is in progress so probes see
+/// the server as not ready rather than unreachable.
+///
+/// # Arguments
+///
+/// * `listener` - Bound health listener
+/// * `health` - Health manager computing the results
+/// * `server` - Server being probed
+/// * `shutdown_state` - Optional shutdown state for coordinated shutdown
+pub async fn serve_health(
+    listener: TcpListener,
+    health: Arc<HealthManager>,
+    server: Arc<GameServer>,
+    shutdown_state: Option<ShutdownState>,
+) {
+    loop {
+        if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_complete()) {
+            break;
+        }
+
+        let (stream, addr) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                warn!("🩺 Health: Failed to accept connection: {}", e);
+                continue;
+            }
+        };
+
+        let (health, server, shutdown_state) = (health.clone(), server.clone(), shutdown_state.clone());
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &health, &server, shutdown_state.as_ref()).await {
+                debug!("🩺 Health: Connection from {} failed: {}", addr, e);
+            }
+        });
+    }
+}
+
+/// Reads one request from a connection and writes its response
+async fn handle_connection(
+    mut stream: TcpStream,
+    health: &HealthManager,
+    server: &GameServer,
+    shutdown_state: Option<&ShutdownState>,
+) -> std::io::Result<()> {
+    let mut head = Vec::with_capacity(512);
+    let mut buffer = [0u8; 512];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        if head.len() >= MAX_REQUEST_HEAD {
+            return stream.write_all(&HealthResponse::text(400, "request head too large").to_http()).await;
+        }
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+
+    let request = String::from_utf8_lossy(&head);
+    let mut request_line = request.lines().next().unwrap_or_default().split_whitespace();
+    let (method, response) = match (request_line.next(), request_line.next()) {
+        (Some(method), Some(target)) => (method, route(method, target, health, server, shutdown_state).await),
+        _ => ("GET", HealthResponse::text(400, "malformed request line")),
+    };
+
+    let mut http = response.to_http();
+    if method == "HEAD" {
+        let head_len = http.windows(4).position(|window| window == b"\r\n\r\n").map_or(http.len(), |position| position + 4);
+        http.truncate(head_len);
+    }
+    stream.write_all(&http).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_server;
+
+    async fn get(address: std::net::SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(address).await.unwrap();
+        stream
+            .write_all(format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_probes_and_metrics_are_served() {
+        let server = Arc::new(create_server());
+        let shutdown_state = ShutdownState::new();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(serve_health(listener, Arc::new(HealthManager::new()), server.clone(), Some(shutdown_state.clone())));
+
+        assert!(get(address, LIVENESS_ROUTE).await.starts_with("HTTP/1.1 200 OK"));
+
+        // No plugins are loaded, so the server isn't ready for players
+        let response = get(address, READINESS_ROUTE).await;
+        assert!(response.starts_with("HTTP/1.1 503"));
+        assert!(response.ends_with("not ready\n"));
+
+        let response = get(address, METRICS_ROUTE).await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains(PROMETHEUS_CONTENT_TYPE));
+        assert!(response.contains("\nhorizon_server_plugins_loaded 0\n"));
+
+        assert!(get(address, "/unknown").await.starts_with("HTTP/1.1 404"));
+
+        server.get_connection_manager().set_draining(true);
+        assert!(get(address, READINESS_ROUTE).await.ends_with("draining\n"));
+
+        shutdown_state.initiate_shutdown();
+        assert!(get(address, READINESS_ROUTE).await.ends_with("shutting down\n"));
+        assert!(get(address, LIVENESS_ROUTE).await.starts_with("HTTP/1.1 200 OK"));
+    }
+}
@@ -1,6 +1,9 @@
 //! Health check and monitoring endpoints for production deployment.
 
-use crate::GameServer;
+use crate::server::listeners::AcceptLoopStats;
+use crate::{GameServer, LaneStats};
+use horizon_event_system::{DetailedEventSystemStats, EventKeyStats, EventSystem};
+use plugin_system::{PluginHealth, PluginHealthSnapshot};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
@@ -9,6 +12,7 @@ use sysinfo::{System, Pid};
 
 pub mod metrics;
 pub mod circuit_breaker;
+pub mod endpoint;
 
 /// Health check manager for monitoring server status
 #[derive(Debug)]
@@ -27,7 +31,17 @@ pub struct HealthCheckResult {
     pub memory_usage_mb: u64,
     pub active_connections: usize,
     pub plugin_count: usize,
+    pub plugin_health: Vec<PluginHealthSnapshot>,
     pub event_system_health: EventSystemHealth,
+    /// Depth of the client message priority lanes, highest priority first
+    #[serde(default)]
+    pub message_lanes: Vec<LaneStats>,
+    /// Connections accepted by each accept loop of the WebSocket listeners
+    #[serde(default)]
+    pub accept_loops: Vec<AcceptLoopStats>,
+    /// Breakers of plugin handlers that have failed, by plugin and event key
+    #[serde(default)]
+    pub plugin_circuit_breakers: Vec<circuit_breaker::CircuitBreakerStats>,
     pub errors: Vec<String>,
     pub warnings: Vec<String>,
 }
@@ -40,13 +54,63 @@ pub enum HealthStatus {
     Unhealthy,
 }
 
+/// Number of slowest event keys reported in a health check
+const SLOWEST_EVENT_KEYS: usize = 10;
+
+/// Share of failed events above which the event system is reported as degraded
+const EVENT_ERROR_RATE_WARNING: f64 = 0.05;
+
 /// Health information for the event system
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventSystemHealth {
     pub total_handlers: usize,
+    /// Events dispatched to at least one handler
     pub events_processed: u64,
+    /// Dispatched events for which at least one handler failed or panicked
     pub failed_events: u64,
+    /// Share of dispatched events that failed, from 0.0 to 1.0
+    #[serde(default)]
+    pub error_rate: f64,
+    /// Mean handler run time across all event keys
     pub average_event_time_ms: f64,
+    /// Event keys with the highest p99 handler latency, slowest first
+    #[serde(default)]
+    pub slowest_event_keys: Vec<EventKeyStats>,
+}
+
+impl EventSystemHealth {
+    /// Summarizes the event system's throughput counters and per-key statistics
+    fn collect(event_system: &EventSystem, stats: DetailedEventSystemStats) -> Self {
+        let events_processed = event_system.events_processed();
+        let failed_events = event_system.events_failed();
+        let invocations: u64 = stats.event_keys.iter().map(|key| key.invocations).sum();
+        let total_latency_us: f64 = stats
+            .event_keys
+            .iter()
+            .map(|key| key.mean_latency_us * key.invocations as f64)
+            .sum();
+
+        let mut slowest_event_keys = stats.event_keys;
+        slowest_event_keys.sort_by_key(|key| std::cmp::Reverse(key.p99_latency_us));
+        slowest_event_keys.truncate(SLOWEST_EVENT_KEYS);
+
+        Self {
+            total_handlers: stats.base.total_handlers,
+            events_processed,
+            failed_events,
+            error_rate: if events_processed == 0 {
+                0.0
+            } else {
+                failed_events as f64 / events_processed as f64
+            },
+            average_event_time_ms: if invocations == 0 {
+                0.0
+            } else {
+                total_latency_us / invocations as f64 / 1000.0
+            },
+            slowest_event_keys,
+        }
+    }
 }
 
 impl HealthManager {
@@ -73,27 +137,46 @@ impl HealthManager {
         // Get plugin information
         let plugin_manager = server.get_plugin_manager();
         let plugin_count = plugin_manager.plugin_count();
+        let plugin_health = plugin_manager.plugin_health_snapshots();
         
         // Get event system statistics
         let event_system = server.get_horizon_event_system();
-        let event_stats = event_system.get_stats().await;
-        
-        let event_system_health = EventSystemHealth {
-            total_handlers: event_stats.total_handlers,
-            events_processed: 0, // Would need to track this in event system
-            failed_events: 0,    // Would need to track this in event system
-            average_event_time_ms: 0.0, // Would need performance metrics
-        };
+        let event_system_health = EventSystemHealth::collect(&event_system, event_system.get_detailed_stats().await);
+        let message_lanes = server.get_message_lanes().stats();
+        let accept_loops = server.get_accept_loops().stats();
+        let plugin_circuit_breakers = server.get_plugin_circuit_breakers().get_stats().await;
         
         // Check for issues
         if plugin_count == 0 {
             warnings.push("No plugins loaded".to_string());
         }
         
-        if event_stats.total_handlers == 0 {
+        for plugin in &plugin_health {
+            let detail = plugin.detail.as_deref().unwrap_or("no reason given");
+            match plugin.health {
+                PluginHealth::Healthy => {}
+                PluginHealth::Degraded | PluginHealth::Unresponsive => {
+                    warnings.push(format!("Plugin '{}' is {:?}: {}", plugin.name, plugin.health, detail));
+                }
+                PluginHealth::Failed => {
+                    errors.push(format!("Plugin '{}' has failed: {}", plugin.name, detail));
+                }
+            }
+        }
+        
+        if event_system_health.total_handlers == 0 {
             warnings.push("No event handlers registered".to_string());
         }
         
+        if event_system_health.error_rate > EVENT_ERROR_RATE_WARNING {
+            warnings.push(format!(
+                "{} of {} events had failing handlers ({:.1}%)",
+                event_system_health.failed_events,
+                event_system_health.events_processed,
+                event_system_health.error_rate * 100.0
+            ));
+        }
+        
         if memory_usage_mb > 1024 { // More than 1GB
             warnings.push(format!("High memory usage: {}MB", memory_usage_mb));
         }
@@ -102,6 +185,12 @@ impl HealthManager {
             errors.push(format!("Critical memory usage: {}MB", memory_usage_mb));
         }
         
+        for breaker in &plugin_circuit_breakers {
+            if breaker.state == circuit_breaker::CircuitBreakerState::Open {
+                warnings.push(format!("Plugin handler '{}' is short-circuited after repeated failures", breaker.name));
+            }
+        }
+        
         // Check circuit breakers
         let circuit_breakers = self.circuit_breakers.read().await;
         for cb in circuit_breakers.iter() {
@@ -129,7 +218,11 @@ impl HealthManager {
             memory_usage_mb,
             active_connections: 0, // Would need connection manager stats
             plugin_count,
+            plugin_health,
             event_system_health,
+            message_lanes,
+            accept_loops,
+            plugin_circuit_breakers,
             errors,
             warnings,
         };
@@ -228,7 +321,7 @@ impl HealthManager {
             HealthStatus::Unhealthy => 0.0,
         };
         
-        format!(
+        let mut metrics = format!(
             "# HELP horizon_server_health Overall server health status\n\
              # TYPE horizon_server_health gauge\n\
              horizon_server_health {}\n\
@@ -243,13 +336,82 @@ impl HealthManager {
              horizon_server_plugins_loaded {}\n\
              # HELP horizon_server_event_handlers Total event handlers registered\n\
              # TYPE horizon_server_event_handlers gauge\n\
-             horizon_server_event_handlers {}\n",
+             horizon_server_event_handlers {}\n\
+             # HELP horizon_server_events_processed Events dispatched to handlers\n\
+             # TYPE horizon_server_events_processed counter\n\
+             horizon_server_events_processed {}\n\
+             # HELP horizon_server_event_handler_failures Handler invocations that failed or panicked\n\
+             # TYPE horizon_server_event_handler_failures counter\n\
+             horizon_server_event_handler_failures {}\n",
             status_value,
             health_check.uptime_seconds,
             health_check.memory_usage_mb,
             health_check.plugin_count,
-            health_check.event_system_health.total_handlers
-        )
+            health_check.event_system_health.total_handlers,
+            health_check.event_system_health.events_processed,
+            health_check.event_system_health.failed_events
+        );
+
+        metrics.push_str(
+            "# HELP horizon_server_message_lane_depth Client messages waiting in each priority lane\n\
+             # TYPE horizon_server_message_lane_depth gauge\n",
+        );
+        for lane in &health_check.message_lanes {
+            metrics.push_str(&format!("horizon_server_message_lane_depth{{lane=\"{}\"}} {}\n", lane.lane.name(), lane.depth));
+        }
+        metrics.push_str(
+            "# HELP horizon_server_message_lane_dispatched Client messages routed from each priority lane\n\
+             # TYPE horizon_server_message_lane_dispatched counter\n",
+        );
+        for lane in &health_check.message_lanes {
+            metrics.push_str(&format!(
+                "horizon_server_message_lane_dispatched{{lane=\"{}\"}} {}\n",
+                lane.lane.name(),
+                lane.dispatched
+            ));
+        }
+        metrics.push_str(
+            "# HELP horizon_server_accept_loop_accepted Connections accepted by each accept loop\n\
+             # TYPE horizon_server_accept_loop_accepted counter\n",
+        );
+        for accept_loop in &health_check.accept_loops {
+            metrics.push_str(&format!(
+                "horizon_server_accept_loop_accepted{{address=\"{}\",loop=\"{}\"}} {}\n",
+                accept_loop.address, accept_loop.accept_loop, accept_loop.accepted
+            ));
+        }
+        metrics.push_str(
+            "# HELP horizon_server_accept_loop_errors Accepts that failed in each accept loop\n\
+             # TYPE horizon_server_accept_loop_errors counter\n",
+        );
+        for accept_loop in &health_check.accept_loops {
+            metrics.push_str(&format!(
+                "horizon_server_accept_loop_errors{{address=\"{}\",loop=\"{}\"}} {}\n",
+                accept_loop.address, accept_loop.accept_loop, accept_loop.errors
+            ));
+        }
+        metrics.push_str(
+            "# HELP horizon_server_accept_loop_throttled Connections each accept loop closed for exceeding the connection throttle\n\
+             # TYPE horizon_server_accept_loop_throttled counter\n",
+        );
+        for accept_loop in &health_check.accept_loops {
+            metrics.push_str(&format!(
+                "horizon_server_accept_loop_throttled{{address=\"{}\",loop=\"{}\"}} {}\n",
+                accept_loop.address, accept_loop.accept_loop, accept_loop.throttled
+            ));
+        }
+        let open_breakers = health_check
+            .plugin_circuit_breakers
+            .iter()
+            .filter(|breaker| breaker.state == circuit_breaker::CircuitBreakerState::Open)
+            .count();
+        metrics.push_str(&format!(
+            "# HELP horizon_server_open_plugin_circuit_breakers Plugin handlers short-circuited after repeated failures\n\
+             # TYPE horizon_server_open_plugin_circuit_breakers gauge\n\
+             horizon_server_open_plugin_circuit_breakers {}\n",
+            open_breakers
+        ));
+        metrics
     }
 }
 
@@ -274,12 +436,82 @@ mod tests {
         // Basic assertions
         assert!(result.uptime_seconds < 60); // Should be very small for new server
         assert_eq!(result.plugin_count, 0); // No plugins loaded in test
+        assert!(result.plugin_health.is_empty());
         
         // Status should be degraded due to no plugins
         assert_eq!(result.status, HealthStatus::Degraded);
         assert!(!result.warnings.is_empty());
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_health_check_reports_event_failures() {
+        let health_manager = HealthManager::new();
+        let server = create_server();
+        let events = server.get_horizon_event_system();
+        events
+            .on_core("health_probe", |event: serde_json::Value| {
+                event["ok"]
+                    .as_bool()
+                    .filter(|ok| *ok)
+                    .map(|_| ())
+                    .ok_or_else(|| horizon_event_system::EventError::HandlerExecution("probe failed".to_string()))
+            })
+            .await
+            .unwrap();
+        events.emit_core("health_probe", &serde_json::json!({ "ok": true })).await.unwrap();
+        events.emit_core("health_probe", &serde_json::json!({ "ok": false })).await.unwrap();
+
+        let result = health_manager.perform_health_check(&server).await;
+        let health = &result.event_system_health;
+        assert_eq!((health.events_processed, health.failed_events), (2, 1));
+        assert_eq!(health.error_rate, 0.5);
+        assert_eq!(health.slowest_event_keys[0].event_key, "core:health_probe");
+        assert!(result.warnings.iter().any(|warning| warning.contains("failing handlers")));
+
+        let metrics = health_manager.get_prometheus_metrics(&server).await;
+        assert!(metrics.contains("horizon_server_event_handler_failures 1\n"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_accept_loops_are_reported() {
+        let health_manager = HealthManager::new();
+        let server = create_server();
+        let address = "0.0.0.0:8080".parse().unwrap();
+        server.get_accept_loops().register(address, true);
+        server.get_accept_loops().register(address, true).record_accepted();
+
+        let result = health_manager.perform_health_check(&server).await;
+        assert_eq!(result.accept_loops.iter().map(|stats| stats.accepted).collect::<Vec<_>>(), vec![0, 1]);
+        let metrics = health_manager.get_prometheus_metrics(&server).await;
+        assert!(metrics.contains("horizon_server_accept_loop_accepted{address=\"0.0.0.0:8080\",loop=\"1\"} 1\n"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_failing_plugin_handler_is_short_circuited() {
+        let health_manager = HealthManager::new();
+        let server = create_server();
+        let events = server.get_horizon_event_system();
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = calls.clone();
+        let registration = events.on_core("breaker_probe", move |_: serde_json::Value| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Err(horizon_event_system::EventError::HandlerExecution("always fails".to_string()))
+        });
+        events.register_as("flaky", registration).await.unwrap();
+
+        // The default breaker opens after five consecutive failures
+        for _ in 0..8 {
+            events.emit_core("breaker_probe", &serde_json::json!({})).await.unwrap();
+        }
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 5);
+
+        let result = health_manager.perform_health_check(&server).await;
+        assert_eq!(result.plugin_circuit_breakers.len(), 1);
+        assert_eq!(result.plugin_circuit_breakers[0].name, "flaky core:breaker_probe");
+        assert_eq!(result.plugin_circuit_breakers[0].state, circuit_breaker::CircuitBreakerState::Open);
+        assert!(result.warnings.iter().any(|warning| warning.contains("short-circuited")));
+    }
+
     #[tokio::test]
     async fn test_liveness_check() {
         let health_manager = HealthManager::new();
@@ -0,0 +1,258 @@
+//! Persistent links between external identities and players.
+//!
+//! Links are kept in memory for fast lookups on every login and, when the
+//! store has a storage backend, written through to it on every change so a
+//! player keeps its ID across restarts.
+
+use super::{ExternalIdentity, IdentityError};
+use horizon_event_system::{current_timestamp, PlayerId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// An external identity linked to a player
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkedAccount {
+    /// Name of the identity provider
+    pub provider: String,
+    /// The provider's ID of the user
+    pub subject: String,
+    /// Player the identity logs in as
+    pub player_id: PlayerId,
+    /// Name the user last went by at the provider
+    pub display_name: Option<String>,
+    /// Unix timestamp when the identity was linked
+    pub linked_at: u64,
+    /// Unix timestamp of the identity's last login
+    pub last_login: u64,
+}
+
+impl LinkedAccount {
+    /// Creates a link made now
+    fn new(identity: &ExternalIdentity, player_id: PlayerId) -> Self {
+        let now = current_timestamp();
+        Self {
+            provider: identity.provider.clone(),
+            subject: identity.subject.clone(),
+            player_id,
+            display_name: identity.display_name.clone(),
+            linked_at: now,
+            last_login: now,
+        }
+    }
+}
+
+/// Backend the account links are persisted to
+pub trait AccountStorage: std::fmt::Debug + Send + Sync {
+    /// Loads every stored link
+    fn load(&self) -> Result<Vec<LinkedAccount>, IdentityError>;
+
+    /// Replaces the stored links
+    fn save(&self, accounts: &[LinkedAccount]) -> Result<(), IdentityError>;
+}
+
+/// Account links stored as a JSON file
+#[derive(Debug, Clone)]
+pub struct FileAccountStorage {
+    path: PathBuf,
+}
+
+impl FileAccountStorage {
+    /// Creates a storage backend for the file at `path`, which doesn't need to exist yet
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Gets the path of the account links file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AccountStorage for FileAccountStorage {
+    fn load(&self) -> Result<Vec<LinkedAccount>, IdentityError> {
+        match std::fs::read(&self.path) {
+            Ok(contents) => Ok(serde_json::from_slice(&contents)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Writes the file next to its destination and renames it into place,
+    /// so a crash mid-save never loses every player's link.
+    fn save(&self, accounts: &[LinkedAccount]) -> Result<(), IdentityError> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let temp_path = self.path.with_extension("tmp");
+        std::fs::write(&temp_path, serde_json::to_vec_pretty(accounts)?)?;
+        std::fs::rename(&temp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Provider name and subject an account is looked up by
+type AccountKey = (String, String);
+
+/// Links between external identities and players, optionally backed by persistent storage
+#[derive(Debug, Default)]
+pub struct AccountLinks {
+    accounts: std::sync::RwLock<HashMap<AccountKey, LinkedAccount>>,
+    storage: Option<Box<dyn AccountStorage>>,
+}
+
+impl AccountLinks {
+    /// Creates an empty set of links that only lives in memory
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens the links persisted to the given storage
+    ///
+    /// # Errors
+    ///
+    /// Fails if the stored links can't be read.
+    pub fn open(storage: Box<dyn AccountStorage>) -> Result<Self, IdentityError> {
+        let accounts = storage
+            .load()?
+            .into_iter()
+            .map(|account| ((account.provider.clone(), account.subject.clone()), account))
+            .collect();
+        Ok(Self {
+            accounts: std::sync::RwLock::new(accounts),
+            storage: Some(storage),
+        })
+    }
+
+    /// Gets the player an identity logs in as, linking it to a new player on its first login.
+    ///
+    /// The link's display name and last login are brought up to date.
+    pub fn resolve(&self, identity: &ExternalIdentity) -> PlayerId {
+        let player_id = {
+            let mut accounts = self.write();
+            let account = accounts
+                .entry((identity.provider.clone(), identity.subject.clone()))
+                .or_insert_with(|| LinkedAccount::new(identity, PlayerId::new()));
+            account.last_login = current_timestamp();
+            if identity.display_name.is_some() {
+                account.display_name = identity.display_name.clone();
+            }
+            account.player_id
+        };
+        self.persist();
+        player_id
+    }
+
+    /// Links another identity to an existing player, so it logs in as that player.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the identity is already linked to a different player.
+    pub fn link(&self, identity: &ExternalIdentity, player_id: PlayerId) -> Result<(), IdentityError> {
+        {
+            let mut accounts = self.write();
+            let key = (identity.provider.clone(), identity.subject.clone());
+            match accounts.get(&key) {
+                Some(account) if account.player_id == player_id => return Ok(()),
+                Some(account) => return Err(IdentityError::AlreadyLinked(identity.to_string(), account.player_id)),
+                None => accounts.insert(key, LinkedAccount::new(identity, player_id)),
+            };
+        }
+        self.persist();
+        Ok(())
+    }
+
+    /// Removes the link of an identity
+    ///
+    /// # Returns
+    ///
+    /// The player the identity was linked to, if it was.
+    pub fn unlink(&self, provider: &str, subject: &str) -> Option<PlayerId> {
+        let unlinked = self
+            .write()
+            .remove(&(provider.to_string(), subject.to_string()))
+            .map(|account| account.player_id);
+        if unlinked.is_some() {
+            self.persist();
+        }
+        unlinked
+    }
+
+    /// Gets the link of an identity
+    pub fn get(&self, provider: &str, subject: &str) -> Option<LinkedAccount> {
+        self.read().get(&(provider.to_string(), subject.to_string())).cloned()
+    }
+
+    /// Gets every identity linked to a player, oldest link first
+    pub fn accounts_of(&self, player_id: PlayerId) -> Vec<LinkedAccount> {
+        let mut accounts: Vec<LinkedAccount> = self
+            .read()
+            .values()
+            .filter(|account| account.player_id == player_id)
+            .cloned()
+            .collect();
+        accounts.sort_by(|a, b| (a.linked_at, &a.provider).cmp(&(b.linked_at, &b.provider)));
+        accounts
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<AccountKey, LinkedAccount>> {
+        self.accounts.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<AccountKey, LinkedAccount>> {
+        self.accounts.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Writes the links through to storage. A failed write keeps the link in
+    /// effect for this run, so it is logged rather than surfaced.
+    fn persist(&self) {
+        let Some(storage) = &self.storage else {
+            return;
+        };
+        let accounts: Vec<LinkedAccount> = self.read().values().cloned().collect();
+        if let Err(e) = storage.save(&accounts) {
+            warn!("🪪 Failed to save the account links: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(provider: &str, subject: &str) -> ExternalIdentity {
+        ExternalIdentity {
+            provider: provider.to_string(),
+            subject: subject.to_string(),
+            display_name: Some(format!("{subject} at {provider}")),
+            claims: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn test_links_persist_and_join_identities() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("accounts.json");
+        let accounts = AccountLinks::open(Box::new(FileAccountStorage::new(&path))).unwrap();
+
+        let player_id = accounts.resolve(&identity("steam", "7656"));
+        accounts.link(&identity("discord", "8812"), player_id).unwrap();
+        let other = accounts.resolve(&identity("discord", "1234"));
+        assert_ne!(other, player_id);
+        assert!(matches!(
+            accounts.link(&identity("discord", "1234"), player_id),
+            Err(IdentityError::AlreadyLinked(_, linked)) if linked == other
+        ));
+
+        let reopened = AccountLinks::open(Box::new(FileAccountStorage::new(&path))).unwrap();
+        assert_eq!(reopened.resolve(&identity("discord", "8812")), player_id);
+        assert_eq!(reopened.accounts_of(player_id).len(), 2);
+        assert_eq!(reopened.get("steam", "7656").unwrap().display_name.as_deref(), Some("7656 at steam"));
+
+        assert_eq!(reopened.unlink("discord", "1234"), Some(other));
+        let reopened = AccountLinks::open(Box::new(FileAccountStorage::new(&path))).unwrap();
+        assert!(reopened.get("discord", "1234").is_none());
+    }
+}
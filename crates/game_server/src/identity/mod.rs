@@ -0,0 +1,287 @@
+//! Login through external identity providers.
+//!
+//! Games rarely want to run their own account system. With providers
+//! configured, a WebSocket client logs in right after connecting with
+//!
+//! ```json
+//! { "type": "login", "provider": "discord", "token": "<provider token>" }
+//! ```
+//!
+//! The token is exchanged with the provider for the identity it belongs to,
+//! and that identity is linked to a persistent [`PlayerId`]. The first login
+//! of an identity creates its player; every later login, from any device,
+//! binds the connection to the same player, so plugins can key saved state
+//! on the player ID without doing any account linking themselves.
+//!
+//! Several identities can be linked to one player with
+//! [`AccountLinks::link`], e.g. so a player can log in through Steam and
+//! Discord alike.
+
+pub mod accounts;
+pub mod providers;
+
+pub use accounts::{AccountLinks, AccountStorage, FileAccountStorage, LinkedAccount};
+pub use providers::{DiscordProvider, OidcProvider, SteamProvider};
+
+use crate::config::{AuthConfig, IdentityProviderConfig};
+use async_trait::async_trait;
+use horizon_event_system::PlayerId;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Errors raised while logging in through an identity provider
+#[derive(Debug, Error)]
+pub enum IdentityError {
+    /// No provider of that name is configured
+    #[error("Unknown identity provider '{0}'")]
+    UnknownProvider(String),
+    /// The provider didn't accept the token
+    #[error("Token rejected: {0}")]
+    Rejected(String),
+    /// The provider couldn't be asked about the token
+    #[error("Identity provider unavailable: {0}")]
+    Unavailable(String),
+    /// A signed identity token is invalid
+    #[error("Invalid identity token: {0}")]
+    InvalidToken(#[from] jsonwebtoken::errors::Error),
+    /// The identity is already linked to another player
+    #[error("{0} is already linked to player {1}")]
+    AlreadyLinked(String, PlayerId),
+    /// Reading or writing a file failed
+    #[error("Identity I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A file or response could not be encoded or decoded
+    #[error("Identity serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl From<reqwest::Error> for IdentityError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Unavailable(error.to_string())
+    }
+}
+
+/// An identity a provider vouched for
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalIdentity {
+    /// Name of the provider
+    pub provider: String,
+    /// The provider's stable ID of the user
+    pub subject: String,
+    /// Name the user goes by at the provider, if it shares one
+    pub display_name: Option<String>,
+    /// Everything the provider said about the user
+    pub claims: serde_json::Value,
+}
+
+impl std::fmt::Display for ExternalIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} user {}", self.provider, self.subject)
+    }
+}
+
+/// A provider that exchanges client tokens for the identity they belong to
+#[async_trait]
+pub trait IdentityProvider: std::fmt::Debug + Send + Sync {
+    /// Gets the name clients log in with
+    fn name(&self) -> &str;
+
+    /// Verifies a client's token with the provider
+    ///
+    /// # Returns
+    ///
+    /// The identity the token belongs to, or an `IdentityError` if it was rejected.
+    async fn verify(&self, token: &str) -> Result<ExternalIdentity, IdentityError>;
+}
+
+/// Identity providers clients log in through and the players their identities are linked to
+#[derive(Debug, Default)]
+pub struct IdentityService {
+    providers: HashMap<String, Box<dyn IdentityProvider>>,
+    accounts: AccountLinks,
+}
+
+impl IdentityService {
+    /// Creates a service without providers that links identities to players in `accounts`
+    pub fn new(accounts: AccountLinks) -> Self {
+        Self {
+            providers: HashMap::new(),
+            accounts,
+        }
+    }
+
+    /// Creates a service with the configured providers
+    ///
+    /// # Returns
+    ///
+    /// The service, or an `IdentityError` if a provider's keys can't be loaded.
+    pub fn from_config(config: &AuthConfig, accounts: AccountLinks) -> Result<Self, IdentityError> {
+        let mut service = Self::new(accounts);
+        for provider in &config.providers {
+            let provider: Box<dyn IdentityProvider> = match provider {
+                IdentityProviderConfig::Oidc { name, issuer, client_id, jwks_uri, jwks_path } => {
+                    let mut provider = OidcProvider::new(name, issuer, client_id);
+                    if let Some(jwks_uri) = jwks_uri {
+                        provider = provider.with_jwks_uri(jwks_uri);
+                    }
+                    if let Some(jwks_path) = jwks_path {
+                        provider = provider.with_jwks(serde_json::from_slice(&std::fs::read(jwks_path)?)?);
+                    }
+                    Box::new(provider)
+                }
+                IdentityProviderConfig::Discord { name, client_id, api_url } => {
+                    Box::new(DiscordProvider::new(name, client_id, api_url))
+                }
+                IdentityProviderConfig::Steam { name, app_id, api_key, api_url } => {
+                    Box::new(SteamProvider::new(name, *app_id, api_key, api_url))
+                }
+            };
+            service = service.with_provider(provider);
+        }
+        Ok(service)
+    }
+
+    /// Adds a provider, replacing any provider of the same name
+    pub fn with_provider(mut self, provider: Box<dyn IdentityProvider>) -> Self {
+        self.providers.insert(provider.name().to_string(), provider);
+        self
+    }
+
+    /// Checks whether any provider is configured
+    pub fn has_providers(&self) -> bool {
+        !self.providers.is_empty()
+    }
+
+    /// Gets the links between identities and players
+    pub fn accounts(&self) -> &AccountLinks {
+        &self.accounts
+    }
+
+    /// Logs a client in with a provider token.
+    ///
+    /// An identity logging in for the first time is linked to a new player.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Name of the provider that issued the token
+    /// * `token` - The client's provider token
+    ///
+    /// # Returns
+    ///
+    /// The identity's player and the identity, or an `IdentityError` if the
+    /// provider is unknown or rejected the token.
+    pub async fn login(&self, provider: &str, token: &str) -> Result<(PlayerId, ExternalIdentity), IdentityError> {
+        let identity = self.verify(provider, token).await?;
+        let player_id = self.accounts.resolve(&identity);
+        Ok((player_id, identity))
+    }
+
+    /// Asks a provider whose token a client presented
+    async fn verify(&self, provider: &str, token: &str) -> Result<ExternalIdentity, IdentityError> {
+        self.providers
+            .get(provider)
+            .ok_or_else(|| IdentityError::UnknownProvider(provider.to_string()))?
+            .verify(token)
+            .await
+    }
+
+    /// Links the identity a provider token belongs to to an existing player,
+    /// such as a guest upgrading to an account.
+    ///
+    /// # Returns
+    ///
+    /// The identity, or an `IdentityError` if the provider is unknown,
+    /// rejected the token or the identity is linked to another player.
+    pub async fn link(&self, provider: &str, token: &str, player_id: PlayerId) -> Result<ExternalIdentity, IdentityError> {
+        let identity = self.verify(provider, token).await?;
+        self.accounts.link(&identity, player_id)?;
+        Ok(identity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Provider accepting tokens of the form `user:<subject>`
+    #[derive(Debug)]
+    struct StaticProvider;
+
+    #[async_trait]
+    impl IdentityProvider for StaticProvider {
+        fn name(&self) -> &str {
+            "static"
+        }
+
+        async fn verify(&self, token: &str) -> Result<ExternalIdentity, IdentityError> {
+            let subject = token.strip_prefix("user:").ok_or_else(|| IdentityError::Rejected("bad token".to_string()))?;
+            Ok(ExternalIdentity {
+                provider: self.name().to_string(),
+                subject: subject.to_string(),
+                display_name: None,
+                claims: serde_json::Value::Null,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_logins_resolve_to_persistent_players() {
+        let identity = IdentityService::new(AccountLinks::new()).with_provider(Box::new(StaticProvider));
+
+        let (first, _) = identity.login("static", "user:alice").await.unwrap();
+        let (again, account) = identity.login("static", "user:alice").await.unwrap();
+        let (other, _) = identity.login("static", "user:bob").await.unwrap();
+        assert_eq!(first, again);
+        assert_ne!(first, other);
+        assert_eq!(account.to_string(), "static user alice");
+
+        assert!(matches!(identity.login("static", "forged").await, Err(IdentityError::Rejected(_))));
+        assert!(matches!(identity.login("steam", "user:alice").await, Err(IdentityError::UnknownProvider(_))));
+    }
+
+    #[tokio::test]
+    async fn test_clients_log_in_as_their_linked_player() {
+        use crate::config::MessageLaneConfig;
+        use crate::connection::ConnectionManager;
+        use crate::messaging::MessageLanes;
+        use crate::server::handlers::handle_connection;
+        use futures::{SinkExt, StreamExt};
+        use horizon_event_system::EventSystem;
+        use std::sync::Arc;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let identity = IdentityService::new(AccountLinks::new()).with_provider(Box::new(StaticProvider));
+        let connection_manager = Arc::new(ConnectionManager::new().with_identity(Arc::new(identity)));
+        let events = Arc::new(EventSystem::new());
+        let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server_connections = connection_manager.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, addr)) = listener.accept().await {
+                let (connection_manager, events, message_lanes) = (server_connections.clone(), events.clone(), message_lanes.clone());
+                tokio::spawn(handle_connection(stream, addr, connection_manager, events, message_lanes, None));
+            }
+        });
+        let log_in = |provider: &'static str, token: &'static str| async move {
+            let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{address}/")).await.unwrap();
+            let login = serde_json::json!({ "type": "login", "provider": provider, "token": token });
+            client.send(Message::Text(login.to_string().into())).await.unwrap();
+            match client.next().await {
+                Some(Ok(Message::Text(text))) => serde_json::from_str::<serde_json::Value>(&text).unwrap(),
+                other => panic!("expected a text message, got {other:?}"),
+            }
+        };
+
+        let first = log_in("static", "user:alice").await;
+        assert_eq!((first["event"].as_str(), first["provider"].as_str()), (Some("authenticated"), Some("static")));
+        let again = log_in("static", "user:alice").await;
+        assert_eq!(again["player_id"], first["player_id"]);
+        assert_ne!(log_in("static", "user:bob").await["player_id"], first["player_id"]);
+
+        let players = connection_manager.connected_players().await.len();
+        assert_eq!(log_in("discord", "user:alice").await["event"], "auth_failed");
+        // A failed login never gets a player
+        assert!(connection_manager.connected_players().await.len() <= players);
+    }
+}
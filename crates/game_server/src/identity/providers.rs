@@ -0,0 +1,433 @@
+//! Built-in identity providers: OpenID Connect, Discord and Steam.
+
+use super::{ExternalIdentity, IdentityError, IdentityProvider};
+use async_trait::async_trait;
+use jsonwebtoken::jwk::{AlgorithmParameters, EllipticCurve, Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// How long a provider has to answer
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Minimum time between fetches of an OIDC provider's keys, so tokens
+/// naming unknown keys can't make the server hammer the provider
+const JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+fn http_client() -> reqwest::Client {
+    reqwest::Client::builder().timeout(HTTP_TIMEOUT).build().unwrap_or_default()
+}
+
+/// OpenID Connect provider verifying the ID tokens it issued to the game.
+///
+/// The provider's signing keys are fetched from its JWKS URL, found through
+/// the issuer's discovery document unless configured, and fetched again
+/// when a token names a key the server doesn't know yet.
+#[derive(Debug)]
+pub struct OidcProvider {
+    name: String,
+    issuer: String,
+    client_id: String,
+    jwks_uri: Option<String>,
+    keys: tokio::sync::RwLock<JwkSet>,
+    static_keys: bool,
+    last_fetch: tokio::sync::Mutex<Option<Instant>>,
+    http: reqwest::Client,
+}
+
+impl OidcProvider {
+    /// Creates a provider that fetches its keys through discovery
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name clients log in with
+    /// * `issuer` - The provider's issuer URL, the required `iss` claim
+    /// * `client_id` - Client ID of the game, the required `aud` claim
+    pub fn new(name: impl Into<String>, issuer: impl Into<String>, client_id: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            issuer: issuer.into(),
+            client_id: client_id.into(),
+            jwks_uri: None,
+            keys: tokio::sync::RwLock::new(JwkSet { keys: Vec::new() }),
+            static_keys: false,
+            last_fetch: tokio::sync::Mutex::new(None),
+            http: http_client(),
+        }
+    }
+
+    /// Fetches the keys from the given JWKS URL instead of the one found through discovery
+    pub fn with_jwks_uri(mut self, jwks_uri: impl Into<String>) -> Self {
+        self.jwks_uri = Some(jwks_uri.into());
+        self
+    }
+
+    /// Verifies tokens with fixed keys. Without a JWKS URL they are never fetched.
+    pub fn with_jwks(mut self, keys: JwkSet) -> Self {
+        self.keys = tokio::sync::RwLock::new(keys);
+        self.static_keys = true;
+        self
+    }
+
+    /// Finds the key a token was signed with
+    async fn find_key(&self, kid: Option<&str>) -> Option<Jwk> {
+        let keys = self.keys.read().await;
+        match kid {
+            Some(kid) => keys.find(kid).cloned(),
+            None if keys.keys.len() == 1 => keys.keys.first().cloned(),
+            None => None,
+        }
+    }
+
+    /// Fetches the provider's current keys, unless they were fetched recently
+    async fn refresh_keys(&self) -> Result<(), IdentityError> {
+        if self.static_keys && self.jwks_uri.is_none() {
+            return Ok(());
+        }
+        let mut last_fetch = self.last_fetch.lock().await;
+        if last_fetch.is_some_and(|fetched| fetched.elapsed() < JWKS_REFRESH_INTERVAL) {
+            return Ok(());
+        }
+        *last_fetch = Some(Instant::now());
+
+        let jwks_uri = match &self.jwks_uri {
+            Some(jwks_uri) => jwks_uri.clone(),
+            None => self.discover_jwks_uri().await?,
+        };
+        let keys: JwkSet = self.http.get(&jwks_uri).send().await?.error_for_status()?.json().await?;
+        info!("🪪 Fetched {} signing keys of identity provider {}", keys.keys.len(), self.name);
+        *self.keys.write().await = keys;
+        Ok(())
+    }
+
+    /// Looks up the JWKS URL in the issuer's discovery document
+    async fn discover_jwks_uri(&self) -> Result<String, IdentityError> {
+        #[derive(Deserialize)]
+        struct Discovery {
+            jwks_uri: String,
+        }
+
+        let url = format!("{}/.well-known/openid-configuration", self.issuer.trim_end_matches('/'));
+        let discovery: Discovery = self.http.get(url).send().await?.error_for_status()?.json().await?;
+        Ok(discovery.jwks_uri)
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for OidcProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn verify(&self, token: &str) -> Result<ExternalIdentity, IdentityError> {
+        let header = jsonwebtoken::decode_header(token)?;
+        let key = match self.find_key(header.kid.as_deref()).await {
+            Some(key) => key,
+            None => {
+                self.refresh_keys().await?;
+                self.find_key(header.kid.as_deref())
+                    .await
+                    .ok_or_else(|| IdentityError::Rejected("token signed with an unknown key".to_string()))?
+            }
+        };
+
+        // The key decides the algorithm; a header naming another one is refused
+        let algorithm = jwk_algorithm(&key)?;
+        if header.alg != algorithm {
+            return Err(IdentityError::Rejected(format!(
+                "token is signed with {:?} but its key uses {:?}",
+                header.alg, algorithm
+            )));
+        }
+        let mut validation = Validation::new(algorithm);
+        validation.set_required_spec_claims(&["exp", "sub", "iss", "aud"]);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.client_id]);
+        let claims = jsonwebtoken::decode::<serde_json::Map<String, serde_json::Value>>(token, &DecodingKey::from_jwk(&key)?, &validation)?.claims;
+
+        let claim = |name: &str| claims.get(name).and_then(serde_json::Value::as_str).map(str::to_string);
+        Ok(ExternalIdentity {
+            provider: self.name.clone(),
+            subject: claim("sub").unwrap_or_default(),
+            display_name: claim("preferred_username").or_else(|| claim("name")),
+            claims: serde_json::Value::Object(claims),
+        })
+    }
+}
+
+/// Gets the signature algorithm a provider key verifies.
+///
+/// Keys without an `alg` get the usual algorithm of their key type and
+/// curve; symmetric keys are never accepted from a provider.
+fn jwk_algorithm(key: &Jwk) -> Result<Algorithm, IdentityError> {
+    if let Some(key_algorithm) = key.common.key_algorithm {
+        return key_algorithm
+            .to_string()
+            .parse()
+            .map_err(|_| IdentityError::Rejected(format!("key algorithm {key_algorithm} can't verify signatures")));
+    }
+    match &key.algorithm {
+        AlgorithmParameters::RSA(_) => Ok(Algorithm::RS256),
+        AlgorithmParameters::EllipticCurve(params) => match params.curve {
+            EllipticCurve::P256 => Ok(Algorithm::ES256),
+            EllipticCurve::P384 => Ok(Algorithm::ES384),
+            _ => Err(IdentityError::Rejected(format!("unsupported key curve {:?}", params.curve))),
+        },
+        AlgorithmParameters::OctetKeyPair(_) => Ok(Algorithm::EdDSA),
+        AlgorithmParameters::OctetKey(_) => Err(IdentityError::Rejected("symmetric provider keys aren't accepted".to_string())),
+    }
+}
+
+/// Discord OAuth2, verifying access tokens by asking Discord whose they are
+/// and which application they were issued to
+#[derive(Debug)]
+pub struct DiscordProvider {
+    name: String,
+    client_id: String,
+    api_url: String,
+    http: reqwest::Client,
+}
+
+impl DiscordProvider {
+    /// Creates a provider asking the Discord API at `api_url`
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name clients log in with
+    /// * `client_id` - Client ID of the game's Discord application
+    /// * `api_url` - Base URL of the Discord API
+    pub fn new(name: impl Into<String>, client_id: impl Into<String>, api_url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            client_id: client_id.into(),
+            api_url: api_url.into(),
+            http: http_client(),
+        }
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for DiscordProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn verify(&self, token: &str) -> Result<ExternalIdentity, IdentityError> {
+        let url = format!("{}/oauth2/@me", self.api_url.trim_end_matches('/'));
+        let response = self.http.get(url).bearer_auth(token).send().await?;
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            return Err(IdentityError::Rejected("Discord didn't accept the access token".to_string()));
+        }
+        let mut authorization: serde_json::Value = response.error_for_status()?.json().await?;
+
+        // Tokens issued to other applications must not log in to this game
+        if authorization["application"]["id"].as_str() != Some(self.client_id.as_str()) {
+            return Err(IdentityError::Rejected("access token was issued to another application".to_string()));
+        }
+        let user = authorization["user"].take();
+        if !user.is_object() {
+            return Err(IdentityError::Rejected("access token lacks the identify scope".to_string()));
+        }
+
+        let field = |name: &str| user.get(name).and_then(serde_json::Value::as_str).map(str::to_string);
+        let subject = field("id").ok_or_else(|| IdentityError::Unavailable("Discord user has no id".to_string()))?;
+        Ok(ExternalIdentity {
+            provider: self.name.clone(),
+            subject,
+            display_name: field("global_name").or_else(|| field("username")),
+            claims: user,
+        })
+    }
+}
+
+/// Steam, verifying session tickets through the Steam Web API
+pub struct SteamProvider {
+    name: String,
+    app_id: u32,
+    api_key: String,
+    api_url: String,
+    http: reqwest::Client,
+}
+
+impl std::fmt::Debug for SteamProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The publisher key stays out of logs
+        f.debug_struct("SteamProvider")
+            .field("name", &self.name)
+            .field("app_id", &self.app_id)
+            .field("api_url", &self.api_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SteamProvider {
+    /// Creates a provider verifying tickets for an app
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name clients log in with
+    /// * `app_id` - Steam app ID of the game
+    /// * `api_key` - Steam Web API publisher key
+    /// * `api_url` - Base URL of the Steam Web API
+    pub fn new(name: impl Into<String>, app_id: u32, api_key: impl Into<String>, api_url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            app_id,
+            api_key: api_key.into(),
+            api_url: api_url.into(),
+            http: http_client(),
+        }
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for SteamProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn verify(&self, token: &str) -> Result<ExternalIdentity, IdentityError> {
+        let url = format!("{}/ISteamUserAuth/AuthenticateUserTicket/v1/", self.api_url.trim_end_matches('/'));
+        let app_id = self.app_id.to_string();
+        let query = [("key", self.api_key.as_str()), ("appid", app_id.as_str()), ("ticket", token)];
+        let body: serde_json::Value = self.http.get(url).query(&query).send().await?.error_for_status()?.json().await?;
+
+        let response = &body["response"];
+        if let Some(description) = response["error"]["errordesc"].as_str() {
+            return Err(IdentityError::Rejected(format!("Steam didn't accept the ticket: {description}")));
+        }
+        let params = &response["params"];
+        match (params["result"].as_str(), params["steamid"].as_str()) {
+            (Some("OK"), Some(steam_id)) => Ok(ExternalIdentity {
+                provider: self.name.clone(),
+                subject: steam_id.to_string(),
+                display_name: None,
+                claims: params.clone(),
+            }),
+            _ => Err(IdentityError::Rejected("Steam didn't accept the ticket".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use horizon_event_system::current_timestamp;
+    use jsonwebtoken::{EncodingKey, Header};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Serves the response of the first route whose pattern the request contains, one request per connection
+    async fn serve(routes: Vec<(String, serde_json::Value)>) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = vec![0; 4096];
+                let read = stream.read(&mut request).await.unwrap();
+                let request = String::from_utf8_lossy(&request[..read]);
+                let (status, body) = routes
+                    .iter()
+                    .find(|(pattern, _)| request.contains(pattern.as_str()))
+                    .map_or((401, "{}".to_string()), |(_, body)| (200, body.to_string()));
+                let response = format!(
+                    "HTTP/1.1 {status} Status\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        format!("http://{address}")
+    }
+
+    #[tokio::test]
+    async fn test_oidc_id_tokens_are_verified_against_fetched_keys() {
+        let key_pair = rcgen::KeyPair::generate().unwrap();
+        let point = key_pair.public_key_raw();
+        let jwks = serde_json::json!({ "keys": [{
+            "kty": "EC",
+            "crv": "P-256",
+            "kid": "key-1",
+            "alg": "ES256",
+            "x": URL_SAFE_NO_PAD.encode(&point[1..33]),
+            "y": URL_SAFE_NO_PAD.encode(&point[33..]),
+        }]});
+        let jwks_server = serve(vec![("GET /jwks".to_string(), jwks)]).await;
+        let discovery = serde_json::json!({ "jwks_uri": format!("{jwks_server}/jwks") });
+        let issuer = serve(vec![("GET /.well-known/openid-configuration".to_string(), discovery)]).await;
+        let provider = OidcProvider::new("accounts", &issuer, "horizon-game");
+
+        let encoding_key = EncodingKey::from_ec_pem(key_pair.serialize_pem().as_bytes()).unwrap();
+        let sign = |claims: serde_json::Value| {
+            let mut header = Header::new(Algorithm::ES256);
+            header.kid = Some("key-1".to_string());
+            jsonwebtoken::encode(&header, &claims, &encoding_key).unwrap()
+        };
+        let exp = current_timestamp() + 60;
+
+        let identity = provider
+            .verify(&sign(serde_json::json!({ "sub": "u-42", "iss": issuer, "aud": "horizon-game", "exp": exp, "name": "Ada" })))
+            .await
+            .unwrap();
+        assert_eq!((identity.subject.as_str(), identity.display_name.as_deref()), ("u-42", Some("Ada")));
+
+        let other_game = sign(serde_json::json!({ "sub": "u-42", "iss": issuer, "aud": "other-game", "exp": exp }));
+        assert!(matches!(provider.verify(&other_game).await, Err(IdentityError::InvalidToken(_))));
+    }
+
+    #[tokio::test]
+    async fn test_oidc_tokens_must_use_their_keys_algorithm() {
+        let secret = b"provider-secret-material-32bytes";
+        let key = |alg: Option<&str>| {
+            let mut key = serde_json::json!({ "kty": "EC", "crv": "P-256", "kid": "key-1", "x": "", "y": "" });
+            if let Some(alg) = alg {
+                key["alg"] = alg.into();
+            }
+            key
+        };
+        // An HMAC token over a public key must not be checked as anything but ES256
+        let forged = {
+            let mut header = Header::new(Algorithm::HS256);
+            header.kid = Some("key-1".to_string());
+            let claims = serde_json::json!({ "sub": "u-42", "iss": "issuer", "aud": "horizon-game", "exp": current_timestamp() + 60 });
+            jsonwebtoken::encode(&header, &claims, &EncodingKey::from_secret(secret)).unwrap()
+        };
+
+        for alg in [Some("ES256"), None] {
+            let jwks: JwkSet = serde_json::from_value(serde_json::json!({ "keys": [key(alg)] })).unwrap();
+            let provider = OidcProvider::new("accounts", "issuer", "horizon-game").with_jwks(jwks);
+            assert!(matches!(provider.verify(&forged).await, Err(IdentityError::Rejected(e)) if e.contains("HS256")));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_discord_and_steam_vouch_for_their_users() {
+        let user = serde_json::json!({ "id": "8812", "username": "ada", "global_name": "Ada" });
+        let authorization = |application: &str| serde_json::json!({ "application": { "id": application }, "scopes": ["identify"], "user": user });
+        let discord_api = serve(vec![
+            ("Bearer valid".to_string(), authorization("1100")),
+            ("Bearer other-game".to_string(), authorization("2200")),
+            ("Bearer no-identify".to_string(), serde_json::json!({ "application": { "id": "1100" }, "scopes": ["guilds"] })),
+        ])
+        .await;
+        let discord = DiscordProvider::new("discord", "1100", discord_api);
+        let identity = discord.verify("valid").await.unwrap();
+        assert_eq!((identity.subject.as_str(), identity.display_name.as_deref()), ("8812", Some("Ada")));
+        assert!(matches!(discord.verify("expired").await, Err(IdentityError::Rejected(_))));
+        assert!(matches!(discord.verify("other-game").await, Err(IdentityError::Rejected(e)) if e.contains("another application")));
+        assert!(matches!(discord.verify("no-identify").await, Err(IdentityError::Rejected(e)) if e.contains("identify")));
+
+        let ticket = serde_json::json!({ "response": { "params": { "result": "OK", "steamid": "76561197960287930", "vacbanned": false } } });
+        let invalid = serde_json::json!({ "response": { "error": { "errorcode": 101, "errordesc": "Invalid ticket" } } });
+        let steam_api = serve(vec![
+            ("?key=secret&appid=480&ticket=14000000 ".to_string(), ticket),
+            ("GET /ISteamUserAuth/AuthenticateUserTicket/v1/".to_string(), invalid),
+        ])
+        .await;
+        let steam = SteamProvider::new("steam", 480, "secret", steam_api);
+        assert_eq!(steam.verify("14000000").await.unwrap().subject, "76561197960287930");
+        assert!(matches!(steam.verify("deadbeef").await, Err(IdentityError::Rejected(e)) if e.contains("Invalid ticket")));
+    }
+}
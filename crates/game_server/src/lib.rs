@@ -87,13 +87,14 @@
 //!
 //! ## Performance Considerations
 //!
-//! * **Multi-threaded accept loops** - Configure `use_reuse_port` for CPU core scaling
+//! * **Multi-threaded accept loops** - Configure `use_reuse_port` and `accept_threads` for CPU core scaling
 //! * **Efficient message routing** - Zero-copy message passing where possible  
 //! * **Plugin isolation** - Plugins run in separate contexts to prevent interference
 //! * **Connection pooling** - Reuse connections and minimize allocation overhead
 
 // Re-export core types and functions for easy access
-pub use config::ServerConfig;
+pub use config::{ServerConfig, SnapshotConfig, AlertingConfig, AlertWebhookConfig, AlertWebhookFormat, AuditLogConfig, ConnectionThrottleConfig, GeoIpConfig, ContentFilterAction, ContentFilterConfig, ContentFilterRule, FederationConfig, NeighborConfig, HeartbeatConfig, AuthConfig, IdentityProviderConfig, JwtKeyConfig, ListenerConfig, MessageLane, MessageLaneConfig, OutboundQueueConfig, ProtocolConfig, ProxyProtocolConfig, QuicConfig, ReplayProtectionConfig, S2sConfig, S2sPeerConfig, SecurityConfig, TlsConfig, SniCertificateConfig};
+pub use messaging::LaneStats;
 pub use error::ServerError;
 pub use server::GameServer;
 pub use utils::{create_server, create_server_with_config};
@@ -105,6 +106,14 @@ pub mod server;
 pub mod utils;
 pub mod security;
 pub mod health;
+pub mod federation;
+pub mod identity;
+pub mod admin;
+pub mod alerting;
+pub mod audit;
+pub mod grpc;
+pub mod udp;
+pub mod s2s;
 
 // Internal modules (not part of public API)
 mod connection;
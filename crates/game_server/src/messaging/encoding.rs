@@ -0,0 +1,240 @@
+//! Wire encodings of client messages.
+//!
+//! Clients send JSON text frames by default. A WebSocket client can instead
+//! negotiate a binary encoding at connect by offering its subprotocol in the
+//! `Sec-WebSocket-Protocol` header:
+//!
+//! * `horizon.msgpack` - MessagePack maps
+//! * `horizon.cbor` - CBOR maps
+//! * `horizon.json` - JSON, the same as offering nothing
+//!
+//! The server accepts the first offered protocol it supports and echoes it.
+//! Clients that declare their protocol version offer the same encodings as
+//! `horizon.v1.msgpack` and so on (see [`crate::connection::version`]).
+//! Binary frames are then decoded with the negotiated encoding into the same
+//! structure a JSON message has, so a native GORC event or a
+//! [`ClientMessage`](super::ClientMessage) keeps its field names and plugins
+//! see no difference. Text frames are always JSON, and messages sent to the
+//! client are unaffected.
+//!
+//! Independently of the encoding, any client can send raw binary frames for
+//! payloads the server shouldn't parse, such as voice frames. A raw frame is
+//! a `0x00` marker byte, which never starts a JSON, MessagePack or CBOR map,
+//! then a big-endian `u16` channel and the payload. Its payload goes to the
+//! plugins handling the channel with
+//! [`on_client_binary`](horizon_event_system::EventSystem::on_client_binary).
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Encoding of the messages a client sends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClientEncoding {
+    /// JSON text frames
+    #[default]
+    Json,
+    /// MessagePack binary frames
+    #[serde(rename = "msgpack")]
+    MessagePack,
+    /// CBOR binary frames
+    Cbor,
+}
+
+impl ClientEncoding {
+    /// Every encoding, in the server's order of preference
+    pub const ALL: [ClientEncoding; 3] = [ClientEncoding::MessagePack, ClientEncoding::Cbor, ClientEncoding::Json];
+
+    /// Gets the WebSocket subprotocol clients offer to use the encoding
+    pub fn subprotocol(&self) -> &'static str {
+        match self {
+            ClientEncoding::Json => "horizon.json",
+            ClientEncoding::MessagePack => "horizon.msgpack",
+            ClientEncoding::Cbor => "horizon.cbor",
+        }
+    }
+
+    /// Picks the encoding of a `Sec-WebSocket-Protocol` header.
+    ///
+    /// # Returns
+    ///
+    /// The encoding of the first offered subprotocol the server supports, or
+    /// `None` if it supports none of them.
+    pub fn negotiate(offered: &str) -> Option<Self> {
+        offered
+            .split(',')
+            .map(str::trim)
+            .find_map(|protocol| Self::ALL.into_iter().find(|encoding| encoding.subprotocol() == protocol))
+    }
+
+    /// Checks whether messages in this encoding arrive as binary frames
+    pub fn is_binary(&self) -> bool {
+        !matches!(self, ClientEncoding::Json)
+    }
+
+    /// Decodes a message in this encoding.
+    ///
+    /// # Returns
+    ///
+    /// The decoded value, or a description of why the message is malformed.
+    pub fn decode<T: DeserializeOwned>(&self, message: &[u8]) -> Result<T, String> {
+        match self {
+            ClientEncoding::Json => serde_json::from_slice(message).map_err(|e| e.to_string()),
+            ClientEncoding::MessagePack => rmp_serde::from_slice(message).map_err(|e| e.to_string()),
+            ClientEncoding::Cbor => ciborium::de::from_reader(message).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// First byte of a raw binary frame
+pub const RAW_FRAME_MARKER: u8 = 0x00;
+
+/// A raw client message as it arrived on the connection
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClientFrame {
+    /// JSON text
+    Text(String),
+    /// Binary message in a negotiated encoding
+    Binary(ClientEncoding, Vec<u8>),
+    /// Opaque payload of a raw binary frame, routed by channel without parsing
+    Raw {
+        /// Channel the payload was sent on
+        channel: u16,
+        /// The payload
+        data: Vec<u8>,
+    },
+}
+
+impl ClientFrame {
+    /// Gets the frame of a binary message, telling raw frames apart from
+    /// messages in the connection's encoding by their marker byte.
+    ///
+    /// # Returns
+    ///
+    /// The frame, or `None` if the message is neither a raw frame nor in a
+    /// binary encoding.
+    pub fn from_binary(encoding: ClientEncoding, message: Vec<u8>) -> Option<Self> {
+        match message.first() {
+            Some(&RAW_FRAME_MARKER) if message.len() >= 3 => Some(ClientFrame::Raw {
+                channel: u16::from_be_bytes([message[1], message[2]]),
+                data: message[3..].to_vec(),
+            }),
+            _ if encoding.is_binary() => Some(ClientFrame::Binary(encoding, message)),
+            _ => None,
+        }
+    }
+
+    /// Checks whether this is a raw binary frame
+    pub fn is_raw(&self) -> bool {
+        matches!(self, ClientFrame::Raw { .. })
+    }
+
+    /// Decodes the frame's message
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, String> {
+        match self {
+            ClientFrame::Text(text) => ClientEncoding::Json.decode(text.as_bytes()),
+            ClientFrame::Binary(encoding, message) => encoding.decode(message),
+            ClientFrame::Raw { channel, .. } => Err(format!("raw frame on channel {channel} has no structured message")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messaging::ClientMessage;
+
+    #[test]
+    fn test_binary_encodings_decode_to_client_messages() {
+        let message = serde_json::json!({
+            "namespace": "movement",
+            "event": "move_request",
+            "data": { "x": 1.5, "tags": ["fast"] },
+        });
+
+        let msgpack = ClientFrame::Binary(ClientEncoding::MessagePack, rmp_serde::to_vec_named(&message).unwrap());
+        let mut cbor = Vec::new();
+        ciborium::ser::into_writer(&message, &mut cbor).unwrap();
+        let cbor = ClientFrame::Binary(ClientEncoding::Cbor, cbor);
+
+        for frame in [ClientFrame::Text(message.to_string()), msgpack, cbor] {
+            let decoded: ClientMessage = frame.decode().unwrap();
+            assert_eq!(decoded.namespace, "movement");
+            assert_eq!(decoded.data, message["data"]);
+        }
+        assert!(ClientFrame::Binary(ClientEncoding::Cbor, vec![0xff]).decode::<ClientMessage>().is_err());
+    }
+
+    #[test]
+    fn test_raw_frames_are_told_apart_from_encoded_messages() {
+        let raw = ClientFrame::from_binary(ClientEncoding::Json, vec![RAW_FRAME_MARKER, 0x01, 0x02, 0xca, 0xfe]);
+        assert_eq!(raw, Some(ClientFrame::Raw { channel: 0x0102, data: vec![0xca, 0xfe] }));
+        assert!(raw.unwrap().decode::<ClientMessage>().is_err());
+
+        let msgpack = rmp_serde::to_vec_named(&serde_json::json!({ "namespace": "chat" })).unwrap();
+        assert!(matches!(
+            ClientFrame::from_binary(ClientEncoding::MessagePack, msgpack.clone()),
+            Some(ClientFrame::Binary(ClientEncoding::MessagePack, _))
+        ));
+        // JSON connections only accept raw binary frames
+        assert_eq!(ClientFrame::from_binary(ClientEncoding::Json, msgpack), None);
+        assert_eq!(ClientFrame::from_binary(ClientEncoding::Json, vec![RAW_FRAME_MARKER, 0x01]), None);
+    }
+
+    #[tokio::test]
+    async fn test_negotiated_msgpack_messages_reach_plugins() {
+        use crate::config::MessageLaneConfig;
+        use crate::connection::ConnectionManager;
+        use crate::messaging::MessageLanes;
+        use crate::server::handlers::handle_connection;
+        use futures::SinkExt;
+        use horizon_event_system::{EventSystem, RawClientMessageEvent};
+        use std::sync::Arc;
+        use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+        use tokio_tungstenite::tungstenite::http::header::{HeaderValue, SEC_WEBSOCKET_PROTOCOL};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let events = Arc::new(EventSystem::new());
+        let (routed_tx, mut routed_rx) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("raw_client_message", move |event: RawClientMessageEvent| {
+                let _ = routed_tx.send(event);
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+        message_lanes.start(connection_manager.clone(), events.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, addr) = listener.accept().await.unwrap();
+            handle_connection(stream, addr, connection_manager, events, message_lanes, None).await
+        });
+
+        let mut request = format!("ws://{address}/").into_client_request().unwrap();
+        request
+            .headers_mut()
+            .insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static("chat, horizon.msgpack"));
+        let (mut websocket, response) = tokio_tungstenite::connect_async(request).await.unwrap();
+        assert_eq!(response.headers()[SEC_WEBSOCKET_PROTOCOL], "horizon.msgpack");
+
+        let message = serde_json::json!({ "namespace": "movement", "event": "move_request", "data": { "x": 4 } });
+        websocket
+            .send(Message::Binary(rmp_serde::to_vec_named(&message).unwrap().into()))
+            .await
+            .unwrap();
+        let routed = routed_rx.recv().await.unwrap();
+        assert_eq!(routed.message_type, "movement:move_request");
+        assert_eq!(routed.data, br#"{"x":4}"#);
+    }
+
+    #[test]
+    fn test_negotiation_picks_first_supported_subprotocol() {
+        assert_eq!(ClientEncoding::negotiate("chat, horizon.cbor, horizon.msgpack"), Some(ClientEncoding::Cbor));
+        assert_eq!(ClientEncoding::negotiate("horizon.json"), Some(ClientEncoding::Json));
+        assert_eq!(ClientEncoding::negotiate("chat"), None);
+    }
+}
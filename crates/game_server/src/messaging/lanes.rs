@@ -0,0 +1,443 @@
+//! Priority lanes for incoming client messages.
+//!
+//! Connection readers classify each message into a [`MessageLane`] and hand
+//! it to a router shard instead of routing it inline. Each shard drains its
+//! critical lane before its normal lane and its normal lane before its bulk
+//! lane, so movement keeps flowing while scans and metadata pile up under
+//! load. A connection always uses the same shard, which keeps the messages
+//! it sends in one lane in order.
+
+use crate::config::{MessageLane, MessageLaneConfig};
+use crate::connection::{ConnectionId, ConnectionManager};
+use crate::messaging::{route_client_frame, ClientEncoding, ClientFrame};
+use crate::security::SecurityManager;
+use horizon_event_system::{EventSystem, BINARY_NAMESPACE};
+use serde::de::{self, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tracing::{debug, trace};
+
+/// Point-in-time state of one priority lane across all shards
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LaneStats {
+    /// Lane the statistics belong to
+    pub lane: MessageLane,
+    /// Messages waiting to be routed
+    pub depth: usize,
+    /// Deepest the lane has been
+    pub peak_depth: usize,
+    /// Messages routed from the lane
+    pub dispatched: u64,
+}
+
+#[derive(Debug, Default)]
+struct LaneCounters {
+    depth: AtomicUsize,
+    peak_depth: AtomicUsize,
+    dispatched: AtomicU64,
+}
+
+/// Message waiting in a lane
+#[derive(Debug)]
+struct LaneMessage {
+    connection_id: ConnectionId,
+    frame: ClientFrame,
+}
+
+/// Receiving ends of one shard's lanes, highest priority first
+type ShardReceivers = [mpsc::Receiver<LaneMessage>; 3];
+
+/// Router shards with one queue per priority lane.
+///
+/// Messages submitted before [`start`](Self::start) wait in their lanes
+/// until the shards begin routing.
+#[derive(Debug)]
+pub struct MessageLanes {
+    namespaces: HashMap<String, MessageLane>,
+    shards: Vec<[mpsc::Sender<LaneMessage>; 3]>,
+    receivers: Mutex<Option<Vec<ShardReceivers>>>,
+    counters: Arc<[LaneCounters; 3]>,
+    security_manager: Option<Arc<SecurityManager>>,
+}
+
+impl MessageLanes {
+    /// Creates the lanes of every shard
+    pub fn new(config: &MessageLaneConfig) -> Self {
+        let (shards, receivers) = (0..config.shards.max(1))
+            .map(|_| {
+                let (critical_tx, critical_rx) = mpsc::channel(config.capacity.max(1));
+                let (normal_tx, normal_rx) = mpsc::channel(config.capacity.max(1));
+                let (bulk_tx, bulk_rx) = mpsc::channel(config.capacity.max(1));
+                ([critical_tx, normal_tx, bulk_tx], [critical_rx, normal_rx, bulk_rx])
+            })
+            .unzip();
+
+        Self {
+            namespaces: config.namespaces.clone(),
+            shards,
+            receivers: Mutex::new(Some(receivers)),
+            counters: Arc::new(Default::default()),
+            security_manager: None,
+        }
+    }
+
+    /// Enforces the security manager's message rate limits while routing
+    pub fn with_security_manager(mut self, security_manager: Arc<SecurityManager>) -> Self {
+        self.security_manager = Some(security_manager);
+        self
+    }
+
+    /// Starts routing queued messages; later calls do nothing
+    ///
+    /// # Arguments
+    ///
+    /// * `connection_manager` - Manager for looking up the sending players
+    /// * `horizon_event_system` - Event system messages are routed to
+    pub fn start(&self, connection_manager: Arc<ConnectionManager>, horizon_event_system: Arc<EventSystem>) {
+        let Some(receivers) = self.receivers.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take() else {
+            return;
+        };
+
+        debug!("🚦 Starting {} client message router shard(s)", receivers.len());
+        for receivers in receivers {
+            tokio::spawn(route_shard(
+                receivers,
+                self.counters.clone(),
+                connection_manager.clone(),
+                horizon_event_system.clone(),
+                self.security_manager.clone(),
+            ));
+        }
+    }
+
+    /// Queues a JSON client message in its priority lane.
+    ///
+    /// Waits while the lane is full, which slows down reading from the
+    /// connection instead of buffering without bound.
+    pub async fn submit(&self, connection_id: ConnectionId, text: String) {
+        self.submit_frame(connection_id, ClientFrame::Text(text)).await
+    }
+
+    /// Queues a binary client message in its priority lane
+    ///
+    /// # Arguments
+    ///
+    /// * `connection_id` - Connection the message arrived on
+    /// * `encoding` - Encoding the connection negotiated
+    /// * `message` - The encoded message
+    pub async fn submit_binary(&self, connection_id: ConnectionId, encoding: ClientEncoding, message: Vec<u8>) {
+        self.submit_frame(connection_id, ClientFrame::Binary(encoding, message)).await
+    }
+
+    /// Queues a client message of any encoding in its priority lane
+    pub async fn submit_frame(&self, connection_id: ConnectionId, frame: ClientFrame) {
+        let lane = self.classify_frame(&frame);
+        let counters = &self.counters[lane_index(lane)];
+        let depth = counters.depth.fetch_add(1, Ordering::Relaxed) + 1;
+        counters.peak_depth.fetch_max(depth, Ordering::Relaxed);
+
+        let shard = &self.shards[connection_id % self.shards.len()];
+        if shard[lane_index(lane)].send(LaneMessage { connection_id, frame }).await.is_err() {
+            // Shards only stop when the runtime shuts down
+            counters.depth.fetch_sub(1, Ordering::Relaxed);
+            debug!("🚦 Dropped message from connection {}: router shard stopped", connection_id);
+        }
+    }
+
+    /// Gets the current state of every lane, highest priority first
+    pub fn stats(&self) -> Vec<LaneStats> {
+        MessageLane::ALL
+            .iter()
+            .map(|&lane| {
+                let counters = &self.counters[lane_index(lane)];
+                LaneStats {
+                    lane,
+                    depth: counters.depth.load(Ordering::Relaxed),
+                    peak_depth: counters.peak_depth.load(Ordering::Relaxed),
+                    dispatched: counters.dispatched.load(Ordering::Relaxed),
+                }
+            })
+            .collect()
+    }
+
+    /// Picks the lane of a raw client message.
+    ///
+    /// Native GORC events use the lane of their replication channel. Other
+    /// messages use the lane configured for their namespace, then the lane
+    /// of a `channel` in their data, and the normal lane otherwise.
+    /// Unparseable messages go to the normal lane, where the router rejects
+    /// them as usual.
+    pub fn classify(&self, text: &str) -> MessageLane {
+        self.lane_of(ClientEncoding::Json.decode(text.as_bytes()).ok())
+    }
+
+    /// Picks the lane of a client message of any encoding, like [`classify`](Self::classify).
+    ///
+    /// Raw binary frames use the lane configured for the `binary` namespace.
+    pub fn classify_frame(&self, frame: &ClientFrame) -> MessageLane {
+        if frame.is_raw() {
+            return self.namespaces.get(BINARY_NAMESPACE).copied().unwrap_or(MessageLane::Normal);
+        }
+        self.lane_of(frame.decode().ok())
+    }
+
+    fn lane_of(&self, probe: Option<LaneProbe>) -> MessageLane {
+        let Some(probe) = probe else {
+            return MessageLane::Normal;
+        };
+
+        if probe.message_type.as_deref() == Some("gorc_event") {
+            return MessageLane::for_channel(probe.channel.unwrap_or(0));
+        }
+        probe
+            .namespace
+            .and_then(|namespace| self.namespaces.get(&namespace).copied())
+            .or_else(|| probe.data.0.map(MessageLane::for_channel))
+            .unwrap_or(MessageLane::Normal)
+    }
+}
+
+fn lane_index(lane: MessageLane) -> usize {
+    match lane {
+        MessageLane::Critical => 0,
+        MessageLane::Normal => 1,
+        MessageLane::Bulk => 2,
+    }
+}
+
+/// Routes one shard's messages, always taking from the highest non-empty lane
+async fn route_shard(
+    receivers: ShardReceivers,
+    counters: Arc<[LaneCounters; 3]>,
+    connection_manager: Arc<ConnectionManager>,
+    horizon_event_system: Arc<EventSystem>,
+    security_manager: Option<Arc<SecurityManager>>,
+) {
+    let [mut critical, mut normal, mut bulk] = receivers;
+    loop {
+        let (lane, message) = tokio::select! {
+            biased;
+            Some(message) = critical.recv() => (MessageLane::Critical, message),
+            Some(message) = normal.recv() => (MessageLane::Normal, message),
+            Some(message) = bulk.recv() => (MessageLane::Bulk, message),
+            else => break,
+        };
+
+        let counters = &counters[lane_index(lane)];
+        counters.depth.fetch_sub(1, Ordering::Relaxed);
+        if let Err(e) = route_client_frame(
+            &message.frame,
+            message.connection_id,
+            &connection_manager,
+            &horizon_event_system,
+            security_manager.as_deref(),
+        )
+        .await
+        {
+            trace!("❌ Message routing error: {}", e);
+        }
+        counters.dispatched.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Fields of a client message that decide its lane; everything else is skipped
+#[derive(Deserialize)]
+struct LaneProbe {
+    #[serde(rename = "type", default)]
+    message_type: Option<String>,
+    #[serde(default)]
+    channel: Option<u8>,
+    #[serde(default)]
+    namespace: Option<String>,
+    #[serde(default)]
+    data: DataChannel,
+}
+
+/// `channel` field of a message's data, if the data is an object that has one
+#[derive(Default)]
+struct DataChannel(Option<u8>);
+
+impl<'de> Deserialize<'de> for DataChannel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DataChannelVisitor)
+    }
+}
+
+struct DataChannelVisitor;
+
+impl<'de> Visitor<'de> for DataChannelVisitor {
+    type Value = DataChannel;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("message data")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<DataChannel, A::Error> {
+        let mut channel = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "channel" {
+                // GORC channels are 0-3
+                channel = map.next_value::<u64>().ok().map(|channel| channel.min(3) as u8);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(DataChannel(channel))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<DataChannel, A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(DataChannel(None))
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<DataChannel, E> {
+        Ok(DataChannel(None))
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<DataChannel, E> {
+        Ok(DataChannel(None))
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<DataChannel, E> {
+        Ok(DataChannel(None))
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<DataChannel, E> {
+        Ok(DataChannel(None))
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<DataChannel, E> {
+        Ok(DataChannel(None))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<DataChannel, E> {
+        Ok(DataChannel(None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_classification() {
+        let lanes = MessageLanes::new(&MessageLaneConfig::default());
+        let classify = |message: serde_json::Value| lanes.classify(&message.to_string());
+
+        assert_eq!(classify(serde_json::json!({ "namespace": "movement", "event": "move", "data": {} })), MessageLane::Critical);
+        assert_eq!(classify(serde_json::json!({ "namespace": "chat", "event": "say", "data": "hi" })), MessageLane::Normal);
+        assert_eq!(classify(serde_json::json!({ "namespace": "scan", "event": "ping", "data": [1, 2] })), MessageLane::Bulk);
+        assert_eq!(classify(serde_json::json!({ "type": "gorc_event", "channel": 0, "event": "move" })), MessageLane::Critical);
+        assert_eq!(classify(serde_json::json!({ "type": "gorc_event", "channel": 3, "event": "info" })), MessageLane::Bulk);
+        assert_eq!(
+            classify(serde_json::json!({ "namespace": "ship", "event": "hail", "data": { "channel": 1, "text": "hello" } })),
+            MessageLane::Normal
+        );
+        assert_eq!(
+            classify(serde_json::json!({ "namespace": "ship", "event": "specs", "data": { "channel": 9 } })),
+            MessageLane::Bulk
+        );
+        assert_eq!(lanes.classify("not json"), MessageLane::Normal);
+
+        let move_event = rmp_serde::to_vec_named(&serde_json::json!({ "type": "gorc_event", "channel": 0, "event": "move" })).unwrap();
+        assert_eq!(lanes.classify_frame(&ClientFrame::Binary(ClientEncoding::MessagePack, move_event)), MessageLane::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_critical_lane_routed_first() {
+        let lanes = MessageLanes::new(&MessageLaneConfig {
+            shards: 1,
+            ..MessageLaneConfig::default()
+        });
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let connection_id = connection_manager.add_connection("127.0.0.1:9000".parse().unwrap()).await;
+        connection_manager.set_player_id(connection_id, horizon_event_system::PlayerId::new()).await;
+
+        let events = Arc::new(EventSystem::new());
+        let (routed_tx, mut routed_rx) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("raw_client_message", move |event: horizon_event_system::RawClientMessageEvent| {
+                let _ = routed_tx.send(event.message_type);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        // Queue a backlog before routing starts, as under load
+        for (namespace, event) in [("scan", "sweep"), ("chat", "say"), ("movement", "move")] {
+            let message = serde_json::json!({ "namespace": namespace, "event": event, "data": {} });
+            lanes.submit(connection_id, message.to_string()).await;
+        }
+        assert_eq!(lanes.stats().iter().map(|lane| lane.depth).collect::<Vec<_>>(), [1, 1, 1]);
+
+        lanes.start(connection_manager, events);
+        let mut routed = Vec::new();
+        for _ in 0..3 {
+            routed.push(routed_rx.recv().await.unwrap());
+        }
+        assert_eq!(routed, ["movement:move", "chat:say", "scan:sweep"]);
+
+        let stats = lanes.stats();
+        assert!(stats.iter().all(|lane| lane.depth == 0 && lane.peak_depth == 1));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_messages_are_dropped() {
+        use crate::config::SecurityConfig;
+        use horizon_event_system::{ClientRateLimitedEvent, RawClientMessageEvent};
+
+        let security_manager = SecurityManager::new(SecurityConfig {
+            message_rate_limits: HashMap::from([("chat:say".to_string(), 2)]),
+            ..SecurityConfig::default()
+        });
+        let lanes = MessageLanes::new(&MessageLaneConfig {
+            shards: 1,
+            ..MessageLaneConfig::default()
+        })
+        .with_security_manager(Arc::new(security_manager));
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let connection_id = connection_manager.add_connection("127.0.0.1:9000".parse().unwrap()).await;
+        let player_id = horizon_event_system::PlayerId::new();
+        connection_manager.set_player_id(connection_id, player_id).await;
+
+        let events = Arc::new(EventSystem::new());
+        let (routed_tx, mut routed_rx) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("raw_client_message", move |event: RawClientMessageEvent| {
+                let _ = routed_tx.send(event.message_type);
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (limited_tx, mut limited_rx) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("client_rate_limited", move |event: ClientRateLimitedEvent| {
+                let _ = limited_tx.send(event);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        for event in ["say", "say", "say", "emote"] {
+            let message = serde_json::json!({ "namespace": "chat", "event": event, "data": {} });
+            lanes.submit(connection_id, message.to_string()).await;
+        }
+        lanes.start(connection_manager, events);
+
+        let mut routed = Vec::new();
+        for _ in 0..3 {
+            routed.push(routed_rx.recv().await.unwrap());
+        }
+        assert_eq!(routed, ["chat:say", "chat:say", "chat:emote"]);
+
+        let violation = limited_rx.recv().await.unwrap();
+        assert_eq!(violation.player_id, player_id);
+        assert_eq!((violation.namespace.as_str(), violation.event.as_str()), ("chat", "say"));
+        assert_eq!((violation.limit_key.as_str(), violation.limit_per_sec), ("chat:say", 2));
+        assert!(limited_rx.try_recv().is_err());
+    }
+}
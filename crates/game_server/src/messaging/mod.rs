@@ -3,8 +3,12 @@
 //! This module provides the infrastructure for parsing, routing, and handling
 //! messages between clients and the server plugin system.
 
+pub mod encoding;
+pub mod lanes;
 pub mod router;
 pub mod types;
 
-pub use router::route_client_message;
+pub use encoding::{ClientEncoding, ClientFrame};
+pub use lanes::{LaneStats, MessageLanes};
+pub use router::route_client_frame;
 pub use types::ClientMessage;
\ No newline at end of file
@@ -3,8 +3,17 @@
 //! This module handles the parsing and routing of incoming client messages
 //! to the appropriate plugin handlers through the event system.
 
-use crate::{connection::ConnectionId, error::ServerError, messaging::ClientMessage};
-use horizon_event_system::{current_timestamp, EventSystem, RawClientMessageEvent, GorcObjectId};
+use crate::{
+    audit::record_audit,
+    connection::ConnectionId,
+    error::ServerError,
+    messaging::{ClientFrame, ClientMessage},
+    security::{SecurityError, SecurityManager},
+};
+use horizon_event_system::{
+    current_timestamp, AuditAction, ClientBinaryMessage, ClientRateLimitedEvent, EventError, EventSystem, GorcObjectId, PlayerId,
+    PlayerFocusChangedEvent, RawClientMessageEvent, ReplicationAckEvent, SecurityAuditEvent, BINARY_NAMESPACE,
+};
 use tracing::{debug, trace, warn};
 
 /// Routes a raw client message to the appropriate plugin handlers.
@@ -19,20 +28,26 @@ use tracing::{debug, trace, warn};
 /// * `connection_id` - The unique identifier for the client connection
 /// * `connection_manager` - Manager for looking up player information
 /// * `horizon_event_system` - Event system for dispatching to plugins
+/// * `security_manager` - Optional security manager enforcing rate limits and replay protection
 /// 
 /// # Returns
 /// 
 /// `Ok(())` if the message was successfully routed, or a `ServerError` if
-/// parsing failed or the player was not found.
+/// parsing failed, the player was not found, the message was rate limited or
+/// it violated its schema.
 /// 
 /// # Message Flow
 /// 
 /// 1. Parse the raw text as a `ClientMessage` JSON structure
-/// 2. Look up the player ID for the connection
-/// 3. Create a `RawClientMessageEvent` for core processing
-/// 4. Emit the raw event to core handlers
-/// 5. Route the parsed message to the appropriate plugin namespace/event
-/// 6. Check if the message is GORC-compatible and route to GORC handlers if applicable
+/// 2. Drop the message if replay protection finds it stale or already received
+/// 3. Look up the player ID for the connection
+/// 4. Drop the message if the player exceeded one of its rate limits
+/// 5. Reject the message if its data violates the schema registered for its
+///    namespace and event, telling the client what was wrong
+/// 6. Create a `RawClientMessageEvent` for core processing
+/// 7. Emit the raw event to core handlers
+/// 8. Route the parsed message to the appropriate plugin namespace/event
+/// 9. Check if the message is GORC-compatible and route to GORC handlers if applicable
 /// 
 /// # Example Message Format
 /// 
@@ -67,25 +82,203 @@ pub async fn route_client_message(
     connection_id: ConnectionId,
     connection_manager: &crate::connection::ConnectionManager,
     horizon_event_system: &EventSystem,
+    security_manager: Option<&SecurityManager>,
 ) -> Result<(), ServerError> {
-    // Check if this is a native GORC event format first
-    if let Ok(parsed_json) = serde_json::from_str::<serde_json::Value>(text) {
-        if let Some(msg_type) = parsed_json.get("type").and_then(|v| v.as_str()) {
-            if msg_type == "gorc_event" {
-                return route_native_gorc_event(text, connection_id, connection_manager, horizon_event_system).await;
-            }
+    let message = serde_json::from_str(text)
+        .map_err(|e| ServerError::Network(format!("Invalid JSON: {e}")))?;
+    route_client_value(message, connection_id, connection_manager, horizon_event_system, security_manager).await
+}
+
+/// Routes a client message in any wire encoding to the appropriate plugin handlers.
+/// 
+/// Binary messages are decoded with the encoding the client negotiated and
+/// then routed exactly like [`route_client_message`] routes JSON text. Raw
+/// binary frames are not decoded at all; their payload goes to the handlers
+/// of their channel.
+/// 
+/// # Arguments
+/// 
+/// * `frame` - The raw message as it arrived from the client
+/// * `connection_id` - The unique identifier for the client connection
+/// * `connection_manager` - Manager for looking up player information
+/// * `horizon_event_system` - Event system for dispatching to plugins
+/// * `security_manager` - Optional security manager enforcing rate limits and replay protection
+/// 
+/// # Returns
+/// 
+/// `Ok(())` if the message was successfully routed, or a `ServerError` if
+/// decoding failed, the player was not found, the message was too large or
+/// it was rate limited.
+pub async fn route_client_frame(
+    frame: &ClientFrame,
+    connection_id: ConnectionId,
+    connection_manager: &crate::connection::ConnectionManager,
+    horizon_event_system: &EventSystem,
+    security_manager: Option<&SecurityManager>,
+) -> Result<(), ServerError> {
+    match frame {
+        ClientFrame::Text(text) => {
+            route_client_message(text, connection_id, connection_manager, horizon_event_system, security_manager).await
+        }
+        ClientFrame::Binary(encoding, _) => {
+            let message = frame
+                .decode()
+                .map_err(|e| ServerError::Network(format!("Invalid {encoding:?} message: {e}")))?;
+            route_client_value(message, connection_id, connection_manager, horizon_event_system, security_manager).await
+        }
+        ClientFrame::Raw { channel, data } => {
+            route_raw_frame(*channel, data, connection_id, connection_manager, horizon_event_system, security_manager).await
+        }
+    }
+}
+
+/// Routes the payload of a raw binary frame to the handlers of its channel.
+///
+/// Raw payloads are never parsed, so they bypass schema validation, but the
+/// size limit and the rate limits of the `binary` namespace, keyed by
+/// channel as `binary:<channel>`, still apply.
+///
+/// # Returns
+///
+/// `Ok(())` if the payload was routed, or a `ServerError` if the player was
+/// not found, or the payload was too large or rate limited.
+async fn route_raw_frame(
+    channel: u16,
+    data: &[u8],
+    connection_id: ConnectionId,
+    connection_manager: &crate::connection::ConnectionManager,
+    horizon_event_system: &EventSystem,
+    security_manager: Option<&SecurityManager>,
+) -> Result<(), ServerError> {
+    let player_id = connection_manager
+        .get_player_id(connection_id)
+        .await
+        .ok_or_else(|| ServerError::Internal("Player not found".to_string()))?;
+
+    let channel_name = channel.to_string();
+    enforce_authentication(BINARY_NAMESPACE, &channel_name, connection_id, connection_manager).await?;
+    if let Some(security_manager) = security_manager {
+        if let Err(error) = security_manager.check_binary_message_size(data.len()) {
+            trace!("🚫 Dropped raw message on channel {} from player {}: {}", channel, player_id, error);
+            let response = serde_json::json!({
+                "type": "error",
+                "error": "message_too_large",
+                "namespace": BINARY_NAMESPACE,
+                "channel": channel,
+                "size": data.len(),
+            });
+            connection_manager
+                .send_to_connection(connection_id, response.to_string().into_bytes())
+                .await;
+            let audit = SecurityAuditEvent::new(AuditAction::OversizedMessage, "server")
+                .with_target(player_id)
+                .with_detail(format!("{BINARY_NAMESPACE}:{channel} ({} bytes)", data.len()));
+            record_audit(horizon_event_system, audit).await;
+            return Err(ServerError::Network(error.to_string()));
         }
+        enforce_message_rate(
+            security_manager,
+            player_id,
+            BINARY_NAMESPACE,
+            &channel_name,
+            connection_id,
+            connection_manager,
+            horizon_event_system,
+        )
+        .await?;
+    }
+
+    let message = ClientBinaryMessage {
+        player_id,
+        channel,
+        data: data.to_vec(),
+        timestamp: current_timestamp(),
+    };
+    horizon_event_system
+        .emit_client_binary(&message)
+        .await
+        .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+    trace!("✅ Routed {} byte raw message on channel {} from player {}", data.len(), channel, player_id);
+    Ok(())
+}
+
+/// Routes a decoded client message: a native GORC event, a replication acknowledgement, a focus change or a `ClientMessage`
+async fn route_client_value(
+    message: serde_json::Value,
+    connection_id: ConnectionId,
+    connection_manager: &crate::connection::ConnectionManager,
+    horizon_event_system: &EventSystem,
+    security_manager: Option<&SecurityManager>,
+) -> Result<(), ServerError> {
+    if let Some(security_manager) = security_manager {
+        let player_id = connection_manager
+            .get_player_id(connection_id)
+            .await
+            .ok_or_else(|| ServerError::Internal("Player not found".to_string()))?;
+        if let Err(error) = security_manager.check_replay(player_id, &message) {
+            trace!("🚫 Dropped message from connection {}: {}", connection_id, error);
+            let response = serde_json::json!({
+                "type": "error",
+                "error": "replay_rejected",
+                "reason": error.to_string(),
+            });
+            connection_manager
+                .send_to_connection(connection_id, response.to_string().into_bytes())
+                .await;
+            return Err(ServerError::Network(error.to_string()));
+        }
+    }
+
+    // Check if this is a native GORC event or acknowledgement format first
+    match message.get("type").and_then(|v| v.as_str()) {
+        Some("gorc_event") => {
+            return route_native_gorc_event(message, connection_id, connection_manager, horizon_event_system, security_manager)
+                .await;
+        }
+        Some("gorc_ack") => {
+            return route_replication_ack(message, connection_id, connection_manager, horizon_event_system, security_manager)
+                .await;
+        }
+        Some("gorc_focus") => {
+            return route_focus_change(message, connection_id, connection_manager, horizon_event_system, security_manager)
+                .await;
+        }
+        _ => {}
     }
     
     // Parse as generic ClientMessage structure (legacy format)
-    let message: ClientMessage = serde_json::from_str(text)
-        .map_err(|e| ServerError::Network(format!("Invalid JSON: {e}")))?;
+    let message: ClientMessage = serde_json::from_value(message)
+        .map_err(|e| ServerError::Network(format!("Invalid client message: {e}")))?;
+    // Raw channels only take raw binary frames
+    if message.namespace == BINARY_NAMESPACE {
+        return Err(ServerError::Network(format!(
+            "Namespace '{BINARY_NAMESPACE}' is reserved for raw binary frames"
+        )));
+    }
 
     let player_id = connection_manager
         .get_player_id(connection_id)
         .await
         .ok_or_else(|| ServerError::Internal("Player not found".to_string()))?;
 
+    enforce_authentication(&message.namespace, &message.event, connection_id, connection_manager).await?;
+    if let Some(security_manager) = security_manager {
+        enforce_message_rate(
+            security_manager,
+            player_id,
+            &message.namespace,
+            &message.event,
+            connection_id,
+            connection_manager,
+            horizon_event_system,
+        )
+        .await?;
+    }
+    if let Err(e) = horizon_event_system.validate_client_message(&message.namespace, &message.event, &message.data) {
+        return Err(reject_invalid_payload(e, &message.namespace, &message.event, connection_id, connection_manager).await);
+    }
+
     debug!(
         "📨 Routing message to namespace '{}' event '{}' from player {}",
         message.namespace, message.event, player_id
@@ -106,10 +299,17 @@ pub async fn route_client_message(
         .map_err(|e| ServerError::Internal(e.to_string()))?;
 
     // Generic routing using client-specified namespace and event with connection context
-    horizon_event_system
+    match horizon_event_system
         .emit_client_with_context(&message.namespace, &message.event, player_id, &message.data)
         .await
-        .map_err(|e| ServerError::Internal(e.to_string()))?;
+    {
+        Ok(()) => {}
+        // A schema registered after the message was checked
+        Err(e @ EventError::SchemaViolation(_)) => {
+            return Err(reject_invalid_payload(e, &message.namespace, &message.event, connection_id, connection_manager).await);
+        }
+        Err(e) => return Err(ServerError::Internal(e.to_string())),
+    }
 
     // Check if this message should also be routed to GORC handlers
     // For messages that match the GORC format, also emit as GORC events
@@ -147,19 +347,22 @@ pub async fn route_client_message(
 /// 
 /// # Arguments
 /// 
-/// * `text` - The raw JSON text of the native GORC event
+/// * `message` - The decoded native GORC event
 /// * `connection_id` - The connection ID of the client
 /// * `connection_manager` - Manager for connection tracking
 /// * `horizon_event_system` - Event system for routing
+/// * `security_manager` - Optional security manager enforcing message rate limits
 /// 
 /// # Returns
 /// 
-/// `Ok(())` if the event was successfully routed, or a `ServerError` if parsing failed
+/// `Ok(())` if the event was successfully routed, or a `ServerError` if parsing
+/// failed or the event was rate limited
 async fn route_native_gorc_event(
-    text: &str,
+    message: serde_json::Value,
     connection_id: ConnectionId,
     connection_manager: &crate::connection::ConnectionManager,
     horizon_event_system: &EventSystem,
+    security_manager: Option<&SecurityManager>,
 ) -> Result<(), ServerError> {
     // Parse the native GORC event
     #[derive(serde::Deserialize)]
@@ -173,14 +376,28 @@ async fn route_native_gorc_event(
         player_id: String,
     }
     
-    let gorc_msg: NativeGorcEvent = serde_json::from_str(text)
-        .map_err(|e| ServerError::Network(format!("Invalid native GORC event JSON: {e}")))?;
+    let gorc_msg: NativeGorcEvent = serde_json::from_value(message)
+        .map_err(|e| ServerError::Network(format!("Invalid native GORC event: {e}")))?;
         
     let player_id = connection_manager
         .get_player_id(connection_id)
         .await
         .ok_or_else(|| ServerError::Internal("Player not found".to_string()))?;
         
+    enforce_authentication("gorc", &gorc_msg.event, connection_id, connection_manager).await?;
+    if let Some(security_manager) = security_manager {
+        enforce_message_rate(
+            security_manager,
+            player_id,
+            "gorc",
+            &gorc_msg.event,
+            connection_id,
+            connection_manager,
+            horizon_event_system,
+        )
+        .await?;
+    }
+        
     debug!(
         "🎯 Routing native GORC event: object_id='{}', channel={}, event='{}' from player {}",
         gorc_msg.object_id, gorc_msg.channel, gorc_msg.event, player_id
@@ -222,6 +439,9 @@ async fn route_native_gorc_event(
             debug!("✅ Successfully routed client GORC event to handlers: player {} -> {}:{}:{}", 
                 player_id, gorc_id, gorc_msg.channel, gorc_msg.event);
         }
+        Err(e @ EventError::SchemaViolation(_)) => {
+            return Err(reject_invalid_payload(e, "gorc", &gorc_msg.event, connection_id, connection_manager).await);
+        }
         Err(e) => {
             // Log as warning but don't fail - might be no handlers registered yet
             warn!("📝 No client GORC handlers found for {}:{}:{}: {}", 
@@ -237,6 +457,264 @@ async fn route_native_gorc_event(
     Ok(())
 }
 
+/// Routes a client's acknowledgement of reliable replication updates.
+/// 
+/// Clients acknowledge every update of a replication batch that was marked
+/// `reliable`, by its sequence number. The acknowledgement is emitted as the
+/// `replication_ack` core event, which the server hands to its network
+/// engine so it stops resending those updates.
+/// 
+/// # Acknowledgement Format
+/// 
+/// ```json
+/// {
+///   "type": "gorc_ack",
+///   "sequences": [12, 13, 15]
+/// }
+/// ```
+/// 
+/// # Returns
+/// 
+/// `Ok(())` if the acknowledgement was routed, or a `ServerError` if parsing
+/// failed, the player was not found or the message was rate limited
+async fn route_replication_ack(
+    message: serde_json::Value,
+    connection_id: ConnectionId,
+    connection_manager: &crate::connection::ConnectionManager,
+    horizon_event_system: &EventSystem,
+    security_manager: Option<&SecurityManager>,
+) -> Result<(), ServerError> {
+    #[derive(serde::Deserialize)]
+    struct GorcAck {
+        sequences: Vec<u32>,
+    }
+
+    let ack: GorcAck = serde_json::from_value(message)
+        .map_err(|e| ServerError::Network(format!("Invalid GORC acknowledgement: {e}")))?;
+
+    let player_id = connection_manager
+        .get_player_id(connection_id)
+        .await
+        .ok_or_else(|| ServerError::Internal("Player not found".to_string()))?;
+
+    enforce_authentication("gorc", "ack", connection_id, connection_manager).await?;
+    if let Some(security_manager) = security_manager {
+        enforce_message_rate(
+            security_manager,
+            player_id,
+            "gorc",
+            "ack",
+            connection_id,
+            connection_manager,
+            horizon_event_system,
+        )
+        .await?;
+    }
+
+    let event = ReplicationAckEvent {
+        player_id,
+        sequences: ack.sequences,
+        timestamp: current_timestamp(),
+    };
+    horizon_event_system
+        .emit_core("replication_ack", &event)
+        .await
+        .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+    trace!("✅ Routed acknowledgement of {} replication updates from player {}", event.sequences.len(), player_id);
+    Ok(())
+}
+
+/// Routes a client's declaration of the object it is focused on.
+/// 
+/// Clients send the object they focus on, such as their current combat
+/// target, or `null` to clear it. The change is emitted as the
+/// `player_focus_changed` core event, which the server hands to its
+/// subscription manager so the object is replicated to the player at a
+/// higher priority and frequency regardless of range.
+/// 
+/// # Focus Format
+/// 
+/// ```json
+/// {
+///   "type": "gorc_focus",
+///   "object_id": "550e8400-e29b-41d4-a716-446655440000"
+/// }
+/// ```
+/// 
+/// # Returns
+/// 
+/// `Ok(())` if the focus change was routed, or a `ServerError` if parsing
+/// failed, the player was not found or the message was rate limited
+async fn route_focus_change(
+    message: serde_json::Value,
+    connection_id: ConnectionId,
+    connection_manager: &crate::connection::ConnectionManager,
+    horizon_event_system: &EventSystem,
+    security_manager: Option<&SecurityManager>,
+) -> Result<(), ServerError> {
+    #[derive(serde::Deserialize)]
+    struct GorcFocus {
+        object_id: Option<GorcObjectId>,
+    }
+
+    let focus: GorcFocus = serde_json::from_value(message)
+        .map_err(|e| ServerError::Network(format!("Invalid GORC focus change: {e}")))?;
+
+    let player_id = connection_manager
+        .get_player_id(connection_id)
+        .await
+        .ok_or_else(|| ServerError::Internal("Player not found".to_string()))?;
+
+    enforce_authentication("gorc", "focus", connection_id, connection_manager).await?;
+    if let Some(security_manager) = security_manager {
+        enforce_message_rate(
+            security_manager,
+            player_id,
+            "gorc",
+            "focus",
+            connection_id,
+            connection_manager,
+            horizon_event_system,
+        )
+        .await?;
+    }
+
+    let event = PlayerFocusChangedEvent {
+        player_id,
+        object_id: focus.object_id,
+        timestamp: current_timestamp(),
+    };
+    horizon_event_system
+        .emit_core("player_focus_changed", &event)
+        .await
+        .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+    trace!("✅ Routed focus change of player {} to {:?}", player_id, event.object_id);
+    Ok(())
+}
+
+/// Drops a message from a player that isn't authenticated for the message's namespace.
+/// 
+/// # Returns
+/// 
+/// `Ok(())` if the message may be routed, or a `ServerError` if it was dropped
+async fn enforce_authentication(
+    namespace: &str,
+    event: &str,
+    connection_id: ConnectionId,
+    connection_manager: &crate::connection::ConnectionManager,
+) -> Result<(), ServerError> {
+    let status = connection_manager.get_auth_status(connection_id).await.unwrap_or_default();
+    if connection_manager.authentication().permits(namespace, status) {
+        return Ok(());
+    }
+
+    let response = serde_json::json!({
+        "type": "error",
+        "error": "unauthenticated",
+        "namespace": namespace,
+        "event": event,
+    });
+    connection_manager
+        .send_to_connection(connection_id, response.to_string().into_bytes())
+        .await;
+    Err(ServerError::Network(format!(
+        "Connection {connection_id} isn't authenticated for namespace '{namespace}'"
+    )))
+}
+
+/// Tells a client which parts of its message's data violate the schema of
+/// the message's event, instead of dropping the message silently.
+/// 
+/// # Returns
+/// 
+/// The `ServerError` to drop the message with
+async fn reject_invalid_payload(
+    error: EventError,
+    namespace: &str,
+    event: &str,
+    connection_id: ConnectionId,
+    connection_manager: &crate::connection::ConnectionManager,
+) -> ServerError {
+    let EventError::SchemaViolation(rejection) = error else {
+        return ServerError::Internal(error.to_string());
+    };
+
+    trace!("🚫 Dropped '{}:{}' message from connection {}: {}", namespace, event, connection_id, rejection);
+    let response = serde_json::json!({
+        "type": "error",
+        "error": "schema_violation",
+        "namespace": namespace,
+        "event": event,
+        "violations": rejection.violations,
+    });
+    connection_manager
+        .send_to_connection(connection_id, response.to_string().into_bytes())
+        .await;
+    ServerError::Network(rejection.to_string())
+}
+
+/// Drops a message that exceeds one of the sender's rate limits.
+/// 
+/// Tells the client which limit it hit and emits a `client_rate_limited` core
+/// event so plugins can act on players that keep flooding.
+/// 
+/// # Returns
+/// 
+/// `Ok(())` if the message may be routed, or a `ServerError` if it was dropped
+async fn enforce_message_rate(
+    security_manager: &SecurityManager,
+    player_id: PlayerId,
+    namespace: &str,
+    event: &str,
+    connection_id: ConnectionId,
+    connection_manager: &crate::connection::ConnectionManager,
+    horizon_event_system: &EventSystem,
+) -> Result<(), ServerError> {
+    let ip = connection_manager
+        .get_remote_addr(connection_id)
+        .await
+        .ok_or_else(|| ServerError::Internal("Connection not found".to_string()))?
+        .ip();
+    let Err(error) = security_manager.check_message_rate(player_id, ip, namespace, event).await else {
+        return Ok(());
+    };
+    let SecurityError::MessageRateExceeded { key, limit_per_sec } = &error else {
+        return Err(ServerError::Network(error.to_string()));
+    };
+
+    trace!("🚫 Dropped '{}:{}' message from player {}: {}", namespace, event, player_id, error);
+    let response = serde_json::json!({
+        "type": "error",
+        "error": "rate_limited",
+        "namespace": namespace,
+        "event": event,
+        "limit_per_sec": limit_per_sec,
+    });
+    connection_manager
+        .send_to_connection(connection_id, response.to_string().into_bytes())
+        .await;
+
+    let violation = ClientRateLimitedEvent {
+        player_id,
+        namespace: namespace.to_string(),
+        event: event.to_string(),
+        limit_key: key.clone(),
+        limit_per_sec: *limit_per_sec,
+        timestamp: current_timestamp(),
+    };
+    if let Err(e) = horizon_event_system.emit_core("client_rate_limited", &violation).await {
+        warn!("Failed to emit client_rate_limited event: {}", e);
+    }
+    let audit = SecurityAuditEvent::new(AuditAction::RateLimited, "server")
+        .with_target(player_id)
+        .with_detail(violation.limit_key);
+    record_audit(horizon_event_system, audit).await;
+
+    Err(ServerError::Network(error.to_string()))
+}
+
 /// Checks if a client message is a GORC event.
 /// 
 /// A message is considered a GORC event if it contains an `instance_uuid` field
@@ -460,4 +938,95 @@ fn capitalize_first_letter(s: &str) -> String {
         None => String::new(),
         Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::ConnectionManager;
+    use horizon_event_system::EventSchema;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_schema_violations_never_reach_handlers() {
+        let connection_manager = ConnectionManager::new();
+        let connection_id = connection_manager.add_connection("127.0.0.1:9000".parse().unwrap()).await;
+        connection_manager.set_player_id(connection_id, PlayerId::new()).await;
+        let mut outgoing = connection_manager.subscribe();
+
+        let events = EventSystem::new();
+        events
+            .register_client_schema("chat", "say", EventSchema::json(serde_json::json!({
+                "type": "object",
+                "required": ["text"],
+                "properties": { "text": { "type": "string", "maxLength": 8 } }
+            })))
+            .unwrap();
+        let raw_messages = Arc::new(AtomicUsize::new(0));
+        let counter = raw_messages.clone();
+        events
+            .on_core("raw_client_message", move |_: RawClientMessageEvent| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let message = serde_json::json!({ "namespace": "chat", "event": "say", "data": { "text": "far too long" } });
+        let routed = route_client_message(&message.to_string(), connection_id, &connection_manager, &events, None).await;
+        assert!(matches!(routed, Err(ServerError::Network(_))));
+        assert_eq!(raw_messages.load(Ordering::SeqCst), 0);
+
+        let (recipient, response) = outgoing.try_recv().unwrap();
+        let response: serde_json::Value = serde_json::from_slice(&response).unwrap();
+        assert_eq!(recipient, connection_id);
+        assert_eq!((response["error"].as_str(), response["event"].as_str()), (Some("schema_violation"), Some("say")));
+        assert_eq!(response["violations"][0]["path"], "$.text");
+
+        let message = serde_json::json!({ "namespace": "chat", "event": "say", "data": { "text": "hi" } });
+        route_client_message(&message.to_string(), connection_id, &connection_manager, &events, None).await.unwrap();
+        assert_eq!(raw_messages.load(Ordering::SeqCst), 1);
+        assert!(outgoing.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_raw_frames_reach_channel_handlers_untouched() {
+        let connection_manager = ConnectionManager::new();
+        let connection_id = connection_manager.add_connection("127.0.0.1:9000".parse().unwrap()).await;
+        let player_id = PlayerId::new();
+        connection_manager.set_player_id(connection_id, player_id).await;
+        let mut outgoing = connection_manager.subscribe();
+        let security = SecurityManager::new(crate::config::SecurityConfig {
+            max_binary_message_size: 4,
+            ..Default::default()
+        });
+
+        let events = EventSystem::new();
+        let (voice_tx, mut voice_rx) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_client_binary(7, move |message: ClientBinaryMessage| {
+                let _ = voice_tx.send(message);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let frame = ClientFrame::Raw { channel: 7, data: vec![0x00, 0xff, 0x7b, 0x22] };
+        route_client_frame(&frame, connection_id, &connection_manager, &events, Some(&security)).await.unwrap();
+        let message = voice_rx.try_recv().unwrap();
+        assert_eq!((message.player_id, message.channel, message.data), (player_id, 7, vec![0x00, 0xff, 0x7b, 0x22]));
+
+        let oversized = ClientFrame::Raw { channel: 7, data: vec![0; 5] };
+        let routed = route_client_frame(&oversized, connection_id, &connection_manager, &events, Some(&security)).await;
+        assert!(matches!(routed, Err(ServerError::Network(_))));
+        assert!(voice_rx.try_recv().is_err());
+        let (_, response) = outgoing.try_recv().unwrap();
+        let response: serde_json::Value = serde_json::from_slice(&response).unwrap();
+        assert_eq!((response["error"].as_str(), response["channel"].as_u64()), (Some("message_too_large"), Some(7)));
+
+        // JSON messages can't pose as raw ones
+        let spoofed = serde_json::json!({ "namespace": "binary", "event": "7", "data": {} });
+        assert!(route_client_message(&spoofed.to_string(), connection_id, &connection_manager, &events, None).await.is_err());
+        assert!(voice_rx.try_recv().is_err());
+    }
+}
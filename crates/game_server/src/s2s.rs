@@ -0,0 +1,428 @@
+//! WebSocket transport for server-to-server events.
+//!
+//! Region servers, matchmakers and chat services built on Horizon exchange
+//! typed `s2s:*` events over this channel; plugins send them with
+//! [`EventSystem::send_s2s`] and handle them with [`EventSystem::on_s2s`].
+//!
+//! Servers open a WebSocket to a peer's `s2s.listen_address` the first time
+//! they send it an event, presenting the peer's secret (or the shared one) as
+//! `Authorization: Bearer <secret>` and their name in the
+//! [`SERVER_NAME_HEADER`]. Only configured peers are accepted, each with
+//! its own secret if it has one. Either end then sends over the same connection,
+//! so servers that only connect out, like a matchmaker behind NAT, still
+//! receive replies. Each text frame carries one event:
+//!
+//! ```json
+//! { "event": "match_found", "data": { "match_id": 7 }, "timestamp": 1700000000 }
+//! ```
+//!
+//! The sender is the name the connection authenticated as, never a field
+//! of the frame.
+
+use crate::admin::constant_time_eq;
+use crate::config::{S2sConfig, S2sPeerConfig};
+use futures::{SinkExt, StreamExt};
+use horizon_event_system::{current_timestamp, EventError, EventSystem, S2sEvent, ServerLink, ShutdownState};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, OnceLock, Weak};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, Mutex};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::header::{HeaderValue, AUTHORIZATION};
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+use tracing::{debug, info, warn};
+
+/// Header servers name themselves in when they connect
+pub const SERVER_NAME_HEADER: &str = "x-horizon-server";
+
+/// How long connecting to a peer may take
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// One event on the wire
+#[derive(Debug, Serialize, Deserialize)]
+struct S2sFrame {
+    event: String,
+    data: serde_json::Value,
+    timestamp: u64,
+}
+
+/// Open connections, keyed by the name of the server at the other end
+type Connections = Arc<Mutex<HashMap<String, mpsc::UnboundedSender<Message>>>>;
+
+/// Server link that carries `s2s:*` events over authenticated WebSockets
+pub struct S2sLink {
+    /// Name this server authenticates as
+    name: String,
+    /// Secret shared by every server on the channel
+    secret: String,
+    /// Configured peers, keyed by name
+    peers: HashMap<String, S2sPeerConfig>,
+    /// Open connections, inbound and outbound
+    connections: Connections,
+    /// Event system received events are delivered to, once attached
+    events: Arc<OnceLock<Weak<EventSystem>>>,
+}
+
+impl S2sLink {
+    /// Creates the link described by the server-to-server settings.
+    ///
+    /// # Returns
+    ///
+    /// The link, or `None` if no secret is configured and the channel is disabled.
+    pub fn new(config: &S2sConfig) -> Option<Self> {
+        let secret = config.secret.clone()?;
+        Some(Self {
+            name: config.name.clone(),
+            secret,
+            peers: config.peers.iter().map(|peer| (peer.name.clone(), peer.clone())).collect(),
+            connections: Arc::new(Mutex::new(HashMap::new())),
+            events: Arc::new(OnceLock::new()),
+        })
+    }
+
+    /// Delivers received events to an event system from now on.
+    ///
+    /// The link only keeps a weak reference, as the event system holds the link.
+    pub fn attach(&self, events: &Arc<EventSystem>) {
+        let _ = self.events.set(Arc::downgrade(events));
+    }
+
+    /// Secret exchanged with a peer: its own, or the shared one
+    fn peer_secret<'a>(&'a self, peer: &'a S2sPeerConfig) -> &'a str {
+        peer.secret.as_deref().unwrap_or(&self.secret)
+    }
+
+    /// Opens a connection to a configured peer
+    async fn connect(&self, server: &str) -> Result<WebSocketStream<tokio_tungstenite::MaybeTlsStream<TcpStream>>, EventError> {
+        let peer = self
+            .peers
+            .get(server)
+            .ok_or_else(|| EventError::HandlerNotFound(format!("Unknown server {server}")))?;
+        let url = peer
+            .url
+            .as_deref()
+            .ok_or_else(|| EventError::HandlerNotFound(format!("Server {server} is not connected and has no URL")))?;
+        let mut request = url
+            .into_client_request()
+            .map_err(|e| EventError::RuntimeError(format!("Invalid URL of server {server}: {e}")))?;
+        let headers = request.headers_mut();
+        headers.insert(AUTHORIZATION, header_value(&format!("Bearer {}", self.peer_secret(peer)))?);
+        headers.insert(SERVER_NAME_HEADER, header_value(&self.name)?);
+
+        let (ws_stream, _) = tokio::time::timeout(CONNECT_TIMEOUT, tokio_tungstenite::connect_async(request))
+            .await
+            .map_err(|_| EventError::Timeout(format!("Connecting to server {server}")))?
+            .map_err(|e| EventError::RuntimeError(format!("Failed to connect to server {server}: {e}")))?;
+        info!("🔗 S2S: Connected to server {} at {}", server, url);
+        Ok(ws_stream)
+    }
+
+    /// Starts sending and receiving over an authenticated connection.
+    ///
+    /// Replaces any older connection to the same server.
+    ///
+    /// # Returns
+    ///
+    /// The sender queueing frames onto the connection.
+    fn open<S>(
+        &self,
+        connections: &mut HashMap<String, mpsc::UnboundedSender<Message>>,
+        server: String,
+        ws_stream: WebSocketStream<S>,
+    ) -> mpsc::UnboundedSender<Message>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let (mut ws_sender, mut ws_receiver) = ws_stream.split();
+        let (sender, mut outgoing) = mpsc::unbounded_channel();
+        connections.insert(server.clone(), sender.clone());
+
+        tokio::spawn(async move {
+            while let Some(message) = outgoing.recv().await {
+                if ws_sender.send(message).await.is_err() {
+                    break;
+                }
+            }
+            let _ = ws_sender.close().await;
+        });
+
+        let (registered, connections, events) = (sender.clone(), self.connections.clone(), self.events.clone());
+        tokio::spawn(async move {
+            while let Some(Ok(message)) = ws_receiver.next().await {
+                let frame = match message {
+                    Message::Text(text) => serde_json::from_str::<S2sFrame>(&text),
+                    Message::Close(_) => break,
+                    _ => continue,
+                };
+                let Some(events) = events.get().and_then(Weak::upgrade) else {
+                    continue;
+                };
+                match frame {
+                    Ok(frame) => {
+                        let event = S2sEvent { source: server.clone(), data: frame.data, timestamp: frame.timestamp };
+                        if let Err(e) = events.emit_s2s(&frame.event, &event).await {
+                            debug!("🔗 S2S: Failed to deliver {} from server {}: {}", frame.event, server, e);
+                        }
+                    }
+                    Err(e) => warn!("🔗 S2S: Dropped malformed frame from server {}: {}", server, e),
+                }
+            }
+
+            let mut connections = connections.lock().await;
+            if connections.get(&server).is_some_and(|current| current.same_channel(&registered)) {
+                connections.remove(&server);
+            }
+            info!("🔗 S2S: Server {} disconnected", server);
+        });
+
+        sender
+    }
+}
+
+#[horizon_event_system::async_trait]
+impl ServerLink for S2sLink {
+    async fn send(&self, server: &str, event_name: &str, data: serde_json::Value) -> Result<(), EventError> {
+        let frame = S2sFrame { event: event_name.to_string(), data, timestamp: current_timestamp() };
+        let message = Message::text(serde_json::to_string(&frame)?);
+
+        {
+            let mut connections = self.connections.lock().await;
+            if let Some(sender) = connections.get(server) {
+                if sender.send(message.clone()).is_ok() {
+                    return Ok(());
+                }
+                connections.remove(server);
+            }
+        }
+
+        // Connect without the lock, so a slow peer doesn't hold up sends to the others
+        let ws_stream = self.connect(server).await?;
+        let mut connections = self.connections.lock().await;
+        // Another send or the peer itself may have connected in the meantime
+        if let Some(sender) = connections.get(server) {
+            if sender.send(message.clone()).is_ok() {
+                return Ok(());
+            }
+        }
+        self.open(&mut connections, server.to_string(), ws_stream)
+            .send(message)
+            .map_err(|_| EventError::RuntimeError(format!("Connection to server {server} closed")))
+    }
+
+    async fn connected_servers(&self) -> Vec<String> {
+        let mut servers: Vec<String> = self.connections.lock().await.keys().cloned().collect();
+        servers.sort();
+        servers
+    }
+}
+
+fn header_value(value: &str) -> Result<HeaderValue, EventError> {
+    HeaderValue::from_str(value).map_err(|e| EventError::RuntimeError(format!("Invalid header value: {e}")))
+}
+
+/// Checks the name and secret a connecting server presented.
+///
+/// The name must be a configured peer, and the secret that peer's own or,
+/// if it has none, the shared one.
+///
+/// # Returns
+///
+/// The name the server authenticated as, or why it was refused.
+fn authenticate(request: &Request, link: &S2sLink) -> Result<String, &'static str> {
+    let name = request
+        .headers()
+        .get(SERVER_NAME_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .ok_or("Missing server name")?;
+    let peer = link.peers.get(name);
+    // Unknown names are checked against the shared secret too, so the answer doesn't reveal which names exist
+    let secret = peer.map_or(link.secret.as_str(), |peer| link.peer_secret(peer));
+
+    let presented = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim);
+    if !presented.is_some_and(|presented| constant_time_eq(presented.as_bytes(), secret.as_bytes())) || peer.is_none() {
+        return Err("Missing or invalid server secret");
+    }
+    Ok(name.to_string())
+}
+
+/// Accepts connections from other servers until shutdown is initiated.
+///
+/// # Arguments
+///
+/// * `listener` - Bound server-to-server listener
+/// * `link` - Link the authenticated connections are added to
+/// * `shutdown_state` - Optional shutdown state for coordinated shutdown
+pub async fn serve_s2s(listener: TcpListener, link: Arc<S2sLink>, shutdown_state: Option<ShutdownState>) {
+    loop {
+        if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+            break;
+        }
+        let accepted = tokio::time::timeout(Duration::from_millis(250), listener.accept()).await;
+        let Ok(Ok((stream, addr))) = accepted else {
+            continue;
+        };
+
+        let link = link.clone();
+        tokio::spawn(async move {
+            if let Err(e) = accept_server(stream, addr, &link).await {
+                debug!("🔗 S2S: Refused connection from {}: {}", addr, e);
+            }
+        });
+    }
+}
+
+async fn accept_server(stream: TcpStream, addr: SocketAddr, link: &S2sLink) -> Result<(), String> {
+    stream.set_nodelay(true).ok();
+    let mut server = None;
+    #[allow(clippy::result_large_err)]
+    let ws_stream = tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response: Response| {
+        match authenticate(request, link) {
+            Ok(name) => {
+                server = Some(name);
+                Ok(response)
+            }
+            Err(reason) => {
+                let mut error = ErrorResponse::new(Some(reason.to_string()));
+                *error.status_mut() = StatusCode::UNAUTHORIZED;
+                Err(error)
+            }
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let server = server.ok_or("Handshake completed without a server name")?;
+    info!("🔗 S2S: Server {} connected from {}", server, addr);
+    let mut connections = link.connections.lock().await;
+    link.open(&mut connections, server, ws_stream);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::S2sPeerConfig;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct MatchFound {
+        match_id: u32,
+    }
+
+    fn peer(name: &str, url: Option<String>) -> S2sPeerConfig {
+        S2sPeerConfig { name: name.to_string(), url, secret: None }
+    }
+
+    fn server(config: &S2sConfig) -> (Arc<S2sLink>, Arc<EventSystem>) {
+        let link = Arc::new(S2sLink::new(config).unwrap());
+        let mut events = EventSystem::new();
+        events.set_server_link(link.clone());
+        let events = Arc::new(events);
+        link.attach(&events);
+        (link, events)
+    }
+
+    #[tokio::test]
+    async fn test_servers_exchange_events() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let secret = Some("shared".to_string());
+        // The region connects out, so the matchmaker knows it without a URL
+        let matchmaker_config = S2sConfig {
+            name: "matchmaker".to_string(),
+            secret: secret.clone(),
+            peers: vec![peer("region-east", None)],
+            ..S2sConfig::default()
+        };
+        let (matchmaker_link, matchmaker) = server(&matchmaker_config);
+        tokio::spawn(serve_s2s(listener, matchmaker_link, None));
+        let region_config = S2sConfig {
+            name: "region-east".to_string(),
+            secret,
+            peers: vec![peer("matchmaker", Some(format!("ws://{address}")))],
+            ..S2sConfig::default()
+        };
+        let (_region_link, region) = server(&region_config);
+
+        let (found_sender, mut found) = mpsc::unbounded_channel();
+        matchmaker
+            .on_s2s("match_found", move |event: S2sEvent<MatchFound>| {
+                let _ = found_sender.send(event);
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (ack_sender, mut acks) = mpsc::unbounded_channel();
+        region
+            .on_s2s("match_ack", move |event: S2sEvent<u32>| {
+                let _ = ack_sender.send((event.source, event.data));
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        region.send_s2s("matchmaker", "match_found", &MatchFound { match_id: 7 }).await.unwrap();
+        let event = tokio::time::timeout(Duration::from_secs(5), found.recv()).await.unwrap().unwrap();
+        assert_eq!((event.source.as_str(), event.data), ("region-east", MatchFound { match_id: 7 }));
+
+        // The matchmaker answers over the connection the region opened
+        matchmaker.send_s2s("region-east", "match_ack", &7u32).await.unwrap();
+        let ack = tokio::time::timeout(Duration::from_secs(5), acks.recv()).await.unwrap().unwrap();
+        assert_eq!(ack, ("matchmaker".to_string(), 7));
+        assert!(region.send_s2s("chat", "match_found", &MatchFound { match_id: 8 }).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated_servers_are_refused() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let config = S2sConfig {
+            name: "matchmaker".to_string(),
+            secret: Some("shared".to_string()),
+            peers: vec![
+                peer("region-west", None),
+                S2sPeerConfig { secret: Some("chat-only".to_string()), ..peer("chat", None) },
+            ],
+            ..S2sConfig::default()
+        };
+        let (link, _events) = server(&config);
+        tokio::spawn(serve_s2s(listener, link.clone(), None));
+        let connect_as = |name: &str, secret: &str| S2sConfig {
+            name: name.to_string(),
+            secret: Some(secret.to_string()),
+            peers: vec![peer("matchmaker", Some(format!("ws://{address}")))],
+            ..S2sConfig::default()
+        };
+
+        // A wrong secret, a name that isn't a configured peer, and the shared
+        // secret for a peer that has its own are all refused
+        for (name, secret) in [("region-west", "guessed"), ("region-south", "shared"), ("chat", "shared")] {
+            let (_impostor_link, impostor) = server(&connect_as(name, secret));
+            assert!(impostor.send_s2s("matchmaker", "match_found", &MatchFound { match_id: 7 }).await.is_err());
+        }
+        assert!(link.connected_servers().await.is_empty());
+
+        let (_chat_link, chat) = server(&connect_as("chat", "chat-only"));
+        chat.send_s2s("matchmaker", "match_found", &MatchFound { match_id: 7 }).await.unwrap();
+        for _ in 0..100 {
+            if !link.connected_servers().await.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(link.connected_servers().await, vec!["chat".to_string()]);
+    }
+}
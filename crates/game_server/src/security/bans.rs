@@ -0,0 +1,272 @@
+//! Persistent ban list refusing addresses, players and client hardware.
+//!
+//! Bans are kept in memory for fast checks on every connection and, when
+//! the store has a storage backend, written through to it on every change
+//! so they survive restarts. Bans can be lifted at runtime through the admin
+//! API, and temporary bans stop applying once they expire.
+
+use horizon_event_system::{current_timestamp, PlayerId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use tracing::warn;
+
+/// What a ban refuses
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum BanTarget {
+    /// Connections from an IP address
+    Ip(IpAddr),
+    /// A player, whichever address they connect from
+    Player(PlayerId),
+    /// A client machine, identified by the `hwid` its client sends on connect
+    Hardware(String),
+}
+
+impl std::fmt::Display for BanTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ip(ip) => write!(f, "address {ip}"),
+            Self::Player(player_id) => write!(f, "player {player_id}"),
+            Self::Hardware(hardware_id) => write!(f, "hardware {hardware_id}"),
+        }
+    }
+}
+
+/// A single ban
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BanEntry {
+    /// What is banned
+    pub target: BanTarget,
+    /// Why the ban was issued
+    pub reason: Option<String>,
+    /// Unix timestamp when the ban was issued
+    pub banned_at: u64,
+    /// Unix timestamp when the ban lapses, or `None` for a permanent ban
+    pub expires_at: Option<u64>,
+}
+
+impl BanEntry {
+    /// Creates a ban issued now
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - What is banned
+    /// * `reason` - Why the ban was issued
+    /// * `duration_secs` - How long the ban lasts, or `None` for a permanent ban
+    pub fn new(target: BanTarget, reason: Option<String>, duration_secs: Option<u64>) -> Self {
+        let banned_at = current_timestamp();
+        Self {
+            target,
+            reason,
+            banned_at,
+            expires_at: duration_secs.map(|duration| banned_at.saturating_add(duration)),
+        }
+    }
+
+    /// Checks whether the ban has lapsed at the given Unix timestamp
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Errors raised while loading or saving the ban list
+#[derive(Debug, Error)]
+pub enum BanStoreError {
+    /// Reading or writing the ban list failed
+    #[error("Ban list I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The ban list could not be encoded or decoded
+    #[error("Ban list serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Backend the ban list is persisted to
+pub trait BanStorage: std::fmt::Debug + Send + Sync {
+    /// Loads every stored ban
+    fn load(&self) -> Result<Vec<BanEntry>, BanStoreError>;
+
+    /// Replaces the stored bans
+    fn save(&self, entries: &[BanEntry]) -> Result<(), BanStoreError>;
+}
+
+/// Ban list stored as a JSON file
+#[derive(Debug, Clone)]
+pub struct FileBanStorage {
+    path: PathBuf,
+}
+
+impl FileBanStorage {
+    /// Creates a storage backend for the file at `path`, which doesn't need to exist yet
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Gets the path of the ban list file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl BanStorage for FileBanStorage {
+    fn load(&self) -> Result<Vec<BanEntry>, BanStoreError> {
+        match std::fs::read(&self.path) {
+            Ok(contents) => Ok(serde_json::from_slice(&contents)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Writes the file next to its destination and renames it into place,
+    /// so a crash mid-save never leaves a truncated ban list behind.
+    fn save(&self, entries: &[BanEntry]) -> Result<(), BanStoreError> {
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let temp_path = self.path.with_extension("tmp");
+        std::fs::write(&temp_path, serde_json::to_vec_pretty(entries)?)?;
+        std::fs::rename(&temp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Runtime-mutable ban list, optionally backed by persistent storage
+#[derive(Debug, Default)]
+pub struct BanStore {
+    entries: std::sync::RwLock<HashMap<BanTarget, BanEntry>>,
+    storage: Option<Box<dyn BanStorage>>,
+}
+
+impl BanStore {
+    /// Creates an empty ban list that only lives in memory
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a ban list persisted to the given storage.
+    ///
+    /// Bans that expired while the server was down are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the stored bans can't be read.
+    pub fn open(storage: Box<dyn BanStorage>) -> Result<Self, BanStoreError> {
+        let now = current_timestamp();
+        let entries = storage
+            .load()?
+            .into_iter()
+            .filter(|entry| !entry.is_expired(now))
+            .map(|entry| (entry.target.clone(), entry))
+            .collect();
+        Ok(Self {
+            entries: std::sync::RwLock::new(entries),
+            storage: Some(storage),
+        })
+    }
+
+    /// Adds a ban, replacing any earlier ban of the same target.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target wasn't banned yet.
+    pub fn ban(&self, entry: BanEntry) -> bool {
+        let now = current_timestamp();
+        let newly_banned = {
+            let mut entries = self.write();
+            entries.retain(|_, existing| !existing.is_expired(now));
+            entries.insert(entry.target.clone(), entry).is_none()
+        };
+        self.persist();
+        newly_banned
+    }
+
+    /// Lifts the ban of a target
+    ///
+    /// # Returns
+    ///
+    /// `true` if the target was banned.
+    pub fn unban(&self, target: &BanTarget) -> bool {
+        let now = current_timestamp();
+        let lifted = self
+            .write()
+            .remove(target)
+            .is_some_and(|entry| !entry.is_expired(now));
+        if lifted {
+            self.persist();
+        }
+        lifted
+    }
+
+    /// Gets the ban currently in effect for a target
+    pub fn get(&self, target: &BanTarget) -> Option<BanEntry> {
+        let now = current_timestamp();
+        self.read().get(target).filter(|entry| !entry.is_expired(now)).cloned()
+    }
+
+    /// Checks whether a target is currently banned
+    pub fn is_banned(&self, target: &BanTarget) -> bool {
+        self.get(target).is_some()
+    }
+
+    /// Gets every ban in effect, oldest first
+    pub fn entries(&self) -> Vec<BanEntry> {
+        let now = current_timestamp();
+        let mut entries: Vec<BanEntry> = self.read().values().filter(|entry| !entry.is_expired(now)).cloned().collect();
+        entries.sort_by_key(|entry| (entry.banned_at, entry.target.to_string()));
+        entries
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<BanTarget, BanEntry>> {
+        self.entries.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<BanTarget, BanEntry>> {
+        self.entries.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Writes the bans through to storage. A failed write keeps the ban in
+    /// effect for this run, so it is logged rather than surfaced.
+    fn persist(&self) {
+        let Some(storage) = &self.storage else {
+            return;
+        };
+        let entries: Vec<BanEntry> = self.read().values().cloned().collect();
+        if let Err(e) = storage.save(&entries) {
+            warn!("🔨 Failed to save the ban list: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bans_expire_and_persist() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bans.json");
+        let store = BanStore::open(Box::new(FileBanStorage::new(&path))).unwrap();
+        let player_id = PlayerId::new();
+
+        assert!(store.ban(BanEntry::new(BanTarget::Ip("10.0.0.1".parse().unwrap()), None, None)));
+        assert!(store.ban(BanEntry::new(BanTarget::Player(player_id), Some("cheating".to_string()), Some(3600))));
+        assert!(store.ban(BanEntry::new(BanTarget::Hardware("abc".to_string()), None, Some(0))));
+        assert!(!store.ban(BanEntry::new(BanTarget::Ip("10.0.0.1".parse().unwrap()), None, None)));
+
+        // A zero-length ban lapses immediately
+        assert!(!store.is_banned(&BanTarget::Hardware("abc".to_string())));
+        assert!(store.is_banned(&BanTarget::Player(player_id)));
+
+        let reopened = BanStore::open(Box::new(FileBanStorage::new(&path))).unwrap();
+        assert_eq!(reopened.entries().len(), 2);
+        assert_eq!(reopened.get(&BanTarget::Player(player_id)).unwrap().reason.as_deref(), Some("cheating"));
+
+        assert!(reopened.unban(&BanTarget::Ip("10.0.0.1".parse().unwrap())));
+        assert!(!reopened.unban(&BanTarget::Ip("10.0.0.1".parse().unwrap())));
+        let reopened = BanStore::open(Box::new(FileBanStorage::new(&path))).unwrap();
+        assert_eq!(reopened.entries().len(), 1);
+    }
+}
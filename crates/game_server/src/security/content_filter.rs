@@ -0,0 +1,232 @@
+//! Chat content filtering with word lists, patterns and mutes.
+//!
+//! Each rule of the `ContentFilterConfig` compiles to a single
+//! case-insensitive regular expression. Plugins reach the filter through
+//! `ServerContext::content_filter`, so the player plugin's communication
+//! handler and any other chat plugin censor, drop and mute alike, and a
+//! player muted in one chat stays muted in every other.
+
+use crate::config::{ContentFilterAction, ContentFilterConfig, ContentFilterRule};
+use horizon_event_system::{current_timestamp, ContentFilter, ContentVerdict, PlayerId};
+use regex::{Regex, RegexBuilder};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use thiserror::Error;
+
+/// Errors raised while compiling the content filter rules
+#[derive(Debug, Error)]
+pub enum ContentFilterError {
+    /// A pattern isn't a valid regular expression
+    #[error("Invalid pattern in content filter rule '{rule}': {source}")]
+    InvalidPattern { rule: String, source: regex::Error },
+    /// A word list file could not be read
+    #[error("Failed to read the word list of content filter rule '{rule}': {source}")]
+    WordList { rule: String, source: std::io::Error },
+}
+
+/// Content filter backed by the server's configured rules
+#[derive(Debug)]
+pub struct ChatFilter {
+    rules: Vec<CompiledRule>,
+    mute_duration_secs: u64,
+    mask: char,
+    /// Muted players, with when their mute ends and the rule they broke
+    mutes: Mutex<HashMap<PlayerId, (u64, String)>>,
+}
+
+#[derive(Debug)]
+struct CompiledRule {
+    name: String,
+    action: ContentFilterAction,
+    /// Lowercase locales the rule applies to, empty for all
+    locales: Vec<String>,
+    matcher: Regex,
+}
+
+impl ChatFilter {
+    /// Compiles the configured rules, reading their word list files.
+    ///
+    /// # Returns
+    ///
+    /// The filter, or a `ContentFilterError` naming the rule that failed to compile.
+    pub fn new(config: &ContentFilterConfig) -> Result<Self, ContentFilterError> {
+        let rules = config
+            .rules
+            .iter()
+            .enumerate()
+            .filter_map(|(index, rule)| compile_rule(index, rule).transpose())
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            rules,
+            mute_duration_secs: config.mute_duration_secs,
+            mask: config.mask,
+            mutes: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Lifts a player's mute early.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player was muted.
+    pub fn unmute(&self, player_id: PlayerId) -> bool {
+        let mut mutes = self.mutes.lock().unwrap_or_else(|e| e.into_inner());
+        mutes.remove(&player_id).is_some_and(|(until, _)| until > current_timestamp())
+    }
+
+    fn mask_matches(&self, text: &str, matcher: &Regex) -> String {
+        matcher
+            .replace_all(text, |captures: &regex::Captures| {
+                std::iter::repeat_n(self.mask, captures[0].chars().count()).collect::<String>()
+            })
+            .into_owned()
+    }
+}
+
+impl ContentFilter for ChatFilter {
+    fn check(&self, player_id: PlayerId, text: &str, locale: Option<&str>) -> ContentVerdict {
+        let now = current_timestamp();
+        {
+            let mut mutes = self.mutes.lock().unwrap_or_else(|e| e.into_inner());
+            match mutes.get(&player_id) {
+                Some((until, rule)) if *until > now => return ContentVerdict::Mute { rule: rule.clone(), until: *until },
+                Some(_) => {
+                    mutes.remove(&player_id);
+                }
+                None => {}
+            }
+        }
+
+        let locale = locale.map(str::to_ascii_lowercase);
+        let broken: Vec<&CompiledRule> = self
+            .rules
+            .iter()
+            .filter(|rule| locale.as_deref().is_none_or(|locale| rule.applies_to(locale)))
+            .filter(|rule| rule.matcher.is_match(text))
+            .collect();
+        let Some(worst) = broken.iter().max_by_key(|rule| rule.action) else {
+            return ContentVerdict::Allow;
+        };
+
+        match worst.action {
+            ContentFilterAction::Mute => {
+                let until = now + self.mute_duration_secs;
+                let mut mutes = self.mutes.lock().unwrap_or_else(|e| e.into_inner());
+                mutes.insert(player_id, (until, worst.name.clone()));
+                ContentVerdict::Mute { rule: worst.name.clone(), until }
+            }
+            ContentFilterAction::Drop => ContentVerdict::Drop { rule: worst.name.clone() },
+            ContentFilterAction::Censor => ContentVerdict::Censor(
+                broken
+                    .iter()
+                    .fold(text.to_string(), |censored, rule| self.mask_matches(&censored, &rule.matcher)),
+            ),
+        }
+    }
+}
+
+impl CompiledRule {
+    /// Checks whether the rule covers a lowercase locale, `"pt"` covering `"pt-br"`
+    fn applies_to(&self, locale: &str) -> bool {
+        self.locales.is_empty()
+            || self.locales.iter().any(|rule_locale| {
+                locale == rule_locale || locale.strip_prefix(rule_locale.as_str()).is_some_and(|rest| rest.starts_with('-'))
+            })
+    }
+}
+
+/// Compiles a rule into one regular expression.
+///
+/// # Returns
+///
+/// The compiled rule, or `None` if it has no words or patterns to match.
+fn compile_rule(index: usize, rule: &ContentFilterRule) -> Result<Option<CompiledRule>, ContentFilterError> {
+    let name = if rule.name.is_empty() { format!("rule {}", index + 1) } else { rule.name.clone() };
+
+    let mut words = rule.words.clone();
+    if let Some(path) = &rule.word_list_path {
+        let list = std::fs::read_to_string(path).map_err(|source| ContentFilterError::WordList { rule: name.clone(), source })?;
+        words.extend(list.lines().map(str::trim).filter(|word| !word.is_empty() && !word.starts_with('#')).map(String::from));
+    }
+
+    let mut alternatives: Vec<String> = rule.patterns.iter().map(|pattern| format!("(?:{pattern})")).collect();
+    let words: Vec<String> = words.iter().map(|word| word.trim()).filter(|word| !word.is_empty()).map(regex::escape).collect();
+    if !words.is_empty() {
+        alternatives.push(format!(r"\b(?:{})\b", words.join("|")));
+    }
+    if alternatives.is_empty() {
+        return Ok(None);
+    }
+
+    let matcher = RegexBuilder::new(&alternatives.join("|"))
+        .case_insensitive(true)
+        .build()
+        .map_err(|source| ContentFilterError::InvalidPattern { rule: name.clone(), source })?;
+    Ok(Some(CompiledRule {
+        name,
+        action: rule.action,
+        locales: rule.locales.iter().map(|locale| locale.to_ascii_lowercase()).collect(),
+        matcher,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(name: &str, words: &[&str], patterns: &[&str], action: ContentFilterAction, locales: &[&str]) -> ContentFilterRule {
+        ContentFilterRule {
+            name: name.to_string(),
+            words: words.iter().map(|word| word.to_string()).collect(),
+            patterns: patterns.iter().map(|pattern| pattern.to_string()).collect(),
+            action,
+            locales: locales.iter().map(|locale| locale.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rules_censor_drop_and_mute_by_locale() {
+        let filter = ChatFilter::new(&ContentFilterConfig {
+            rules: vec![
+                rule("profanity", &["darn", "heck"], &[], ContentFilterAction::Censor, &[]),
+                rule("spam", &[], &[r"fr[e3]{2}\s*gold"], ContentFilterAction::Drop, &[]),
+                rule("portuguese", &["bobo"], &[], ContentFilterAction::Censor, &["pt"]),
+                rule("threats", &["grief you"], &[], ContentFilterAction::Mute, &[]),
+            ],
+            ..Default::default()
+        })
+        .unwrap();
+        let player = PlayerId::new();
+
+        assert_eq!(filter.check(player, "Docking at Alpha", Some("en")), ContentVerdict::Allow);
+        // Whole words only, ignoring case
+        assert_eq!(filter.check(player, "DARN it, heckle", None), ContentVerdict::Censor("**** it, heckle".to_string()));
+        assert_eq!(filter.check(player, "FR33 gold here", None), ContentVerdict::Drop { rule: "spam".to_string() });
+
+        // Locale lists only apply to their locale and its regional variants
+        assert_eq!(filter.check(player, "seu bobo", Some("en-US")), ContentVerdict::Allow);
+        assert_eq!(filter.check(player, "seu bobo", Some("pt-BR")), ContentVerdict::Censor("seu ****".to_string()));
+
+        // The harshest broken rule wins, and the mute outlasts the message
+        let ContentVerdict::Mute { rule, until } = filter.check(player, "darn, I'll grief you", None) else {
+            panic!("expected a mute");
+        };
+        assert_eq!(rule, "threats");
+        assert!(until >= current_timestamp() + 299);
+        assert_eq!(filter.check(player, "sorry", None), ContentVerdict::Mute { rule, until });
+        assert_eq!(filter.check(PlayerId::new(), "sorry", None), ContentVerdict::Allow);
+
+        assert!(filter.unmute(player));
+        assert_eq!(filter.check(player, "sorry", None), ContentVerdict::Allow);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_reported() {
+        let config = ContentFilterConfig {
+            rules: vec![rule("broken", &[], &["(unclosed"], ContentFilterAction::Drop, &[])],
+            ..Default::default()
+        };
+        assert!(matches!(ChatFilter::new(&config), Err(ContentFilterError::InvalidPattern { ref rule, .. }) if rule == "broken"));
+    }
+}
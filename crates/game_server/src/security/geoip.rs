@@ -0,0 +1,267 @@
+//! GeoIP connection policy with country rules and per-region caps.
+//!
+//! Each connecting address is looked up in a MaxMind database. Clients from
+//! denied countries, or from countries outside a non-empty allow list, are
+//! refused, as are clients whose country or continent already has as many
+//! connections as its cap allows. The country and continent found are kept
+//! on the connection and exposed to plugins as its `geo` metadata, as
+//! `{"country": "DE", "continent": "EU"}`, so matchmaking plugins can group
+//! nearby players.
+
+use crate::config::GeoIpConfig;
+use maxminddb::{geoip2, MaxMindDBError, Reader};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::Mutex;
+use thiserror::Error;
+use tracing::warn;
+
+/// Errors raised while opening the GeoIP database or admitting a connection
+#[derive(Debug, Error)]
+pub enum GeoIpError {
+    /// The database could not be opened or read
+    #[error("GeoIP database error: {0}")]
+    Database(#[from] MaxMindDBError),
+    /// The client's country isn't allowed, or is denied
+    #[error("Connections from {0} are not allowed")]
+    CountryRefused(String),
+    /// The client's country or continent is at its connection cap
+    #[error("Connection cap of {cap} for {region} reached")]
+    RegionFull { region: String, cap: u32 },
+}
+
+/// Where an address is located
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GeoTag {
+    /// ISO country code, such as `"DE"`
+    pub country: Option<String>,
+    /// Continent code, such as `"EU"`
+    pub continent: Option<String>,
+}
+
+/// Source of address locations
+pub trait GeoLookup: std::fmt::Debug + Send + Sync {
+    /// Locates an address, with empty fields for what isn't known
+    fn locate(&self, ip: IpAddr) -> Result<GeoTag, GeoIpError>;
+}
+
+/// Locations from a MaxMind `.mmdb` database
+#[derive(Debug)]
+pub struct MaxMindLookup {
+    reader: Reader<Vec<u8>>,
+}
+
+impl MaxMindLookup {
+    /// Reads the database at `path` into memory
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self, GeoIpError> {
+        Ok(Self { reader: Reader::open_readfile(path)? })
+    }
+}
+
+impl GeoLookup for MaxMindLookup {
+    fn locate(&self, ip: IpAddr) -> Result<GeoTag, GeoIpError> {
+        // IPv4 clients on a dual-stack socket show up as mapped IPv6 addresses
+        let ip = match ip {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+            IpAddr::V4(_) => ip,
+        };
+        match self.reader.lookup::<geoip2::Country>(ip) {
+            Ok(record) => Ok(GeoTag {
+                country: record.country.and_then(|country| country.iso_code).map(String::from),
+                continent: record.continent.and_then(|continent| continent.code).map(String::from),
+            }),
+            Err(MaxMindDBError::AddressNotFoundError(_)) => Ok(GeoTag::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Country rules and region caps connections are admitted by
+#[derive(Debug)]
+pub struct GeoPolicy {
+    lookup: Box<dyn GeoLookup>,
+    allow_countries: HashSet<String>,
+    deny_countries: HashSet<String>,
+    refuse_unknown: bool,
+    country_caps: HashMap<String, u32>,
+    continent_caps: HashMap<String, u32>,
+    /// Connections admitted per capped country and continent, keyed by `country:<code>` or `continent:<code>`
+    connections: Mutex<HashMap<String, u32>>,
+}
+
+impl GeoPolicy {
+    /// Opens the configured database.
+    ///
+    /// # Returns
+    ///
+    /// The policy, `None` if no database is configured, or the error that
+    /// prevented reading the database.
+    pub fn open(config: &GeoIpConfig) -> Result<Option<Self>, GeoIpError> {
+        let Some(path) = &config.database_path else {
+            return Ok(None);
+        };
+        Ok(Some(Self::with_lookup(config, MaxMindLookup::open(path)?)))
+    }
+
+    /// Creates a policy looking addresses up in the given source
+    pub fn with_lookup(config: &GeoIpConfig, lookup: impl GeoLookup + 'static) -> Self {
+        let codes = |codes: &[String]| codes.iter().map(|code| code.to_ascii_uppercase()).collect();
+        let caps = |caps: &HashMap<String, u32>| caps.iter().map(|(code, cap)| (code.to_ascii_uppercase(), *cap)).collect();
+        Self {
+            lookup: Box::new(lookup),
+            allow_countries: codes(&config.allow_countries),
+            deny_countries: codes(&config.deny_countries),
+            refuse_unknown: config.refuse_unknown,
+            country_caps: caps(&config.country_connection_caps),
+            continent_caps: caps(&config.continent_connection_caps),
+            connections: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Admits a connection from an address, counting it against its region's caps.
+    ///
+    /// A database error admits the connection untagged rather than
+    /// refusing it. Every admitted connection must be handed to
+    /// [`release`](Self::release) once it closes.
+    ///
+    /// # Returns
+    ///
+    /// Where the address is located, or the `GeoIpError` it was refused with.
+    pub fn admit(&self, ip: IpAddr) -> Result<GeoTag, GeoIpError> {
+        let tag = self.lookup.locate(ip).unwrap_or_else(|e| {
+            warn!("🌍 GeoIP lookup for {} failed: {}", ip, e);
+            GeoTag::default()
+        });
+
+        match &tag.country {
+            Some(country) if self.deny_countries.contains(country) => {
+                return Err(GeoIpError::CountryRefused(country.clone()));
+            }
+            Some(country) if !self.allow_countries.is_empty() && !self.allow_countries.contains(country) => {
+                return Err(GeoIpError::CountryRefused(country.clone()));
+            }
+            None if self.refuse_unknown => return Err(GeoIpError::CountryRefused("unknown locations".to_string())),
+            _ => {}
+        }
+
+        let capped = self.capped_regions(&tag);
+        let mut connections = self.connections.lock().unwrap_or_else(|e| e.into_inner());
+        for (key, cap) in &capped {
+            if connections.get(key).copied().unwrap_or_default() >= *cap {
+                let region = key.split_once(':').map_or(key.as_str(), |(_, code)| code).to_string();
+                return Err(GeoIpError::RegionFull { region, cap: *cap });
+            }
+        }
+        for (key, _) in capped {
+            *connections.entry(key).or_default() += 1;
+        }
+        Ok(tag)
+    }
+
+    /// Stops counting a closed connection against its region's caps
+    pub fn release(&self, tag: &GeoTag) {
+        let mut connections = self.connections.lock().unwrap_or_else(|e| e.into_inner());
+        for (key, _) in self.capped_regions(tag) {
+            if let Some(count) = connections.get_mut(&key) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    connections.remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Gets the connections counted against each capped region, keyed by
+    /// `country:<code>` or `continent:<code>`
+    pub fn region_connections(&self) -> HashMap<String, u32> {
+        self.connections.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Gets the capped regions a location falls in, with their caps
+    fn capped_regions(&self, tag: &GeoTag) -> Vec<(String, u32)> {
+        let country = tag
+            .country
+            .as_ref()
+            .and_then(|country| Some((format!("country:{country}"), *self.country_caps.get(country)?)));
+        let continent = tag
+            .continent
+            .as_ref()
+            .and_then(|continent| Some((format!("continent:{continent}"), *self.continent_caps.get(continent)?)));
+        country.into_iter().chain(continent).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Locates 10.0.1.x in Germany, 10.0.2.x in France, 10.0.3.x in the US and nothing else
+    #[derive(Debug)]
+    struct FixedLookup;
+
+    impl GeoLookup for FixedLookup {
+        fn locate(&self, ip: IpAddr) -> Result<GeoTag, GeoIpError> {
+            let IpAddr::V4(v4) = ip else {
+                return Ok(GeoTag::default());
+            };
+            let (country, continent) = match v4.octets()[2] {
+                1 => ("DE", "EU"),
+                2 => ("FR", "EU"),
+                3 => ("US", "NA"),
+                _ => return Ok(GeoTag::default()),
+            };
+            Ok(GeoTag { country: Some(country.to_string()), continent: Some(continent.to_string()) })
+        }
+    }
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn test_country_rules() {
+        let config = GeoIpConfig {
+            allow_countries: vec!["de".to_string(), "US".to_string()],
+            deny_countries: vec!["US".to_string()],
+            ..Default::default()
+        };
+        let policy = GeoPolicy::with_lookup(&config, FixedLookup);
+
+        let tag = policy.admit(ip("10.0.1.5")).unwrap();
+        assert_eq!(tag.country.as_deref(), Some("DE"));
+        assert_eq!(tag.continent.as_deref(), Some("EU"));
+        // Denied even though allowed, and outside the allow list
+        assert!(matches!(policy.admit(ip("10.0.3.5")), Err(GeoIpError::CountryRefused(ref country)) if country == "US"));
+        assert!(matches!(policy.admit(ip("10.0.2.5")), Err(GeoIpError::CountryRefused(_))));
+        // Unknown locations pass unless refused outright
+        assert_eq!(policy.admit(ip("192.168.0.2")).unwrap(), GeoTag::default());
+
+        let strict = GeoPolicy::with_lookup(&GeoIpConfig { refuse_unknown: true, ..Default::default() }, FixedLookup);
+        assert!(strict.admit(ip("192.168.0.2")).is_err());
+        assert!(strict.admit(ip("10.0.2.5")).is_ok());
+    }
+
+    #[test]
+    fn test_region_caps_are_released_on_close() {
+        let config = GeoIpConfig {
+            country_connection_caps: HashMap::from([("DE".to_string(), 1)]),
+            continent_connection_caps: HashMap::from([("EU".to_string(), 2)]),
+            ..Default::default()
+        };
+        let policy = GeoPolicy::with_lookup(&config, FixedLookup);
+
+        let german = policy.admit(ip("10.0.1.1")).unwrap();
+        assert!(matches!(policy.admit(ip("10.0.1.2")), Err(GeoIpError::RegionFull { ref region, cap: 1 }) if region == "DE"));
+        let french = policy.admit(ip("10.0.2.1")).unwrap();
+        assert!(matches!(policy.admit(ip("10.0.2.2")), Err(GeoIpError::RegionFull { ref region, cap: 2 }) if region == "EU"));
+        // A refused connection takes no slot, and uncapped regions are never full
+        assert_eq!(policy.region_connections(), HashMap::from([("country:DE".to_string(), 1), ("continent:EU".to_string(), 2)]));
+        assert!(policy.admit(ip("10.0.3.1")).is_ok());
+
+        policy.release(&german);
+        assert!(policy.admit(ip("10.0.1.2")).is_ok());
+        policy.release(&french);
+        assert_eq!(policy.region_connections().get("continent:EU"), Some(&1));
+    }
+}
@@ -0,0 +1,295 @@
+//! IP allow and deny lists with CIDR ranges that can change at runtime.
+//!
+//! The lists start out as configured in `SecurityConfig`. When a filter file
+//! is configured it takes over: the file is read on startup, checked for
+//! changes on an interval, and rewritten when the lists are replaced through
+//! the admin API, so operators can block an attacking range without a
+//! restart that would drop every player.
+
+use horizon_event_system::ShutdownState;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
+use thiserror::Error;
+use tracing::{info, warn};
+
+/// Errors raised while parsing or loading IP filter lists
+#[derive(Debug, Error)]
+pub enum IpFilterError {
+    /// A list entry isn't an address or CIDR range
+    #[error("Invalid IP network '{0}'")]
+    InvalidNetwork(String),
+    /// Reading or writing the filter file failed
+    #[error("IP filter I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The filter file could not be encoded or decoded
+    #[error("IP filter serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// An IP address range in CIDR notation, such as `10.0.0.0/8`.
+///
+/// A bare address is a range of that single address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct IpNetwork {
+    address: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpNetwork {
+    /// Checks whether an address falls within the range
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.address, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                prefix_matches(u32::from(network).into(), u32::from(ip).into(), self.prefix_len, 32)
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => prefix_matches(network.into(), ip.into(), self.prefix_len, 128),
+            // IPv4 clients on a dual-stack socket show up as mapped IPv6 addresses
+            (IpAddr::V4(_), IpAddr::V6(ip)) => ip.to_ipv4_mapped().is_some_and(|ip| self.contains(IpAddr::V4(ip))),
+            (IpAddr::V6(_), IpAddr::V4(_)) => false,
+        }
+    }
+}
+
+/// Compares the leading `prefix_len` bits of two addresses `bits` wide
+fn prefix_matches(network: u128, ip: u128, prefix_len: u8, bits: u32) -> bool {
+    let ignored = bits - u32::from(prefix_len);
+    ignored >= bits || (network ^ ip) >> ignored == 0
+}
+
+impl FromStr for IpNetwork {
+    type Err = IpFilterError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || IpFilterError::InvalidNetwork(value.to_string());
+        let (address, prefix_len) = match value.trim().split_once('/') {
+            Some((address, prefix_len)) => (address, Some(prefix_len)),
+            None => (value.trim(), None),
+        };
+        let address: IpAddr = address.parse().map_err(|_| invalid())?;
+        let max_prefix_len = if address.is_ipv4() { 32 } else { 128 };
+        let prefix_len = match prefix_len {
+            Some(prefix_len) => prefix_len.parse::<u8>().ok().filter(|len| *len <= max_prefix_len).ok_or_else(invalid)?,
+            None => max_prefix_len,
+        };
+        Ok(Self { address, prefix_len })
+    }
+}
+
+impl TryFrom<String> for IpNetwork {
+    type Error = IpFilterError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<IpNetwork> for String {
+    fn from(network: IpNetwork) -> Self {
+        network.to_string()
+    }
+}
+
+impl std::fmt::Display for IpNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_len)
+    }
+}
+
+/// Allow and deny lists of IP ranges
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IpFilterLists {
+    /// Ranges clients may connect from; empty allows every address
+    pub allow: Vec<IpNetwork>,
+    /// Ranges refused even if allowed
+    pub deny: Vec<IpNetwork>,
+}
+
+impl IpFilterLists {
+    /// Checks whether clients may connect from an address
+    pub fn permits(&self, ip: IpAddr) -> bool {
+        !self.deny.iter().any(|network| network.contains(ip))
+            && (self.allow.is_empty() || self.allow.iter().any(|network| network.contains(ip)))
+    }
+}
+
+/// IP filter consulted for every connection
+#[derive(Debug, Default)]
+pub struct IpFilter {
+    lists: RwLock<IpFilterLists>,
+    path: Option<PathBuf>,
+    /// Modification time of the filter file when last loaded
+    modified: Mutex<Option<SystemTime>>,
+}
+
+impl IpFilter {
+    /// Creates a filter with fixed initial lists
+    pub fn new(lists: IpFilterLists) -> Self {
+        Self {
+            lists: RwLock::new(lists),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a filter backed by a JSON file of `{"allow": [...], "deny": [...]}`.
+    ///
+    /// The initial lists are used until the file is loaded with
+    /// [`IpFilter::reload_if_changed`].
+    pub fn with_file(lists: IpFilterLists, path: impl Into<PathBuf>) -> Self {
+        Self {
+            lists: RwLock::new(lists),
+            path: Some(path.into()),
+            modified: Mutex::new(None),
+        }
+    }
+
+    /// Gets the filter file, if the filter has one
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Checks whether clients may connect from an address
+    pub fn permits(&self, ip: IpAddr) -> bool {
+        self.lists.read().unwrap_or_else(|poisoned| poisoned.into_inner()).permits(ip)
+    }
+
+    /// Gets the lists in effect
+    pub fn lists(&self) -> IpFilterLists {
+        self.lists.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+
+    /// Replaces the lists, writing them to the filter file if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the filter file can't be written. The new lists are in effect either way.
+    pub fn replace(&self, lists: IpFilterLists) -> Result<(), IpFilterError> {
+        *self.lists.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = lists.clone();
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let mut modified = self.modified.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, serde_json::to_vec_pretty(&lists)?)?;
+        std::fs::rename(&temp_path, path)?;
+        // The watcher shouldn't load back what was just written
+        *modified = modification_time(path);
+        Ok(())
+    }
+
+    /// Reloads the lists if the filter file changed since it was loaded.
+    ///
+    /// If loading fails, the previous lists stay in effect until the file
+    /// changes again.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the lists were reloaded.
+    pub fn reload_if_changed(&self) -> Result<bool, IpFilterError> {
+        let Some(path) = &self.path else {
+            return Ok(false);
+        };
+        let mut last_modified = self.modified.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let modified = modification_time(path);
+        if modified.is_none() || modified == *last_modified {
+            return Ok(false);
+        }
+
+        *last_modified = modified;
+        let lists: IpFilterLists = serde_json::from_slice(&std::fs::read(path)?)?;
+        *self.lists.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = lists;
+        Ok(true)
+    }
+}
+
+fn modification_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// Reloads a changed IP filter file on an interval until shutdown
+///
+/// # Arguments
+///
+/// * `filter` - Filter to keep current
+/// * `interval` - Time between checks for a changed file
+/// * `shutdown_state` - Optional shutdown state that stops the checks
+pub fn spawn_ip_filter_reloader(filter: Arc<IpFilter>, interval: Duration, shutdown_state: Option<ShutdownState>) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // The first tick completes immediately and the filter was just loaded
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+                break;
+            }
+            match filter.reload_if_changed() {
+                Ok(true) => info!("🛡️ Reloaded IP allow and deny lists"),
+                Ok(false) => {}
+                Err(e) => warn!("⚠️ Keeping current IP allow and deny lists: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn networks(networks: &[&str]) -> Vec<IpNetwork> {
+        networks.iter().map(|network| network.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_cidr_ranges_allow_and_deny() {
+        assert!("10.0.0.0/33".parse::<IpNetwork>().is_err());
+        assert!("not-an-ip".parse::<IpNetwork>().is_err());
+        assert_eq!("192.168.1.1".parse::<IpNetwork>().unwrap().to_string(), "192.168.1.1/32");
+
+        let lists = IpFilterLists {
+            allow: networks(&["10.0.0.0/8", "2001:db8::/32"]),
+            deny: networks(&["10.66.0.0/16"]),
+        };
+        assert!(lists.permits("10.1.2.3".parse().unwrap()));
+        assert!(lists.permits("::ffff:10.1.2.3".parse().unwrap()));
+        assert!(lists.permits("2001:db8::1".parse().unwrap()));
+        assert!(!lists.permits("10.66.0.1".parse().unwrap()));
+        assert!(!lists.permits("192.168.1.1".parse().unwrap()));
+
+        let everyone = IpFilterLists { allow: networks(&["0.0.0.0/0", "::/0"]), deny: Vec::new() };
+        assert!(everyone.permits("8.8.8.8".parse().unwrap()));
+        assert!(IpFilterLists::default().permits("::1".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_filter_file_is_reloaded_and_rewritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ip_filter.json");
+        let filter = IpFilter::with_file(IpFilterLists { allow: Vec::new(), deny: networks(&["10.0.0.1"]) }, &path);
+        // Without a file the configured lists apply
+        assert!(!filter.reload_if_changed().unwrap());
+        assert!(!filter.permits("10.0.0.1".parse().unwrap()));
+
+        std::fs::write(&path, r#"{"deny": ["192.168.0.0/16"]}"#).unwrap();
+        assert!(filter.reload_if_changed().unwrap());
+        assert!(filter.permits("10.0.0.1".parse().unwrap()));
+        assert!(!filter.permits("192.168.4.4".parse().unwrap()));
+        assert!(!filter.reload_if_changed().unwrap());
+
+        filter.replace(IpFilterLists { allow: Vec::new(), deny: networks(&["172.16.0.0/12"]) }).unwrap();
+        assert!(!filter.reload_if_changed().unwrap());
+        let reopened = IpFilter::with_file(IpFilterLists::default(), &path);
+        assert!(reopened.reload_if_changed().unwrap());
+        assert_eq!(reopened.lists().deny, networks(&["172.16.0.0/12"]));
+
+        std::fs::write(&path, "{not json").unwrap();
+        let corrupt = IpFilter::with_file(IpFilterLists::default(), &path);
+        assert!(corrupt.reload_if_changed().is_err());
+    }
+}
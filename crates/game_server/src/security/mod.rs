@@ -1,6 +1,7 @@
 //! Security module for input validation, rate limiting, and protection mechanisms.
 
 use crate::config::SecurityConfig;
+use horizon_event_system::PlayerId;
 use std::collections::HashMap;
 use std::net::IpAddr;
 use std::sync::Arc;
@@ -10,12 +11,29 @@ use serde::{Deserialize, Serialize};
 
 pub mod input_validation;
 pub mod rate_limiter;
+pub mod bans;
+pub mod ip_filter;
+pub mod replay;
+pub mod content_filter;
+pub mod geoip;
 
 /// Central security manager for the game server
+/// 
+/// Client messages pass hierarchical token buckets, from the most specific
+/// up: the player's bucket for the message's event type, then the player's,
+/// then their IP address's, then the server-wide one. Every bucket is
+/// checked before tokens are taken from any of them, so a message one bucket
+/// drops costs nothing from the others: flooding chat doesn't eat into a
+/// player's movement allowance, and a message a busy IP or server refuses
+/// doesn't use up the player's own budget.
 #[derive(Debug)]
 pub struct SecurityManager {
     config: SecurityConfig,
-    rate_limiter: rate_limiter::RateLimiter,
+    global_rate_limiter: Option<rate_limiter::RateLimiter<()>>,
+    ip_rate_limiter: Option<rate_limiter::RateLimiter>,
+    player_rate_limiter: Option<rate_limiter::RateLimiter<PlayerId>>,
+    message_rate_limiters: HashMap<String, rate_limiter::RateLimiter<PlayerId>>,
+    replay_guard: Option<replay::ReplayGuard>,
     connection_tracker: Arc<RwLock<HashMap<IpAddr, ConnectionInfo>>>,
 }
 
@@ -28,14 +46,23 @@ struct ConnectionInfo {
 impl SecurityManager {
     /// Creates a new security manager with the given configuration
     pub fn new(config: SecurityConfig) -> Self {
-        let rate_limiter = rate_limiter::RateLimiter::new(
-            config.max_requests_per_minute,
-            Duration::from_secs(60),
-        );
+        let message_rate_limiters = config
+            .message_rate_limits
+            .iter()
+            .filter(|(_, &per_second)| per_second > 0)
+            .map(|(key, &per_second)| (key.clone(), rate_limiter::RateLimiter::per_second(per_second)))
+            .collect();
 
         Self {
+            global_rate_limiter: per_second_limiter(config.global_message_rate_limit),
+            ip_rate_limiter: per_second_limiter(config.ip_message_rate_limit),
+            player_rate_limiter: per_second_limiter(config.player_message_rate_limit),
+            replay_guard: config
+                .replay_protection
+                .enabled
+                .then(|| replay::ReplayGuard::new(config.replay_protection.clone())),
             config,
-            rate_limiter,
+            message_rate_limiters,
             connection_tracker: Arc::new(RwLock::new(HashMap::new())),
         }
     }
@@ -66,23 +93,112 @@ impl SecurityManager {
         Ok(())
     }
 
-    /// Validates an incoming message
-    pub async fn validate_message(&self, ip: IpAddr, message: &[u8]) -> Result<(), SecurityError> {
+    /// Validates an incoming message from a player
+    /// 
+    /// Rate limits are applied separately, by [`Self::check_message_rate`].
+    pub async fn validate_message(&self, player_id: PlayerId, message: &[u8]) -> Result<(), SecurityError> {
         // Check message size
         if message.len() > self.config.max_message_size {
             return Err(SecurityError::MessageTooLarge(message.len()));
         }
 
-        // Apply rate limiting
-        if self.config.enable_rate_limiting {
-            if !self.rate_limiter.check_rate_limit(ip).await {
-                return Err(SecurityError::RateLimitExceeded(ip));
-            }
-        }
-
         // Validate message content
         input_validation::validate_json_message(message, &self.config)?;
 
+        // Refuse replayed messages
+        if self.replay_guard.is_some() {
+            let json: serde_json::Value = serde_json::from_slice(message)
+                .map_err(|e| SecurityError::InvalidMessageFormat(e.to_string()))?;
+            self.check_replay(player_id, &json)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks a decoded client message's nonce and timestamp when replay
+    /// protection is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - Player who sent the message, whose nonces it is checked against
+    /// * `message` - The decoded client message
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the message is fresh or replay protection is disabled, or
+    /// `SecurityError::ReplayRejected` if it is stale, was already received,
+    /// or the player's nonce cache is full.
+    pub fn check_replay(&self, player_id: PlayerId, message: &serde_json::Value) -> Result<(), SecurityError> {
+        match &self.replay_guard {
+            Some(replay_guard) => replay_guard.check(player_id, message, horizon_event_system::current_timestamp()),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks the payload size of a raw binary client message
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the payload may be routed, or `SecurityError::MessageTooLarge`
+    /// if it exceeds `max_binary_message_size` or raw messages are disabled.
+    pub fn check_binary_message_size(&self, size: usize) -> Result<(), SecurityError> {
+        if size > self.config.max_binary_message_size || self.config.max_binary_message_size == 0 {
+            return Err(SecurityError::MessageTooLarge(size));
+        }
+        Ok(())
+    }
+
+    /// Takes a token for a player's message from every bucket, if all of them have one left.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - Player that sent the message
+    /// * `ip` - Address the player is connected from
+    /// * `namespace` - Namespace of the message (`gorc` for native GORC events)
+    /// * `event` - Event name of the message
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the message may be routed, or `SecurityError::MessageRateExceeded`
+    /// naming the limit the player went over: the `namespace` or
+    /// `namespace:event` key of an event type's limit, or `player`, `ip` or `global`.
+    pub async fn check_message_rate(&self, player_id: PlayerId, ip: IpAddr, namespace: &str, event: &str) -> Result<(), SecurityError> {
+        if !self.config.enable_rate_limiting {
+            return Ok(());
+        }
+
+        let event_key = format!("{namespace}:{event}");
+        let event_limiter = self
+            .message_rate_limiters
+            .get_key_value(&event_key)
+            .or_else(|| self.message_rate_limiters.get_key_value(namespace));
+
+        // Check every level before taking a token from any of them
+        if let Some((key, limiter)) = event_limiter {
+            check_bucket(limiter, &player_id, key).await?;
+        }
+        if let Some(limiter) = &self.player_rate_limiter {
+            check_bucket(limiter, &player_id, PLAYER_BUCKET).await?;
+        }
+        if let Some(limiter) = &self.ip_rate_limiter {
+            check_bucket(limiter, &ip, IP_BUCKET).await?;
+        }
+        if let Some(limiter) = &self.global_rate_limiter {
+            check_bucket(limiter, &(), GLOBAL_BUCKET).await?;
+        }
+
+        if let Some((_, limiter)) = event_limiter {
+            limiter.take_token(player_id).await;
+        }
+        if let Some(limiter) = &self.player_rate_limiter {
+            limiter.take_token(player_id).await;
+        }
+        if let Some(limiter) = &self.ip_rate_limiter {
+            limiter.take_token(ip).await;
+        }
+        if let Some(limiter) = &self.global_rate_limiter {
+            limiter.take_token(()).await;
+        }
         Ok(())
     }
 
@@ -119,20 +235,92 @@ impl SecurityManager {
             0
         };
 
+        let mut rate_limit_buckets = Vec::new();
+        if let Some(limiter) = &self.global_rate_limiter {
+            rate_limit_buckets.push(RateLimitBucketStats::of(GLOBAL_BUCKET, limiter).await);
+        }
+        if let Some(limiter) = &self.ip_rate_limiter {
+            rate_limit_buckets.push(RateLimitBucketStats::of(IP_BUCKET, limiter).await);
+        }
+        if let Some(limiter) = &self.player_rate_limiter {
+            rate_limit_buckets.push(RateLimitBucketStats::of(PLAYER_BUCKET, limiter).await);
+        }
+        let mut event_types: Vec<_> = self.message_rate_limiters.iter().collect();
+        event_types.sort_by_key(|(key, _)| *key);
+        for (key, limiter) in event_types {
+            rate_limit_buckets.push(RateLimitBucketStats::of(key, limiter).await);
+        }
+
         SecurityStats {
             tracked_ips: connection_count,
-            rate_limited_requests: self.rate_limiter.get_blocked_count().await,
+            rate_limited_requests: rate_limit_buckets.iter().map(|bucket| bucket.blocked).sum(),
             banned_ips: self.config.banned_ips.len(),
+            rate_limit_buckets,
         }
     }
 }
 
+/// Limit key of the server-wide message bucket
+const GLOBAL_BUCKET: &str = "global";
+/// Limit key of the per-IP message buckets
+const IP_BUCKET: &str = "ip";
+/// Limit key of the per-player message buckets
+const PLAYER_BUCKET: &str = "player";
+
+fn per_second_limiter<K: Eq + std::hash::Hash>(per_second: u32) -> Option<rate_limiter::RateLimiter<K>> {
+    (per_second > 0).then(|| rate_limiter::RateLimiter::per_second(per_second))
+}
+
+/// Checks that a bucket has a token left, counting the message as blocked if not
+async fn check_bucket<K: Eq + std::hash::Hash>(
+    limiter: &rate_limiter::RateLimiter<K>,
+    key: &K,
+    limit_key: &str,
+) -> Result<(), SecurityError> {
+    if limiter.has_token(key).await {
+        Ok(())
+    } else {
+        limiter.record_blocked();
+        Err(SecurityError::MessageRateExceeded {
+            key: limit_key.to_string(),
+            limit_per_sec: limiter.max_tokens(),
+        })
+    }
+}
+
 /// Security-related statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityStats {
     pub tracked_ips: usize,
     pub rate_limited_requests: u64,
     pub banned_ips: usize,
+    /// Each configured message rate limit, widest first
+    #[serde(default)]
+    pub rate_limit_buckets: Vec<RateLimitBucketStats>,
+}
+
+/// Statistics of one message rate limit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitBucketStats {
+    /// `global`, `ip`, `player`, or the `namespace` or `namespace:event` of an event type's limit
+    pub key: String,
+    /// Messages per second each bucket allows
+    pub limit_per_sec: u32,
+    /// Number of buckets currently kept, one per IP or player
+    pub buckets: usize,
+    /// Messages the limit dropped
+    pub blocked: u64,
+}
+
+impl RateLimitBucketStats {
+    async fn of<K: Eq + std::hash::Hash>(key: &str, limiter: &rate_limiter::RateLimiter<K>) -> Self {
+        Self {
+            key: key.to_string(),
+            limit_per_sec: limiter.max_tokens(),
+            buckets: limiter.tracked_keys().await,
+            blocked: limiter.get_blocked_count().await,
+        }
+    }
 }
 
 /// Security-related errors
@@ -150,9 +338,124 @@ pub enum SecurityError {
     #[error("Rate limit exceeded for IP {0}")]
     RateLimitExceeded(IpAddr),
     
+    #[error("Message rate limit of {limit_per_sec}/s exceeded for '{key}'")]
+    MessageRateExceeded { key: String, limit_per_sec: u32 },
+    
     #[error("Invalid message format: {0}")]
     InvalidMessageFormat(String),
     
     #[error("Malicious content detected")]
     MaliciousContent,
-}
\ No newline at end of file
+    
+    #[error("Replay protection refused message: {0}")]
+    ReplayRejected(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_event_limits_take_precedence_over_namespace_limits() {
+        let security = SecurityManager::new(SecurityConfig {
+            message_rate_limits: HashMap::from([
+                ("chat".to_string(), 2),
+                ("movement:move".to_string(), 5),
+            ]),
+            ..SecurityConfig::default()
+        });
+        let player_id = PlayerId::new();
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+
+        for _ in 0..2 {
+            assert!(security.check_message_rate(player_id, ip, "chat", "say").await.is_ok());
+        }
+        // Every event of the namespace shares the namespace's bucket
+        let error = security.check_message_rate(player_id, ip, "chat", "whisper").await.unwrap_err();
+        assert!(matches!(error, SecurityError::MessageRateExceeded { ref key, limit_per_sec: 2 } if key == "chat"));
+
+        for _ in 0..5 {
+            assert!(security.check_message_rate(player_id, ip, "movement", "move").await.is_ok());
+        }
+        assert!(security.check_message_rate(player_id, ip, "movement", "move").await.is_err());
+
+        // Namespaces and events without a limit are never rate limited
+        for _ in 0..10 {
+            assert!(security.check_message_rate(player_id, ip, "movement", "jump").await.is_ok());
+        }
+        assert_eq!(security.get_stats().await.rate_limited_requests, 2);
+    }
+
+    #[tokio::test]
+    async fn test_messages_pass_event_player_ip_and_global_buckets() {
+        let security = SecurityManager::new(SecurityConfig {
+            message_rate_limits: HashMap::from([("chat".to_string(), 2)]),
+            player_message_rate_limit: 5,
+            ip_message_rate_limit: 8,
+            global_message_rate_limit: 10,
+            ..SecurityConfig::default()
+        });
+        let (flooder, housemate, stranger) = (PlayerId::new(), PlayerId::new(), PlayerId::new());
+        let (home, elsewhere): (IpAddr, IpAddr) = ("10.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap());
+
+        // Chat the chat bucket drops doesn't eat into the flooder's movement
+        for _ in 0..2 {
+            assert!(security.check_message_rate(flooder, home, "chat", "say").await.is_ok());
+        }
+        for _ in 0..10 {
+            assert!(security.check_message_rate(flooder, home, "chat", "say").await.is_err());
+        }
+        for _ in 0..3 {
+            assert!(security.check_message_rate(flooder, home, "movement", "move").await.is_ok());
+        }
+        let error = security.check_message_rate(flooder, home, "movement", "move").await.unwrap_err();
+        assert!(matches!(error, SecurityError::MessageRateExceeded { ref key, limit_per_sec: 5 } if key == "player"));
+
+        // Players behind the same address share its bucket
+        for _ in 0..3 {
+            assert!(security.check_message_rate(housemate, home, "movement", "move").await.is_ok());
+        }
+        let error = security.check_message_rate(housemate, home, "movement", "move").await.unwrap_err();
+        assert!(matches!(error, SecurityError::MessageRateExceeded { ref key, limit_per_sec: 8 } if key == "ip"));
+
+        // And every client shares the global one
+        for _ in 0..2 {
+            assert!(security.check_message_rate(stranger, elsewhere, "movement", "move").await.is_ok());
+        }
+        let error = security.check_message_rate(stranger, elsewhere, "movement", "move").await.unwrap_err();
+        assert!(matches!(error, SecurityError::MessageRateExceeded { ref key, limit_per_sec: 10 } if key == "global"));
+
+        let stats = security.get_stats().await;
+        let blocked: Vec<_> = stats
+            .rate_limit_buckets
+            .iter()
+            .map(|bucket| (bucket.key.as_str(), bucket.buckets, bucket.blocked))
+            .collect();
+        assert_eq!(blocked, [("global", 1, 1), ("ip", 2, 1), ("player", 3, 1), ("chat", 1, 10)]);
+        assert_eq!(stats.rate_limited_requests, 13);
+    }
+
+    #[tokio::test]
+    async fn test_refused_message_takes_no_tokens_from_narrower_buckets() {
+        let security = SecurityManager::new(SecurityConfig {
+            message_rate_limits: HashMap::from([("chat".to_string(), 1)]),
+            global_message_rate_limit: 1,
+            ..SecurityConfig::default()
+        });
+        let (first, second) = (PlayerId::new(), PlayerId::new());
+        let (first_ip, second_ip): (IpAddr, IpAddr) = ("10.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap());
+
+        assert!(security.check_message_rate(first, first_ip, "chat", "say").await.is_ok());
+        let error = security.check_message_rate(second, second_ip, "chat", "say").await.unwrap_err();
+        assert!(matches!(error, SecurityError::MessageRateExceeded { ref key, .. } if key == "global"));
+
+        // The global refusal left the second player's chat, player and IP buckets untouched
+        let stats = security.get_stats().await;
+        let buckets: Vec<_> = stats
+            .rate_limit_buckets
+            .iter()
+            .map(|bucket| (bucket.key.as_str(), bucket.buckets, bucket.blocked))
+            .collect();
+        assert_eq!(buckets, [("global", 1, 1), ("ip", 1, 0), ("player", 1, 0), ("chat", 1, 0)]);
+    }
+}
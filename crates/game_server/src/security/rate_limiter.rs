@@ -1,15 +1,18 @@
 //! Rate limiting implementation using token bucket algorithm.
 
 use std::collections::HashMap;
+use std::hash::Hash;
 use std::net::IpAddr;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
-/// Token bucket rate limiter for controlling request rates
+/// Token bucket rate limiter for controlling request rates.
+///
+/// Keeps one bucket per key, which is the client IP unless stated otherwise.
 #[derive(Debug)]
-pub struct RateLimiter {
-    buckets: Arc<RwLock<HashMap<IpAddr, TokenBucket>>>,
+pub struct RateLimiter<K = IpAddr> {
+    buckets: Arc<RwLock<HashMap<K, TokenBucket>>>,
     max_tokens: u32,
     refill_interval: Duration,
     blocked_count: Arc<std::sync::atomic::AtomicU64>,
@@ -21,7 +24,7 @@ struct TokenBucket {
     last_refill: Instant,
 }
 
-impl RateLimiter {
+impl<K: Eq + Hash> RateLimiter<K> {
     /// Creates a new rate limiter with the specified parameters
     pub fn new(max_tokens: u32, refill_interval: Duration) -> Self {
         Self {
@@ -32,40 +35,91 @@ impl RateLimiter {
         }
     }
 
-    /// Checks if a request from the given IP should be allowed
-    pub async fn check_rate_limit(&self, ip: IpAddr) -> bool {
+    /// Creates a rate limiter allowing `per_second` requests per key each second
+    pub fn per_second(per_second: u32) -> Self {
+        let per_second = per_second.max(1);
+        Self::new(per_second, Duration::from_secs(1) / per_second)
+    }
+
+    /// Checks if a request from the given key should be allowed
+    pub async fn check_rate_limit(&self, key: K) -> bool {
         let mut buckets = self.buckets.write().await;
         let now = Instant::now();
 
-        let bucket = buckets.entry(ip).or_insert(TokenBucket {
+        let bucket = buckets.entry(key).or_insert(TokenBucket {
             tokens: self.max_tokens,
             last_refill: now,
         });
-
-        // Refill tokens based on elapsed time
-        let elapsed = now.duration_since(bucket.last_refill);
-        if elapsed >= self.refill_interval {
-            let intervals_passed = elapsed.as_millis() / self.refill_interval.as_millis();
-            let tokens_to_add = (intervals_passed as u32).min(self.max_tokens - bucket.tokens);
-            bucket.tokens = (bucket.tokens + tokens_to_add).min(self.max_tokens);
-            bucket.last_refill = now;
-        }
+        self.refill(bucket, now);
 
         // Check if we have tokens available
         if bucket.tokens > 0 {
             bucket.tokens -= 1;
             true
         } else {
-            self.blocked_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            self.record_blocked();
             false
         }
     }
 
+    /// Checks if the given key has a token left, without taking it.
+    ///
+    /// Used with [`Self::take_token`] when a request has to pass several
+    /// limiters, so that one refusing it leaves the others untouched.
+    pub async fn has_token(&self, key: &K) -> bool {
+        let buckets = self.buckets.read().await;
+        let Some(bucket) = buckets.get(key) else {
+            return self.max_tokens > 0;
+        };
+        let mut bucket = bucket.clone();
+        self.refill(&mut bucket, Instant::now());
+        bucket.tokens > 0
+    }
+
+    /// Takes a token from the given key's bucket, if it has one left.
+    pub async fn take_token(&self, key: K) {
+        let mut buckets = self.buckets.write().await;
+        let now = Instant::now();
+
+        let bucket = buckets.entry(key).or_insert(TokenBucket {
+            tokens: self.max_tokens,
+            last_refill: now,
+        });
+        self.refill(bucket, now);
+        bucket.tokens = bucket.tokens.saturating_sub(1);
+    }
+
+    /// Counts a request that was refused.
+    pub fn record_blocked(&self) {
+        self.blocked_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Refills a bucket's tokens based on the time elapsed since its last refill
+    fn refill(&self, bucket: &mut TokenBucket, now: Instant) {
+        let elapsed = now.duration_since(bucket.last_refill);
+        if elapsed >= self.refill_interval {
+            let intervals_passed = elapsed.as_nanos() / self.refill_interval.as_nanos().max(1);
+            let tokens_to_add = u32::try_from(intervals_passed).unwrap_or(u32::MAX).min(self.max_tokens - bucket.tokens);
+            bucket.tokens = (bucket.tokens + tokens_to_add).min(self.max_tokens);
+            bucket.last_refill = now;
+        }
+    }
+
+    /// Gets the number of tokens each bucket holds when full
+    pub fn max_tokens(&self) -> u32 {
+        self.max_tokens
+    }
+
     /// Gets the total number of blocked requests
     pub async fn get_blocked_count(&self) -> u64 {
         self.blocked_count.load(std::sync::atomic::Ordering::Relaxed)
     }
 
+    /// Gets the number of keys with a bucket
+    pub async fn tracked_keys(&self) -> usize {
+        self.buckets.read().await.len()
+    }
+
     /// Cleans up old rate limit entries
     pub async fn cleanup_old_entries(&self) {
         let mut buckets = self.buckets.write().await;
@@ -110,4 +164,20 @@ mod tests {
         // Should be able to make requests again
         assert!(limiter.check_rate_limit(ip).await);
     }
+
+    #[tokio::test]
+    async fn test_per_second_limiter_keeps_separate_buckets() {
+        let limiter = RateLimiter::per_second(3);
+
+        for _ in 0..3 {
+            assert!(limiter.check_rate_limit("alice").await);
+        }
+        assert!(!limiter.check_rate_limit("alice").await);
+        assert!(limiter.check_rate_limit("bob").await);
+        assert_eq!(limiter.get_blocked_count().await, 1);
+
+        // A third of a second refills one token
+        tokio::time::sleep(Duration::from_millis(400)).await;
+        assert!(limiter.check_rate_limit("alice").await);
+    }
 }
\ No newline at end of file
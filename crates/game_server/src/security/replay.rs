@@ -0,0 +1,187 @@
+//! Replay protection for client messages.
+//!
+//! Clients stamp each message with a random `nonce` and the Unix `timestamp`
+//! it was sent at. A message is only accepted while its timestamp is within
+//! the allowed clock skew of the server's clock, and only the first time its
+//! sender used its nonce. Nonces are remembered per player until their
+//! message would be too old anyway, so one player's traffic can't push
+//! another player's nonces out of the cache.
+
+use super::SecurityError;
+use crate::config::ReplayProtectionConfig;
+use horizon_event_system::PlayerId;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+
+/// Longest nonce accepted, in bytes
+const MAX_NONCE_LEN: usize = 128;
+
+/// Refuses client messages that are stale or were already received
+#[derive(Debug)]
+pub struct ReplayGuard {
+    config: ReplayProtectionConfig,
+    seen: Mutex<SeenByPlayer>,
+}
+
+/// Nonce caches of the players that sent messages within the window
+#[derive(Debug, Default)]
+struct SeenByPlayer {
+    players: HashMap<PlayerId, SeenNonces>,
+    /// Unix time players with only expired nonces were last dropped at
+    swept_at: u64,
+}
+
+/// Nonces a player used within the window, oldest first
+#[derive(Debug, Default)]
+struct SeenNonces {
+    nonces: HashSet<String>,
+    /// Each nonce with the time after which its message would be refused anyway
+    expiries: VecDeque<(u64, String)>,
+}
+
+impl SeenNonces {
+    /// Forgets the nonces whose messages would now be refused as stale
+    fn expire(&mut self, now: u64) {
+        while self.expiries.front().is_some_and(|(expiry, _)| *expiry < now) {
+            if let Some((_, expired)) = self.expiries.pop_front() {
+                self.nonces.remove(&expired);
+            }
+        }
+    }
+}
+
+impl ReplayGuard {
+    /// Creates a guard with an empty nonce cache
+    pub fn new(config: ReplayProtectionConfig) -> Self {
+        Self {
+            config,
+            seen: Mutex::new(SeenByPlayer::default()),
+        }
+    }
+
+    /// Checks a message's nonce and timestamp, and remembers the nonce for its sender.
+    ///
+    /// A player whose cache already holds `nonce_cache_size` unexpired
+    /// nonces has their messages refused until some of them expire.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - Player who sent the message
+    /// * `message` - The decoded client message
+    /// * `now` - Current Unix time in seconds
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the message is fresh, or `SecurityError::ReplayRejected`
+    /// saying why it was refused.
+    pub fn check(&self, player_id: PlayerId, message: &Value, now: u64) -> Result<(), SecurityError> {
+        let nonce = message
+            .get("nonce")
+            .and_then(Value::as_str)
+            .filter(|nonce| !nonce.is_empty() && nonce.len() <= MAX_NONCE_LEN)
+            .ok_or_else(|| SecurityError::ReplayRejected("missing or invalid nonce".to_string()))?;
+        let timestamp = message
+            .get("timestamp")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| SecurityError::ReplayRejected("missing or invalid timestamp".to_string()))?;
+        if timestamp.abs_diff(now) > self.config.max_clock_skew_secs {
+            return Err(SecurityError::ReplayRejected(format!(
+                "timestamp {timestamp} is more than {}s from server time {now}",
+                self.config.max_clock_skew_secs
+            )));
+        }
+
+        let mut seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        if seen.swept_at < now {
+            seen.swept_at = now;
+            seen.players.retain(|_, player| {
+                player.expire(now);
+                !player.nonces.is_empty()
+            });
+        }
+
+        let player = seen.players.entry(player_id).or_default();
+        player.expire(now);
+        if player.nonces.contains(nonce) {
+            return Err(SecurityError::ReplayRejected(format!("nonce '{nonce}' was already used")));
+        }
+        if player.nonces.len() >= self.config.nonce_cache_size {
+            return Err(SecurityError::ReplayRejected(format!(
+                "more than {} messages within the replay window",
+                self.config.nonce_cache_size
+            )));
+        }
+
+        player.nonces.insert(nonce.to_string());
+        player.expiries.push_back((timestamp + self.config.max_clock_skew_secs, nonce.to_string()));
+        Ok(())
+    }
+
+    /// Gets the number of nonces currently remembered across all players
+    pub fn tracked_nonces(&self) -> usize {
+        let seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        seen.players.values().map(|player| player.nonces.len()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn guard(nonce_cache_size: usize) -> ReplayGuard {
+        ReplayGuard::new(ReplayProtectionConfig {
+            enabled: true,
+            max_clock_skew_secs: 30,
+            nonce_cache_size,
+        })
+    }
+
+    #[test]
+    fn test_replayed_and_stale_messages_are_refused() {
+        let guard = guard(100);
+        let player = PlayerId::new();
+        let purchase = json!({ "namespace": "shop", "event": "buy", "data": {}, "nonce": "a1", "timestamp": 1_000 });
+
+        assert!(guard.check(player, &purchase, 1_010).is_ok());
+        assert!(matches!(guard.check(player, &purchase, 1_011), Err(SecurityError::ReplayRejected(_))));
+
+        // Too old, or from too far in the future
+        let stale = json!({ "nonce": "a2", "timestamp": 1_000 });
+        assert!(guard.check(player, &stale, 1_031).is_err());
+        let early = json!({ "nonce": "a3", "timestamp": 1_100 });
+        assert!(guard.check(player, &early, 1_031).is_err());
+
+        assert!(guard.check(player, &json!({ "timestamp": 1_000 }), 1_000).is_err());
+        assert!(guard.check(player, &json!({ "nonce": "a4" }), 1_000).is_err());
+        assert!(guard.check(player, &json!({ "nonce": "", "timestamp": 1_000 }), 1_000).is_err());
+
+        // Nonces are forgotten once their messages would be stale anyway
+        assert!(guard.check(player, &json!({ "nonce": "b1", "timestamp": 1_050 }), 1_050).is_ok());
+        assert_eq!(guard.tracked_nonces(), 1);
+    }
+
+    #[test]
+    fn test_full_nonce_cache_refuses_messages() {
+        let guard = guard(2);
+        let (flooder, victim) = (PlayerId::new(), PlayerId::new());
+        assert!(guard.check(victim, &json!({ "nonce": "buy", "timestamp": 500 }), 500).is_ok());
+
+        // A full cache refuses new messages instead of forgetting unexpired nonces
+        for nonce in ["n1", "n2"] {
+            assert!(guard.check(flooder, &json!({ "nonce": nonce, "timestamp": 500 }), 500).is_ok());
+        }
+        assert!(guard.check(flooder, &json!({ "nonce": "n3", "timestamp": 500 }), 500).is_err());
+        assert!(guard.check(flooder, &json!({ "nonce": "n1", "timestamp": 500 }), 500).is_err());
+        assert_eq!(guard.tracked_nonces(), 3);
+
+        // Other players keep their own nonces and room
+        assert!(guard.check(victim, &json!({ "nonce": "buy", "timestamp": 500 }), 501).is_err());
+        assert!(guard.check(victim, &json!({ "nonce": "n1", "timestamp": 500 }), 501).is_ok());
+
+        // Once the window passes the flooder may send again
+        assert!(guard.check(flooder, &json!({ "nonce": "n3", "timestamp": 540 }), 540).is_ok());
+        assert_eq!(guard.tracked_nonces(), 1);
+    }
+}
@@ -5,24 +5,49 @@
 //! event systems, plugin management, and GORC infrastructure.
 
 use crate::{
-    config::ServerConfig,
-    connection::{ConnectionManager, GameServerResponseSender},
+    admin::{serve_admin, AdminApi},
+    alerting::Alerter,
+    audit::AuditLog,
+    grpc::{serve_grpc, ManagementService},
+    s2s::{serve_s2s, S2sLink},
+    config::{ListenerConfig, ServerConfig},
+    connection::{register_moderation_handlers, ClientAuthentication, ConnectionManager, GameServerContext, GameServerResponseSender},
     error::ServerError,
-    server::handlers::handle_connection,
+    federation::{hand_off_player, serve_federation, TcpRegionLink},
+    health::{endpoint::serve_health, HealthManager},
+    health::circuit_breaker::PluginCircuitBreakers,
+    identity::{AccountLinks, FileAccountStorage, IdentityService},
+    messaging::MessageLanes,
+    security::{
+        bans::{BanStore, FileBanStorage},
+        content_filter::ChatFilter,
+        geoip::GeoPolicy,
+        ip_filter::{spawn_ip_filter_reloader, IpFilter, IpFilterLists},
+        SecurityManager,
+    },
+    server::handlers::handle_admitted_connection,
+    server::listeners::{bind_listeners, AcceptLoops, AcceptThrottle},
+    server::quic::{self, serve_quic, QuicSessions},
+    server::tls::{spawn_certificate_reloader, tls_acceptor, CertificateStore},
+    udp::{serve_udp, UdpTransport},
 };
 use plugin_system::PluginManager;
 use futures::stream::{FuturesUnordered, StreamExt as FuturesStreamExt};
 use horizon_event_system::{
-    current_timestamp, EventSystem, GorcManager, MulticastManager,
-    PlayerConnectedEvent, PlayerDisconnectedEvent, RegionId, RegionStartedEvent, SpatialPartition,
+    current_timestamp, EventSystem, GorcBroadcastEvent, GorcManager, MulticastManager,
+    PlayerConnectedEvent, PlayerDisconnectedEvent, PlayerFocusChangedEvent, PlayerLatencyEvent, PlayerMovementEvent, Position,
+    RegionId, RegionStartedEvent, SpatialPartition,
     SubscriptionManager, AuthenticationStatusSetEvent, AuthenticationStatusGetEvent, 
     AuthenticationStatusGetResponseEvent, AuthenticationStatusChangedEvent, ShutdownState,
+    NeighborRegion, PlayerRegionHandoffEvent, RegionFederation, ReplicationAckEvent,
 };
-use horizon_sockets::SocketBuilder;
+use horizon_event_system::gorc::{utils::create_complete_gorc_system_with_config, CompleteGorcSystem, WireFormat};
+use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, Mutex};
+use tokio_rustls::TlsAcceptor;
 use tokio::time::{interval, Duration};
-use tracing::{error, info, trace, warn, debug};
+use tracing::{error, info, warn, debug};
 use bug::bug_with_handle;
 
 #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd", target_os = "openbsd", target_os = "netbsd", target_os = "dragonfly", target_os = "macos"))]
@@ -71,6 +96,9 @@ pub struct GameServer {
     /// Main GORC manager for replication channels
     gorc_manager: Arc<GorcManager>,
     
+    /// Replication engine batching, compressing and resending updates to players
+    gorc_replication: Arc<Mutex<CompleteGorcSystem>>,
+    
     /// Manager for dynamic subscription handling
     subscription_manager: Arc<SubscriptionManager>,
     
@@ -79,6 +107,24 @@ pub struct GameServer {
     
     /// Spatial partitioning for region and proximity queries
     spatial_partition: Arc<SpatialPartition>,
+    
+    /// Channel to other Horizon servers, when a server-to-server secret is configured
+    s2s_link: Option<Arc<S2sLink>>,
+    
+    /// Priority lanes incoming client messages are routed through
+    message_lanes: Arc<MessageLanes>,
+    
+    /// Circuit breakers short-circuiting failing plugin handlers
+    plugin_circuit_breakers: Arc<PluginCircuitBreakers>,
+    
+    /// UDP transport for channel 0 replication, idle unless a UDP address is configured
+    udp_transport: Arc<UdpTransport>,
+    
+    /// Clients connected through the QUIC listener
+    quic_sessions: Arc<QuicSessions>,
+    
+    /// Counters of the accept loops serving the WebSocket listeners
+    accept_loops: Arc<AcceptLoops>,
 }
 
 impl GameServer {
@@ -100,21 +146,101 @@ impl GameServer {
     /// 
     /// 1. Creates event system and connection manager
     /// 2. Sets up client response sender for event system integration
-    /// 3. Initializes plugin manager with event system binding
+    /// 3. Initializes plugin manager with event system binding and a
+    ///    connection-backed server context
     /// 4. Creates all GORC components for advanced networking
     /// 5. Generates unique region ID for this server instance
     pub fn new(config: ServerConfig) -> Self {
     let region_id = RegionId::new();
     use horizon_event_system::gorc::instance::GorcInstanceManager;
-    let gorc_instance_manager = Arc::new(GorcInstanceManager::new());
+    let gorc_instance_manager = Arc::new(
+        GorcInstanceManager::new()
+            .with_zone_exit_margin(config.zone_exit_margin)
+            .with_dead_reckoning_horizon(Duration::from_millis(config.dead_reckoning_horizon_ms))
+            .with_object_shards(config.object_shards)
+            .with_spatial_index(config.spatial_index.clone())
+    );
     let mut horizon_event_system = Arc::new(EventSystem::with_gorc(gorc_instance_manager.clone()));
-        let connection_manager = Arc::new(ConnectionManager::new());
+        let bans = match &config.security.ban_list_path {
+            Some(path) => BanStore::open(Box::new(FileBanStorage::new(path))).unwrap_or_else(|e| {
+                // Keep the unreadable file intact rather than overwriting it with new bans
+                error!("🔨 Failed to load the ban list from {}, bans won't be saved: {}", path.display(), e);
+                BanStore::new()
+            }),
+            None => BanStore::new(),
+        };
+        let ip_filter_lists = IpFilterLists {
+            allow: config.security.ip_allow_list.clone(),
+            deny: config.security.ip_deny_list.clone(),
+        };
+        let ip_filter = match &config.security.ip_filter_path {
+            Some(path) => IpFilter::with_file(ip_filter_lists, path),
+            None => IpFilter::new(ip_filter_lists),
+        };
+        if let Err(e) = ip_filter.reload_if_changed() {
+            // The reloader picks the file up once it is fixed
+            error!("🛡️ Failed to load the IP filter file, using the configured lists: {}", e);
+        }
+        let authentication = ClientAuthentication::new(&config.auth).unwrap_or_else(|e| {
+            // Starting the server fails below rather than letting clients in unauthenticated
+            error!("🔐 Failed to load the JWT keys: {}", e);
+            ClientAuthentication::default()
+        });
+        let accounts = match &config.auth.account_links_path {
+            Some(path) => AccountLinks::open(Box::new(FileAccountStorage::new(path))).unwrap_or_else(|e| {
+                // Keep the unreadable file intact rather than overwriting it with new links
+                error!("🪪 Failed to load the account links from {}, links won't be saved: {}", path.display(), e);
+                AccountLinks::new()
+            }),
+            None => AccountLinks::new(),
+        };
+        let identity = IdentityService::from_config(&config.auth, accounts).unwrap_or_else(|e| {
+            // Starting the server fails below rather than letting clients in without logging in
+            error!("🪪 Failed to set up the identity providers: {}", e);
+            IdentityService::default()
+        });
+        let geo_policy = GeoPolicy::open(&config.geoip).unwrap_or_else(|e| {
+            // Starting the server fails below rather than letting clients in unfiltered
+            error!("🌍 Failed to open the GeoIP database: {}", e);
+            None
+        });
+        let mut connection_manager = ConnectionManager::new()
+            .with_resume_window(Duration::from_secs(config.session_resume_window_secs))
+            .with_heartbeat(config.heartbeat.clone())
+            .with_outbound_queue(config.outbound_queue.clone())
+            .with_protocol(config.protocol.clone())
+            .with_proxy_protocol(config.proxy_protocol.clone())
+            .with_ban_store(Arc::new(bans))
+            .with_ip_filter(Arc::new(ip_filter))
+            .with_authentication(Arc::new(authentication))
+            .with_identity(Arc::new(identity));
+        if let Some(geo_policy) = geo_policy {
+            connection_manager = connection_manager.with_geo_policy(Arc::new(geo_policy));
+        }
+        let connection_manager = Arc::new(connection_manager);
+        for ip in &config.security.banned_ips {
+            connection_manager.ban_ip(*ip);
+        }
         let (shutdown_sender, _) = broadcast::channel(1);
 
         // Set up connection-aware response sender
-        let response_sender = Arc::new(GameServerResponseSender::new(connection_manager.clone()));
+        let udp_transport = Arc::new(UdpTransport::new());
+        let quic_sessions = Arc::new(QuicSessions::new());
+        let response_sender = Arc::new(
+            GameServerResponseSender::new(connection_manager.clone())
+                .with_udp(udp_transport.clone())
+                .with_quic(quic_sessions.clone()),
+        );
+        let plugin_circuit_breakers = Arc::new(PluginCircuitBreakers::new(config.plugin_circuit_breaker.clone()));
+        let s2s_link = S2sLink::new(&config.s2s).map(Arc::new);
         if let Some(event_system_mut) = Arc::get_mut(&mut horizon_event_system) {
             event_system_mut.set_client_response_sender(response_sender);
+            if let Some(s2s_link) = &s2s_link {
+                event_system_mut.set_server_link(s2s_link.clone());
+            }
+            event_system_mut.set_handler_gate(plugin_circuit_breakers.clone());
+            event_system_mut.set_emission_queue_config(config.event_queue.clone());
+            event_system_mut.set_handler_span_config(config.handler_spans.clone());
         } else {
             bug_with_handle!(horizon_bugs::get_bugs(), "crash", {
                 error_type = "⚠️ Failed to get mutable reference to event system during initialization",
@@ -124,14 +250,47 @@ impl GameServer {
             });
         }
 
+        // Plugins loaded after the region came up still need its bounds
+        horizon_event_system.latch_core("region_started");
+        if let Some(s2s_link) = &s2s_link {
+            s2s_link.attach(&horizon_event_system);
+        }
+
+        // Give plugins a connection-backed context for every lifecycle phase
+        let mut server_context = GameServerContext::new(horizon_event_system.clone(), region_id, connection_manager.clone())
+            .with_gorc(gorc_instance_manager.clone());
+        if !config.content_filter.rules.is_empty() {
+            match ChatFilter::new(&config.content_filter) {
+                Ok(content_filter) => server_context = server_context.with_content_filter(Arc::new(content_filter)),
+                Err(e) => error!("💬 Failed to compile the content filter, chat won't be filtered: {}", e),
+            }
+        }
+        let server_context = Arc::new(server_context);
+
+        // Replicate the event system's objects to players through their connections
+        let subscription_manager = Arc::new(SubscriptionManager::new());
+        let mut gorc_replication = create_complete_gorc_system_with_config(
+            server_context.clone(),
+            gorc_instance_manager.clone(),
+            &config.gorc_network,
+        )
+        .expect("Failed to build the GORC replication system");
+        // Focus and facing adjust each player's replication priority
+        gorc_replication.coordinator.set_subscription_manager(subscription_manager.clone());
+        let gorc_replication = Arc::new(Mutex::new(gorc_replication));
+
         // Initialize plugin manager with safety configuration and GORC support
-        let plugin_manager = Arc::new(PluginManager::with_gorc(horizon_event_system.clone(), config.plugin_safety.clone(), gorc_instance_manager.clone()));
+        let plugin_manager = Arc::new(
+            PluginManager::with_gorc(horizon_event_system.clone(), config.plugin_safety.clone(), gorc_instance_manager.clone())
+                .with_server_context(server_context),
+        );
 
         // Initialize GORC components
         let gorc_manager = Arc::new(GorcManager::new());
-        let subscription_manager = Arc::new(SubscriptionManager::new());
-        let multicast_manager = Arc::new(MulticastManager::new());
+        let multicast_manager = horizon_event_system.get_multicast_manager();
         let spatial_partition = Arc::new(SpatialPartition::new());
+        let security_manager = Arc::new(SecurityManager::new(config.security.clone()));
+        let message_lanes = Arc::new(MessageLanes::new(&config.message_lanes).with_security_manager(security_manager));
 
         Self {
             config,
@@ -141,9 +300,16 @@ impl GameServer {
             shutdown_sender,
             region_id,
             gorc_manager,
+            gorc_replication,
             subscription_manager,
             multicast_manager,
             spatial_partition,
+            s2s_link,
+            message_lanes,
+            plugin_circuit_breakers,
+            udp_transport,
+            quic_sessions,
+            accept_loops: Arc::new(AcceptLoops::new()),
         }
     }
 
@@ -170,9 +336,10 @@ impl GameServer {
     /// 
     /// # Multi-threading
     /// 
-    /// If `use_reuse_port` is enabled in configuration, the server will
-    /// create multiple accept loops equal to the number of CPU cores for
-    /// improved performance under high load.
+    /// Each listener is served by `accept_threads` accept loops. If
+    /// `use_reuse_port` is enabled each loop gets its own socket, one per CPU
+    /// core unless `accept_threads` says otherwise; without it the loops
+    /// share a single listener.
     /// 
     /// # Returns
     /// 
@@ -200,9 +367,10 @@ impl GameServer {
     /// 
     /// # Multi-threading
     /// 
-    /// If `use_reuse_port` is enabled in configuration, the server will
-    /// create multiple accept loops equal to the number of CPU cores for
-    /// improved performance under high load.
+    /// Each listener is served by `accept_threads` accept loops. If
+    /// `use_reuse_port` is enabled each loop gets its own socket, one per CPU
+    /// core unless `accept_threads` says otherwise; without it the loops
+    /// share a single listener.
     /// 
     /// # Returns
     /// 
@@ -215,10 +383,23 @@ impl GameServer {
     /// Internal method for starting the server with optional shutdown state.
     async fn start_internal(&self, shutdown_state: Option<ShutdownState>) -> Result<(), ServerError> {
         info!("🚀 Starting game server on {}", self.config.bind_address);
+        for listener in &self.config.listeners {
+            info!("🚀 Also listening on {}", listener.address);
+        }
         info!("🌍 Region ID: {}", self.region_id.0);
 
         info!("🔧 Runtime handle configured for async handlers");
 
+        if !self.config.auth.jwt_keys.is_empty() && !self.connection_manager.authentication().requires_token() {
+            return Err(ServerError::Internal("JWT keys are configured but couldn't be loaded".to_string()));
+        }
+        if !self.config.auth.providers.is_empty() && !self.connection_manager.identity().has_providers() {
+            return Err(ServerError::Internal("Identity providers are configured but couldn't be set up".to_string()));
+        }
+        if self.config.geoip.database_path.is_some() && self.connection_manager.geo_policy().is_none() {
+            return Err(ServerError::Internal("A GeoIP database is configured but couldn't be opened".to_string()));
+        }
+
         // Register minimal core event handlers
         self.register_core_handlers().await?;
 
@@ -237,6 +418,46 @@ impl GameServer {
             info!("📭 No plugins loaded");
         }
 
+        // Supervise plugins so panicking handlers trigger restarts instead of outages
+        self.plugin_manager.start_supervisor();
+        info!("🩺 Plugin supervisor started");
+
+        // Poll plugin heartbeats so stuck or degraded plugins are visible to operators
+        self.plugin_manager.start_watchdog();
+        info!("🐕 Plugin watchdog started");
+
+        // Optionally pick up plugins dropped into the plugin directory at runtime
+        if self.config.plugin_safety.watch_directory {
+            if let Err(e) = self.plugin_manager.start_directory_watcher(&self.config.plugin_directory) {
+                warn!("⚠️ Plugin directory watcher disabled: {}", e);
+            }
+        }
+
+        // Restore persisted world state now that plugins have registered their snapshot types
+        if self.config.snapshot.restore_on_start {
+            self.restore_world_snapshot().await;
+        }
+        self.start_snapshot_autosave_with_shutdown(shutdown_state.clone());
+        self.start_spatial_maintenance_with_shutdown(shutdown_state.clone()).await;
+
+        // Exchange border objects with neighboring region servers
+        self.start_federation_with_shutdown(shutdown_state.clone()).await?;
+
+        // Accept typed s2s:* events from other Horizon servers
+        self.start_s2s_with_shutdown(shutdown_state.clone()).await?;
+
+        // Serve live zone state for debugging when an admin address is configured
+        self.start_admin_with_shutdown(shutdown_state.clone()).await?;
+
+        // Let orchestration tooling manage the server when a gRPC address is configured
+        self.start_grpc_with_shutdown(shutdown_state.clone()).await?;
+
+        // Offer clients an unreliable path for channel 0 replication
+        self.start_udp_with_shutdown(shutdown_state.clone()).await?;
+
+        // Send batched replication updates and resend unacknowledged reliable ones
+        self.start_replication_with_shutdown(shutdown_state.clone());
+
         // Start server tick if configured
         if self.config.tick_interval_ms > 0 {
             self.start_server_tick_with_shutdown(shutdown_state.clone()).await;
@@ -258,95 +479,53 @@ impl GameServer {
             .await
             .map_err(|e| ServerError::Internal(e.to_string()))?;
 
+        // Route client messages through their priority lanes
+        self.message_lanes.start(self.connection_manager.clone(), self.horizon_event_system.clone());
+
+        // Accept QUIC clients next to the WebSocket listeners
+        self.start_quic_with_shutdown(shutdown_state.clone())?;
 
-        // Unified listener creation logic for all platforms
-        let core_count = num_cpus::get();
-        let use_reuse_port = self.config.use_reuse_port;
-        let num_acceptors = if use_reuse_port { core_count } else { 1 };
-        info!("🧠 Detected {} CPU cores, using {} acceptor(s)", core_count, num_acceptors);
+        // Pick up edits to the IP allow and deny lists without a restart
+        if self.connection_manager.ip_filter().path().is_some() && self.config.security.ip_filter_reload_interval_secs > 0 {
+            spawn_ip_filter_reloader(
+                self.connection_manager.ip_filter().clone(),
+                Duration::from_secs(self.config.security.ip_filter_reload_interval_secs),
+                shutdown_state.clone(),
+            );
+        }
+
+        // Terminate TLS on the WebSocket listeners when configured
+        let tls = self.tls_acceptor_with_shutdown(shutdown_state.clone())?;
 
-        // Try to create multiple listeners, but if any fail, fall back to one listener
+        // Bind the primary address, then every additional listener
+        let primary = ListenerConfig {
+            address: self.config.bind_address,
+            use_reuse_port: self.config.use_reuse_port,
+            ipv6_only: false,
+        };
         let mut listeners = Vec::new();
-        let mut multi_listener_error = None;
-        for i in 0..num_acceptors {
-            let mut builder = match SocketBuilder::new().bind(self.config.bind_address.to_string()) {
-                Ok(b) => b,
-                Err(e) => {
-                    multi_listener_error = Some(format!("SocketBuilder bind failed: {e}"));
-                    break;
-                }
-            };
-            if use_reuse_port {
-                match builder.reuse_port(true) {
-                    Ok(b) => { builder = b; },
-                    Err(e) => {
-                        multi_listener_error = Some(format!("SO_REUSEPORT failed: {e}"));
-                        break;
-                    }
-                }
+        for listener_config in std::iter::once(&primary).chain(&self.config.listeners) {
+            let bound = bind_listeners(listener_config, self.config.accept_threads)?;
+            for listener in &bound {
+                let shared_listener = bound.iter().filter(|other| Arc::ptr_eq(other, listener)).count() > 1;
+                let address = listener.local_addr().unwrap_or(listener_config.address);
+                listeners.push((listener.clone(), self.accept_loops.register(address, shared_listener)));
             }
-            builder = match builder.backlog(65535) {
-                Ok(b) => b,
-                Err(e) => {
-                    multi_listener_error = Some(format!("SocketBuilder backlog failed: {e}"));
-                    break;
-                }
-            };
-            let listener = match builder.tcp_listener() {
-                Ok(l) => l,
-                Err(e) => {
-                    multi_listener_error = Some(format!("TcpListener creation failed: {e}"));
-                    break;
-                }
-            };
-            let std_listener = match listener.as_std().try_clone() {
-                Ok(sl) => sl,
-                Err(e) => {
-                    multi_listener_error = Some(format!("Failed to clone std TcpListener: {e}"));
-                    break;
-                }
-            };
-            std_listener.set_nonblocking(true).ok();
-            let tokio_listener = match tokio::net::TcpListener::from_std(std_listener) {
-                Ok(tl) => tl,
-                Err(e) => {
-                    multi_listener_error = Some(format!("Tokio listener creation failed: {e}"));
-                    break;
-                }
-            };
-            listeners.push(tokio_listener);
-            trace!("✅ Listener {} bound on {}", i, self.config.bind_address);
-        }
-
-        // If any error occurred, fall back to single listener
-        if multi_listener_error.is_some() {
-            warn!("Multi-listener creation failed: {}. Falling back to single listener with many acceptors.", multi_listener_error.unwrap());
-            listeners.clear();
-            let mut builder = SocketBuilder::new()
-                .bind(self.config.bind_address.to_string())
-                .map_err(|e| ServerError::Network(format!("SocketBuilder bind failed: {e}")))?;
-            builder = builder.backlog(65535)
-                .map_err(|e| ServerError::Network(format!("SocketBuilder backlog failed: {e}")))?;
-            let listener = builder.tcp_listener()
-                .map_err(|e| ServerError::Network(format!("TcpListener creation failed: {e}")))?;
-            let std_listener = listener.as_std().try_clone()
-                .map_err(|e| ServerError::Network(format!("Failed to clone std TcpListener: {e}")))?;
-            std_listener.set_nonblocking(true).ok();
-            let tokio_listener = tokio::net::TcpListener::from_std(std_listener)
-                .map_err(|e| ServerError::Network(format!("Tokio listener creation failed: {e}")))?;
-            listeners.push(tokio_listener);
-            info!("Fallback: Single listener bound on {}", self.config.bind_address);
-        }
-
-        // Main server accept loops
+        }
+
+        // Main server accept loops, sharing one throttle on new connections
         let mut shutdown_receiver = self.shutdown_sender.subscribe();
+        let throttle = Arc::new(AcceptThrottle::new(&self.config.connection_throttle));
 
         // Create futures for all accept loops with shutdown monitoring
         let mut accept_futures = listeners
             .into_iter()
-            .map(|listener| {
+            .map(|(listener, counters)| {
                 let connection_manager = self.connection_manager.clone();
                 let horizon_event_system = self.horizon_event_system.clone();
+                let message_lanes = self.message_lanes.clone();
+                let tls = tls.clone();
+                let throttle = throttle.clone();
                 let shutdown_state_clone = shutdown_state.clone();
                 
                 async move {
@@ -361,22 +540,39 @@ impl GameServer {
 
                         match listener.accept().await {
                             Ok((stream, addr)) => {
+                                counters.record_accepted();
+                                let handshake = match throttle.admit().await {
+                                    Ok(handshake) => handshake,
+                                    Err(refusal) => {
+                                        // Close straight away, before any per-IP checks or handshakes run
+                                        counters.record_throttled();
+                                        debug!("🚦 Closing connection from {}: {:?}", addr, refusal);
+                                        drop(stream);
+                                        continue;
+                                    }
+                                };
                                 let connection_manager = connection_manager.clone();
                                 let horizon_event_system = horizon_event_system.clone();
+                                let message_lanes = message_lanes.clone();
+                                let tls = tls.clone();
 
                                 // Spawn individual connection handler
                                 tokio::spawn(async move {
-                                    if let Err(e) = handle_connection(
+                                    if let Err(e) = handle_admitted_connection(
                                         stream,
                                         addr,
                                         connection_manager,
                                         horizon_event_system,
+                                        message_lanes,
+                                        tls,
+                                        handshake,
                                     ).await {
                                         error!("Connection error: {:?}", e);
                                     }
                                 });
                             }
                             Err(e) => {
+                                counters.record_error();
                                 error!("Failed to accept connection: {}", e);
                                 break;
                             }
@@ -399,6 +595,8 @@ impl GameServer {
         
         // Note: Plugin shutdown is now handled by the application layer
         // to ensure it happens even if the server task times out
+
+        self.save_world_snapshot().await;
         
         info!("✅ Server cleanup completed");
 
@@ -416,26 +614,147 @@ impl GameServer {
     /// 
     /// `Ok(())` if all handlers were registered successfully, or a `ServerError`
     /// if registration failed.
-    async fn register_core_handlers(&self) -> Result<(), ServerError> {
+    pub(crate) async fn register_core_handlers(&self) -> Result<(), ServerError> {
         // Core infrastructure events only - no game logic!
 
+        let gorc_replication_for_connect = self.gorc_replication.clone();
+        let subscription_manager_for_connect = self.subscription_manager.clone();
         self.horizon_event_system
-            .on_core("player_connected", |event: PlayerConnectedEvent| {
+            .on_core("player_connected", move |event: PlayerConnectedEvent| {
                 info!(
                     "👋 Player {} connected from {}",
                     event.player_id, event.remote_addr
                 );
+
+                // Give the player a replication queue and interest tracking before their first update
+                let gorc_replication = gorc_replication_for_connect.clone();
+                let subscription_manager = subscription_manager_for_connect.clone();
+                tokio::spawn(async move {
+                    subscription_manager.add_player(event.player_id, Position::new(0.0, 0.0, 0.0)).await;
+                    gorc_replication.lock().await.network_engine.add_player(event.player_id).await;
+                });
                 Ok(())
             })
             .await
             .map_err(|e| ServerError::Internal(e.to_string()))?;
 
+        let gorc_replication_for_disconnect = self.gorc_replication.clone();
+        let subscription_manager_for_disconnect = self.subscription_manager.clone();
+        let horizon_event_system_for_disconnect = self.horizon_event_system.clone();
+        let udp_transport_for_disconnect = self.udp_transport.clone();
         self.horizon_event_system
-            .on_core("player_disconnected", |event: PlayerDisconnectedEvent| {
+            .on_core("player_disconnected", move |event: PlayerDisconnectedEvent| {
                 info!(
                     "👋 Player {} disconnected: {:?}",
                     event.player_id, event.reason
                 );
+                horizon_event_system_for_disconnect.clear_wire_format(event.player_id);
+                udp_transport_for_disconnect.close_session(event.player_id);
+
+                // Drop the player's replication queue, GORC subscriptions and object ownership
+                let gorc_replication = gorc_replication_for_disconnect.clone();
+                let subscription_manager = subscription_manager_for_disconnect.clone();
+                let events = horizon_event_system_for_disconnect.clone();
+                tokio::spawn(async move {
+                    subscription_manager.remove_player(event.player_id).await;
+                    gorc_replication.lock().await.remove_player(event.player_id).await;
+                    // Announce the objects handed back to the server
+                    if let Err(e) = events.emit_authority_changes().await {
+                        error!("Failed to emit authority changes: {}", e);
+                    }
+                });
+
+                // Drop the player's multicast group memberships
+                let multicast = horizon_event_system_for_disconnect.get_multicast_manager();
+                tokio::spawn(async move {
+                    let _ = multicast.remove_player(event.player_id).await;
+                });
+                Ok(())
+            })
+            .await
+            .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+        // Stop resending reliable updates once the client has acknowledged them
+        let gorc_replication_for_ack = self.gorc_replication.clone();
+        self.horizon_event_system
+            .on_core("replication_ack", move |event: ReplicationAckEvent| {
+                let gorc_replication = gorc_replication_for_ack.clone();
+                tokio::spawn(async move {
+                    let released = gorc_replication
+                        .lock()
+                        .await
+                        .coordinator
+                        .acknowledge_player_updates(event.player_id, &event.sequences)
+                        .await;
+                    debug!("📡 Player {} acknowledged {} reliable update(s)", event.player_id, released);
+                });
+                Ok(())
+            })
+            .await
+            .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+        // Scale each player's replication frequencies to their heartbeat round-trip time
+        let gorc_replication_for_latency = self.gorc_replication.clone();
+        self.horizon_event_system
+            .on_core("player_latency", move |event: PlayerLatencyEvent| {
+                let gorc_replication = gorc_replication_for_latency.clone();
+                tokio::spawn(async move {
+                    gorc_replication
+                        .lock()
+                        .await
+                        .coordinator
+                        .record_player_rtt(event.player_id, event.round_trip_ms)
+                        .await;
+                });
+                Ok(())
+            })
+            .await
+            .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+        // Queue plugin announcements for every player on the broadcast channel
+        let gorc_replication_for_broadcast = self.gorc_replication.clone();
+        self.horizon_event_system
+            .on_core("gorc_broadcast", move |event: GorcBroadcastEvent| {
+                let gorc_replication = gorc_replication_for_broadcast.clone();
+                tokio::spawn(async move {
+                    let queued = gorc_replication
+                        .lock()
+                        .await
+                        .broadcast(&event.event_type, event.data, event.priority)
+                        .await;
+                    debug!("📢 Broadcast {} queued for {} player(s)", event.event_type, queued);
+                });
+                Ok(())
+            })
+            .await
+            .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+        let subscription_manager_for_movement = self.subscription_manager.clone();
+        self.horizon_event_system
+            .on_core("player_movement", move |event: PlayerMovementEvent| {
+                // Players face the way they move; stationary players keep their last facing
+                let subscription_manager = subscription_manager_for_movement.clone();
+                tokio::spawn(async move {
+                    subscription_manager.update_player_position(event.player_id, event.new_position.into()).await;
+                    if let Some(velocity) = event.velocity {
+                        subscription_manager
+                            .update_player_facing_from_velocity(event.player_id, velocity.into())
+                            .await;
+                    }
+                });
+                Ok(())
+            })
+            .await
+            .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+        let subscription_manager_for_focus = self.subscription_manager.clone();
+        self.horizon_event_system
+            .on_core("player_focus_changed", move |event: PlayerFocusChangedEvent| {
+                debug!("🎯 Player {} focused on {:?}", event.player_id, event.object_id);
+                let subscription_manager = subscription_manager_for_focus.clone();
+                tokio::spawn(async move {
+                    subscription_manager.set_focus_target(event.player_id, event.object_id).await;
+                });
                 Ok(())
             })
             .await
@@ -528,6 +847,29 @@ impl GameServer {
             .await
             .map_err(|e| ServerError::Internal(e.to_string()))?;
 
+        // Write security_audit events to the audit log file
+        if let Some(path) = &self.config.audit_log.path {
+            info!("📜 Writing the security audit log to {}", path.display());
+            Arc::new(AuditLog::new(self.config.audit_log.clone()))
+                .register(&self.horizon_event_system)
+                .await
+                .map_err(|e| ServerError::Internal(e.to_string()))?;
+        }
+
+        // Alert the configured webhooks when security events pile up
+        if !self.config.alerting.webhooks.is_empty() {
+            info!("🚨 Posting security alerts to {} webhook(s)", self.config.alerting.webhooks.len());
+            Arc::new(Alerter::new(self.config.alerting.clone()))
+                .register(&self.horizon_event_system)
+                .await
+                .map_err(|e| ServerError::Internal(e.to_string()))?;
+        }
+
+        // Let moderation and anti-cheat plugins kick and ban players
+        register_moderation_handlers(&self.horizon_event_system, self.connection_manager.clone(), self.quic_sessions.clone())
+            .await
+            .map_err(|e| ServerError::Internal(e.to_string()))?;
+
         self.horizon_event_system
             .on_core("auth_status_changed", |event: AuthenticationStatusChangedEvent| {
                 info!(
@@ -572,6 +914,70 @@ impl GameServer {
                 Ok(())
         }).await.map_err(|e| ServerError::Internal(e.to_string()))?;
 
+        // Let clients negotiate the wire format used for GORC replication messages
+        let horizon_event_system_for_wire = self.horizon_event_system.clone();
+        self.horizon_event_system
+            .on_client("system", "wire_format", move |data: serde_json::Value, player_id: horizon_event_system::PlayerId, conn| {
+                let requested = data.get("format").and_then(|f| f.as_str()).unwrap_or("json");
+                let response = match requested.parse::<WireFormat>() {
+                    Ok(format) => {
+                        horizon_event_system_for_wire.set_wire_format(player_id, format);
+                        info!("📦 Player {} negotiated {:?} wire format", player_id, format);
+                        serde_json::json!({ "format": format, "accepted": true })
+                    }
+                    Err(e) => {
+                        warn!("⚠️ Player {} requested unsupported wire format: {}", player_id, e);
+                        serde_json::json!({
+                            "format": horizon_event_system_for_wire.wire_format(player_id),
+                            "accepted": false,
+                        })
+                    }
+                };
+
+                // Use block_on to execute async response in sync handler
+                if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                    handle.block_on(async {
+                        let response_bytes = match serde_json::to_vec(&response) {
+                            Ok(bytes) => bytes,
+                            Err(e) => {
+                                error!("Failed to serialize wire format response: {}", e);
+                                return;
+                            }
+                        };
+
+                        if let Err(e) = conn.respond(&response_bytes).await {
+                            error!("Failed to send wire format response: {}", e);
+                        }
+                    });
+                }
+
+                Ok(())
+        }).await.map_err(|e| ServerError::Internal(e.to_string()))?;
+
+        // Hand out UDP session tokens so clients can receive channel 0 as datagrams
+        let udp_transport = self.udp_transport.clone();
+        self.horizon_event_system
+            .on_client("system", "udp_session", move |_data: serde_json::Value, player_id: horizon_event_system::PlayerId, conn| {
+                let response = match (udp_transport.open_session(player_id), udp_transport.local_addr()) {
+                    (Some(token), Some(address)) => {
+                        info!("📶 Opened UDP session for player {}", player_id);
+                        serde_json::json!({ "accepted": true, "port": address.port(), "token": token })
+                    }
+                    _ => serde_json::json!({ "accepted": false }),
+                };
+
+                // Use block_on to execute async response in sync handler
+                if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                    handle.block_on(async {
+                        if let Err(e) = conn.respond_json(&response).await {
+                            error!("Failed to send UDP session response: {}", e);
+                        }
+                    });
+                }
+
+                Ok(())
+        }).await.map_err(|e| ServerError::Internal(e.to_string()))?;
+
         Ok(())
     }
 
@@ -580,6 +986,8 @@ impl GameServer {
     /// Creates a background task that emits `server_tick` events at the configured
     /// interval. This allows plugins and other components to perform periodic
     /// operations like game state updates, cleanup, or maintenance tasks.
+    /// Each tick also despawns GORC objects whose time-to-live has elapsed and
+    /// re-evaluates zones at dead-reckoned player positions.
     /// 
     /// The tick system monitors the shutdown state and gracefully stops when
     /// shutdown is initiated, ensuring no new tick events are processed.
@@ -619,7 +1027,8 @@ impl GameServer {
                 }
                 
                 tick_count += 1;
-                
+                event_system.set_server_tick(tick_count);
+
                 let tick_event = serde_json::json!({
                     "tick_count": tick_count,
                     "timestamp": current_timestamp()
@@ -629,12 +1038,425 @@ impl GameServer {
                     error!("Failed to emit server_tick event: {}", e);
                     // Continue ticking even if emission fails
                 }
+
+                // Despawn objects registered with a time-to-live
+                if let Err(e) = event_system.despawn_expired_gorc_objects().await {
+                    error!("Failed to despawn expired GORC objects: {}", e);
+                }
+
+                // Announce authority changes made directly on the instance manager
+                if let Err(e) = event_system.emit_authority_changes().await {
+                    error!("Failed to emit authority changes: {}", e);
+                }
+
+                // Move fast players into zones between their movement updates
+                if let Err(e) = event_system.update_predicted_zones().await {
+                    error!("Failed to update predicted GORC zones: {}", e);
+                }
             }
             
             info!("✅ Server tick loop completed gracefully");
         });
     }
 
+    /// Starts the replication loop that flushes the network engine's queues.
+    /// 
+    /// Each tick sends the batches that are due to every player, including
+    /// broadcast channel updates and reliable updates awaiting a resend. The
+    /// loop runs at the fastest configured channel frequency.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `shutdown_state` - Optional shutdown state for coordinated shutdown
+    fn start_replication_with_shutdown(&self, shutdown_state: Option<ShutdownState>) {
+        let fastest_hz = self.config.gorc_network.channel_frequencies.iter().copied().fold(0.0, f64::max);
+        if fastest_hz <= 0.0 {
+            info!("⏸️ GORC replication loop disabled (no channel frequency configured)");
+            return;
+        }
+
+        let gorc_replication = self.gorc_replication.clone();
+        let tick_interval = Duration::from_secs_f64(1.0 / fastest_hz);
+        tokio::spawn(async move {
+            let mut ticker = interval(tick_interval);
+            loop {
+                ticker.tick().await;
+                if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+                    info!("📡 GORC replication loop stopping - shutdown initiated");
+                    break;
+                }
+
+                if let Err(e) = gorc_replication.lock().await.tick().await {
+                    error!("Failed to send GORC replication updates: {}", e);
+                }
+            }
+        });
+        info!("📡 GORC replication loop started at {} Hz", fastest_hz);
+    }
+
+    /// Restores GORC objects from the configured world snapshot, if any.
+    ///
+    /// A missing snapshot file is expected on first start and is not an error.
+    async fn restore_world_snapshot(&self) {
+        let (Some(path), Some(gorc_instances)) = (
+            self.config.snapshot.path.as_ref(),
+            self.horizon_event_system.get_gorc_instances(),
+        ) else {
+            return;
+        };
+
+        if !tokio::fs::try_exists(path).await.unwrap_or(false) {
+            info!("💾 No world snapshot at {}, starting with an empty world", path.display());
+            return;
+        }
+
+        match gorc_instances.load_snapshot(path).await {
+            Ok(count) => info!("💾 Restored {} object(s) from {}", count, path.display()),
+            Err(e) => error!("💾 Failed to restore world snapshot from {}: {}", path.display(), e),
+        }
+    }
+
+    /// Saves GORC objects to the configured world snapshot, if any.
+    pub async fn save_world_snapshot(&self) {
+        let (Some(path), Some(gorc_instances)) = (
+            self.config.snapshot.path.as_ref(),
+            self.horizon_event_system.get_gorc_instances(),
+        ) else {
+            return;
+        };
+
+        match gorc_instances.save_snapshot(path).await {
+            Ok(count) => info!("💾 Saved {} object(s) to {}", count, path.display()),
+            Err(e) => error!("💾 Failed to save world snapshot to {}: {}", path.display(), e),
+        }
+    }
+
+    /// Starts periodic world snapshot autosaves with shutdown support.
+    ///
+    /// Does nothing when snapshots or autosave are disabled in the config.
+    ///
+    /// # Arguments
+    ///
+    /// * `shutdown_state` - Optional shutdown state for coordinated shutdown
+    fn start_snapshot_autosave_with_shutdown(&self, shutdown_state: Option<ShutdownState>) {
+        let interval_secs = self.config.snapshot.autosave_interval_secs;
+        let (Some(path), Some(gorc_instances)) = (
+            self.config.snapshot.path.clone(),
+            self.horizon_event_system.get_gorc_instances(),
+        ) else {
+            return;
+        };
+        if interval_secs == 0 {
+            return;
+        }
+
+        info!("💾 World snapshot autosave every {}s to {}", interval_secs, path.display());
+        tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_secs(interval_secs));
+            // The first tick completes immediately; skip it so we don't save right after restoring
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+                if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+                    break;
+                }
+
+                match gorc_instances.save_snapshot(&path).await {
+                    Ok(count) => debug!("💾 Autosaved {} object(s) to {}", count, path.display()),
+                    Err(e) => error!("💾 World snapshot autosave failed: {}", e),
+                }
+            }
+        });
+    }
+
+    /// Starts background spatial index maintenance with shutdown support.
+    ///
+    /// Each pass bulk rebuilds region indexes that have absorbed enough
+    /// insertions and removals, preferring ticks with little player movement.
+    /// Does nothing when the maintenance interval is 0.
+    async fn start_spatial_maintenance_with_shutdown(&self, shutdown_state: Option<ShutdownState>) {
+        let Some(gorc_instances) = self.horizon_event_system.get_gorc_instances() else {
+            return;
+        };
+        let Some(maintenance_interval) = gorc_instances.spatial_maintenance_interval().await else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            let mut ticker = interval(maintenance_interval);
+            loop {
+                ticker.tick().await;
+                if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+                    break;
+                }
+
+                let report = gorc_instances.maintain_spatial_index().await;
+                if report.regions_rebuilt > 0 {
+                    debug!("🗺️ Rebuilt {} spatial region(s) in {:?} after {} mutation(s)",
+                           report.regions_rebuilt, report.duration, report.mutations);
+                } else if report.deferred {
+                    debug!("🗺️ Deferred spatial index rebuild: {} mutation(s) this tick", report.mutations);
+                }
+            }
+        });
+    }
+
+    /// Starts cross-server federation with the configured neighbor regions.
+    /// 
+    /// Binds the federation listener when `listen_address` is set and spawns a
+    /// scan loop that mirrors border objects to neighbors as ghosts, hands off
+    /// objects that leave this region, and emits `player_region_handoff` core
+    /// events for players that crossed into a neighbor. Players crossing into a
+    /// neighbor with a client URL are handed off and their client redirected.
+    /// Does nothing when no neighbors are configured.
+    /// 
+    /// # Returns
+    /// 
+    /// `Ok(())` if federation started or is disabled, or a `ServerError` if a
+    /// neighbor has no secret or the federation listener could not be bound.
+    async fn start_federation_with_shutdown(&self, shutdown_state: Option<ShutdownState>) -> Result<(), ServerError> {
+        let settings = &self.config.federation;
+        if settings.neighbors.is_empty() {
+            return Ok(());
+        }
+        if let Some(neighbor) = settings.neighbors.iter().find(|neighbor| neighbor.secret.is_empty()) {
+            return Err(ServerError::Internal(format!("Federation neighbor {} has no secret", neighbor.name)));
+        }
+        let Some(gorc_instances) = self.horizon_event_system.get_gorc_instances() else {
+            warn!("🌐 Federation disabled: GORC instance manager not available");
+            return Ok(());
+        };
+
+        let neighbors = settings
+            .neighbors
+            .iter()
+            .map(|neighbor| NeighborRegion { name: neighbor.name.clone(), bounds: neighbor.bounds.clone() })
+            .collect();
+        let link = Arc::new(TcpRegionLink::new(settings.region_name.clone(), &settings.neighbors));
+        let federation = Arc::new(
+            RegionFederation::new(self.config.region_bounds.clone(), neighbors, gorc_instances, link)
+                .with_overlap_band(settings.overlap_band),
+        );
+
+        if let Some(listen_address) = settings.listen_address {
+            let listener = tokio::net::TcpListener::bind(listen_address)
+                .await
+                .map_err(|e| ServerError::Network(format!("Federation listener bind failed: {e}")))?;
+            info!("🌐 Federation listening on {}", listen_address);
+            tokio::spawn(serve_federation(listener, federation.clone(), settings.neighbors.clone(), shutdown_state.clone()));
+        }
+
+        info!(
+            "🌐 Region '{}' federated with {} neighbor(s)",
+            settings.region_name,
+            settings.neighbors.len()
+        );
+
+        self.connection_manager.set_region_federation(federation.clone());
+        let horizon_event_system = self.horizon_event_system.clone();
+        let connection_manager = self.connection_manager.clone();
+        let neighbor_configs = settings.neighbors.clone();
+        let scan_interval_ms = settings.scan_interval_ms.max(1);
+        tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_millis(scan_interval_ms));
+            loop {
+                ticker.tick().await;
+                if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+                    break;
+                }
+
+                let report = federation.scan().await;
+                for (player_id, neighbor, position) in report.players_leaving {
+                    let event = PlayerRegionHandoffEvent {
+                        player_id,
+                        neighbor,
+                        position,
+                        timestamp: current_timestamp(),
+                    };
+                    if let Err(e) = horizon_event_system.emit_core("player_region_handoff", &event).await {
+                        error!("🌐 Failed to emit player_region_handoff: {}", e);
+                    }
+                    if let Some(neighbor) = neighbor_configs.iter().find(|config| config.name == event.neighbor) {
+                        hand_off_player(&federation, &connection_manager, neighbor, player_id, position).await;
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Starts accepting connections from other servers on the server-to-server channel.
+    /// 
+    /// Does nothing unless a secret and a listen address are configured;
+    /// with only a secret the server still connects out to its peers.
+    /// 
+    /// # Returns
+    /// 
+    /// `Ok(())` if the listener started or isn't configured, or a
+    /// `ServerError` if it could not be bound.
+    async fn start_s2s_with_shutdown(&self, shutdown_state: Option<ShutdownState>) -> Result<(), ServerError> {
+        let Some(s2s_link) = &self.s2s_link else {
+            return Ok(());
+        };
+        let Some(listen_address) = self.config.s2s.listen_address else {
+            info!("🔗 S2S: Connecting out to {} peers as {}", self.config.s2s.peers.len(), self.config.s2s.name);
+            return Ok(());
+        };
+
+        let listener = tokio::net::TcpListener::bind(listen_address)
+            .await
+            .map_err(|e| ServerError::Network(format!("S2S listener bind failed: {e}")))?;
+        info!("🔗 S2S: Listening for servers on ws://{} as {}", listen_address, self.config.s2s.name);
+        tokio::spawn(serve_s2s(listener, s2s_link.clone(), shutdown_state));
+        Ok(())
+    }
+
+    /// Starts the admin HTTP endpoint.
+    /// 
+    /// Does nothing when no `admin_address` is configured.
+    /// 
+    /// # Returns
+    /// 
+    /// `Ok(())` if the endpoint started or is disabled, or a `ServerError` if
+    /// the admin listener could not be bound.
+    async fn start_admin_with_shutdown(&self, shutdown_state: Option<ShutdownState>) -> Result<(), ServerError> {
+        let Some(admin_address) = self.config.admin_address else {
+            return Ok(());
+        };
+        let mut api = AdminApi::new(self.connection_manager.clone(), self.horizon_event_system.clone())
+            .with_plugin_manager(self.plugin_manager.clone());
+        match &self.config.admin_token {
+            Some(token) => api = api.with_token(token.clone()),
+            None => warn!("🛠️ Admin routes disabled: no admin token configured"),
+        }
+
+        let listener = tokio::net::TcpListener::bind(admin_address)
+            .await
+            .map_err(|e| ServerError::Network(format!("Admin listener bind failed: {e}")))?;
+        info!("🛠️ Admin endpoint listening on http://{}", admin_address);
+        tokio::spawn(serve_admin(listener, Arc::new(api), shutdown_state));
+        Ok(())
+    }
+
+    /// Starts the gRPC management API.
+    /// 
+    /// Does nothing when no `grpc_address` is configured.
+    /// 
+    /// # Returns
+    /// 
+    /// `Ok(())` if the API started or is disabled, or a `ServerError` if the
+    /// gRPC listener could not be bound.
+    async fn start_grpc_with_shutdown(&self, shutdown_state: Option<ShutdownState>) -> Result<(), ServerError> {
+        let Some(grpc_address) = self.config.grpc_address else {
+            return Ok(());
+        };
+        let mut service = ManagementService::new(self.connection_manager.clone(), self.horizon_event_system.clone())
+            .with_plugin_manager(self.plugin_manager.clone());
+        if let Some(shutdown_state) = &shutdown_state {
+            service = service.with_shutdown_state(shutdown_state.clone());
+        }
+        if self.config.admin_token.is_none() {
+            warn!("🛠️ gRPC management API disabled: no admin token configured");
+        }
+
+        let listener = tokio::net::TcpListener::bind(grpc_address)
+            .await
+            .map_err(|e| ServerError::Network(format!("gRPC listener bind failed: {e}")))?;
+        info!("🛠️ gRPC management API listening on {}", grpc_address);
+        let token = self.config.admin_token.clone();
+        tokio::spawn(async move {
+            if let Err(e) = serve_grpc(listener, service, token, shutdown_state).await {
+                error!("🛠️ gRPC management API stopped: {}", e);
+            }
+        });
+        Ok(())
+    }
+
+    /// Binds the UDP replication socket and answers session binds on it.
+    ///
+    /// Does nothing unless a UDP address is configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `shutdown_state` - Optional shutdown state that stops the receive loop
+    async fn start_udp_with_shutdown(&self, shutdown_state: Option<ShutdownState>) -> Result<(), ServerError> {
+        let Some(udp_address) = self.config.udp_address else {
+            return Ok(());
+        };
+
+        let socket = tokio::net::UdpSocket::bind(udp_address)
+            .await
+            .map_err(|e| ServerError::Network(format!("UDP socket bind failed: {e}")))?;
+        if !self.udp_transport.attach(Arc::new(socket)) {
+            warn!("📶 UDP transport already started");
+            return Ok(());
+        }
+        info!("📶 UDP replication endpoint listening on {}", udp_address);
+        tokio::spawn(serve_udp(self.udp_transport.clone(), shutdown_state));
+        Ok(())
+    }
+
+    /// Binds the QUIC endpoint and accepts QUIC clients on it.
+    ///
+    /// Does nothing unless a QUIC listen address is configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `shutdown_state` - Optional shutdown state that stops the accept loop
+    fn start_quic_with_shutdown(&self, shutdown_state: Option<ShutdownState>) -> Result<(), ServerError> {
+        let Some(listen_address) = self.config.quic.listen_address else {
+            return Ok(());
+        };
+
+        let endpoint = quinn::Endpoint::server(quic::server_config(&self.config.quic)?, listen_address)
+            .map_err(|e| ServerError::Network(format!("QUIC endpoint bind failed: {e}")))?;
+        info!("⚡ QUIC listener accepting clients on {}", listen_address);
+        tokio::spawn(serve_quic(
+            endpoint,
+            self.connection_manager.clone(),
+            self.horizon_event_system.clone(),
+            self.message_lanes.clone(),
+            self.quic_sessions.clone(),
+            shutdown_state,
+        ));
+        Ok(())
+    }
+
+    /// Loads the TLS certificates and builds the acceptor for `wss://` connections.
+    ///
+    /// Also starts reloading renewed certificates if a reload interval is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `shutdown_state` - Optional shutdown state that stops certificate reloading
+    ///
+    /// # Returns
+    ///
+    /// The acceptor, or `None` if TLS is disabled.
+    fn tls_acceptor_with_shutdown(&self, shutdown_state: Option<ShutdownState>) -> Result<Option<TlsAcceptor>, ServerError> {
+        let config = &self.config.tls;
+        if !config.enabled {
+            return Ok(None);
+        }
+
+        let store = Arc::new(CertificateStore::load(config)?);
+        info!(
+            "🔒 Serving wss:// on {} with SNI certificates for {:?}",
+            self.config.bind_address,
+            store.server_names()
+        );
+        if config.reload_interval_secs > 0 {
+            spawn_certificate_reloader(
+                store.clone(),
+                Duration::from_secs(config.reload_interval_secs),
+                shutdown_state,
+            );
+        }
+        tls_acceptor(store).map(Some)
+    }
+
     /// Starts the server tick loop that emits periodic tick events.
     /// 
     /// Creates a background task that emits `server_tick` events at the configured
@@ -662,6 +1484,39 @@ impl GameServer {
         Ok(())
     }
 
+    /// Starts the HTTP endpoint serving health probes and metrics.
+    /// 
+    /// Unlike the other listeners it is started outside [`start`](Self::start),
+    /// so probes are answered while plugins are still loading, and keeps
+    /// answering until shutdown completes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `shutdown_state` - Optional shutdown state for coordinated shutdown
+    /// 
+    /// # Returns
+    /// 
+    /// The bound address, `None` when no `health_address` is configured, or a
+    /// `ServerError` if the listener could not be bound.
+    pub async fn start_health_endpoint(
+        self: &Arc<Self>,
+        shutdown_state: Option<ShutdownState>,
+    ) -> Result<Option<SocketAddr>, ServerError> {
+        let Some(health_address) = self.config.health_address else {
+            return Ok(None);
+        };
+
+        let listener = tokio::net::TcpListener::bind(health_address)
+            .await
+            .map_err(|e| ServerError::Network(format!("Health listener bind failed: {e}")))?;
+        let local_address = listener
+            .local_addr()
+            .map_err(|e| ServerError::Network(format!("Health listener address unavailable: {e}")))?;
+        info!("🩺 Health endpoint listening on http://{}", local_address);
+        tokio::spawn(serve_health(listener, Arc::new(HealthManager::new()), self.clone(), shutdown_state));
+        Ok(Some(local_address))
+    }
+
     /// Gets a reference to the event system.
     /// 
     /// Provides access to the core event system for plugins and external
@@ -683,6 +1538,15 @@ impl GameServer {
         self.gorc_manager.clone()
     }
 
+    /// Gets the GORC replication system sending batched updates to players.
+    /// 
+    /// # Returns
+    /// 
+    /// The replication system, shared with the server's replication loop.
+    pub fn get_gorc_replication(&self) -> Arc<Mutex<CompleteGorcSystem>> {
+        self.gorc_replication.clone()
+    }
+
     /// Gets the subscription manager for dynamic subscription handling.
     /// 
     /// # Returns
@@ -710,6 +1574,15 @@ impl GameServer {
         self.spatial_partition.clone()
     }
 
+    /// Gets the connection manager tracking connected clients.
+    /// 
+    /// # Returns
+    /// 
+    /// An `Arc<ConnectionManager>` for inspecting and operating connections.
+    pub fn get_connection_manager(&self) -> Arc<ConnectionManager> {
+        self.connection_manager.clone()
+    }
+
     /// Gets the plugin manager for plugin lifecycle management.
     /// 
     /// # Returns
@@ -719,4 +1592,31 @@ impl GameServer {
         self.plugin_manager.clone()
     }
 
+    /// Gets the accept loops serving the WebSocket listeners.
+    /// 
+    /// # Returns
+    /// 
+    /// An `Arc<AcceptLoops>` for inspecting per-loop accept counters.
+    pub fn get_accept_loops(&self) -> Arc<AcceptLoops> {
+        self.accept_loops.clone()
+    }
+
+    /// Gets the priority lanes incoming client messages are routed through.
+    /// 
+    /// # Returns
+    /// 
+    /// An `Arc<MessageLanes>` for inspecting lane depths.
+    pub fn get_message_lanes(&self) -> Arc<MessageLanes> {
+        self.message_lanes.clone()
+    }
+
+    /// Gets the circuit breakers around plugin event handlers.
+    /// 
+    /// # Returns
+    /// 
+    /// An `Arc<PluginCircuitBreakers>` for inspecting breaker states.
+    pub fn get_plugin_circuit_breakers(&self) -> Arc<PluginCircuitBreakers> {
+        self.plugin_circuit_breakers.clone()
+    }
+
 }
\ No newline at end of file
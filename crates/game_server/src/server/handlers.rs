@@ -5,20 +5,36 @@
 //! handshaking, message processing, and cleanup.
 
 use crate::{
-    connection::ConnectionManager,
+    audit::record_audit,
+    connection::{
+        auth::AuthRequest,
+        hardware_id_from_query,
+        version::{self, Negotiated, VersionError},
+        ClientStream, ConnectionId, ConnectionManager, Heartbeat, SessionClaim,
+    },
+    config::ProtocolConfig,
+    security::{bans::BanTarget, geoip::GeoTag},
     error::ServerError,
-    messaging::route_client_message,
+    messaging::{ClientEncoding, ClientFrame, MessageLanes},
+    server::listeners::HandshakePermit,
+    server::proxy_protocol,
 };
+use futures::stream::{SplitSink, SplitStream};
 use futures::{SinkExt, StreamExt};
 use horizon_event_system::{
-    current_timestamp, DisconnectReason, EventSystem, PlayerConnectedEvent,
-    PlayerDisconnectedEvent, PlayerId,
+    current_timestamp, AuditAction, AuthenticationStatus, AuthenticationStatusChangedEvent, DisconnectReason, EventSystem,
+    PlayerArrival, PlayerAuthenticatedEvent, PlayerConnectedEvent, PlayerDisconnectedEvent, PlayerId,
+    PlayerLatencyEvent, PlayerReconnectedEvent, PlayerRegionArrivalEvent, SecurityAuditEvent,
 };
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::net::TcpStream;
-use tokio_tungstenite::{accept_async, tungstenite::Message};
-use tracing::{debug, error, trace};
+use tokio_rustls::TlsAcceptor;
+use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+use tokio_tungstenite::tungstenite::http::header::{HeaderValue, SEC_WEBSOCKET_PROTOCOL};
+use tokio_tungstenite::tungstenite::protocol::{frame::coding::CloseCode, CloseFrame};
+use tokio_tungstenite::{accept_hdr_async, tungstenite::Message, WebSocketStream};
+use tracing::{debug, error, info, warn};
 
 /// Handles a single client connection from establishment to cleanup.
 /// 
@@ -28,20 +44,29 @@ use tracing::{debug, error, trace};
 /// 
 /// # Connection Flow
 /// 
-/// 1. Perform WebSocket handshake
-/// 2. Register connection with the connection manager
-/// 3. Generate and assign a player ID
-/// 4. Emit player connected event
-/// 5. Start message handling tasks (incoming and outgoing)
-/// 6. Handle connection termination and cleanup
-/// 7. Emit player disconnected event
+/// 1. Read the client's address off the PROXY protocol header of a trusted load balancer,
+///    perform the TLS handshake if the listener terminates TLS, then the WebSocket handshake,
+///    negotiating the client's protocol version and message encoding, and closing the
+///    connection of incompatible clients
+/// 2. Wait for the client's JWT or identity provider token when tokens are required,
+///    and disconnect it if the token is invalid
+/// 3. Register connection with the connection manager
+/// 4. Generate and assign a player ID, bind the player a provider identity is linked to,
+///    resume the player of a `?resume=<token>` URL or claim the player a neighboring
+///    region handed off with `?handoff=<ticket>`
+/// 5. Emit player connected (reconnected, region arrival) and authenticated events
+/// 6. Send the session token and start message handling tasks (incoming and outgoing)
+/// 7. Handle connection termination and cleanup
+/// 8. Emit player disconnected event, once the resume window passed
 /// 
 /// # Arguments
 /// 
 /// * `stream` - The TCP stream for the client connection
-/// * `addr` - The remote address of the client
+/// * `addr` - The remote address of the client, or of the proxy relaying for it
 /// * `connection_manager` - Manager for tracking connections
 /// * `horizon_event_system` - Event system for plugin communication
+/// * `message_lanes` - Priority lanes incoming messages are routed through
+/// * `tls` - Acceptor terminating TLS for `wss://` listeners, `None` for `ws://`
 /// 
 /// # Returns
 /// 
@@ -50,87 +75,244 @@ use tracing::{debug, error, trace};
 /// 
 /// # Message Handling
 /// 
-/// The function spawns two concurrent tasks:
+/// The function runs three concurrent tasks:
 /// 
-/// * **Incoming Task**: Receives messages from the client and routes them to plugins
+/// * **Incoming Task**: Receives messages from the client and queues them in
+///   their priority lane for routing to plugins, and records heartbeat pongs
 /// * **Outgoing Task**: Receives messages from plugins and sends them to the client
+/// * **Heartbeat Task**: Pings the client every heartbeat interval and closes
+///   the connection once it leaves too many pings unanswered
 /// 
-/// These tasks run until the connection is closed or an error occurs.
+/// These tasks run until the connection is closed, times out or an error occurs.
 pub async fn handle_connection(
     stream: TcpStream,
     addr: SocketAddr,
     connection_manager: Arc<ConnectionManager>,
     horizon_event_system: Arc<EventSystem>,
+    message_lanes: Arc<MessageLanes>,
+    tls: Option<TlsAcceptor>,
+) -> Result<(), ServerError> {
+    let handshake = HandshakePermit::unlimited();
+    handle_admitted_connection(stream, addr, connection_manager, horizon_event_system, message_lanes, tls, handshake).await
+}
+
+/// Handles a connection the accept throttle admitted.
+/// 
+/// Works like [`handle_connection`], but gives up on the TLS and WebSocket
+/// handshakes once the permit's deadline passes, and releases the permit's
+/// pending slot as soon as the connection has a player.
+pub async fn handle_admitted_connection(
+    mut stream: TcpStream,
+    addr: SocketAddr,
+    connection_manager: Arc<ConnectionManager>,
+    horizon_event_system: Arc<EventSystem>,
+    message_lanes: Arc<MessageLanes>,
+    tls: Option<TlsAcceptor>,
+    handshake_permit: HandshakePermit,
 ) -> Result<(), ServerError> {
-    // Perform WebSocket handshake
-    let ws_stream = accept_async(stream)
+    // Behind a load balancer, the client is named by the proxy's header
+    let proxy = addr;
+    let addr = proxy_protocol::client_address(&mut stream, proxy, connection_manager.proxy_protocol())
         .await
+        .map_err(|e| ServerError::Network(format!("Connection from proxy {proxy}: {e}")))?;
+    if addr != proxy {
+        debug!("🔀 Proxy {} relays connection from {}", proxy, addr);
+    }
+    // Filtered addresses are turned away before paying for any handshake
+    refuse_filtered(&connection_manager, addr)?;
+    let stream = match tls {
+        Some(acceptor) => ClientStream::Tls(Box::new(
+            handshake_permit
+                .limit(acceptor.accept(stream))
+                .await?
+                .map_err(|e| ServerError::Network(format!("TLS handshake failed: {e}")))?,
+        )),
+        None => ClientStream::Plain(stream),
+    };
+
+    // Perform WebSocket handshake, picking up a session token or handoff ticket
+    // and the client's hardware ID, and negotiating the protocol version and
+    // encoding of the client's messages
+    let mut claim = None;
+    let mut hardware_id = None;
+    let mut negotiated = None;
+    #[allow(clippy::result_large_err)]
+    let upgrade = accept_hdr_async(stream, |request: &Request, mut response: Response| {
+        claim = request.uri().query().and_then(SessionClaim::from_query);
+        hardware_id = request.uri().query().and_then(hardware_id_from_query);
+        let offered = request.headers().get(SEC_WEBSOCKET_PROTOCOL).and_then(|offered| offered.to_str().ok());
+        let negotiation = version::negotiate(offered, version::client_version_from_request(request), connection_manager.protocol());
+        // Clients fail upgrades that echo none of their subprotocols, so an
+        // incompatible client gets its first one echoed to receive the close code
+        let subprotocol = match &negotiation {
+            Ok(Negotiated { subprotocol, .. }) => subprotocol.clone(),
+            Err(_) => offered.and_then(|offered| offered.split(',').next()).map(|first| first.trim().to_string()),
+        };
+        if let Some(subprotocol) = subprotocol.and_then(|subprotocol| HeaderValue::from_str(&subprotocol).ok()) {
+            response.headers_mut().insert(SEC_WEBSOCKET_PROTOCOL, subprotocol);
+        }
+        negotiated = Some(negotiation);
+        Ok(response)
+    });
+    let ws_stream = handshake_permit
+        .limit(upgrade)
+        .await?
         .map_err(|e| ServerError::Network(format!("WebSocket handshake failed: {e}")))?;
 
     let (ws_sender, mut ws_receiver) = ws_stream.split();
     let ws_sender = Arc::new(tokio::sync::Mutex::new(ws_sender));
-    let connection_id = connection_manager.add_connection(addr).await;
+    // Incompatible clients are told what the server expects before they get a player
+    let negotiated = match negotiated {
+        Some(Ok(negotiated)) => negotiated,
+        Some(Err(error)) => {
+            warn!("🧩 Refused incompatible client {}: {}", addr, error);
+            refuse_incompatible(&ws_sender, &error, connection_manager.protocol()).await;
+            return Ok(());
+        }
+        None => return Err(ServerError::Internal("WebSocket handshake skipped negotiation".to_string())),
+    };
+    let encoding = negotiated.encoding;
+    if let Some(hardware_id) = &hardware_id {
+        refuse_banned_identity(&connection_manager, &horizon_event_system, addr, BanTarget::Hardware(hardware_id.clone())).await?;
+    }
+    // Clients prove who they are before they get a player, so a failed login
+    // never reaches plugins. Players arriving from a neighboring region
+    // authenticated there.
+    let requires_credentials = requires_credentials(&connection_manager);
+    let handshake = match &claim {
+        Some(SessionClaim::Handoff(_)) => None,
+        _ if !requires_credentials => None,
+        _ => match receive_handshake(&mut ws_receiver, encoding, &connection_manager).await {
+            Ok(handshake) => Some(handshake),
+            Err(error) => {
+                warn!("🔐 Client {} failed to authenticate: {}", addr, error);
+                refuse_credentials(&ws_sender, &error).await;
+                return Ok(());
+            }
+        },
+    };
+
+    let account = handshake.as_ref().and_then(Handshake::account);
+    let player = connect_player(addr, &connection_manager, &horizon_event_system, claim.as_ref(), account).await?;
+    drop(handshake_permit);
+    let connection_id = player.connection_id;
+    if let Some(hardware_id) = hardware_id {
+        connection_manager.set_hardware_id(connection_id, hardware_id).await;
+    }
+    connection_manager.set_client_version(connection_id, negotiated.version).await;
     connection_manager.register_ws_sender(connection_id, ws_sender.clone()).await;
 
-    // Generate player ID and emit connection event
-    let player_id = PlayerId::new();
-    connection_manager
-        .set_player_id(connection_id, player_id)
-        .await;
+    match handshake {
+        Some(handshake) => {
+            start_session(connection_id, player.player_id, handshake, &*ws_sender, &connection_manager, &horizon_event_system).await;
+        }
+        None => {
+            let status = connection_manager.get_auth_status(connection_id).await;
+            let vouched_for = status == Some(AuthenticationStatus::Authenticated)
+                || (status == Some(AuthenticationStatus::Guest) && connection_manager.authentication().allows_guests());
+            if requires_credentials && !vouched_for {
+                // A handoff ticket that didn't check out leaves nothing vouching for the client
+                let error = "the handoff ticket is invalid or expired".to_string();
+                warn!("🔐 Connection {} failed to authenticate: {}", connection_id, error);
+                refuse_credentials(&ws_sender, &error).await;
+                let reason = DisconnectReason::Error(format!("Authentication failed: {error}"));
+                disconnect_player(connection_id, reason, &connection_manager, &horizon_event_system).await?;
+                connection_manager.remove_ws_sender(connection_id).await;
+                return Ok(());
+            }
+        }
+    }
+    let mut guest = connection_manager.get_auth_status(connection_id).await == Some(AuthenticationStatus::Guest);
 
-    // Emit core infrastructure event
-    horizon_event_system
-        .emit_core(
-            "player_connected",
-            &PlayerConnectedEvent {
-                player_id,
-                connection_id: connection_id.to_string(),
-                remote_addr: addr.to_string(),
-                timestamp: current_timestamp(),
-            },
-        )
-        .await
-        .map_err(|e| ServerError::Internal(e.to_string()))?;
+    let resumption = connection_manager.session_resumption();
+    if let Some(token) = resumption.issue(player.player_id) {
+        let session = serde_json::json!({
+            "event": "session",
+            "player_id": player.player_id,
+            "token": token,
+            "resume_window_secs": resumption.window().as_secs(),
+            "resumed": player.resumed,
+        });
+        let _ = ws_sender.lock().await.send(Message::Text(session.to_string().into())).await;
+    }
 
     let mut message_receiver = connection_manager.subscribe();
+    let counters = connection_manager.counters(connection_id).await.unwrap_or_default();
+    counters.clear_queue();
+    let counters_incoming = counters.clone();
+    let overflow = counters.clone();
     let ws_sender_incoming = ws_sender.clone();
     let ws_sender_outgoing = ws_sender.clone();
+    let heartbeat_config = connection_manager.heartbeat().clone();
+    let heartbeat = Arc::new(std::sync::Mutex::new(Heartbeat::new(heartbeat_config.max_missed)));
+    let heartbeat_incoming = heartbeat.clone();
+    let connection_manager_incoming = connection_manager.clone();
+    let horizon_event_system_incoming = horizon_event_system.clone();
+    let player_id = player.player_id;
 
-    // Incoming message task - routes raw messages to plugins
-    let incoming_task = {
-        let connection_manager = connection_manager.clone();
-        let horizon_event_system = horizon_event_system.clone();
-
-        async move {
-            while let Some(msg) = ws_receiver.next().await {
-                match msg {
-                    Ok(Message::Text(text)) => {
-                        // Route raw message to plugins via events
-                        if let Err(e) = route_client_message(
-                            &text,
-                            connection_id,
-                            &connection_manager,
-                            &horizon_event_system,
-                        )
-                        .await
-                        {
-                            trace!("❌ Message routing error: {}", e);
+    // Incoming message task - queues raw messages for routing to plugins
+    let incoming_task = async move {
+        while let Some(msg) = ws_receiver.next().await {
+            match &msg {
+                Ok(Message::Text(text)) => counters_incoming.record_received(text.len()),
+                Ok(Message::Binary(message)) => counters_incoming.record_received(message.len()),
+                _ => counters_incoming.touch(),
+            }
+            match msg {
+                Ok(Message::Text(text)) => {
+                    // Guests upgrade by sending the message clients authenticate with
+                    if guest {
+                        if let Some(request) = upgrade_request(&ClientFrame::Text(text.to_string())) {
+                            guest = !upgrade_guest(connection_id, player_id, request, &*ws_sender_incoming, &connection_manager_incoming, &horizon_event_system_incoming).await;
+                            continue;
                         }
                     }
-                    Ok(Message::Close(_)) => {
-                        debug!("🔌 Client {} requested close", connection_id);
-                        break;
-                    }
-                    Ok(Message::Ping(data)) => {
-                        let mut ws_sender = ws_sender_incoming.lock().await;
-                        let _ = ws_sender.send(Message::Pong(data)).await;
+                    // Higher lanes are routed first when the server is busy
+                    message_lanes.submit(connection_id, text.to_string()).await;
+                }
+                Ok(Message::Binary(message)) => {
+                    // Raw binary frames are accepted whatever the connection's encoding
+                    let Some(frame) = ClientFrame::from_binary(encoding, message.to_vec()) else {
+                        continue;
+                    };
+                    if guest {
+                        if let Some(request) = upgrade_request(&frame) {
+                            guest = !upgrade_guest(connection_id, player_id, request, &*ws_sender_incoming, &connection_manager_incoming, &horizon_event_system_incoming).await;
+                            continue;
+                        }
                     }
-                    Err(e) => {
-                        error!("WebSocket error for connection {}: {}", connection_id, e);
-                        break;
+                    message_lanes.submit_frame(connection_id, frame).await;
+                }
+                Ok(Message::Close(_)) => {
+                    debug!("🔌 Client {} requested close", connection_id);
+                    break;
+                }
+                Ok(Message::Ping(data)) => {
+                    let mut ws_sender = ws_sender_incoming.lock().await;
+                    let _ = ws_sender.send(Message::Pong(data)).await;
+                }
+                Ok(Message::Pong(data)) => {
+                    let round_trip_time = heartbeat_incoming
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .on_pong(&data);
+                    if let Some(round_trip_time) = round_trip_time {
+                        connection_manager_incoming.set_round_trip_time(connection_id, round_trip_time).await;
+                        let latency = PlayerLatencyEvent {
+                            player_id,
+                            round_trip_ms: round_trip_time.as_secs_f32() * 1000.0,
+                            timestamp: current_timestamp(),
+                        };
+                        if let Err(e) = horizon_event_system_incoming.emit_core("player_latency", &latency).await {
+                            warn!("⚠️ Failed to emit latency of player {}: {}", player_id, e);
+                        }
                     }
-                    _ => {}
                 }
+                Err(e) => {
+                    error!("WebSocket error for connection {}: {}", connection_id, e);
+                    break;
+                }
+                _ => {}
             }
         }
     };
@@ -141,43 +323,687 @@ pub async fn handle_connection(
         async move {
             while let Ok((target_connection_id, message)) = message_receiver.recv().await {
                 if target_connection_id == connection_id {
-                    let message_text = String::from_utf8_lossy(&message);
+                    let bytes = message.len();
+                    // JSON goes out as text; binary GORC frames are never valid UTF-8
+                    let frame = match String::from_utf8(message) {
+                        Ok(text) => Message::Text(text.into()),
+                        Err(e) => Message::Binary(e.into_bytes().into()),
+                    };
                     let mut ws_sender = ws_sender.lock().await;
-                    if let Err(e) = ws_sender
-                        .send(Message::Text(message_text.to_string().into()))
-                        .await
-                    {
+                    if let Err(e) = ws_sender.send(frame).await {
+                        counters.record_dequeued(None);
                         error!("Failed to send message: {}", e);
                         break;
                     }
+                    counters.record_dequeued(Some(bytes));
+                }
+            }
+        }
+    };
+
+    // Heartbeat task - pings the client and gives up on it after too many missed pongs
+    let heartbeat_task = {
+        let ws_sender = ws_sender.clone();
+        async move {
+            if heartbeat_config.interval_ms == 0 {
+                return std::future::pending().await;
+            }
+
+            let interval = std::time::Duration::from_millis(heartbeat_config.interval_ms);
+            let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+            loop {
+                ticker.tick().await;
+                let ping = heartbeat.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).next_ping();
+                let mut ws_sender = ws_sender.lock().await;
+                let Some(ping) = ping else {
+                    warn!(
+                        "💔 Connection {} missed {} heartbeats, disconnecting",
+                        connection_id, heartbeat_config.max_missed
+                    );
+                    let close = Message::Close(Some(CloseFrame {
+                        code: CloseCode::Away,
+                        reason: "Heartbeat timeout".into(),
+                    }));
+                    let _ = ws_sender.send(close).await;
+                    return;
+                };
+                if ws_sender.send(Message::Ping(ping.into())).await.is_err() {
+                    // The other tasks see the broken connection and end it
+                    return std::future::pending().await;
+                }
+            }
+        }
+    };
+
+    // Run the tasks concurrently until one completes
+    let reason = tokio::select! {
+        _ = incoming_task => DisconnectReason::ClientDisconnect,
+        _ = outgoing_task => DisconnectReason::ClientDisconnect,
+        _ = heartbeat_task => DisconnectReason::Timeout,
+        // Dropping the outgoing task frees a send stuck on the stalled client
+        _ = overflow.overflowed() => DisconnectReason::Error("Outbound queue overflow".to_string()),
+    };
+
+    disconnect_player(connection_id, reason, &connection_manager, &horizon_event_system).await?;
+    connection_manager.remove_ws_sender(connection_id).await;
+    Ok(())
+}
+
+/// What a client proved about itself before it got a player
+pub(crate) struct Credentials {
+    /// Subject of the JWT, or the provider's ID of the user
+    subject: String,
+    /// Identity provider the client logged in through, `None` for a JWT
+    provider: Option<String>,
+    /// Claims of the JWT, or what the provider said about the user
+    claims: serde_json::Value,
+    /// Player the client's provider identity is linked to
+    account: Option<PlayerId>,
+}
+
+/// How a client started its session
+pub(crate) enum Handshake {
+    /// The client presented valid credentials
+    Authenticated(Credentials),
+    /// The client plays as a guest
+    Guest,
+}
+
+impl Handshake {
+    /// Gets the player the client's provider identity is linked to
+    pub(crate) fn account(&self) -> Option<PlayerId> {
+        match self {
+            Handshake::Authenticated(credentials) => credentials.account,
+            Handshake::Guest => None,
+        }
+    }
+}
+
+/// Sends the replies of the handshake and of guest upgrades to a client,
+/// whichever transport it connected over
+#[horizon_event_system::async_trait]
+pub(crate) trait ClientReplies: Send + Sync {
+    /// Sends a JSON message to the client
+    async fn reply(&self, message: serde_json::Value);
+}
+
+#[horizon_event_system::async_trait]
+impl ClientReplies for tokio::sync::Mutex<SplitSink<WebSocketStream<ClientStream>, Message>> {
+    async fn reply(&self, message: serde_json::Value) {
+        let _ = self.lock().await.send(Message::Text(message.to_string().into())).await;
+    }
+}
+
+/// Checks whether clients must authenticate before they get a player
+pub(crate) fn requires_credentials(connection_manager: &ConnectionManager) -> bool {
+    connection_manager.authentication().requires_token() || connection_manager.identity().has_providers()
+}
+
+/// Waits for the JWT or identity provider token a client presents after
+/// connecting and verifies it, or for the client to ask to play as a guest.
+/// 
+/// # Returns
+/// 
+/// How the client started its session, or why it was rejected.
+async fn receive_handshake(
+    ws_receiver: &mut SplitStream<WebSocketStream<ClientStream>>,
+    encoding: ClientEncoding,
+    connection_manager: &ConnectionManager,
+) -> Result<Handshake, String> {
+    let authentication = connection_manager.authentication();
+    let next_frame = async {
+        while let Some(message) = ws_receiver.next().await {
+            match message {
+                Ok(Message::Text(text)) => return Some(ClientFrame::Text(text.to_string())),
+                Ok(Message::Binary(message)) if encoding.is_binary() => {
+                    return Some(ClientFrame::Binary(encoding, message.to_vec()))
                 }
+                Ok(Message::Ping(_) | Message::Pong(_)) => {}
+                _ => return None,
+            }
+        }
+        None
+    };
+    match tokio::time::timeout(authentication.handshake_timeout(), next_frame).await {
+        Err(_) => Err("no token was presented in time".to_string()),
+        Ok(None) => Err("the connection closed before a token was presented".to_string()),
+        Ok(Some(frame)) => check_handshake(&frame, connection_manager).await,
+    }
+}
+
+/// Verifies the first message of a client, which presents its credentials
+/// or asks to play as a guest.
+/// 
+/// # Returns
+/// 
+/// How the client started its session, or why it was rejected.
+pub(crate) async fn check_handshake(frame: &ClientFrame, connection_manager: &ConnectionManager) -> Result<Handshake, String> {
+    let request = frame.decode::<AuthRequest>().map_err(|_| expected_credentials())?;
+    if request.kind == "guest" && connection_manager.authentication().allows_guests() {
+        return Ok(Handshake::Guest);
+    }
+    verify_credentials(request, None, connection_manager).await.map(Handshake::Authenticated)
+}
+
+/// Applies a completed handshake to the player the client got.
+/// 
+/// Authenticated clients are announced as in [`authenticate_player`];
+/// guests get the `Guest` status and a `guest` reply.
+pub(crate) async fn start_session(
+    connection_id: ConnectionId,
+    player_id: PlayerId,
+    handshake: Handshake,
+    replies: &dyn ClientReplies,
+    connection_manager: &ConnectionManager,
+    horizon_event_system: &EventSystem,
+) {
+    match handshake {
+        Handshake::Authenticated(credentials) => {
+            authenticate_player(connection_id, player_id, credentials, false, replies, connection_manager, horizon_event_system).await;
+        }
+        Handshake::Guest => {
+            update_auth_status(connection_id, player_id, AuthenticationStatus::Guest, connection_manager, horizon_event_system).await;
+            info!("👤 Player {} is playing as a guest", player_id);
+            replies.reply(serde_json::json!({ "event": "guest", "player_id": player_id })).await;
+        }
+    }
+}
+
+/// Describes the messages clients authenticate with
+fn expected_credentials() -> String {
+    "expected {\"type\": \"auth\", \"token\": ...} or {\"type\": \"login\", \"provider\": ..., \"token\": ...} as the first message"
+        .to_string()
+}
+
+/// Verifies the JWT or identity provider token a client presented
+/// 
+/// # Arguments
+/// 
+/// * `request` - The client's authentication message
+/// * `guest` - Player of a guest upgrading to an account, which a provider identity is linked to
+/// * `connection_manager` - Manager holding the keys and identity providers
+/// 
+/// # Returns
+/// 
+/// What the token proved, or why it was rejected.
+async fn verify_credentials(
+    request: AuthRequest,
+    guest: Option<PlayerId>,
+    connection_manager: &ConnectionManager,
+) -> Result<Credentials, String> {
+    match (request.kind.as_str(), request.provider) {
+        ("auth", _) => {
+            let claims = connection_manager.authentication().verify(&request.token).map_err(|e| e.to_string())?;
+            Ok(Credentials {
+                subject: claims.sub,
+                provider: None,
+                claims: serde_json::Value::Object(claims.claims),
+                account: None,
+            })
+        }
+        ("login", Some(provider)) => {
+            let identity = connection_manager.identity();
+            let (player_id, identity) = match guest {
+                Some(player_id) => (player_id, identity.link(&provider, &request.token, player_id).await),
+                None => match identity.login(&provider, &request.token).await {
+                    Ok((player_id, identity)) => (player_id, Ok(identity)),
+                    Err(e) => return Err(e.to_string()),
+                },
+            };
+            let identity = identity.map_err(|e| e.to_string())?;
+            Ok(Credentials {
+                subject: identity.subject,
+                provider: Some(identity.provider),
+                claims: identity.claims,
+                account: Some(player_id),
+            })
+        }
+        _ => Err(expected_credentials()),
+    }
+}
+
+/// Picks out a guest's request to upgrade to an account among its messages
+pub(crate) fn upgrade_request(frame: &ClientFrame) -> Option<AuthRequest> {
+    frame
+        .decode::<AuthRequest>()
+        .ok()
+        .filter(|request| request.kind == "auth" || request.kind == "login")
+}
+
+/// Upgrades a guest to an account in-session.
+/// 
+/// The guest keeps its player, and with it its GORC object, position and
+/// subscriptions. A failed upgrade leaves it a guest.
+/// 
+/// # Returns
+/// 
+/// `true` if the guest was upgraded.
+pub(crate) async fn upgrade_guest(
+    connection_id: ConnectionId,
+    player_id: PlayerId,
+    request: AuthRequest,
+    replies: &dyn ClientReplies,
+    connection_manager: &ConnectionManager,
+    horizon_event_system: &EventSystem,
+) -> bool {
+    match verify_credentials(request, Some(player_id), connection_manager).await {
+        Ok(credentials) => {
+            info!("👤 Guest {} upgraded to an account", player_id);
+            authenticate_player(connection_id, player_id, credentials, true, replies, connection_manager, horizon_event_system).await;
+            true
+        }
+        Err(error) => {
+            debug!("👤 Guest {} failed to upgrade: {}", player_id, error);
+            replies.reply(serde_json::json!({ "event": "upgrade_failed", "error": error })).await;
+            false
+        }
+    }
+}
+
+/// Marks a connection's player authenticated and tells plugins and the client.
+/// 
+/// The status change is announced as `auth_status_changed` and the
+/// credentials as a `player_authenticated` core event.
+async fn authenticate_player(
+    connection_id: ConnectionId,
+    player_id: PlayerId,
+    credentials: Credentials,
+    upgraded_from_guest: bool,
+    replies: &dyn ClientReplies,
+    connection_manager: &ConnectionManager,
+    horizon_event_system: &EventSystem,
+) {
+    update_auth_status(connection_id, player_id, AuthenticationStatus::Authenticated, connection_manager, horizon_event_system).await;
+    match &credentials.provider {
+        Some(provider) => info!("🪪 Player {} logged in as {} user {}", player_id, provider, credentials.subject),
+        None => info!("🔐 Player {} authenticated as {}", player_id, credentials.subject),
+    }
+
+    let authenticated = serde_json::json!({
+        "event": "authenticated",
+        "player_id": player_id,
+        "subject": credentials.subject,
+        "provider": credentials.provider,
+        "upgraded": upgraded_from_guest,
+    });
+    replies.reply(authenticated).await;
+
+    let authenticated = PlayerAuthenticatedEvent {
+        player_id,
+        subject: credentials.subject,
+        provider: credentials.provider,
+        claims: credentials.claims,
+        upgraded_from_guest,
+        timestamp: current_timestamp(),
+    };
+    if let Err(e) = horizon_event_system.emit_core("player_authenticated", &authenticated).await {
+        warn!("⚠️ Failed to emit player_authenticated event for player {}: {}", player_id, e);
+    }
+}
+
+/// Tells a client why its credentials were refused and closes its connection
+async fn refuse_credentials(ws_sender: &tokio::sync::Mutex<SplitSink<WebSocketStream<ClientStream>, Message>>, error: &str) {
+    let mut sender = ws_sender.lock().await;
+    let rejection = serde_json::json!({ "event": "auth_failed", "error": error });
+    let _ = sender.send(Message::Text(rejection.to_string().into())).await;
+    let close = Message::Close(Some(CloseFrame {
+        code: CloseCode::Policy,
+        reason: "Authentication failed".into(),
+    }));
+    let _ = sender.send(close).await;
+}
+
+/// Tells a client which protocol and client versions the server expects and closes its connection
+async fn refuse_incompatible(
+    ws_sender: &tokio::sync::Mutex<SplitSink<WebSocketStream<ClientStream>, Message>>,
+    error: &VersionError,
+    protocol: &ProtocolConfig,
+) {
+    let mut sender = ws_sender.lock().await;
+    let rejection = serde_json::json!({
+        "event": "incompatible_client",
+        "error": error.to_string(),
+        "protocol_version": version::PROTOCOL_VERSION,
+        "min_client_version": protocol.min_client_version,
+    });
+    let _ = sender.send(Message::Text(rejection.to_string().into())).await;
+    let close = Message::Close(Some(CloseFrame {
+        code: CloseCode::from(error.close_code()),
+        reason: match error {
+            VersionError::UnsupportedProtocol(_) | VersionError::MissingProtocol => "Unsupported protocol version",
+            VersionError::OutdatedClient(..) | VersionError::MissingClientVersion(_) => "Client version outdated",
+        }
+        .into(),
+    }));
+    let _ = sender.send(close).await;
+}
+
+/// Sets a connection's authentication status and announces the change to plugins
+async fn update_auth_status(
+    connection_id: ConnectionId,
+    player_id: PlayerId,
+    status: AuthenticationStatus,
+    connection_manager: &ConnectionManager,
+    horizon_event_system: &EventSystem,
+) {
+    let old_status = connection_manager.get_auth_status(connection_id).await.unwrap_or_default();
+    connection_manager.set_auth_status(connection_id, status).await;
+    if old_status == status {
+        return;
+    }
+
+    let changed = AuthenticationStatusChangedEvent {
+        player_id,
+        old_status,
+        new_status: status,
+        timestamp: current_timestamp(),
+    };
+    if let Err(e) = horizon_event_system.emit_core("auth_status_changed", &changed).await {
+        warn!("⚠️ Failed to emit auth status changed event for player {}: {:?}", player_id, e);
+    }
+}
+
+/// A client connection registered by [`connect_player`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ConnectedPlayer {
+    /// The new connection
+    pub connection_id: ConnectionId,
+    /// The connection's player
+    pub player_id: PlayerId,
+    /// Whether the connection resumed an existing player
+    pub resumed: bool,
+    /// Whether the player was handed off by a neighboring region
+    pub arrived: bool,
+}
+
+/// Registers a new client connection and announces its player to plugins.
+/// 
+/// Shared by every client transport so plugins see the same
+/// `player_connected` event regardless of how the client connected. A valid
+/// session token binds the connection to the token's player instead, and
+/// `player_reconnected` is emitted. A valid handoff ticket binds it to the
+/// player a neighboring region handed off, and `player_region_arrival` is
+/// emitted. A client that logged in through an identity provider is bound
+/// to the player its identity is linked to, whatever token it presented.
+/// While the server drains, only resumed sessions are accepted.
+/// 
+/// # Arguments
+/// 
+/// * `addr` - The remote address of the client
+/// * `connection_manager` - Manager for tracking connections
+/// * `horizon_event_system` - Event system for plugin communication
+/// * `claim` - Session token or handoff ticket the client presented, if any
+/// * `account` - Player the client's provider identity is linked to, if it logged in
+/// 
+/// # Returns
+/// 
+/// The new connection with its player.
+pub(crate) async fn connect_player(
+    addr: SocketAddr,
+    connection_manager: &ConnectionManager,
+    horizon_event_system: &EventSystem,
+    claim: Option<&SessionClaim>,
+    account: Option<PlayerId>,
+) -> Result<ConnectedPlayer, ServerError> {
+    refuse_filtered(connection_manager, addr)?;
+    refuse_banned(connection_manager, BanTarget::Ip(addr.ip()))?;
+
+    if let Some(SessionClaim::Handoff(ticket)) = claim {
+        let arrival = match connection_manager.region_federation() {
+            Some(federation) => federation.claim_arrival(ticket).await,
+            None => None,
+        };
+        match arrival {
+            Some(arrival) => {
+                refuse_draining(connection_manager, addr)?;
+                return arrive_player(addr, connection_manager, horizon_event_system, arrival).await;
             }
+            None => debug!("🌐 Connection from {} presented an unknown or expired handoff ticket", addr),
         }
+    }
+
+    let resume_token = match claim {
+        Some(SessionClaim::Resume(token)) => Some(token.as_str()),
+        _ => None,
     };
+    let resumption = connection_manager.session_resumption();
+    let resumed_player = match account {
+        // A parked account player picks up where its dropped connection left off
+        Some(player_id) => (resumption.is_parked(player_id) && resumption.revoke(player_id)).then_some(player_id),
+        None => resume_token.and_then(|token| resumption.resume(token)),
+    };
+    if account.is_none() && resume_token.is_some() && resumed_player.is_none() {
+        debug!("🔑 Connection from {} presented an invalid or expired session token", addr);
+    }
+    if resumed_player.is_none() {
+        refuse_draining(connection_manager, addr)?;
+    }
+    if let Some(player_id) = resumed_player.or(account) {
+        refuse_banned_identity(connection_manager, horizon_event_system, addr, BanTarget::Player(player_id)).await?;
+        // The dropped connection may not have been noticed yet
+        if let Some(stale_connection_id) = connection_manager.get_connection_id_by_player(player_id).await {
+            let _ = connection_manager
+                .close_connection(stale_connection_id, Some("Session resumed elsewhere".to_string()))
+                .await;
+        }
+    }
 
-    // Run both tasks concurrently until one completes
-    tokio::select! {
-        _ = incoming_task => {},
-        _ = outgoing_task => {},
+    let geo = admit_geo(connection_manager, addr)?;
+    let connection_id = connection_manager.add_connection(addr).await;
+    if let Some(geo) = geo {
+        connection_manager.set_geo_tag(connection_id, geo).await;
     }
+    let player_id = resumed_player.or(account).unwrap_or_default();
+    connection_manager
+        .set_player_id(connection_id, player_id)
+        .await;
 
-    // Emit disconnection event
-    if let Some(player_id) = connection_manager.get_player_id(connection_id).await {
+    if resumed_player.is_some() {
+        info!("🔑 Player {} resumed its session from {}", player_id, addr);
         horizon_event_system
             .emit_core(
-                "player_disconnected",
-                &PlayerDisconnectedEvent {
+                "player_reconnected",
+                &PlayerReconnectedEvent {
                     player_id,
                     connection_id: connection_id.to_string(),
-                    reason: DisconnectReason::ClientDisconnect,
+                    remote_addr: addr.to_string(),
                     timestamp: current_timestamp(),
                 },
             )
             .await
             .map_err(|e| ServerError::Internal(e.to_string()))?;
+        return Ok(ConnectedPlayer { connection_id, player_id, resumed: true, arrived: false });
+    }
+
+    // Emit core infrastructure event
+    horizon_event_system
+        .emit_core(
+            "player_connected",
+            &PlayerConnectedEvent {
+                player_id,
+                connection_id: connection_id.to_string(),
+                remote_addr: addr.to_string(),
+                timestamp: current_timestamp(),
+            },
+        )
+        .await
+        .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+    Ok(ConnectedPlayer { connection_id, player_id, resumed: false, arrived: false })
+}
+
+/// Refuses a connection from an address the IP allow and deny lists don't permit
+fn refuse_filtered(connection_manager: &ConnectionManager, addr: SocketAddr) -> Result<(), ServerError> {
+    match connection_manager.ip_filter().permits(addr.ip()) {
+        true => Ok(()),
+        false => Err(ServerError::Network(format!("Refused connection from filtered address {}", addr.ip()))),
+    }
+}
+
+/// Admits a connection by the GeoIP policy, counting it against its region's caps.
+/// 
+/// # Returns
+/// 
+/// Where the client is located, `None` without a GeoIP policy, or the
+/// refusal if its country is refused or its region is full.
+fn admit_geo(connection_manager: &ConnectionManager, addr: SocketAddr) -> Result<Option<GeoTag>, ServerError> {
+    let Some(geo_policy) = connection_manager.geo_policy() else {
+        return Ok(None);
+    };
+    geo_policy
+        .admit(addr.ip())
+        .map(Some)
+        .map_err(|e| ServerError::Network(format!("Refused connection from {}: {}", addr.ip(), e)))
+}
+
+/// Refuses a new player while the server drains
+fn refuse_draining(connection_manager: &ConnectionManager, addr: SocketAddr) -> Result<(), ServerError> {
+    match connection_manager.is_draining() {
+        true => Err(ServerError::Network(format!("Refused connection from {addr} while draining"))),
+        false => Ok(()),
+    }
+}
+
+/// Refuses a connection if its address, player or client hardware is banned
+fn refuse_banned(connection_manager: &ConnectionManager, target: BanTarget) -> Result<(), ServerError> {
+    match connection_manager.bans().is_banned(&target) {
+        true => Err(ServerError::Network(format!("Refused connection from banned {target}"))),
+        false => Ok(()),
+    }
+}
+
+/// Refuses a connection if its player or client hardware is banned.
+/// 
+/// A banned player or hardware turning up from an address that isn't banned
+/// is likely dodging its ban, so the attempt is recorded as a `ban_evasion`
+/// security audit event.
+async fn refuse_banned_identity(
+    connection_manager: &ConnectionManager,
+    horizon_event_system: &EventSystem,
+    addr: SocketAddr,
+    target: BanTarget,
+) -> Result<(), ServerError> {
+    let refused = refuse_banned(connection_manager, target.clone());
+    if refused.is_err() && !connection_manager.bans().is_banned(&BanTarget::Ip(addr.ip())) {
+        warn!("🚷 Banned {} tried to connect from {}", target, addr.ip());
+        let audit = SecurityAuditEvent::new(AuditAction::BanEvasion, "server")
+            .with_target(&target)
+            .with_detail(addr.ip().to_string());
+        record_audit(horizon_event_system, audit).await;
+    }
+    refused
+}
+
+/// Binds a connection to a player handed off by a neighboring region.
+/// 
+/// The player keeps its ID and authentication status, and is placed at the
+/// position it crossed the region edge at so its GORC subscriptions are in
+/// place before its first movement update.
+async fn arrive_player(
+    addr: SocketAddr,
+    connection_manager: &ConnectionManager,
+    horizon_event_system: &EventSystem,
+    arrival: PlayerArrival,
+) -> Result<ConnectedPlayer, ServerError> {
+    let player_id = arrival.session.player_id;
+    refuse_banned_identity(connection_manager, horizon_event_system, addr, BanTarget::Player(player_id)).await?;
+    if let Some(stale_connection_id) = connection_manager.get_connection_id_by_player(player_id).await {
+        let _ = connection_manager
+            .close_connection(stale_connection_id, Some("Player connected elsewhere".to_string()))
+            .await;
+    }
+
+    let geo = admit_geo(connection_manager, addr)?;
+    let connection_id = connection_manager.add_connection(addr).await;
+    if let Some(geo) = geo {
+        connection_manager.set_geo_tag(connection_id, geo).await;
+    }
+    connection_manager.set_player_id(connection_id, player_id).await;
+    connection_manager.set_auth_status(connection_id, arrival.session.auth_status).await;
+    if let Some(gorc_instances) = horizon_event_system.get_gorc_instances() {
+        gorc_instances.add_player(player_id, arrival.session.position).await;
+        gorc_instances.update_player_position(player_id, arrival.session.position).await;
     }
 
+    info!("🌐 Player {} arrived from region {} via {}", player_id, arrival.source, addr);
+    horizon_event_system
+        .emit_core(
+            "player_region_arrival",
+            &PlayerRegionArrivalEvent {
+                player_id,
+                connection_id: connection_id.to_string(),
+                remote_addr: addr.to_string(),
+                source_region: arrival.source,
+                timestamp: current_timestamp(),
+            },
+        )
+        .await
+        .map_err(|e| ServerError::Internal(e.to_string()))?;
+
+    Ok(ConnectedPlayer { connection_id, player_id, resumed: false, arrived: true })
+}
+
+/// Announces that a connection's player left and forgets the connection.
+/// 
+/// A player holding a session token is kept for the resume window instead,
+/// and only announced as disconnected if its client doesn't resume in time.
+/// 
+/// # Arguments
+/// 
+/// * `connection_id` - The connection that closed
+/// * `reason` - Why the connection closed
+/// * `connection_manager` - Manager for tracking connections
+/// * `horizon_event_system` - Event system for plugin communication
+pub(crate) async fn disconnect_player(
+    connection_id: ConnectionId,
+    reason: DisconnectReason,
+    connection_manager: &ConnectionManager,
+    horizon_event_system: &Arc<EventSystem>,
+) -> Result<(), ServerError> {
+    let Some(player_id) = connection_manager.get_player_id(connection_id).await else {
+        connection_manager.remove_connection(connection_id).await;
+        return Ok(());
+    };
+
+    let resumption = connection_manager.session_resumption().clone();
+    if let Some(park) = resumption.park(player_id) {
+        connection_manager.remove_connection(connection_id).await;
+        info!("🔑 Holding player {} for {:?} so its client can resume", player_id, resumption.window());
+
+        let horizon_event_system = horizon_event_system.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(resumption.window()).await;
+            if resumption.expire(player_id, park) {
+                if let Err(e) = emit_player_disconnected(&horizon_event_system, player_id, connection_id, reason).await {
+                    error!("Failed to announce expired session of player {}: {}", player_id, e);
+                }
+            }
+        });
+        return Ok(());
+    }
+
+    emit_player_disconnected(horizon_event_system, player_id, connection_id, reason).await?;
     connection_manager.remove_connection(connection_id).await;
-    connection_manager.remove_ws_sender(connection_id).await;
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Emits the `player_disconnected` core event
+async fn emit_player_disconnected(
+    horizon_event_system: &EventSystem,
+    player_id: PlayerId,
+    connection_id: ConnectionId,
+    reason: DisconnectReason,
+) -> Result<(), ServerError> {
+    horizon_event_system
+        .emit_core(
+            "player_disconnected",
+            &PlayerDisconnectedEvent {
+                player_id,
+                connection_id: connection_id.to_string(),
+                reason,
+                timestamp: current_timestamp(),
+            },
+        )
+        .await
+        .map_err(|e| ServerError::Internal(e.to_string()))
+}
@@ -0,0 +1,509 @@
+//! TCP listeners the WebSocket server accepts clients on.
+//!
+//! The server listens on `bind_address` and on every additional address in
+//! `ServerConfig::listeners`, so one process can serve a LAN interface, a
+//! public IPv4 address and an IPv6 endpoint at once.
+//!
+//! Each address is served by `accept_threads` accept loops, one per CPU core
+//! by default when it uses SO_REUSEPORT. With SO_REUSEPORT every loop gets
+//! its own socket and the kernel spreads connections across them; without
+//! it, or where the platform doesn't support it (Windows), the loops share a
+//! single listener. Every loop counts the connections it accepted, see
+//! [`AcceptLoops`].
+//!
+//! The loops share one [`AcceptThrottle`], which turns connections away as
+//! soon as they are accepted once too many arrive per second or too many are
+//! still handshaking.
+//!
+//! IPv6 addresses are bound with IPV6_V6ONLY set explicitly, so whether a
+//! `[::]` listener also takes IPv4 clients depends on its configuration
+//! rather than on the host's defaults.
+
+use crate::config::{ConnectionThrottleConfig, ListenerConfig};
+use crate::error::ServerError;
+use crate::security::rate_limiter::RateLimiter;
+use horizon_event_system::current_timestamp;
+use horizon_sockets::SocketBuilder;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpSocket};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+use tracing::{info, trace, warn};
+
+/// Connections waiting to be accepted before the kernel refuses more
+const BACKLOG: u32 = 65535;
+
+/// Point-in-time counters of one accept loop
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AcceptLoopStats {
+    /// Address the loop accepts clients on
+    pub address: SocketAddr,
+    /// Index of the loop among the loops of its address
+    pub accept_loop: usize,
+    /// Whether the loop shares its listener with the other loops of its address
+    pub shared_listener: bool,
+    /// Connections accepted
+    pub accepted: u64,
+    /// Accepts that failed
+    pub errors: u64,
+    /// Accepted connections the throttle closed right away
+    pub throttled: u64,
+    /// Unix timestamp in milliseconds of the last accepted connection (0 if none yet)
+    pub last_accept_ms: u64,
+}
+
+/// Counters an accept loop updates as it accepts connections
+#[derive(Debug)]
+pub struct AcceptLoopCounters {
+    address: SocketAddr,
+    accept_loop: usize,
+    shared_listener: bool,
+    accepted: AtomicU64,
+    errors: AtomicU64,
+    throttled: AtomicU64,
+    last_accept_ms: AtomicU64,
+}
+
+impl AcceptLoopCounters {
+    /// Counts an accepted connection
+    pub fn record_accepted(&self) {
+        self.accepted.fetch_add(1, Ordering::Relaxed);
+        self.last_accept_ms.store(current_timestamp() * 1000, Ordering::Relaxed);
+    }
+
+    /// Counts a failed accept
+    pub fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Counts a connection the throttle turned away
+    pub fn record_throttled(&self) {
+        self.throttled.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Takes a snapshot of the counters
+    pub fn stats(&self) -> AcceptLoopStats {
+        AcceptLoopStats {
+            address: self.address,
+            accept_loop: self.accept_loop,
+            shared_listener: self.shared_listener,
+            accepted: self.accepted.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            throttled: self.throttled.load(Ordering::Relaxed),
+            last_accept_ms: self.last_accept_ms.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// The accept loops of every listener, for reporting per-loop metrics
+#[derive(Debug, Default)]
+pub struct AcceptLoops {
+    loops: RwLock<Vec<Arc<AcceptLoopCounters>>>,
+}
+
+impl AcceptLoops {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an accept loop
+    ///
+    /// # Returns
+    ///
+    /// The counters the loop updates.
+    pub fn register(&self, address: SocketAddr, shared_listener: bool) -> Arc<AcceptLoopCounters> {
+        let mut loops = self.loops.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let counters = Arc::new(AcceptLoopCounters {
+            address,
+            accept_loop: loops.iter().filter(|counters| counters.address == address).count(),
+            shared_listener,
+            accepted: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            throttled: AtomicU64::new(0),
+            last_accept_ms: AtomicU64::new(0),
+        });
+        loops.push(counters.clone());
+        counters
+    }
+
+    /// Gets the counters of every accept loop
+    pub fn stats(&self) -> Vec<AcceptLoopStats> {
+        self.loops
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|counters| counters.stats())
+            .collect()
+    }
+}
+
+/// Why the throttle turned a connection away
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleRefusal {
+    /// More new connections arrived this second than allowed
+    RateExceeded,
+    /// Too many connections are still handshaking
+    TooManyPending,
+}
+
+/// Global limits on new connections, shared by every accept loop
+#[derive(Debug)]
+pub struct AcceptThrottle {
+    rate_limiter: Option<RateLimiter<()>>,
+    pending: Option<Arc<Semaphore>>,
+    max_pending_handshakes: usize,
+    handshake_timeout: Option<Duration>,
+}
+
+impl AcceptThrottle {
+    /// Creates a throttle enforcing the configured limits
+    pub fn new(config: &ConnectionThrottleConfig) -> Self {
+        Self {
+            rate_limiter: (config.max_new_connections_per_sec > 0)
+                .then(|| RateLimiter::per_second(config.max_new_connections_per_sec)),
+            pending: (config.max_pending_handshakes > 0).then(|| Arc::new(Semaphore::new(config.max_pending_handshakes))),
+            max_pending_handshakes: config.max_pending_handshakes,
+            handshake_timeout: (config.handshake_timeout_ms > 0).then(|| Duration::from_millis(config.handshake_timeout_ms)),
+        }
+    }
+
+    /// Admits a connection that was just accepted.
+    ///
+    /// # Returns
+    ///
+    /// The permit the connection holds until it has a player, or why it
+    /// must be closed.
+    pub async fn admit(&self) -> Result<HandshakePermit, ThrottleRefusal> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            if !rate_limiter.check_rate_limit(()).await {
+                return Err(ThrottleRefusal::RateExceeded);
+            }
+        }
+        let permit = match &self.pending {
+            Some(pending) => Some(pending.clone().try_acquire_owned().map_err(|_| ThrottleRefusal::TooManyPending)?),
+            None => None,
+        };
+        Ok(HandshakePermit {
+            _permit: permit,
+            deadline: self.handshake_timeout.map(|timeout| Instant::now() + timeout),
+        })
+    }
+
+    /// Gets the number of connections currently handshaking (always 0 while they aren't capped)
+    pub fn pending_handshakes(&self) -> usize {
+        self.pending
+            .as_ref()
+            .map_or(0, |pending| self.max_pending_handshakes.saturating_sub(pending.available_permits()))
+    }
+}
+
+/// A pending connection's slot, released once it is dropped
+#[derive(Debug, Default)]
+pub struct HandshakePermit {
+    _permit: Option<OwnedSemaphorePermit>,
+    deadline: Option<Instant>,
+}
+
+impl HandshakePermit {
+    /// Creates a permit without a slot or deadline, for connections that
+    /// didn't come through a throttled accept loop
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Runs a handshake step, giving up once the handshake deadline passes
+    pub async fn limit<F: std::future::Future>(&self, step: F) -> Result<F::Output, ServerError> {
+        match self.deadline {
+            Some(deadline) => tokio::time::timeout_at(deadline, step)
+                .await
+                .map_err(|_| ServerError::Network("Handshake timed out".to_string())),
+            None => Ok(step.await),
+        }
+    }
+}
+
+/// Works out how many accept loops serve an address
+///
+/// # Arguments
+///
+/// * `accept_threads` - Configured number of loops (0 picks a default)
+/// * `use_reuse_port` - Whether the address uses SO_REUSEPORT
+///
+/// # Returns
+///
+/// `accept_threads` if set, else one loop per CPU core with SO_REUSEPORT and one without.
+pub fn accept_loop_count(accept_threads: usize, use_reuse_port: bool) -> usize {
+    match accept_threads {
+        0 if use_reuse_port => num_cpus::get(),
+        0 => 1,
+        threads => threads,
+    }
+}
+
+/// Binds the listeners for one address
+///
+/// # Arguments
+///
+/// * `config` - Address to listen on and how
+/// * `accept_threads` - Accept loops to serve the address with (0 picks a default)
+///
+/// # Returns
+///
+/// The listener of each accept loop, the same listener repeated when the
+/// loops share one, or a `ServerError` if the address can't be bound.
+pub fn bind_listeners(config: &ListenerConfig, accept_threads: usize) -> Result<Vec<Arc<TcpListener>>, ServerError> {
+    let num_acceptors = accept_loop_count(accept_threads, config.use_reuse_port);
+    info!("🧠 Detected {} CPU cores, using {} acceptor(s) on {}", num_cpus::get(), num_acceptors, config.address);
+
+    // Only SO_REUSEPORT gives each loop a socket of its own
+    let sockets = if config.use_reuse_port { num_acceptors } else { 1 };
+    let sockets = if config.address.is_ipv6() {
+        bind_ipv6_listeners(config, sockets)?
+    } else {
+        bind_socket_builder_listeners(config.address, config.use_reuse_port, sockets)?
+    };
+    if sockets.len() < num_acceptors {
+        info!("👂 Listening on {} with {} accept loop(s) sharing {} listener(s)", config.address, num_acceptors, sockets.len());
+    } else {
+        info!("👂 Listening on {} with {} acceptor(s)", config.address, num_acceptors);
+    }
+
+    let sockets: Vec<Arc<TcpListener>> = sockets.into_iter().map(Arc::new).collect();
+    Ok(sockets.iter().cycle().take(num_acceptors).cloned().collect())
+}
+
+/// Binds listeners through `SocketBuilder`, falling back to a single
+/// listener if SO_REUSEPORT listeners can't be created
+fn bind_socket_builder_listeners(address: SocketAddr, use_reuse_port: bool, num_acceptors: usize) -> Result<Vec<TcpListener>, ServerError> {
+    // Try to create multiple listeners, but if any fail, fall back to one listener
+    let mut listeners = Vec::new();
+    let mut multi_listener_error = None;
+    for i in 0..num_acceptors {
+        let mut builder = match SocketBuilder::new().bind(address.to_string()) {
+            Ok(b) => b,
+            Err(e) => {
+                multi_listener_error = Some(format!("SocketBuilder bind failed: {e}"));
+                break;
+            }
+        };
+        if use_reuse_port {
+            match builder.reuse_port(true) {
+                Ok(b) => { builder = b; },
+                Err(e) => {
+                    multi_listener_error = Some(format!("SO_REUSEPORT failed: {e}"));
+                    break;
+                }
+            }
+        }
+        builder = match builder.backlog(BACKLOG as i32) {
+            Ok(b) => b,
+            Err(e) => {
+                multi_listener_error = Some(format!("SocketBuilder backlog failed: {e}"));
+                break;
+            }
+        };
+        let listener = match builder.tcp_listener() {
+            Ok(l) => l,
+            Err(e) => {
+                multi_listener_error = Some(format!("TcpListener creation failed: {e}"));
+                break;
+            }
+        };
+        let std_listener = match listener.as_std().try_clone() {
+            Ok(sl) => sl,
+            Err(e) => {
+                multi_listener_error = Some(format!("Failed to clone std TcpListener: {e}"));
+                break;
+            }
+        };
+        std_listener.set_nonblocking(true).ok();
+        let tokio_listener = match TcpListener::from_std(std_listener) {
+            Ok(tl) => tl,
+            Err(e) => {
+                multi_listener_error = Some(format!("Tokio listener creation failed: {e}"));
+                break;
+            }
+        };
+        listeners.push(tokio_listener);
+        trace!("✅ Listener {} bound on {}", i, address);
+    }
+
+    // If any error occurred, fall back to single listener
+    if let Some(error) = multi_listener_error {
+        warn!("Multi-listener creation failed: {}. Falling back to single listener with many acceptors.", error);
+        listeners.clear();
+        let mut builder = SocketBuilder::new()
+            .bind(address.to_string())
+            .map_err(|e| ServerError::Network(format!("SocketBuilder bind failed: {e}")))?;
+        builder = builder.backlog(BACKLOG as i32)
+            .map_err(|e| ServerError::Network(format!("SocketBuilder backlog failed: {e}")))?;
+        let listener = builder.tcp_listener()
+            .map_err(|e| ServerError::Network(format!("TcpListener creation failed: {e}")))?;
+        let std_listener = listener.as_std().try_clone()
+            .map_err(|e| ServerError::Network(format!("Failed to clone std TcpListener: {e}")))?;
+        std_listener.set_nonblocking(true).ok();
+        let tokio_listener = TcpListener::from_std(std_listener)
+            .map_err(|e| ServerError::Network(format!("Tokio listener creation failed: {e}")))?;
+        listeners.push(tokio_listener);
+        info!("Fallback: Single listener bound on {}", address);
+    }
+
+    Ok(listeners)
+}
+
+/// Binds IPv6 listeners with IPV6_V6ONLY set as configured.
+///
+/// Listeners after the first bind to the first one's address, so an
+/// ephemeral port (`:0`) is shared by all of them.
+fn bind_ipv6_listeners(config: &ListenerConfig, num_acceptors: usize) -> Result<Vec<TcpListener>, ServerError> {
+    let mut listeners: Vec<TcpListener> = Vec::with_capacity(num_acceptors);
+    let mut address = config.address;
+    let reuse_port = config.use_reuse_port && cfg!(unix);
+    let num_acceptors = if reuse_port { num_acceptors } else { 1 };
+    for i in 0..num_acceptors {
+        let socket = TcpSocket::new_v6().map_err(|e| ServerError::Network(format!("IPv6 socket creation failed: {e}")))?;
+        #[cfg(unix)]
+        {
+            socket.set_reuseaddr(true).map_err(|e| ServerError::Network(format!("SO_REUSEADDR failed: {e}")))?;
+            if reuse_port {
+                socket.set_reuseport(true).map_err(|e| ServerError::Network(format!("SO_REUSEPORT failed: {e}")))?;
+            }
+            set_ipv6_only(&socket, config.ipv6_only).map_err(|e| ServerError::Network(format!("IPV6_V6ONLY failed: {e}")))?;
+        }
+        socket.bind(address).map_err(|e| ServerError::Network(format!("Failed to bind {address}: {e}")))?;
+        let listener = socket.listen(BACKLOG).map_err(|e| ServerError::Network(format!("Failed to listen on {address}: {e}")))?;
+        if i == 0 {
+            address = listener.local_addr().map_err(|e| ServerError::Network(e.to_string()))?;
+        }
+        listeners.push(listener);
+        trace!("✅ Listener {} bound on {} (IPv6 only: {})", i, address, config.ipv6_only);
+    }
+    Ok(listeners)
+}
+
+/// Sets whether an IPv6 socket refuses IPv4-mapped connections
+#[cfg(unix)]
+fn set_ipv6_only(socket: &TcpSocket, ipv6_only: bool) -> std::io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let value = libc::c_int::from(ipv6_only);
+    // SAFETY: the descriptor belongs to `socket` and stays open for the call,
+    // and `value` is a live c_int of the length passed.
+    let result = unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::IPPROTO_IPV6,
+            libc::IPV6_V6ONLY,
+            &value as *const libc::c_int as *const libc::c_void,
+            std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+        )
+    };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpStream;
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_ipv6_listeners_follow_their_stack_setting() {
+        let dual_stack = ListenerConfig::new("[::]:0".parse().unwrap());
+        let listeners = bind_listeners(&dual_stack, 0).unwrap();
+        let port = listeners[0].local_addr().unwrap().port();
+        assert!(TcpStream::connect(("::1", port)).await.is_ok());
+        assert!(TcpStream::connect(("127.0.0.1", port)).await.is_ok());
+
+        let ipv6_only = ListenerConfig {
+            ipv6_only: true,
+            ..ListenerConfig::new("[::]:0".parse().unwrap())
+        };
+        let listeners = bind_listeners(&ipv6_only, 0).unwrap();
+        let port = listeners[0].local_addr().unwrap().port();
+        assert!(TcpStream::connect(("::1", port)).await.is_ok());
+        assert!(TcpStream::connect(("127.0.0.1", port)).await.is_err());
+
+        // An IPv6-only listener leaves the port free for a separate IPv4 listener
+        let ipv4 = std::net::TcpListener::bind(("127.0.0.1", port));
+        assert!(ipv4.is_ok());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_reuse_port_shares_one_address() {
+        let config = ListenerConfig {
+            use_reuse_port: true,
+            ipv6_only: true,
+            ..ListenerConfig::new("[::1]:0".parse().unwrap())
+        };
+        let listeners = bind_listeners(&config, 3).unwrap();
+        assert_eq!(listeners.len(), 3);
+        let address = listeners[0].local_addr().unwrap();
+        assert!(listeners.iter().all(|listener| listener.local_addr().unwrap() == address));
+        assert!(!Arc::ptr_eq(&listeners[0], &listeners[1]));
+    }
+
+    #[tokio::test]
+    async fn test_accept_loops_share_a_listener_without_reuse_port() {
+        assert_eq!(accept_loop_count(0, false), 1);
+        assert_eq!(accept_loop_count(0, true), num_cpus::get());
+        assert_eq!(accept_loop_count(6, false), 6);
+
+        let config = ListenerConfig::new("[::1]:0".parse().unwrap());
+        let listeners = bind_listeners(&config, 4).unwrap();
+        assert_eq!(listeners.len(), 4);
+        assert!(listeners.iter().all(|listener| Arc::ptr_eq(listener, &listeners[0])));
+
+        let loops = AcceptLoops::new();
+        let address = listeners[0].local_addr().unwrap();
+        let counters: Vec<_> = (0..2).map(|_| loops.register(address, true)).collect();
+        let (client, accepted) = tokio::join!(TcpStream::connect(address), listeners[1].accept());
+        assert!(client.is_ok() && accepted.is_ok());
+        counters[1].record_accepted();
+        counters[0].record_error();
+
+        let stats = loops.stats();
+        assert_eq!((stats[0].accept_loop, stats[0].accepted, stats[0].errors), (0, 0, 1));
+        assert_eq!((stats[1].accept_loop, stats[1].accepted, stats[1].errors), (1, 1, 0));
+        assert!(stats[1].shared_listener && stats[1].last_accept_ms > 0);
+    }
+
+    #[tokio::test]
+    async fn test_throttle_caps_connection_rate_and_pending_handshakes() {
+        let rate_only = AcceptThrottle::new(&ConnectionThrottleConfig {
+            max_new_connections_per_sec: 2,
+            ..Default::default()
+        });
+        assert!(rate_only.admit().await.is_ok());
+        assert!(rate_only.admit().await.is_ok());
+        assert_eq!(rate_only.admit().await.unwrap_err(), ThrottleRefusal::RateExceeded);
+
+        let throttle = AcceptThrottle::new(&ConnectionThrottleConfig {
+            max_pending_handshakes: 2,
+            handshake_timeout_ms: 20,
+            ..Default::default()
+        });
+        let first = throttle.admit().await.unwrap();
+        let _second = throttle.admit().await.unwrap();
+        assert_eq!(throttle.pending_handshakes(), 2);
+        assert_eq!(throttle.admit().await.unwrap_err(), ThrottleRefusal::TooManyPending);
+
+        // A stalled handshake gives up at the deadline, and its slot frees up once dropped
+        let stalled = first.limit(std::future::pending::<()>()).await;
+        assert!(matches!(stalled, Err(ServerError::Network(_))));
+        drop(first);
+        assert_eq!(throttle.pending_handshakes(), 1);
+        assert!(throttle.admit().await.is_ok());
+        assert_eq!(HandshakePermit::unlimited().limit(async { 7 }).await.unwrap(), 7);
+    }
+}
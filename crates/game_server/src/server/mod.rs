@@ -5,5 +5,9 @@
 
 pub mod core;
 pub mod handlers;
+pub mod listeners;
+pub mod proxy_protocol;
+pub mod quic;
+pub mod tls;
 
 pub use core::GameServer;
\ No newline at end of file
@@ -0,0 +1,307 @@
+//! PROXY protocol headers sent by load balancers in front of the server.
+//!
+//! Behind HAProxy or a network load balancer, every TCP connection comes
+//! from the proxy, so rate limits, bans and IP filters would all apply to
+//! the proxy's address. With the PROXY protocol the proxy prefixes each
+//! connection with a header naming the client it accepted the connection
+//! from. Both the binary version 2 header and the text version 1 header are
+//! understood.
+//!
+//! Headers are only read from connections of trusted proxies, so clients
+//! connecting directly can't claim someone else's address.
+
+use crate::config::ProxyProtocolConfig;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Signature every version 2 header starts with
+const V2_SIGNATURE: [u8; 12] = *b"\r\n\r\n\0\r\nQUIT\n";
+
+/// Longest version 1 header, including its CRLF
+const V1_MAX_LENGTH: usize = 107;
+
+/// Errors raised while reading a PROXY protocol header
+#[derive(Debug, Error)]
+pub enum ProxyProtocolError {
+    /// The connection didn't start with a PROXY protocol header
+    #[error("Connection did not start with a PROXY protocol header")]
+    MissingHeader,
+    /// The header is malformed
+    #[error("Malformed PROXY protocol header: {0}")]
+    Malformed(String),
+    /// The proxy didn't send the header in time
+    #[error("Timed out waiting for the PROXY protocol header")]
+    Timeout,
+    /// Reading the header failed
+    #[error("PROXY protocol I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl ProxyProtocolConfig {
+    /// Checks whether connections from a peer start with a PROXY protocol header
+    pub fn expects_header_from(&self, peer: IpAddr) -> bool {
+        self.enabled && self.trusted_proxies.iter().any(|network| network.contains(peer))
+    }
+}
+
+/// Gets the address of the client a connection is from.
+///
+/// Connections from trusted proxies have their PROXY protocol header read
+/// off the stream; every other connection is from its peer.
+///
+/// # Arguments
+///
+/// * `stream` - The accepted connection, positioned at its first byte
+/// * `peer` - Address the connection was accepted from
+/// * `config` - Whether PROXY protocol is enabled and which proxies are trusted
+///
+/// # Returns
+///
+/// The client's address, or a `ProxyProtocolError` if a trusted proxy sent
+/// no valid header in time.
+pub async fn client_address<S>(stream: &mut S, peer: SocketAddr, config: &ProxyProtocolConfig) -> Result<SocketAddr, ProxyProtocolError>
+where
+    S: AsyncRead + Unpin,
+{
+    if !config.expects_header_from(peer.ip()) {
+        return Ok(peer);
+    }
+
+    let timeout = std::time::Duration::from_millis(config.header_timeout_ms);
+    let source = tokio::time::timeout(timeout, read_header(stream))
+        .await
+        .map_err(|_| ProxyProtocolError::Timeout)??;
+    // Health checks of the proxy itself carry no client address
+    Ok(source.unwrap_or(peer))
+}
+
+/// Reads a PROXY protocol header, leaving the stream at the first byte after it.
+///
+/// # Returns
+///
+/// The source address the header carries, or `None` for connections the proxy
+/// made on its own behalf (`LOCAL`, `UNKNOWN` or an unsupported address family).
+pub async fn read_header<S>(stream: &mut S) -> Result<Option<SocketAddr>, ProxyProtocolError>
+where
+    S: AsyncRead + Unpin,
+{
+    // Both versions can be told apart by their first six bytes
+    let mut prefix = [0u8; 6];
+    stream.read_exact(&mut prefix).await?;
+    if prefix == V2_SIGNATURE[..6] {
+        read_v2(stream, prefix).await
+    } else if &prefix == b"PROXY " {
+        read_v1(stream).await
+    } else {
+        Err(ProxyProtocolError::MissingHeader)
+    }
+}
+
+/// Reads the rest of a version 2 header after its first six bytes
+async fn read_v2<S>(stream: &mut S, prefix: [u8; 6]) -> Result<Option<SocketAddr>, ProxyProtocolError>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut header = [0u8; 16];
+    header[..6].copy_from_slice(&prefix);
+    stream.read_exact(&mut header[6..]).await?;
+    if header[..12] != V2_SIGNATURE {
+        return Err(ProxyProtocolError::MissingHeader);
+    }
+
+    let version_command = header[12];
+    if version_command >> 4 != 2 {
+        return Err(ProxyProtocolError::Malformed(format!("unsupported version {}", version_command >> 4)));
+    }
+    let family_protocol = header[13];
+    let length = u16::from_be_bytes([header[14], header[15]]) as usize;
+    let mut addresses = vec![0u8; length];
+    stream.read_exact(&mut addresses).await?;
+
+    match version_command & 0x0f {
+        // LOCAL: the proxy's own connection, e.g. a health check
+        0x0 => return Ok(None),
+        // PROXY: relayed on behalf of a client
+        0x1 => {}
+        command => return Err(ProxyProtocolError::Malformed(format!("unknown command {command}"))),
+    }
+
+    let truncated = || ProxyProtocolError::Malformed("address block is too short".to_string());
+    match family_protocol >> 4 {
+        // AF_INET: source address, destination address, source port, destination port
+        0x1 => {
+            let block: &[u8; 12] = addresses.get(..12).and_then(|block| block.try_into().ok()).ok_or_else(truncated)?;
+            let ip = Ipv4Addr::new(block[0], block[1], block[2], block[3]);
+            Ok(Some(SocketAddr::new(IpAddr::V4(ip), u16::from_be_bytes([block[8], block[9]]))))
+        }
+        // AF_INET6
+        0x2 => {
+            let block: &[u8; 36] = addresses.get(..36).and_then(|block| block.try_into().ok()).ok_or_else(truncated)?;
+            let ip: [u8; 16] = block[..16].try_into().expect("slice of 16 bytes");
+            Ok(Some(SocketAddr::new(IpAddr::V6(Ipv6Addr::from(ip)), u16::from_be_bytes([block[32], block[33]]))))
+        }
+        // AF_UNSPEC and AF_UNIX carry no IP address
+        _ => Ok(None),
+    }
+}
+
+/// Reads the rest of a version 1 header after its `PROXY ` prefix
+async fn read_v1<S>(stream: &mut S) -> Result<Option<SocketAddr>, ProxyProtocolError>
+where
+    S: AsyncRead + Unpin,
+{
+    // The header has no length field, so it is read up to its CRLF and no further
+    let mut line = Vec::with_capacity(V1_MAX_LENGTH);
+    while !line.ends_with(b"\r\n") {
+        if line.len() + 6 >= V1_MAX_LENGTH {
+            return Err(ProxyProtocolError::Malformed("header is longer than 107 bytes".to_string()));
+        }
+        line.push(stream.read_u8().await?);
+    }
+    let line = std::str::from_utf8(&line[..line.len() - 2])
+        .map_err(|_| ProxyProtocolError::Malformed("header is not ASCII".to_string()))?;
+
+    let fields: Vec<&str> = line.split(' ').collect();
+    match fields.as_slice() {
+        ["UNKNOWN", ..] => Ok(None),
+        [protocol @ ("TCP4" | "TCP6"), source, _destination, source_port, _destination_port] => {
+            let ip: IpAddr = source
+                .parse()
+                .map_err(|_| ProxyProtocolError::Malformed(format!("invalid source address '{source}'")))?;
+            if ip.is_ipv4() != (*protocol == "TCP4") {
+                return Err(ProxyProtocolError::Malformed(format!("{protocol} header with address {ip}")));
+            }
+            let port = source_port
+                .parse()
+                .map_err(|_| ProxyProtocolError::Malformed(format!("invalid source port '{source_port}'")))?;
+            Ok(Some(SocketAddr::new(ip, port)))
+        }
+        _ => Err(ProxyProtocolError::Malformed(format!("unexpected header 'PROXY {line}'"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a version 2 header with the given command, family and address block
+    fn v2_header(command: u8, family: u8, addresses: &[u8]) -> Vec<u8> {
+        let mut header = V2_SIGNATURE.to_vec();
+        header.push(0x20 | command);
+        header.push(family << 4 | 0x1);
+        header.extend_from_slice(&(addresses.len() as u16).to_be_bytes());
+        header.extend_from_slice(addresses);
+        header
+    }
+
+    async fn parse(bytes: &[u8]) -> (Result<Option<SocketAddr>, ProxyProtocolError>, Vec<u8>) {
+        let mut stream = bytes;
+        let result = read_header(&mut stream).await;
+        (result, stream.to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_headers_carry_client_addresses() {
+        let mut ipv4 = vec![203, 0, 113, 7, 10, 0, 0, 1];
+        ipv4.extend_from_slice(&51234u16.to_be_bytes());
+        ipv4.extend_from_slice(&8080u16.to_be_bytes());
+        let mut bytes = v2_header(0x1, 0x1, &ipv4);
+        bytes.extend_from_slice(b"GET / HTTP/1.1");
+        let (source, rest) = parse(&bytes).await;
+        assert_eq!(source.unwrap(), Some("203.0.113.7:51234".parse().unwrap()));
+        // The stream is left at the client's first byte
+        assert_eq!(rest, b"GET / HTTP/1.1");
+
+        let mut ipv6 = "2001:db8::7".parse::<Ipv6Addr>().unwrap().octets().to_vec();
+        ipv6.extend_from_slice(&Ipv6Addr::LOCALHOST.octets());
+        ipv6.extend_from_slice(&[0xc8, 0x22, 0x1f, 0x90]);
+        let (source, _) = parse(&v2_header(0x1, 0x2, &ipv6)).await;
+        assert_eq!(source.unwrap(), Some("[2001:db8::7]:51234".parse().unwrap()));
+
+        let (source, rest) = parse(b"PROXY TCP4 198.51.100.9 10.0.0.1 40000 8080\r\nGET").await;
+        assert_eq!(source.unwrap(), Some("198.51.100.9:40000".parse().unwrap()));
+        assert_eq!(rest, b"GET");
+
+        // Health checks of the proxy itself
+        assert_eq!(parse(&v2_header(0x0, 0x0, &[])).await.0.unwrap(), None);
+        assert_eq!(parse(b"PROXY UNKNOWN\r\n").await.0.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_headers_are_refused() {
+        assert!(matches!(parse(b"GET / HTTP/1.1\r\n").await.0, Err(ProxyProtocolError::MissingHeader)));
+        assert!(matches!(parse(&v2_header(0x1, 0x1, &[203, 0, 113])).await.0, Err(ProxyProtocolError::Malformed(_))));
+        assert!(matches!(parse(&v2_header(0x7, 0x1, &[0; 12])).await.0, Err(ProxyProtocolError::Malformed(_))));
+        assert!(matches!(
+            parse(b"PROXY TCP4 2001:db8::1 10.0.0.1 1 2\r\n").await.0,
+            Err(ProxyProtocolError::Malformed(_))
+        ));
+        assert!(matches!(parse(&[b'x'; 200]).await.0, Err(ProxyProtocolError::MissingHeader)));
+        let mut endless = b"PROXY ".to_vec();
+        endless.extend_from_slice(&[b'1'; 200]);
+        assert!(matches!(parse(&endless).await.0, Err(ProxyProtocolError::Malformed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_only_trusted_proxies_are_believed() {
+        let config = ProxyProtocolConfig {
+            enabled: true,
+            trusted_proxies: vec!["10.0.0.0/8".parse().unwrap()],
+            ..ProxyProtocolConfig::default()
+        };
+        let header = b"PROXY TCP4 198.51.100.9 10.0.0.1 40000 8080\r\n";
+
+        let proxy = "10.1.2.3:6000".parse().unwrap();
+        let client = client_address(&mut &header[..], proxy, &config).await.unwrap();
+        assert_eq!(client, "198.51.100.9:40000".parse().unwrap());
+
+        // A client connecting directly keeps its own address
+        let direct = "192.0.2.4:6000".parse().unwrap();
+        assert_eq!(client_address(&mut &header[..], direct, &config).await.unwrap(), direct);
+        assert_eq!(client_address(&mut &header[..], proxy, &ProxyProtocolConfig::default()).await.unwrap(), proxy);
+
+        // Without trusted proxies nobody is believed
+        let untrusted = ProxyProtocolConfig { enabled: true, ..ProxyProtocolConfig::default() };
+        assert_eq!(client_address(&mut &header[..], proxy, &untrusted).await.unwrap(), proxy);
+    }
+
+    #[tokio::test]
+    async fn test_proxied_clients_connect_with_their_own_address() {
+        use crate::config::MessageLaneConfig;
+        use crate::connection::ConnectionManager;
+        use crate::messaging::MessageLanes;
+        use crate::server::handlers::handle_connection;
+        use horizon_event_system::EventSystem;
+        use std::sync::Arc;
+        use tokio::io::AsyncWriteExt;
+
+        let config = ProxyProtocolConfig {
+            enabled: true,
+            trusted_proxies: vec!["127.0.0.0/8".parse().unwrap()],
+            ..ProxyProtocolConfig::default()
+        };
+        let connection_manager = Arc::new(ConnectionManager::new().with_proxy_protocol(config));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server_connections = connection_manager.clone();
+        tokio::spawn(async move {
+            let (stream, addr) = listener.accept().await.unwrap();
+            let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+            let _ = handle_connection(stream, addr, server_connections, Arc::new(EventSystem::new()), message_lanes, None).await;
+        });
+
+        let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+        stream.write_all(b"PROXY TCP4 198.51.100.9 10.0.0.1 40000 8080\r\n").await.unwrap();
+        let (_client, _) = tokio_tungstenite::client_async(format!("ws://{address}/"), stream).await.unwrap();
+
+        for _ in 0..50 {
+            if let Some((_, _, remote_addr, _, _)) = connection_manager.connected_players().await.pop() {
+                assert_eq!(remote_addr, "198.51.100.9:40000".parse().unwrap());
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("the proxied client never got a player");
+    }
+}
@@ -0,0 +1,534 @@
+//! QUIC listener alongside the WebSocket server.
+//!
+//! Clients connect with the [`ALPN`] protocol and open one bidirectional
+//! stream. Both directions of that stream carry the same messages as a
+//! WebSocket connection, each prefixed with its length as a big-endian
+//! `u32`: `{namespace, event, data}` JSON from the client, and responses and
+//! GORC messages from the server.
+//!
+//! When clients must authenticate, the first stream message carries the
+//! same `auth`, `login` or `guest` request a WebSocket client sends first,
+//! and the client gets a player only once it checks out. Refused clients
+//! have their connection closed with the `auth_failed` message as the reason.
+//!
+//! QUIC datagrams add an unreliable path in both directions. Clients may send
+//! `{namespace, event, data}` messages as datagrams when a late message is
+//! worse than a lost one, such as movement input. The server sends channel 0
+//! replication updates as datagrams in the [UDP transport](crate::udp)'s
+//! update format, so clients apply them with
+//! [`LatestWins`](crate::udp::LatestWins). Updates that don't fit a datagram
+//! go over the stream.
+//!
+//! The listener speaks raw QUIC. Browsers only reach QUIC through
+//! WebTransport sessions negotiated over HTTP/3, which the listener doesn't
+//! accept yet, so browser clients keep using the WebSocket endpoint.
+
+use crate::config::QuicConfig;
+use crate::connection::{ConnectionId, ConnectionManager};
+use crate::error::ServerError;
+use crate::messaging::encoding::RAW_FRAME_MARKER;
+use crate::messaging::{ClientEncoding, ClientFrame, MessageLanes};
+use crate::server::handlers::{
+    check_handshake, connect_player, disconnect_player, requires_credentials, start_session, upgrade_guest, upgrade_request,
+    ClientReplies, Handshake,
+};
+use crate::udp::{encode_update, UPDATE_HEADER_LEN};
+use bytes::Bytes;
+use horizon_event_system::{AuthenticationStatus, DisconnectReason, EventSystem, GorcObjectId, PlayerId, ShutdownState};
+use quinn::crypto::rustls::QuicServerConfig;
+use quinn::rustls;
+use quinn::rustls::pki_types::pem::PemObject;
+use quinn::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, error, info, warn};
+
+/// ALPN protocol clients negotiate
+pub const ALPN: &[u8] = b"horizon";
+
+/// Largest message accepted on the stream
+pub const MAX_FRAME_LEN: usize = 1024 * 1024;
+
+/// An open QUIC connection
+#[derive(Debug)]
+struct QuicSession {
+    connection: quinn::Connection,
+    /// Sequence number of the last update datagram sent
+    sequence: u64,
+}
+
+/// Open QUIC connections by player, used to send datagrams to them
+#[derive(Debug, Default)]
+pub struct QuicSessions {
+    sessions: Mutex<HashMap<PlayerId, QuicSession>>,
+}
+
+impl QuicSessions {
+    /// Creates an empty session registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn insert(&self, player_id: PlayerId, connection: quinn::Connection) {
+        self.lock_sessions().insert(player_id, QuicSession { connection, sequence: 0 });
+    }
+
+    fn remove(&self, player_id: PlayerId) -> Option<quinn::Connection> {
+        self.lock_sessions().remove(&player_id).map(|session| session.connection)
+    }
+
+    /// Checks whether a player is connected over QUIC
+    pub fn contains(&self, player_id: PlayerId) -> bool {
+        self.lock_sessions().contains_key(&player_id)
+    }
+
+    /// Sends a replication update to a player as a datagram.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the update was sent. `false` means the caller should send
+    /// it reliably instead: the player isn't connected over QUIC, the peer
+    /// doesn't accept datagrams, or the update doesn't fit one.
+    pub fn send_update(&self, player_id: PlayerId, object_id: GorcObjectId, payload: &[u8]) -> bool {
+        let mut sessions = self.lock_sessions();
+        let Some(session) = sessions.get_mut(&player_id) else {
+            return false;
+        };
+        let Some(max_len) = session.connection.max_datagram_size() else {
+            return false;
+        };
+        if UPDATE_HEADER_LEN + payload.len() > max_len {
+            return false;
+        }
+
+        session.sequence += 1;
+        let datagram = encode_update(session.sequence, object_id, payload);
+        session.connection.send_datagram(Bytes::from(datagram)).is_ok()
+    }
+
+    /// Closes a player's QUIC connection
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player was connected over QUIC.
+    pub fn close(&self, player_id: PlayerId, reason: &str) -> bool {
+        match self.remove(player_id) {
+            Some(connection) => {
+                connection.close(0u32.into(), reason.as_bytes());
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn lock_sessions(&self) -> std::sync::MutexGuard<'_, HashMap<PlayerId, QuicSession>> {
+        self.sessions.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Builds the QUIC server configuration from the configured PEM files
+///
+/// # Returns
+///
+/// The configuration, or a `ServerError` if the certificate or key is
+/// missing or invalid.
+pub fn server_config(config: &QuicConfig) -> Result<quinn::ServerConfig, ServerError> {
+    let (Some(cert_path), Some(key_path)) = (&config.cert_path, &config.key_path) else {
+        return Err(ServerError::Internal("QUIC listener needs a cert_path and a key_path".to_string()));
+    };
+
+    let certs = CertificateDer::pem_file_iter(cert_path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| ServerError::Internal(format!("Invalid QUIC certificate {}: {e}", cert_path.display())))?;
+    let key = PrivateKeyDer::from_pem_file(key_path)
+        .map_err(|e| ServerError::Internal(format!("Invalid QUIC private key {}: {e}", key_path.display())))?;
+    tls_server_config(certs, key)
+}
+
+/// Builds a QUIC server configuration presenting the given certificate
+pub fn tls_server_config(
+    certs: Vec<CertificateDer<'static>>,
+    key: PrivateKeyDer<'static>,
+) -> Result<quinn::ServerConfig, ServerError> {
+    let mut crypto = rustls::ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .map_err(|e| ServerError::Internal(format!("QUIC TLS setup failed: {e}")))?
+        .with_no_client_auth()
+        .with_single_cert(certs, key)
+        .map_err(|e| ServerError::Internal(format!("QUIC certificate rejected: {e}")))?;
+    crypto.alpn_protocols = vec![ALPN.to_vec()];
+
+    let crypto = QuicServerConfig::try_from(crypto)
+        .map_err(|e| ServerError::Internal(format!("QUIC TLS setup failed: {e}")))?;
+    Ok(quinn::ServerConfig::with_crypto(Arc::new(crypto)))
+}
+
+/// Reads one length-prefixed message from a stream
+///
+/// # Returns
+///
+/// The message, or `None` once the peer finished the stream.
+pub async fn read_frame(recv: &mut quinn::RecvStream) -> Result<Option<Vec<u8>>, ServerError> {
+    let mut len = [0u8; 4];
+    match recv.read_exact(&mut len).await {
+        Ok(()) => {}
+        Err(quinn::ReadExactError::FinishedEarly(0)) => return Ok(None),
+        Err(e) => return Err(ServerError::Network(format!("QUIC stream read failed: {e}"))),
+    }
+
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(ServerError::Network(format!("QUIC message of {len} bytes exceeds {MAX_FRAME_LEN}")));
+    }
+    let mut frame = vec![0; len];
+    recv.read_exact(&mut frame)
+        .await
+        .map_err(|e| ServerError::Network(format!("QUIC stream read failed: {e}")))?;
+    Ok(Some(frame))
+}
+
+/// Writes one length-prefixed message to a stream
+pub async fn write_frame(send: &mut quinn::SendStream, message: &[u8]) -> Result<(), ServerError> {
+    let len = u32::try_from(message.len())
+        .map_err(|_| ServerError::Network(format!("QUIC message of {} bytes is too large", message.len())))?;
+    send.write_all(&len.to_be_bytes())
+        .await
+        .and(send.write_all(message).await)
+        .map_err(|e| ServerError::Network(format!("QUIC stream write failed: {e}")))
+}
+
+/// Accepts QUIC connections until the endpoint closes or shutdown begins.
+///
+/// # Arguments
+///
+/// * `endpoint` - Endpoint bound to the QUIC address
+/// * `connection_manager` - Manager for tracking connections
+/// * `horizon_event_system` - Event system for plugin communication
+/// * `message_lanes` - Priority lanes incoming messages are routed through
+/// * `sessions` - Registry the connections are added to for datagram delivery
+/// * `shutdown_state` - Optional shutdown state that stops the accept loop
+pub async fn serve_quic(
+    endpoint: quinn::Endpoint,
+    connection_manager: Arc<ConnectionManager>,
+    horizon_event_system: Arc<EventSystem>,
+    message_lanes: Arc<MessageLanes>,
+    sessions: Arc<QuicSessions>,
+    shutdown_state: Option<ShutdownState>,
+) {
+    while let Some(incoming) = endpoint.accept().await {
+        if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+            info!("🛑 QUIC accept loop stopping - shutdown initiated");
+            break;
+        }
+
+        let connection_manager = connection_manager.clone();
+        let horizon_event_system = horizon_event_system.clone();
+        let message_lanes = message_lanes.clone();
+        let sessions = sessions.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_quic_connection(incoming, connection_manager, horizon_event_system, message_lanes, sessions).await {
+                error!("QUIC connection error: {:?}", e);
+            }
+        });
+    }
+}
+
+/// Handles a single QUIC client from handshake to cleanup
+async fn handle_quic_connection(
+    incoming: quinn::Incoming,
+    connection_manager: Arc<ConnectionManager>,
+    horizon_event_system: Arc<EventSystem>,
+    message_lanes: Arc<MessageLanes>,
+    sessions: Arc<QuicSessions>,
+) -> Result<(), ServerError> {
+    let connection = incoming
+        .await
+        .map_err(|e| ServerError::Network(format!("QUIC handshake failed: {e}")))?;
+    let (send, mut recv) = connection
+        .accept_bi()
+        .await
+        .map_err(|e| ServerError::Network(format!("QUIC client opened no stream: {e}")))?;
+    let send = tokio::sync::Mutex::new(send);
+
+    // Clients prove who they are before they get a player, as over WebSocket
+    let handshake = match requires_credentials(&connection_manager) {
+        false => None,
+        true => match receive_handshake(&mut recv, &connection_manager).await {
+            Ok(handshake) => Some(handshake),
+            Err(error) => {
+                warn!("🔐 QUIC client {} failed to authenticate: {}", connection.remote_address(), error);
+                let rejection = serde_json::json!({ "event": "auth_failed", "error": error });
+                connection.close(0u32.into(), rejection.to_string().as_bytes());
+                return Ok(());
+            }
+        },
+    };
+
+    // QUIC clients keep their connection across address changes, so they don't resume sessions
+    let account = handshake.as_ref().and_then(Handshake::account);
+    let player = connect_player(connection.remote_address(), &connection_manager, &horizon_event_system, None, account).await?;
+    let (connection_id, player_id) = (player.connection_id, player.player_id);
+    if let Some(handshake) = handshake {
+        start_session(connection_id, player_id, handshake, &send, &connection_manager, &horizon_event_system).await;
+    }
+    let mut guest = connection_manager.get_auth_status(connection_id).await == Some(AuthenticationStatus::Guest);
+    sessions.insert(player_id, connection.clone());
+    let mut message_receiver = connection_manager.subscribe();
+    let counters = connection_manager.counters(connection_id).await.unwrap_or_default();
+    counters.clear_queue();
+
+    // Reliable messages - queued for routing in their priority lanes
+    let stream_task = async {
+        loop {
+            match read_frame(&mut recv).await {
+                Ok(Some(frame)) => {
+                    counters.record_received(frame.len());
+                    // Guests upgrade by sending the message clients authenticate with
+                    if guest {
+                        if let Some(request) = upgrade_request(&ClientFrame::Binary(ClientEncoding::Json, frame.clone())) {
+                            guest = !upgrade_guest(connection_id, player_id, request, &send, &connection_manager, &horizon_event_system).await;
+                            continue;
+                        }
+                    }
+                    submit(&message_lanes, connection_id, frame).await
+                }
+                Ok(None) => {
+                    debug!("🔌 QUIC client {} finished its stream", connection_id);
+                    break;
+                }
+                Err(e) => {
+                    debug!("🔌 QUIC client {} stream closed: {}", connection_id, e);
+                    break;
+                }
+            }
+        }
+    };
+
+    // Unreliable messages - routed like stream messages, but may be lost
+    let datagram_task = async {
+        while let Ok(datagram) = connection.read_datagram().await {
+            counters.record_received(datagram.len());
+            submit(&message_lanes, connection_id, datagram.to_vec()).await;
+        }
+    };
+
+    // Outgoing message task
+    let outgoing_task = async {
+        while let Ok((target_connection_id, message)) = message_receiver.recv().await {
+            if target_connection_id == connection_id {
+                if let Err(e) = write_frame(&mut *send.lock().await, &message).await {
+                    counters.record_dequeued(None);
+                    error!("Failed to send message: {}", e);
+                    break;
+                }
+                counters.record_dequeued(Some(message.len()));
+            }
+        }
+    };
+
+    // Run all tasks concurrently until one completes
+    tokio::select! {
+        _ = stream_task => {},
+        _ = datagram_task => {},
+        _ = outgoing_task => {},
+        _ = counters.overflowed() => debug!("🐌 QUIC client {} fell too far behind, disconnecting", connection_id),
+    }
+
+    sessions.remove(player_id);
+    connection.close(0u32.into(), b"");
+    disconnect_player(connection_id, DisconnectReason::ClientDisconnect, &connection_manager, &horizon_event_system).await
+}
+
+/// Waits for the credentials a client presents in its first stream message
+/// and verifies them.
+///
+/// # Returns
+///
+/// How the client started its session, or why it was rejected.
+async fn receive_handshake(recv: &mut quinn::RecvStream, connection_manager: &ConnectionManager) -> Result<Handshake, String> {
+    let timeout = connection_manager.authentication().handshake_timeout();
+    match tokio::time::timeout(timeout, read_frame(recv)).await {
+        Err(_) => Err("no token was presented in time".to_string()),
+        Ok(Ok(Some(frame))) => check_handshake(&ClientFrame::Binary(ClientEncoding::Json, frame), connection_manager).await,
+        Ok(_) => Err("the connection closed before a token was presented".to_string()),
+    }
+}
+
+#[horizon_event_system::async_trait]
+impl ClientReplies for tokio::sync::Mutex<quinn::SendStream> {
+    async fn reply(&self, message: serde_json::Value) {
+        let _ = write_frame(&mut *self.lock().await, message.to_string().as_bytes()).await;
+    }
+}
+
+/// Queues a client message for routing, dropping messages that are neither
+/// text nor raw binary frames
+async fn submit(message_lanes: &MessageLanes, connection_id: ConnectionId, message: Vec<u8>) {
+    // The marker is valid UTF-8, so raw frames must be told apart first
+    if message.first() == Some(&RAW_FRAME_MARKER) {
+        match ClientFrame::from_binary(ClientEncoding::Json, message) {
+            Some(frame) => message_lanes.submit_frame(connection_id, frame).await,
+            None => warn!("⚠️ Dropped truncated raw QUIC frame from connection {}", connection_id),
+        }
+        return;
+    }
+    match String::from_utf8(message) {
+        Ok(text) => message_lanes.submit(connection_id, text).await,
+        Err(_) => warn!("⚠️ Dropped non-UTF-8 QUIC message from connection {}", connection_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MessageLaneConfig;
+    use crate::connection::GameServerResponseSender;
+    use crate::udp::{decode_update, LatestWins};
+    use horizon_event_system::{ClientConnectionRef, PlayerConnectedEvent};
+    use quinn::crypto::rustls::QuicClientConfig;
+    use quinn::rustls::pki_types::PrivatePkcs8KeyDer;
+
+    struct TestServer {
+        address: std::net::SocketAddr,
+        certificate: CertificateDer<'static>,
+        sessions: Arc<QuicSessions>,
+        players: tokio::sync::mpsc::UnboundedReceiver<PlayerId>,
+    }
+
+    async fn start_server() -> TestServer {
+        start_server_with(ConnectionManager::new()).await
+    }
+
+    async fn start_server_with(connection_manager: ConnectionManager) -> TestServer {
+        let certified = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let certificate = certified.cert.der().clone();
+        let key = PrivatePkcs8KeyDer::from(certified.key_pair.serialize_der()).into();
+        let endpoint = quinn::Endpoint::server(
+            tls_server_config(vec![certificate.clone()], key).unwrap(),
+            "127.0.0.1:0".parse().unwrap(),
+        )
+        .unwrap();
+
+        let connection_manager = Arc::new(connection_manager);
+        let sessions = Arc::new(QuicSessions::new());
+        let mut events = EventSystem::new();
+        events.set_client_response_sender(Arc::new(
+            GameServerResponseSender::new(connection_manager.clone()).with_quic(sessions.clone()),
+        ));
+        let events = Arc::new(events);
+
+        let (player_sender, players) = tokio::sync::mpsc::unbounded_channel();
+        events
+            .on_core("player_connected", move |event: PlayerConnectedEvent| {
+                let _ = player_sender.send(event.player_id);
+                Ok(())
+            })
+            .await
+            .unwrap();
+        events
+            .on_client("chat", "echo", |data: serde_json::Value, _player_id: PlayerId, conn: ClientConnectionRef| {
+                tokio::spawn(async move {
+                    let _ = conn.respond_json(&serde_json::json!({ "echo": data["data"] })).await;
+                });
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+        message_lanes.start(connection_manager.clone(), events.clone());
+        let address = endpoint.local_addr().unwrap();
+        tokio::spawn(serve_quic(endpoint, connection_manager, events, message_lanes, sessions.clone(), None));
+
+        TestServer { address, certificate, sessions, players }
+    }
+
+    async fn connect(server: &TestServer) -> (quinn::Endpoint, quinn::Connection) {
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add(server.certificate.clone()).unwrap();
+        let mut crypto = rustls::ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_protocol_versions(&[&rustls::version::TLS13])
+            .unwrap()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        crypto.alpn_protocols = vec![ALPN.to_vec()];
+
+        let mut endpoint = quinn::Endpoint::client("127.0.0.1:0".parse().unwrap()).unwrap();
+        endpoint.set_default_client_config(quinn::ClientConfig::new(Arc::new(QuicClientConfig::try_from(crypto).unwrap())));
+        let connection = endpoint.connect(server.address, "localhost").unwrap().await.unwrap();
+        (endpoint, connection)
+    }
+
+    #[tokio::test]
+    async fn test_stream_and_datagram_messages_are_routed() {
+        let mut server = start_server().await;
+        let (_endpoint, connection) = connect(&server).await;
+        let (mut send, mut recv) = connection.open_bi().await.unwrap();
+
+        let message = serde_json::json!({ "namespace": "chat", "event": "echo", "data": "over the stream" });
+        write_frame(&mut send, message.to_string().as_bytes()).await.unwrap();
+        let player_id = server.players.recv().await.unwrap();
+        let response: serde_json::Value = serde_json::from_slice(&read_frame(&mut recv).await.unwrap().unwrap()).unwrap();
+        assert_eq!(response["echo"], "over the stream");
+
+        let message = serde_json::json!({ "namespace": "chat", "event": "echo", "data": "as a datagram" });
+        connection.send_datagram(Bytes::from(message.to_string())).unwrap();
+        let response: serde_json::Value = serde_json::from_slice(&read_frame(&mut recv).await.unwrap().unwrap()).unwrap();
+        assert_eq!(response["echo"], "as a datagram");
+
+        assert!(server.sessions.contains(player_id));
+        let object_id = GorcObjectId::new();
+        assert!(server.sessions.send_update(player_id, object_id, b"position"));
+        let datagram = connection.read_datagram().await.unwrap();
+        let update = decode_update(&datagram).unwrap();
+        assert_eq!((update.sequence, update.object_id, update.payload), (1, object_id, &b"position"[..]));
+        assert!(LatestWins::new().accept(&update));
+        assert!(!server.sessions.send_update(player_id, object_id, &vec![0; MAX_FRAME_LEN]));
+
+        assert!(server.sessions.close(player_id, "test over"));
+        assert!(!server.sessions.send_update(player_id, object_id, b"closed"));
+    }
+
+    #[tokio::test]
+    async fn test_clients_authenticate_before_they_get_a_player() {
+        use crate::config::{AuthConfig, JwtKeyConfig};
+        use crate::connection::auth::ClientAuthentication;
+        use jsonwebtoken::{Algorithm, EncodingKey, Header};
+
+        let authentication = ClientAuthentication::new(&AuthConfig {
+            jwt_keys: vec![JwtKeyConfig {
+                kid: None,
+                algorithm: Algorithm::HS256,
+                secret: Some("shared-secret".to_string()),
+                public_key_path: None,
+            }],
+            ..AuthConfig::default()
+        })
+        .unwrap();
+        let mut server = start_server_with(ConnectionManager::new().with_authentication(Arc::new(authentication))).await;
+
+        // A client that skips the handshake is closed without ever getting a player
+        let (_endpoint, connection) = connect(&server).await;
+        let (mut send, _recv) = connection.open_bi().await.unwrap();
+        let message = serde_json::json!({ "namespace": "chat", "event": "echo", "data": "let me in" });
+        write_frame(&mut send, message.to_string().as_bytes()).await.unwrap();
+        let quinn::ConnectionError::ApplicationClosed(close) = connection.closed().await else {
+            panic!("expected the server to close the connection");
+        };
+        let rejection: serde_json::Value = serde_json::from_slice(&close.reason).unwrap();
+        assert_eq!(rejection["event"], "auth_failed");
+        assert!(server.players.try_recv().is_err());
+
+        let claims = serde_json::json!({ "sub": "account-7", "exp": horizon_event_system::current_timestamp() + 60 });
+        let token = jsonwebtoken::encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(b"shared-secret")).unwrap();
+        let (_endpoint, connection) = connect(&server).await;
+        let (mut send, mut recv) = connection.open_bi().await.unwrap();
+        let auth = serde_json::json!({ "type": "auth", "token": token });
+        write_frame(&mut send, auth.to_string().as_bytes()).await.unwrap();
+        let player_id = server.players.recv().await.unwrap();
+        let reply: serde_json::Value = serde_json::from_slice(&read_frame(&mut recv).await.unwrap().unwrap()).unwrap();
+        assert_eq!(reply["event"], "authenticated");
+        assert_eq!(reply["subject"], "account-7");
+        assert_eq!(reply["player_id"], serde_json::json!(player_id));
+    }
+}
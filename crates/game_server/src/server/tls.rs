@@ -0,0 +1,377 @@
+//! TLS termination for WebSocket clients (`wss://`).
+//!
+//! Each connection gets the certificate for the server name the client
+//! requested through SNI: a certificate for exactly that name first, then one
+//! for a matching `*.` wildcard, then the default certificate. One listener
+//! can therefore serve several domains.
+//!
+//! Certificate files are checked for changes on an interval and reloaded
+//! when they change, so certificates renewed in place by an ACME client such
+//! as certbot or lego are picked up without a restart. The server doesn't
+//! request certificates from an ACME CA itself.
+
+use crate::config::TlsConfig;
+use crate::error::ServerError;
+use horizon_event_system::ShutdownState;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
+use tokio_rustls::rustls;
+use tokio_rustls::rustls::crypto::CryptoProvider;
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::rustls::server::{ClientHello, ResolvesServerCert};
+use tokio_rustls::rustls::sign::CertifiedKey;
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, info, warn};
+
+/// Loaded certificates, by the server names they are served for
+#[derive(Debug, Default)]
+struct Certificates {
+    default: Option<Arc<CertifiedKey>>,
+    by_name: HashMap<String, Arc<CertifiedKey>>,
+}
+
+/// Certificates served to TLS clients, selected by SNI
+#[derive(Debug)]
+pub struct CertificateStore {
+    config: TlsConfig,
+    provider: Arc<CryptoProvider>,
+    certificates: RwLock<Certificates>,
+    /// Latest modification time of the certificate files when last loaded
+    modified: Mutex<Option<SystemTime>>,
+}
+
+impl CertificateStore {
+    /// Loads every certificate the configuration names
+    ///
+    /// # Returns
+    ///
+    /// The store, or a `ServerError` if a certificate can't be loaded or
+    /// none is configured.
+    pub fn load(config: &TlsConfig) -> Result<Self, ServerError> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let modified = latest_modification(config);
+        let certificates = read_certificates(config, &provider)?;
+        Ok(Self {
+            config: config.clone(),
+            provider,
+            certificates: RwLock::new(certificates),
+            modified: Mutex::new(modified),
+        })
+    }
+
+    /// Reloads the certificates if any of their files changed since they were loaded.
+    ///
+    /// If loading fails, the previous certificates stay in use.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the certificates were reloaded.
+    pub fn reload_if_changed(&self) -> Result<bool, ServerError> {
+        let modified = latest_modification(&self.config);
+        let mut last_modified = self.modified.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if modified == *last_modified {
+            return Ok(false);
+        }
+
+        let certificates = read_certificates(&self.config, &self.provider)?;
+        *self.certificates.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = certificates;
+        *last_modified = modified;
+        Ok(true)
+    }
+
+    /// Gets the server names with their own certificate
+    pub fn server_names(&self) -> Vec<String> {
+        let certificates = self.certificates.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut names: Vec<String> = certificates.by_name.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Selects the certificate for a requested server name
+    fn select(&self, server_name: Option<&str>) -> Option<Arc<CertifiedKey>> {
+        let certificates = self.certificates.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let Some(server_name) = server_name.map(str::to_ascii_lowercase) else {
+            return certificates.default.clone();
+        };
+
+        let wildcard = server_name.split_once('.').map(|(_, parent)| format!("*.{parent}"));
+        certificates
+            .by_name
+            .get(&server_name)
+            .or_else(|| wildcard.and_then(|wildcard| certificates.by_name.get(&wildcard)))
+            .or(certificates.default.as_ref())
+            .cloned()
+    }
+}
+
+impl ResolvesServerCert for CertificateStore {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let certificate = self.select(client_hello.server_name());
+        if certificate.is_none() {
+            debug!("🔒 No certificate for server name {:?}", client_hello.server_name());
+        }
+        certificate
+    }
+}
+
+/// Builds the acceptor terminating TLS with the store's certificates
+pub fn tls_acceptor(store: Arc<CertificateStore>) -> Result<TlsAcceptor, ServerError> {
+    let mut config = rustls::ServerConfig::builder_with_provider(store.provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| ServerError::Internal(format!("TLS setup failed: {e}")))?
+        .with_no_client_auth()
+        .with_cert_resolver(store);
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// Reloads renewed certificates on an interval until shutdown
+///
+/// # Arguments
+///
+/// * `store` - Certificates to keep current
+/// * `interval` - Time between checks for changed files
+/// * `shutdown_state` - Optional shutdown state that stops the checks
+pub fn spawn_certificate_reloader(store: Arc<CertificateStore>, interval: Duration, shutdown_state: Option<ShutdownState>) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // The first tick completes immediately and the store was just loaded
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+                break;
+            }
+            match store.reload_if_changed() {
+                Ok(true) => info!("🔒 Reloaded renewed TLS certificates"),
+                Ok(false) => {}
+                Err(e) => warn!("⚠️ Keeping current TLS certificates: {}", e),
+            }
+        }
+    });
+}
+
+/// Loads the default and SNI certificates of a configuration
+fn read_certificates(config: &TlsConfig, provider: &CryptoProvider) -> Result<Certificates, ServerError> {
+    let default = match (&config.cert_path, &config.key_path) {
+        (Some(cert_path), Some(key_path)) => Some(read_certified_key(cert_path, key_path, provider)?),
+        (None, None) => None,
+        _ => return Err(ServerError::Internal("TLS needs both a cert_path and a key_path".to_string())),
+    };
+
+    let mut by_name = HashMap::new();
+    for sni in &config.sni {
+        let certificate = read_certified_key(&sni.cert_path, &sni.key_path, provider)?;
+        by_name.insert(sni.server_name.to_ascii_lowercase(), certificate);
+    }
+
+    if default.is_none() && by_name.is_empty() {
+        return Err(ServerError::Internal("TLS is enabled but no certificate is configured".to_string()));
+    }
+    Ok(Certificates { default, by_name })
+}
+
+/// Loads a PEM certificate chain and its private key
+fn read_certified_key(cert_path: &Path, key_path: &Path, provider: &CryptoProvider) -> Result<Arc<CertifiedKey>, ServerError> {
+    let certs = CertificateDer::pem_file_iter(cert_path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| ServerError::Internal(format!("Invalid TLS certificate {}: {e}", cert_path.display())))?;
+    if certs.is_empty() {
+        return Err(ServerError::Internal(format!("No certificate in {}", cert_path.display())));
+    }
+    let key = PrivateKeyDer::from_pem_file(key_path)
+        .map_err(|e| ServerError::Internal(format!("Invalid TLS private key {}: {e}", key_path.display())))?;
+    let signing_key = provider
+        .key_provider
+        .load_private_key(key)
+        .map_err(|e| ServerError::Internal(format!("Unsupported TLS private key {}: {e}", key_path.display())))?;
+    Ok(Arc::new(CertifiedKey::new(certs, signing_key)))
+}
+
+/// Gets the latest modification time of the configured certificate files
+fn latest_modification(config: &TlsConfig) -> Option<SystemTime> {
+    let default_files = [&config.cert_path, &config.key_path].into_iter().flatten();
+    let sni_files = config.sni.iter().flat_map(|sni| [&sni.cert_path, &sni.key_path]);
+    default_files
+        .chain(sni_files)
+        .filter_map(|path: &PathBuf| std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok())
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{MessageLaneConfig, SniCertificateConfig};
+    use crate::connection::{ConnectionManager, GameServerResponseSender};
+    use crate::messaging::MessageLanes;
+    use crate::server::handlers::handle_connection;
+    use futures::{SinkExt, StreamExt};
+    use horizon_event_system::{ClientConnectionRef, EventSystem, PlayerId};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_rustls::rustls::pki_types::ServerName;
+    use tokio_rustls::TlsConnector;
+
+    /// Writes a self-signed certificate for `name` and returns its DER and file paths
+    fn write_certificate(dir: &Path, name: &str) -> (CertificateDer<'static>, PathBuf, PathBuf) {
+        let certified = rcgen::generate_simple_self_signed(vec![name.to_string()]).unwrap();
+        let file_stem = name.replace('*', "wildcard");
+        let cert_path = dir.join(format!("{file_stem}.pem"));
+        let key_path = dir.join(format!("{file_stem}.key"));
+        std::fs::write(&cert_path, certified.cert.pem()).unwrap();
+        std::fs::write(&key_path, certified.key_pair.serialize_pem()).unwrap();
+        (certified.cert.der().clone(), cert_path, key_path)
+    }
+
+    fn sni(server_name: &str, cert_path: &Path, key_path: &Path) -> SniCertificateConfig {
+        SniCertificateConfig {
+            server_name: server_name.to_string(),
+            cert_path: cert_path.to_path_buf(),
+            key_path: key_path.to_path_buf(),
+        }
+    }
+
+    /// Builds a client connector trusting the given certificates
+    fn connector(roots: &[CertificateDer<'static>]) -> TlsConnector {
+        let mut root_store = rustls::RootCertStore::empty();
+        for root in roots {
+            root_store.add(root.clone()).unwrap();
+        }
+        let config = rustls::ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_root_certificates(root_store)
+            .with_no_client_auth();
+        TlsConnector::from(Arc::new(config))
+    }
+
+    /// Performs a TLS handshake for `server_name` and returns the certificate the server presented
+    async fn served_certificate(acceptor: TlsAcceptor, roots: &[CertificateDer<'static>], server_name: &str) -> CertificateDer<'static> {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let _ = acceptor.accept(stream).await;
+        });
+
+        let stream = TcpStream::connect(address).await.unwrap();
+        let tls = connector(roots)
+            .connect(ServerName::try_from(server_name.to_string()).unwrap(), stream)
+            .await
+            .unwrap();
+        tls.get_ref().1.peer_certificates().unwrap()[0].clone()
+    }
+
+    #[tokio::test]
+    async fn test_certificates_are_selected_by_sni() {
+        let dir = tempfile::tempdir().unwrap();
+        let (default_der, default_cert, default_key) = write_certificate(dir.path(), "game.test");
+        let (eu_der, eu_cert, eu_key) = write_certificate(dir.path(), "eu.game.test");
+        let (wildcard_der, wildcard_cert, wildcard_key) = write_certificate(dir.path(), "*.region.test");
+
+        let config = TlsConfig {
+            enabled: true,
+            cert_path: Some(default_cert),
+            key_path: Some(default_key),
+            sni: vec![
+                sni("EU.game.test", &eu_cert, &eu_key),
+                sni("*.region.test", &wildcard_cert, &wildcard_key),
+            ],
+            ..TlsConfig::default()
+        };
+        let store = Arc::new(CertificateStore::load(&config).unwrap());
+        assert_eq!(store.server_names(), vec!["*.region.test".to_string(), "eu.game.test".to_string()]);
+
+        let roots = [default_der.clone(), eu_der.clone(), wildcard_der.clone()];
+        let acceptor = tls_acceptor(store).unwrap();
+        assert_eq!(served_certificate(acceptor.clone(), &roots, "game.test").await, default_der);
+        assert_eq!(served_certificate(acceptor.clone(), &roots, "eu.game.test").await, eu_der);
+        assert_eq!(served_certificate(acceptor, &roots, "us.region.test").await, wildcard_der);
+    }
+
+    #[test]
+    fn test_changed_certificates_are_reloaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let (first_der, cert_path, key_path) = write_certificate(dir.path(), "game.test");
+        let config = TlsConfig {
+            enabled: true,
+            cert_path: Some(cert_path),
+            key_path: Some(key_path),
+            ..TlsConfig::default()
+        };
+        let store = CertificateStore::load(&config).unwrap();
+        assert!(!store.reload_if_changed().unwrap());
+        assert_eq!(store.select(Some("game.test")).unwrap().cert[0], first_der);
+
+        // An ACME client renewing the certificate rewrites the same files
+        *store.modified.lock().unwrap() = None;
+        let (renewed_der, _, _) = write_certificate(dir.path(), "game.test");
+        assert!(store.reload_if_changed().unwrap());
+        assert_eq!(store.select(None).unwrap().cert[0], renewed_der);
+
+        std::fs::write(dir.path().join("game.test.pem"), "not a certificate").unwrap();
+        *store.modified.lock().unwrap() = None;
+        assert!(store.reload_if_changed().is_err());
+        assert_eq!(store.select(None).unwrap().cert[0], renewed_der);
+
+        assert!(CertificateStore::load(&TlsConfig { enabled: true, ..TlsConfig::default() }).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_websocket_messages_over_tls() {
+        let dir = tempfile::tempdir().unwrap();
+        let (certificate, cert_path, key_path) = write_certificate(dir.path(), "localhost");
+        let config = TlsConfig {
+            enabled: true,
+            cert_path: Some(cert_path),
+            key_path: Some(key_path),
+            ..TlsConfig::default()
+        };
+        let acceptor = tls_acceptor(Arc::new(CertificateStore::load(&config).unwrap())).unwrap();
+
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let mut events = EventSystem::new();
+        events.set_client_response_sender(Arc::new(GameServerResponseSender::new(connection_manager.clone())));
+        let events = Arc::new(events);
+        events
+            .on_client("chat", "echo", |data: serde_json::Value, _player_id: PlayerId, conn: ClientConnectionRef| {
+                tokio::spawn(async move {
+                    let _ = conn.respond_json(&serde_json::json!({ "echo": data["data"] })).await;
+                });
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let message_lanes = Arc::new(MessageLanes::new(&MessageLaneConfig::default()));
+        message_lanes.start(connection_manager.clone(), events.clone());
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, addr) = listener.accept().await.unwrap();
+            let _ = handle_connection(stream, addr, connection_manager, events, message_lanes, Some(acceptor)).await;
+        });
+
+        let stream = TcpStream::connect(address).await.unwrap();
+        let tls = connector(&[certificate])
+            .connect(ServerName::try_from("localhost").unwrap(), stream)
+            .await
+            .unwrap();
+        let (mut websocket, _) = tokio_tungstenite::client_async(format!("wss://localhost:{}", address.port()), tls)
+            .await
+            .unwrap();
+
+        let message = serde_json::json!({ "namespace": "chat", "event": "echo", "data": "encrypted" });
+        websocket.send(Message::Text(message.to_string().into())).await.unwrap();
+        let response = loop {
+            match websocket.next().await.unwrap().unwrap() {
+                Message::Text(text) => break serde_json::from_str::<serde_json::Value>(&text).unwrap(),
+                _ => continue,
+            }
+        };
+        assert_eq!(response["echo"], "encrypted");
+    }
+}
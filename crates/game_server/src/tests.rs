@@ -303,6 +303,38 @@ mod tests {
             connection_timeout: 300,
             use_reuse_port: true,
             tick_interval_ms: 16, // 60 FPS
+            zone_exit_margin: 0.1,
+            dead_reckoning_horizon_ms: 200,
+            object_shards: 16,
+            spatial_index: Default::default(),
+            gorc_network: Default::default(),
+            snapshot: Default::default(),
+            federation: Default::default(),
+            admin_address: None,
+            admin_token: None,
+            grpc_address: None,
+            health_address: None,
+            session_resume_window_secs: 0,
+            heartbeat: Default::default(),
+            outbound_queue: Default::default(),
+            protocol: Default::default(),
+            s2s: Default::default(),
+            content_filter: Default::default(),
+            audit_log: Default::default(),
+            geoip: Default::default(),
+            alerting: Default::default(),
+            connection_throttle: Default::default(),
+            auth: Default::default(),
+            udp_address: None,
+            quic: Default::default(),
+            tls: Default::default(),
+            proxy_protocol: Default::default(),
+            accept_threads: 0,
+            listeners: Vec::new(),
+            event_queue: Default::default(),
+            handler_spans: Default::default(),
+            message_lanes: Default::default(),
+            plugin_circuit_breaker: Default::default(),
             security: Default::default(),
             plugin_safety: Default::default(),
         };
@@ -373,6 +405,38 @@ mod tests {
         // Create config with tick disabled
         let config = ServerConfig {
             tick_interval_ms: 0, // Disabled
+            zone_exit_margin: 0.1,
+            dead_reckoning_horizon_ms: 200,
+            object_shards: 16,
+            spatial_index: Default::default(),
+            gorc_network: Default::default(),
+            snapshot: Default::default(),
+            federation: Default::default(),
+            admin_address: None,
+            admin_token: None,
+            grpc_address: None,
+            health_address: None,
+            session_resume_window_secs: 0,
+            heartbeat: Default::default(),
+            outbound_queue: Default::default(),
+            protocol: Default::default(),
+            s2s: Default::default(),
+            content_filter: Default::default(),
+            audit_log: Default::default(),
+            geoip: Default::default(),
+            alerting: Default::default(),
+            connection_throttle: Default::default(),
+            auth: Default::default(),
+            udp_address: None,
+            quic: Default::default(),
+            tls: Default::default(),
+            proxy_protocol: Default::default(),
+            accept_threads: 0,
+            listeners: Vec::new(),
+            event_queue: Default::default(),
+            handler_spans: Default::default(),
+            message_lanes: Default::default(),
+            plugin_circuit_breaker: Default::default(),
             bind_address: "127.0.0.1:8081".parse().unwrap(),
             region_bounds: RegionBounds::default(),
             plugin_directory: std::path::PathBuf::from("plugins"),
@@ -539,4 +603,421 @@ mod tests {
             info!("✅ Server created with plugin_directory: {:?}", dir);
         }
     }
-}
\ No newline at end of file
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_admin_zone_dump_route() {
+        use horizon_event_system::gorc::GorcInstanceManager;
+        use horizon_event_system::{PlayerId, Vec3};
+        use std::sync::Arc;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let gorc_instances = Arc::new(GorcInstanceManager::new());
+        let player_id = PlayerId::new();
+        gorc_instances.add_player(player_id, Vec3::new(0.0, 0.0, 0.0)).await;
+        gorc_instances.update_player_position(player_id, Vec3::new(5.0, 0.0, 0.0)).await;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let api = admin::AdminApi::new(Arc::new(connection::ConnectionManager::new()), Arc::new(horizon_event_system::EventSystem::new()))
+            .with_gorc_instances(gorc_instances.clone());
+        tokio::spawn(admin::serve_admin(listener, Arc::new(api), None));
+
+        let request = |target: String| async move {
+            let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+            let request = format!("GET {target} HTTP/1.1\r\nHost: localhost\r\n\r\n");
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+
+        let response = request(format!("{}?player={}", admin::ZONES_ROUTE, player_id)).await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        let dump: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(dump["players"][0]["player_id"], player_id.to_string());
+        assert_eq!(dump["players"][0]["position"]["x"], 5.0);
+
+        assert!(request(format!("{}?player=nope", admin::ZONES_ROUTE)).await.starts_with("HTTP/1.1 400"));
+        assert!(request("/debug/unknown".to_string()).await.starts_with("HTTP/1.1 404"));
+    }
+
+    #[tokio::test]
+    async fn test_admin_api_routes() {
+        use admin::{AdminApi, AdminRequest};
+        use horizon_event_system::PlayerId;
+        use connection::ConnectionManager;
+        use horizon_event_system::EventSystem;
+        use plugin_system::{PluginManager, PluginSafetyConfig};
+        use std::sync::Arc;
+
+        let connection_manager = Arc::new(ConnectionManager::new());
+        let events = Arc::new(EventSystem::new());
+        let player_id = PlayerId::new();
+        let connection_id = connection_manager.add_connection("10.0.0.7:4000".parse().unwrap()).await;
+        connection_manager.set_player_id(connection_id, player_id).await;
+        let json = |response: admin::AdminResponse| serde_json::from_str::<serde_json::Value>(&response.body).unwrap();
+
+        // Without a token only the debug routes are served
+        let open_api = AdminApi::new(connection_manager.clone(), events.clone());
+        assert_eq!(open_api.route(&AdminRequest::new("GET", "/admin/players")).await.status, 403);
+        assert_eq!(open_api.route(&AdminRequest::new("GET", admin::ZONES_ROUTE)).await.status, 503);
+
+        let api = AdminApi::new(connection_manager.clone(), events.clone()).with_token("secret");
+        assert_eq!(api.route(&AdminRequest::new("GET", "/admin/players")).await.status, 401);
+        assert_eq!(api.route(&AdminRequest::new("GET", "/admin/players").with_token("wrong")).await.status, 401);
+        assert_eq!(api.route(&AdminRequest::new("GET", admin::ZONES_ROUTE)).await.status, 401);
+        let get = |target: &str| AdminRequest::new("GET", target).with_token("secret");
+        let post = |target: &str, body: &str| AdminRequest::new("POST", target).with_token("secret").with_body(body);
+
+        let players = json(api.route(&get("/admin/players")).await);
+        assert_eq!(players["players"][0]["player_id"], player_id.to_string());
+        assert_eq!(players["players"][0]["remote_addr"], "10.0.0.7:4000");
+        assert_eq!(api.route(&AdminRequest::new("DELETE", "/admin/players").with_token("secret")).await.status, 405);
+
+        let mut outgoing = connection_manager.subscribe();
+        let response = api.route(&post("/admin/broadcast", r#"{"message": "Restart in 5 minutes"}"#)).await;
+        assert_eq!(json(response)["recipients"], 1);
+        let (target, message) = outgoing.recv().await.unwrap();
+        assert_eq!(target, connection_id);
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(&message).unwrap()["message"], "Restart in 5 minutes");
+        assert_eq!(api.route(&post("/admin/broadcast", "{}")).await.status, 400);
+
+        let response = api.route(&post(&format!("/admin/players/{player_id}/ban"), r#"{"reason": "cheating"}"#)).await;
+        assert_eq!(json(response)["ip"], "10.0.0.7");
+        assert!(connection_manager.connected_players().await.is_empty());
+        assert!(connection_manager.is_banned("10.0.0.7".parse().unwrap()));
+        let refused = crate::server::handlers::connect_player("10.0.0.7:4001".parse().unwrap(), &connection_manager, &events, None, None).await;
+        assert!(refused.is_err());
+        assert_eq!(json(api.route(&get("/admin/bans")).await)["banned_ips"][0], "10.0.0.7");
+
+        let unban = AdminRequest::new("DELETE", "/admin/bans/10.0.0.7").with_token("secret");
+        assert_eq!(api.route(&unban).await.status, 200);
+        assert_eq!(api.route(&unban).await.status, 404);
+
+        // Players stay banned after their address ban is lifted
+        let bans = json(api.route(&get("/admin/bans")).await);
+        assert_eq!(bans["bans"][0]["target"], serde_json::json!({ "type": "player", "value": player_id }));
+        assert_eq!(bans["bans"][0]["reason"], "cheating");
+        let unban_player = AdminRequest::new("DELETE", &format!("/admin/bans/players/{player_id}")).with_token("secret");
+        assert_eq!(api.route(&unban_player).await.status, 200);
+
+        let response = api.route(&post("/admin/bans", r#"{"hardware_id": "rig-42", "duration_secs": 600}"#)).await;
+        assert_eq!(json(response)["banned"]["target"]["value"], "rig-42");
+        assert_eq!(api.route(&post("/admin/bans", r#"{"ip": "10.0.0.8", "hardware_id": "rig-42"}"#)).await.status, 400);
+        assert_eq!(api.route(&post("/admin/bans", r#"{"ip": "10.0.0.8", "duration_secs": -1}"#)).await.status, 400);
+        let unban_hardware = AdminRequest::new("DELETE", "/admin/bans/hardware/rig-42").with_token("secret");
+        assert_eq!(api.route(&unban_hardware).await.status, 200);
+        assert_eq!(json(api.route(&get("/admin/bans")).await)["bans"], serde_json::json!([]));
+
+        let put_filter = |body: &str| AdminRequest::new("PUT", "/admin/ip-filter").with_token("secret").with_body(body);
+        assert_eq!(api.route(&put_filter(r#"{"deny": ["10.0.0.0/33"]}"#)).await.status, 400);
+        let response = api.route(&put_filter(r#"{"deny": ["10.0.0.0/24"]}"#)).await;
+        assert_eq!(json(response)["deny"], serde_json::json!(["10.0.0.0/24"]));
+        let filtered = crate::server::handlers::connect_player("10.0.0.9:4000".parse().unwrap(), &connection_manager, &events, None, None).await;
+        assert!(filtered.is_err());
+        api.route(&put_filter("{}")).await;
+        assert_eq!(json(api.route(&get("/admin/ip-filter")).await), serde_json::json!({ "allow": [], "deny": [] }));
+
+        let player_id = crate::server::handlers::connect_player("10.0.0.7:4002".parse().unwrap(), &connection_manager, &events, None, None)
+            .await
+            .unwrap()
+            .player_id;
+        assert_eq!(api.route(&post(&format!("/admin/players/{player_id}/kick"), "")).await.status, 200);
+        assert_eq!(api.route(&post(&format!("/admin/players/{player_id}/kick"), "")).await.status, 404);
+        assert_eq!(api.route(&post("/admin/players/nope/kick", "")).await.status, 400);
+
+        assert_eq!(api.route(&get("/admin/plugins")).await.status, 503);
+        let plugin_manager = Arc::new(PluginManager::new(events.clone(), PluginSafetyConfig::default()));
+        let api = AdminApi::new(connection_manager, events).with_token("secret").with_plugin_manager(plugin_manager);
+        assert_eq!(json(api.route(&get("/admin/plugins")).await)["plugins"], serde_json::json!([]));
+        assert_eq!(api.route(&post("/admin/plugins/missing/reload", "")).await.status, 404);
+    }
+
+    /// Locates 10.1.x.x in Germany and everything else nowhere
+    #[derive(Debug)]
+    struct GermanSubnet;
+
+    impl security::geoip::GeoLookup for GermanSubnet {
+        fn locate(&self, ip: std::net::IpAddr) -> Result<security::geoip::GeoTag, security::geoip::GeoIpError> {
+            let german = matches!(ip, std::net::IpAddr::V4(v4) if v4.octets()[..2] == [10, 1]);
+            Ok(security::geoip::GeoTag {
+                country: german.then(|| "DE".to_string()),
+                continent: german.then(|| "EU".to_string()),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_geoip_policy_tags_and_caps_connections() {
+        use connection::ConnectionManager;
+        use horizon_event_system::EventSystem;
+        use security::geoip::GeoPolicy;
+        use std::sync::Arc;
+
+        let config = GeoIpConfig {
+            refuse_unknown: true,
+            country_connection_caps: std::collections::HashMap::from([("DE".to_string(), 1)]),
+            ..Default::default()
+        };
+        let connection_manager = ConnectionManager::new().with_geo_policy(Arc::new(GeoPolicy::with_lookup(&config, GermanSubnet)));
+        let events = EventSystem::new();
+        let connect = |address: &str| {
+            crate::server::handlers::connect_player(address.parse().unwrap(), &connection_manager, &events, None, None)
+        };
+
+        assert!(connect("192.168.1.20:4000").await.is_err());
+        let player = connect("10.1.0.7:4000").await.unwrap();
+        let geo = connection_manager.get_metadata_by_player(player.player_id, "geo").await;
+        assert_eq!(geo, Some(serde_json::json!({ "country": "DE", "continent": "EU" })));
+
+        // Germany is full until the first player leaves
+        assert!(connect("10.1.0.8:4000").await.is_err());
+        connection_manager.remove_connection(player.connection_id).await;
+        assert!(connect("10.1.0.8:4000").await.is_ok());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_unreadable_geoip_database_fails_startup() {
+        let mut config = ServerConfig::default();
+        config.geoip.database_path = Some(std::env::temp_dir().join("horizon-missing-geoip.mmdb"));
+        let server = create_server_with_config(config);
+
+        let error = server.start().await.unwrap_err();
+        assert!(error.to_string().contains("GeoIP"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_replication_uses_configured_network_settings() {
+        let mut config = ServerConfig::default();
+        config.gorc_network.compression_threshold = 256;
+        config.gorc_network.max_batch_size = 50;
+        let server = create_server_with_config(config);
+
+        let gorc_replication = server.get_gorc_replication();
+        let gorc_replication = gorc_replication.lock().await;
+        let settings = gorc_replication.network_engine.get_config().await;
+        assert_eq!((settings.compression_threshold, settings.max_batch_size), (256, 50));
+
+        // Objects registered through the event system are the ones replicated
+        let gorc_instances = server.get_horizon_event_system().get_gorc_instances().unwrap();
+        assert!(std::sync::Arc::ptr_eq(&gorc_replication.instance_manager, &gorc_instances));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_client_acks_release_reliable_updates() {
+        use horizon_event_system::gorc::{CompressionType, ReplicationBatch, ReplicationPriority, ReplicationUpdate};
+        use horizon_event_system::{GorcObjectId, PlayerId};
+        use tokio::time::{sleep, Duration};
+
+        let server = create_server();
+        server.register_core_handlers().await.unwrap();
+        let events = server.get_horizon_event_system();
+        let connection_manager = server.get_connection_manager();
+        let network_engine = server.get_gorc_replication().lock().await.network_engine.clone();
+        let mut outgoing = connection_manager.subscribe();
+
+        let player_id = PlayerId::new();
+        let connection_id = connection_manager.add_connection("127.0.0.1:9000".parse().unwrap()).await;
+        connection_manager.set_player_id(connection_id, player_id).await;
+        events
+            .emit_core("player_connected", &PlayerConnectedEvent {
+                player_id,
+                connection_id: connection_id.to_string(),
+                remote_addr: "127.0.0.1:9000".to_string(),
+                timestamp: horizon_event_system::current_timestamp(),
+            })
+            .await
+            .unwrap();
+        for _ in 0..100 {
+            if network_engine.get_active_player_count().await == 1 {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        let update = ReplicationUpdate {
+            object_id: GorcObjectId::new(),
+            object_type: "Explosion".to_string(),
+            channel: 0,
+            data: b"{}".to_vec(),
+            priority: ReplicationPriority::Critical,
+            sequence: 0,
+            timestamp: 0,
+            compression: CompressionType::None,
+            reliable: true,
+            tick: 0,
+            sent_at: 0,
+        };
+        network_engine.queue_update(vec![player_id], update).await;
+        network_engine.flush_player(player_id).await.unwrap();
+        let (target, batch) = outgoing.recv().await.unwrap();
+        assert_eq!(target, connection_id);
+        let batch: ReplicationBatch = serde_json::from_slice(&batch).unwrap();
+        let sequence = batch.updates[0].sequence;
+
+        // The client's acknowledgement travels through the router to the network engine
+        let ack = serde_json::json!({ "type": "gorc_ack", "sequences": [sequence] });
+        messaging::router::route_client_message(&ack.to_string(), connection_id, &connection_manager, &events, None)
+            .await
+            .unwrap();
+        for _ in 0..100 {
+            if network_engine.get_stats().await.reliable_acked == 1 {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(network_engine.get_stats().await.reliable_acked, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_heartbeat_latency_reaches_the_network_engine() {
+        use horizon_event_system::{PlayerId, PlayerLatencyEvent};
+        use tokio::time::{sleep, Duration};
+
+        let server = create_server();
+        server.register_core_handlers().await.unwrap();
+        let events = server.get_horizon_event_system();
+        let network_engine = server.get_gorc_replication().lock().await.network_engine.clone();
+        let player_id = PlayerId::new();
+        network_engine.add_player(player_id).await;
+
+        let latency = PlayerLatencyEvent {
+            player_id,
+            round_trip_ms: 250.0,
+            timestamp: horizon_event_system::current_timestamp(),
+        };
+        events.emit_core("player_latency", &latency).await.unwrap();
+        for _ in 0..100 {
+            if network_engine.get_rtt(player_id).await.is_some() {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(network_engine.get_rtt(player_id).await, Some(250.0));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_broadcasts_reach_connected_clients() {
+        use horizon_event_system::gorc::{ReplicationBatch, ReplicationPriority};
+        use horizon_event_system::{GorcBroadcastEvent, PlayerId};
+        use tokio::time::{sleep, Duration};
+
+        let server = create_server();
+        server.register_core_handlers().await.unwrap();
+        let events = server.get_horizon_event_system();
+        let connection_manager = server.get_connection_manager();
+        let network_engine = server.get_gorc_replication().lock().await.network_engine.clone();
+        let mut outgoing = connection_manager.subscribe();
+
+        let player_id = PlayerId::new();
+        let connection_id = connection_manager.add_connection("127.0.0.1:9000".parse().unwrap()).await;
+        connection_manager.set_player_id(connection_id, player_id).await;
+        network_engine.add_player(player_id).await;
+
+        let announcement = GorcBroadcastEvent {
+            event_type: "world_boss_spawned".to_string(),
+            data: br#"{"boss":"Leviathan"}"#.to_vec(),
+            priority: ReplicationPriority::High,
+            timestamp: horizon_event_system::current_timestamp(),
+        };
+        events.emit_core("gorc_broadcast", &announcement).await.unwrap();
+        for _ in 0..100 {
+            if network_engine.get_stats().await.broadcasts_queued == 1 {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        // The queued broadcast goes out through the player's connection
+        network_engine.flush_player(player_id).await.unwrap();
+        let (target, batch) = outgoing.recv().await.unwrap();
+        assert_eq!(target, connection_id);
+        let batch: ReplicationBatch = serde_json::from_slice(&batch).unwrap();
+        assert_eq!(batch.updates[0].object_type, "world_boss_spawned");
+        assert_eq!(batch.updates[0].data, announcement.data);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_focus_and_movement_reach_the_subscription_manager() {
+        use horizon_event_system::gorc::ViewRelation;
+        use horizon_event_system::{GorcObjectId, PlayerId, PlayerMovementEvent, Position, Vec3};
+        use tokio::time::{sleep, Duration};
+
+        let server = create_server();
+        server.register_core_handlers().await.unwrap();
+        let events = server.get_horizon_event_system();
+        let connection_manager = server.get_connection_manager();
+        let subscriptions = server.get_subscription_manager();
+        let network_engine = server.get_gorc_replication().lock().await.network_engine.clone();
+
+        let player_id = PlayerId::new();
+        let connection_id = connection_manager.add_connection("127.0.0.1:9000".parse().unwrap()).await;
+        connection_manager.set_player_id(connection_id, player_id).await;
+        events
+            .emit_core("player_connected", &PlayerConnectedEvent {
+                player_id,
+                connection_id: connection_id.to_string(),
+                remote_addr: "127.0.0.1:9000".to_string(),
+                timestamp: horizon_event_system::current_timestamp(),
+            })
+            .await
+            .unwrap();
+        for _ in 0..100 {
+            if network_engine.get_active_player_count().await == 1 {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        // The client's focus travels through the router to the subscription manager
+        let target = GorcObjectId::new();
+        let focus = serde_json::json!({ "type": "gorc_focus", "object_id": target });
+        messaging::router::route_client_message(&focus.to_string(), connection_id, &connection_manager, &events, None)
+            .await
+            .unwrap();
+        for _ in 0..100 {
+            if subscriptions.get_focus_target(player_id).await.is_some() {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(subscriptions.get_focus_target(player_id).await, Some(target));
+
+        // Moving east turns the player to face east
+        events
+            .emit_core("player_movement", &PlayerMovementEvent {
+                player_id,
+                old_position: None,
+                new_position: Vec3::new(0.0, 0.0, 0.0),
+                velocity: Some(Vec3::new(5.0, 0.0, 0.0)),
+                timestamp: horizon_event_system::current_timestamp(),
+            })
+            .await
+            .unwrap();
+        let ahead = Position::new(50.0, 0.0, 0.0);
+        for _ in 0..100 {
+            if subscriptions.get_view_relation(player_id, ahead).await != ViewRelation::Unknown {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(subscriptions.get_view_relation(player_id, ahead).await, ViewRelation::InView);
+        assert_eq!(
+            subscriptions.get_view_relation(player_id, Position::new(-50.0, 0.0, 0.0)).await,
+            ViewRelation::Behind
+        );
+
+        // Clearing the focus drops the target
+        let clear = serde_json::json!({ "type": "gorc_focus", "object_id": null });
+        messaging::router::route_client_message(&clear.to_string(), connection_id, &connection_manager, &events, None)
+            .await
+            .unwrap();
+        for _ in 0..100 {
+            if subscriptions.get_focus_target(player_id).await.is_none() {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(subscriptions.get_focus_target(player_id).await, None);
+    }
+}
@@ -0,0 +1,398 @@
+//! UDP transport for latency-critical GORC replication.
+//!
+//! Channel 0 carries position updates many times a second, and every update
+//! supersedes the previous one for its object. Over WebSocket a single lost
+//! segment stalls every update behind it, so when a UDP address is
+//! configured clients may receive channel 0 as datagrams instead. All other
+//! channels, and every update that doesn't fit a datagram, stay on the
+//! WebSocket connection.
+//!
+//! ## Session setup
+//!
+//! 1. The client sends the `system:udp_session` event over its WebSocket and
+//!    receives `{ "accepted": true, "port": <u16>, "token": "<uuid>" }`.
+//! 2. From the socket it receives on, the client sends a [`DATAGRAM_BIND`]
+//!    datagram followed by the 16 token bytes, repeating it until the server
+//!    answers with a single [`DATAGRAM_BOUND`] byte. Binding again moves the
+//!    session to the new address, e.g. after a NAT rebinding.
+//!
+//! ## Updates
+//!
+//! Each update datagram is [`DATAGRAM_UPDATE`], the sequence number as a
+//! big-endian `u64`, the 16 bytes of the object ID, then the GORC message in
+//! the player's negotiated wire format. Sequence numbers increase with every
+//! datagram sent to a player. Datagrams may be lost, duplicated or reordered,
+//! so clients apply an update only if it is newer than the last one applied
+//! for its object, as [`LatestWins`] does.
+
+use crate::config::MessageLane;
+use horizon_event_system::{GorcObjectId, PlayerId, ShutdownState};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::net::UdpSocket;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+/// Datagram kind binding a session to the sender's address
+pub const DATAGRAM_BIND: u8 = 0x01;
+
+/// Datagram kind acknowledging a bind
+pub const DATAGRAM_BOUND: u8 = 0x02;
+
+/// Datagram kind carrying a replication update
+pub const DATAGRAM_UPDATE: u8 = 0x03;
+
+/// Bytes in front of the payload of an update datagram
+pub const UPDATE_HEADER_LEN: usize = 1 + 8 + 16;
+
+/// Largest datagram sent, small enough to avoid IP fragmentation on common paths
+pub const MAX_DATAGRAM_LEN: usize = 1200;
+
+/// A client's UDP session
+#[derive(Debug)]
+struct UdpSession {
+    /// Secret the client proves ownership of the session with
+    token: Uuid,
+    /// Address the client bound, once it has
+    address: Option<SocketAddr>,
+    /// Sequence number of the last update sent
+    sequence: u64,
+}
+
+/// Sessions by player, with the token index used by binds
+#[derive(Debug, Default)]
+struct Sessions {
+    by_player: HashMap<PlayerId, UdpSession>,
+    by_token: HashMap<Uuid, PlayerId>,
+}
+
+/// Server side of the UDP replication transport.
+///
+/// Created unconditionally by the server; until a socket is attached, no
+/// sessions can be opened and every update falls back to WebSocket.
+#[derive(Debug, Default)]
+pub struct UdpTransport {
+    /// Socket updates are sent from and binds are received on
+    socket: OnceLock<Arc<UdpSocket>>,
+    sessions: Mutex<Sessions>,
+}
+
+/// A decoded update datagram
+#[derive(Debug, Clone, PartialEq)]
+pub struct UdpUpdate<'a> {
+    /// Position of the update in the player's datagram stream
+    pub sequence: u64,
+    /// Object the update is for
+    pub object_id: GorcObjectId,
+    /// GORC message in the player's wire format
+    pub payload: &'a [u8],
+}
+
+impl UdpTransport {
+    /// Creates a transport without a socket
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches the socket the transport sends and receives on.
+    ///
+    /// # Returns
+    ///
+    /// `false` if a socket was already attached.
+    pub fn attach(&self, socket: Arc<UdpSocket>) -> bool {
+        self.socket.set(socket).is_ok()
+    }
+
+    /// Gets the local address of the attached socket
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.socket.get()?.local_addr().ok()
+    }
+
+    /// Checks whether a replication channel is sent over UDP when possible
+    pub fn carries_channel(channel: u8) -> bool {
+        MessageLane::for_channel(channel) == MessageLane::Critical
+    }
+
+    /// Opens a session for a player, or returns the one already open.
+    ///
+    /// # Returns
+    ///
+    /// The token the client binds with, or `None` if no socket is attached.
+    pub fn open_session(&self, player_id: PlayerId) -> Option<Uuid> {
+        self.socket.get()?;
+        let mut sessions = self.lock_sessions();
+        if let Some(session) = sessions.by_player.get(&player_id) {
+            return Some(session.token);
+        }
+
+        let token = Uuid::new_v4();
+        sessions.by_player.insert(player_id, UdpSession { token, address: None, sequence: 0 });
+        sessions.by_token.insert(token, player_id);
+        Some(token)
+    }
+
+    /// Closes a player's session so further updates go over WebSocket
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player had a session.
+    pub fn close_session(&self, player_id: PlayerId) -> bool {
+        let mut sessions = self.lock_sessions();
+        match sessions.by_player.remove(&player_id) {
+            Some(session) => {
+                sessions.by_token.remove(&session.token);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Gets the address a player's session is bound to
+    pub fn bound_address(&self, player_id: PlayerId) -> Option<SocketAddr> {
+        self.lock_sessions().by_player.get(&player_id)?.address
+    }
+
+    /// Handles a datagram received from a client.
+    ///
+    /// # Returns
+    ///
+    /// The datagram to answer with, if any. Unknown tokens and malformed
+    /// datagrams are ignored so the socket can't be used to probe sessions.
+    pub fn handle_datagram(&self, datagram: &[u8], from: SocketAddr) -> Option<Vec<u8>> {
+        let (&kind, body) = datagram.split_first()?;
+        if kind != DATAGRAM_BIND {
+            return None;
+        }
+        let token = Uuid::from_slice(body).ok()?;
+
+        let mut sessions = self.lock_sessions();
+        let player_id = *sessions.by_token.get(&token)?;
+        let session = sessions.by_player.get_mut(&player_id)?;
+        if session.address != Some(from) {
+            info!("📶 Player {} bound UDP session to {}", player_id, from);
+            session.address = Some(from);
+        }
+        Some(vec![DATAGRAM_BOUND])
+    }
+
+    /// Sends a replication update to a player over UDP.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the update was sent. `false` means the caller should send
+    /// it over WebSocket instead: the player has no bound session, the
+    /// update doesn't fit a datagram, or sending failed.
+    pub async fn send_update(&self, player_id: PlayerId, object_id: GorcObjectId, payload: &[u8]) -> bool {
+        let Some(socket) = self.socket.get() else {
+            return false;
+        };
+        if UPDATE_HEADER_LEN + payload.len() > MAX_DATAGRAM_LEN {
+            return false;
+        }
+
+        let (address, datagram) = {
+            let mut sessions = self.lock_sessions();
+            let Some(session) = sessions.by_player.get_mut(&player_id) else {
+                return false;
+            };
+            let Some(address) = session.address else {
+                return false;
+            };
+            session.sequence += 1;
+            (address, encode_update(session.sequence, object_id, payload))
+        };
+
+        match socket.send_to(&datagram, address).await {
+            Ok(_) => true,
+            Err(e) => {
+                debug!("📶 UDP update to player {} at {} failed: {}", player_id, address, e);
+                false
+            }
+        }
+    }
+
+    fn lock_sessions(&self) -> std::sync::MutexGuard<'_, Sessions> {
+        self.sessions.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Encodes an update datagram
+pub fn encode_update(sequence: u64, object_id: GorcObjectId, payload: &[u8]) -> Vec<u8> {
+    let mut datagram = Vec::with_capacity(UPDATE_HEADER_LEN + payload.len());
+    datagram.push(DATAGRAM_UPDATE);
+    datagram.extend_from_slice(&sequence.to_be_bytes());
+    datagram.extend_from_slice(object_id.0.as_bytes());
+    datagram.extend_from_slice(payload);
+    datagram
+}
+
+/// Decodes an update datagram, returning `None` for any other datagram
+pub fn decode_update(datagram: &[u8]) -> Option<UdpUpdate<'_>> {
+    if datagram.len() < UPDATE_HEADER_LEN || datagram[0] != DATAGRAM_UPDATE {
+        return None;
+    }
+    let sequence = u64::from_be_bytes(datagram[1..9].try_into().ok()?);
+    let object_id = GorcObjectId(Uuid::from_slice(&datagram[9..UPDATE_HEADER_LEN]).ok()?);
+    Some(UdpUpdate {
+        sequence,
+        object_id,
+        payload: &datagram[UPDATE_HEADER_LEN..],
+    })
+}
+
+/// Last-writer-wins filter for received updates.
+///
+/// Remembers the newest sequence number applied per object and rejects
+/// updates that are older or duplicated.
+#[derive(Debug, Default)]
+pub struct LatestWins {
+    applied: HashMap<GorcObjectId, u64>,
+}
+
+impl LatestWins {
+    /// Creates an empty filter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks whether an update should be applied, recording it if so
+    pub fn accept(&mut self, update: &UdpUpdate<'_>) -> bool {
+        match self.applied.get(&update.object_id) {
+            Some(&applied) if applied >= update.sequence => false,
+            _ => {
+                self.applied.insert(update.object_id, update.sequence);
+                true
+            }
+        }
+    }
+
+    /// Forgets an object, e.g. after it left the client's interest
+    pub fn forget(&mut self, object_id: GorcObjectId) {
+        self.applied.remove(&object_id);
+    }
+}
+
+/// Answers session binds on the transport's socket until shutdown.
+///
+/// # Arguments
+///
+/// * `transport` - Transport with an attached socket
+/// * `shutdown_state` - Optional shutdown state that stops the receive loop
+pub async fn serve_udp(transport: Arc<UdpTransport>, shutdown_state: Option<ShutdownState>) {
+    let Some(socket) = transport.socket.get().cloned() else {
+        warn!("📶 UDP transport has no socket to serve");
+        return;
+    };
+
+    let mut buffer = [0u8; MAX_DATAGRAM_LEN];
+    loop {
+        if shutdown_state.as_ref().is_some_and(|state| state.is_shutdown_initiated()) {
+            break;
+        }
+
+        let (len, from) = match socket.recv_from(&mut buffer).await {
+            Ok(received) => received,
+            Err(e) => {
+                // Errors such as ICMP port unreachable only affect one peer
+                debug!("📶 UDP receive failed: {}", e);
+                continue;
+            }
+        };
+        if let Some(reply) = transport.handle_datagram(&buffer[..len], from) {
+            if let Err(e) = socket.send_to(&reply, from).await {
+                debug!("📶 UDP bind reply to {} failed: {}", from, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn bound_transport() -> (Arc<UdpTransport>, UdpSocket, PlayerId) {
+        let transport = Arc::new(UdpTransport::new());
+        let player_id = PlayerId::new();
+        assert_eq!(transport.open_session(player_id), None);
+
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        assert!(transport.attach(Arc::new(socket)));
+        tokio::spawn(serve_udp(transport.clone(), None));
+
+        let token = transport.open_session(player_id).unwrap();
+        assert_eq!(transport.open_session(player_id), Some(token));
+
+        let client = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let mut bind = vec![DATAGRAM_BIND];
+        bind.extend_from_slice(token.as_bytes());
+        client.send_to(&bind, transport.local_addr().unwrap()).await.unwrap();
+
+        let mut reply = [0u8; 16];
+        let len = client.recv(&mut reply).await.unwrap();
+        assert_eq!(&reply[..len], &[DATAGRAM_BOUND]);
+        assert_eq!(transport.bound_address(player_id), Some(client.local_addr().unwrap()));
+        (transport, client, player_id)
+    }
+
+    #[tokio::test]
+    async fn test_updates_reach_bound_session() {
+        let (transport, client, player_id) = bound_transport().await;
+        let object_id = GorcObjectId::new();
+
+        assert!(transport.send_update(player_id, object_id, b"first").await);
+        assert!(transport.send_update(player_id, object_id, b"second").await);
+        assert!(!transport.send_update(player_id, object_id, &[0; MAX_DATAGRAM_LEN]).await);
+        assert!(!transport.send_update(PlayerId::new(), object_id, b"unknown").await);
+
+        let mut buffer = [0u8; MAX_DATAGRAM_LEN];
+        let mut received = Vec::new();
+        for _ in 0..2 {
+            let len = client.recv(&mut buffer).await.unwrap();
+            let update = decode_update(&buffer[..len]).unwrap();
+            assert_eq!(update.object_id, object_id);
+            received.push((update.sequence, update.payload.to_vec()));
+        }
+        assert_eq!(received, vec![(1, b"first".to_vec()), (2, b"second".to_vec())]);
+
+        assert!(transport.close_session(player_id));
+        assert!(!transport.send_update(player_id, object_id, b"closed").await);
+    }
+
+    #[test]
+    fn test_binds_need_a_known_token() {
+        let transport = UdpTransport::new();
+        let from: SocketAddr = "127.0.0.1:4000".parse().unwrap();
+        let mut bind = vec![DATAGRAM_BIND];
+        bind.extend_from_slice(Uuid::new_v4().as_bytes());
+
+        assert_eq!(transport.handle_datagram(&bind, from), None);
+        assert_eq!(transport.handle_datagram(&[DATAGRAM_BIND, 1, 2], from), None);
+        assert_eq!(transport.handle_datagram(&[], from), None);
+    }
+
+    #[test]
+    fn test_latest_update_wins() {
+        let (first, second) = (GorcObjectId::new(), GorcObjectId::new());
+        let datagrams = [
+            encode_update(2, first, b"a"),
+            encode_update(1, first, b"stale"),
+            encode_update(2, first, b"duplicate"),
+            encode_update(1, second, b"b"),
+            encode_update(3, first, b"c"),
+        ];
+
+        let mut filter = LatestWins::new();
+        let applied: Vec<&[u8]> = datagrams
+            .iter()
+            .filter_map(|datagram| decode_update(datagram))
+            .filter(|update| filter.accept(update))
+            .map(|update| update.payload)
+            .collect();
+        assert_eq!(applied, vec![&b"a"[..], b"b", b"c"]);
+
+        filter.forget(first);
+        assert!(filter.accept(&decode_update(&encode_update(1, first, b"")).unwrap()));
+        assert_eq!(decode_update(&[DATAGRAM_BOUND]), None);
+    }
+}
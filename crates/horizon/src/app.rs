@@ -7,6 +7,7 @@
 use crate::{cli::CliArgs, config::AppConfig, logging::display_banner, signals::{setup_signal_handlers, setup_signal_handlers_silent}};
 use horizon_event_system::ShutdownState;
 use game_server::GameServer;
+use std::sync::Arc;
 use tracing::{error, info, warn};
 
 /// Main application struct with enhanced monitoring capabilities.
@@ -147,9 +148,12 @@ impl Application {
         // Get plugin manager reference before moving server
         let plugin_manager = self.server.get_plugin_manager();
 
+        // Answer health probes and metrics scrapes while the server starts up
+        let server = Arc::new(self.server);
+        server.start_health_endpoint(Some(shutdown_state.clone())).await?;
+
         // Start server in background with enhanced error handling
         let server_handle = {
-            let server = self.server;
             tokio::spawn(async move {
                 match server.start_with_shutdown_state(shutdown_state_for_server).await {
                     Ok(()) => {
@@ -203,8 +207,11 @@ impl Application {
         info!("🔍 Health monitoring active - stats every 60 seconds");
         info!("🛑 Press Ctrl+C to gracefully shutdown");
 
-        // Wait for shutdown signal - this will update the shared shutdown state
-        let signal_shutdown_state = setup_signal_handlers().await?;
+        // Wait for a shutdown signal, or for the management API to initiate shutdown
+        let signal_shutdown_state = tokio::select! {
+            signal_shutdown_state = setup_signal_handlers() => Some(signal_shutdown_state?),
+            _ = shutdown_state.initiated() => None,
+        };
 
         // merciless shutdown
         tokio::spawn(async move {
@@ -218,7 +225,7 @@ impl Application {
         });
         
         // Transfer shutdown state to our server's shutdown state
-        if signal_shutdown_state.is_shutdown_initiated() {
+        if signal_shutdown_state.is_some_and(|state| state.is_shutdown_initiated()) {
             shutdown_state.initiate_shutdown();
         }
 
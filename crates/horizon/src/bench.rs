@@ -0,0 +1,150 @@
+//! Spatial index benchmark for the `horizon bench spatial` subcommand.
+//!
+//! Times radius queries against every GORC spatial index implementation using
+//! the server's own object layout, so operators can choose `gorc.spatial.index`
+//! from measurements instead of guesswork. Object positions come from the
+//! world snapshot when one exists; otherwise the configured region is filled
+//! uniformly with `gorc.general.max_objects` objects.
+
+use crate::config::AppConfig;
+use horizon_event_system::gorc::{SpatialBenchResult, SpatialBenchmark, WorldSnapshot};
+use horizon_event_system::Vec3;
+use std::path::PathBuf;
+
+/// Seed for uniform fills, fixed so repeated runs index the same positions
+const UNIFORM_SEED: u64 = 0x0048_6f72_697a_6f6e;
+
+/// Options for a spatial index benchmark run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialBenchOptions {
+    /// Number of uniformly spread objects; overrides the snapshot
+    pub objects: Option<usize>,
+    /// Radius of each query
+    pub radius: f64,
+    /// Number of queries per index implementation
+    pub queries: usize,
+    /// Snapshot to read positions from instead of the configured one
+    pub snapshot: Option<PathBuf>,
+}
+
+impl SpatialBenchOptions {
+    /// Builds the benchmark described by these options.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Server configuration providing region bounds, snapshot path and index tuning
+    ///
+    /// # Returns
+    ///
+    /// The benchmark and a description of where its positions came from.
+    pub fn build(&self, config: &AppConfig) -> Result<(SpatialBenchmark, String), Box<dyn std::error::Error>> {
+        let region = &config.server.region;
+        let min = Vec3::new(region.min_x, region.min_y, region.min_z);
+        let max = Vec3::new(region.max_x, region.max_y, region.max_z);
+
+        let snapshot_path = self.snapshot.clone().or_else(|| {
+            let path = PathBuf::from(&config.gorc.snapshot.path);
+            (config.gorc.snapshot.enabled && path.exists()).then_some(path)
+        });
+
+        let (benchmark, source) = match (self.objects, snapshot_path) {
+            (None, Some(path)) => {
+                let snapshot = WorldSnapshot::load(&path)?;
+                (
+                    SpatialBenchmark::from_snapshot(&snapshot, min, max),
+                    format!("world snapshot {}", path.display()),
+                )
+            }
+            (objects, _) => {
+                let count = objects.unwrap_or(config.gorc.general.max_objects);
+                (
+                    SpatialBenchmark::uniform(count, min, max, UNIFORM_SEED),
+                    "uniform fill of the server region".to_string(),
+                )
+            }
+        };
+
+        let benchmark = benchmark
+            .with_query_radius(self.radius)
+            .with_queries(self.queries)
+            .with_index_config(config.spatial_index_config());
+        Ok((benchmark, source))
+    }
+}
+
+/// Runs the benchmark and prints a comparison table.
+///
+/// # Arguments
+///
+/// * `config` - Server configuration
+/// * `options` - Benchmark options from the command line
+pub fn run_spatial_bench(config: &AppConfig, options: &SpatialBenchOptions) -> Result<(), Box<dyn std::error::Error>> {
+    let (benchmark, source) = options.build(config)?;
+    println!(
+        "📐 Benchmarking {} objects from the {} ({} queries, radius {})",
+        benchmark.object_count(),
+        source,
+        options.queries,
+        options.radius
+    );
+
+    let results = benchmark.run();
+    println!("{:<12} {:>12} {:>12} {:>12} {:>10}", "index", "build (ms)", "avg (µs)", "p99 (µs)", "avg hits");
+    for result in &results {
+        println!(
+            "{:<12} {:>12.2} {:>12.2} {:>12.2} {:>10.1}",
+            result.kind,
+            result.build_time.as_secs_f64() * 1_000.0,
+            result.avg_query_us,
+            result.p99_query_us,
+            result.avg_hits
+        );
+    }
+
+    if let Some(fastest) = fastest(&results) {
+        let current = config.gorc.spatial.index;
+        if fastest.kind == current {
+            println!("✅ The configured index ({current}) is the fastest for this world");
+        } else {
+            println!("💡 Set gorc.spatial.index = \"{}\" for the lowest query latency (currently {current})", fastest.kind);
+        }
+    }
+    Ok(())
+}
+
+/// Picks the implementation with the lowest mean query latency.
+fn fastest(results: &[SpatialBenchResult]) -> Option<&SpatialBenchResult> {
+    results.iter().min_by(|a, b| a.avg_query_us.total_cmp(&b.avg_query_us))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_fill_uses_configured_capacity() {
+        let mut config = AppConfig::default();
+        config.gorc.general.max_objects = 250;
+        config.gorc.snapshot.enabled = false;
+
+        let options = SpatialBenchOptions { objects: None, radius: 50.0, queries: 20, snapshot: None };
+        let (benchmark, source) = options.build(&config).unwrap();
+        assert_eq!(benchmark.object_count(), 250);
+        assert!(source.contains("uniform"));
+
+        let results = benchmark.run();
+        assert_eq!(results.len(), 3);
+        assert!(fastest(&results).is_some());
+    }
+
+    #[test]
+    fn test_missing_snapshot_is_an_error() {
+        let options = SpatialBenchOptions {
+            objects: None,
+            radius: 50.0,
+            queries: 20,
+            snapshot: Some(PathBuf::from("does/not/exist.json")),
+        };
+        assert!(options.build(&AppConfig::default()).is_err());
+    }
+}
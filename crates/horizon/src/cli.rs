@@ -23,12 +23,39 @@ pub struct CliArgs {
     pub log_level: Option<String>,
     /// Whether to force JSON log output
     pub json_logs: bool,
-    /// Whether to allow plugins with different Rust compiler versions (DANGEROUS)
+    /// Whether to allow plugins with different Rust compiler versions or missing/invalid signatures (DANGEROUS)
     pub danger_allow_unsafe_plugins: bool,
     /// Whether to allow plugins with different ABI versions (DANGEROUS)
     pub danger_allow_abi_mismatch: bool,
     /// Whether to require exact version matching including patch digits
     pub strict_versioning: bool,
+    /// Subcommand to run instead of starting the server
+    pub command: Option<CliCommand>,
+}
+
+/// Subcommands that run a one-off task instead of starting the server.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliCommand {
+    /// `horizon plugin new <NAME>` - generate a new plugin crate
+    NewPlugin {
+        /// Name of the plugin to create
+        name: String,
+        /// Directory in which the plugin crate is created
+        directory: PathBuf,
+        /// Optional local path to `horizon_event_system` instead of a version pin
+        event_system_path: Option<PathBuf>,
+    },
+    /// `horizon bench spatial` - compare spatial index query latency
+    BenchSpatial {
+        /// Number of uniformly spread objects when no snapshot is used
+        objects: Option<usize>,
+        /// Radius of each benchmark query
+        radius: f64,
+        /// Number of queries per index implementation
+        queries: usize,
+        /// World snapshot to take object positions from
+        snapshot: Option<PathBuf>,
+    },
 }
 
 impl CliArgs {
@@ -88,7 +115,7 @@ impl CliArgs {
             .arg(
                 Arg::new("danger-allow-unsafe-plugins")
                     .long("danger-allow-unsafe-plugins")
-                    .help("Allow loading plugins compiled with different Rust compiler versions (MAY CAUSE CRASHES)")
+                    .help("Allow loading plugins compiled with different Rust compiler versions or with missing/invalid signatures (MAY CAUSE CRASHES)")
                     .action(clap::ArgAction::SetTrue),
             )
             .arg(
@@ -103,8 +130,112 @@ impl CliArgs {
                     .help("Require exact version matching including patch digits (default: only major.minor must match)")
                     .action(clap::ArgAction::SetTrue),
             )
+            .subcommand(
+                Command::new("plugin")
+                    .about("Plugin development tools")
+                    .subcommand_required(true)
+                    .subcommand(
+                        Command::new("new")
+                            .about("Generate a new plugin crate")
+                            .arg(
+                                Arg::new("name")
+                                    .value_name("NAME")
+                                    .help("Plugin name (e.g. inventory)")
+                                    .required(true),
+                            )
+                            .arg(
+                                Arg::new("dir")
+                                    .short('d')
+                                    .long("dir")
+                                    .value_name("DIR")
+                                    .help("Directory to create the plugin crate in")
+                                    .default_value("."),
+                            )
+                            .arg(
+                                Arg::new("event-system-path")
+                                    .long("event-system-path")
+                                    .value_name("DIR")
+                                    .help("Depend on a local horizon_event_system checkout instead of the pinned version"),
+                            ),
+                    ),
+            )
+            .subcommand(
+                Command::new("bench")
+                    .about("Performance benchmarks")
+                    .subcommand_required(true)
+                    .subcommand(
+                        Command::new("spatial")
+                            .about("Compare spatial index query latency for the configured world")
+                            .arg(
+                                Arg::new("objects")
+                                    .short('n')
+                                    .long("objects")
+                                    .value_name("COUNT")
+                                    .value_parser(clap::value_parser!(usize))
+                                    .help("Benchmark this many uniformly spread objects instead of the world snapshot"),
+                            )
+                            .arg(
+                                Arg::new("radius")
+                                    .short('r')
+                                    .long("radius")
+                                    .value_name("METERS")
+                                    .value_parser(clap::value_parser!(f64))
+                                    .help("Query radius")
+                                    .default_value("100"),
+                            )
+                            .arg(
+                                Arg::new("queries")
+                                    .short('q')
+                                    .long("queries")
+                                    .value_name("COUNT")
+                                    .value_parser(clap::value_parser!(usize))
+                                    .help("Queries per index implementation")
+                                    .default_value("10000"),
+                            )
+                            .arg(
+                                Arg::new("snapshot")
+                                    .long("snapshot")
+                                    .value_name("FILE")
+                                    .help("World snapshot to read object positions from (default: the configured snapshot path)"),
+                            ),
+                    ),
+            )
             .get_matches();
 
+        let command = match matches.subcommand() {
+            Some(("plugin", plugin_matches)) => match plugin_matches.subcommand() {
+                Some(("new", new_matches)) => Some(CliCommand::NewPlugin {
+                    name: new_matches
+                        .get_one::<String>("name")
+                        .expect("Plugin name is required")
+                        .clone(),
+                    directory: PathBuf::from(
+                        new_matches
+                            .get_one::<String>("dir")
+                            .expect("Default plugin directory should always be set"),
+                    ),
+                    event_system_path: new_matches
+                        .get_one::<String>("event-system-path")
+                        .map(PathBuf::from),
+                }),
+                _ => None,
+            },
+            Some(("bench", bench_matches)) => match bench_matches.subcommand() {
+                Some(("spatial", spatial_matches)) => Some(CliCommand::BenchSpatial {
+                    objects: spatial_matches.get_one::<usize>("objects").copied(),
+                    radius: *spatial_matches
+                        .get_one::<f64>("radius")
+                        .expect("Default query radius should always be set"),
+                    queries: *spatial_matches
+                        .get_one::<usize>("queries")
+                        .expect("Default query count should always be set"),
+                    snapshot: spatial_matches.get_one::<String>("snapshot").map(PathBuf::from),
+                }),
+                _ => None,
+            },
+            _ => None,
+        };
+
         Self {
             config_path: PathBuf::from(
                 matches
@@ -118,6 +249,7 @@ impl CliArgs {
             danger_allow_unsafe_plugins: matches.get_flag("danger-allow-unsafe-plugins"),
             danger_allow_abi_mismatch: matches.get_flag("danger-allow-abi-mismatch"),
             strict_versioning: matches.get_flag("strict-versioning"),
+            command,
         }
     }
 
@@ -134,6 +266,7 @@ impl CliArgs {
             allow_unsafe_plugins: self.danger_allow_unsafe_plugins,
             allow_abi_mismatch: self.danger_allow_abi_mismatch,
             strict_versioning: self.strict_versioning,
+            ..Default::default()
         }
     }
 }
\ No newline at end of file
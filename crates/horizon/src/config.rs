@@ -3,11 +3,16 @@
 //! This module handles loading, validation, and conversion of server configuration
 //! from TOML files and command-line arguments.
 
-use horizon_event_system::RegionBounds;
-use horizon_event_system::gorc::{VirtualizationConfig, GorcServerConfig};
-use game_server::ServerConfig;
+use horizon_event_system::{EmissionQueueConfig, HandlerSpanConfig, RegionBounds, SpatialIndexConfig, SpatialIndexKind};
+use horizon_event_system::gorc::{VirtualizationConfig, VirtualizationStrategyKind, GorcServerConfig, DEFAULT_OBJECT_SHARDS};
+use game_server::health::circuit_breaker::CircuitBreakerConfig;
+use game_server::security::content_filter::ChatFilter;
+use game_server::{
+    AlertWebhookFormat, AlertingConfig, AuditLogConfig, AuthConfig, ConnectionThrottleConfig, ContentFilterConfig, FederationConfig, GeoIpConfig, HeartbeatConfig, ListenerConfig, MessageLaneConfig, NeighborConfig, OutboundQueueConfig, ProtocolConfig, ProxyProtocolConfig, QuicConfig, S2sConfig, SecurityConfig, ServerConfig, SnapshotConfig, TlsConfig,
+};
 use plugin_system::PluginSafetyConfig;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use tracing::info;
 
@@ -48,12 +53,90 @@ pub struct ServerSettings {
     /// Connection timeout in seconds
     #[serde(default = "default_connection_timeout")]
     pub connection_timeout: u64,
+    /// Seconds a dropped client can resume its player with its session token (0 disables resumption)
+    #[serde(default)]
+    pub session_resume_window_secs: u64,
+    /// WebSocket pings measuring round-trip time; clients missing max_missed in a row are disconnected
+    #[serde(default)]
+    pub heartbeat: HeartbeatConfig,
+    /// Backlog at which slow clients lose cosmetic updates and then their connection
+    #[serde(default)]
+    pub outbound_queue: OutboundQueueConfig,
+    /// Global rate of new connections and cap on connections still handshaking
+    #[serde(default)]
+    pub connection_throttle: ConnectionThrottleConfig,
+    /// Protocol version clients must offer and the oldest client version allowed
+    #[serde(default)]
+    pub protocol: ProtocolConfig,
+    /// Authenticated channel other Horizon servers exchange s2s:* events over
+    #[serde(default)]
+    pub s2s: S2sConfig,
+    /// Word lists and rules the chat filter plugins share applies
+    #[serde(default)]
+    pub content_filter: ContentFilterConfig,
+    /// File bans, kicks, rate-limit trips and admin calls are logged to, and its rotation
+    #[serde(default)]
+    pub audit_log: AuditLogConfig,
+    /// GeoIP database with the countries clients may connect from and per-region caps
+    #[serde(default)]
+    pub geoip: GeoIpConfig,
+    /// Webhooks posted to when rate-limit storms, ban evasion or oversized messages cross their thresholds
+    #[serde(default)]
+    pub alerting: AlertingConfig,
+    /// JWT keys clients authenticate with and the namespaces open before they do
+    #[serde(default)]
+    pub auth: AuthConfig,
     /// Whether to use SO_REUSEPORT for multi-threaded accept loops (Linux only)
     #[serde(default)]
     pub use_reuse_port: bool,
+    /// Accept loops per listener (0 uses one per CPU core with use_reuse_port, else one)
+    #[serde(default)]
+    pub accept_threads: usize,
+    /// Further addresses to accept clients on next to bind_address, such as a LAN or IPv6 endpoint
+    #[serde(default)]
+    pub listeners: Vec<ListenerConfig>,
     /// Server tick interval in milliseconds (0 to disable)
     #[serde(default = "default_tick_interval")]
     pub tick_interval_ms: u64,
+    /// Address of the admin HTTP endpoint serving live zone state and the admin API (None disables it)
+    #[serde(default)]
+    pub admin_address: Option<String>,
+    /// Bearer token the admin API requires (None disables the /admin/ routes)
+    #[serde(default)]
+    pub admin_token: Option<String>,
+    /// Address of the gRPC management API for orchestration tooling, authorized by the admin token (None disables it)
+    #[serde(default)]
+    pub grpc_address: Option<String>,
+    /// Address serving /healthz, /readyz and /metrics for probes and Prometheus (None disables it)
+    #[serde(default)]
+    pub health_address: Option<String>,
+    /// Address of the UDP endpoint for channel 0 replication (None keeps all traffic on WebSocket)
+    #[serde(default)]
+    pub udp_address: Option<String>,
+    /// QUIC listener alongside the WebSocket server (disabled without a listen_address)
+    #[serde(default)]
+    pub quic: QuicConfig,
+    /// TLS termination for WebSocket clients (wss://) with SNI certificate selection
+    #[serde(default)]
+    pub tls: TlsConfig,
+    /// PROXY protocol headers from load balancers naming the real client address
+    #[serde(default)]
+    pub proxy_protocol: ProxyProtocolConfig,
+    /// Bounds and overflow policy of the per-category event emission queues
+    #[serde(default)]
+    pub event_queue: EmissionQueueConfig,
+    /// Tracing spans around event handler invocations
+    #[serde(default)]
+    pub handler_spans: HandlerSpanConfig,
+    /// Priority lanes incoming client messages are routed through
+    #[serde(default)]
+    pub message_lanes: MessageLaneConfig,
+    /// Input validation, connection limits and per-namespace message rate limits
+    #[serde(default)]
+    pub security: SecurityConfig,
+    /// Circuit breakers around each plugin's handler for an event key
+    #[serde(default)]
+    pub plugin_circuit_breaker: CircuitBreakerConfig,
 }
 
 /// Default for connection_timeout
@@ -72,6 +155,9 @@ fn default_max_players() -> usize { 1000 }
 fn default_max_channels_per_object() -> u8 { 8 }
 fn default_auto_optimize_zones() -> bool { true }
 fn default_optimization_interval_ms() -> u64 { 5000 }
+fn default_zone_exit_margin() -> f64 { 0.1 }
+fn default_dead_reckoning_horizon_ms() -> u64 { 200 }
+fn default_object_shards() -> usize { DEFAULT_OBJECT_SHARDS }
 
 fn default_virtualization_enabled() -> bool { true }
 fn default_density_threshold() -> f64 { 0.3 }
@@ -80,14 +166,19 @@ fn default_max_virtual_zone_radius() -> f64 { 1000.0 }
 fn default_min_zone_radius() -> f64 { 50.0 }
 fn default_check_interval_ms() -> u64 { 1000 }
 fn default_max_objects_per_virtual_zone() -> usize { 20 }
+fn default_virtualization_grid_cell_size() -> f64 { 500.0 }
+fn default_min_players_per_virtual_zone() -> usize { 10 }
 
 fn default_world_bounds() -> (f64, f64, f64, f64, f64, f64) {
     (-10000.0, -10000.0, -1000.0, 10000.0, 10000.0, 1000.0)
 }
 fn default_max_objects_per_leaf() -> usize { 64 }
 fn default_rebuild_threshold() -> usize { 5_000 }
+fn default_maintenance_interval_ms() -> u64 { 1_000 }
+fn default_low_load_mutations() -> usize { 256 }
 fn default_enable_caching() -> bool { true }
 fn default_cache_expiry_ms() -> u64 { 30000 }
+fn default_grid_cell_size() -> f64 { 100.0 }
 
 fn default_max_batch_size() -> usize { 1000 }
 fn default_channel_frequencies() -> [f64; 4] { [60.0, 30.0, 15.0, 5.0] }
@@ -104,6 +195,14 @@ fn default_track_memory_usage() -> bool { true }
 fn default_slow_operation_threshold_us() -> u64 { 1000 }
 fn default_enable_performance_alerts() -> bool { true }
 
+fn default_snapshot_path() -> String { "data/gorc_snapshot.json".to_string() }
+fn default_autosave_interval_secs() -> u64 { 300 }
+fn default_restore_on_start() -> bool { true }
+
+fn default_region_name() -> String { "default".to_string() }
+fn default_overlap_band() -> f64 { 50.0 }
+fn default_federation_scan_interval_ms() -> u64 { 200 }
+
 /// Spatial region boundary configuration.
 /// 
 /// Defines the 3D coordinate space that this server instance manages.
@@ -124,6 +223,20 @@ pub struct RegionSettings {
     pub max_z: f64,
 }
 
+impl RegionSettings {
+    /// Converts the settings into region bounds
+    pub fn bounds(&self) -> RegionBounds {
+        RegionBounds {
+            min_x: self.min_x,
+            max_x: self.max_x,
+            min_y: self.min_y,
+            max_y: self.max_y,
+            min_z: self.min_z,
+            max_z: self.max_z,
+        }
+    }
+}
+
 /// Plugin system configuration.
 /// 
 /// Controls plugin loading behavior, directory locations, and security settings.
@@ -134,7 +247,30 @@ pub struct PluginSettings {
     /// Whether to automatically load all plugins on startup
     pub auto_load: bool,
     /// Plugin whitelist - if non-empty, only these plugins will be loaded
+    ///
+    /// Enforced both at startup and for hot-loaded plugins. Earlier releases
+    /// ignored this list, so configs that carried a partial whitelist will now
+    /// skip the unlisted plugins (each one is logged at startup).
     pub whitelist: Vec<String>,
+    /// Require a valid Ed25519 signature (`<library>.sig`) for every plugin, and
+    /// one for its manifest (`<library>.plugin.toml.sig`) if it has one
+    #[serde(default)]
+    pub require_signatures: bool,
+    /// Base64-encoded Ed25519 public keys trusted to sign plugins
+    #[serde(default)]
+    pub trusted_keys: Vec<String>,
+    /// Require a manifest (`<library>.plugin.toml`) for every plugin
+    #[serde(default)]
+    pub require_manifest: bool,
+    /// Capabilities plugins may declare in their manifest (empty allows any)
+    #[serde(default)]
+    pub allowed_capabilities: Vec<String>,
+    /// Watch the plugin directory and load newly added plugins automatically
+    #[serde(default)]
+    pub watch: bool,
+    /// Plugins to initialize first, in order (e.g. auth and persistence before gameplay)
+    #[serde(default)]
+    pub load_order: Vec<String>,
 }
 
 /// Logging system configuration.
@@ -148,6 +284,26 @@ pub struct LoggingSettings {
     pub json_format: bool,
     /// Optional file path for log output (None means stdout only)
     pub file_path: Option<String>,
+    /// Per-plugin log routing, keyed by plugin name
+    #[serde(default)]
+    pub plugins: HashMap<String, PluginLogSettings>,
+}
+
+/// Log routing for a single plugin.
+///
+/// Messages a plugin logs through its server context or its `tracing` macros
+/// are filtered by `level` and, when `file_path` is set, written to that file
+/// instead of the main log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginLogSettings {
+    /// Log level filter for this plugin (trace, debug, info, warn, error)
+    pub level: String,
+    /// Optional file the plugin's logs are written to
+    #[serde(default)]
+    pub file_path: Option<String>,
+    /// Also write the plugin's logs to the main log when `file_path` is set
+    #[serde(default)]
+    pub propagate: bool,
 }
 
 /// GORC (Game Object Replication Channels) system configuration.
@@ -171,6 +327,66 @@ pub struct GorcSettings {
     /// Performance monitoring configuration
     #[serde(default)]
     pub monitoring: MonitoringSettings,
+    /// World snapshot persistence configuration
+    #[serde(default)]
+    pub snapshot: SnapshotSettings,
+    /// Cross-server region federation configuration
+    #[serde(default)]
+    pub federation: FederationSettings,
+}
+
+/// Cross-server region federation configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FederationSettings {
+    /// Name this region uses when talking to its neighbors
+    #[serde(default = "default_region_name")]
+    pub region_name: String,
+    /// Address to accept neighbor connections on
+    #[serde(default)]
+    pub listen_address: Option<String>,
+    /// Neighboring region servers (empty disables federation)
+    #[serde(default)]
+    pub neighbors: Vec<NeighborSettings>,
+    /// Width of the border band mirrored to neighbors as ghost copies
+    #[serde(default = "default_overlap_band")]
+    pub overlap_band: f64,
+    /// Milliseconds between federation scans
+    #[serde(default = "default_federation_scan_interval_ms")]
+    pub scan_interval_ms: u64,
+}
+
+/// A neighboring region server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeighborSettings {
+    /// Name the neighbor uses for itself
+    pub name: String,
+    /// Federation address of the neighbor
+    pub address: String,
+    /// Spatial bounds owned by the neighbor
+    pub region: RegionSettings,
+    /// Secret shared with the neighbor to authenticate federation connections
+    #[serde(default)]
+    pub secret: String,
+    /// WebSocket URL clients reconnect to when handed off to the neighbor
+    #[serde(default)]
+    pub client_url: Option<String>,
+}
+
+/// World snapshot persistence configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotSettings {
+    /// Whether world snapshots are saved and restored
+    #[serde(default)]
+    pub enabled: bool,
+    /// Snapshot file path
+    #[serde(default = "default_snapshot_path")]
+    pub path: String,
+    /// Seconds between autosaves (0 to disable autosave)
+    #[serde(default = "default_autosave_interval_secs")]
+    pub autosave_interval_secs: u64,
+    /// Restore the snapshot when the server starts
+    #[serde(default = "default_restore_on_start")]
+    pub restore_on_start: bool,
 }
 
 /// General GORC system configuration
@@ -191,6 +407,15 @@ pub struct GorcGeneralSettings {
     /// Frequency of zone optimization checks (in milliseconds)
     #[serde(default = "default_optimization_interval_ms")]
     pub optimization_interval_ms: u64,
+    /// Zone exit margin as a fraction of the zone radius (0.1 = exit at 1.1x)
+    #[serde(default = "default_zone_exit_margin")]
+    pub zone_exit_margin: f64,
+    /// How far ahead player positions are dead-reckoned for zone checks (in milliseconds, 0 to disable)
+    #[serde(default = "default_dead_reckoning_horizon_ms")]
+    pub dead_reckoning_horizon_ms: u64,
+    /// Number of locked shards objects are partitioned into (more shards allow more concurrent updates)
+    #[serde(default = "default_object_shards")]
+    pub object_shards: usize,
     /// Enable debug logging for GORC operations
     #[serde(default)]
     pub debug_logging: bool,
@@ -220,6 +445,15 @@ pub struct VirtualizationSettings {
     /// Maximum objects per virtual zone
     #[serde(default = "default_max_objects_per_virtual_zone")]
     pub max_objects_per_virtual_zone: usize,
+    /// Merge/split strategy: "density", "player_count" or "static_grid"
+    #[serde(default)]
+    pub strategy: VirtualizationStrategyKind,
+    /// Cell size used by the "static_grid" strategy
+    #[serde(default = "default_virtualization_grid_cell_size")]
+    pub grid_cell_size: f64,
+    /// Players needed near a cluster for the "player_count" strategy to merge it
+    #[serde(default = "default_min_players_per_virtual_zone")]
+    pub min_players_per_virtual_zone: usize,
 }
 
 /// Spatial indexing configuration
@@ -234,12 +468,24 @@ pub struct SpatialSettings {
     /// Number of mutations before triggering a bulk rebuild
     #[serde(default = "default_rebuild_threshold")]
     pub rebuild_threshold: usize,
+    /// Interval between background index maintenance passes in milliseconds (0 disables them)
+    #[serde(default = "default_maintenance_interval_ms")]
+    pub maintenance_interval_ms: u64,
+    /// Most mutations between maintenance passes for a tick to count as low-load
+    #[serde(default = "default_low_load_mutations")]
+    pub low_load_mutations: usize,
     /// Enable spatial index caching
     #[serde(default = "default_enable_caching")]
     pub enable_caching: bool,
     /// Cache expiry time in milliseconds
     #[serde(default = "default_cache_expiry_ms")]
     pub cache_expiry_ms: u64,
+    /// Spatial index implementation: "rtree", "octree" or "loose_grid"
+    #[serde(default)]
+    pub index: SpatialIndexKind,
+    /// Cell edge length when using the loose grid index
+    #[serde(default = "default_grid_cell_size")]
+    pub grid_cell_size: f64,
 }
 
 /// Network replication configuration
@@ -302,6 +548,31 @@ impl Default for GorcSettings {
             spatial: SpatialSettings::default(),
             network: NetworkSettings::default(),
             monitoring: MonitoringSettings::default(),
+            snapshot: SnapshotSettings::default(),
+            federation: FederationSettings::default(),
+        }
+    }
+}
+
+impl Default for FederationSettings {
+    fn default() -> Self {
+        Self {
+            region_name: default_region_name(),
+            listen_address: None,
+            neighbors: Vec::new(),
+            overlap_band: default_overlap_band(),
+            scan_interval_ms: default_federation_scan_interval_ms(),
+        }
+    }
+}
+
+impl Default for SnapshotSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_snapshot_path(),
+            autosave_interval_secs: default_autosave_interval_secs(),
+            restore_on_start: default_restore_on_start(),
         }
     }
 }
@@ -314,6 +585,9 @@ impl Default for GorcGeneralSettings {
             max_channels_per_object: default_max_channels_per_object(),
             auto_optimize_zones: default_auto_optimize_zones(),
             optimization_interval_ms: default_optimization_interval_ms(),
+            zone_exit_margin: default_zone_exit_margin(),
+            dead_reckoning_horizon_ms: default_dead_reckoning_horizon_ms(),
+            object_shards: default_object_shards(),
             debug_logging: false,
         }
     }
@@ -329,6 +603,9 @@ impl Default for VirtualizationSettings {
             min_zone_radius: default_min_zone_radius(),
             check_interval_ms: default_check_interval_ms(),
             max_objects_per_virtual_zone: default_max_objects_per_virtual_zone(),
+            strategy: VirtualizationStrategyKind::default(),
+            grid_cell_size: default_virtualization_grid_cell_size(),
+            min_players_per_virtual_zone: default_min_players_per_virtual_zone(),
         }
     }
 }
@@ -339,8 +616,12 @@ impl Default for SpatialSettings {
             world_bounds: default_world_bounds(),
             max_objects_per_leaf: default_max_objects_per_leaf(),
             rebuild_threshold: default_rebuild_threshold(),
+            maintenance_interval_ms: default_maintenance_interval_ms(),
+            low_load_mutations: default_low_load_mutations(),
             enable_caching: default_enable_caching(),
             cache_expiry_ms: default_cache_expiry_ms(),
+            index: SpatialIndexKind::default(),
+            grid_cell_size: default_grid_cell_size(),
         }
     }
 }
@@ -390,16 +671,49 @@ impl Default for AppConfig {
                 connection_timeout: 60,
                 use_reuse_port: false,
                 tick_interval_ms: 50,
+                admin_address: None,
+                admin_token: None,
+                grpc_address: None,
+                health_address: None,
+                session_resume_window_secs: 0,
+                heartbeat: Default::default(),
+                outbound_queue: Default::default(),
+                protocol: Default::default(),
+                s2s: Default::default(),
+                content_filter: Default::default(),
+                audit_log: Default::default(),
+                geoip: Default::default(),
+                alerting: Default::default(),
+                connection_throttle: Default::default(),
+                auth: Default::default(),
+                udp_address: None,
+                quic: Default::default(),
+                tls: Default::default(),
+                accept_threads: 0,
+                listeners: Vec::new(),
+                proxy_protocol: Default::default(),
+                event_queue: Default::default(),
+                handler_spans: Default::default(),
+                message_lanes: Default::default(),
+                security: Default::default(),
+                plugin_circuit_breaker: Default::default(),
             },
             plugins: PluginSettings {
                 directory: "plugins".to_string(),
                 auto_load: true,
                 whitelist: vec![],
+                require_signatures: false,
+                trusted_keys: vec![],
+                require_manifest: false,
+                allowed_capabilities: vec![],
+                watch: false,
+                load_order: vec![],
             },
             logging: LoggingSettings {
                 level: "info".to_string(),
                 json_format: false,
                 file_path: None,
+                plugins: HashMap::new(),
             },
             gorc: GorcSettings::default(),
         }
@@ -441,7 +755,8 @@ impl AppConfig {
     ///
     /// # Arguments
     ///
-    /// * `plugin_safety` - Plugin safety configuration from CLI arguments
+    /// * `plugin_safety` - Plugin safety configuration from CLI arguments. Signature
+    ///   settings from the `[plugins]` section are merged into it.
     ///
     /// # Returns
     ///
@@ -449,24 +764,92 @@ impl AppConfig {
     pub fn to_server_config(&self, plugin_safety: PluginSafetyConfig) -> Result<ServerConfig, Box<dyn std::error::Error>> {
         Ok(ServerConfig {
             bind_address: self.server.bind_address.parse()?,
-            region_bounds: RegionBounds {
-                min_x: self.server.region.min_x,
-                max_x: self.server.region.max_x,
-                min_y: self.server.region.min_y,
-                max_y: self.server.region.max_y,
-                min_z: self.server.region.min_z,
-                max_z: self.server.region.max_z,
-            },
+            region_bounds: self.server.region.bounds(),
             plugin_directory: PathBuf::from(&self.plugins.directory),
             max_connections: self.server.max_connections,
             connection_timeout: self.server.connection_timeout,
+            session_resume_window_secs: self.server.session_resume_window_secs,
+            heartbeat: self.server.heartbeat.clone(),
+            outbound_queue: self.server.outbound_queue.clone(),
+            protocol: self.server.protocol.clone(),
+            s2s: self.server.s2s.clone(),
+            content_filter: self.server.content_filter.clone(),
+            audit_log: self.server.audit_log.clone(),
+            geoip: self.server.geoip.clone(),
+            alerting: self.server.alerting.clone(),
+            connection_throttle: self.server.connection_throttle.clone(),
+            auth: self.server.auth.clone(),
             use_reuse_port: self.server.use_reuse_port,
+            accept_threads: self.server.accept_threads,
+            listeners: self.server.listeners.clone(),
             tick_interval_ms: self.server.tick_interval_ms,
-            security: Default::default(),
-            plugin_safety,
+            zone_exit_margin: self.gorc.general.zone_exit_margin,
+            dead_reckoning_horizon_ms: self.gorc.general.dead_reckoning_horizon_ms,
+            object_shards: self.gorc.general.object_shards,
+            spatial_index: self.spatial_index_config(),
+            gorc_network: self.to_gorc_config().network,
+            security: self.server.security.clone(),
+            snapshot: SnapshotConfig {
+                path: self.gorc.snapshot.enabled.then(|| PathBuf::from(&self.gorc.snapshot.path)),
+                autosave_interval_secs: self.gorc.snapshot.autosave_interval_secs,
+                restore_on_start: self.gorc.snapshot.restore_on_start,
+            },
+            federation: FederationConfig {
+                region_name: self.gorc.federation.region_name.clone(),
+                listen_address: self.gorc.federation.listen_address.as_deref().map(str::parse).transpose()?,
+                neighbors: self
+                    .gorc
+                    .federation
+                    .neighbors
+                    .iter()
+                    .map(|neighbor| {
+                        Ok(NeighborConfig {
+                            name: neighbor.name.clone(),
+                            address: neighbor.address.parse()?,
+                            bounds: neighbor.region.bounds(),
+                            secret: neighbor.secret.clone(),
+                            client_url: neighbor.client_url.clone(),
+                        })
+                    })
+                    .collect::<Result<_, std::net::AddrParseError>>()?,
+                overlap_band: self.gorc.federation.overlap_band,
+                scan_interval_ms: self.gorc.federation.scan_interval_ms,
+            },
+            admin_address: self.server.admin_address.as_deref().map(str::parse).transpose()?,
+            admin_token: self.server.admin_token.clone(),
+            grpc_address: self.server.grpc_address.as_deref().map(str::parse).transpose()?,
+            health_address: self.server.health_address.as_deref().map(str::parse).transpose()?,
+            udp_address: self.server.udp_address.as_deref().map(str::parse).transpose()?,
+            quic: self.server.quic.clone(),
+            tls: self.server.tls.clone(),
+            proxy_protocol: self.server.proxy_protocol.clone(),
+            event_queue: self.server.event_queue.clone(),
+            handler_spans: self.server.handler_spans.clone(),
+            message_lanes: self.server.message_lanes.clone(),
+            plugin_circuit_breaker: self.server.plugin_circuit_breaker.clone(),
+            plugin_safety: PluginSafetyConfig {
+                require_signatures: self.plugins.require_signatures,
+                trusted_keys: self.plugins.trusted_keys.clone(),
+                require_manifest: self.plugins.require_manifest,
+                allowed_capabilities: self.plugins.allowed_capabilities.clone(),
+                whitelist: self.plugins.whitelist.clone(),
+                watch_directory: self.plugins.watch,
+                load_order: self.plugins.load_order.clone(),
+                ..plugin_safety
+            },
         })
     }
 
+    /// Gets the spatial index selection from the `[gorc.spatial]` section.
+    pub fn spatial_index_config(&self) -> SpatialIndexConfig {
+        SpatialIndexConfig::new(self.gorc.spatial.index)
+            .with_max_objects_per_leaf(self.gorc.spatial.max_objects_per_leaf)
+            .with_cell_size(self.gorc.spatial.grid_cell_size)
+            .with_rebuild_threshold(self.gorc.spatial.rebuild_threshold)
+            .with_maintenance_interval_ms(self.gorc.spatial.maintenance_interval_ms)
+            .with_low_load_mutations(self.gorc.spatial.low_load_mutations)
+    }
+
     /// Converts the GORC configuration to the internal GORC config structure.
     ///
     /// This method translates the TOML-based GORC configuration into the types
@@ -488,6 +871,8 @@ impl AppConfig {
                 max_channels_per_object: self.gorc.general.max_channels_per_object,
                 auto_optimize_zones: self.gorc.general.auto_optimize_zones,
                 optimization_interval_ms: self.gorc.general.optimization_interval_ms,
+                zone_exit_margin: self.gorc.general.zone_exit_margin,
+                dead_reckoning_horizon_ms: self.gorc.general.dead_reckoning_horizon_ms,
                 debug_logging: self.gorc.general.debug_logging,
             },
             virtualization: VirtualizationConfig {
@@ -498,13 +883,20 @@ impl AppConfig {
                 min_zone_radius: self.gorc.virtualization.min_zone_radius,
                 check_interval_ms: self.gorc.virtualization.check_interval_ms,
                 max_objects_per_virtual_zone: self.gorc.virtualization.max_objects_per_virtual_zone,
+                strategy: self.gorc.virtualization.strategy,
+                grid_cell_size: self.gorc.virtualization.grid_cell_size,
+                min_players_per_virtual_zone: self.gorc.virtualization.min_players_per_virtual_zone,
             },
             spatial: SpatialConfig {
                 world_bounds: self.gorc.spatial.world_bounds,
                 max_objects_per_leaf: self.gorc.spatial.max_objects_per_leaf,
                 rebuild_threshold: self.gorc.spatial.rebuild_threshold,
+                maintenance_interval_ms: self.gorc.spatial.maintenance_interval_ms,
+                low_load_mutations: self.gorc.spatial.low_load_mutations,
                 enable_caching: self.gorc.spatial.enable_caching,
                 cache_expiry_ms: self.gorc.spatial.cache_expiry_ms,
+                index: self.gorc.spatial.index,
+                grid_cell_size: self.gorc.spatial.grid_cell_size,
             },
             network: GorcNetworkConfig {
                 max_batch_size: self.gorc.network.max_batch_size,
@@ -543,6 +935,7 @@ impl AppConfig {
                 &self.server.bind_address
             ));
         }
+        self.validate_listeners()?;
 
         // Validate region bounds
         if self.server.region.min_x >= self.server.region.max_x {
@@ -560,6 +953,10 @@ impl AppConfig {
             return Err("Plugin directory cannot be empty".to_string());
         }
 
+        if self.plugins.require_signatures && self.plugins.trusted_keys.is_empty() {
+            return Err("plugins.require_signatures is enabled but no plugins.trusted_keys are configured".to_string());
+        }
+
         // Validate log level
         let valid_levels = ["trace", "debug", "info", "warn", "error"];
         if !valid_levels.contains(&self.logging.level.as_str()) {
@@ -569,6 +966,15 @@ impl AppConfig {
             ));
         }
 
+        for (plugin, settings) in &self.logging.plugins {
+            if !valid_levels.contains(&settings.level.as_str()) {
+                return Err(format!(
+                    "Invalid log level for plugin {plugin}: {}. Must be one of: {valid_levels:?}",
+                    settings.level
+                ));
+            }
+        }
+
         if self.gorc.spatial.max_objects_per_leaf == 0 {
             return Err("gorc.spatial.max_objects_per_leaf must be greater than 0".to_string());
         }
@@ -577,6 +983,288 @@ impl AppConfig {
             return Err("gorc.spatial.rebuild_threshold must be greater than 0".to_string());
         }
 
+        if self.gorc.spatial.grid_cell_size <= 0.0 {
+            return Err("gorc.spatial.grid_cell_size must be greater than 0".to_string());
+        }
+
+        if self.gorc.snapshot.enabled && self.gorc.snapshot.path.is_empty() {
+            return Err("gorc.snapshot.path cannot be empty when snapshots are enabled".to_string());
+        }
+
+        if !(0.0..=1.0).contains(&self.gorc.general.zone_exit_margin) {
+            return Err("gorc.general.zone_exit_margin must be between 0.0 and 1.0".to_string());
+        }
+
+        if self.gorc.general.object_shards == 0 {
+            return Err("gorc.general.object_shards must be greater than 0".to_string());
+        }
+
+        if let Some(address) = &self.server.admin_address {
+            if address.parse::<std::net::SocketAddr>().is_err() {
+                return Err(format!("Invalid server.admin_address: {address}"));
+            }
+        }
+
+        if let Some(address) = &self.server.health_address {
+            if address.parse::<std::net::SocketAddr>().is_err() {
+                return Err(format!("Invalid server.health_address: {address}"));
+            }
+        }
+
+        if let Some(address) = &self.server.grpc_address {
+            if address.parse::<std::net::SocketAddr>().is_err() {
+                return Err(format!("Invalid server.grpc_address: {address}"));
+            }
+        }
+
+        if self.server.admin_token.as_deref().is_some_and(|token| token.trim().is_empty()) {
+            return Err("server.admin_token must not be empty".to_string());
+        }
+
+        if let Some(address) = &self.server.udp_address {
+            if address.parse::<std::net::SocketAddr>().is_err() {
+                return Err(format!("Invalid server.udp_address: {address}"));
+            }
+        }
+
+        if self.server.quic.listen_address.is_some()
+            && (self.server.quic.cert_path.is_none() || self.server.quic.key_path.is_none())
+        {
+            return Err("server.quic needs a cert_path and a key_path when listen_address is set".to_string());
+        }
+
+        let tls = &self.server.tls;
+        if tls.enabled {
+            if tls.cert_path.is_some() != tls.key_path.is_some() {
+                return Err("server.tls needs both a cert_path and a key_path".to_string());
+            }
+            if tls.cert_path.is_none() && tls.sni.is_empty() {
+                return Err("server.tls needs a cert_path and key_path or at least one sni certificate when enabled".to_string());
+            }
+            if let Some(sni) = tls.sni.iter().find(|sni| sni.server_name.trim().is_empty()) {
+                return Err(format!("server.tls.sni certificate {} needs a server_name", sni.cert_path.display()));
+            }
+        }
+
+        if self.server.proxy_protocol.enabled && self.server.proxy_protocol.header_timeout_ms == 0 {
+            return Err("server.proxy_protocol.header_timeout_ms must be greater than 0 when enabled".to_string());
+        }
+        if self.server.proxy_protocol.enabled && self.server.proxy_protocol.trusted_proxies.is_empty() {
+            return Err("server.proxy_protocol.trusted_proxies must list the proxies when enabled".to_string());
+        }
+
+        if self.server.heartbeat.interval_ms > 0 && self.server.heartbeat.max_missed == 0 {
+            return Err("server.heartbeat.max_missed must be greater than 0 when heartbeats are enabled".to_string());
+        }
+        if let Some(min_client_version) = &self.server.protocol.min_client_version {
+            if !horizon_event_system::ClientVersion::is_valid(min_client_version) {
+                return Err(format!(
+                    "server.protocol.min_client_version '{}' must be a dotted numeric version such as 1.4.0",
+                    min_client_version
+                ));
+            }
+        }
+        self.validate_s2s()?;
+        if !self.server.content_filter.rules.is_empty() {
+            ChatFilter::new(&self.server.content_filter).map_err(|e| format!("Invalid server.content_filter: {e}"))?;
+        }
+        if self.server.audit_log.path.is_some() && self.server.audit_log.max_file_bytes == 0 {
+            return Err("server.audit_log.max_file_bytes must be greater than 0".to_string());
+        }
+        self.validate_geoip()?;
+        self.validate_alerting()?;
+        let throttle = &self.server.connection_throttle;
+        if throttle.max_pending_handshakes > 0 && throttle.handshake_timeout_ms == 0 {
+            return Err("server.connection_throttle.handshake_timeout_ms must be set when max_pending_handshakes is, or stalled handshakes hold their slots forever".to_string());
+        }
+        let outbound_queue = &self.server.outbound_queue;
+        if outbound_queue.disconnect_at > 0 && outbound_queue.drop_cosmetic_at >= outbound_queue.disconnect_at {
+            return Err("server.outbound_queue.drop_cosmetic_at must be below server.outbound_queue.disconnect_at".to_string());
+        }
+
+        if let Some(per_minute) = self.server.security.max_requests_per_minute {
+            return Err(format!(
+                "server.security.max_requests_per_minute ({per_minute}) is no longer supported; \
+                 set the per-second global_message_rate_limit, ip_message_rate_limit and player_message_rate_limit instead"
+            ));
+        }
+        for (key, &per_second) in &self.server.security.message_rate_limits {
+            if key.trim().is_empty() || key.starts_with(':') || key.ends_with(':') {
+                return Err(format!("Invalid server.security.message_rate_limits key '{key}': expected namespace or namespace:event"));
+            }
+            if per_second == 0 {
+                return Err(format!("server.security.message_rate_limits.\"{key}\" must be greater than 0"));
+            }
+        }
+        let replay_protection = &self.server.security.replay_protection;
+        if replay_protection.enabled && (replay_protection.max_clock_skew_secs == 0 || replay_protection.nonce_cache_size == 0) {
+            return Err("server.security.replay_protection.max_clock_skew_secs and nonce_cache_size must be greater than 0 when enabled".to_string());
+        }
+
+        let auth = &self.server.auth;
+        if (!auth.jwt_keys.is_empty() || !auth.providers.is_empty()) && auth.handshake_timeout_ms == 0 {
+            return Err("server.auth.handshake_timeout_ms must be greater than 0 when jwt_keys or providers are configured".to_string());
+        }
+        let mut provider_names = std::collections::HashSet::new();
+        for provider in &auth.providers {
+            if provider.name().trim().is_empty() {
+                return Err("server.auth.providers entries need a name".to_string());
+            }
+            if !provider_names.insert(provider.name()) {
+                return Err(format!("Duplicate identity provider: {}", provider.name()));
+            }
+        }
+
+        self.validate_federation()?;
+
+        Ok(())
+    }
+
+    /// Validates the cross-server federation settings
+    /// Checks that no two listeners claim the same address, counting a
+    /// dual-stack `[::]` listener as also claiming its port on IPv4
+    fn validate_listeners(&self) -> Result<(), String> {
+        let Ok(bind_address) = self.server.bind_address.parse::<std::net::SocketAddr>() else {
+            return Ok(());
+        };
+        let primary = ListenerConfig::new(bind_address);
+        let listeners: Vec<&ListenerConfig> = std::iter::once(&primary).chain(&self.server.listeners).collect();
+        for (i, a) in listeners.iter().enumerate() {
+            for b in &listeners[i + 1..] {
+                if a.address.port() == 0 || a.address.port() != b.address.port() {
+                    continue;
+                }
+                let dual_stack_over_ipv4 = |v6: &ListenerConfig, v4: &ListenerConfig| {
+                    v6.address.ip().is_unspecified() && v6.address.is_ipv6() && !v6.ipv6_only && v4.address.is_ipv4()
+                };
+                if a.address == b.address {
+                    return Err(format!("server.listeners binds {} more than once", a.address));
+                }
+                if dual_stack_over_ipv4(a, b) || dual_stack_over_ipv4(b, a) {
+                    return Err(format!(
+                        "server.listeners: dual-stack {} already takes IPv4 clients on port {}; set ipv6_only = true to listen on IPv4 separately",
+                        if a.address.is_ipv6() { a.address } else { b.address },
+                        a.address.port()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the server-to-server channel settings
+    fn validate_s2s(&self) -> Result<(), String> {
+        let s2s = &self.server.s2s;
+        if s2s.secret.as_deref().is_some_and(|secret| secret.trim().is_empty()) {
+            return Err("server.s2s.secret must not be empty".to_string());
+        }
+        if s2s.name.trim().is_empty() {
+            return Err("server.s2s.name must not be empty".to_string());
+        }
+
+        let mut names = std::collections::HashSet::new();
+        for peer in &s2s.peers {
+            if peer.name.is_empty() || peer.name == s2s.name {
+                return Err(format!("server.s2s peer names must be non-empty and differ from server.s2s.name: '{}'", peer.name));
+            }
+            if !names.insert(peer.name.as_str()) {
+                return Err(format!("Duplicate server.s2s peer: {}", peer.name));
+            }
+            if let Some(url) = peer.url.as_deref().filter(|url| !url.starts_with("ws://")) {
+                return Err(format!("URL of server.s2s peer {} must be a ws:// URL: {}", peer.name, url));
+            }
+            if peer.secret.as_deref().is_some_and(|secret| secret.trim().is_empty()) {
+                return Err(format!("Secret of server.s2s peer {} must not be empty", peer.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the GeoIP connection policy settings
+    fn validate_geoip(&self) -> Result<(), String> {
+        let geoip = &self.server.geoip;
+        let has_rules = !geoip.allow_countries.is_empty()
+            || !geoip.deny_countries.is_empty()
+            || geoip.refuse_unknown
+            || !geoip.country_connection_caps.is_empty()
+            || !geoip.continent_connection_caps.is_empty();
+        if has_rules && geoip.database_path.is_none() {
+            return Err("server.geoip.database_path is required for GeoIP rules and caps".to_string());
+        }
+
+        let codes = geoip
+            .allow_countries
+            .iter()
+            .chain(&geoip.deny_countries)
+            .chain(geoip.country_connection_caps.keys())
+            .chain(geoip.continent_connection_caps.keys());
+        for code in codes {
+            if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(format!("server.geoip codes must be two-letter ISO codes such as DE or EU: '{}'", code));
+            }
+        }
+        let mut caps = geoip.country_connection_caps.iter().chain(&geoip.continent_connection_caps);
+        if let Some((code, _)) = caps.find(|(_, cap)| **cap == 0) {
+            return Err(format!("server.geoip connection cap for {} must be greater than 0 (deny the country instead)", code));
+        }
+        Ok(())
+    }
+
+    fn validate_alerting(&self) -> Result<(), String> {
+        let alerting = &self.server.alerting;
+        if !alerting.webhooks.is_empty() && alerting.window_secs == 0 {
+            return Err("server.alerting.window_secs must be greater than 0".to_string());
+        }
+        for webhook in &alerting.webhooks {
+            if !webhook.url.starts_with("http://") && !webhook.url.starts_with("https://") {
+                return Err(format!("server.alerting webhook URL must be http:// or https://: '{}'", webhook.url));
+            }
+            if webhook.format == AlertWebhookFormat::PagerDuty && webhook.routing_key.as_deref().is_none_or(str::is_empty) {
+                return Err(format!("server.alerting PagerDuty webhook {} needs a routing_key", webhook.url));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_federation(&self) -> Result<(), String> {
+        let federation = &self.gorc.federation;
+
+        if federation.overlap_band < 0.0 {
+            return Err("gorc.federation.overlap_band must not be negative".to_string());
+        }
+        if federation.scan_interval_ms == 0 {
+            return Err("gorc.federation.scan_interval_ms must be greater than 0".to_string());
+        }
+        if let Some(address) = &federation.listen_address {
+            if address.parse::<std::net::SocketAddr>().is_err() {
+                return Err(format!("Invalid gorc.federation.listen_address: {address}"));
+            }
+        }
+
+        let mut names = std::collections::HashSet::new();
+        for neighbor in &federation.neighbors {
+            if neighbor.name.is_empty() || neighbor.name == federation.region_name {
+                return Err(format!(
+                    "Federation neighbor names must be non-empty and differ from region_name: '{}'",
+                    neighbor.name
+                ));
+            }
+            if !names.insert(neighbor.name.as_str()) {
+                return Err(format!("Duplicate federation neighbor: {}", neighbor.name));
+            }
+            if neighbor.address.parse::<std::net::SocketAddr>().is_err() {
+                return Err(format!("Invalid address for federation neighbor {}: {}", neighbor.name, neighbor.address));
+            }
+            if neighbor.secret.is_empty() {
+                return Err(format!("Federation neighbor {} needs a secret", neighbor.name));
+            }
+            if let Some(url) = &neighbor.client_url {
+                if !url.starts_with("ws://") && !url.starts_with("wss://") {
+                    return Err(format!("Client URL of federation neighbor {} must be a ws:// or wss:// URL: {}", neighbor.name, url));
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -634,6 +1322,32 @@ mod tests {
             connection_timeout: 120,
             use_reuse_port: true,
             tick_interval_ms: 16,
+            admin_address: None,
+            admin_token: None,
+            grpc_address: None,
+            health_address: None,
+            session_resume_window_secs: 0,
+            heartbeat: Default::default(),
+            outbound_queue: Default::default(),
+            protocol: Default::default(),
+            s2s: Default::default(),
+            content_filter: Default::default(),
+            audit_log: Default::default(),
+            geoip: Default::default(),
+            alerting: Default::default(),
+            connection_throttle: Default::default(),
+            auth: Default::default(),
+            udp_address: None,
+            quic: Default::default(),
+            tls: Default::default(),
+            accept_threads: 0,
+            listeners: Vec::new(),
+            proxy_protocol: Default::default(),
+            event_queue: Default::default(),
+            handler_spans: Default::default(),
+            message_lanes: Default::default(),
+            security: Default::default(),
+            plugin_circuit_breaker: Default::default(),
         };
 
         assert_eq!(settings.bind_address, "0.0.0.0:9999");
@@ -651,6 +1365,12 @@ mod tests {
             directory: "/custom/plugins".to_string(),
             auto_load: false,
             whitelist: vec!["plugin1".to_string(), "plugin2".to_string()],
+            require_signatures: true,
+            trusted_keys: vec!["key".to_string()],
+            require_manifest: true,
+            allowed_capabilities: vec!["chat".to_string()],
+            watch: true,
+            load_order: vec!["plugin_auth".to_string()],
         };
 
         assert_eq!(settings.directory, "/custom/plugins");
@@ -658,6 +1378,12 @@ mod tests {
         assert_eq!(settings.whitelist.len(), 2);
         assert!(settings.whitelist.contains(&"plugin1".to_string()));
         assert!(settings.whitelist.contains(&"plugin2".to_string()));
+        assert!(settings.require_signatures);
+        assert_eq!(settings.trusted_keys, vec!["key".to_string()]);
+        assert!(settings.require_manifest);
+        assert_eq!(settings.allowed_capabilities, vec!["chat".to_string()]);
+        assert!(settings.watch);
+        assert_eq!(settings.load_order, vec!["plugin_auth".to_string()]);
     }
 
     #[test]
@@ -666,6 +1392,7 @@ mod tests {
             level: "debug".to_string(),
             json_format: true,
             file_path: Some("/var/log/horizon.log".to_string()),
+            plugins: HashMap::new(),
         };
 
         assert_eq!(settings.level, "debug");
@@ -778,16 +1505,49 @@ file_path = "/tmp/test.log"
                 connection_timeout: 180,
                 use_reuse_port: true,
                 tick_interval_ms: 25,
+                admin_address: None,
+                admin_token: None,
+                grpc_address: None,
+                health_address: None,
+                session_resume_window_secs: 0,
+                heartbeat: Default::default(),
+                outbound_queue: Default::default(),
+                protocol: Default::default(),
+                s2s: Default::default(),
+                content_filter: Default::default(),
+                audit_log: Default::default(),
+                geoip: Default::default(),
+                alerting: Default::default(),
+                connection_throttle: Default::default(),
+                auth: Default::default(),
+                udp_address: None,
+                quic: Default::default(),
+                tls: Default::default(),
+                accept_threads: 0,
+                listeners: Vec::new(),
+                proxy_protocol: Default::default(),
+                event_queue: Default::default(),
+                handler_spans: Default::default(),
+                message_lanes: Default::default(),
+                security: Default::default(),
+                plugin_circuit_breaker: Default::default(),
             },
             plugins: PluginSettings {
                 directory: "/srv/plugins".to_string(),
                 auto_load: true,
                 whitelist: vec![],
+                require_signatures: false,
+                trusted_keys: vec![],
+                require_manifest: false,
+                allowed_capabilities: vec![],
+                watch: false,
+                load_order: vec![],
             },
             logging: LoggingSettings {
                 level: "warn".to_string(),
                 json_format: false,
                 file_path: None,
+                plugins: HashMap::new(),
             },
             gorc: GorcSettings::default(),
         };
@@ -802,6 +1562,519 @@ file_path = "/tmp/test.log"
         assert_eq!(server_config.plugin_directory, PathBuf::from("/srv/plugins"));
         assert_eq!(server_config.region_bounds.min_x, -1500.0);
         assert_eq!(server_config.region_bounds.max_x, 1500.0);
+        assert!(!server_config.plugin_safety.require_signatures);
+        assert!(!server_config.plugin_safety.require_manifest);
+        assert!(server_config.federation.neighbors.is_empty());
+    }
+
+    #[test]
+    fn test_federation_settings() {
+        let mut config = AppConfig::default();
+        config.gorc.federation.region_name = "west".to_string();
+        config.gorc.federation.listen_address = Some("0.0.0.0:9100".to_string());
+        config.gorc.federation.neighbors.push(NeighborSettings {
+            name: "east".to_string(),
+            address: "10.0.0.2:9100".to_string(),
+            region: RegionSettings {
+                min_x: 1000.0,
+                max_x: 3000.0,
+                min_y: -1000.0,
+                max_y: 1000.0,
+                min_z: -100.0,
+                max_z: 100.0,
+            },
+            secret: String::new(),
+            client_url: Some("wss://east.example.com/".to_string()),
+        });
+        assert!(config.validate().unwrap_err().contains("Federation neighbor east needs a secret"));
+        config.gorc.federation.neighbors[0].secret = "west-east".to_string();
+        assert!(config.validate().is_ok());
+
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        let federation = &server_config.federation;
+        assert_eq!(federation.region_name, "west");
+        assert_eq!(federation.listen_address, Some("0.0.0.0:9100".parse().unwrap()));
+        assert_eq!(federation.neighbors[0].address.to_string(), "10.0.0.2:9100");
+        assert_eq!(federation.neighbors[0].bounds.min_x, 1000.0);
+        assert_eq!(federation.neighbors[0].secret, "west-east");
+        assert_eq!(federation.neighbors[0].client_url.as_deref(), Some("wss://east.example.com/"));
+
+        config.gorc.federation.neighbors[0].client_url = Some("https://east.example.com/".to_string());
+        assert!(config.validate().unwrap_err().contains("Client URL of federation neighbor east"));
+        config.gorc.federation.neighbors[0].client_url = None;
+
+        config.gorc.federation.neighbors[0].address = "not-an-address".to_string();
+        assert!(config.validate().unwrap_err().contains("federation neighbor east"));
+    }
+
+    #[test]
+    fn test_admin_address_setting() {
+        let mut config = AppConfig::default();
+        assert!(config.to_server_config(PluginSafetyConfig::default()).unwrap().admin_address.is_none());
+
+        config.server.admin_address = Some("127.0.0.1:9200".to_string());
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.admin_address, Some("127.0.0.1:9200".parse().unwrap()));
+
+        config.server.admin_address = Some("localhost".to_string());
+        assert!(config.validate().unwrap_err().contains("server.admin_address"));
+        config.server.admin_address = None;
+
+        config.server.admin_token = Some(" ".to_string());
+        assert!(config.validate().unwrap_err().contains("server.admin_token"));
+        config.server.admin_token = Some("secret".to_string());
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.admin_token.as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn test_grpc_address_setting() {
+        let mut config = AppConfig::default();
+        assert!(config.to_server_config(PluginSafetyConfig::default()).unwrap().grpc_address.is_none());
+
+        config.server.grpc_address = Some("127.0.0.1:9300".to_string());
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.grpc_address, Some("127.0.0.1:9300".parse().unwrap()));
+
+        config.server.grpc_address = Some("9300".to_string());
+        assert!(config.validate().unwrap_err().contains("server.grpc_address"));
+    }
+
+    #[test]
+    fn test_health_address_setting() {
+        let mut config = AppConfig::default();
+        assert!(config.to_server_config(PluginSafetyConfig::default()).unwrap().health_address.is_none());
+
+        config.server.health_address = Some("0.0.0.0:9100".to_string());
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.health_address, Some("0.0.0.0:9100".parse().unwrap()));
+
+        config.server.health_address = Some("9100".to_string());
+        assert!(config.validate().unwrap_err().contains("server.health_address"));
+    }
+
+    #[test]
+    fn test_session_resume_window_setting() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.to_server_config(PluginSafetyConfig::default()).unwrap().session_resume_window_secs, 0);
+
+        config.server.session_resume_window_secs = 30;
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.session_resume_window_secs, 30);
+    }
+
+    #[test]
+    fn test_heartbeat_settings() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.to_server_config(PluginSafetyConfig::default()).unwrap().heartbeat, HeartbeatConfig::default());
+
+        config.server.heartbeat.max_missed = 0;
+        assert!(config.validate().unwrap_err().contains("server.heartbeat.max_missed"));
+
+        // Disabled heartbeats don't need a miss limit
+        config.server.heartbeat.interval_ms = 0;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_protocol_settings() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.to_server_config(PluginSafetyConfig::default()).unwrap().protocol, ProtocolConfig::default());
+
+        config.server.protocol.min_client_version = Some("1.4.0".to_string());
+        assert!(config.validate().is_ok());
+        config.server.protocol.min_client_version = Some("latest".to_string());
+        assert!(config.validate().unwrap_err().contains("server.protocol.min_client_version"));
+    }
+
+    #[test]
+    fn test_s2s_settings() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.to_server_config(PluginSafetyConfig::default()).unwrap().s2s, S2sConfig::default());
+
+        config.server.s2s.name = "region-east".to_string();
+        config.server.s2s.secret = Some("shared".to_string());
+        config.server.s2s.peers = vec![game_server::S2sPeerConfig {
+            name: "matchmaker".to_string(),
+            url: Some("ws://10.0.0.12:9500".to_string()),
+            secret: None,
+        }];
+        assert!(config.validate().is_ok());
+        assert_eq!(config.to_server_config(PluginSafetyConfig::default()).unwrap().s2s.peers.len(), 1);
+
+        // Peers that only connect in need no URL
+        config.server.s2s.peers[0].url = None;
+        assert!(config.validate().is_ok());
+        config.server.s2s.peers[0].secret = Some(" ".to_string());
+        assert!(config.validate().unwrap_err().contains("matchmaker"));
+        config.server.s2s.peers[0].secret = None;
+
+        config.server.s2s.peers[0].url = Some("10.0.0.12:9500".to_string());
+        assert!(config.validate().unwrap_err().contains("matchmaker"));
+        config.server.s2s.peers[0].name = "region-east".to_string();
+        assert!(config.validate().unwrap_err().contains("server.s2s"));
+        config.server.s2s.peers.clear();
+        config.server.s2s.secret = Some(String::new());
+        assert!(config.validate().unwrap_err().contains("server.s2s.secret"));
+    }
+
+    #[test]
+    fn test_content_filter_settings() {
+        let mut config = AppConfig::default();
+        config.server.content_filter = toml::from_str(
+            "[[rules]]\nname = \"spam\"\npatterns = [\"fr[e3]{2}\\\\s*gold\"]\naction = \"drop\"\nlocales = [\"en\"]\n",
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        let content_filter = config.to_server_config(PluginSafetyConfig::default()).unwrap().content_filter;
+        assert_eq!(content_filter.rules[0].action, game_server::ContentFilterAction::Drop);
+        assert_eq!(content_filter.mute_duration_secs, 300);
+
+        config.server.content_filter.rules[0].patterns.push("(unclosed".to_string());
+        assert!(config.validate().unwrap_err().contains("server.content_filter"));
+    }
+
+    #[test]
+    fn test_audit_log_settings() {
+        let mut config = AppConfig::default();
+        config.server.audit_log.path = Some(PathBuf::from("logs/audit.jsonl"));
+        config.server.audit_log.max_files = 3;
+        assert!(config.validate().is_ok());
+        let audit_log = config.to_server_config(PluginSafetyConfig::default()).unwrap().audit_log;
+        assert_eq!(audit_log.path.as_deref(), Some(std::path::Path::new("logs/audit.jsonl")));
+        assert_eq!(audit_log.max_files, 3);
+
+        config.server.audit_log.max_file_bytes = 0;
+        assert!(config.validate().unwrap_err().contains("server.audit_log.max_file_bytes"));
+    }
+
+    #[test]
+    fn test_geoip_settings() {
+        let mut config = AppConfig::default();
+        config.server.geoip = toml::from_str(
+            "deny_countries = [\"KP\"]\n[country_connection_caps]\nUS = 500\n[continent_connection_caps]\nEU = 800\n",
+        )
+        .unwrap();
+        // Rules need a database to look addresses up in
+        assert!(config.validate().unwrap_err().contains("server.geoip.database_path"));
+
+        config.server.geoip.database_path = Some(PathBuf::from("data/GeoLite2-Country.mmdb"));
+        assert!(config.validate().is_ok());
+        let geoip = config.to_server_config(PluginSafetyConfig::default()).unwrap().geoip;
+        assert_eq!(geoip.continent_connection_caps.get("EU"), Some(&800));
+
+        config.server.geoip.allow_countries = vec!["Germany".to_string()];
+        assert!(config.validate().unwrap_err().contains("two-letter"));
+        config.server.geoip.allow_countries.clear();
+        config.server.geoip.country_connection_caps.insert("CA".to_string(), 0);
+        assert!(config.validate().unwrap_err().contains("cap for CA"));
+    }
+
+    #[test]
+    fn test_alerting_settings() {
+        let mut config = AppConfig::default();
+        config.server.alerting = toml::from_str(
+            "ban_evasion_threshold = 1\n[[webhooks]]\nurl = \"https://discord.com/api/webhooks/1/abc\"\n[[webhooks]]\nurl = \"https://events.pagerduty.com/v2/enqueue\"\nformat = \"pagerduty\"\n",
+        )
+        .unwrap();
+        // PagerDuty needs to know which service to page
+        assert!(config.validate().unwrap_err().contains("routing_key"));
+
+        config.server.alerting.webhooks[1].routing_key = Some("R0UT1NG".to_string());
+        assert!(config.validate().is_ok());
+        let alerting = config.to_server_config(PluginSafetyConfig::default()).unwrap().alerting;
+        assert_eq!(alerting.webhooks[0].format, AlertWebhookFormat::Discord);
+        assert_eq!((alerting.ban_evasion_threshold, alerting.rate_limit_storm_threshold), (1, 500));
+
+        config.server.alerting.webhooks[0].url = "discord.com/api/webhooks/1/abc".to_string();
+        assert!(config.validate().unwrap_err().contains("http:// or https://"));
+    }
+
+    #[test]
+    fn test_connection_throttle_settings() {
+        let mut config = AppConfig::default();
+        config.server.connection_throttle.max_new_connections_per_sec = 200;
+        config.server.connection_throttle.max_pending_handshakes = 1024;
+        assert!(config.validate().is_ok());
+        let throttle = config.to_server_config(PluginSafetyConfig::default()).unwrap().connection_throttle;
+        assert_eq!(throttle.max_new_connections_per_sec, 200);
+        assert_eq!(throttle.handshake_timeout_ms, 10_000);
+
+        config.server.connection_throttle.handshake_timeout_ms = 0;
+        assert!(config.validate().unwrap_err().contains("server.connection_throttle.handshake_timeout_ms"));
+    }
+
+    #[test]
+    fn test_outbound_queue_settings() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.to_server_config(PluginSafetyConfig::default()).unwrap().outbound_queue, OutboundQueueConfig::default());
+
+        // Cosmetic updates must be dropped before the client is disconnected
+        config.server.outbound_queue.drop_cosmetic_at = 2048;
+        assert!(config.validate().unwrap_err().contains("server.outbound_queue.drop_cosmetic_at"));
+
+        config.server.outbound_queue.disconnect_at = 0;
+        assert!(config.validate().is_ok());
+        config.server.outbound_queue.disconnect_at = 4096;
+        assert_eq!(config.to_server_config(PluginSafetyConfig::default()).unwrap().outbound_queue.disconnect_at, 4096);
+    }
+
+    #[test]
+    fn test_message_rate_limit_settings() {
+        let mut config = AppConfig::default();
+        config.server.security.message_rate_limits =
+            HashMap::from([("gorc:move".to_string(), 120), ("chat".to_string(), 5)]);
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.security.message_rate_limits["gorc:move"], 120);
+
+        config.server.security.message_rate_limits.insert("gorc:ship_scan".to_string(), 0);
+        assert!(config.validate().unwrap_err().contains("must be greater than 0"));
+
+        config.server.security.message_rate_limits = HashMap::from([("chat:".to_string(), 5)]);
+        assert!(config.validate().unwrap_err().contains("namespace or namespace:event"));
+    }
+
+    #[test]
+    fn test_removed_per_minute_rate_limit_is_rejected() {
+        let mut config = AppConfig::default();
+        let security = &config.server.security;
+        assert!(security.enable_rate_limiting);
+        assert!(security.global_message_rate_limit > 0 && security.ip_message_rate_limit > 0 && security.player_message_rate_limit > 0);
+
+        config.server.security = toml::from_str("enable_rate_limiting = true\nmax_requests_per_minute = 60").unwrap();
+        assert!(config.validate().unwrap_err().contains("max_requests_per_minute"));
+    }
+
+    #[test]
+    fn test_replay_protection_settings() {
+        let mut config = AppConfig::default();
+        config.server.security.replay_protection.enabled = true;
+        config.server.security.replay_protection.max_clock_skew_secs = 10;
+        assert!(config.validate().is_ok());
+        let replay_protection = config.to_server_config(PluginSafetyConfig::default()).unwrap().security.replay_protection;
+        assert_eq!((replay_protection.enabled, replay_protection.max_clock_skew_secs, replay_protection.nonce_cache_size), (true, 10, 1_000));
+
+        config.server.security.replay_protection.nonce_cache_size = 0;
+        assert!(config.validate().unwrap_err().contains("replay_protection"));
+    }
+
+    #[test]
+    fn test_ip_filter_settings() {
+        let mut toml_content = toml::to_string(&AppConfig::default()).unwrap();
+        toml_content = toml_content.replace("ip_deny_list = []", r#"ip_deny_list = ["10.66.0.0/16", "192.0.2.7"]"#);
+        let config: AppConfig = toml::from_str(&toml_content).unwrap();
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        let deny: Vec<String> = server_config.security.ip_deny_list.iter().map(ToString::to_string).collect();
+        assert_eq!(deny, ["10.66.0.0/16", "192.0.2.7/32"]);
+
+        let invalid = toml_content.replace("10.66.0.0/16", "10.66.0.0/40");
+        assert!(toml::from_str::<AppConfig>(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_auth_settings() {
+        let mut config = AppConfig::default();
+        assert!(config.server.auth.jwt_keys.is_empty());
+        assert_eq!(config.server.auth.public_namespaces, ["system"]);
+
+        let toml_content = r#"
+            issuer = "accounts"
+            require_authentication = true
+            allow_guests = true
+
+            [[jwt_keys]]
+            kid = "primary"
+            algorithm = "HS256"
+            secret = "shared-secret"
+        "#;
+        config.server.auth = toml::from_str(toml_content).unwrap();
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.auth.issuer.as_deref(), Some("accounts"));
+        assert_eq!(server_config.auth.jwt_keys[0].kid.as_deref(), Some("primary"));
+        assert!(server_config.auth.allow_guests);
+        assert_eq!(server_config.auth.guest_namespaces, ["system"]);
+        assert!(config.validate().is_ok());
+
+        config.server.auth.handshake_timeout_ms = 0;
+        assert!(config.validate().unwrap_err().contains("server.auth.handshake_timeout_ms"));
+        config.server.auth.handshake_timeout_ms = 10_000;
+
+        let providers = r#"
+            [[providers]]
+            type = "oidc"
+            name = "accounts"
+            issuer = "https://accounts.example.com"
+            client_id = "horizon"
+
+            [[providers]]
+            type = "discord"
+            client_id = "1100"
+
+            [[providers]]
+            type = "steam"
+            app_id = 480
+            api_key = "publisher-key"
+        "#;
+        config.server.auth.providers = toml::from_str::<AuthConfig>(providers).unwrap().providers;
+        let names: Vec<&str> = config.server.auth.providers.iter().map(|provider| provider.name()).collect();
+        assert_eq!(names, ["accounts", "discord", "steam"]);
+        assert!(config.validate().is_ok());
+
+        config.server.auth.providers.push(config.server.auth.providers[1].clone());
+        assert!(config.validate().unwrap_err().contains("Duplicate identity provider: discord"));
+    }
+
+    #[test]
+    fn test_udp_address_setting() {
+        let mut config = AppConfig::default();
+        assert!(config.to_server_config(PluginSafetyConfig::default()).unwrap().udp_address.is_none());
+
+        config.server.udp_address = Some("0.0.0.0:8081".to_string());
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.udp_address, Some("0.0.0.0:8081".parse().unwrap()));
+
+        config.server.udp_address = Some("8081".to_string());
+        assert!(config.validate().unwrap_err().contains("server.udp_address"));
+    }
+
+    #[test]
+    fn test_quic_settings() {
+        let mut config: AppConfig = toml::from_str(&toml::to_string(&AppConfig::default()).unwrap()).unwrap();
+        assert_eq!(config.server.quic, QuicConfig::default());
+
+        config.server.quic.listen_address = Some("0.0.0.0:8443".parse().unwrap());
+        assert!(config.validate().unwrap_err().contains("server.quic"));
+
+        config.server.quic.cert_path = Some(PathBuf::from("certs/server.pem"));
+        config.server.quic.key_path = Some(PathBuf::from("certs/server.key"));
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.quic, config.server.quic);
+    }
+
+    #[test]
+    fn test_tls_settings() {
+        let mut config: AppConfig = toml::from_str(&toml::to_string(&AppConfig::default()).unwrap()).unwrap();
+        assert_eq!(config.server.tls, TlsConfig::default());
+
+        config.server.tls.enabled = true;
+        assert!(config.validate().unwrap_err().contains("server.tls"));
+
+        config.server.tls.sni.push(game_server::SniCertificateConfig {
+            server_name: "eu.example.com".to_string(),
+            cert_path: PathBuf::from("certs/eu.pem"),
+            key_path: PathBuf::from("certs/eu.key"),
+        });
+        assert!(config.validate().is_ok());
+
+        config.server.tls.cert_path = Some(PathBuf::from("certs/server.pem"));
+        assert!(config.validate().unwrap_err().contains("key_path"));
+        config.server.tls.key_path = Some(PathBuf::from("certs/server.key"));
+        assert!(config.validate().is_ok());
+
+        config.server.tls.sni[0].server_name = " ".to_string();
+        assert!(config.validate().unwrap_err().contains("server_name"));
+        config.server.tls.sni[0].server_name = "*.example.com".to_string();
+
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.tls, config.server.tls);
+    }
+
+    #[test]
+    fn test_proxy_protocol_settings() {
+        let mut config = AppConfig::default();
+        assert!(!config.server.proxy_protocol.enabled);
+
+        let toml_content = r#"
+            enabled = true
+            trusted_proxies = ["10.0.0.0/8", "fd00::/8"]
+        "#;
+        config.server.proxy_protocol = toml::from_str(toml_content).unwrap();
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.proxy_protocol.trusted_proxies.len(), 2);
+        assert_eq!(server_config.proxy_protocol.header_timeout_ms, 5_000);
+
+        config.server.proxy_protocol.header_timeout_ms = 0;
+        assert!(config.validate().unwrap_err().contains("server.proxy_protocol"));
+        config.server.proxy_protocol.header_timeout_ms = 5_000;
+        config.server.proxy_protocol.trusted_proxies.clear();
+        assert!(config.validate().unwrap_err().contains("server.proxy_protocol.trusted_proxies"));
+        assert!(toml::from_str::<ProxyProtocolConfig>(r#"trusted_proxies = ["10.0.0.0/40"]"#).is_err());
+    }
+
+    #[test]
+    fn test_listener_settings() {
+        let mut config = AppConfig::default();
+        config.server.bind_address = "0.0.0.0:8080".to_string();
+        let toml_content = r#"
+            [[listeners]]
+            address = "[::]:8080"
+            ipv6_only = true
+            use_reuse_port = true
+
+            [[listeners]]
+            address = "192.168.1.10:9090"
+        "#;
+        #[derive(Deserialize)]
+        struct Listeners {
+            listeners: Vec<ListenerConfig>,
+        }
+        config.server.listeners = toml::from_str::<Listeners>(toml_content).unwrap().listeners;
+        config.server.accept_threads = 4;
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.accept_threads, 4);
+        assert_eq!(server_config.listeners.len(), 2);
+        assert!(server_config.listeners[0].ipv6_only && server_config.listeners[0].use_reuse_port);
+        assert!(!server_config.listeners[1].ipv6_only);
+
+        // A dual-stack [::] listener would take the IPv4 port bind_address already has
+        config.server.listeners[0].ipv6_only = false;
+        assert!(config.validate().unwrap_err().contains("ipv6_only"));
+        config.server.listeners[0].address = "0.0.0.0:8080".parse().unwrap();
+        assert!(config.validate().unwrap_err().contains("more than once"));
+    }
+
+    #[test]
+    fn test_spatial_index_settings() {
+        let mut config = AppConfig::default();
+        config.gorc.spatial.index = SpatialIndexKind::LooseGrid;
+        config.gorc.spatial.grid_cell_size = 250.0;
+        config.gorc.spatial.rebuild_threshold = 800;
+        config.gorc.spatial.maintenance_interval_ms = 0;
+        assert!(config.validate().is_ok());
+
+        let toml_content = toml::to_string(&config).unwrap();
+        assert!(toml_content.contains("index = \"loose_grid\""));
+        let config: AppConfig = toml::from_str(&toml_content).unwrap();
+
+        let spatial_index = config.to_server_config(PluginSafetyConfig::default()).unwrap().spatial_index;
+        assert_eq!(spatial_index.kind, SpatialIndexKind::LooseGrid);
+        assert_eq!(spatial_index.cell_size, 250.0);
+        assert_eq!(spatial_index.rebuild_threshold, 800);
+        assert_eq!(spatial_index.maintenance_interval_ms, 0);
+        assert_eq!(AppConfig::default().spatial_index_config().kind, SpatialIndexKind::RTree);
+    }
+
+    #[test]
+    fn test_object_shard_settings() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.gorc.general.object_shards, DEFAULT_OBJECT_SHARDS);
+
+        config.gorc.general.object_shards = 64;
+        assert!(config.validate().is_ok());
+        let server_config = config.to_server_config(PluginSafetyConfig::default()).unwrap();
+        assert_eq!(server_config.object_shards, 64);
+
+        config.gorc.general.object_shards = 0;
+        assert!(config.validate().is_err());
     }
 
     #[test]
@@ -860,6 +2133,19 @@ file_path = "/tmp/test.log"
         assert!(result.unwrap_err().contains("Plugin directory cannot be empty"));
     }
 
+    #[test]
+    fn test_validation_signatures_without_keys() {
+        let mut config = AppConfig::default();
+        config.plugins.require_signatures = true;
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("trusted_keys"));
+
+        config.plugins.trusted_keys = vec!["AAAA".to_string()];
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_validation_invalid_log_level() {
         let mut config = AppConfig::default();
@@ -883,6 +2169,25 @@ file_path = "/tmp/test.log"
         }
     }
 
+    #[test]
+    fn test_plugin_log_settings() {
+        let mut config: AppConfig = toml::from_str(&format!(
+            "{}\n[logging.plugins.greeter]\nlevel = \"debug\"\nfile_path = \"logs/greeter.log\"\n",
+            toml::to_string(&AppConfig::default()).unwrap()
+        ))
+        .unwrap();
+
+        let greeter = &config.logging.plugins["greeter"];
+        assert_eq!(greeter.level, "debug");
+        assert_eq!(greeter.file_path.as_deref(), Some("logs/greeter.log"));
+        assert!(!greeter.propagate);
+        assert!(config.validate().is_ok());
+
+        config.logging.plugins.get_mut("greeter").unwrap().level = "loud".to_string();
+        let result = config.validate();
+        assert!(result.unwrap_err().contains("Invalid log level for plugin greeter"));
+    }
+
     #[test]
     fn test_default_tick_interval_function() {
         assert_eq!(default_tick_interval(), 50);
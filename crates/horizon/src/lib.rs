@@ -18,6 +18,12 @@
 //!
 //! # JSON logging for production
 //! horizon --json-logs
+//!
+//! # Generate a new plugin crate
+//! horizon plugin new inventory --dir crates
+//!
+//! # Compare spatial index implementations for this world
+//! horizon bench spatial --radius 150
 //! ```
 //!
 //! ## Configuration
@@ -41,14 +47,18 @@
 use tracing::error;
 
 mod app;
+mod bench;
 mod cli;
 mod config;
 mod logging;
+mod scaffold;
 mod signals;
 
 use app::Application;
-use cli::CliArgs;
+use bench::SpatialBenchOptions;
+use cli::{CliArgs, CliCommand};
 use config::AppConfig;
+use scaffold::PluginScaffold;
 use horizon_event_system::async_logging;
 
 /// Main entry point for the Horizon Game Server.
@@ -72,6 +82,11 @@ pub async fn init() -> Result<(), Box<dyn std::error::Error>> {
     // Parse CLI arguments first
     let args = CliArgs::parse();
 
+    // Subcommands run a one-off task and exit without starting the server
+    if let Some(command) = &args.command {
+        return run_command(command, &args).await;
+    }
+
     // Load configuration to get logging settings
     let config = AppConfig::load_from_file(&args.config_path)
         .await
@@ -85,6 +100,7 @@ pub async fn init() -> Result<(), Box<dyn std::error::Error>> {
     
     // Initialize async logging system
     async_logging::init_global_async_logger();
+    logging::setup_plugin_log_routes(&config.logging);
 
     // Create and run application
     match Application::new(args).await {
@@ -103,6 +119,36 @@ pub async fn init() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Runs a CLI subcommand.
+async fn run_command(command: &CliCommand, args: &CliArgs) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        CliCommand::NewPlugin { name, directory, event_system_path } => {
+            let mut scaffold = PluginScaffold::new(name, directory)?;
+            if let Some(path) = event_system_path {
+                scaffold = scaffold.with_event_system_path(path.clone());
+            }
+
+            let files = scaffold.generate()?;
+            println!("🔌 Created plugin '{}' in {}", scaffold.plugin_name, scaffold.output_dir.display());
+            for file in files {
+                println!("   {}", file.display());
+            }
+            println!("🔨 Build it with: cargo build --release --manifest-path {}", scaffold.output_dir.join("Cargo.toml").display());
+            Ok(())
+        }
+        CliCommand::BenchSpatial { objects, radius, queries, snapshot } => {
+            let config = AppConfig::load_from_file(&args.config_path).await?;
+            let options = SpatialBenchOptions {
+                objects: *objects,
+                radius: *radius,
+                queries: *queries,
+                snapshot: snapshot.clone(),
+            };
+            bench::run_spatial_bench(&config, &options)
+        }
+    }
+}
+
 // Re-export main types for potential library usage
 pub use config::{LoggingSettings, PluginSettings, RegionSettings, ServerSettings};
 
@@ -156,6 +202,7 @@ mod tests {
             danger_allow_unsafe_plugins: false,
             danger_allow_abi_mismatch: false,
             strict_versioning: false,
+            command: None,
         };
 
         assert_eq!(args.config_path, PathBuf::from("test.toml"));
@@ -176,6 +223,7 @@ mod tests {
             danger_allow_unsafe_plugins: false,
             danger_allow_abi_mismatch: false,
             strict_versioning: false,
+            command: None,
         };
 
         // Create a test config file
@@ -4,7 +4,9 @@
 //! logging system with support for both human-readable and JSON output formats.
 
 use crate::config::LoggingSettings;
-use tracing::info;
+use horizon_event_system::async_logging::{self, LogRoute};
+use horizon_event_system::LogLevel;
+use tracing::{info, warn};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 /// Initializes the logging system with the specified configuration.
@@ -65,6 +67,38 @@ pub fn setup_logging(
     Ok(())
 }
 
+/// Applies per-plugin log routes to the global async logger.
+/// 
+/// Each configured plugin gets its own level filter and, optionally, its own
+/// log file. Must be called after the global async logger is initialized.
+/// 
+/// # Arguments
+/// 
+/// * `config` - Logging configuration from the config file
+pub fn setup_plugin_log_routes(config: &LoggingSettings) {
+    let logger = async_logging::global_async_logger();
+
+    for (plugin, settings) in &config.plugins {
+        let Ok(level) = settings.level.parse::<LogLevel>() else {
+            warn!("⚠️ Ignoring log route for plugin {}: invalid level {}", plugin, settings.level);
+            continue;
+        };
+
+        let mut route = LogRoute::new(level).with_propagate(settings.propagate);
+        if let Some(file_path) = &settings.file_path {
+            route = route.with_file(file_path);
+        }
+
+        info!(
+            "📝 Routing logs for plugin {} at level {} to {}",
+            plugin,
+            settings.level,
+            settings.file_path.as_deref().unwrap_or("the main log")
+        );
+        logger.set_route(plugin, route);
+    }
+}
+
 /// Displays the startup banner using proper logging.
 /// 
 /// Shows the Horizon server logo and version information using structured
@@ -0,0 +1,330 @@
+//! Plugin crate scaffolding for the `horizon plugin new` subcommand.
+//!
+//! Generates a ready-to-build plugin crate so plugin authors don't have to
+//! copy an existing plugin by hand. The generated crate pins the exact
+//! `horizon_event_system` version and Rust toolchain this server was built
+//! with, since plugins built against anything else are rejected by the
+//! loader's ABI check.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Options for generating a new plugin crate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginScaffold {
+    /// Plugin name as reported by `SimplePlugin::name` (e.g. `inventory`)
+    pub plugin_name: String,
+    /// Cargo package name (e.g. `plugin_inventory`)
+    pub crate_name: String,
+    /// Name of the generated plugin struct (e.g. `InventoryPlugin`)
+    pub struct_name: String,
+    /// Directory the crate is generated in
+    pub output_dir: PathBuf,
+    /// Optional local path to `horizon_event_system` instead of a version pin
+    pub event_system_path: Option<PathBuf>,
+}
+
+impl PluginScaffold {
+    /// Creates scaffold options for a plugin.
+    ///
+    /// The `plugin_` prefix is optional: `inventory` and `plugin_inventory`
+    /// both produce a crate named `plugin_inventory`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Plugin name (lowercase letters, digits, `_` or `-`)
+    /// * `parent_dir` - Directory in which the crate directory is created
+    ///
+    /// # Returns
+    ///
+    /// The scaffold options, or an error describing why the name is invalid.
+    pub fn new(name: &str, parent_dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let normalized = name.trim().replace('-', "_");
+        let plugin_name = normalized
+            .strip_prefix("plugin_")
+            .unwrap_or(&normalized)
+            .to_string();
+
+        let valid = plugin_name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase())
+            && plugin_name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid {
+            return Err(format!(
+                "Invalid plugin name '{name}': use lowercase letters, digits and underscores, starting with a letter"
+            )
+            .into());
+        }
+
+        let crate_name = format!("plugin_{plugin_name}");
+        let struct_name = plugin_name
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect::<String>()
+            + "Plugin";
+
+        Ok(Self {
+            output_dir: parent_dir.join(&crate_name),
+            plugin_name,
+            crate_name,
+            struct_name,
+            event_system_path: None,
+        })
+    }
+
+    /// Uses a local `horizon_event_system` checkout instead of the pinned registry version.
+    pub fn with_event_system_path(mut self, path: PathBuf) -> Self {
+        self.event_system_path = Some(path);
+        self
+    }
+
+    /// Writes the plugin crate to disk.
+    ///
+    /// # Returns
+    ///
+    /// The list of files that were created, or an error if the target
+    /// directory already exists or a file could not be written.
+    pub fn generate(&self) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+        if self.output_dir.exists() {
+            return Err(format!("{} already exists", self.output_dir.display()).into());
+        }
+
+        fs::create_dir_all(self.output_dir.join("src"))?;
+
+        let mut files = vec![
+            (self.output_dir.join("Cargo.toml"), self.cargo_toml()),
+            (self.output_dir.join("src").join("lib.rs"), self.lib_rs()),
+            (self.output_dir.join("plugin.toml"), self.manifest()),
+            (self.output_dir.join(".gitignore"), "/target\nCargo.lock\n".to_string()),
+        ];
+        if let Some(toolchain) = self.rust_toolchain() {
+            files.push((self.output_dir.join("rust-toolchain.toml"), toolchain));
+        }
+
+        for (path, contents) in &files {
+            fs::write(path, contents)?;
+        }
+
+        Ok(files.into_iter().map(|(path, _)| path).collect())
+    }
+
+    /// Renders the crate manifest with the ABI-relevant dependency pinned.
+    pub fn cargo_toml(&self) -> String {
+        let (event_system_version, _) = abi_components();
+        let event_system_dep = match &self.event_system_path {
+            Some(path) => format!(
+                "{{ path = \"{}\" }}",
+                path.display().to_string().replace('\\', "/")
+            ),
+            // Exact pin: the loader rejects plugins built against any other version
+            None => format!("\"={event_system_version}\""),
+        };
+
+        format!(
+            r#"[package]
+name = "{crate_name}"
+version = "0.1.0"
+edition = "2021"
+
+# Keep this crate out of any enclosing workspace so it builds on its own
+[workspace]
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+horizon_event_system = {event_system_dep}
+async-trait = "0.1"
+futures = "0.3"
+serde = {{ version = "1.0", features = ["derive"] }}
+serde_json = "1.0"
+tracing = "0.1"
+"#,
+            crate_name = self.crate_name,
+        )
+    }
+
+    /// Renders the plugin manifest declaring the plugin's client namespace.
+    ///
+    /// Install it next to the built library as `<library>.plugin.toml`.
+    pub fn manifest(&self) -> String {
+        format!(
+            "# Install next to the built library as lib{crate_name}.plugin.toml\nname = \"{plugin_name}\"\nversion = \"0.1.0\"\ncapabilities = []\nevent_namespaces = [\"{plugin_name}\"]\n",
+            crate_name = self.crate_name,
+            plugin_name = self.plugin_name,
+        )
+    }
+
+    /// Renders `rust-toolchain.toml` pinning the compiler the server was built with.
+    ///
+    /// Returns `None` when the server's compiler version is unknown.
+    pub fn rust_toolchain(&self) -> Option<String> {
+        let (_, rust_version) = abi_components();
+        if rust_version == "unknown" || rust_version.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            "# Plugins must be built with the same compiler as the server (ABI {abi})\n[toolchain]\nchannel = \"{rust_version}\"\n",
+            abi = horizon_event_system::ABI_VERSION,
+        ))
+    }
+
+    /// Renders the plugin source with example core, client and GORC handlers.
+    pub fn lib_rs(&self) -> String {
+        LIB_TEMPLATE
+            .replace("{{struct_name}}", &self.struct_name)
+            .replace("{{plugin_name}}", &self.plugin_name)
+    }
+}
+
+/// Splits the event system ABI version into `(crate_version, rust_version)`.
+fn abi_components() -> (&'static str, &'static str) {
+    horizon_event_system::ABI_VERSION
+        .split_once(':')
+        .unwrap_or((horizon_event_system::ABI_VERSION, "unknown"))
+}
+
+const LIB_TEMPLATE: &str = r#"use async_trait::async_trait;
+use horizon_event_system::{
+    create_simple_plugin, register_handlers, ClientConnectionRef, EventSystem, GorcEvent,
+    LogLevel, ObjectInstance, PlayerId, PluginError, ServerContext, SimplePlugin,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Example client event, sent by clients as `{"namespace": "{{plugin_name}}", "event": "ping", "data": {...}}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingEvent {
+    pub message: String,
+}
+
+pub struct {{struct_name}} {
+    name: String,
+}
+
+impl {{struct_name}} {
+    pub fn new() -> Self {
+        Self {
+            name: "{{plugin_name}}".to_string(),
+        }
+    }
+}
+
+impl Default for {{struct_name}} {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SimplePlugin for {{struct_name}} {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    async fn register_handlers(&mut self, events: Arc<EventSystem>, _context: Arc<dyn ServerContext>) -> Result<(), PluginError> {
+        // Core server events
+        register_handlers!(events; core {
+            "player_connected" => |event: serde_json::Value| {
+                info!("{{plugin_name}}: player connected {:?}", event);
+                Ok(())
+            }
+        })?;
+
+        // Client events (namespace "{{plugin_name}}")
+        register_handlers!(events; client {
+            "{{plugin_name}}", "ping" => |event: PingEvent, player_id: PlayerId, _connection: ClientConnectionRef| {
+                debug!("{{plugin_name}}: ping from {}: {}", player_id, event.message);
+                Ok(())
+            }
+        })?;
+
+        // GORC instance events (object type "Player", channel 0)
+        events
+            .on_gorc_instance("Player", 0, "move", |event: GorcEvent, _instance: &mut ObjectInstance| {
+                debug!("{{plugin_name}}: object {} moved", event.object_id);
+                Ok(())
+            })
+            .await
+            .map_err(|e| PluginError::ExecutionError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn on_init(&mut self, context: Arc<dyn ServerContext>) -> Result<(), PluginError> {
+        context.log(LogLevel::Info, "{{plugin_name}}: initialized");
+        Ok(())
+    }
+
+    async fn on_shutdown(&mut self, context: Arc<dyn ServerContext>) -> Result<(), PluginError> {
+        context.log(LogLevel::Info, "{{plugin_name}}: shutting down");
+        Ok(())
+    }
+}
+
+create_simple_plugin!({{struct_name}});
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_scaffold_names() {
+        let scaffold = PluginScaffold::new("space-mining", Path::new("plugins")).unwrap();
+        assert_eq!(scaffold.plugin_name, "space_mining");
+        assert_eq!(scaffold.crate_name, "plugin_space_mining");
+        assert_eq!(scaffold.struct_name, "SpaceMiningPlugin");
+        assert_eq!(scaffold.output_dir, PathBuf::from("plugins/plugin_space_mining"));
+
+        let prefixed = PluginScaffold::new("plugin_chat", Path::new(".")).unwrap();
+        assert_eq!(prefixed.crate_name, "plugin_chat");
+
+        assert!(PluginScaffold::new("", Path::new(".")).is_err());
+        assert!(PluginScaffold::new("1abc", Path::new(".")).is_err());
+        assert!(PluginScaffold::new("Bad Name", Path::new(".")).is_err());
+    }
+
+    #[test]
+    fn test_scaffold_generate() {
+        let dir = TempDir::new().unwrap();
+        let scaffold = PluginScaffold::new("inventory", dir.path()).unwrap();
+        let files = scaffold.generate().unwrap();
+        assert!(files.iter().all(|file| file.exists()));
+
+        let manifest = fs::read_to_string(scaffold.output_dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"plugin_inventory\""));
+        assert!(manifest.contains("crate-type = [\"cdylib\"]"));
+        let (version, _) = abi_components();
+        assert!(manifest.contains(&format!("horizon_event_system = \"={version}\"")));
+
+        let source = fs::read_to_string(scaffold.output_dir.join("src/lib.rs")).unwrap();
+        assert!(source.contains("create_simple_plugin!(InventoryPlugin);"));
+        assert!(source.contains("\"inventory\", \"ping\""));
+        assert!(!source.contains("{{"));
+
+        let manifest = fs::read_to_string(scaffold.output_dir.join("plugin.toml")).unwrap();
+        assert!(manifest.contains("name = \"inventory\""));
+        assert!(manifest.contains("event_namespaces = [\"inventory\"]"));
+
+        // Refuses to overwrite an existing crate
+        assert!(scaffold.generate().is_err());
+    }
+}
@@ -2,11 +2,25 @@
 //!
 //! This module provides a non-blocking logging system that offloads log processing
 //! to a dedicated thread, preventing main/hot threads from being blocked by stdout speed.
+//!
+//! Messages can be routed by target (plugins log with their plugin name as the
+//! target) to their own file with an independent level filter, so one noisy
+//! plugin can be debugged without drowning the main log. A plugin's `tracing`
+//! macros are routed the same way once it logs through a [`PluginSubscriber`].
 
 use crate::context::LogLevel;
-use std::sync::Arc;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::fmt::Write as _;
+use std::sync::{Arc, RwLock};
 use tokio::sync::mpsc;
-use tracing::{debug, error, info, trace, warn};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::Interest;
+use tracing::{debug, error, info, trace, warn, Dispatch, Event, Level, Metadata, Subscriber};
 
 /// Log message sent to the dedicated logging thread.
 #[derive(Debug, Clone)]
@@ -16,10 +30,48 @@ pub struct LogMessage {
     pub target: Option<String>,
 }
 
+/// Routing rule for messages logged with a specific target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRoute {
+    /// Most verbose level written for this target
+    pub level: LogLevel,
+    /// File the target's messages are appended to (None keeps them in the main log)
+    pub file_path: Option<PathBuf>,
+    /// Also write messages to the main log when a file is configured
+    pub propagate: bool,
+}
+
+impl LogRoute {
+    /// Creates a route that filters a target's messages in the main log.
+    pub fn new(level: LogLevel) -> Self {
+        Self {
+            level,
+            file_path: None,
+            propagate: false,
+        }
+    }
+
+    /// Writes the target's messages to a file instead of the main log.
+    pub fn with_file<P: Into<PathBuf>>(mut self, file_path: P) -> Self {
+        self.file_path = Some(file_path.into());
+        self
+    }
+
+    /// Keeps writing the target's messages to the main log as well.
+    pub fn with_propagate(mut self, propagate: bool) -> Self {
+        self.propagate = propagate;
+        self
+    }
+}
+
+/// Routes keyed by log target.
+type LogRoutes = Arc<RwLock<HashMap<String, LogRoute>>>;
+
 /// Asynchronous logging handle for non-blocking log operations.
 #[derive(Debug, Clone)]
 pub struct AsyncLogger {
     sender: mpsc::UnboundedSender<LogMessage>,
+    routes: LogRoutes,
 }
 
 impl AsyncLogger {
@@ -29,20 +81,53 @@ impl AsyncLogger {
     /// log messages without blocking the caller.
     pub fn new() -> Self {
         let (sender, mut receiver) = mpsc::unbounded_channel::<LogMessage>();
+        let routes: LogRoutes = Arc::new(RwLock::new(HashMap::new()));
+        let task_routes = routes.clone();
         
         // Spawn dedicated logging task
         tokio::spawn(async move {
+            let mut files = HashMap::new();
             while let Some(log_msg) = receiver.recv().await {
-                Self::write_log(log_msg);
+                Self::route_log(&task_routes, &mut files, log_msg);
             }
             
             // Process any remaining messages before shutdown
             while let Ok(log_msg) = receiver.try_recv() {
-                Self::write_log(log_msg);
+                Self::route_log(&task_routes, &mut files, log_msg);
             }
         });
         
-        Self { sender }
+        Self { sender, routes }
+    }
+
+    /// Routes a target's messages according to `route`, replacing any previous route.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Log target to route (a plugin's name for plugin logs)
+    /// * `route` - Level filter and destination for the target
+    pub fn set_route(&self, target: &str, route: LogRoute) {
+        self.routes
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(target.to_string(), route);
+    }
+
+    /// Removes a target's route so its messages go to the main log again.
+    pub fn remove_route(&self, target: &str) -> Option<LogRoute> {
+        self.routes
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(target)
+    }
+
+    /// Gets the route configured for a target, if any.
+    pub fn route(&self, target: &str) -> Option<LogRoute> {
+        self.routes
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(target)
+            .cloned()
     }
     
     /// Log a message asynchronously without blocking the caller.
@@ -72,6 +157,64 @@ impl AsyncLogger {
         }
     }
     
+    /// Applies the target's route to a message, writing it to its file and/or the main log.
+    fn route_log(routes: &LogRoutes, files: &mut HashMap<PathBuf, File>, log_msg: LogMessage) {
+        let route = log_msg.target.as_deref().and_then(|target| {
+            routes
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .get(target)
+                .cloned()
+        });
+
+        let Some(route) = route else {
+            Self::write_log(log_msg);
+            return;
+        };
+
+        if log_msg.level > route.level {
+            return;
+        }
+
+        let Some(file_path) = &route.file_path else {
+            Self::write_log(log_msg);
+            return;
+        };
+
+        if let Err(e) = Self::write_to_file(files, file_path, &log_msg) {
+            // Never lose the message because its file is unavailable
+            warn!("⚠️ Failed to write log file {}: {}", file_path.display(), e);
+            Self::write_log(log_msg);
+            return;
+        }
+
+        if route.propagate {
+            Self::write_log(log_msg);
+        }
+    }
+
+    /// Appends a message to a routed log file, opening it on first use.
+    fn write_to_file(files: &mut HashMap<PathBuf, File>, file_path: &Path, log_msg: &LogMessage) -> std::io::Result<()> {
+        let file = match files.entry(file_path.to_path_buf()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                if let Some(parent) = file_path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+                    std::fs::create_dir_all(parent)?;
+                }
+                entry.insert(OpenOptions::new().create(true).append(true).open(file_path)?)
+            }
+        };
+
+        writeln!(
+            file,
+            "{} {:?} {}: {}",
+            crate::utils::current_timestamp(),
+            log_msg.level,
+            log_msg.target.as_deref().unwrap_or("horizon"),
+            log_msg.message
+        )
+    }
+
     /// Internal method to write log messages using tracing.
     /// 
     /// This runs on the dedicated logging thread and performs the actual
@@ -98,6 +241,139 @@ impl AsyncLogger {
     }
 }
 
+/// Tracing subscriber for one plugin's `tracing` output.
+/// 
+/// A plugin library links its own copy of `tracing`, so installing this as
+/// its global default attributes everything it logs to the plugin. Events
+/// are checked against the plugin's route: routes with a file get the event
+/// through the async logger, everything else goes on to the server's
+/// subscriber.
+#[derive(Debug)]
+pub struct PluginSubscriber {
+    plugin_name: String,
+    inner: Dispatch,
+}
+
+impl PluginSubscriber {
+    /// Creates a subscriber that forwards to the server's current dispatcher.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `plugin_name` - Name of the plugin, used as the log target
+    pub fn new(plugin_name: &str) -> Self {
+        Self {
+            plugin_name: plugin_name.to_string(),
+            inner: tracing::dispatcher::get_default(Dispatch::clone),
+        }
+    }
+
+    /// Wraps the subscriber in a dispatcher a plugin can install.
+    pub fn dispatch(plugin_name: &str) -> Dispatch {
+        Dispatch::new(Self::new(plugin_name))
+    }
+
+    /// Gets the plugin's route, without starting the async logger if it isn't running.
+    fn route(&self) -> Option<LogRoute> {
+        GLOBAL_LOGGER.get()?.route(&self.plugin_name)
+    }
+}
+
+/// Converts a tracing level to the matching log level.
+fn log_level(level: &Level) -> LogLevel {
+    match *level {
+        Level::ERROR => LogLevel::Error,
+        Level::WARN => LogLevel::Warn,
+        Level::INFO => LogLevel::Info,
+        Level::DEBUG => LogLevel::Debug,
+        Level::TRACE => LogLevel::Trace,
+    }
+}
+
+/// Formats an event's message followed by its other fields.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+impl Subscriber for PluginSubscriber {
+    fn register_callsite(&self, _metadata: &'static Metadata<'static>) -> Interest {
+        // Routes can change at runtime, so decide per event
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        match self.route() {
+            Some(route) if route.file_path.is_some() => log_level(metadata.level()) <= route.level,
+            Some(route) => log_level(metadata.level()) <= route.level && self.inner.enabled(metadata),
+            None => self.inner.enabled(metadata),
+        }
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.inner.new_span(span)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        self.inner.record(span, values)
+    }
+
+    fn record_follows_from(&self, span: &Id, follows: &Id) {
+        self.inner.record_follows_from(span, follows)
+    }
+
+    fn event(&self, event: &Event<'_>) {
+        let routed_to_file = self.route().is_some_and(|route| route.file_path.is_some());
+        match GLOBAL_LOGGER.get() {
+            Some(logger) if routed_to_file => {
+                let mut visitor = MessageVisitor::default();
+                event.record(&mut visitor);
+                visitor.message.push_str(&visitor.fields);
+                logger.log_with_target(log_level(event.metadata().level()), &visitor.message, Some(&self.plugin_name));
+            }
+            _ => {
+                if self.inner.enabled(event.metadata()) {
+                    self.inner.event(event);
+                }
+            }
+        }
+    }
+
+    fn enter(&self, span: &Id) {
+        self.inner.enter(span)
+    }
+
+    fn exit(&self, span: &Id) {
+        self.inner.exit(span)
+    }
+
+    fn clone_span(&self, id: &Id) -> Id {
+        self.inner.clone_span(id)
+    }
+
+    fn try_close(&self, id: Id) -> bool {
+        self.inner.try_close(id)
+    }
+}
+
 /// Global async logger instance for use throughout the application.
 /// 
 /// This provides a singleton pattern for the async logger while maintaining
@@ -120,4 +396,76 @@ pub fn global_async_logger() -> Arc<AsyncLogger> {
     GLOBAL_LOGGER
         .get_or_init(|| AsyncLogger::shared())
         .clone()
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_routes_target_to_file_with_level_filter() {
+        let log_dir = std::env::temp_dir().join(format!("horizon_logs_{}", uuid::Uuid::new_v4()));
+        let log_path = log_dir.join("noisy.log");
+
+        let logger = AsyncLogger::new();
+        logger.set_route("noisy", LogRoute::new(LogLevel::Debug).with_file(&log_path));
+        assert_eq!(logger.route("noisy").unwrap().level, LogLevel::Debug);
+
+        logger.log_with_target(LogLevel::Debug, "debugging noisy", Some("noisy"));
+        logger.log_with_target(LogLevel::Trace, "too verbose", Some("noisy"));
+        logger.log_with_target(LogLevel::Info, "from another plugin", Some("quiet"));
+
+        let mut contents = String::new();
+        for _ in 0..100 {
+            contents = std::fs::read_to_string(&log_path).unwrap_or_default();
+            if !contents.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+
+        assert!(contents.contains("Debug noisy: debugging noisy"));
+        assert!(!contents.contains("too verbose"));
+        assert!(!contents.contains("another plugin"));
+
+        assert!(logger.remove_route("noisy").is_some());
+        assert!(logger.route("noisy").is_none());
+        let _ = std::fs::remove_dir_all(log_dir);
+    }
+
+    #[tokio::test]
+    async fn test_plugin_tracing_follows_its_route() {
+        let log_dir = std::env::temp_dir().join(format!("horizon_logs_{}", uuid::Uuid::new_v4()));
+        let log_path = log_dir.join("chatty.log");
+        global_async_logger().set_route("chatty", LogRoute::new(LogLevel::Debug).with_file(&log_path));
+
+        let dispatch = PluginSubscriber::dispatch("chatty");
+        tracing::dispatcher::with_default(&dispatch, || {
+            debug!(player = 7, "joined the lobby");
+            trace!("too verbose");
+        });
+
+        let mut contents = String::new();
+        for _ in 0..100 {
+            contents = std::fs::read_to_string(&log_path).unwrap_or_default();
+            if !contents.is_empty() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+
+        assert!(contents.contains("Debug chatty: joined the lobby player=7"));
+        assert!(!contents.contains("too verbose"));
+
+        global_async_logger().remove_route("chatty");
+        let _ = std::fs::remove_dir_all(log_dir);
+    }
+
+    #[test]
+    fn test_log_level_parsing_and_order() {
+        assert_eq!("DEBUG".parse::<LogLevel>(), Ok(LogLevel::Debug));
+        assert_eq!("warning".parse::<LogLevel>(), Ok(LogLevel::Warn));
+        assert!("verbose".parse::<LogLevel>().is_err());
+        assert!(LogLevel::Error < LogLevel::Info);
+        assert!(LogLevel::Trace > LogLevel::Debug);
+    }
+}
@@ -11,6 +11,7 @@
 //! - **Logging** - Structured logging integrated with server infrastructure
 //! - **Player Communication** - Direct messaging and broadcasting capabilities
 //! - **Region Information** - Context about the current game region
+//! - **Content Filtering** - The server's chat filter, shared by every plugin
 //!
 //! ## Design Principles
 //!
@@ -25,11 +26,12 @@
 //! threads concurrently. The context uses appropriate synchronization
 //! internally to ensure data consistency.
 
-use crate::system::EventSystem;
-use crate::types::{PlayerId, RegionId};
+use crate::system::{EventSystem, TimerHandle};
+use crate::types::{PlayerId, RegionId, Vec3};
 use async_trait::async_trait;
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::time::Duration;
 use luminal;
 
 // ============================================================================
@@ -106,6 +108,21 @@ pub trait ServerContext: Send + Sync + Debug {
     /// * `message` - The message to log
     fn log(&self, level: LogLevel, message: &str);
 
+    /// Returns the dispatcher a plugin's `tracing` macros should log through.
+    /// 
+    /// A plugin library links its own copy of `tracing`, so its `info!` or
+    /// `debug!` calls don't reach the server's subscriber until it installs
+    /// this dispatcher as its global default. Plugins built with
+    /// `create_simple_plugin!` do this in `pre_init`.
+    /// 
+    /// # Returns
+    /// 
+    /// The dispatcher to install, or `None` if the context doesn't forward
+    /// plugin tracing output.
+    fn log_dispatch(&self) -> Option<tracing::Dispatch> {
+        None
+    }
+
     /// Sends raw data to a specific player.
     /// 
     /// This method bypasses the event system and sends data directly to a
@@ -138,6 +155,69 @@ pub trait ServerContext: Send + Sync + Debug {
     /// if the broadcast failed.
     async fn broadcast(&self, data: &[u8]) -> Result<(), ServerError>;
 
+    /// Sends raw data to each of the given players.
+    /// 
+    /// Players that aren't connected are skipped.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `players` - Players to send to
+    /// * `data` - Raw bytes to send
+    /// 
+    /// # Returns
+    /// 
+    /// Returns the number of players the data was queued for.
+    async fn broadcast_to_players(&self, players: &[PlayerId], data: &[u8]) -> Result<usize, ServerError> {
+        let mut sent = 0;
+        for &player_id in players {
+            if self.send_to_player(player_id, data).await.is_ok() {
+                sent += 1;
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Sends raw data to every connected player except one, such as the
+    /// player whose action is being announced.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `excluded` - Player that doesn't receive the data
+    /// * `data` - Raw bytes to broadcast
+    /// 
+    /// # Returns
+    /// 
+    /// Returns the number of players the data was queued for, or a
+    /// `ServerError` if the context doesn't track connections.
+    async fn broadcast_except(&self, excluded: PlayerId, _data: &[u8]) -> Result<usize, ServerError> {
+        Err(ServerError::Internal(format!(
+            "Cannot broadcast to everyone but player {}: filtered broadcasts are not available in this server context",
+            excluded
+        )))
+    }
+
+    /// Sends raw data to every player within `radius` of `center`.
+    /// 
+    /// Player positions come from the GORC instance manager.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `center` - Center of the area
+    /// * `radius` - Maximum distance from the center (inclusive)
+    /// * `data` - Raw bytes to send
+    /// 
+    /// # Returns
+    /// 
+    /// Returns the number of players the data was queued for, or a
+    /// `ServerError` if GORC is not enabled for this server context.
+    async fn broadcast_to_radius(&self, center: Vec3, radius: f64, data: &[u8]) -> Result<usize, ServerError> {
+        let instances = self.gorc_instance_manager().ok_or_else(|| {
+            ServerError::Internal("Cannot broadcast to an area: GORC is not enabled for this server context".to_string())
+        })?;
+        let players = instances.find_players_in_radius(center, radius).await;
+        self.broadcast_to_players(&players, data).await
+    }
+
     /// Returns the luminal runtime handle for cross-DLL compatibility.
     /// 
     /// This provides plugins with access to a luminal runtime for async operations
@@ -162,6 +242,177 @@ pub trait ServerContext: Send + Sync + Debug {
     /// Returns an Arc to the GorcInstanceManager if available, or None if GORC
     /// is not enabled for this server context.
     fn gorc_instance_manager(&self) -> Option<Arc<crate::gorc::GorcInstanceManager>>;
+
+    /// Returns a spatial query handle over tracked player and object positions.
+    /// 
+    /// Gameplay plugins use this for proximity logic such as area-of-effect
+    /// damage or proximity mines instead of keeping their own position maps.
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a query handle backed by the GORC instance manager, or None if
+    /// GORC is not enabled for this server context.
+    fn spatial(&self) -> Option<crate::gorc::SpatialQueryHandle> {
+        self.gorc_instance_manager().map(crate::gorc::SpatialQueryHandle::new)
+    }
+
+    /// Emits a [`TimerEvent`](crate::TimerEvent) as a core event every `period`.
+    /// 
+    /// Timers run on a schedule of their own, independent of the server tick
+    /// rate, so plugins don't need to count `server_tick` events for periodic
+    /// work. Scheduling a name that is already in use replaces the earlier
+    /// timer. Timers scheduled during plugin initialization are cancelled when
+    /// the plugin is unloaded.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `name` - Unique name of the timer
+    /// * `period` - Time between events (must be non-zero)
+    /// * `event_name` - Core event the timer emits
+    /// 
+    /// # Returns
+    /// 
+    /// Returns a handle that cancels the timer, or a `ServerError` if the
+    /// context can't run timers or the period is zero.
+    fn schedule_interval(&self, name: &str, _period: Duration, _event_name: &str) -> Result<TimerHandle, ServerError> {
+        Err(ServerError::Internal(format!(
+            "Cannot schedule timer '{}': interval timers are not available in this server context",
+            name
+        )))
+    }
+
+    /// Cancels an interval timer by name.
+    /// 
+    /// # Returns
+    /// 
+    /// Returns `true` if a timer with that name was running.
+    fn cancel_interval(&self, name: &str) -> bool {
+        self.events().cancel_interval(name)
+    }
+
+    /// Gets the traffic statistics of a player's connection.
+    /// 
+    /// Gameplay and anti-cheat plugins use these to spot clients that lag
+    /// behind (a growing queue depth) or flood the server (a fast-rising
+    /// received message count).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - Player whose connection to read
+    /// 
+    /// # Returns
+    /// 
+    /// The statistics, or `None` if the player isn't connected or the context
+    /// doesn't track connections.
+    async fn connection_stats(&self, _player_id: PlayerId) -> Option<crate::ConnectionStats> {
+        None
+    }
+
+    /// Gets the protocol version a player's client negotiated and the client
+    /// version it declared, so plugins can gate features on them.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - Player whose connection to read
+    /// 
+    /// # Returns
+    /// 
+    /// The versions, or `None` if the player isn't connected or the context
+    /// doesn't track connections.
+    async fn client_version(&self, _player_id: PlayerId) -> Option<crate::ClientVersion> {
+        None
+    }
+
+    /// Gets a value stored on a player's connection.
+    /// 
+    /// Connection metadata is shared by every plugin and handler, and is read
+    /// and written the same way through
+    /// [`ClientConnectionRef`](crate::ClientConnectionRef).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - Player whose connection to read
+    /// * `key` - Key the value was stored under
+    /// 
+    /// # Returns
+    /// 
+    /// The value, or `None` if the player isn't connected or nothing is stored
+    /// under the key.
+    async fn connection_metadata(&self, _player_id: PlayerId, _key: &str) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Stores a value on a player's connection, or removes it.
+    /// 
+    /// Values live as long as the connection.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - Player whose connection to update
+    /// * `key` - Key to store the value under
+    /// * `value` - The value, or `None` to remove the key
+    /// 
+    /// # Returns
+    /// 
+    /// Returns `Ok(())` if the connection was updated, or a `ServerError` if the
+    /// player isn't connected or the context doesn't track connections.
+    async fn set_connection_metadata(&self, player_id: PlayerId, _key: &str, _value: Option<serde_json::Value>) -> Result<(), ServerError> {
+        Err(ServerError::Internal(format!(
+            "Cannot update player {}: connection metadata is not available in this server context",
+            player_id
+        )))
+    }
+
+    /// Gets the server's chat content filter, so every chat plugin applies
+    /// the same word lists, rules and mutes.
+    /// 
+    /// # Returns
+    /// 
+    /// The filter, or `None` if the server has no filter rules configured.
+    fn content_filter(&self) -> Option<Arc<dyn ContentFilter>> {
+        None
+    }
+}
+
+/// Filter for player-written text such as chat messages.
+/// 
+/// The server configures the word lists and rules, and hands the filter to
+/// plugins through [`ServerContext::content_filter`].
+pub trait ContentFilter: Send + Sync + Debug {
+    /// Checks a message a player wants to send.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `player_id` - Player sending the message
+    /// * `text` - The message
+    /// * `locale` - Language of the message, such as `"en"` or `"pt-BR"`;
+    ///   without one, the rules of every locale apply
+    /// 
+    /// # Returns
+    /// 
+    /// What to do with the message.
+    fn check(&self, player_id: PlayerId, text: &str, locale: Option<&str>) -> ContentVerdict;
+}
+
+/// What to do with a message a [`ContentFilter`] checked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentVerdict {
+    /// Deliver the message as it is
+    Allow,
+    /// Deliver this text instead, with the offending words masked
+    Censor(String),
+    /// Drop the message
+    Drop {
+        /// Name of the rule the message broke
+        rule: String,
+    },
+    /// Drop the message, and every other message from the player until the mute ends
+    Mute {
+        /// Name of the rule the player broke
+        rule: String,
+        /// Unix timestamp when the mute ends
+        until: u64,
+    },
 }
 
 // ============================================================================
@@ -174,6 +425,9 @@ pub trait ServerContext: Send + Sync + Debug {
 /// the server's logging infrastructure. Higher levels indicate more
 /// severe or important messages.
 /// 
+/// Levels compare from most to least severe (`Error < Trace`), so a message
+/// passes a level filter when `message_level <= filter_level`.
+/// 
 /// # Level Guidelines
 /// 
 /// - **Error**: System errors, plugin failures, critical issues
@@ -196,7 +450,7 @@ pub trait ServerContext: Send + Sync + Debug {
 /// context.log(LogLevel::Warn, "Player inventory is nearly full");
 /// context.log(LogLevel::Error, "Failed to load combat configuration");
 /// ```
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LogLevel {
     /// Critical errors that may affect system stability
     Error,
@@ -210,6 +464,22 @@ pub enum LogLevel {
     Trace,
 }
 
+impl std::str::FromStr for LogLevel {
+    type Err = String;
+
+    /// Parses a level name (`error`, `warn`, `info`, `debug`, `trace`), ignoring case.
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        match level.to_ascii_lowercase().as_str() {
+            "error" => Ok(LogLevel::Error),
+            "warn" | "warning" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            "trace" => Ok(LogLevel::Trace),
+            _ => Err(format!("Invalid log level: {level}")),
+        }
+    }
+}
+
 /// Errors that can occur during server operations.
 /// 
 /// This enum covers error conditions that can arise when plugins interact
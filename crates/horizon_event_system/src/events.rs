@@ -22,7 +22,7 @@
 //! - **Performance**: Efficient serialization and handler dispatch
 //! - **Extensibility**: Easy to add new event types by implementing [`Event`]
 
-use crate::types::{PlayerId, RegionId, RegionBounds, DisconnectReason, AuthenticationStatus};
+use crate::types::{PlayerId, RegionId, RegionBounds, Vec3, DisconnectReason, AuthenticationStatus};
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{any::{Any, TypeId}, fmt::Debug};
@@ -60,6 +60,23 @@ pub trait Event: Send + Sync + Any + std::fmt::Debug {
     /// Returns `Ok(Vec<u8>)` containing the serialized event data, or
     /// `Err(EventError)` if serialization fails.
     fn serialize(&self) -> Result<Vec<u8>, EventError>;
+
+    /// Serializes the event with the given codec.
+    /// 
+    /// Binary codecs prefix the payload with a header naming the codec, so
+    /// [`deserialize`](Self::deserialize) decodes it without being told how
+    /// it was encoded.
+    /// 
+    /// # Returns
+    /// 
+    /// Returns `Ok(Vec<u8>)` containing the encoded event, or
+    /// `Err(EventError)` if the event can't be encoded with the codec.
+    fn serialize_with(&self, codec: crate::system::EventCodec) -> Result<Vec<u8>, EventError> {
+        match codec {
+            crate::system::EventCodec::Json => self.serialize(),
+            other => Err(EventError::Codec(format!("{} encoding is not supported by this event type", other.name()))),
+        }
+    }
     
     /// Deserializes an event from bytes.
     /// 
@@ -116,7 +133,35 @@ where
         })
     }
 
+    fn serialize_with(&self, codec: crate::system::EventCodec) -> Result<Vec<u8>, EventError> {
+        if codec == crate::system::EventCodec::Json {
+            return Event::serialize(self);
+        }
+        codec.encode(self).inspect_err(|e| {
+            tracing::error!(
+                "🔴 Event {} encoding failed for type '{}': {}",
+                codec.name(),
+                Self::type_name(),
+                e
+            );
+        })
+    }
+
     fn deserialize(data: &[u8]) -> Result<Self, EventError> {
+        // Binary payloads name their codec in a header
+        let codec = crate::system::EventCodec::of(data);
+        if codec != crate::system::EventCodec::Json {
+            return crate::system::EventCodec::decode(data).inspect_err(|e| {
+                tracing::error!(
+                    "🔴 Event {} decoding failed for type '{}': {} (data length: {} bytes)",
+                    codec.name(),
+                    Self::type_name(),
+                    e,
+                    data.len()
+                );
+            });
+        }
+
         serde_json::from_slice(data).map_err(|e| {
             let type_name = Self::type_name();
             let data_preview = if data.len() > 200 {
@@ -172,6 +217,14 @@ pub trait EventHandler: Send + Sync + 'static + Debug {
     
     /// Returns a human-readable name for this handler for debugging.
     fn handler_name(&self) -> &str;
+
+    /// Checks whether the handler wants an event, before it is deserialized.
+    ///
+    /// Handlers that decline an event are skipped without being invoked.
+    /// Handlers accept every event by default.
+    fn accepts(&self, _data: &[u8]) -> bool {
+        true
+    }
 }
 
 /// Type-safe wrapper for event handlers.
@@ -376,6 +429,43 @@ pub struct PlayerDisconnectedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a dropped player's client resumes its session.
+/// 
+/// The player keeps its ID, GORC subscriptions and player object, and no
+/// `player_disconnected` or `player_connected` event is emitted for the
+/// interruption. It's used for:
+/// - Resending state the client may have missed while away
+/// - Logging reconnects
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use horizon_event_system::{PlayerReconnectedEvent, PlayerId, current_timestamp};
+/// 
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #     let events = horizon_event_system::create_horizon_event_system();
+/// events.emit_core("player_reconnected", &PlayerReconnectedEvent {
+///     player_id: PlayerId::new(),
+///     connection_id: "conn_def456".to_string(),
+///     remote_addr: "192.168.1.100:45679".to_string(),
+///     timestamp: current_timestamp(),
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerReconnectedEvent {
+    /// Unique identifier for the player, unchanged by the reconnect
+    pub player_id: PlayerId,
+    /// Identifier of the new connection
+    pub connection_id: String,
+    /// Remote address of the new connection
+    pub remote_addr: String,
+    /// Unix timestamp when the session was resumed
+    pub timestamp: u64,
+}
+
 /// Event emitted to set the authentication status of a player.
 /// 
 /// This event allows backend plugins to set the authentication status
@@ -529,6 +619,7 @@ pub struct AuthenticationStatusChangedEvent {
 ///     player_id,
 ///     old_position: Some(Vec3::new(100.0, 0.0, 200.0)),
 ///     new_position: Vec3::new(110.0, 0.0, 205.0),
+///     velocity: Some(Vec3::new(20.0, 0.0, 10.0)),
 ///     timestamp: current_timestamp(),
 /// }).await?;
 /// #     Ok(())
@@ -542,10 +633,176 @@ pub struct PlayerMovementEvent {
     pub old_position: Option<crate::types::Vec3>,
     /// New position 
     pub new_position: crate::types::Vec3,
+    /// Velocity in units per second (if known), used to dead-reckon the
+    /// player between movement updates
+    #[serde(default)]
+    pub velocity: Option<crate::types::Vec3>,
     /// Unix timestamp when the movement occurred
     pub timestamp: u64,
 }
 
+/// Event emitted when authority over a GORC object changes hands.
+/// 
+/// Authority decides who may push state for an object: the server or a
+/// single player. It moves, for example, when a player boards another
+/// player's ship.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use horizon_event_system::{AuthorityChangedEvent, ObjectAuthority, GorcObjectId, PlayerId, current_timestamp};
+/// 
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #     let events = horizon_event_system::create_horizon_event_system();
+/// events.emit_core("authority_changed", &AuthorityChangedEvent {
+///     object_id: GorcObjectId::new(),
+///     previous_authority: ObjectAuthority::Server,
+///     new_authority: ObjectAuthority::Player(PlayerId::new()),
+///     timestamp: current_timestamp(),
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorityChangedEvent {
+    /// Object whose authority changed
+    pub object_id: crate::gorc::instance::GorcObjectId,
+    /// Authority before the change
+    pub previous_authority: crate::gorc::instance::ObjectAuthority,
+    /// Authority after the change
+    pub new_authority: crate::gorc::instance::ObjectAuthority,
+    /// Unix timestamp when the authority changed
+    pub timestamp: u64,
+}
+
+/// Event emitted when a player enters one of a GORC object's replication zones.
+/// 
+/// Emitted as the `gorc_zone_entered` core event whenever the movement
+/// pipeline subscribes a player to an object channel, whether the player or
+/// the object moved. Systems such as aggro or stealth can react to it without
+/// owning the movement pipeline.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use horizon_event_system::{GorcZoneEnteredEvent, GorcObjectId, PlayerId, current_timestamp};
+/// 
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #     let events = horizon_event_system::create_horizon_event_system();
+/// events.emit_core("gorc_zone_entered", &GorcZoneEnteredEvent {
+///     player_id: PlayerId::new(),
+///     object_id: GorcObjectId::new(),
+///     object_type: "Asteroid".to_string(),
+///     channel: 0,
+///     timestamp: current_timestamp(),
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GorcZoneEnteredEvent {
+    /// Player that entered the zone
+    pub player_id: PlayerId,
+    /// Object owning the zone
+    pub object_id: crate::gorc::instance::GorcObjectId,
+    /// Type name of the object
+    pub object_type: String,
+    /// Replication channel of the zone
+    pub channel: u8,
+    /// Unix timestamp when the player entered the zone
+    pub timestamp: u64,
+}
+
+/// Event emitted when a player leaves one of a GORC object's replication zones.
+/// 
+/// Emitted as the `gorc_zone_exited` core event when a player or object moves
+/// out of range, or when the object is despawned while the player is subscribed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GorcZoneExitedEvent {
+    /// Player that left the zone
+    pub player_id: PlayerId,
+    /// Object owning the zone
+    pub object_id: crate::gorc::instance::GorcObjectId,
+    /// Type name of the object
+    pub object_type: String,
+    /// Replication channel of the zone
+    pub channel: u8,
+    /// Unix timestamp when the player left the zone
+    pub timestamp: u64,
+}
+
+/// Event emitted when a GORC object is attached to a parent object.
+/// 
+/// Emitted as the `gorc_object_attached` core event and replicated to the
+/// child's subscribers as its `attached` instance event, on the child's
+/// lowest replication channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GorcObjectAttachedEvent {
+    /// Object that was attached
+    pub object_id: crate::gorc::instance::GorcObjectId,
+    /// Object it was attached to
+    pub parent_id: crate::gorc::instance::GorcObjectId,
+    /// Position of the object relative to its parent
+    pub offset: crate::types::Vec3,
+    /// Unix timestamp when the object was attached
+    pub timestamp: u64,
+}
+
+/// Event emitted when a GORC object is detached from its parent object.
+/// 
+/// Emitted as the `gorc_object_detached` core event and replicated to the
+/// child's subscribers as its `detached` instance event, on the child's
+/// lowest replication channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GorcObjectDetachedEvent {
+    /// Object that was detached
+    pub object_id: crate::gorc::instance::GorcObjectId,
+    /// Object it was attached to
+    pub parent_id: crate::gorc::instance::GorcObjectId,
+    /// Unix timestamp when the object was detached
+    pub timestamp: u64,
+}
+
+/// Event emitted when the virtualization manager merges overlapping zones
+/// into a virtual zone.
+/// 
+/// Emitted as the `gorc_virtual_zone_merged` core event, so plugins can
+/// adapt to dense areas (e.g. send aggregate rendering hints for a field
+/// of asteroids).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GorcVirtualZoneMergedEvent {
+    /// The new virtual zone
+    pub virtual_id: crate::gorc::virtualization::VirtualZoneId,
+    /// Replication channel of the merged zones
+    pub channel: u8,
+    /// Center of the virtual zone
+    pub center: crate::types::Vec3,
+    /// Radius of the virtual zone
+    pub radius: f64,
+    /// Objects whose zones were merged
+    pub object_ids: Vec<crate::gorc::instance::GorcObjectId>,
+    /// Unix timestamp when the zones were merged
+    pub timestamp: u64,
+}
+
+/// Event emitted when the virtualization manager splits a virtual zone
+/// back into its objects' own zones.
+/// 
+/// Emitted as the `gorc_virtual_zone_split` core event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GorcVirtualZoneSplitEvent {
+    /// The removed virtual zone
+    pub virtual_id: crate::gorc::virtualization::VirtualZoneId,
+    /// Replication channel of the virtual zone
+    pub channel: u8,
+    /// Objects that were members of the virtual zone
+    pub object_ids: Vec<crate::gorc::instance::GorcObjectId>,
+    /// Unix timestamp when the virtual zone was split
+    pub timestamp: u64,
+}
+
 /// Event emitted when a plugin is successfully loaded.
 /// 
 /// This event signals that a plugin has been loaded into the server and
@@ -575,7 +832,7 @@ pub struct PluginLoadedEvent {
     pub plugin_name: String,
     /// Version string of the plugin
     pub version: String,
-    /// List of capabilities or features provided by this plugin
+    /// Capabilities declared in the plugin's manifest (empty if it has none)
     pub capabilities: Vec<String>,
     /// Unix timestamp when the plugin was loaded
     pub timestamp: u64,
@@ -609,6 +866,24 @@ pub struct PluginUnloadedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when the plugin watchdog observes a change in plugin health.
+/// 
+/// Emitted as `core:plugin_health_changed` so operators and monitoring
+/// plugins can see which plugin is degraded or stuck.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginHealthChangedEvent {
+    /// Name of the plugin whose health changed
+    pub plugin_name: String,
+    /// Previous health state (e.g. "Healthy")
+    pub previous: String,
+    /// New health state (e.g. "Unresponsive")
+    pub current: String,
+    /// Reason reported by the plugin or the watchdog, if any
+    pub detail: Option<String>,
+    /// Unix timestamp when the change was observed
+    pub timestamp: u64,
+}
+
 /// Event emitted when a game region is started.
 /// 
 /// Regions are logical areas of the game world that can be managed
@@ -674,6 +949,288 @@ pub struct RegionStoppedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted by an interval timer each time its period elapses.
+///
+/// Timers are scheduled with
+/// [`ServerContext::schedule_interval`](crate::ServerContext::schedule_interval)
+/// and emit this as the core event named when scheduling, independent of the
+/// server tick rate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimerEvent {
+    /// Name the timer was scheduled under
+    pub name: String,
+    /// Number of times the timer has fired, starting at 1
+    pub tick: u64,
+    /// Timer period in milliseconds
+    pub period_ms: u64,
+    /// Unix timestamp when the timer fired
+    pub timestamp: u64,
+}
+
+/// Event emitted when a player crosses into a neighboring region server.
+///
+/// Emitted as the `player_region_handoff` core event by the region federation
+/// scan. When the neighbor has a client URL configured, the server hands the
+/// player's session to it and redirects the client itself; otherwise
+/// connection handling plugins use the event to move the client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerRegionHandoffEvent {
+    /// Player that left this region
+    pub player_id: PlayerId,
+    /// Name of the neighboring region the player entered
+    pub neighbor: String,
+    /// Player position when the crossing was detected
+    pub position: Vec3,
+    /// Unix timestamp when the crossing was detected
+    pub timestamp: u64,
+}
+
+/// Event emitted when a player handed off by a neighboring region connects.
+///
+/// Emitted as the `player_region_arrival` core event instead of
+/// `player_connected`. The player keeps the ID it had in the source region,
+/// and its player object arrived with the handoff and is already registered
+/// as owned by the player, so plugins pick it up rather than spawn a new one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerRegionArrivalEvent {
+    /// Player that arrived, with the ID it had in the source region
+    pub player_id: PlayerId,
+    /// Identifier of the player's connection to this region
+    pub connection_id: String,
+    /// Remote address of the connection
+    pub remote_addr: String,
+    /// Name of the region the player came from
+    pub source_region: String,
+    /// Unix timestamp when the player connected
+    pub timestamp: u64,
+}
+
+/// Request to disconnect a player, emitted by moderation or anti-cheat plugins.
+///
+/// Emit it as the `kick_player` core event. The server sends the client a
+/// `{"event": "kicked", "reason": ..., "message": ...}` notice, then closes
+/// the connection with the reason code. Kicked players can't resume their
+/// session. Only server-side code can emit core events, so clients can't
+/// forge kicks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KickPlayerEvent {
+    /// Player to disconnect
+    pub player_id: PlayerId,
+    /// Machine-readable reason, e.g. `"speed_hack"` or `"afk"`
+    pub reason_code: String,
+    /// Optional human-readable explanation shown to the player
+    pub message: Option<String>,
+    /// Name of the plugin or system requesting the kick
+    pub requested_by: String,
+    /// Unix timestamp of the request
+    pub timestamp: u64,
+}
+
+/// Request to ban a player and disconnect them.
+///
+/// Emit it as the `ban_player` core event. Works like [`KickPlayerEvent`],
+/// except that the notice's event is `"banned"` and the player, the remote
+/// address of its connection and its client hardware are refused from then
+/// on, until the ban expires.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BanPlayerEvent {
+    /// Player to ban
+    pub player_id: PlayerId,
+    /// Machine-readable reason, e.g. `"cheating"`
+    pub reason_code: String,
+    /// Optional human-readable explanation shown to the player
+    pub message: Option<String>,
+    /// Name of the plugin or system requesting the ban
+    pub requested_by: String,
+    /// How long the ban lasts in seconds, or `None` for a permanent ban
+    #[serde(default)]
+    pub duration_secs: Option<u64>,
+    /// Unix timestamp of the request
+    pub timestamp: u64,
+}
+
+/// Request to broadcast an event to every connected player, regardless of zones.
+///
+/// Emit it as the `gorc_broadcast` core event, e.g. for server announcements
+/// and world boss spawns. The server queues it on the GORC broadcast channel,
+/// so it is batched and prioritised with regular replication, and clients
+/// receive it with the nil object ID and `event_type` as its object type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GorcBroadcastEvent {
+    /// Name clients receive the event under, e.g. `"world_boss_spawned"`
+    pub event_type: String,
+    /// Serialized event payload
+    pub data: Vec<u8>,
+    /// Queue priority of the event
+    pub priority: crate::gorc::ReplicationPriority,
+    /// Unix timestamp of the request
+    pub timestamp: u64,
+}
+
+/// Event emitted when a client proves its identity with a signed token.
+///
+/// Emitted as the `player_authenticated` core event once the server verified
+/// the JWT a client presented after connecting, or the token it logged in
+/// with at an identity provider. The player's authentication status is
+/// `Authenticated` by then, and plugins can load the account the token's
+/// subject names.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerAuthenticatedEvent {
+    /// Player the client controls; the same on every login of a provider identity
+    pub player_id: PlayerId,
+    /// Subject (`sub` claim) of the token, usually an account ID
+    pub subject: String,
+    /// Identity provider the client logged in through, `None` for a JWT
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Every claim of the token, or what the provider said about the user
+    pub claims: serde_json::Value,
+    /// Whether the player was a guest until now and kept its session
+    #[serde(default)]
+    pub upgraded_from_guest: bool,
+    /// Unix timestamp when the token was verified
+    pub timestamp: u64,
+}
+
+/// Event emitted when a player's message is dropped for exceeding a rate limit.
+///
+/// Emitted as the `client_rate_limited` core event by the message router for
+/// every dropped message, so plugins can warn, throttle or kick players that
+/// keep flooding a namespace or event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientRateLimitedEvent {
+    /// Player whose message was dropped
+    pub player_id: PlayerId,
+    /// Namespace of the dropped message (`gorc` for native GORC events)
+    pub namespace: String,
+    /// Event name of the dropped message
+    pub event: String,
+    /// Configured limit that was exceeded: `namespace` or `namespace:event`
+    /// for an event type's limit, or `player`, `ip` or `global`
+    pub limit_key: String,
+    /// Messages per second the limit allows
+    pub limit_per_sec: u32,
+    /// Unix timestamp when the message was dropped
+    pub timestamp: u64,
+}
+
+/// Event emitted when a heartbeat measures a player's round-trip time.
+///
+/// Emitted as the `player_latency` core event for every answered heartbeat
+/// ping, so the server's network engine can scale the player's replication
+/// frequencies to the quality of their link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerLatencyEvent {
+    /// Player whose connection was measured
+    pub player_id: PlayerId,
+    /// Measured round-trip time in milliseconds
+    pub round_trip_ms: f32,
+    /// Unix timestamp when the heartbeat was answered
+    pub timestamp: u64,
+}
+
+/// Event emitted when a client acknowledges reliable replication updates.
+///
+/// Emitted as the `replication_ack` core event by the message router for
+/// every `{"type": "gorc_ack", "sequences": [...]}` message, so the server's
+/// network engine stops resending the acknowledged updates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplicationAckEvent {
+    /// Player who acknowledged the updates
+    pub player_id: PlayerId,
+    /// Sequence numbers of the reliable updates the client received
+    pub sequences: Vec<u32>,
+    /// Unix timestamp when the acknowledgement was received
+    pub timestamp: u64,
+}
+
+/// Event emitted when a client declares the object it is focused on.
+///
+/// Emitted as the `player_focus_changed` core event by the message router for
+/// every `{"type": "gorc_focus", "object_id": ...}` message, so the server's
+/// subscription manager replicates the focused object (e.g. the current
+/// combat target) at a higher priority and frequency for that player.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerFocusChangedEvent {
+    /// Player whose focus changed
+    pub player_id: PlayerId,
+    /// Focused object, or `None` if the client cleared its focus
+    pub object_id: Option<crate::gorc::instance::GorcObjectId>,
+    /// Unix timestamp when the focus changed
+    pub timestamp: u64,
+}
+
+/// A security-relevant action taken on the server.
+///
+/// Emitted as the `security_audit` core event for every ban, unban, kick,
+/// rate-limit trip, oversized message, ban evasion attempt, unsafe plugin
+/// override and admin API call, and written
+/// to the server's audit log, so moderation plugins can follow along.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecurityAuditEvent {
+    /// What was done
+    pub action: AuditAction,
+    /// Who did it: `admin_api`, `grpc`, `server` or the requesting plugin
+    pub actor: String,
+    /// Player, address, hardware or plugin it was done to, if any
+    #[serde(default)]
+    pub target: Option<String>,
+    /// Reason, route or other details
+    #[serde(default)]
+    pub detail: Option<String>,
+    /// Unix timestamp when it was done
+    pub timestamp: u64,
+}
+
+impl SecurityAuditEvent {
+    /// Creates an event for an action taken now
+    pub fn new(action: AuditAction, actor: impl Into<String>) -> Self {
+        Self {
+            action,
+            actor: actor.into(),
+            target: None,
+            detail: None,
+            timestamp: crate::utils::current_timestamp(),
+        }
+    }
+
+    /// Names what the action was done to
+    pub fn with_target(mut self, target: impl ToString) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+
+    /// Adds the reason or other details
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// Kinds of action recorded in the security audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    /// A player, address or hardware was banned
+    Ban,
+    /// A ban was lifted
+    Unban,
+    /// A player was disconnected
+    Kick,
+    /// A player's message was dropped for exceeding a rate limit
+    RateLimited,
+    /// A player's message was dropped for exceeding the size limit
+    OversizedMessage,
+    /// A banned player or client hardware tried to connect from an address that isn't banned
+    BanEvasion,
+    /// A plugin was loaded despite failing a safety check
+    UnsafePluginOverride,
+    /// The IP allow and deny lists were replaced
+    IpFilterChanged,
+    /// A request reached the admin HTTP or gRPC API
+    AdminRequest,
+}
+
 /// Raw client message event for routing to plugins.
 /// 
 /// This event represents unprocessed messages received from game clients.
@@ -716,6 +1273,25 @@ pub struct RawClientMessageEvent {
     pub timestamp: u64,
 }
 
+/// Opaque binary message a client sent on a raw channel.
+///
+/// Clients send these as binary frames of a `0x00` marker byte, the channel
+/// as a big-endian `u16` and the payload. The server never parses the payload;
+/// it is handed to the handlers registered with
+/// [`EventSystem::on_client_binary`](crate::EventSystem::on_client_binary) for
+/// the channel, which suits voice frames and other custom binary protocols.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientBinaryMessage {
+    /// ID of the player who sent the message
+    pub player_id: PlayerId,
+    /// Raw channel the message was sent on
+    pub channel: u16,
+    /// The payload, exactly as the client sent it
+    pub data: Vec<u8>,
+    /// Unix timestamp when the message was received
+    pub timestamp: u64,
+}
+
 /// GORC (Game Object Replication Channels) event for object state replication.
 /// 
 /// This event represents a change in game object state that needs to be
@@ -866,6 +1442,27 @@ pub enum EventError {
     /// Runtime error when dealing with async operations
     #[error("Runtime error: {0}")]
     RuntimeError(String),
+    /// An emission was refused because its category's queue was full
+    #[error("Emission queue full: {0}")]
+    QueueFull(String),
+    /// A wildcard handler was registered with a pattern that contains no `*`
+    #[error("Invalid event pattern: {0}")]
+    InvalidPattern(String),
+    /// A request received no response in time
+    #[error("Request timed out: {0}")]
+    Timeout(String),
+    /// A payload did not match the schema registered for its event
+    #[error("{0}")]
+    SchemaViolation(crate::system::SchemaValidationError),
+    /// A schema was registered for an event whose schema belongs to another owner
+    #[error("Schema conflict: {0}")]
+    SchemaConflict(String),
+    /// A payload could not be encoded or decoded with its binary codec
+    #[error("Codec error: {0}")]
+    Codec(String),
+    /// A plugin used a reserved namespace or emitted outside its declared ones
+    #[error("Namespace violation: {0}")]
+    NamespaceViolation(String),
     #[error("An unexpected error occurred: {0}")]
     Other(String),
 }
@@ -1,4 +1,5 @@
 /// Replication layer definitions and management
+use super::lod::LodTier;
 use super::types::{CompressionType, ReplicationPriority};
 use crate::Vec3;
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,15 @@ pub struct ReplicationLayer {
     pub compression: CompressionType,
     /// Priority level for this layer
     pub priority: ReplicationPriority,
+    /// Exit margin as a fraction of the radius (overrides the manager default)
+    #[serde(default)]
+    pub exit_margin: Option<f64>,
+    /// Reduced-detail tiers for distant subscribers, ordered by distance
+    #[serde(default)]
+    pub lod: Vec<LodTier>,
+    /// Whether updates on this layer are sequenced, acknowledged and resent
+    #[serde(default)]
+    pub reliable: bool,
 }
 
 impl ReplicationLayer {
@@ -45,9 +55,52 @@ impl ReplicationLayer {
             properties,
             compression,
             priority,
+            exit_margin: None,
+            lod: Vec::new(),
+            reliable: false,
         }
     }
 
+    /// Sets the exit margin for this layer.
+    ///
+    /// Players enter the zone at `radius` and leave it beyond
+    /// `radius * (1.0 + margin)`.
+    pub fn with_exit_margin(mut self, margin: f64) -> Self {
+        self.exit_margin = Some(margin.max(0.0));
+        self
+    }
+
+    /// Get the radius at which subscribed players leave this layer
+    pub fn exit_radius(&self, default_margin: f64) -> f64 {
+        self.radius * (1.0 + self.exit_margin.unwrap_or(default_margin))
+    }
+
+    /// Marks this layer as reliable.
+    ///
+    /// Updates on a reliable layer are resent until the client acknowledges
+    /// their sequence number. Use it for critical state that must not be
+    /// lost, such as health reaching zero or an explosion.
+    pub fn with_reliable(mut self) -> Self {
+        self.reliable = true;
+        self
+    }
+
+    /// Adds a level-of-detail tier for distant subscribers.
+    ///
+    /// Subscribers closer than every tier's `min_distance` receive the full
+    /// payload; the others receive the payload trimmed by the farthest tier
+    /// they are beyond.
+    pub fn with_lod_tier(mut self, tier: LodTier) -> Self {
+        self.lod.push(tier);
+        self.lod.sort_by(|a, b| a.min_distance.total_cmp(&b.min_distance));
+        self
+    }
+
+    /// Get the tier to trim payloads with for a subscriber at `distance`
+    pub fn lod_tier_for_distance(&self, distance: f64) -> Option<&LodTier> {
+        self.lod.iter().rev().find(|tier| distance >= tier.min_distance)
+    }
+
     /// Get the update interval for this layer
     pub fn update_interval(&self) -> Duration {
         Duration::from_millis((1000.0 / self.frequency) as u64)
@@ -75,6 +128,7 @@ impl ReplicationLayer {
             CompressionType::None => 1.0,
             CompressionType::Lz4 => 0.7,
             CompressionType::Zlib => 0.6,
+            CompressionType::Zstd => 0.5,
             CompressionType::Delta => 0.3,
             CompressionType::Quantized => 0.5,
             CompressionType::High => 0.4,
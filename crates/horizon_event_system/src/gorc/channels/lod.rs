@@ -0,0 +1,105 @@
+/// Level-of-detail payload trimming for replication layers
+///
+/// A layer may define LOD tiers so that subscribers far from an object
+/// receive a reduced payload on the same channel: only a subset of the
+/// layer's properties, with positions quantized to a coarse grid. Nearby
+/// subscribers, closer than the first tier, keep the full payload.
+use crate::gorc::multicast::LodLevel;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Payload reduction applied to subscribers beyond a distance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LodTier {
+    /// Detail level this tier represents
+    pub level: LodLevel,
+    /// Subscriber distance from which this tier applies
+    pub min_distance: f64,
+    /// Top-level properties kept in the payload (empty keeps all)
+    #[serde(default)]
+    pub properties: Vec<String>,
+    /// Grid size positions are rounded to, if any
+    #[serde(default)]
+    pub position_precision: Option<f64>,
+}
+
+impl LodTier {
+    /// Creates a tier for a detail level.
+    ///
+    /// The tier starts where the next higher level's radius ends, so
+    /// `LodLevel::Medium` applies from `LodLevel::High.radius()` onwards.
+    /// `LodLevel::Ultra` applies at any distance.
+    pub fn new(level: LodLevel) -> Self {
+        Self {
+            level,
+            min_distance: level.upgrade().map(|higher| higher.radius()).unwrap_or(0.0),
+            properties: Vec::new(),
+            position_precision: None,
+        }
+    }
+
+    /// Overrides the distance from which this tier applies
+    pub fn with_min_distance(mut self, min_distance: f64) -> Self {
+        self.min_distance = min_distance.max(0.0);
+        self
+    }
+
+    /// Keeps only the given top-level properties in trimmed payloads
+    pub fn with_properties<S: Into<String>>(mut self, properties: impl IntoIterator<Item = S>) -> Self {
+        self.properties = properties.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Rounds `position` fields to multiples of `precision`
+    pub fn with_position_precision(mut self, precision: f64) -> Self {
+        self.position_precision = (precision > 0.0).then_some(precision);
+        self
+    }
+
+    /// Trims a JSON payload for this tier.
+    ///
+    /// Non-object payloads are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Full-detail payload
+    ///
+    /// # Returns
+    ///
+    /// The payload with only this tier's properties and quantized positions.
+    pub fn trim(&self, payload: Value) -> Value {
+        let Value::Object(mut fields) = payload else {
+            return payload;
+        };
+
+        if !self.properties.is_empty() {
+            fields.retain(|name, _| self.properties.iter().any(|property| property == name));
+        }
+        if let (Some(precision), Some(position)) = (self.position_precision, fields.get_mut("position")) {
+            quantize(position, precision);
+        }
+        Value::Object(fields)
+    }
+
+    /// Trims a serialized JSON payload for this tier.
+    pub fn trim_bytes(&self, payload: &[u8]) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(&self.trim(serde_json::from_slice(payload)?))
+    }
+}
+
+/// Rounds every number inside a position value to a multiple of `precision`
+fn quantize(value: &mut Value, precision: f64) {
+    match value {
+        Value::Number(number) => {
+            if let Some(rounded) = number
+                .as_f64()
+                .and_then(|n| serde_json::Number::from_f64((n / precision).round() * precision))
+            {
+                *number = rounded;
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| quantize(item, precision)),
+        Value::Object(fields) => fields.values_mut().for_each(|field| quantize(field, precision)),
+        _ => {}
+    }
+}
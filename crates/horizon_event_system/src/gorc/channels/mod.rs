@@ -5,6 +5,7 @@
 
 mod channel;
 mod layer;
+mod lod;
 mod manager;
 mod registry;
 mod types;
@@ -12,6 +13,7 @@ mod types;
 // Re-export public types and functions
 pub use channel::{ReplicationChannel, ChannelStats};
 pub use layer::{ReplicationLayer, ReplicationLayers};
+pub use lod::LodTier;
 pub use manager::{
     GorcManager, GorcConfig, GorcStats, 
     ChannelPerformanceReport, PerformanceReport
@@ -10,6 +10,8 @@ pub enum CompressionType {
     Lz4,
     /// Zlib compression - smaller payload but slower
     Zlib,
+    /// Zstd compression - high ratio at moderate speed
+    Zstd,
     /// Delta compression - only send changes from previous state
     Delta,
     /// Quantized compression - reduce precision for smaller payload
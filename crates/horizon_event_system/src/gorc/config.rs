@@ -3,6 +3,7 @@
 //! This module provides configuration structures for the entire GORC system,
 //! including virtualization settings, performance tuning, and feature flags.
 
+use crate::gorc::spatial::{SpatialIndexConfig, SpatialIndexKind};
 use crate::gorc::virtualization::VirtualizationConfig;
 use serde::{Deserialize, Serialize};
 
@@ -46,6 +47,11 @@ pub struct GorcGeneralConfig {
     pub auto_optimize_zones: bool,
     /// Frequency of zone optimization checks (in milliseconds)
     pub optimization_interval_ms: u64,
+    /// Zone exit margin as a fraction of the zone radius (0.1 = exit at 1.1x)
+    pub zone_exit_margin: f64,
+    /// How far ahead player positions are dead-reckoned for zone checks (in milliseconds, 0 to disable)
+    #[serde(default = "default_dead_reckoning_horizon_ms")]
+    pub dead_reckoning_horizon_ms: u64,
     /// Enable debug logging for GORC operations
     pub debug_logging: bool,
 }
@@ -58,11 +64,17 @@ impl Default for GorcGeneralConfig {
             max_channels_per_object: 8,
             auto_optimize_zones: true,
             optimization_interval_ms: 5000, // 5 seconds
+            zone_exit_margin: 0.1, // Exit at 1.1x the enter radius
+            dead_reckoning_horizon_ms: default_dead_reckoning_horizon_ms(),
             debug_logging: false,
         }
     }
 }
 
+fn default_dead_reckoning_horizon_ms() -> u64 {
+    200
+}
+
 /// Spatial indexing configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpatialConfig {
@@ -72,10 +84,46 @@ pub struct SpatialConfig {
     pub max_objects_per_leaf: usize,
     /// Number of mutations before triggering a bulk rebuild
     pub rebuild_threshold: usize,
+    /// Interval between background index maintenance passes in milliseconds (0 disables them)
+    #[serde(default = "default_maintenance_interval_ms")]
+    pub maintenance_interval_ms: u64,
+    /// Most mutations between maintenance passes for a tick to count as low-load
+    #[serde(default = "default_low_load_mutations")]
+    pub low_load_mutations: usize,
     /// Enable spatial index caching
     pub enable_caching: bool,
     /// Cache expiry time in milliseconds
     pub cache_expiry_ms: u64,
+    /// Spatial index implementation used for regions
+    #[serde(default)]
+    pub index: SpatialIndexKind,
+    /// Cell edge length when using the loose grid index
+    #[serde(default = "default_grid_cell_size")]
+    pub grid_cell_size: f64,
+}
+
+fn default_grid_cell_size() -> f64 {
+    100.0
+}
+
+fn default_maintenance_interval_ms() -> u64 {
+    1_000
+}
+
+fn default_low_load_mutations() -> usize {
+    256
+}
+
+impl SpatialConfig {
+    /// Gets the index selection and tuning for the instance manager
+    pub fn index_config(&self) -> SpatialIndexConfig {
+        SpatialIndexConfig::new(self.index)
+            .with_max_objects_per_leaf(self.max_objects_per_leaf)
+            .with_cell_size(self.grid_cell_size)
+            .with_rebuild_threshold(self.rebuild_threshold)
+            .with_maintenance_interval_ms(self.maintenance_interval_ms)
+            .with_low_load_mutations(self.low_load_mutations)
+    }
 }
 
 impl Default for SpatialConfig {
@@ -84,8 +132,12 @@ impl Default for SpatialConfig {
             world_bounds: (-10000.0, -10000.0, -1000.0, 10000.0, 10000.0, 1000.0),
             max_objects_per_leaf: 64,
             rebuild_threshold: 5_000,
+            maintenance_interval_ms: default_maintenance_interval_ms(),
+            low_load_mutations: default_low_load_mutations(),
             enable_caching: true,
             cache_expiry_ms: 30000, // 30 seconds
+            index: SpatialIndexKind::default(),
+            grid_cell_size: default_grid_cell_size(),
         }
     }
 }
@@ -182,6 +234,12 @@ impl GorcConfigBuilder {
         self
     }
 
+    /// Selects the spatial index implementation
+    pub fn with_spatial_index(mut self, index: SpatialIndexKind) -> Self {
+        self.config.spatial.index = index;
+        self
+    }
+
     /// Sets maximum number of objects and players
     pub fn with_capacity(mut self, max_objects: usize, max_players: usize) -> Self {
         self.config.general.max_objects = max_objects;
@@ -321,6 +379,10 @@ impl GorcServerConfig {
             return Err(ConfigValidationError::InvalidValue("rebuild_threshold must be greater than 0".to_string()));
         }
 
+        if self.spatial.grid_cell_size <= 0.0 {
+            return Err(ConfigValidationError::InvalidValue("grid_cell_size must be > 0.0".to_string()));
+        }
+
         // Validate network config
         if self.network.max_batch_size == 0 {
             return Err(ConfigValidationError::InvalidValue("max_batch_size must be > 0".to_string()));
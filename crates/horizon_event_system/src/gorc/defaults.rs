@@ -67,6 +67,7 @@ pub fn default_object_layers() -> ReplicationLayers {
 /// * **Frequencies**: Tiered update rates from 30Hz (critical) to 2Hz (metadata)
 /// * **Compression**: Enabled with 128-byte threshold
 /// * **Priority Queues**: Sized based on importance level
+/// * **Reliable Delivery**: Unacknowledged updates resent after 250ms, up to 5 sends
 /// 
 /// # Returns
 /// 
@@ -74,6 +75,7 @@ pub fn default_object_layers() -> ReplicationLayers {
 pub fn default_network_config() -> NetworkConfig {
     NetworkConfig {
         max_bandwidth_per_player: 512 * 1024, // 512 KB/s per player
+        max_bytes_per_tick: 16 * 1024, // ~512 KB/s at 30 ticks per second
         max_batch_size: 25,
         max_batch_age_ms: 16, // ~60 FPS
         target_frequencies: {
@@ -86,6 +88,7 @@ pub fn default_network_config() -> NetworkConfig {
         },
         compression_enabled: true,
         compression_threshold: 128,
+        compression: CompressionType::Lz4,
         priority_queue_sizes: {
             let mut sizes = HashMap::new();
             sizes.insert(ReplicationPriority::Critical, 500);
@@ -94,6 +97,9 @@ pub fn default_network_config() -> NetworkConfig {
             sizes.insert(ReplicationPriority::Low, 50);
             sizes
         },
+        adaptive_frequency: Default::default(),
+        reliable_resend_ms: 250, // ~2 RTTs on a typical connection
+        reliable_max_attempts: 5,
     }
 }
 
@@ -0,0 +1,640 @@
+//! # GORC Region Federation
+//!
+//! Hands objects off between neighboring region servers and keeps ghost
+//! copies of border objects so players near a region edge can see across it.
+//!
+//! Each server scans its objects periodically:
+//! - Objects inside the overlap band along an edge shared with a neighbor are
+//!   sent to that neighbor as ghost updates.
+//! - Objects that leave this region's bounds and enter a neighbor's bounds are
+//!   handed off. The neighbor becomes authoritative and the local copy is kept
+//!   as a ghost until the neighbor removes it.
+//! - Players that cross into a neighbor are reported so the connection layer
+//!   can move them to the neighboring server.
+//!
+//! Moving a player is a handoff of its own: [`RegionFederation::hand_off_player`]
+//! sends the player's session and its object (with everything attached to it)
+//! to the neighbor under a single-use ticket. The neighbor holds the player as
+//! a pending arrival until its client connects there and presents the ticket,
+//! see [`RegionFederation::claim_arrival`].
+//!
+//! Object state travels as [`ObjectSnapshot`]s, so only types that implement
+//! `GorcObject::snapshot_state` and are registered with
+//! `GorcInstanceManager::register_snapshot_type` can cross regions. The
+//! transport between servers is abstracted by [`RegionLink`].
+
+use crate::gorc::instance::{GorcInstanceManager, GorcObjectId, ObjectAttachment, ObjectAuthority};
+use crate::gorc::snapshot::ObjectSnapshot;
+use crate::types::{AuthenticationStatus, PlayerId, RegionBounds, Vec3};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+/// Errors raised by the federation layer
+#[derive(Debug, Error)]
+pub enum FederationError {
+    /// Sending to or receiving from a neighbor failed
+    #[error("Federation transport error: {0}")]
+    Transport(String),
+    /// A federation message could not be encoded or decoded
+    #[error("Federation serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    /// A message was addressed to a region that isn't configured
+    #[error("Unknown neighbor region: {0}")]
+    UnknownNeighbor(String),
+}
+
+/// A neighboring region server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeighborRegion {
+    /// Name used to address the neighbor over the region link
+    pub name: String,
+    /// Spatial bounds owned by the neighbor
+    pub bounds: RegionBounds,
+}
+
+/// Session state that travels with a player moving to a neighboring region
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSession {
+    /// Player, which keeps its ID in the receiving region
+    pub player_id: PlayerId,
+    /// Player position when the crossing was detected
+    pub position: Vec3,
+    /// Authentication status the player had in the sending region
+    pub auth_status: AuthenticationStatus,
+}
+
+/// An object moving to a neighboring region together with its player
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandedOffObject {
+    /// Full object state
+    pub object: ObjectSnapshot,
+    /// Authority the object had in the sending region
+    pub authority: ObjectAuthority,
+    /// Attachment to another object of the same handoff
+    pub attachment: Option<ObjectAttachment>,
+}
+
+/// A handed-off player whose client hasn't connected to this region yet
+#[derive(Debug, Clone)]
+pub struct PlayerArrival {
+    /// Name of the region the player came from
+    pub source: String,
+    /// Session state sent by the source region
+    pub session: PlayerSession,
+    /// The player's own object, if it arrived with the handoff
+    pub player_object: Option<GorcObjectId>,
+    /// Every object that arrived with the handoff
+    pub objects: Vec<GorcObjectId>,
+    /// When the handoff was received
+    received_at: Instant,
+}
+
+/// Messages exchanged between region servers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FederationMessage {
+    /// Ownership of an object moves to the receiving region
+    Handoff {
+        /// Full object state
+        object: ObjectSnapshot,
+        /// Authority the object had in the sending region
+        authority: ObjectAuthority,
+    },
+    /// Latest state of an object in the sender's overlap band
+    GhostUpdate {
+        /// Full object state
+        object: ObjectSnapshot,
+    },
+    /// The object left the sender's overlap band or was despawned
+    GhostRemove {
+        /// Object whose ghost should be removed
+        object_id: GorcObjectId,
+    },
+    /// A player moves to the receiving region and its client will reconnect there
+    PlayerHandoff {
+        /// Single-use ticket the client presents to the receiving region
+        ticket: String,
+        /// The player's session state
+        session: PlayerSession,
+        /// The player's own object, if it has one
+        player_object: Option<GorcObjectId>,
+        /// The player's object followed by the objects attached to it
+        objects: Vec<HandedOffObject>,
+    },
+}
+
+/// Server-to-server channel used to reach neighboring regions
+#[async_trait]
+pub trait RegionLink: Send + Sync + std::fmt::Debug {
+    /// Sends a message to the named neighbor
+    async fn send(&self, neighbor: &str, message: FederationMessage) -> Result<(), FederationError>;
+}
+
+/// Outcome of a single federation scan
+#[derive(Debug, Clone, Default)]
+pub struct FederationReport {
+    /// Ghost updates sent to neighbors
+    pub ghost_updates_sent: usize,
+    /// Ghosts removed from neighbors
+    pub ghost_removals_sent: usize,
+    /// Objects handed off to neighbors
+    pub handoffs_sent: usize,
+    /// Players that crossed into a neighbor since the last scan, with the neighbor's name and their position
+    pub players_leaving: Vec<(PlayerId, String, Vec3)>,
+    /// Handed-off players dropped because their client didn't connect in time
+    pub arrivals_expired: usize,
+}
+
+/// Cumulative federation statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FederationStats {
+    /// Objects handed off to neighbors
+    pub handoffs_sent: u64,
+    /// Objects received from neighbors
+    pub handoffs_received: u64,
+    /// Ghost updates sent to neighbors
+    pub ghost_updates_sent: u64,
+    /// Ghost updates received from neighbors
+    pub ghost_updates_received: u64,
+    /// Ghost copies currently held for neighbors' objects
+    pub active_ghosts: usize,
+    /// Players handed off to neighbors
+    pub players_handed_off: u64,
+    /// Players received from neighbors
+    pub players_received: u64,
+    /// Received players whose client hasn't connected yet
+    pub pending_arrivals: usize,
+    /// Messages that failed to send or apply
+    pub errors: u64,
+}
+
+/// Hands objects off between this region and its neighbors
+#[derive(Debug)]
+pub struct RegionFederation {
+    /// Bounds owned by this region
+    bounds: RegionBounds,
+    /// Neighboring regions
+    neighbors: Vec<NeighborRegion>,
+    /// Width of the border band mirrored to neighbors
+    overlap_band: f64,
+    /// Local object registry
+    instance_manager: Arc<GorcInstanceManager>,
+    /// Transport to neighboring servers
+    link: Arc<dyn RegionLink>,
+    /// Ghost copies of neighbors' objects, mapped to the owning neighbor
+    ghosts: RwLock<HashMap<GorcObjectId, String>>,
+    /// Local objects currently mirrored to a neighbor
+    mirrored: RwLock<HashMap<GorcObjectId, String>>,
+    /// Players already reported as leaving, mapped to the neighbor they entered
+    departed_players: RwLock<HashMap<PlayerId, String>>,
+    /// Handed-off players awaiting their client, keyed by ticket
+    arrivals: RwLock<HashMap<String, PlayerArrival>>,
+    /// How long a handed-off player's client has to connect
+    arrival_window: Duration,
+    /// Cumulative statistics
+    stats: RwLock<FederationStats>,
+}
+
+impl RegionFederation {
+    /// Default width of the overlap band in world units
+    pub const DEFAULT_OVERLAP_BAND: f64 = 50.0;
+
+    /// Default time a handed-off player's client has to connect
+    pub const DEFAULT_ARRIVAL_WINDOW: Duration = Duration::from_secs(30);
+
+    /// Creates a federation for a region
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - Bounds owned by this region
+    /// * `neighbors` - Regions sharing an edge with this one
+    /// * `instance_manager` - Local object registry
+    /// * `link` - Transport to neighboring servers
+    pub fn new(
+        bounds: RegionBounds,
+        neighbors: Vec<NeighborRegion>,
+        instance_manager: Arc<GorcInstanceManager>,
+        link: Arc<dyn RegionLink>,
+    ) -> Self {
+        Self {
+            bounds,
+            neighbors,
+            overlap_band: Self::DEFAULT_OVERLAP_BAND,
+            instance_manager,
+            link,
+            ghosts: RwLock::new(HashMap::new()),
+            mirrored: RwLock::new(HashMap::new()),
+            departed_players: RwLock::new(HashMap::new()),
+            arrivals: RwLock::new(HashMap::new()),
+            arrival_window: Self::DEFAULT_ARRIVAL_WINDOW,
+            stats: RwLock::new(FederationStats::default()),
+        }
+    }
+
+    /// Sets the width of the border band mirrored to neighbors
+    pub fn with_overlap_band(mut self, overlap_band: f64) -> Self {
+        self.overlap_band = overlap_band.max(0.0);
+        self
+    }
+
+    /// Sets how long a handed-off player's client has to connect
+    pub fn with_arrival_window(mut self, arrival_window: Duration) -> Self {
+        self.arrival_window = arrival_window;
+        self
+    }
+
+    /// Finds the neighbor that owns a position
+    fn owning_neighbor(&self, position: Vec3) -> Option<&NeighborRegion> {
+        self.neighbors.iter().find(|neighbor| neighbor.bounds.contains(position))
+    }
+
+    /// Finds the neighbor whose edge a local position is within the overlap band of
+    fn bordering_neighbor(&self, position: Vec3) -> Option<&NeighborRegion> {
+        self.neighbors
+            .iter()
+            .find(|neighbor| neighbor.bounds.expanded(self.overlap_band).contains(position))
+    }
+
+    /// Checks whether an object is a ghost copy of a neighbor's object
+    pub async fn is_ghost(&self, object_id: GorcObjectId) -> bool {
+        self.ghosts.read().await.contains_key(&object_id)
+    }
+
+    /// Scans local objects and players, sending ghost updates and handoffs.
+    ///
+    /// Objects that cannot be snapshotted are skipped. Send failures are
+    /// logged and counted so one unreachable neighbor doesn't stall the rest.
+    pub async fn scan(&self) -> FederationReport {
+        let mut report = FederationReport::default();
+        let ghosts = self.ghosts.read().await.clone();
+
+        for (object_id, position) in self.instance_manager.get_object_positions().await {
+            if ghosts.contains_key(&object_id) {
+                continue;
+            }
+
+            if !self.bounds.contains(position) {
+                if let Some(neighbor) = self.owning_neighbor(position) {
+                    if self.hand_off(object_id, &neighbor.name).await {
+                        report.handoffs_sent += 1;
+                    }
+                }
+                continue;
+            }
+
+            if let Some(neighbor) = self.bordering_neighbor(position) {
+                let Some(object) = self.instance_manager.snapshot_object(object_id).await else {
+                    continue;
+                };
+                if self.send(&neighbor.name, FederationMessage::GhostUpdate { object }).await {
+                    let previous = self.mirrored.write().await.insert(object_id, neighbor.name.clone());
+                    report.ghost_updates_sent += 1;
+                    // The object moved from another neighbor's band into this one's
+                    if let Some(previous) = previous.filter(|previous| *previous != neighbor.name) {
+                        if self.send(&previous, FederationMessage::GhostRemove { object_id }).await {
+                            report.ghost_removals_sent += 1;
+                        }
+                    }
+                }
+            } else {
+                let previous = self.mirrored.write().await.remove(&object_id);
+                if let Some(neighbor) = previous {
+                    if self.send(&neighbor, FederationMessage::GhostRemove { object_id }).await {
+                        report.ghost_removals_sent += 1;
+                    }
+                }
+            }
+        }
+
+        // Objects that were mirrored but no longer exist locally
+        let live: std::collections::HashSet<GorcObjectId> = self
+            .instance_manager
+            .get_object_positions()
+            .await
+            .into_iter()
+            .map(|(object_id, _)| object_id)
+            .collect();
+        let despawned: Vec<(GorcObjectId, String)> = {
+            let mut mirrored = self.mirrored.write().await;
+            let gone: Vec<GorcObjectId> = mirrored.keys().filter(|id| !live.contains(id)).copied().collect();
+            gone.into_iter()
+                .filter_map(|object_id| mirrored.remove(&object_id).map(|neighbor| (object_id, neighbor)))
+                .collect()
+        };
+        for (object_id, neighbor) in despawned {
+            if self.send(&neighbor, FederationMessage::GhostRemove { object_id }).await {
+                report.ghost_removals_sent += 1;
+            }
+        }
+
+        report.players_leaving = self.scan_players().await;
+        report.arrivals_expired = self.expire_arrivals().await;
+
+        {
+            let mut stats = self.stats.write().await;
+            stats.handoffs_sent += report.handoffs_sent as u64;
+            stats.ghost_updates_sent += report.ghost_updates_sent as u64;
+        }
+        report
+    }
+
+    /// Reports players that crossed into a neighbor since the last scan
+    async fn scan_players(&self) -> Vec<(PlayerId, String, Vec3)> {
+        let mut departed = self.departed_players.write().await;
+        let positions = self.instance_manager.get_player_positions().await;
+        departed.retain(|player_id, _| positions.iter().any(|(id, _)| id == player_id));
+
+        let mut leaving = Vec::new();
+        for (player_id, position) in positions {
+            if self.bounds.contains(position) {
+                departed.remove(&player_id);
+                continue;
+            }
+            let Some(neighbor) = self.owning_neighbor(position) else {
+                continue;
+            };
+            if departed.get(&player_id) != Some(&neighbor.name) {
+                departed.insert(player_id, neighbor.name.clone());
+                leaving.push((player_id, neighbor.name.clone(), position));
+            }
+        }
+        leaving
+    }
+
+    /// Hands an object to a neighbor, keeping the local copy as a ghost
+    async fn hand_off(&self, object_id: GorcObjectId, neighbor: &str) -> bool {
+        let Some(object) = self.instance_manager.snapshot_object(object_id).await else {
+            debug!("🌐 Federation: Object {} left the region but has no snapshot state", object_id);
+            return false;
+        };
+        let authority = self
+            .instance_manager
+            .get_object_authority(object_id)
+            .await
+            .unwrap_or_default();
+
+        if !self.send(neighbor, FederationMessage::Handoff { object, authority }).await {
+            return false;
+        }
+
+        self.mirrored.write().await.remove(&object_id);
+        self.ghosts.write().await.insert(object_id, neighbor.to_string());
+        info!("🌐 Federation: Handed off object {} to region {}", object_id, neighbor);
+        true
+    }
+
+    /// Hands a player that crossed into a neighbor off to it.
+    ///
+    /// The player's object and everything attached to it travel with the
+    /// session; objects without snapshot state stay behind. The local copies
+    /// are kept as ghosts, like objects handed off by [`scan`](Self::scan).
+    ///
+    /// # Arguments
+    ///
+    /// * `neighbor` - Region the player entered
+    /// * `session` - The player's session state
+    ///
+    /// # Returns
+    ///
+    /// The ticket the player's client presents to the neighbor, or `None` if
+    /// the handoff couldn't be sent.
+    pub async fn hand_off_player(&self, neighbor: &str, session: PlayerSession) -> Option<String> {
+        let mut player_object = self.instance_manager.find_player_object(session.player_id).await;
+        let mut objects = Vec::new();
+        if let Some(root) = player_object {
+            let attached = self.instance_manager.attached_positions(&[root]).await;
+            for object_id in std::iter::once(root).chain(attached.into_iter().map(|(object_id, _)| object_id)) {
+                let Some(object) = self.instance_manager.snapshot_object(object_id).await else {
+                    debug!("🌐 Federation: Object {} of player {} has no snapshot state and stays behind", object_id, session.player_id);
+                    continue;
+                };
+                let authority = self.instance_manager.get_object_authority(object_id).await.unwrap_or_default();
+                let attachment = match object_id == root {
+                    true => None,
+                    false => self.instance_manager.get_attachment(object_id).await,
+                };
+                objects.push(HandedOffObject { object, authority, attachment });
+            }
+            if objects.first().is_none_or(|handed_off| handed_off.object.object_id != root) {
+                player_object = None;
+            }
+        }
+
+        let player_id = session.player_id;
+        let ticket = uuid::Uuid::new_v4().simple().to_string();
+        let object_ids: Vec<GorcObjectId> = objects.iter().map(|handed_off| handed_off.object.object_id).collect();
+        let message = FederationMessage::PlayerHandoff { ticket: ticket.clone(), session, player_object, objects };
+        if !self.send(neighbor, message).await {
+            return None;
+        }
+
+        {
+            let mut mirrored = self.mirrored.write().await;
+            for object_id in &object_ids {
+                mirrored.remove(object_id);
+            }
+        }
+        {
+            let mut ghosts = self.ghosts.write().await;
+            for object_id in object_ids {
+                ghosts.insert(object_id, neighbor.to_string());
+            }
+        }
+        self.stats.write().await.players_handed_off += 1;
+        info!("🌐 Federation: Handed off player {} to region {}", player_id, neighbor);
+        Some(ticket)
+    }
+
+    /// Claims a handed-off player for a connecting client.
+    ///
+    /// Tickets are single use, and only valid for the arrival window.
+    ///
+    /// # Returns
+    ///
+    /// The arrival, or `None` if the ticket is unknown, already claimed or
+    /// expired.
+    pub async fn claim_arrival(&self, ticket: &str) -> Option<PlayerArrival> {
+        let mut arrivals = self.arrivals.write().await;
+        if arrivals.get(ticket)?.received_at.elapsed() > self.arrival_window {
+            return None;
+        }
+        arrivals.remove(ticket)
+    }
+
+    /// Drops handed-off players whose client didn't connect in time,
+    /// unregistering the objects that arrived with them
+    async fn expire_arrivals(&self) -> usize {
+        let expired: Vec<PlayerArrival> = {
+            let mut arrivals = self.arrivals.write().await;
+            let tickets: Vec<String> = arrivals
+                .iter()
+                .filter(|(_, arrival)| arrival.received_at.elapsed() > self.arrival_window)
+                .map(|(ticket, _)| ticket.clone())
+                .collect();
+            tickets.iter().filter_map(|ticket| arrivals.remove(ticket)).collect()
+        };
+
+        for arrival in &expired {
+            warn!(
+                "🌐 Federation: Player {} from region {} never arrived",
+                arrival.session.player_id, arrival.source
+            );
+            self.instance_manager.remove_player_ownership(arrival.session.player_id).await;
+            for object_id in &arrival.objects {
+                self.instance_manager.unregister_object(*object_id).await;
+            }
+        }
+        expired.len()
+    }
+
+    /// Checks whether a neighbor may write an object's state here.
+    ///
+    /// Neighbors may only send objects this region doesn't hold yet and
+    /// objects it holds as ghosts of theirs, which includes objects this
+    /// region handed off to them. Objects owned here, and ghosts of another
+    /// neighbor's objects, are refused.
+    async fn accepts_state_from(&self, source: &str, object_id: GorcObjectId) -> bool {
+        let owner = self.ghosts.read().await.get(&object_id).cloned();
+        match owner {
+            Some(owner) => owner == source,
+            None => self.instance_manager.get_object_position(object_id).await.is_none(),
+        }
+    }
+
+    /// Applies an object handed off by a neighbor, making this region authoritative
+    async fn receive_object(&self, source: &str, object: ObjectSnapshot, authority: ObjectAuthority) -> bool {
+        let object_id = object.object_id;
+        if !self.accepts_state_from(source, object_id).await {
+            warn!("🌐 Federation: Refused handoff of object {} from region {}, which doesn't own it", object_id, source);
+            self.stats.write().await.errors += 1;
+            return false;
+        }
+        if !self.instance_manager.apply_object_snapshot(object).await {
+            self.stats.write().await.errors += 1;
+            return false;
+        }
+        self.instance_manager.set_object_authority(object_id, authority).await;
+
+        // The object is ours now; keep the sender's ghost in sync from here on
+        self.ghosts.write().await.remove(&object_id);
+        self.mirrored.write().await.insert(object_id, source.to_string());
+        true
+    }
+
+    /// Sends a message, logging and counting failures
+    async fn send(&self, neighbor: &str, message: FederationMessage) -> bool {
+        match self.link.send(neighbor, message).await {
+            Ok(()) => true,
+            Err(e) => {
+                warn!("🌐 Federation: Failed to send to region {}: {}", neighbor, e);
+                self.stats.write().await.errors += 1;
+                false
+            }
+        }
+    }
+
+    /// Applies a message received from a neighbor
+    ///
+    /// Transports must take `source` from the credentials the neighbor
+    /// authenticated with, never from anything the neighbor sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Name of the authenticated sending region
+    /// * `message` - The received message
+    pub async fn apply(&self, source: &str, message: FederationMessage) -> Result<(), FederationError> {
+        if !self.neighbors.iter().any(|neighbor| neighbor.name == source) {
+            self.stats.write().await.errors += 1;
+            return Err(FederationError::UnknownNeighbor(source.to_string()));
+        }
+
+        match message {
+            FederationMessage::Handoff { object, authority } => {
+                let object_id = object.object_id;
+                if !self.receive_object(source, object, authority).await {
+                    return Ok(());
+                }
+                self.stats.write().await.handoffs_received += 1;
+                info!("🌐 Federation: Received object {} from region {}", object_id, source);
+            }
+            FederationMessage::PlayerHandoff { ticket, session, player_object, objects } => {
+                let mut received = Vec::with_capacity(objects.len());
+                for HandedOffObject { object, authority, attachment } in objects {
+                    let object_id = object.object_id;
+                    if !self.receive_object(source, object, authority).await {
+                        continue;
+                    }
+                    received.push(object_id);
+                    if let Some(attachment) = attachment {
+                        if let Err(e) = self
+                            .instance_manager
+                            .attach_object(object_id, attachment.parent_id, attachment.offset)
+                            .await
+                        {
+                            warn!("🌐 Federation: Failed to reattach object {}: {}", object_id, e);
+                        }
+                    }
+                }
+
+                let player_object = player_object.filter(|object_id| received.contains(object_id));
+                if let Some(object_id) = player_object {
+                    self.instance_manager.register_object_owner(session.player_id, object_id).await;
+                }
+
+                info!(
+                    "🌐 Federation: Player {} is moving here from region {} with {} object(s)",
+                    session.player_id,
+                    source,
+                    received.len()
+                );
+                let arrival = PlayerArrival {
+                    source: source.to_string(),
+                    session,
+                    player_object,
+                    objects: received,
+                    received_at: Instant::now(),
+                };
+                self.arrivals.write().await.insert(ticket, arrival);
+                self.stats.write().await.players_received += 1;
+            }
+            FederationMessage::GhostUpdate { object } => {
+                let object_id = object.object_id;
+                if !self.accepts_state_from(source, object_id).await {
+                    debug!("🌐 Federation: Ignoring ghost update for object {} not owned by region {}", object_id, source);
+                    return Ok(());
+                }
+                if self.instance_manager.apply_object_snapshot(object).await {
+                    self.ghosts.write().await.insert(object_id, source.to_string());
+                    self.stats.write().await.ghost_updates_received += 1;
+                }
+            }
+            FederationMessage::GhostRemove { object_id } => {
+                let removed = {
+                    let mut ghosts = self.ghosts.write().await;
+                    match ghosts.get(&object_id) {
+                        Some(owner) if owner == source => ghosts.remove(&object_id).is_some(),
+                        _ => false,
+                    }
+                };
+                if removed {
+                    self.instance_manager.unregister_object(object_id).await;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets cumulative federation statistics
+    pub async fn get_stats(&self) -> FederationStats {
+        let mut stats = self.stats.read().await.clone();
+        stats.active_ghosts = self.ghosts.read().await.len();
+        stats.pending_arrivals = self.arrivals.read().await.len();
+        stats
+    }
+}
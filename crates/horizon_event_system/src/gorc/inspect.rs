@@ -0,0 +1,104 @@
+//! Live zone inspection for debugging GORC replication.
+//!
+//! A [`ZoneInspection`] is a point-in-time dump of every object's zones with
+//! their current subscribers, and of every player's subscriptions. It answers
+//! "why didn't this player receive that event?" without attaching a debugger:
+//! serialize it to JSON and check whether the player was subscribed to the
+//! object's channel and how far away they were.
+
+use crate::gorc::instance::{GorcObjectId, ObjectAuthority};
+use crate::types::{PlayerId, Vec3};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Point-in-time dump of all zones and subscriptions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ZoneInspection {
+    /// Every registered object with its zones
+    pub objects: Vec<ObjectZones>,
+    /// Every tracked player with their subscriptions
+    pub players: Vec<PlayerSubscriptions>,
+    /// Capture time in seconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+/// Zones of a single object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectZones {
+    /// Object the zones belong to
+    pub object_id: GorcObjectId,
+    /// Type name of the object
+    pub object_type: String,
+    /// Tracked position of the object (the zone center)
+    pub position: Vec3,
+    /// Party allowed to push state for the object
+    pub authority: ObjectAuthority,
+    /// Runtime tags of the object, ordered by key
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+    /// Zones ordered by channel
+    pub zones: Vec<ZoneState>,
+}
+
+/// Current state of one zone
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZoneState {
+    /// Replication channel of the zone
+    pub channel: u8,
+    /// Radius at which players enter the zone
+    pub radius: f64,
+    /// Radius beyond which subscribed players leave the zone
+    pub exit_radius: f64,
+    /// Whether the zone is active
+    pub active: bool,
+    /// Players currently subscribed to the channel
+    pub subscribers: Vec<PlayerId>,
+    /// Subscribers pinned to the channel regardless of distance
+    pub pinned: Vec<PlayerId>,
+}
+
+/// Subscriptions of a single player
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSubscriptions {
+    /// The player
+    pub player_id: PlayerId,
+    /// Tracked position of the player, if known
+    pub position: Option<Vec3>,
+    /// Objects the player is subscribed to
+    pub subscriptions: Vec<PlayerSubscription>,
+}
+
+/// A player's subscription to one object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSubscription {
+    /// Object the player is subscribed to
+    pub object_id: GorcObjectId,
+    /// Channels the player is subscribed to, ascending
+    pub channels: Vec<u8>,
+    /// Distance between the player and the object, if the player's position is known
+    pub distance: Option<f64>,
+}
+
+impl ZoneInspection {
+    /// Gets the subscriptions of a player
+    pub fn player(&self, player_id: PlayerId) -> Option<&PlayerSubscriptions> {
+        self.players.iter().find(|player| player.player_id == player_id)
+    }
+
+    /// Gets the zones of an object
+    pub fn object(&self, object_id: GorcObjectId) -> Option<&ObjectZones> {
+        self.objects.iter().find(|object| object.object_id == object_id)
+    }
+
+    /// Restricts the dump to one player and the objects they are subscribed to
+    pub fn for_player(mut self, player_id: PlayerId) -> Self {
+        self.players.retain(|player| player.player_id == player_id);
+        let subscribed: Vec<GorcObjectId> = self
+            .players
+            .iter()
+            .flat_map(|player| player.subscriptions.iter().map(|subscription| subscription.object_id))
+            .collect();
+        self.objects.retain(|object| subscribed.contains(&object.object_id));
+        self
+    }
+}
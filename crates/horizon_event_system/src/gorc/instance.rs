@@ -6,16 +6,24 @@
 //! proximity-based replication.
 
 use crate::types::{PlayerId, Position, Vec3};
-use crate::gorc::channels::{ReplicationPriority, ReplicationLayer};
+use crate::gorc::channels::{LodTier, ReplicationPriority, ReplicationLayer};
 use crate::gorc::zones::ZoneManager;
-use crate::gorc::spatial::SpatialPartition;
+use crate::gorc::spatial::{
+    ObjectQueryResult, QueryFilters, QueryResult, SpatialIndexConfig, SpatialIndexKind, SpatialIndexStats,
+    SpatialMaintenanceReport, SpatialPartition, SpatialQuery,
+};
 use crate::gorc::virtualization::{VirtualizationManager, VirtualizationConfig};
+use crate::gorc::wire::PayloadEncoding;
+use crate::gorc::inspect::{ObjectZones, PlayerSubscription, PlayerSubscriptions, ZoneInspection, ZoneState};
+use crate::gorc::snapshot::{self, ObjectSnapshot, SnapshotError, SnapshotRestorer, WorldSnapshot};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use crate::events::AuthorityChangedEvent;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 use std::any::Any;
 use tokio::sync::RwLock;
-use tokio::time::Instant;
+use thiserror::Error;
+use tokio::time::{Duration, Instant};
 use uuid::Uuid;
 use tracing::{debug, info, warn};
 
@@ -60,9 +68,23 @@ pub trait GorcObject: Send + Sync + Any + std::fmt::Debug {
     
     /// Serialize data for a specific replication layer
     fn serialize_for_layer(&self, layer: &ReplicationLayer) -> Result<Vec<u8>, Box<dyn std::error::Error>>;
+
+    /// Serialize data for a specific replication layer in the binary wire format
+    ///
+    /// Returns `None` when the object has no binary encoding, in which case
+    /// binary clients receive the JSON layer data inside a binary frame.
+    fn serialize_for_layer_binary(&self, layer: &ReplicationLayer) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+        let _ = layer;
+        Ok(None)
+    }
     
     /// Get all replication layers for this object type
     fn get_layers(&self) -> Vec<ReplicationLayer>;
+
+    /// Get the tags spatial queries can filter on (e.g. "hostile", "mineable")
+    fn tags(&self) -> &[String] {
+        &[]
+    }
     
     /// Called when the object is registered with GORC
     fn on_register(&mut self, object_id: GorcObjectId) {
@@ -83,6 +105,15 @@ pub trait GorcObject: Send + Sync + Any + std::fmt::Debug {
     /// Update the object's position (called by the game logic)
     fn update_position(&mut self, new_position: Vec3);
 
+    /// Capture the object's state for a world snapshot
+    ///
+    /// Returns `None` for objects that should not survive a restart, which is
+    /// the default. Types that opt in must also be registered with
+    /// [`GorcInstanceManager::register_snapshot_type`] so they can be restored.
+    fn snapshot_state(&self) -> Option<serde_json::Value> {
+        None
+    }
+
     /// Get the object as Any for downcasting
     fn as_any(&self) -> &dyn Any;
     
@@ -93,6 +124,68 @@ pub trait GorcObject: Send + Sync + Any + std::fmt::Debug {
     fn clone_object(&self) -> Box<dyn GorcObject>;
 }
 
+/// The party allowed to push state for an object
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "player_id", rename_all = "lowercase")]
+pub enum ObjectAuthority {
+    /// The server simulates the object
+    #[default]
+    Server,
+    /// A player's client drives the object
+    Player(PlayerId),
+}
+
+impl ObjectAuthority {
+    /// Checks whether a player may push state under this authority
+    pub fn allows(&self, player_id: PlayerId) -> bool {
+        matches!(self, Self::Player(authority) if *authority == player_id)
+    }
+
+    /// Gets the authoritative player, if a player holds authority
+    pub fn player(&self) -> Option<PlayerId> {
+        match self {
+            Self::Player(player_id) => Some(*player_id),
+            Self::Server => None,
+        }
+    }
+}
+
+/// Attachment of a child object to its parent
+///
+/// The child's position is the parent's position plus `offset`, so the
+/// child (and its zones) follow the parent whenever it moves.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ObjectAttachment {
+    /// Object the child is attached to
+    pub parent_id: GorcObjectId,
+    /// Child position relative to the parent
+    pub offset: Vec3,
+}
+
+/// Errors raised when attaching objects to each other
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum AttachmentError {
+    /// The object is not registered
+    #[error("Object {0} not found")]
+    UnknownObject(GorcObjectId),
+    /// An object cannot be attached to itself
+    #[error("Object {0} cannot be attached to itself")]
+    SelfAttachment(GorcObjectId),
+    /// The parent is already attached below the child
+    #[error("Attaching {child} to {parent} would create a cycle")]
+    Cycle {
+        /// Object being attached
+        child: GorcObjectId,
+        /// Requested parent
+        parent: GorcObjectId,
+    },
+}
+
+/// Key of an object in the object spatial index, which shares the UUID key space of player entries
+fn object_key(object_id: GorcObjectId) -> PlayerId {
+    PlayerId(object_id.0)
+}
+
 /// Information about a registered GORC object instance
 #[derive(Debug)]
 pub struct ObjectInstance {
@@ -112,6 +205,12 @@ pub struct ObjectInstance {
     pub stats: ObjectStats,
     /// Whether this object needs a replication update
     pub needs_update: HashMap<u8, bool>,
+    /// Party allowed to push state for this object
+    pub authority: ObjectAuthority,
+    /// Subscriptions pinned regardless of distance, per channel
+    pub pinned: HashMap<u8, HashSet<PlayerId>>,
+    /// String-keyed attributes set at runtime (e.g. "faction", "quest_id")
+    pub tags: HashMap<String, String>,
 }
 
 impl ObjectInstance {
@@ -136,6 +235,9 @@ impl ObjectInstance {
             last_updates: HashMap::new(),
             stats: ObjectStats::default(),
             needs_update: HashMap::new(),
+            authority: ObjectAuthority::Server,
+            pinned: HashMap::new(),
+            tags: HashMap::new(),
         }
     }
 
@@ -185,6 +287,24 @@ impl ObjectInstance {
             .unwrap_or(false)
     }
 
+    /// Get the value of a runtime tag
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags.get(key).map(String::as_str)
+    }
+
+    /// Check if the object carries a tag, either set at runtime or declared
+    /// by the object through [`GorcObject::tags`]
+    pub fn has_tag(&self, key: &str) -> bool {
+        self.tags.contains_key(key) || self.object.tags().iter().any(|tag| tag == key)
+    }
+
+    /// Check if a player's subscription to a channel is pinned
+    pub fn is_pinned(&self, channel: u8, player_id: PlayerId) -> bool {
+        self.pinned
+            .get(&channel)
+            .is_some_and(|pinned| pinned.contains(&player_id))
+    }
+
     /// Get all subscribers for a channel
     pub fn get_subscribers(&self, channel: u8) -> Vec<PlayerId> {
         self.subscribers
@@ -210,6 +330,11 @@ impl ObjectInstance {
         self.stats.updates_sent += 1;
     }
 
+    /// Check if a player is the authority for this object
+    pub fn has_authority(&self, player_id: PlayerId) -> bool {
+        self.authority.allows(player_id)
+    }
+
     /// Get the object as a specific type (read-only)
     pub fn get_object<T: GorcObject + 'static>(&self) -> Option<&T> {
         self.object.as_any().downcast_ref::<T>()
@@ -234,6 +359,9 @@ impl Clone for ObjectInstance {
             last_updates: self.last_updates.clone(),
             stats: self.stats.clone(),
             needs_update: self.needs_update.clone(),
+            authority: self.authority,
+            pinned: self.pinned.clone(),
+            tags: self.tags.clone(),
         }
     }
 }
@@ -253,23 +381,250 @@ pub struct ObjectStats {
     pub zone_transitions: u64,
 }
 
+/// Identifies a single zone membership: player, object and channel
+type ZoneKey = (PlayerId, GorcObjectId, u8);
+
+/// Re-entering a zone within this window of leaving it counts as thrash
+const ZONE_THRASH_WINDOW: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Decides zone membership for a layer with exit hysteresis.
+///
+/// Returns whether the player is in the zone, and whether that is only
+/// because the exit margin retained an existing subscription.
+fn zone_membership(layer: &ReplicationLayer, distance: f64, subscribed: bool, default_margin: f64) -> (bool, bool) {
+    if distance <= layer.radius {
+        (true, false)
+    } else if subscribed && distance <= layer.exit_radius(default_margin) {
+        (true, true)
+    } else {
+        (false, false)
+    }
+}
+
+/// Recomputes zone membership of every player for an object at `position`.
+///
+/// Subscribers are added and removed on the instance as players enter and
+/// leave its zones.
+///
+/// # Returns
+///
+/// The zone changes as (player, channel, is_entry), and the number of
+/// subscriptions kept alive only by the exit margin.
+fn recalculate_instance_zones(
+    instance: &mut ObjectInstance,
+    position: Vec3,
+    player_positions: &[(PlayerId, Vec3)],
+    default_margin: f64,
+) -> (Vec<(PlayerId, u8, bool)>, u64) {
+    let mut zone_changes = Vec::new();
+    let mut retained = 0u64;
+    let layers = instance.object.get_layers();
+
+    for &(player_id, player_pos) in player_positions {
+        let distance = player_pos.distance(position);
+        for layer in &layers {
+            let channel = layer.channel;
+            let is_subbed = instance.is_subscribed(channel, player_id);
+            // Pinned subscriptions hold regardless of distance
+            let (is_in_zone, held) = if instance.is_pinned(channel, player_id) {
+                (true, false)
+            } else {
+                zone_membership(layer, distance, is_subbed, default_margin)
+            };
+            if held {
+                retained += 1;
+            }
+
+            match (is_subbed, is_in_zone) {
+                (false, true) => {
+                    // Zone entry
+                    instance.add_subscriber(channel, player_id);
+                    instance.stats.zone_transitions += 1;
+                    zone_changes.push((player_id, channel, true)); // true = entry
+                    debug!("🎯 GORC Object Movement: Player {} entered zone {} of object {}", player_id, channel, instance.object_id);
+                }
+                (true, false) => {
+                    // Zone exit
+                    instance.remove_subscriber(channel, player_id);
+                    instance.stats.zone_transitions += 1;
+                    zone_changes.push((player_id, channel, false)); // false = exit
+                    debug!("🚪 GORC Object Movement: Player {} exited zone {} of object {}", player_id, channel, instance.object_id);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    (zone_changes, retained)
+}
+
+/// Last known velocity of a player, used to dead-reckon between movement updates
+#[derive(Debug, Clone, Copy)]
+struct PlayerMotion {
+    /// Velocity in units per second
+    velocity: Vec3,
+    /// When the player's position was last reported
+    updated_at: Instant,
+}
+
+impl PlayerMotion {
+    /// Extrapolates a position along the velocity, at most `horizon` ahead
+    fn extrapolate(&self, position: Vec3, horizon: Duration) -> Vec3 {
+        let seconds = self.updated_at.elapsed().min(horizon).as_secs_f64();
+        Vec3::new(
+            position.x + self.velocity.x * seconds,
+            position.y + self.velocity.y * seconds,
+            position.z + self.velocity.z * seconds,
+        )
+    }
+}
+
+/// Result of moving one object in a batch position update
+#[derive(Debug, Clone)]
+pub struct ObjectMove {
+    /// The object that moved
+    pub object_id: GorcObjectId,
+    /// Position before the update
+    pub old_position: Vec3,
+    /// Position after the update
+    pub new_position: Vec3,
+    /// Zone membership changes as (player, channel, is_entry)
+    pub zone_changes: Vec<(PlayerId, u8, bool)>,
+}
+
+/// Attachment hierarchy, kept under a single lock so both directions stay consistent
+#[derive(Debug, Default)]
+struct AttachmentGraph {
+    /// Child to parent attachments
+    parents: HashMap<GorcObjectId, ObjectAttachment>,
+    /// Parent to attached children mapping
+    children: HashMap<GorcObjectId, HashSet<GorcObjectId>>,
+}
+
+impl AttachmentGraph {
+    /// Removes a child from its parent's set of attached children
+    fn remove_child(&mut self, parent_id: GorcObjectId, child_id: GorcObjectId) {
+        if let Some(children) = self.children.get_mut(&parent_id) {
+            children.remove(&child_id);
+            if children.is_empty() {
+                self.children.remove(&parent_id);
+            }
+        }
+    }
+}
+
+/// Captures an object's snapshot, preferring its tracked position
+fn capture_object(instance: &ObjectInstance, object_positions: &HashMap<GorcObjectId, Vec3>) -> Option<ObjectSnapshot> {
+    let state = instance.object.snapshot_state()?;
+    Some(ObjectSnapshot {
+        object_id: instance.object_id,
+        type_name: instance.type_name.clone(),
+        position: object_positions
+            .get(&instance.object_id)
+            .copied()
+            .unwrap_or_else(|| instance.object.position()),
+        state,
+    })
+}
+
+/// Default number of object shards
+pub const DEFAULT_OBJECT_SHARDS: usize = 16;
+
+/// Maximum authority changes kept while waiting for the event system to emit them
+const MAX_PENDING_AUTHORITY_CHANGES: usize = 1024;
+
+/// Object map of a single shard
+type ObjectShard = RwLock<HashMap<GorcObjectId, ObjectInstance>>;
+
+/// Object instances partitioned by ID hash, each shard behind its own lock.
+///
+/// Updates to objects in different shards never wait on each other. Scans
+/// over every object lock one shard at a time, so they only hold up the
+/// shard they are currently visiting.
+#[derive(Debug)]
+struct ObjectShards {
+    shards: Vec<ObjectShard>,
+}
+
+impl ObjectShards {
+    fn new(count: usize) -> Self {
+        Self {
+            shards: (0..count.max(1)).map(|_| RwLock::new(HashMap::new())).collect(),
+        }
+    }
+
+    /// Gets the index of the shard holding an object
+    fn index(&self, object_id: &GorcObjectId) -> usize {
+        let bits = object_id.0.as_u128();
+        ((bits as u64 ^ (bits >> 64) as u64) % self.shards.len() as u64) as usize
+    }
+
+    /// Gets the shard holding an object
+    fn shard(&self, object_id: &GorcObjectId) -> &ObjectShard {
+        &self.shards[self.index(object_id)]
+    }
+
+    /// Gets every shard, in index order
+    fn all(&self) -> &[ObjectShard] {
+        &self.shards
+    }
+
+    /// Moves every object into `count` new shards
+    fn reshard(&mut self, count: usize) {
+        let mut resharded = Self::new(count);
+        for shard in &mut self.shards {
+            for (object_id, instance) in shard.get_mut().drain() {
+                let index = resharded.index(&object_id);
+                resharded.shards[index].get_mut().insert(object_id, instance);
+            }
+        }
+        *self = resharded;
+    }
+
+    async fn contains(&self, object_id: &GorcObjectId) -> bool {
+        self.shard(object_id).read().await.contains_key(object_id)
+    }
+}
+
 /// Manager for all GORC object instances
 #[derive(Debug)]
 pub struct GorcInstanceManager {
-    /// All registered object instances
-    objects: Arc<RwLock<HashMap<GorcObjectId, ObjectInstance>>>,
+    /// All registered object instances, sharded by ID
+    objects: ObjectShards,
     /// Type name to object IDs mapping
     type_registry: Arc<RwLock<HashMap<String, HashSet<GorcObjectId>>>>,
     /// Spatial index using an R-tree for efficient proximity queries
     spatial_index: Arc<RwLock<SpatialPartition>>,
     /// Object positions for spatial tracking
     object_positions: Arc<RwLock<HashMap<GorcObjectId, Vec3>>>,
+    /// Spatial index of object positions for object queries
+    object_index: Arc<RwLock<SpatialPartition>>,
     /// Player positions for subscription management
     player_positions: Arc<RwLock<HashMap<PlayerId, Vec3>>>,
     /// Zone size warnings tracking (object_id -> largest_zone_radius)
     zone_size_warnings: Arc<RwLock<HashMap<GorcObjectId, f64>>>,
     /// Zone virtualization manager for high-density optimization
     virtualization_manager: Arc<VirtualizationManager>,
+    /// Player to owned object mapping (e.g. a player's avatar)
+    player_objects: Arc<RwLock<HashMap<PlayerId, GorcObjectId>>>,
+    /// Object to owning player mapping
+    object_owners: Arc<RwLock<HashMap<GorcObjectId, PlayerId>>>,
+    /// Attachments between objects, in both directions
+    attachments: Arc<RwLock<AttachmentGraph>>,
+    /// Default zone exit margin as a fraction of a layer's radius
+    zone_exit_margin: f64,
+    /// Last known player velocities for dead reckoning
+    player_motion: Arc<RwLock<HashMap<PlayerId, PlayerMotion>>>,
+    /// How far ahead player positions are extrapolated for zone checks
+    dead_reckoning_horizon: Duration,
+    /// Last zone exit per player, object and channel for thrash detection
+    zone_exit_times: Arc<RwLock<HashMap<ZoneKey, Instant>>>,
+    /// Restorers for object types that opt in to world snapshots
+    snapshot_types: Arc<RwLock<HashMap<String, SnapshotRestorer>>>,
+    /// Despawn deadlines for objects registered with a time-to-live
+    object_expiries: Arc<RwLock<HashMap<GorcObjectId, Instant>>>,
+    /// Authority changes not yet emitted by the event system, oldest first
+    pending_authority_changes: Arc<RwLock<VecDeque<AuthorityChangedEvent>>>,
     /// Global statistics
     stats: Arc<RwLock<InstanceManagerStats>>,
 }
@@ -286,13 +641,24 @@ impl GorcInstanceManager {
         let virtualization_manager = Arc::new(VirtualizationManager::new(virtualization_config));
 
         let manager = Self {
-            objects: Arc::new(RwLock::new(HashMap::new())),
+            objects: ObjectShards::new(DEFAULT_OBJECT_SHARDS),
             type_registry: Arc::new(RwLock::new(HashMap::new())),
             spatial_index: Arc::new(RwLock::new(spatial_index)),
             object_positions: Arc::new(RwLock::new(HashMap::new())),
+            object_index: Arc::new(RwLock::new(SpatialPartition::new())),
             player_positions: Arc::new(RwLock::new(HashMap::new())),
             zone_size_warnings: Arc::new(RwLock::new(HashMap::new())),
             virtualization_manager,
+            player_objects: Arc::new(RwLock::new(HashMap::new())),
+            object_owners: Arc::new(RwLock::new(HashMap::new())),
+            attachments: Arc::new(RwLock::new(AttachmentGraph::default())),
+            zone_exit_margin: 0.0,
+            player_motion: Arc::new(RwLock::new(HashMap::new())),
+            dead_reckoning_horizon: Duration::ZERO,
+            zone_exit_times: Arc::new(RwLock::new(HashMap::new())),
+            snapshot_types: Arc::new(RwLock::new(HashMap::new())),
+            object_expiries: Arc::new(RwLock::new(HashMap::new())),
+            pending_authority_changes: Arc::new(RwLock::new(VecDeque::new())),
             stats: Arc::new(RwLock::new(InstanceManagerStats::default())),
         };
 
@@ -310,6 +676,162 @@ impl GorcInstanceManager {
         manager
     }
 
+    /// Sets the default zone exit margin used by layers without their own.
+    ///
+    /// A player enters a zone at the layer radius but only leaves it beyond
+    /// `radius * (1.0 + margin)`, which stops subscription churn for players
+    /// moving along a zone boundary. Managers start without a margin; the
+    /// server configuration enables a 10% margin by default.
+    pub fn with_zone_exit_margin(mut self, margin: f64) -> Self {
+        self.zone_exit_margin = margin.max(0.0);
+        self
+    }
+
+    /// Selects the spatial index implementation used for player and object lookups.
+    ///
+    /// Must be called before players or objects are added; the R*-tree is used otherwise.
+    pub fn with_spatial_index(mut self, index: SpatialIndexConfig) -> Self {
+        self.object_index = Arc::new(RwLock::new(SpatialPartition::with_index(index.clone())));
+        self.spatial_index = Arc::new(RwLock::new(SpatialPartition::with_index(index)));
+        self
+    }
+
+    /// Gets the spatial index implementation used for player lookups
+    pub async fn spatial_index_kind(&self) -> SpatialIndexKind {
+        self.spatial_index.read().await.index_kind()
+    }
+
+    /// Gets the interval between spatial index maintenance passes, or `None` if disabled
+    pub async fn spatial_maintenance_interval(&self) -> Option<std::time::Duration> {
+        let interval_ms = self.spatial_index.read().await.index_config().maintenance_interval_ms;
+        (interval_ms > 0).then(|| std::time::Duration::from_millis(interval_ms))
+    }
+
+    /// Runs one spatial index maintenance pass, rebuilding regions that are
+    /// due when the current tick is low-load.
+    pub async fn maintain_spatial_index(&self) -> SpatialMaintenanceReport {
+        let mut report = self.spatial_index.read().await.maintain().await;
+        let objects = self.object_index.read().await.maintain().await;
+        report.mutations += objects.mutations;
+        report.regions_rebuilt += objects.regions_rebuilt;
+        report.deferred |= objects.deferred;
+        report.duration += objects.duration;
+        report
+    }
+
+    /// Gets the spatial index statistics of each region, including rebuild timings
+    pub async fn spatial_index_stats(&self) -> HashMap<String, SpatialIndexStats> {
+        self.spatial_index.read().await.index_stats().await
+    }
+
+    /// Gets the default zone exit margin
+    pub fn zone_exit_margin(&self) -> f64 {
+        self.zone_exit_margin
+    }
+
+    /// Sets the number of shards objects are partitioned into.
+    ///
+    /// Each shard has its own lock, so more shards let more object updates
+    /// run concurrently at the cost of longer scans over every object.
+    /// Objects already registered are moved into the new shards.
+    pub fn with_object_shards(mut self, count: usize) -> Self {
+        self.objects.reshard(count);
+        self
+    }
+
+    /// Gets the number of object shards
+    pub fn object_shard_count(&self) -> usize {
+        self.objects.all().len()
+    }
+
+    /// Sets how far ahead player positions are dead-reckoned for zone checks.
+    ///
+    /// Between movement updates, zone membership is evaluated at the player's
+    /// last position extrapolated along their last known velocity, for at
+    /// most `horizon`. Fast-moving players then enter zones on time instead
+    /// of one update late. Managers start with no extrapolation.
+    pub fn with_dead_reckoning_horizon(mut self, horizon: Duration) -> Self {
+        self.dead_reckoning_horizon = horizon;
+        self
+    }
+
+    /// Gets the dead-reckoning horizon
+    pub fn dead_reckoning_horizon(&self) -> Duration {
+        self.dead_reckoning_horizon
+    }
+
+    /// Records a player's last known velocity for dead reckoning.
+    ///
+    /// Call it alongside [`update_player_position`](Self::update_player_position)
+    /// whenever a movement update carries a velocity.
+    pub async fn update_player_velocity(&self, player_id: PlayerId, velocity: Vec3) {
+        self.player_motion.write().await.insert(player_id, PlayerMotion {
+            velocity,
+            updated_at: Instant::now(),
+        });
+    }
+
+    /// Gets a player's position extrapolated along their last known velocity
+    pub async fn get_predicted_player_position(&self, player_id: PlayerId) -> Option<Vec3> {
+        let position = self.get_player_position(player_id).await?;
+        Some(match self.player_motion.read().await.get(&player_id) {
+            Some(motion) => motion.extrapolate(position, self.dead_reckoning_horizon),
+            None => position,
+        })
+    }
+
+    /// Gets every player's position extrapolated along their last known velocity
+    pub async fn predicted_player_positions(&self) -> Vec<(PlayerId, Vec3)> {
+        let player_positions = self.player_positions.read().await;
+        if self.dead_reckoning_horizon.is_zero() {
+            return player_positions.iter().map(|(&id, &pos)| (id, pos)).collect();
+        }
+
+        let player_motion = self.player_motion.read().await;
+        player_positions
+            .iter()
+            .map(|(&id, &pos)| match player_motion.get(&id) {
+                Some(motion) => (id, motion.extrapolate(pos, self.dead_reckoning_horizon)),
+                None => (id, pos),
+            })
+            .collect()
+    }
+
+    /// Re-evaluates zone membership of moving players at their dead-reckoned positions.
+    ///
+    /// Meant to run every server tick, between movement updates. Players
+    /// without a known velocity, or at rest, are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Zone entries and exits per player, as `(player, entries, exits)`.
+    pub async fn refresh_predicted_zones(&self) -> Vec<(PlayerId, Vec<(GorcObjectId, u8)>, Vec<(GorcObjectId, u8)>)> {
+        if self.dead_reckoning_horizon.is_zero() {
+            return Vec::new();
+        }
+
+        let moving: Vec<PlayerId> = self
+            .player_motion
+            .read()
+            .await
+            .iter()
+            .filter(|(_, motion)| motion.velocity != Vec3::default())
+            .map(|(&player_id, _)| player_id)
+            .collect();
+
+        let mut changes = Vec::new();
+        for player_id in moving {
+            let Some(predicted) = self.get_predicted_player_position(player_id).await else {
+                continue;
+            };
+            let (entries, exits) = self.apply_player_zones(player_id, predicted, false).await;
+            if !entries.is_empty() || !exits.is_empty() {
+                changes.push((player_id, entries, exits));
+            }
+        }
+        changes
+    }
+
     /// Registers a new object instance (convenience - auto-generated UUID)
     pub async fn register_object<T: GorcObject + 'static>(
         &self,
@@ -319,23 +841,102 @@ impl GorcInstanceManager {
         self.register_object_with_uuid(object, initial_position, None).await
     }
 
+    /// Registers a short-lived object that despawns once `ttl` has elapsed
+    ///
+    /// Expired objects are removed by `EventSystem::despawn_expired_gorc_objects`,
+    /// which the game server runs every tick.
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The object to register (e.g. a projectile or loot drop)
+    /// * `initial_position` - Starting position of the object
+    /// * `ttl` - How long the object lives before it is despawned
+    pub async fn register_object_with_ttl<T: GorcObject + 'static>(
+        &self,
+        object: T,
+        initial_position: Vec3,
+        ttl: Duration,
+    ) -> GorcObjectId {
+        let object_id = self.register_object(object, initial_position).await;
+        self.set_object_ttl(object_id, Some(ttl)).await;
+        object_id
+    }
+
+    /// Sets, extends or clears an object's time-to-live
+    ///
+    /// # Arguments
+    ///
+    /// * `object_id` - The object to update
+    /// * `ttl` - Remaining lifetime from now, or `None` to keep the object indefinitely
+    ///
+    /// # Returns
+    ///
+    /// `false` if the object is not registered
+    pub async fn set_object_ttl(&self, object_id: GorcObjectId, ttl: Option<Duration>) -> bool {
+        if !self.objects.contains(&object_id).await {
+            return false;
+        }
+
+        let mut expiries = self.object_expiries.write().await;
+        match ttl {
+            Some(ttl) => {
+                expiries.insert(object_id, Instant::now() + ttl);
+            }
+            None => {
+                expiries.remove(&object_id);
+            }
+        }
+        true
+    }
+
+    /// Gets the remaining lifetime of an object with a time-to-live
+    pub async fn get_object_ttl(&self, object_id: GorcObjectId) -> Option<Duration> {
+        self.object_expiries
+            .read()
+            .await
+            .get(&object_id)
+            .map(|expires_at| expires_at.saturating_duration_since(Instant::now()))
+    }
+
+    /// Gets all objects whose time-to-live has elapsed
+    pub async fn expired_objects(&self) -> Vec<GorcObjectId> {
+        let now = Instant::now();
+        self.object_expiries
+            .read()
+            .await
+            .iter()
+            .filter(|(_, expires_at)| **expires_at <= now)
+            .map(|(object_id, _)| *object_id)
+            .collect()
+    }
+
     /// Registers a new object instance (optionally provide UUID)
     pub async fn register_object_with_uuid<T: GorcObject + 'static>(
         &self,
         object: T,
         initial_position: Vec3,
         uuid: Option<GorcObjectId>,
+    ) -> GorcObjectId {
+        self.register_boxed_object(Box::new(object), initial_position, uuid).await
+    }
+
+    /// Registers an already boxed object instance (optionally provide UUID)
+    async fn register_boxed_object(
+        &self,
+        object: Box<dyn GorcObject>,
+        initial_position: Vec3,
+        uuid: Option<GorcObjectId>,
     ) -> GorcObjectId {
         let object_id = uuid.unwrap_or_else(GorcObjectId::new);
         let type_name = object.type_name().to_string();
         let type_name_for_registry = type_name.clone();
         let type_name_for_log = type_name.clone();
         
-        let instance = ObjectInstance::new(object_id, Box::new(object));
+        let instance = ObjectInstance::new(object_id, object);
         
         // Register in all mappings
         {
-            let mut objects = self.objects.write().await;
+            let mut objects = self.objects.shard(&object_id).write().await;
             objects.insert(object_id, instance);
         }
         
@@ -351,10 +952,11 @@ impl GorcInstanceManager {
             let mut object_positions = self.object_positions.write().await;
             object_positions.insert(object_id, initial_position);
         }
+        self.object_index.read().await.update_player_position(object_key(object_id), initial_position.into()).await;
 
         // Check and warn about large zone sizes
         let layers_for_warning = {
-            let objects = self.objects.read().await;
+            let objects = self.objects.shard(&object_id).read().await;
             if let Some(instance) = objects.get(&object_id) {
                 instance.object.get_layers()
             } else {
@@ -378,7 +980,7 @@ impl GorcInstanceManager {
         for player_id in player_ids {
             if let Some(player_pos) = self.player_positions.read().await.get(&player_id).copied() {
                 // Check each channel of the new object
-                let mut objects = self.objects.write().await;
+                let mut objects = self.objects.shard(&object_id).write().await;
                 if let Some(instance) = objects.get_mut(&object_id) {
                     for channel in 0..4 {
                         let should_sub = instance.zone_manager.is_in_zone(player_pos, channel);
@@ -396,10 +998,166 @@ impl GorcInstanceManager {
         object_id
     }
 
+    /// Registers an object type that can be restored from world snapshots.
+    ///
+    /// The type's [`GorcObject::snapshot_state`] must return the object
+    /// serialized with serde so it can be deserialized back into `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_name` - The name returned by the type's `GorcObject::type_name`
+    pub async fn register_snapshot_type<T>(&self, type_name: &str)
+    where
+        T: GorcObject + serde::de::DeserializeOwned + 'static,
+    {
+        self.snapshot_types
+            .write()
+            .await
+            .insert(type_name.to_string(), snapshot::restore_object::<T>);
+    }
+
+    /// Captures every object whose snapshot hook returns state.
+    ///
+    /// Objects without snapshot state, such as player avatars, are left out.
+    pub async fn snapshot(&self) -> WorldSnapshot {
+        let object_positions = self.object_positions.read().await.clone();
+
+        let mut captured = Vec::new();
+        for shard in self.objects.all() {
+            let objects = shard.read().await;
+            captured.extend(objects.values().filter_map(|instance| capture_object(instance, &object_positions)));
+        }
+
+        WorldSnapshot::new(captured)
+    }
+
+    /// Captures a single object's snapshot state
+    ///
+    /// # Returns
+    ///
+    /// `None` if the object doesn't exist or has no snapshot state
+    pub async fn snapshot_object(&self, object_id: GorcObjectId) -> Option<ObjectSnapshot> {
+        let object_positions = self.object_positions.read().await;
+        let objects = self.objects.shard(&object_id).read().await;
+        capture_object(objects.get(&object_id)?, &object_positions)
+    }
+
+    /// Applies an object snapshot, registering the object or replacing its state
+    ///
+    /// An existing object keeps its subscribers and authority; only its state
+    /// and position change. Used to keep cross-region ghost copies current.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the type has no registered restorer or the state fails to deserialize
+    pub async fn apply_object_snapshot(&self, entry: ObjectSnapshot) -> bool {
+        let Some(restorer) = self.snapshot_types.read().await.get(&entry.type_name).copied() else {
+            warn!("💾 GORC: Cannot apply snapshot for {} - no restorer for type {}", entry.object_id, entry.type_name);
+            return false;
+        };
+        let mut object = match restorer(entry.state) {
+            Ok(object) => object,
+            Err(e) => {
+                warn!("💾 GORC: Failed to apply {} snapshot for {}: {}", entry.type_name, entry.object_id, e);
+                return false;
+            }
+        };
+
+        let replaced = {
+            let mut objects = self.objects.shard(&entry.object_id).write().await;
+            match objects.get_mut(&entry.object_id) {
+                Some(instance) => {
+                    // Keep the tracked position until the move below updates zones
+                    object.update_position(instance.object.position());
+                    object.on_register(entry.object_id);
+                    instance.object = object;
+                    true
+                }
+                None => {
+                    drop(objects);
+                    self.register_boxed_object(object, entry.position, Some(entry.object_id)).await;
+                    false
+                }
+            }
+        };
+
+        if replaced {
+            self.update_object_position(entry.object_id, entry.position).await;
+        }
+        true
+    }
+
+    /// Restores objects from a world snapshot, keeping their original IDs.
+    ///
+    /// Objects whose type has no registered restorer, whose state fails to
+    /// deserialize, or whose ID is already registered are skipped with a
+    /// warning so one bad entry cannot block the rest of the world.
+    ///
+    /// # Returns
+    ///
+    /// The number of objects restored.
+    pub async fn restore(&self, snapshot: WorldSnapshot) -> usize {
+        let restorers = self.snapshot_types.read().await.clone();
+        let mut restored = 0;
+
+        for entry in snapshot.objects {
+            if self.objects.contains(&entry.object_id).await {
+                warn!("💾 GORC: Skipping snapshot object {} - already registered", entry.object_id);
+                continue;
+            }
+            let Some(restorer) = restorers.get(&entry.type_name) else {
+                warn!("💾 GORC: Skipping snapshot object {} - no restorer for type {}", entry.object_id, entry.type_name);
+                continue;
+            };
+
+            match restorer(entry.state) {
+                Ok(object) => {
+                    self.register_boxed_object(object, entry.position, Some(entry.object_id)).await;
+                    restored += 1;
+                }
+                Err(e) => warn!("💾 GORC: Failed to restore {} object {}: {}", entry.type_name, entry.object_id, e),
+            }
+        }
+
+        info!("💾 GORC: Restored {} object(s) from snapshot", restored);
+        restored
+    }
+
+    /// Writes a world snapshot to disk
+    ///
+    /// The file is written on the blocking thread pool, so large worlds don't
+    /// stall the runtime.
+    ///
+    /// # Returns
+    ///
+    /// The number of objects saved.
+    pub async fn save_snapshot(&self, path: impl AsRef<std::path::Path>) -> Result<usize, SnapshotError> {
+        let snapshot = self.snapshot().await;
+        let count = snapshot.objects.len();
+        let path = path.as_ref().to_path_buf();
+        tokio::task::spawn_blocking(move || snapshot.save(path))
+            .await
+            .map_err(|e| SnapshotError::Io(std::io::Error::other(e)))??;
+        Ok(count)
+    }
+
+    /// Loads a world snapshot from disk and restores its objects
+    ///
+    /// # Returns
+    ///
+    /// The number of objects restored.
+    pub async fn load_snapshot(&self, path: impl AsRef<std::path::Path>) -> Result<usize, SnapshotError> {
+        let path = path.as_ref().to_path_buf();
+        let snapshot = tokio::task::spawn_blocking(move || WorldSnapshot::load(path))
+            .await
+            .map_err(|e| SnapshotError::Io(std::io::Error::other(e)))??;
+        Ok(self.restore(snapshot).await)
+    }
+
     /// Unregisters an object instance
     pub async fn unregister_object(&self, object_id: GorcObjectId) -> bool {
         let type_name = {
-            let mut objects = self.objects.write().await;
+            let mut objects = self.objects.shard(&object_id).write().await;
             if let Some(mut instance) = objects.remove(&object_id) {
                 instance.object.on_unregister();
                 Some(instance.type_name)
@@ -423,15 +1181,33 @@ impl GorcInstanceManager {
                 let mut object_positions = self.object_positions.write().await;
                 object_positions.remove(&object_id);
             }
+            self.object_index.read().await.remove_player(object_key(object_id)).await;
 
             {
                 let mut zone_warnings = self.zone_size_warnings.write().await;
                 zone_warnings.remove(&object_id);
             }
+
+            self.unregister_object_owner(object_id).await;
+            self.remove_attachments(object_id).await;
+
+            {
+                let mut zone_exit_times = self.zone_exit_times.write().await;
+                zone_exit_times.retain(|(_, id, _), _| *id != object_id);
+            }
+
+            let expired = self.object_expiries
+                .write()
+                .await
+                .remove(&object_id)
+                .is_some_and(|expires_at| expires_at <= Instant::now());
             
             {
                 let mut stats = self.stats.write().await;
                 stats.total_objects = stats.total_objects.saturating_sub(1);
+                if expired {
+                    stats.objects_expired += 1;
+                }
             }
             
             tracing::info!("🗑️ Unregistered GORC object {} ({})", object_id, type_name);
@@ -444,7 +1220,7 @@ impl GorcInstanceManager {
     /// Update an object's position and return zone membership changes for zone events
     pub async fn update_object_position(&self, object_id: GorcObjectId, new_position: Vec3) -> Option<(Vec3, Vec3, Vec<(PlayerId, u8, bool)>)> {
         let old_position = {
-            let mut objects = self.objects.write().await;
+            let mut objects = self.objects.shard(&object_id).write().await;
             if let Some(instance) = objects.get_mut(&object_id) {
                 let old_pos = instance.object.position();
                 instance.update_position(new_position);
@@ -459,6 +1235,7 @@ impl GorcInstanceManager {
             let mut object_positions = self.object_positions.write().await;
             object_positions.insert(object_id, new_position);
         }
+        self.object_index.read().await.update_player_position(object_key(object_id), new_position.into()).await;
 
         // Check for virtual zone splits due to object movement
         let virtual_zones_to_split = self.virtualization_manager
@@ -473,16 +1250,100 @@ impl GorcInstanceManager {
         }
 
         // Calculate zone membership changes for all players
-        let zone_changes = self.recalculate_subscriptions_for_object_with_events(object_id, old_position, new_position).await;
+        let zone_changes = self.recalculate_subscriptions_for_object_with_events(object_id, new_position).await;
 
         Some((old_position, new_position, zone_changes))
     }
 
+    /// Moves a batch of objects and returns their zone membership changes.
+    ///
+    /// Takes each object shard's lock and the position lock once for the
+    /// whole batch instead of once per object, so plugins moving many objects
+    /// per tick don't contend on them. Unknown objects are skipped; if an object appears
+    /// more than once, its moves are applied in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `updates` - Objects and their new positions
+    ///
+    /// # Returns
+    ///
+    /// One [`ObjectMove`] per applied update, in input order.
+    pub async fn update_positions(&self, updates: &[(GorcObjectId, Vec3)]) -> Vec<ObjectMove> {
+        if updates.is_empty() {
+            return Vec::new();
+        }
+
+        let player_positions = self.predicted_player_positions().await;
+
+        // Group the batch by shard, keeping input order within each shard
+        let mut by_shard = vec![Vec::new(); self.objects.all().len()];
+        for (index, (object_id, _)) in updates.iter().enumerate() {
+            by_shard[self.objects.index(object_id)].push(index);
+        }
+
+        let mut indexed_moves = Vec::with_capacity(updates.len());
+        let mut retained = 0u64;
+        for (shard, indices) in self.objects.all().iter().zip(by_shard) {
+            if indices.is_empty() {
+                continue;
+            }
+            let mut objects = shard.write().await;
+            for index in indices {
+                let (object_id, new_position) = updates[index];
+                let Some(instance) = objects.get_mut(&object_id) else {
+                    continue;
+                };
+                let old_position = instance.object.position();
+                instance.update_position(new_position);
+
+                let (zone_changes, held) =
+                    recalculate_instance_zones(instance, new_position, &player_positions, self.zone_exit_margin);
+                retained += held;
+                indexed_moves.push((index, ObjectMove { object_id, old_position, new_position, zone_changes }));
+            }
+        }
+        indexed_moves.sort_by_key(|(index, _)| *index);
+        let moves: Vec<ObjectMove> = indexed_moves.into_iter().map(|(_, object_move)| object_move).collect();
+
+        {
+            let mut object_positions = self.object_positions.write().await;
+            for object_move in &moves {
+                object_positions.insert(object_move.object_id, object_move.new_position);
+            }
+        }
+        {
+            let object_index = self.object_index.read().await;
+            for object_move in &moves {
+                object_index.update_player_position(object_key(object_move.object_id), object_move.new_position.into()).await;
+            }
+        }
+
+        for object_move in &moves {
+            let virtual_zones_to_split = self.virtualization_manager
+                .update_object_position(object_move.object_id, object_move.old_position, object_move.new_position)
+                .await;
+            for virtual_id in virtual_zones_to_split {
+                if let Err(e) = self.virtualization_manager.split_virtual_zone(virtual_id).await {
+                    warn!("Failed to split virtual zone due to object movement: {}", e);
+                }
+            }
+        }
+
+        let tracked: Vec<_> = moves.iter()
+            .flat_map(|object_move| {
+                object_move.zone_changes.iter().map(move |&(player_id, channel, is_entry)| {
+                    (player_id, object_move.object_id, channel, is_entry)
+                })
+            })
+            .collect();
+        self.track_zone_changes(&tracked, retained).await;
+
+        moves
+    }
+
     /// Update a player's position and return zone membership changes
     pub async fn update_player_position(&self, player_id: PlayerId, new_position: Vec3) -> (Vec<(GorcObjectId, u8)>, Vec<(GorcObjectId, u8)>) {
-        let mut zone_entries = Vec::new();
-        let mut zone_exits = Vec::new();
-        
         // Get old position and update to new position
         let old_position = {
             let mut player_positions = self.player_positions.write().await;
@@ -491,6 +1352,11 @@ impl GorcInstanceManager {
             old_pos
         };
 
+        // Dead reckoning restarts from the reported position
+        if let Some(motion) = self.player_motion.write().await.get_mut(&player_id) {
+            motion.updated_at = Instant::now();
+        }
+
         {
             let spatial_position: Position = new_position.into();
             let partition = self.spatial_index.read().await;
@@ -499,64 +1365,95 @@ impl GorcInstanceManager {
                 .await;
         }
 
+        self.apply_player_zones(player_id, new_position, old_position.is_none()).await
+    }
 
-        // Check all objects for zone membership changes
-        let objects = self.objects.read().await;
-        let object_positions_map = self.object_positions.read().await;
-        
-        for (object_id, instance) in objects.iter() {
-            // CRITICAL: Get object position from tracking HashMap (single source of truth)
-            let object_position = match object_positions_map.get(object_id) {
-                Some(&pos) => pos,
-                None => {
-                    warn!("Object {} not found in object_positions tracking", object_id);
-                    continue;
-                }
-            };
-            
-            let layers = instance.object.get_layers();
-            
-            for layer in layers {
-                let distance_to_object = new_position.distance(object_position);
-                let was_in_zone = old_position.map_or(false, |pos| pos.distance(object_position) <= layer.radius);
-                let is_in_zone = distance_to_object <= layer.radius;
-                
-                
-                match (was_in_zone, is_in_zone) {
-                    (false, true) => {
-                        debug!("🎮 GORC: Zone entry - player {} enters object {} channel {}", player_id, object_id, layer.channel);
-                        zone_entries.push((*object_id, layer.channel));
-                    },
-                    (true, false) => {
-                        debug!("🎮 GORC: Zone exit - player {} leaves object {} channel {}", player_id, object_id, layer.channel);
-                        zone_exits.push((*object_id, layer.channel));
-                    },
-                    _ => {
-                        // Special case: if this is a first spawn (old_position is None) and player is in range,
-                        // force zone entry even if the logic above didn't catch it
-                        if old_position.is_none() && is_in_zone {
-                            debug!("🎮 GORC: First spawn entry - player {} enters object {} channel {}", player_id, object_id, layer.channel);
-                            zone_entries.push((*object_id, layer.channel));
+    /// Updates a player's subscriptions for a position and returns the zone
+    /// entries and exits.
+    ///
+    /// `first_spawn` also reports zones the player is already subscribed to,
+    /// so a newly spawned player receives their current state.
+    async fn apply_player_zones(&self, player_id: PlayerId, new_position: Vec3, first_spawn: bool) -> (Vec<(GorcObjectId, u8)>, Vec<(GorcObjectId, u8)>) {
+        let mut zone_entries = Vec::new();
+        let mut zone_exits = Vec::new();
+
+        // Snapshot object positions so the objects lock is never held alongside it
+        let object_positions_map: HashMap<GorcObjectId, Vec3> = self.object_positions.read().await.clone();
+        let mut zone_changes = Vec::new();
+        let mut retained = 0u64;
+
+        // Decide zone membership under each shard's read lock; most moves change
+        // nothing, so the write lock is only taken to apply actual changes
+        for shard in self.objects.all() {
+            let mut shard_changes = Vec::new();
+            {
+                let objects = shard.read().await;
+                for (object_id, instance) in objects.iter() {
+                    // CRITICAL: Get object position from tracking HashMap (single source of truth)
+                    let object_position = match object_positions_map.get(object_id) {
+                        Some(&pos) => pos,
+                        None => {
+                            warn!("Object {} not found in object_positions tracking", object_id);
+                            continue;
+                        }
+                    };
+
+                    let distance_to_object = new_position.distance(object_position);
+                    for layer in instance.object.get_layers() {
+                        let is_subbed = instance.is_subscribed(layer.channel, player_id);
+                        let (is_in_zone, held) = if instance.is_pinned(layer.channel, player_id) {
+                            (true, false)
+                        } else {
+                            zone_membership(&layer, distance_to_object, is_subbed, self.zone_exit_margin)
+                        };
+                        if held {
+                            retained += 1;
+                        }
+
+                        match (is_subbed, is_in_zone) {
+                            (false, true) => shard_changes.push((*object_id, layer.channel, true)),
+                            (true, false) => shard_changes.push((*object_id, layer.channel, false)),
+                            (true, true) if first_spawn => {
+                                // First spawn of an already subscribed player still needs the current state
+                                debug!("🎮 GORC: First spawn entry - player {} enters object {} channel {}", player_id, object_id, layer.channel);
+                                zone_entries.push((*object_id, layer.channel));
+                            },
+                            _ => {}
                         }
                     }
                 }
             }
-        }
 
-        debug!("🎮 GORC: Zone changes for player {} - {} entries, {} exits", player_id, zone_entries.len(), zone_exits.len());
+            if shard_changes.is_empty() {
+                continue;
+            }
 
+            let mut objects = shard.write().await;
+            for (object_id, channel, is_entry) in shard_changes {
+                // The object may have been unregistered, or the change applied, since the read
+                let Some(instance) = objects.get_mut(&object_id) else {
+                    continue;
+                };
+                if instance.is_subscribed(channel, player_id) == is_entry {
+                    continue;
+                }
 
-        // If this is a new player or they moved significantly, recalculate subscriptions
-        //
-        // N.B. `recalculate_player_subscriptions` tries to acquire a write lock to `objects`,
-        // which will deadlock. release the read lock now
-        drop(objects);
-        
-        // If this is a new player or they moved significantly, recalculate subscriptions
-        if old_position.is_none() || 
-           old_position.map(|old| old.distance(new_position) > 5.0).unwrap_or(true) {
-            self.recalculate_player_subscriptions(player_id, new_position).await;
+                if is_entry {
+                    debug!("🎮 GORC: Zone entry - player {} enters object {} channel {}", player_id, object_id, channel);
+                    instance.add_subscriber(channel, player_id);
+                    zone_entries.push((object_id, channel));
+                } else {
+                    debug!("🎮 GORC: Zone exit - player {} leaves object {} channel {}", player_id, object_id, channel);
+                    instance.remove_subscriber(channel, player_id);
+                    zone_exits.push((object_id, channel));
+                }
+                instance.stats.zone_transitions += 1;
+                zone_changes.push((player_id, object_id, channel, is_entry));
+            }
         }
+
+        self.track_zone_changes(&zone_changes, retained).await;
+        debug!("🎮 GORC: Zone changes for player {} - {} entries, {} exits", player_id, zone_entries.len(), zone_exits.len());
         
         (zone_entries, zone_exits)
     }
@@ -577,6 +1474,9 @@ impl GorcInstanceManager {
             .on_core("player_movement", move |event: PlayerMovementEvent| {
                 let manager_clone = instance_manager.clone();
                 tokio::spawn(async move {
+                    if let Some(velocity) = event.velocity {
+                        manager_clone.update_player_velocity(event.player_id, velocity).await;
+                    }
                     manager_clone.update_player_position(event.player_id, event.new_position).await;
                 });
                 Ok(())
@@ -621,33 +1521,166 @@ impl GorcInstanceManager {
     }
     
     /// Remove a player from all subscriptions
+    ///
+    /// Objects the player had authority over fall back to the server, and
+    /// each is queued as an `AuthorityChangedEvent`.
     pub async fn remove_player(&self, player_id: PlayerId) {
         {
             let mut player_positions = self.player_positions.write().await;
             player_positions.remove(&player_id);
         }
+        self.player_motion.write().await.remove(&player_id);
 
         {
             let partition = self.spatial_index.read().await;
             partition.remove_player(player_id).await;
         }
 
-        let mut objects = self.objects.write().await;
-        for instance in objects.values_mut() {
-            for channel in 0..4 {
-                instance.remove_subscriber(channel, player_id);
+        self.remove_player_ownership(player_id).await;
+
+        {
+            let mut zone_exit_times = self.zone_exit_times.write().await;
+            zone_exit_times.retain(|(id, _, _), _| *id != player_id);
+        }
+
+        let mut released = Vec::new();
+        for shard in self.objects.all() {
+            let mut objects = shard.write().await;
+            for (object_id, instance) in objects.iter_mut() {
+                for channel in 0..4 {
+                    instance.remove_subscriber(channel, player_id);
+                }
+                for pinned in instance.pinned.values_mut() {
+                    pinned.remove(&player_id);
+                }
+                // Objects driven by a departing player fall back to the server
+                if instance.has_authority(player_id) {
+                    released.push((*object_id, std::mem::replace(&mut instance.authority, ObjectAuthority::Server)));
+                }
             }
         }
+
+        if !released.is_empty() {
+            self.stats.write().await.authority_transfers += released.len() as u64;
+            for (object_id, previous) in released {
+                self.record_authority_change(object_id, previous, ObjectAuthority::Server).await;
+            }
+        }
+    }
+
+    /// Pins a player's subscription to an object's channel regardless of distance.
+    ///
+    /// The subscription survives zone recalculation until it is released with
+    /// [`force_unsubscribe`](Self::force_unsubscribe) or the player is removed.
+    /// Used for spectators, game masters and quest cameras that must follow an
+    /// object from anywhere in the world.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - Player to subscribe
+    /// * `object_id` - Object to subscribe to
+    /// * `channel` - Replication channel to subscribe to
+    ///
+    /// # Returns
+    ///
+    /// `Some(true)` if the player was newly subscribed, `Some(false)` if they
+    /// already were, or `None` if the object doesn't exist or has no such channel.
+    pub async fn force_subscribe(&self, player_id: PlayerId, object_id: GorcObjectId, channel: u8) -> Option<bool> {
+        let mut objects = self.objects.shard(&object_id).write().await;
+        let instance = objects.get_mut(&object_id)?;
+        if !instance.object.get_layers().iter().any(|layer| layer.channel == channel) {
+            return None;
+        }
+
+        instance.pinned.entry(channel).or_default().insert(player_id);
+        let added = instance.add_subscriber(channel, player_id);
+        if added {
+            instance.stats.zone_transitions += 1;
+        }
+        debug!("📌 GORC: Pinned player {} to object {} channel {}", player_id, object_id, channel);
+        Some(added)
+    }
+
+    /// Releases a pinned subscription.
+    ///
+    /// The player stays subscribed while they are within the channel's zone
+    /// (including its exit margin) and is unsubscribed otherwise.
+    ///
+    /// # Returns
+    ///
+    /// `Some(true)` if the player was unsubscribed, `Some(false)` if they stay
+    /// subscribed because they are in range, or `None` if the subscription
+    /// wasn't pinned.
+    pub async fn force_unsubscribe(&self, player_id: PlayerId, object_id: GorcObjectId, channel: u8) -> Option<bool> {
+        let player_pos = self.get_player_position(player_id).await;
+        let object_pos = self.get_object_position(object_id).await;
+
+        let mut objects = self.objects.shard(&object_id).write().await;
+        let instance = objects.get_mut(&object_id)?;
+        if !instance.pinned.get_mut(&channel)?.remove(&player_id) {
+            return None;
+        }
+
+        let in_range = match (player_pos, object_pos) {
+            (Some(player_pos), Some(object_pos)) => instance
+                .object
+                .get_layers()
+                .iter()
+                .find(|layer| layer.channel == channel)
+                .is_some_and(|layer| {
+                    let subscribed = instance.is_subscribed(channel, player_id);
+                    zone_membership(layer, player_pos.distance(object_pos), subscribed, self.zone_exit_margin).0
+                }),
+            _ => false,
+        };
+
+        let removed = !in_range && instance.remove_subscriber(channel, player_id);
+        if removed {
+            instance.stats.zone_transitions += 1;
+        }
+        debug!("📌 GORC: Unpinned player {} from object {} channel {}", player_id, object_id, channel);
+        Some(removed)
+    }
+
+    /// Check if a player's subscription to an object's channel is pinned
+    pub async fn is_subscription_pinned(&self, player_id: PlayerId, object_id: GorcObjectId, channel: u8) -> bool {
+        let objects = self.objects.shard(&object_id).read().await;
+        objects
+            .get(&object_id)
+            .is_some_and(|instance| instance.is_pinned(channel, player_id))
     }
 
     /// Get an object instance by ID
     pub async fn get_object(&self, object_id: GorcObjectId) -> Option<ObjectInstance> {
-        let objects = self.objects.read().await;
+        let objects = self.objects.shard(&object_id).read().await;
         // Note: This clones the entire instance, which might be expensive for large objects
         // In production, you might want to return a reference or use Arc<Mutex<ObjectInstance>>
         objects.get(&object_id).cloned()
     }
 
+    /// Get copies of several object instances at once
+    ///
+    /// Takes each object shard's lock once for the whole batch. Unknown
+    /// objects are left out of the result.
+    pub async fn get_objects(&self, object_ids: &[GorcObjectId]) -> HashMap<GorcObjectId, ObjectInstance> {
+        let mut by_shard = vec![Vec::new(); self.objects.all().len()];
+        for object_id in object_ids {
+            by_shard[self.objects.index(object_id)].push(*object_id);
+        }
+
+        let mut instances = HashMap::with_capacity(object_ids.len());
+        for (shard, ids) in self.objects.all().iter().zip(by_shard) {
+            if ids.is_empty() {
+                continue;
+            }
+            let objects = shard.read().await;
+            instances.extend(ids.into_iter().filter_map(|object_id| {
+                objects.get(&object_id).map(|instance| (object_id, instance.clone()))
+            }));
+        }
+        instances
+    }
+
     /// Get all objects of a specific type
     pub async fn get_objects_by_type(&self, type_name: &str) -> Vec<GorcObjectId> {
         let type_registry = self.type_registry.read().await;
@@ -657,57 +1690,516 @@ impl GorcInstanceManager {
             .unwrap_or_default()
     }
 
+    /// Get a copy of every object of a concrete type
+    ///
+    /// Only the typed object is cloned, not its `ObjectInstance` with zones
+    /// and subscriber sets.
+    ///
+    /// # Returns
+    ///
+    /// Object IDs paired with a clone of each matching object
+    pub async fn get_objects_of_type<T: GorcObject + Clone + 'static>(&self) -> Vec<(GorcObjectId, T)> {
+        let mut matching = Vec::new();
+        for shard in self.objects.all() {
+            let objects = shard.read().await;
+            matching.extend(objects.iter().filter_map(|(object_id, instance)| {
+                instance.get_object::<T>().map(|object| (*object_id, object.clone()))
+            }));
+        }
+        matching
+    }
+
+    /// Read an object as a concrete type without cloning it
+    ///
+    /// # Returns
+    ///
+    /// The closure's result, or `None` if the object doesn't exist or isn't a `T`
+    pub async fn with_object<T, R>(&self, object_id: GorcObjectId, f: impl FnOnce(&T) -> R) -> Option<R>
+    where
+        T: GorcObject + 'static,
+    {
+        let objects = self.objects.shard(&object_id).read().await;
+        objects.get(&object_id)?.get_object::<T>().map(f)
+    }
+
+    /// Mutate an object as a concrete type in place
+    ///
+    /// Every channel of the object is marked for replication afterwards. If
+    /// the closure moves the object, the move is applied through
+    /// [`update_object_position`](Self::update_object_position) so spatial
+    /// tracking and subscriptions stay consistent; zone events are not emitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `object_id` - The object to modify
+    /// * `f` - Closure receiving the typed object
+    ///
+    /// # Returns
+    ///
+    /// The closure's result, or `None` if the object doesn't exist or isn't a `T`
+    pub async fn with_object_mut<T, R>(&self, object_id: GorcObjectId, f: impl FnOnce(&mut T) -> R) -> Option<R>
+    where
+        T: GorcObject + 'static,
+    {
+        let (result, moved_to) = {
+            let mut objects = self.objects.shard(&object_id).write().await;
+            let instance = objects.get_mut(&object_id)?;
+            let old_position = instance.object.position();
+            let result = f(instance.get_object_mut::<T>()?);
+
+            let new_position = instance.object.position();
+            for layer in instance.object.get_layers() {
+                instance.mark_needs_update(layer.channel);
+            }
+            if new_position == old_position {
+                (result, None)
+            } else {
+                // Roll the move back so it goes through the normal movement path
+                instance.object.update_position(old_position);
+                (result, Some(new_position))
+            }
+        };
+
+        if let Some(new_position) = moved_to {
+            self.update_object_position(object_id, new_position).await;
+        }
+        Some(result)
+    }
+
     /// Update an object instance (after handlers have modified it)
     pub async fn update_object(&self, object_id: GorcObjectId, instance: ObjectInstance) {
-        let mut objects = self.objects.write().await;
+        let mut objects = self.objects.shard(&object_id).write().await;
         objects.insert(object_id, instance);
     }
 
+    /// Records `player_id` as the owner of `object_id`.
+    ///
+    /// A player owns at most one object (typically their avatar), so any
+    /// object the player owned before is released, and any previous owner of
+    /// `object_id` loses it. The owner also takes authority over the object,
+    /// which is queued as an `AuthorityChangedEvent`.
+    ///
+    /// # Returns
+    ///
+    /// `false` if `object_id` is not a registered object.
+    pub async fn register_object_owner(&self, player_id: PlayerId, object_id: GorcObjectId) -> bool {
+        if !self.objects.contains(&object_id).await {
+            warn!("🎯 GORC: Cannot assign owner {} to unknown object {}", player_id, object_id);
+            return false;
+        }
+
+        let mut player_objects = self.player_objects.write().await;
+        let mut object_owners = self.object_owners.write().await;
+
+        if let Some(previous_object) = player_objects.insert(player_id, object_id) {
+            if previous_object != object_id {
+                object_owners.remove(&previous_object);
+            }
+        }
+        if let Some(previous_owner) = object_owners.insert(object_id, player_id) {
+            if previous_owner != player_id {
+                player_objects.remove(&previous_owner);
+            }
+        }
+
+        drop(object_owners);
+        drop(player_objects);
+
+        // Owners drive their own objects until authority is transferred
+        self.set_object_authority(object_id, ObjectAuthority::Player(player_id)).await;
+
+        debug!("🎯 GORC: Player {} now owns object {}", player_id, object_id);
+        true
+    }
+
+    /// Transfers ownership of an object to another player.
+    ///
+    /// # Returns
+    ///
+    /// The previous owner, or `None` if the object had no owner. Returns
+    /// `None` without changes if `object_id` is not a registered object.
+    pub async fn transfer_object_owner(&self, object_id: GorcObjectId, new_owner: PlayerId) -> Option<PlayerId> {
+        let previous_owner = self.get_object_owner(object_id).await;
+        if self.register_object_owner(new_owner, object_id).await {
+            previous_owner.filter(|owner| *owner != new_owner)
+        } else {
+            None
+        }
+    }
+
+    /// Clears the owner of an object, returning the player that owned it.
+    pub async fn unregister_object_owner(&self, object_id: GorcObjectId) -> Option<PlayerId> {
+        // Lock order matches register_object_owner: players first, then objects
+        let mut player_objects = self.player_objects.write().await;
+        let owner = self.object_owners.write().await.remove(&object_id)?;
+        if player_objects.get(&owner) == Some(&object_id) {
+            player_objects.remove(&owner);
+        }
+        Some(owner)
+    }
+
+    /// Releases the object owned by a player, e.g. when they disconnect.
+    ///
+    /// The object itself stays registered; only the ownership is cleared.
+    pub async fn remove_player_ownership(&self, player_id: PlayerId) -> Option<GorcObjectId> {
+        let mut player_objects = self.player_objects.write().await;
+        let object_id = player_objects.remove(&player_id)?;
+        let mut object_owners = self.object_owners.write().await;
+        if object_owners.get(&object_id) == Some(&player_id) {
+            object_owners.remove(&object_id);
+        }
+        Some(object_id)
+    }
+
+    /// Gets the player that owns an object
+    pub async fn get_object_owner(&self, object_id: GorcObjectId) -> Option<PlayerId> {
+        self.object_owners.read().await.get(&object_id).copied()
+    }
+
     /// Find a player's GORC object by player ID (for message routing)
-    /// 
-    /// This is a temporary implementation that assumes the first object of type "GorcPlayer"
-    /// belongs to the requesting player. A more robust implementation would store player->object mappings.
-    pub async fn find_player_object(&self, _player_id: crate::PlayerId) -> Option<GorcObjectId> {
-        // For now, just find the first GorcPlayer object
-        // TODO: Implement proper player ID to object ID mapping
-        let objects_by_type = self.get_objects_by_type("GorcPlayer").await;
-        objects_by_type.into_iter().next()
+    ///
+    /// Returns the object registered for the player with
+    /// [`register_object_owner`](Self::register_object_owner), if any.
+    pub async fn find_player_object(&self, player_id: PlayerId) -> Option<GorcObjectId> {
+        self.player_objects.read().await.get(&player_id).copied()
     }
 
-    /// Get objects within range of a position using spatial index optimization
-    pub async fn get_objects_in_range(&self, position: Vec3, range: f64) -> Vec<GorcObjectId> {
-        let mut result_objects = Vec::new();
+    /// Gets the party allowed to push state for an object
+    pub async fn get_object_authority(&self, object_id: GorcObjectId) -> Option<ObjectAuthority> {
+        self.objects.shard(&object_id).read().await.get(&object_id).map(|instance| instance.authority)
+    }
+
+    /// Checks whether a player is the authority for an object
+    pub async fn has_authority(&self, object_id: GorcObjectId, player_id: PlayerId) -> bool {
+        self.get_object_authority(object_id)
+            .await
+            .is_some_and(|authority| authority.allows(player_id))
+    }
+
+    /// Sets a runtime tag on an object, e.g. `("faction", "pirates")`.
+    ///
+    /// Tags are plain metadata: they don't affect replication, but spatial
+    /// queries and [`get_objects_by_tag`](Self::get_objects_by_tag) can
+    /// filter on them without downcasting the object.
+    ///
+    /// # Returns
+    ///
+    /// The previous value of the tag, or `None` if `object_id` is not a
+    /// registered object.
+    pub async fn set_object_tag(
+        &self,
+        object_id: GorcObjectId,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Option<Option<String>> {
+        let mut objects = self.objects.shard(&object_id).write().await;
+        let instance = objects.get_mut(&object_id)?;
+        Some(instance.tags.insert(key.into(), value.into()))
+    }
+
+    /// Removes a runtime tag from an object
+    ///
+    /// # Returns
+    ///
+    /// The removed value, or `None` if the object or tag doesn't exist.
+    pub async fn remove_object_tag(&self, object_id: GorcObjectId, key: &str) -> Option<String> {
+        let mut objects = self.objects.shard(&object_id).write().await;
+        objects.get_mut(&object_id)?.tags.remove(key)
+    }
+
+    /// Gets the runtime tags of an object
+    pub async fn get_object_tags(&self, object_id: GorcObjectId) -> Option<HashMap<String, String>> {
+        let objects = self.objects.shard(&object_id).read().await;
+        objects.get(&object_id).map(|instance| instance.tags.clone())
+    }
+
+    /// Finds objects carrying a tag
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Tag to look for; tags declared by [`GorcObject::tags`] count as present
+    /// * `value` - Required runtime value, or `None` to accept any value
+    ///
+    /// # Returns
+    ///
+    /// IDs of the matching objects
+    pub async fn get_objects_by_tag(&self, key: &str, value: Option<&str>) -> Vec<GorcObjectId> {
+        let mut matching = Vec::new();
+        for shard in self.objects.all() {
+            let objects = shard.read().await;
+            matching.extend(objects.iter().filter_map(|(object_id, instance)| {
+                let matches = match value {
+                    Some(value) => instance.tag(key) == Some(value),
+                    None => instance.has_tag(key),
+                };
+                matches.then_some(*object_id)
+            }));
+        }
+        matching
+    }
+
+    /// Transfers authority over an object, e.g. when a player boards another
+    /// player's ship.
+    ///
+    /// Ownership is unaffected. The change is queued as an
+    /// `AuthorityChangedEvent`, which the event system emits; use
+    /// [`EventSystem::transfer_authority`](crate::system::EventSystem::transfer_authority)
+    /// to emit it straight away.
+    ///
+    /// # Returns
+    ///
+    /// The previous authority, or `None` if `object_id` is not a registered object.
+    pub async fn set_object_authority(&self, object_id: GorcObjectId, authority: ObjectAuthority) -> Option<ObjectAuthority> {
+        let previous = {
+            let mut objects = self.objects.shard(&object_id).write().await;
+            let instance = objects.get_mut(&object_id)?;
+            std::mem::replace(&mut instance.authority, authority)
+        };
+
+        if previous != authority {
+            self.stats.write().await.authority_transfers += 1;
+            debug!("🎯 GORC: Authority for object {} moved from {:?} to {:?}", object_id, previous, authority);
+            self.record_authority_change(object_id, previous, authority).await;
+        }
+        Some(previous)
+    }
+
+    /// Takes the authority changes made since the last call, oldest first.
+    ///
+    /// Every change of an object's authority is recorded, whether it came
+    /// from [`set_object_authority`](Self::set_object_authority), a change
+    /// of owner or a departing player. Only the most recent changes are kept
+    /// if nothing drains them.
+    pub async fn drain_authority_changes(&self) -> Vec<AuthorityChangedEvent> {
+        self.pending_authority_changes.write().await.drain(..).collect()
+    }
+
+    async fn record_authority_change(&self, object_id: GorcObjectId, previous: ObjectAuthority, authority: ObjectAuthority) {
+        let mut pending = self.pending_authority_changes.write().await;
+        if pending.len() >= MAX_PENDING_AUTHORITY_CHANGES {
+            pending.pop_front();
+        }
+        pending.push_back(AuthorityChangedEvent {
+            object_id,
+            previous_authority: previous,
+            new_authority: authority,
+            timestamp: crate::utils::current_timestamp(),
+        });
+    }
+
+    /// Attaches an object to a parent, e.g. a turret to a ship or a player to a vehicle.
+    ///
+    /// From then on the child's position is derived from the parent's
+    /// position plus `offset`. An object already attached elsewhere is moved
+    /// to the new parent. The attachment only records the relationship;
+    /// [`attached_positions`](Self::attached_positions) gives the positions
+    /// children must be moved to after their parent moves. Use
+    /// [`EventSystem::attach_object`](crate::system::EventSystem::attach_object)
+    /// to also move the child and replicate the attachment.
+    ///
+    /// # Arguments
+    ///
+    /// * `child_id` - Object to attach
+    /// * `parent_id` - Object to attach it to
+    /// * `offset` - Child position relative to the parent
+    ///
+    /// # Returns
+    ///
+    /// The previous attachment, if the child was already attached.
+    pub async fn attach_object(
+        &self,
+        child_id: GorcObjectId,
+        parent_id: GorcObjectId,
+        offset: Vec3,
+    ) -> Result<Option<ObjectAttachment>, AttachmentError> {
+        if child_id == parent_id {
+            return Err(AttachmentError::SelfAttachment(child_id));
+        }
+        for object_id in [child_id, parent_id] {
+            if !self.objects.contains(&object_id).await {
+                return Err(AttachmentError::UnknownObject(object_id));
+            }
+        }
+
+        let mut attachments = self.attachments.write().await;
+        let mut ancestor = Some(parent_id);
+        while let Some(object_id) = ancestor {
+            if object_id == child_id {
+                return Err(AttachmentError::Cycle { child: child_id, parent: parent_id });
+            }
+            ancestor = attachments.parents.get(&object_id).map(|attachment| attachment.parent_id);
+        }
+
+        let previous = attachments.parents.insert(child_id, ObjectAttachment { parent_id, offset });
+        if let Some(previous) = previous {
+            attachments.remove_child(previous.parent_id, child_id);
+        }
+        attachments.children.entry(parent_id).or_default().insert(child_id);
+
+        debug!("🔗 GORC: Attached object {} to {} at offset {:?}", child_id, parent_id, offset);
+        Ok(previous)
+    }
+
+    /// Detaches an object from its parent, leaving it where it is
+    ///
+    /// # Returns
+    ///
+    /// The removed attachment, or `None` if the object wasn't attached.
+    pub async fn detach_object(&self, child_id: GorcObjectId) -> Option<ObjectAttachment> {
+        let mut attachments = self.attachments.write().await;
+        let attachment = attachments.parents.remove(&child_id)?;
+        attachments.remove_child(attachment.parent_id, child_id);
+        drop(attachments);
+        debug!("🔗 GORC: Detached object {} from {}", child_id, attachment.parent_id);
+        Some(attachment)
+    }
+
+    /// Gets an object's attachment to its parent
+    pub async fn get_attachment(&self, child_id: GorcObjectId) -> Option<ObjectAttachment> {
+        self.attachments.read().await.parents.get(&child_id).copied()
+    }
+
+    /// Gets the objects directly attached to a parent
+    pub async fn get_attached_children(&self, parent_id: GorcObjectId) -> Vec<GorcObjectId> {
+        self.attachments
+            .read()
+            .await
+            .children
+            .get(&parent_id)
+            .map(|children| children.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Computes where every object attached below `parents` belongs.
+    ///
+    /// Walks the attachment hierarchy from the parents' tracked positions, so
+    /// children of children are included, each after its own parent.
+    ///
+    /// # Returns
+    ///
+    /// Attached objects and their derived positions, ready for
+    /// [`update_positions`](Self::update_positions).
+    pub async fn attached_positions(&self, parents: &[GorcObjectId]) -> Vec<(GorcObjectId, Vec3)> {
+        let attachments = self.attachments.read().await;
+        if attachments.children.is_empty() {
+            return Vec::new();
+        }
         let object_positions = self.object_positions.read().await;
 
-        // Get largest zone radius for query optimization
-        let query_radius = self.get_max_zone_radius().await.max(range);
-
-        // Use spatial queries for efficiency when available
-        let spatial_index = self.spatial_index.read().await;
-        let query_results = spatial_index.query_radius(
-            crate::types::Position::new(position.x as f64, position.y as f64, position.z as f64),
-            query_radius
-        ).await;
-
-        // Filter by actual object positions and range
-        for _query_result in query_results {
-            for (&object_id, &obj_pos) in object_positions.iter() {
-                if obj_pos.distance(position) <= range {
-                    result_objects.push(object_id);
+        let mut positions: HashMap<GorcObjectId, Vec3> = HashMap::new();
+        let mut pending: std::collections::VecDeque<GorcObjectId> = parents.iter().copied().collect();
+        let mut derived = Vec::new();
+        while let Some(parent_id) = pending.pop_front() {
+            let Some(children) = attachments.children.get(&parent_id) else {
+                continue;
+            };
+            let Some(parent_position) = positions.get(&parent_id).or_else(|| object_positions.get(&parent_id)).copied() else {
+                continue;
+            };
+            for &child_id in children {
+                let Some(attachment) = attachments.parents.get(&child_id) else {
+                    continue;
+                };
+                let position = Vec3::new(
+                    parent_position.x + attachment.offset.x,
+                    parent_position.y + attachment.offset.y,
+                    parent_position.z + attachment.offset.z,
+                );
+                if positions.insert(child_id, position).is_none() {
+                    derived.push(child_id);
+                    pending.push_back(child_id);
                 }
             }
         }
 
-        // Fallback to direct position checking if spatial index is empty
-        if result_objects.is_empty() {
-            result_objects = object_positions
-                .iter()
-                .filter(|(_, &obj_pos)| obj_pos.distance(position) <= range)
-                .map(|(&obj_id, _)| obj_id)
-                .collect();
+        derived.into_iter().map(|child_id| (child_id, positions[&child_id])).collect()
+    }
+
+    /// Drops an unregistered object's attachment and releases its children
+    async fn remove_attachments(&self, object_id: GorcObjectId) {
+        let mut attachments = self.attachments.write().await;
+        if let Some(attachment) = attachments.parents.remove(&object_id) {
+            attachments.remove_child(attachment.parent_id, object_id);
+        }
+        for child_id in attachments.children.remove(&object_id).unwrap_or_default() {
+            attachments.parents.remove(&child_id);
+        }
+    }
+
+    /// Get objects within range of a position using spatial index optimization
+    pub async fn get_objects_in_range(&self, position: Vec3, range: f64) -> Vec<GorcObjectId> {
+        self.query_objects(SpatialQuery::new(position, range))
+            .await
+            .into_iter()
+            .map(|result| result.object_id)
+            .collect()
+    }
+
+    /// Finds objects matching a spatial query
+    ///
+    /// Candidates come from the object spatial index; the type, channel and
+    /// tag filters are then checked against the registered objects. Player
+    /// include/exclude filters don't apply to objects.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Query center, radius and filters
+    ///
+    /// # Returns
+    ///
+    /// Matching objects, nearest first if `sort_by_distance` is set.
+    pub async fn query_objects(&self, query: SpatialQuery) -> Vec<ObjectQueryResult> {
+        let filters = query.filters;
+        let candidates = self
+            .object_index
+            .read()
+            .await
+            .query(SpatialQuery {
+                center: query.center,
+                radius: query.radius,
+                filters: QueryFilters { min_distance: filters.min_distance, ..Default::default() },
+            })
+            .await;
+
+        let mut results = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let object_id = GorcObjectId(candidate.player_id.0);
+            let objects = self.objects.shard(&object_id).read().await;
+            let Some(instance) = objects.get(&object_id) else {
+                continue;
+            };
+            let has_channel = |channel| instance.zone_manager.get_zones().contains_key(&channel);
+            if filters.accepts_object(&instance.type_name, has_channel, |tag| instance.has_tag(tag), |key| instance.tag(key)) {
+                results.push(ObjectQueryResult {
+                    object_id,
+                    object_type: instance.type_name.clone(),
+                    position: Vec3::new(candidate.position.x, candidate.position.y, candidate.position.z),
+                    distance: candidate.distance,
+                });
+            }
         }
 
-        result_objects
+        filters.finish(&mut results, |result| result.distance);
+        results
+    }
+
+    /// Finds players matching a spatial query
+    ///
+    /// Object type, channel and tag filters don't apply to players.
+    ///
+    /// # Returns
+    ///
+    /// Matching players, nearest first if `sort_by_distance` is set.
+    pub async fn query_players(&self, mut query: SpatialQuery) -> Vec<QueryResult> {
+        // Only the nearest players may be kept, so limit after sorting
+        let filters = query.filters.clone();
+        if filters.sort_by_distance {
+            query.filters.max_results = None;
+        }
+
+        let mut results = self.spatial_index.read().await.query(query).await;
+        filters.finish(&mut results, |result| result.distance);
+        results
     }
     
     /// Get the tracked position of an object (single source of truth for spatial queries)
@@ -715,6 +2207,137 @@ impl GorcInstanceManager {
         let object_positions = self.object_positions.read().await;
         object_positions.get(&object_id).copied()
     }
+
+    /// Dumps every object's zones and every player's subscriptions.
+    ///
+    /// Intended for debugging endpoints; it clones the subscription state of
+    /// every object, so avoid calling it on a hot path.
+    pub async fn inspect_zones(&self) -> ZoneInspection {
+        let player_positions = self.player_positions.read().await.clone();
+        let object_positions = self.object_positions.read().await.clone();
+
+        let mut subscriptions: HashMap<PlayerId, Vec<PlayerSubscription>> =
+            player_positions.keys().map(|player_id| (*player_id, Vec::new())).collect();
+        let mut inspected: Vec<ObjectZones> = Vec::with_capacity(object_positions.len());
+
+        for shard in self.objects.all() {
+            let objects = shard.read().await;
+            for (object_id, instance) in objects.iter() {
+                let position = object_positions.get(object_id).copied().unwrap_or_else(|| instance.object.position());
+                let layers = instance.object.get_layers();
+
+                let mut zones: Vec<ZoneState> = instance
+                    .zone_manager
+                    .get_zones()
+                    .values()
+                    .map(|zone| {
+                        let mut subscribers = instance.get_subscribers(zone.channel);
+                        subscribers.sort_by_key(|player_id| player_id.0);
+                        let mut pinned: Vec<PlayerId> = instance
+                            .pinned
+                            .get(&zone.channel)
+                            .map(|pinned| pinned.iter().copied().collect())
+                            .unwrap_or_default();
+                        pinned.sort_by_key(|player_id| player_id.0);
+                        ZoneState {
+                            channel: zone.channel,
+                            radius: zone.radius,
+                            exit_radius: layers
+                                .iter()
+                                .find(|layer| layer.channel == zone.channel)
+                                .map(|layer| layer.exit_radius(self.zone_exit_margin))
+                                .unwrap_or(zone.radius * (1.0 + self.zone_exit_margin)),
+                            active: zone.active,
+                            subscribers,
+                            pinned,
+                        }
+                    })
+                    .collect();
+                zones.sort_by_key(|zone| zone.channel);
+
+                let mut channels_by_player: HashMap<PlayerId, Vec<u8>> = HashMap::new();
+                for zone in &zones {
+                    for player_id in &zone.subscribers {
+                        channels_by_player.entry(*player_id).or_default().push(zone.channel);
+                    }
+                }
+                for (player_id, channels) in channels_by_player {
+                    subscriptions.entry(player_id).or_default().push(PlayerSubscription {
+                        object_id: *object_id,
+                        channels,
+                        distance: player_positions.get(&player_id).map(|player_pos| player_pos.distance(position)),
+                    });
+                }
+
+                inspected.push(ObjectZones {
+                    object_id: *object_id,
+                    object_type: instance.type_name.clone(),
+                    position,
+                    authority: instance.authority,
+                    tags: instance.tags.iter().map(|(key, value)| (key.clone(), value.clone())).collect(),
+                    zones,
+                });
+            }
+        }
+        inspected.sort_by_key(|object| object.object_id.0);
+
+        let mut players: Vec<PlayerSubscriptions> = subscriptions
+            .into_iter()
+            .map(|(player_id, mut subscriptions)| {
+                subscriptions.sort_by_key(|subscription| subscription.object_id.0);
+                PlayerSubscriptions {
+                    player_id,
+                    position: player_positions.get(&player_id).copied(),
+                    subscriptions,
+                }
+            })
+            .collect();
+        players.sort_by_key(|player| player.player_id.0);
+
+        ZoneInspection {
+            objects: inspected,
+            players,
+            timestamp: crate::utils::current_timestamp(),
+        }
+    }
+
+    /// Get the tracked positions of all objects
+    pub async fn get_object_positions(&self) -> Vec<(GorcObjectId, Vec3)> {
+        let object_positions = self.object_positions.read().await;
+        object_positions.iter().map(|(object_id, position)| (*object_id, *position)).collect()
+    }
+
+    /// Get the tracked positions of all players
+    pub async fn get_player_positions(&self) -> Vec<(PlayerId, Vec3)> {
+        let player_positions = self.player_positions.read().await;
+        player_positions.iter().map(|(player_id, position)| (*player_id, *position)).collect()
+    }
+
+    /// Get the tracked position of a player
+    pub async fn get_player_position(&self, player_id: PlayerId) -> Option<Vec3> {
+        let player_positions = self.player_positions.read().await;
+        player_positions.get(&player_id).copied()
+    }
+
+    /// Get the LOD tier an object's layer trims payloads to for a player
+    ///
+    /// Returns `None` when the player should receive the full payload: the
+    /// layer defines no tiers, the player is closer than the first tier, or
+    /// either position is unknown.
+    pub async fn lod_tier_for_player(&self, object_id: GorcObjectId, channel: u8, player_id: PlayerId) -> Option<LodTier> {
+        let player_pos = self.get_player_position(player_id).await?;
+        let object_pos = self.get_object_position(object_id).await?;
+        let objects = self.objects.shard(&object_id).read().await;
+        let layers = objects.get(&object_id)?.object.get_layers();
+        let layer = layers.iter().find(|l| l.channel == channel)?;
+        layer.lod_tier_for_distance(player_pos.distance(object_pos)).cloned()
+    }
+
+    /// Get the type name of an object without cloning its instance
+    pub async fn get_object_type(&self, object_id: GorcObjectId) -> Option<String> {
+        let objects = self.objects.shard(&object_id).read().await;
+        objects.get(&object_id).map(|instance| instance.type_name.clone())
+    }
     
     /// Find all players within radius of a position (for event-driven GORC emission)
     pub async fn find_players_in_radius(&self, position: Vec3, radius: f64) -> Vec<PlayerId> {
@@ -744,7 +2367,7 @@ impl GorcInstanceManager {
     
     /// Get current object state for a specific layer/channel
     pub async fn get_object_state_for_layer(&self, object_id: GorcObjectId, channel: u8) -> Option<Vec<u8>> {
-        let objects = self.objects.read().await;
+        let objects = self.objects.shard(&object_id).read().await;
         if let Some(instance) = objects.get(&object_id) {
             let layers = instance.object.get_layers();
             if let Some(layer) = layers.iter().find(|l| l.channel == channel) {
@@ -757,6 +2380,25 @@ impl GorcInstanceManager {
         None
     }
 
+    /// Get current object state for a specific layer/channel in the binary wire format
+    ///
+    /// Falls back to the JSON layer data when the object provides no binary encoding.
+    pub async fn get_object_state_for_layer_binary(&self, object_id: GorcObjectId, channel: u8) -> Option<(PayloadEncoding, Vec<u8>)> {
+        let objects = self.objects.shard(&object_id).read().await;
+        let instance = objects.get(&object_id)?;
+        let layers = instance.object.get_layers();
+        let layer = layers.iter().find(|l| l.channel == channel)?;
+        match instance.object.serialize_for_layer_binary(layer) {
+            Ok(Some(data)) => Some((PayloadEncoding::Bincode, data)),
+            Ok(None) => instance
+                .object
+                .serialize_for_layer(layer)
+                .ok()
+                .map(|data| (PayloadEncoding::Json, data)),
+            Err(_) => None,
+        }
+    }
+
     /// Check if a player should be subscribed to an object on a specific channel
     #[allow(dead_code)]
     async fn should_subscribe(&self, player_id: PlayerId, object_id: GorcObjectId, channel: u8) -> bool {
@@ -769,7 +2411,7 @@ impl GorcInstanceManager {
             return false;
         };
 
-        let objects = self.objects.read().await;
+        let objects = self.objects.shard(&object_id).read().await;
         let Some(instance) = objects.get(&object_id) else {
             return false;
         };
@@ -777,112 +2419,68 @@ impl GorcInstanceManager {
         instance.zone_manager.is_in_zone(player_pos, channel)
     }
 
-    /// Recalculate subscriptions for a player
-    async fn recalculate_player_subscriptions(&self, player_id: PlayerId, player_position: Vec3) {
-        let object_ids: Vec<GorcObjectId> = {
-            let object_positions = self.object_positions.read().await;
-            object_positions.keys().copied().collect()
-        };
-
-        let mut objects = self.objects.write().await;
-        for object_id in object_ids {
-            if let Some(instance) = objects.get_mut(&object_id) {
-                for channel in 0..4 {
-                    let should_sub = instance.zone_manager.is_in_zone(player_position, channel);
-                    let is_subbed = instance.is_subscribed(channel, player_id);
-
-                    match (should_sub, is_subbed) {
-                        (true, false) => {
-                            instance.add_subscriber(channel, player_id);
-                            tracing::debug!("➕ Player {} subscribed to object {} channel {}", 
-                                          player_id, object_id, channel);
-                        }
-                        (false, true) => {
-                            instance.remove_subscriber(channel, player_id);
-                            tracing::debug!("➖ Player {} unsubscribed from object {} channel {}", 
-                                          player_id, object_id, channel);
-                        }
-                        _ => {} // No change needed
-                    }
-                }
-            }
-        }
-    }
-
     /// Recalculate subscriptions when an object moves and return zone changes for events
     async fn recalculate_subscriptions_for_object_with_events(
         &self,
         object_id: GorcObjectId,
-        old_position: Vec3,
         new_position: Vec3
     ) -> Vec<(PlayerId, u8, bool)> {
-        let mut zone_changes = Vec::new();
+        let player_positions = self.predicted_player_positions().await;
 
-        let player_positions: Vec<(PlayerId, Vec3)> = {
-            let player_positions = self.player_positions.read().await;
-            player_positions.iter().map(|(&id, &pos)| (id, pos)).collect()
+        let (zone_changes, retained) = {
+            let mut objects = self.objects.shard(&object_id).write().await;
+            match objects.get_mut(&object_id) {
+                Some(instance) => recalculate_instance_zones(instance, new_position, &player_positions, self.zone_exit_margin),
+                None => (Vec::new(), 0),
+            }
         };
 
-        let mut objects = self.objects.write().await;
-        if let Some(instance) = objects.get_mut(&object_id) {
-            let layers = instance.object.get_layers();
-
-            for (player_id, player_pos) in player_positions {
-                // Use inner zone optimization - check smallest zones first
-                let mut player_in_inner_zone = false;
-                let mut sorted_layers = layers.clone();
-                sorted_layers.sort_by(|a, b| a.radius.partial_cmp(&b.radius).unwrap());
-
-                let smallest_radius = sorted_layers.get(0).map(|l| l.radius).unwrap_or(0.0);
-                for layer in &sorted_layers {
-                    let channel = layer.channel;
-
-                    // Skip larger zones if player is already in a smaller inner zone
-                    if player_in_inner_zone && layer.radius > smallest_radius {
-                        if instance.is_subscribed(channel, player_id) {
-                            // Player is guaranteed to be in this larger zone too
-                            continue;
-                        }
-                    }
+        let tracked: Vec<_> = zone_changes.iter()
+            .map(|&(player_id, channel, is_entry)| (player_id, object_id, channel, is_entry))
+            .collect();
+        self.track_zone_changes(&tracked, retained).await;
 
-                    let was_in_zone = player_pos.distance(old_position) <= layer.radius;
-                    let is_in_zone = player_pos.distance(new_position) <= layer.radius;
-                    let is_subbed = instance.is_subscribed(channel, player_id);
+        zone_changes
+    }
 
-                    if is_in_zone && layer.radius == smallest_radius {
-                        player_in_inner_zone = true;
-                    }
+    /// Records zone transitions for thrash detection and statistics.
+    ///
+    /// An entry into a zone the player left less than `ZONE_THRASH_WINDOW`
+    /// ago counts as thrash.
+    ///
+    /// # Arguments
+    ///
+    /// * `changes` - Transitions as (player, object, channel, is_entry)
+    /// * `retained` - Subscriptions kept alive by the exit margin
+    async fn track_zone_changes(&self, changes: &[(PlayerId, GorcObjectId, u8, bool)], retained: u64) {
+        if changes.is_empty() && retained == 0 {
+            return;
+        }
 
-                    match (was_in_zone, is_in_zone, is_subbed) {
-                        (false, true, false) => {
-                            // Zone entry
-                            instance.add_subscriber(channel, player_id);
-                            instance.stats.zone_transitions += 1;
-                            zone_changes.push((player_id, channel, true)); // true = entry
-                            debug!("🎯 GORC Object Movement: Player {} entered zone {} of object {}", player_id, channel, object_id);
-                        }
-                        (true, false, true) => {
-                            // Zone exit
-                            instance.remove_subscriber(channel, player_id);
-                            instance.stats.zone_transitions += 1;
-                            zone_changes.push((player_id, channel, false)); // false = exit
-                            debug!("🚪 GORC Object Movement: Player {} exited zone {} of object {}", player_id, channel, object_id);
-                        }
-                        (false, true, true) | (true, false, false) => {
-                            // Subscription state matches zone state - sync if needed
-                            if !is_subbed && is_in_zone {
-                                instance.add_subscriber(channel, player_id);
-                            } else if is_subbed && !is_in_zone {
-                                instance.remove_subscriber(channel, player_id);
-                            }
+        let now = Instant::now();
+        let mut thrash = 0u64;
+        {
+            let mut zone_exit_times = self.zone_exit_times.write().await;
+            for &(player_id, object_id, channel, is_entry) in changes {
+                let key = (player_id, object_id, channel);
+                if is_entry {
+                    if let Some(exited_at) = zone_exit_times.remove(&key) {
+                        if now.duration_since(exited_at) < ZONE_THRASH_WINDOW {
+                            thrash += 1;
                         }
-                        _ => {}
                     }
+                } else {
+                    zone_exit_times.insert(key, now);
                 }
             }
         }
 
-        zone_changes
+        if thrash > 0 {
+            debug!("🔁 GORC: {} zone re-entries within {:?} of leaving", thrash, ZONE_THRASH_WINDOW);
+        }
+        let mut stats = self.stats.write().await;
+        stats.zone_thrash_events += thrash;
+        stats.hysteresis_retained += retained;
     }
 
     /// Check for large zone sizes and emit warnings
@@ -906,16 +2504,6 @@ impl GorcInstanceManager {
         }
     }
 
-    /// Get the maximum zone radius across all objects for spatial query optimization
-    async fn get_max_zone_radius(&self) -> f64 {
-        let objects = self.objects.read().await;
-        objects.values()
-            .flat_map(|instance| instance.object.get_layers())
-            .map(|layer| layer.radius)
-            .max_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap_or(100.0) // Default reasonable radius
-    }
-
     /// Notify existing players when a new object is created (handles Issue #1)
     pub async fn notify_existing_players_for_new_object(&self, object_id: GorcObjectId) -> Vec<(PlayerId, u8)> {
         let mut zone_entries = Vec::new();
@@ -923,7 +2511,7 @@ impl GorcInstanceManager {
         // CRITICAL: Get object position from tracking HashMap (single source of truth)
         let (object_position, layers) = {
             let object_positions = self.object_positions.read().await;
-            let objects = self.objects.read().await;
+            let objects = self.objects.shard(&object_id).read().await;
             
             if let Some(&pos) = object_positions.get(&object_id) {
                 if let Some(instance) = objects.get(&object_id) {
@@ -941,7 +2529,7 @@ impl GorcInstanceManager {
             player_positions.iter().map(|(&id, &pos)| (id, pos)).collect::<Vec<_>>()
         };
 
-        let mut objects = self.objects.write().await;
+        let mut objects = self.objects.shard(&object_id).write().await;
         if let Some(instance) = objects.get_mut(&object_id) {
             for (player_id, player_pos) in player_positions {
                 // Check if player should be subscribed to any zones of this new object
@@ -965,22 +2553,26 @@ impl GorcInstanceManager {
     pub async fn process_virtualization(&self) -> Result<(), Box<dyn std::error::Error>> {
         // Collect current objects and their zones
         let objects_info = {
-            let objects = self.objects.read().await;
-            let object_positions = self.object_positions.read().await;
+            let object_positions = self.object_positions.read().await.clone();
 
             let mut info = HashMap::new();
-            for (object_id, instance) in objects.iter() {
-                if let Some(&position) = object_positions.get(object_id) {
-                    let layers = instance.object.get_layers();
-                    info.insert(*object_id, (position, layers));
+            for shard in self.objects.all() {
+                let objects = shard.read().await;
+                for (object_id, instance) in objects.iter() {
+                    if let Some(&position) = object_positions.get(object_id) {
+                        let layers = instance.object.get_layers();
+                        info.insert(*object_id, (position, layers));
+                    }
                 }
             }
             info
         };
 
+        let players: Vec<Vec3> = self.player_positions.read().await.values().copied().collect();
+
         // Get virtualization recommendations
         let recommendations = self.virtualization_manager
-            .analyze_virtualization_opportunities(&objects_info)
+            .analyze_virtualization_opportunities_with_players(&objects_info, &players)
             .await;
 
         // Apply merge recommendations
@@ -1020,11 +2612,75 @@ impl GorcInstanceManager {
         self.virtualization_manager.get_virtual_zone_objects(virtual_id).await
     }
 
+    /// Takes the virtual zone merges and splits performed since the last call
+    pub async fn drain_virtual_zone_events(&self) -> Vec<crate::gorc::virtualization::VirtualZoneEvent> {
+        self.virtualization_manager.drain_events().await
+    }
+
     /// Gets virtualization statistics
     pub async fn get_virtualization_stats(&self) -> crate::gorc::virtualization::VirtualizationStats {
         self.virtualization_manager.get_stats().await
     }
 
+    /// Records replication traffic that was actually delivered for an object.
+    ///
+    /// Called by the send paths once transmission succeeds, so per-object and
+    /// global stats only count messages that reached the network layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `object_id` - Object the messages replicated
+    /// * `messages` - Number of messages delivered
+    /// * `bytes` - Bytes handed to the network for those messages
+    pub async fn record_replication(&self, object_id: GorcObjectId, messages: u64, bytes: u64) {
+        if messages == 0 {
+            return;
+        }
+
+        if let Some(instance) = self.objects.shard(&object_id).write().await.get_mut(&object_id) {
+            instance.stats.updates_sent += messages;
+            instance.stats.bytes_transmitted += bytes;
+        }
+
+        let mut stats = self.stats.write().await;
+        stats.replication_events_sent += messages;
+        stats.total_bytes_transmitted += bytes;
+    }
+
+    /// Records replication traffic for several objects at once
+    ///
+    /// # Arguments
+    ///
+    /// * `replications` - Objects with the messages and bytes sent for each
+    pub async fn record_replications(&self, replications: &[(GorcObjectId, u64, u64)]) {
+        let mut by_shard = vec![Vec::new(); self.objects.all().len()];
+        for &(object_id, messages, bytes) in replications.iter().filter(|(_, messages, _)| *messages > 0) {
+            by_shard[self.objects.index(&object_id)].push((object_id, messages, bytes));
+        }
+
+        let (mut total_messages, mut total_bytes) = (0, 0);
+        for (shard, entries) in self.objects.all().iter().zip(by_shard) {
+            if entries.is_empty() {
+                continue;
+            }
+            let mut objects = shard.write().await;
+            for (object_id, messages, bytes) in entries {
+                if let Some(instance) = objects.get_mut(&object_id) {
+                    instance.stats.updates_sent += messages;
+                    instance.stats.bytes_transmitted += bytes;
+                }
+                total_messages += messages;
+                total_bytes += bytes;
+            }
+        }
+
+        if total_messages > 0 {
+            let mut stats = self.stats.write().await;
+            stats.replication_events_sent += total_messages;
+            stats.total_bytes_transmitted += total_bytes;
+        }
+    }
+
     /// Get statistics for the instance manager
     pub async fn get_stats(&self) -> InstanceManagerStats {
         let mut stats = self.stats.read().await.clone();
@@ -1058,4 +2714,12 @@ pub struct InstanceManagerStats {
     pub avg_objects_per_type: f32,
     /// Number of objects with large zone warnings
     pub large_zone_warnings: usize,
+    /// Zone re-entries shortly after leaving the same zone (boundary thrash)
+    pub zone_thrash_events: u64,
+    /// Zone checks where the exit margin kept an existing subscription alive
+    pub hysteresis_retained: u64,
+    /// Number of object authority changes
+    pub authority_transfers: u64,
+    /// Number of objects despawned because their time-to-live elapsed
+    pub objects_expired: u64,
 }
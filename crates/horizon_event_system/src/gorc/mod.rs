@@ -54,6 +54,10 @@ pub mod spatial;
 pub mod virtualization;
 pub mod config;
 pub mod system;
+pub mod wire;
+pub mod snapshot;
+pub mod inspect;
+pub mod federation;
 
 // Utility modules
 pub mod defaults;
@@ -67,14 +71,15 @@ pub mod tests;
 
 // Re-export core types for use elsewhere in the core and for use in plugins
 pub use channels::{
-    ReplicationChannel, ReplicationLayer, ReplicationLayers, ReplicationPriority, 
+    ReplicationChannel, ReplicationLayer, ReplicationLayers, LodTier, ReplicationPriority, 
     CompressionType, GorcManager, MineralType, Replication, GorcObjectRegistry,
     GorcConfig, GorcStats, PerformanceReport, GorcError
 };
 
 pub use instance::{
-    GorcObject, GorcObjectId, ObjectInstance, GorcInstanceManager, 
-    InstanceManagerStats, ObjectStats
+    GorcObject, GorcObjectId, ObjectInstance, ObjectAuthority, GorcInstanceManager, 
+    InstanceManagerStats, ObjectStats, ObjectMove, ObjectAttachment, AttachmentError,
+    DEFAULT_OBJECT_SHARDS
 };
 
 pub use zones::{
@@ -91,7 +96,7 @@ pub use network::{
 pub use subscription::{
     SubscriptionManager, SubscriptionType, ProximitySubscription,
     RelationshipSubscription, InterestSubscription, SubscriptionStats,
-    InterestLevel, ActivityPattern
+    InterestLevel, ActivityPattern, ViewCone, ViewRelation
 };
 
 pub use multicast::{
@@ -100,13 +105,18 @@ pub use multicast::{
 };
 
 pub use spatial::{
-    SpatialPartition, SpatialQuery, RegionRTree, QueryResult, QueryFilters,
-    SpatialStats, GlobalSpatialStats, SpatialIndexStats, NodeStats, SpatialObject
+    SpatialPartition, SpatialQuery, RegionRTree, QueryResult, QueryFilters, ObjectQueryResult,
+    SpatialStats, GlobalSpatialStats, SpatialIndexStats, NodeStats, SpatialObject,
+    SpatialQueryHandle, SpatialEntity, SpatialFilter, SpatialHit,
+    SpatialIndex, SpatialIndexConfig, SpatialIndexKind, RegionOctree, RegionLooseGrid,
+    SpatialBenchmark, SpatialBenchResult, SpatialMaintenanceReport
 };
 
 pub use virtualization::{
     VirtualizationManager, VirtualizationConfig, VirtualZone, VirtualZoneId,
-    VirtualizationStats, VirtualizationRecommendations, ZoneMergeRequest, ZoneSplitRequest
+    VirtualizationStats, VirtualizationRecommendations, ZoneMergeRequest, ZoneSplitRequest,
+    VirtualizationStrategy, VirtualizationStrategyKind, ZoneCandidate,
+    DensityStrategy, PlayerCountStrategy, StaticGridStrategy, VirtualZoneEvent
 };
 
 pub use config::{
@@ -114,6 +124,18 @@ pub use config::{
     NetworkConfig as GorcNetworkConfig, MonitoringConfig, ConfigValidationError
 };
 
+pub use wire::{
+    WireFormat, WireFrame, WireMessageKind, WireStamp, PayloadEncoding, WireError
+};
+
+pub use snapshot::{WorldSnapshot, ObjectSnapshot, SnapshotError};
+pub use inspect::{ZoneInspection, ObjectZones, ZoneState, PlayerSubscriptions, PlayerSubscription};
+
+pub use federation::{
+    RegionFederation, RegionLink, NeighborRegion, FederationMessage,
+    FederationError, FederationReport, FederationStats, PlayerSession, PlayerArrival, HandedOffObject
+};
+
 pub use system::{
-    CompleteGorcSystem, GorcPerformanceReport, GORC_VERSION, MAX_CHANNELS
+    CompleteGorcSystem, GorcPerformanceReport, GORC_VERSION, MAX_CHANNELS, BROADCAST_CHANNEL
 };
\ No newline at end of file
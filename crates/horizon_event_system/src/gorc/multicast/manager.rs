@@ -183,9 +183,9 @@ impl MulticastManager {
             player_groups_lock.get(&player_id).cloned().unwrap_or_default()
         };
         
-        // Remove from all groups
+        // Remove from all groups (LOD room IDs share the mapping and are skipped here)
         for group_id in player_groups {
-            if self.remove_player_from_group(player_id, group_id).await? {
+            if let Ok(true) = self.remove_player_from_group(player_id, group_id).await {
                 removed_count += 1;
             }
         }
@@ -203,6 +203,12 @@ impl MulticastManager {
         Ok(removed_count)
     }
 
+    /// Gets the members of a group, or `None` if the group doesn't exist
+    pub async fn get_group_members(&self, group_id: MulticastGroupId) -> Option<Vec<PlayerId>> {
+        let groups = self.groups.read().await;
+        groups.get(&group_id).map(|group| group.get_members())
+    }
+
     /// Gets all groups a player is subscribed to
     pub async fn get_player_groups(&self, player_id: PlayerId) -> Vec<MulticastGroupId> {
         let player_groups = self.player_groups.read().await;
@@ -0,0 +1,189 @@
+/// Adaptive replication frequency based on per-player link quality
+use crate::gorc::instance::GorcObjectId;
+use std::collections::HashMap;
+use tokio::time::{Duration, Instant};
+
+/// Configuration for adaptive frequency scaling
+#[derive(Debug, Clone)]
+pub struct AdaptiveFrequencyConfig {
+    /// Enable adaptive frequency scaling
+    pub enabled: bool,
+    /// Round-trip time above which a player is considered congested
+    pub congested_rtt_ms: f32,
+    /// Queued update count above which a player is considered congested
+    pub congested_backlog: usize,
+    /// Round-trip time below which frequencies are restored
+    pub recovery_rtt_ms: f32,
+    /// Queued update count below which frequencies are restored
+    pub recovery_backlog: usize,
+    /// Lowest fraction of the target frequency a player can be scaled to
+    pub min_frequency_scale: f32,
+    /// Fraction of the target frequency restored per healthy adjustment
+    pub recovery_step: f32,
+    /// Minimum time between scale adjustments
+    pub adjust_interval_ms: u64,
+}
+
+impl Default for AdaptiveFrequencyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            congested_rtt_ms: 200.0,
+            congested_backlog: 200,
+            recovery_rtt_ms: 100.0,
+            recovery_backlog: 50,
+            min_frequency_scale: 1.0 / 3.0, // 60Hz -> 20Hz at worst
+            recovery_step: 0.1,
+            adjust_interval_ms: 1000,
+        }
+    }
+}
+
+/// Link quality tracking and frequency scaling for a single player
+#[derive(Debug)]
+pub struct LinkQuality {
+    /// Smoothed round-trip time in milliseconds (`None` until first sample)
+    pub rtt_ms: Option<f32>,
+    /// Update backlog observed at the last adjustment
+    pub backlog: usize,
+    /// Current fraction of the target channel frequencies (1.0 = full rate)
+    pub frequency_scale: f32,
+    /// Time of the last scale adjustment
+    last_adjustment: Instant,
+    /// Last time each object channel was replicated to this player
+    last_sent: HashMap<(GorcObjectId, u8), Instant>,
+}
+
+impl Default for LinkQuality {
+    fn default() -> Self {
+        Self {
+            rtt_ms: None,
+            backlog: 0,
+            frequency_scale: 1.0,
+            last_adjustment: Instant::now(),
+            last_sent: HashMap::new(),
+        }
+    }
+}
+
+impl LinkQuality {
+    /// Records a round-trip time sample
+    pub fn record_rtt(&mut self, rtt_ms: f32) {
+        self.rtt_ms = Some(match self.rtt_ms {
+            Some(current) => current * 0.8 + rtt_ms * 0.2,
+            None => rtt_ms,
+        });
+    }
+
+    /// Re-evaluates the frequency scale from the current RTT and backlog.
+    ///
+    /// Congested links have their scale halved down to the configured
+    /// minimum; healthy links recover gradually. Links between the two
+    /// thresholds keep their current scale.
+    ///
+    /// # Returns
+    ///
+    /// The new scale if it changed, otherwise `None`.
+    pub fn adjust(&mut self, config: &AdaptiveFrequencyConfig, backlog: usize) -> Option<f32> {
+        self.backlog = backlog;
+        if !config.enabled
+            || self.last_adjustment.elapsed() < Duration::from_millis(config.adjust_interval_ms)
+        {
+            return None;
+        }
+        self.last_adjustment = Instant::now();
+
+        let rtt = self.rtt_ms.unwrap_or(0.0);
+        let previous = self.frequency_scale;
+        if rtt > config.congested_rtt_ms || backlog > config.congested_backlog {
+            self.frequency_scale = (self.frequency_scale * 0.5).max(config.min_frequency_scale);
+        } else if rtt < config.recovery_rtt_ms && backlog < config.recovery_backlog {
+            self.frequency_scale = (self.frequency_scale + config.recovery_step).min(1.0);
+        }
+
+        (self.frequency_scale != previous).then_some(self.frequency_scale)
+    }
+
+    /// Checks whether an object channel is due for replication at the scaled frequency.
+    ///
+    /// Players at full scale are never throttled. Records the send time when
+    /// the update is due.
+    ///
+    /// # Arguments
+    ///
+    /// * `object_id` - Object being replicated
+    /// * `channel` - Replication channel
+    /// * `target_hz` - Unscaled target frequency of the channel
+    pub fn should_replicate(&mut self, object_id: GorcObjectId, channel: u8, target_hz: f32) -> bool {
+        let frequency = target_hz * self.frequency_scale;
+        if self.frequency_scale >= 1.0 || frequency <= 0.0 {
+            return true;
+        }
+
+        let now = Instant::now();
+        let interval = Duration::from_secs_f32(1.0 / frequency);
+        match self.last_sent.get(&(object_id, channel)) {
+            Some(last) if now.duration_since(*last) < interval => false,
+            _ => {
+                self.last_sent.insert((object_id, channel), now);
+                true
+            }
+        }
+    }
+
+    /// Forgets send times for an object that is no longer replicated
+    pub fn forget_object(&mut self, object_id: GorcObjectId) {
+        self.last_sent.retain(|(id, _), _| *id != object_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AdaptiveFrequencyConfig {
+        AdaptiveFrequencyConfig {
+            adjust_interval_ms: 0,
+            ..AdaptiveFrequencyConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_scale_drops_under_congestion_and_recovers() {
+        let config = config();
+        let mut link = LinkQuality::default();
+
+        link.record_rtt(400.0);
+        assert_eq!(link.adjust(&config, 0), Some(0.5));
+        assert_eq!(link.adjust(&config, 0), Some(1.0 / 3.0));
+        assert_eq!(link.adjust(&config, 0), None);
+
+        // Between thresholds the scale holds
+        link.rtt_ms = Some(150.0);
+        assert_eq!(link.adjust(&config, 0), None);
+
+        // A large backlog keeps the link congested even with a good RTT
+        link.rtt_ms = Some(20.0);
+        assert_eq!(link.adjust(&config, 500), None);
+        for _ in 0..20 {
+            link.adjust(&config, 0);
+        }
+        assert_eq!(link.frequency_scale, 1.0);
+    }
+
+    #[test]
+    fn test_should_replicate_honors_scaled_frequency() {
+        let mut link = LinkQuality::default();
+        let object_id = GorcObjectId::new();
+        assert!(link.should_replicate(object_id, 0, 60.0));
+        assert!(link.should_replicate(object_id, 0, 60.0));
+
+        link.frequency_scale = 0.5;
+        assert!(link.should_replicate(object_id, 0, 60.0));
+        assert!(!link.should_replicate(object_id, 0, 60.0));
+        assert!(link.should_replicate(object_id, 1, 30.0));
+
+        link.forget_object(object_id);
+        assert!(link.should_replicate(object_id, 0, 60.0));
+    }
+}
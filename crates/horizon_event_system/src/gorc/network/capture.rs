@@ -0,0 +1,335 @@
+/// Replication capture and offline replay
+///
+/// A [`ReplicationRecorder`] attached to the network engine writes every queued
+/// `ReplicationUpdate` to a JSON-lines file. [`ReplicationReplay`] feeds a
+/// capture back through a `NetworkReplicationEngine`, so bandwidth and zone
+/// issues seen on a live server can be reproduced without clients.
+use super::engine::NetworkReplicationEngine;
+use super::types::{NetworkError, NetworkStats, ReplicationUpdate};
+use crate::context::{LogLevel, ServerContext, ServerError};
+use crate::system::EventSystem;
+use crate::types::{PlayerId, RegionId};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tokio::time::{Duration, Instant};
+
+/// A replication update captured with its recipients and timing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapturedUpdate {
+    /// Milliseconds since the capture started
+    pub offset_ms: u64,
+    /// Players the update was queued for
+    pub target_players: Vec<PlayerId>,
+    /// The captured update
+    pub update: ReplicationUpdate,
+}
+
+/// Writes queued replication updates to a capture file
+#[derive(Debug)]
+pub struct ReplicationRecorder {
+    writer: tokio::sync::Mutex<BufWriter<File>>,
+    started_at: Instant,
+    recorded: AtomicU64,
+}
+
+impl ReplicationRecorder {
+    /// Creates a recorder writing to `path`, replacing any existing file
+    pub async fn create(path: impl AsRef<Path>) -> Result<Self, NetworkError> {
+        let file = File::create(path)
+            .await
+            .map_err(|e| NetworkError::CaptureError(format!("Failed to create capture file: {}", e)))?;
+        Ok(Self {
+            writer: tokio::sync::Mutex::new(BufWriter::new(file)),
+            started_at: Instant::now(),
+            recorded: AtomicU64::new(0),
+        })
+    }
+
+    /// Appends an update to the capture
+    pub async fn record(&self, target_players: &[PlayerId], update: &ReplicationUpdate) -> Result<(), NetworkError> {
+        let entry = CapturedUpdate {
+            offset_ms: self.started_at.elapsed().as_millis() as u64,
+            target_players: target_players.to_vec(),
+            update: update.clone(),
+        };
+        let mut line = serde_json::to_string(&entry)
+            .map_err(|e| NetworkError::SerializationError(e.to_string()))?;
+        line.push('\n');
+
+        self.writer
+            .lock()
+            .await
+            .write_all(line.as_bytes())
+            .await
+            .map_err(|e| NetworkError::CaptureError(format!("Failed to write capture: {}", e)))?;
+        self.recorded.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Flushes buffered updates to disk
+    pub async fn flush(&self) -> Result<(), NetworkError> {
+        self.writer
+            .lock()
+            .await
+            .flush()
+            .await
+            .map_err(|e| NetworkError::CaptureError(format!("Failed to flush capture: {}", e)))
+    }
+
+    /// Gets the number of updates recorded so far
+    pub fn recorded(&self) -> u64 {
+        self.recorded.load(Ordering::Relaxed)
+    }
+}
+
+/// Options controlling how a capture is replayed
+#[derive(Debug, Clone)]
+pub struct ReplayOptions {
+    /// Playback speed relative to the original capture (0.0 replays as fast as possible)
+    pub speed: f64,
+    /// Process the engine queues after every update instead of once per timestamp
+    pub process_each_update: bool,
+}
+
+impl Default for ReplayOptions {
+    fn default() -> Self {
+        Self {
+            speed: 0.0,
+            process_each_update: false,
+        }
+    }
+}
+
+/// Outcome of replaying a capture
+#[derive(Debug, Clone, Default)]
+pub struct ReplayReport {
+    /// Updates fed back into the engine
+    pub updates_replayed: u64,
+    /// Players that appeared in the capture
+    pub players: usize,
+    /// Engine statistics after the replay
+    pub network_stats: NetworkStats,
+}
+
+/// A loaded replication capture ready to be replayed
+#[derive(Debug, Clone, Default)]
+pub struct ReplicationReplay {
+    /// Captured updates in recording order
+    pub updates: Vec<CapturedUpdate>,
+}
+
+impl ReplicationReplay {
+    /// Loads a capture written by [`ReplicationRecorder`]
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self, NetworkError> {
+        let file = File::open(path)
+            .await
+            .map_err(|e| NetworkError::CaptureError(format!("Failed to open capture file: {}", e)))?;
+
+        let mut updates = Vec::new();
+        let mut lines = BufReader::new(file).lines();
+        let mut line_number = 0;
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .map_err(|e| NetworkError::CaptureError(format!("Failed to read capture: {}", e)))?
+        {
+            line_number += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry = serde_json::from_str(&line).map_err(|e| {
+                NetworkError::CaptureError(format!("Invalid capture entry on line {}: {}", line_number, e))
+            })?;
+            updates.push(entry);
+        }
+
+        Ok(Self { updates })
+    }
+
+    /// Gets every player that received updates in the capture
+    pub fn players(&self) -> HashSet<PlayerId> {
+        self.updates
+            .iter()
+            .flat_map(|entry| entry.target_players.iter().copied())
+            .collect()
+    }
+
+    /// Feeds the capture back through a network engine.
+    ///
+    /// Every captured player is added to the engine, updates are queued in
+    /// their original order, and all players are flushed at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - The engine to replay into
+    /// * `options` - Playback speed and processing options
+    pub async fn replay(
+        &self,
+        engine: &NetworkReplicationEngine,
+        options: &ReplayOptions,
+    ) -> Result<ReplayReport, NetworkError> {
+        let players = self.players();
+        for player_id in &players {
+            engine.add_player(*player_id).await;
+        }
+
+        let started_at = Instant::now();
+        let mut last_offset = None;
+        for entry in &self.updates {
+            if options.speed > 0.0 {
+                let due = Duration::from_secs_f64(entry.offset_ms as f64 / 1000.0 / options.speed);
+                tokio::time::sleep_until(started_at + due).await;
+            }
+            if !options.process_each_update && last_offset.is_some_and(|offset| offset != entry.offset_ms) {
+                engine.process_updates().await?;
+            }
+            engine.queue_update(entry.target_players.clone(), entry.update.clone()).await;
+            if options.process_each_update {
+                engine.process_updates().await?;
+            }
+            last_offset = Some(entry.offset_ms);
+        }
+
+        for player_id in &players {
+            engine.flush_player(*player_id).await?;
+        }
+
+        Ok(ReplayReport {
+            updates_replayed: self.updates.len() as u64,
+            players: players.len(),
+            network_stats: engine.get_stats().await,
+        })
+    }
+}
+
+/// Server context for offline replays that records traffic instead of sending it
+#[derive(Debug)]
+pub struct ReplayServerContext {
+    events: Arc<EventSystem>,
+    region_id: RegionId,
+    luminal_handle: luminal::Handle,
+    sent: Mutex<HashMap<PlayerId, (u64, u64)>>,
+}
+
+impl ReplayServerContext {
+    /// Creates a replay context
+    pub fn new(luminal_handle: luminal::Handle) -> Self {
+        Self {
+            events: Arc::new(EventSystem::new()),
+            region_id: RegionId::new(),
+            luminal_handle,
+            sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Gets the messages and bytes sent to each player during the replay
+    pub fn traffic(&self) -> HashMap<PlayerId, (u64, u64)> {
+        self.sent.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+#[async_trait]
+impl ServerContext for ReplayServerContext {
+    fn events(&self) -> Arc<EventSystem> {
+        self.events.clone()
+    }
+
+    fn region_id(&self) -> RegionId {
+        self.region_id
+    }
+
+    fn log(&self, _level: LogLevel, _message: &str) {}
+
+    async fn send_to_player(&self, player_id: PlayerId, data: &[u8]) -> Result<(), ServerError> {
+        let mut sent = self.sent.lock().unwrap_or_else(|e| e.into_inner());
+        let (messages, bytes) = sent.entry(player_id).or_insert((0, 0));
+        *messages += 1;
+        *bytes += data.len() as u64;
+        Ok(())
+    }
+
+    async fn broadcast(&self, _data: &[u8]) -> Result<(), ServerError> {
+        Ok(())
+    }
+
+    fn luminal_handle(&self) -> luminal::Handle {
+        self.luminal_handle.clone()
+    }
+
+    fn gorc_instance_manager(&self) -> Option<Arc<crate::gorc::GorcInstanceManager>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gorc::channels::{CompressionType, ReplicationPriority};
+    use crate::gorc::instance::{GorcInstanceManager, GorcObjectId};
+    use crate::gorc::network::NetworkConfig;
+
+    fn update(object_id: GorcObjectId, sequence: u32) -> ReplicationUpdate {
+        ReplicationUpdate {
+            object_id,
+            object_type: "Asteroid".to_string(),
+            channel: 0,
+            data: vec![sequence as u8; 64],
+            priority: ReplicationPriority::Critical,
+            sequence,
+            timestamp: 0,
+            compression: CompressionType::None,
+            reliable: false,
+            tick: 0,
+            sent_at: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_capture_and_replay_reproduce_traffic() {
+        let luminal_rt = luminal::Runtime::new().expect("Failed to create luminal runtime");
+        let new_engine = |context: Arc<ReplayServerContext>| {
+            NetworkReplicationEngine::new(NetworkConfig::default(), Arc::new(GorcInstanceManager::new()), context)
+        };
+        let (alice, bob) = (PlayerId::new(), PlayerId::new());
+        let object_id = GorcObjectId::new();
+        let path = std::env::temp_dir().join(format!("gorc_capture_{}.jsonl", uuid::Uuid::new_v4()));
+
+        // Record a live session
+        let live_context = Arc::new(ReplayServerContext::new(luminal_rt.handle().clone()));
+        let live = new_engine(live_context.clone());
+        live.add_player(alice).await;
+        live.add_player(bob).await;
+        live.start_capture(&path).await.unwrap();
+        for sequence in 0..10 {
+            live.queue_update(vec![alice, bob], update(object_id, sequence)).await;
+        }
+        live.queue_update(vec![alice], update(object_id, 10)).await;
+        live.flush_player(alice).await.unwrap();
+        live.flush_player(bob).await.unwrap();
+        assert_eq!(live.stop_capture().await.unwrap(), 11);
+
+        // Replay it offline and compare the traffic each player received
+        let replay = ReplicationReplay::load(&path).await.unwrap();
+        assert_eq!(replay.players().len(), 2);
+        let replay_context = Arc::new(ReplayServerContext::new(luminal_rt.handle().clone()));
+        let report = replay
+            .replay(&new_engine(replay_context.clone()), &ReplayOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(report.updates_replayed, 11);
+        assert_eq!(report.network_stats.updates_sent, 21);
+        let live_traffic = live_context.traffic();
+        let replay_traffic = replay_context.traffic();
+        assert_eq!(replay_traffic[&alice].1, live_traffic[&alice].1);
+        assert_eq!(replay_traffic[&bob].1, live_traffic[&bob].1);
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+}
@@ -0,0 +1,118 @@
+/// Compression backends for replication batches
+use super::types::NetworkError;
+use crate::gorc::channels::CompressionType;
+use flate2::{Compression, write::DeflateEncoder, read::DeflateDecoder};
+use std::io::prelude::*;
+
+/// Marker prefixed to LZ4 compressed payloads
+pub const LZ4_MARKER: u8 = 0xF8;
+/// Marker prefixed to deflate compressed payloads
+pub const DEFLATE_MARKER: u8 = 0xF9;
+/// Marker prefixed to Zstd compressed payloads
+pub const ZSTD_MARKER: u8 = 0xFA;
+
+/// Zstd level used for `CompressionType::Zstd`
+const ZSTD_LEVEL: i32 = 3;
+/// Zstd level used for `CompressionType::High`
+const ZSTD_HIGH_LEVEL: i32 = 19;
+
+/// Compresses a payload with the given algorithm.
+///
+/// The output starts with a marker byte identifying the algorithm. Marker
+/// bytes can never begin a UTF-8 sequence, so clients can tell compressed
+/// payloads apart from plain JSON and binary GORC frames.
+///
+/// # Arguments
+///
+/// * `compression` - The algorithm to apply
+/// * `data` - The uncompressed payload
+///
+/// # Returns
+///
+/// The marked, compressed payload, or `None` if the compression type is not
+/// a byte-level codec (`None`, `Delta`, `Quantized` and `Custom` are handled
+/// by the serializer rather than here).
+pub fn compress(compression: CompressionType, data: &[u8]) -> Result<Option<Vec<u8>>, NetworkError> {
+    let (marker, body) = match compression {
+        CompressionType::Lz4 => (LZ4_MARKER, lz4_flex::compress_prepend_size(data)),
+        CompressionType::Zlib => {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::fast());
+            encoder.write_all(data)
+                .map_err(|e| NetworkError::CompressionError(format!("Deflate failed: {}", e)))?;
+            let body = encoder.finish()
+                .map_err(|e| NetworkError::CompressionError(format!("Deflate finalization failed: {}", e)))?;
+            (DEFLATE_MARKER, body)
+        }
+        CompressionType::Zstd => (ZSTD_MARKER, zstd_compress(data, ZSTD_LEVEL)?),
+        CompressionType::High => (ZSTD_MARKER, zstd_compress(data, ZSTD_HIGH_LEVEL)?),
+        CompressionType::None
+        | CompressionType::Delta
+        | CompressionType::Quantized
+        | CompressionType::Custom(_) => return Ok(None),
+    };
+
+    let mut compressed = Vec::with_capacity(body.len() + 1);
+    compressed.push(marker);
+    compressed.extend_from_slice(&body);
+    Ok(Some(compressed))
+}
+
+/// Decompresses a payload produced by [`compress`].
+///
+/// Payloads without a compression marker are returned unchanged.
+pub fn decompress(data: &[u8]) -> Result<Vec<u8>, NetworkError> {
+    let Some((&marker, body)) = data.split_first() else {
+        return Ok(Vec::new());
+    };
+
+    match marker {
+        LZ4_MARKER => lz4_flex::decompress_size_prepended(body)
+            .map_err(|e| NetworkError::CompressionError(format!("LZ4 decompression failed: {}", e))),
+        DEFLATE_MARKER => {
+            let mut decompressed = Vec::new();
+            DeflateDecoder::new(body)
+                .read_to_end(&mut decompressed)
+                .map_err(|e| NetworkError::CompressionError(format!("Deflate decompression failed: {}", e)))?;
+            Ok(decompressed)
+        }
+        ZSTD_MARKER => zstd::stream::decode_all(body)
+            .map_err(|e| NetworkError::CompressionError(format!("Zstd decompression failed: {}", e))),
+        _ => Ok(data.to_vec()),
+    }
+}
+
+fn zstd_compress(data: &[u8], level: i32) -> Result<Vec<u8>, NetworkError> {
+    zstd::bulk::compress(data, level)
+        .map_err(|e| NetworkError::CompressionError(format!("Zstd failed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_payload() -> Vec<u8> {
+        let updates: Vec<_> = (0..32)
+            .map(|i| serde_json::json!({ "object_type": "Asteroid", "channel": 0, "position": [i, i * 2, i * 3] }))
+            .collect();
+        serde_json::to_vec(&updates).unwrap()
+    }
+
+    #[test]
+    fn test_codecs_round_trip() {
+        let data = sample_payload();
+        for compression in [CompressionType::Lz4, CompressionType::Zlib, CompressionType::Zstd, CompressionType::High] {
+            let compressed = compress(compression, &data).unwrap().unwrap();
+            assert!(compressed.len() < data.len(), "{:?} did not shrink the payload", compression);
+            assert!(std::str::from_utf8(&compressed).is_err());
+            assert_eq!(decompress(&compressed).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_non_codec_types_pass_through() {
+        let data = sample_payload();
+        assert!(compress(CompressionType::None, &data).unwrap().is_none());
+        assert!(compress(CompressionType::Delta, &data).unwrap().is_none());
+        assert_eq!(decompress(&data).unwrap(), data);
+    }
+}
@@ -4,6 +4,8 @@ use crate::gorc::channels::{ReplicationPriority, CompressionType, ReplicationLay
 use super::engine::NetworkReplicationEngine;
 use crate::types::PlayerId;
 use crate::gorc::instance::{GorcObjectId, GorcInstanceManager};
+use crate::gorc::system::BROADCAST_CHANNEL;
+use crate::gorc::subscription::SubscriptionManager;
 use crate::Vec3;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -21,6 +23,10 @@ pub struct ReplicationCoordinator {
     update_scheduler: UpdateScheduler,
     /// Sequence counter for updates
     sequence_counter: u32,
+    /// Number of replication ticks run so far
+    tick_count: u64,
+    /// Per-player interest (focus and facing) used to adjust update priority
+    subscriptions: Option<Arc<SubscriptionManager>>,
 }
 
 impl ReplicationCoordinator {
@@ -34,11 +40,23 @@ impl ReplicationCoordinator {
             instance_manager,
             update_scheduler: UpdateScheduler::new(),
             sequence_counter: 0,
+            tick_count: 0,
+            subscriptions: None,
         }
     }
 
+    /// Uses a subscription manager to adjust each player's update priority.
+    ///
+    /// Objects a player focuses on or faces are queued at a higher priority
+    /// for that player, and objects behind them at a lower one.
+    pub fn set_subscription_manager(&mut self, subscriptions: Arc<SubscriptionManager>) {
+        self.subscriptions = Some(subscriptions);
+    }
+
     /// Main replication tick - called regularly to process updates
     pub async fn tick(&mut self) -> Result<(), NetworkError> {
+        self.tick_count += 1;
+
         // Generate updates for objects that need them
         let objects_needing_updates = self.update_scheduler.get_objects_needing_updates().await;
         
@@ -53,14 +71,16 @@ impl ReplicationCoordinator {
                     properties: vec![], // Use all properties
                     compression: CompressionType::None,
                     priority: ReplicationPriority::Normal,
+                    exit_margin: None,
+                    lod: Vec::new(),
+                    reliable: false,
                 };
-                let serialized_data = match object_instance.object.serialize_for_layer(&core_layer) {
-                    Ok(data) => data,
-                    Err(_) => {
-                        // Skip objects that can't be serialized
-                        self.update_scheduler.mark_object_updated(object_id).await;
-                        continue;
-                    }
+                // The boxed serialization error isn't Send, so it must not be held across an await
+                let serialized_data = object_instance.object.serialize_for_layer(&core_layer).ok();
+                let Some(serialized_data) = serialized_data else {
+                    // Skip objects that can't be serialized
+                    self.update_scheduler.mark_object_updated(object_id).await;
+                    continue;
                 };
                 
                 // Create replication update
@@ -79,6 +99,9 @@ impl ReplicationCoordinator {
                         .unwrap_or_default()
                         .as_millis() as u64,
                     compression: CompressionType::None,
+                    reliable: object_instance.object.get_layers().iter().any(|layer| layer.channel == 0 && layer.reliable),
+                    tick: self.tick_count,
+                    sent_at: 0,
                 };
                 
                 // Get all players subscribed to the default channel (0)
@@ -88,7 +111,24 @@ impl ReplicationCoordinator {
                     .unwrap_or_default();
                 
                 // Queue the update in the network engine
-                self.network_engine.queue_update(target_players, update).await;
+                match &self.subscriptions {
+                    Some(subscriptions) => {
+                        let position = object_instance.object.position().into();
+                        let mut by_priority: HashMap<ReplicationPriority, Vec<PlayerId>> = HashMap::new();
+                        for player_id in target_players {
+                            let priority = subscriptions
+                                .get_object_priority(player_id, object_id, position, update.priority)
+                                .await;
+                            by_priority.entry(priority).or_default().push(player_id);
+                        }
+                        for (priority, players) in by_priority {
+                            let mut update = update.clone();
+                            update.priority = priority;
+                            self.network_engine.queue_update(players, update).await;
+                        }
+                    }
+                    None => self.network_engine.queue_update(target_players, update).await,
+                }
             }
             
             // Mark the object as updated regardless of whether we found data
@@ -101,6 +141,45 @@ impl ReplicationCoordinator {
         Ok(())
     }
 
+    /// Broadcasts an event to every connected player, regardless of zones.
+    ///
+    /// The event is queued through the network engine on the broadcast
+    /// channel, so it is batched and prioritised with regular replication.
+    /// Clients receive it with the nil object ID and `event_type` as its
+    /// object type.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_type` - Name clients receive the event under, e.g. `"world_boss_spawned"`
+    /// * `data` - Serialized event payload
+    /// * `priority` - Queue priority of the event
+    ///
+    /// # Returns
+    ///
+    /// The number of players the event was queued for.
+    pub async fn broadcast(&mut self, event_type: &str, data: Vec<u8>, priority: ReplicationPriority) -> usize {
+        self.sequence_counter += 1;
+        let update = ReplicationUpdate {
+            object_id: GorcObjectId(uuid::Uuid::nil()),
+            object_type: event_type.to_string(),
+            channel: BROADCAST_CHANNEL,
+            data,
+            priority,
+            sequence: self.sequence_counter,
+            timestamp: crate::utils::current_timestamp_millis(),
+            compression: CompressionType::None,
+            reliable: false,
+            tick: self.tick_count,
+            sent_at: 0,
+        };
+        self.network_engine.queue_broadcast(update).await
+    }
+
+    /// Gets the number of replication ticks run so far
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
     /// Adds a player to the replication system
     pub async fn add_player(&self, player_id: PlayerId, position: Vec3) {
         self.network_engine.add_player(player_id).await;
@@ -113,6 +192,30 @@ impl ReplicationCoordinator {
         self.instance_manager.remove_player(player_id).await;
     }
 
+    /// Records a round-trip time sample used for adaptive frequency scaling
+    pub async fn record_player_rtt(&self, player_id: PlayerId, rtt_ms: f32) {
+        self.network_engine.record_rtt(player_id, rtt_ms).await;
+    }
+
+    /// Records a client's acknowledgement of reliable updates
+    ///
+    /// # Returns
+    ///
+    /// The number of pending reliable updates the acknowledgement released
+    pub async fn acknowledge_player_updates(&self, player_id: PlayerId, sequences: &[u32]) -> usize {
+        self.network_engine.acknowledge(player_id, sequences).await
+    }
+
+    /// Starts capturing every replication update to a file for offline replay
+    pub async fn start_capture(&self, path: impl AsRef<std::path::Path>) -> Result<(), NetworkError> {
+        self.network_engine.start_capture(path).await
+    }
+
+    /// Stops the active replication capture, returning the number of updates recorded
+    pub async fn stop_capture(&self) -> Result<u64, NetworkError> {
+        self.network_engine.stop_capture().await
+    }
+
     /// Updates a player's position
     pub async fn update_player_position(&self, player_id: PlayerId, position: Vec3) {
         self.instance_manager.update_player_position(player_id, position).await;
@@ -133,6 +236,7 @@ impl ReplicationCoordinator {
     pub async fn unregister_object(&mut self, object_id: GorcObjectId) {
         self.instance_manager.unregister_object(object_id).await;
         self.update_scheduler.remove_object(object_id).await;
+        self.network_engine.forget_object(object_id).await;
     }
 
     /// Gets comprehensive replication statistics
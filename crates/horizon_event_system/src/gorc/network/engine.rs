@@ -1,15 +1,17 @@
 /// Network replication engine implementation
 use super::types::{NetworkConfig, NetworkStats, NetworkError, ReplicationBatch, ReplicationUpdate};
 use super::queue::PlayerNetworkState;
+use super::capture::ReplicationRecorder;
 use crate::types::PlayerId;
-use crate::gorc::instance::GorcInstanceManager;
+use crate::gorc::instance::{GorcInstanceManager, GorcObjectId};
+use crate::gorc::system::BROADCAST_CHANNEL;
 use crate::context::ServerContext;
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{info, warn};
-use flate2::{Compression, write::DeflateEncoder, read::DeflateDecoder};
-use std::io::prelude::*;
+use tracing::{debug, info, warn};
+use super::compression;
 
 /// Core network replication engine that manages update distribution
 #[derive(Debug, Clone)]
@@ -25,6 +27,8 @@ pub struct NetworkReplicationEngine {
     instance_manager: Arc<GorcInstanceManager>,
     /// Reference to server context for network operations
     server_context: Arc<dyn ServerContext>,
+    /// Active replication capture, if recording is enabled
+    recorder: Arc<RwLock<Option<ReplicationRecorder>>>,
 }
 
 impl NetworkReplicationEngine {
@@ -40,6 +44,7 @@ impl NetworkReplicationEngine {
             global_stats: Arc::new(RwLock::new(NetworkStats::default())),
             instance_manager,
             server_context,
+            recorder: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -68,15 +73,153 @@ impl NetworkReplicationEngine {
 
     /// Queues a replication update for transmission
     pub async fn queue_update(&self, target_players: Vec<PlayerId>, update: ReplicationUpdate) {
+        if let Some(recorder) = self.recorder.read().await.as_ref() {
+            if let Err(e) = recorder.record(&target_players, &update).await {
+                warn!("Failed to capture replication update: {}", e);
+            }
+        }
+
+        let target_hz = self.config.read().await
+            .target_frequencies
+            .get(&update.channel)
+            .copied()
+            .unwrap_or(0.0);
         let mut player_states = self.player_states.write().await;
+        let mut throttled = 0u64;
         
         for player_id in target_players {
             if let Some(state) = player_states.get_mut(&player_id) {
+                // Congested players receive each channel at a reduced frequency
+                if !state.link.should_replicate(update.object_id, update.channel, target_hz) {
+                    throttled += 1;
+                    continue;
+                }
                 if let Err(e) = state.queue_update(update.clone()) {
                     warn!("Failed to queue update for player {}: {}", player_id, e);
                 }
             }
         }
+        drop(player_states);
+        
+        if throttled > 0 {
+            self.global_stats.write().await.updates_throttled += throttled;
+        }
+    }
+
+    /// Queues an update for every player, regardless of zones.
+    ///
+    /// The update is sent on [`BROADCAST_CHANNEL`] and goes through each
+    /// player's priority queue and batching like any other update, but is
+    /// never throttled for link quality nor dropped when a player's tick
+    /// budget runs out; it is deferred instead.
+    ///
+    /// # Returns
+    ///
+    /// The number of players the update was queued for.
+    pub async fn queue_broadcast(&self, mut update: ReplicationUpdate) -> usize {
+        update.channel = BROADCAST_CHANNEL;
+        let mut player_states = self.player_states.write().await;
+
+        if let Some(recorder) = self.recorder.read().await.as_ref() {
+            let targets: Vec<PlayerId> = player_states.keys().copied().collect();
+            if let Err(e) = recorder.record(&targets, &update).await {
+                warn!("Failed to capture broadcast update: {}", e);
+            }
+        }
+
+        let mut queued = 0;
+        for (player_id, state) in player_states.iter_mut() {
+            match state.queue_update(update.clone()) {
+                Ok(()) => queued += 1,
+                Err(e) => warn!("Failed to queue broadcast for player {}: {}", player_id, e),
+            }
+        }
+        drop(player_states);
+
+        self.global_stats.write().await.broadcasts_queued += queued as u64;
+        debug!("📢 Queued broadcast {} for {} player(s)", update.object_type, queued);
+        queued
+    }
+
+    /// Starts recording every queued update to a capture file.
+    ///
+    /// Updates are captured before link-quality throttling, so a replay
+    /// reproduces the same input the engine saw. Any capture already in
+    /// progress is flushed and replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File to write the capture to
+    pub async fn start_capture(&self, path: impl AsRef<Path>) -> Result<(), NetworkError> {
+        let path = path.as_ref();
+        let recorder = ReplicationRecorder::create(path).await?;
+        let previous = self.recorder.write().await.replace(recorder);
+        if let Some(previous) = previous {
+            previous.flush().await?;
+        }
+        info!("🎥 Started replication capture to {}", path.display());
+        Ok(())
+    }
+
+    /// Stops the active capture and flushes it to disk
+    ///
+    /// # Returns
+    ///
+    /// The number of updates recorded, or 0 if no capture was running
+    pub async fn stop_capture(&self) -> Result<u64, NetworkError> {
+        let Some(recorder) = self.recorder.write().await.take() else {
+            return Ok(0);
+        };
+        recorder.flush().await?;
+        info!("🎥 Stopped replication capture after {} updates", recorder.recorded());
+        Ok(recorder.recorded())
+    }
+
+    /// Checks whether a replication capture is running
+    pub async fn is_capturing(&self) -> bool {
+        self.recorder.read().await.is_some()
+    }
+
+    /// Drops per-player send timing for an object that is no longer replicated
+    pub async fn forget_object(&self, object_id: GorcObjectId) {
+        for state in self.player_states.write().await.values_mut() {
+            state.link.forget_object(object_id);
+        }
+    }
+
+    /// Records a round-trip time sample for a player's connection
+    pub async fn record_rtt(&self, player_id: PlayerId, rtt_ms: f32) {
+        if let Some(state) = self.player_states.write().await.get_mut(&player_id) {
+            state.link.record_rtt(rtt_ms);
+        }
+    }
+
+    /// Releases a player's acknowledged reliable updates
+    ///
+    /// # Returns
+    ///
+    /// The number of pending reliable updates the acknowledgement released
+    pub async fn acknowledge(&self, player_id: PlayerId, sequences: &[u32]) -> usize {
+        let released = match self.player_states.write().await.get_mut(&player_id) {
+            Some(state) => state.acknowledge(sequences),
+            None => return 0,
+        };
+        self.global_stats.write().await.reliable_acked += released as u64;
+        released
+    }
+
+    /// Gets a player's smoothed round-trip time in milliseconds, if one was recorded
+    pub async fn get_rtt(&self, player_id: PlayerId) -> Option<f32> {
+        self.player_states.read().await
+            .get(&player_id)
+            .and_then(|state| state.link.rtt_ms)
+    }
+
+    /// Gets the fraction of the target channel frequencies a player currently receives
+    pub async fn get_frequency_scale(&self, player_id: PlayerId) -> Option<f32> {
+        self.player_states.read().await
+            .get(&player_id)
+            .map(|state| state.link.frequency_scale)
     }
 
     /// Processes pending updates and sends batches
@@ -88,6 +231,10 @@ impl NetworkReplicationEngine {
             // Process updates for this player
             self.process_player_updates(state, &mut batches_to_send).await?;
         }
+        let throttled_players = player_states.values()
+            .filter(|state| state.link.frequency_scale < 1.0)
+            .count();
+        self.global_stats.write().await.throttled_players = throttled_players;
         
         // Send all batches
         drop(player_states);
@@ -108,8 +255,18 @@ impl NetworkReplicationEngine {
         let max_batch_size = config.max_batch_size;
         let max_batch_age_ms = config.max_batch_age_ms;
         let max_bandwidth_per_player = config.max_bandwidth_per_player;
+        let max_bytes_per_tick = config.max_bytes_per_tick;
+        let adaptive_frequency = config.adaptive_frequency.clone();
+        let reliable_timeout = std::time::Duration::from_millis(config.reliable_resend_ms);
+        let reliable_max_attempts = config.reliable_max_attempts;
         drop(config); // Release the lock early
         
+        // Scale channel frequencies to the player's link quality
+        if let Some(scale) = state.link.adjust(&adaptive_frequency, state.update_queue.len()) {
+            info!("📶 Player {} replication frequency scaled to {:.0}% (rtt: {:?} ms, backlog: {})",
+                  state.player_id, scale * 100.0, state.link.rtt_ms, state.link.backlog);
+        }
+        
         // Check if we should send current batch
         if state.should_send_batch(max_batch_size, max_batch_age_ms) {
             if let Some(updates) = state.finish_batch() {
@@ -120,35 +277,50 @@ impl NetworkReplicationEngine {
             }
         }
         
-        // Start new batch if needed
-        if state.current_batch.is_none() && !state.update_queue.is_empty() {
-            state.start_batch();
+        // Resend overdue reliable updates ahead of new ones
+        let resends = state.take_resends(reliable_timeout, reliable_max_attempts);
+        if !resends.updates.is_empty() || !resends.expired.is_empty() {
+            if !resends.expired.is_empty() {
+                warn!("📡 Player {} never acknowledged reliable updates {:?}", state.player_id, resends.expired);
+            }
+            let mut stats = self.global_stats.write().await;
+            stats.reliable_resends += resends.updates.len() as u64;
+            stats.reliable_expired += resends.expired.len() as u64;
         }
-        
-        // Process updates from queue
-        while !state.update_queue.is_empty() {
-            // Check bandwidth limits
-            let estimated_size = 256; // Rough estimate per update
-            if !state.has_bandwidth(estimated_size, max_bandwidth_per_player) {
-                break;
+        let mut outgoing = resends.updates;
+
+        // Take this tick's updates within the player's byte budget. When the
+        // per-second bandwidth is exhausted everything stays queued.
+        let budget = state.remaining_bandwidth(max_bandwidth_per_player).min(max_bytes_per_tick);
+        if budget > 0 {
+            let selection = state.take_within_budget(budget as usize);
+            if !selection.dropped_channels.is_empty() || selection.deferred > 0 {
+                debug!("📡 Player {} over tick budget: {} updates dropped, {} deferred",
+                       state.player_id, selection.dropped_channels.len(), selection.deferred);
+                let mut stats = self.global_stats.write().await;
+                stats.updates_dropped += selection.dropped_channels.len() as u64;
+                stats.updates_deferred += selection.deferred as u64;
+                for channel in &selection.dropped_channels {
+                    *stats.dropped_by_channel.entry(*channel).or_insert(0) += 1;
+                }
             }
+            for mut update in selection.updates {
+                state.sequence_update(&mut update);
+                outgoing.push(update);
+            }
+        }
+
+        for update in outgoing {
+            if state.current_batch.is_none() {
+                state.start_batch();
+            }
+            state.add_to_batch(update);
             
-            if let Some(update) = state.update_queue.pop() {
-                if !state.add_to_batch(update) {
-                    // Batch is full or doesn't exist, start a new one
-                    if let Some(updates) = state.finish_batch() {
-                        if !updates.is_empty() {
-                            let batch = self.create_batch(state.player_id, updates)?;
-                            batches_to_send.push(batch);
-                        }
-                    }
-                    
-                    state.start_batch();
-                    // Try to add the update to the new batch
-                    if let Some(update) = state.update_queue.pop() {
-                        state.add_to_batch(update);
-                    }
-                    break;
+            // Send full batches straight away
+            if state.current_batch.as_ref().is_some_and(|batch| batch.len() >= max_batch_size) {
+                if let Some(updates) = state.finish_batch() {
+                    let batch = self.create_batch(state.player_id, updates)?;
+                    batches_to_send.push(batch);
                 }
             }
         }
@@ -186,19 +358,27 @@ impl NetworkReplicationEngine {
     }
 
     /// Sends a batch to the target player
-    async fn send_batch(&self, batch: ReplicationBatch) -> Result<(), NetworkError> {
+    async fn send_batch(&self, mut batch: ReplicationBatch) -> Result<(), NetworkError> {
+        // Stamp the send time so clients can interpolate between snapshots
+        let sent_at = crate::utils::current_timestamp_millis();
+        for update in &mut batch.updates {
+            update.sent_at = sent_at;
+        }
+
         // Serialize the batch
         let data = serde_json::to_vec(&batch)
             .map_err(|e| NetworkError::SerializationError(e.to_string()))?;
+        let raw_size = data.len();
 
         let config = self.config.read().await;
         let compression_enabled = config.compression_enabled;
         let compression_threshold = config.compression_threshold;
+        let compression_type = config.compression;
         drop(config); // Release the lock early
 
         // Apply compression if enabled and worthwhile
         let final_data = if compression_enabled && data.len() > compression_threshold {
-            self.compress_data(&data)?
+            self.compress_data(compression_type, data)?
         } else {
             data
         };
@@ -209,81 +389,59 @@ impl NetworkReplicationEngine {
         }
 
         // Update statistics
-        self.update_stats(&batch, final_data.len()).await;
+        self.update_stats(&batch, raw_size, final_data.len()).await;
+        self.record_object_replication(&batch, final_data.len()).await;
 
         Ok(())
     }
 
-    /// Compresses data using deflate compression algorithm
-    fn compress_data(&self, data: &[u8]) -> Result<Vec<u8>, NetworkError> {
-        // We need to get the compression threshold from config
-        // This will be used in a sync context within an async method
-        let config = match self.config.try_read() {
-            Ok(cfg) => cfg.compression_threshold,
-            Err(_) => {
-                // Log a warning about lock contention and fallback
-                warn!("Failed to acquire read lock on config; falling back to default compression threshold.");
-                // Fallback to a reasonable default if we can't read the config
-                64
-            }
-        };
-        
-        if data.len() < config {
-            // For small data, compression overhead isn't worth it
-            return Ok(data.to_vec());
-        }
-        
-        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::fast());
-        encoder.write_all(data)
-            .map_err(|e| NetworkError::SerializationError(format!("Compression failed: {}", e)))?;
-        
-        let compressed = encoder.finish()
-            .map_err(|e| NetworkError::SerializationError(format!("Compression finalization failed: {}", e)))?;
-        
-        // Only use compressed data if it's actually smaller
-        if compressed.len() < data.len() {
-            Ok(compressed)
-        } else {
-            Ok(data.to_vec())
+    /// Credits each object in a delivered batch with its share of the bytes sent.
+    ///
+    /// The batch is compressed as a whole, so bytes are split in proportion
+    /// to each update's payload size.
+    async fn record_object_replication(&self, batch: &ReplicationBatch, bytes_sent: usize) {
+        let payload_total: usize = batch.updates.iter().map(|update| update.data.len()).sum();
+        let update_count = batch.updates.len().max(1);
+
+        for update in &batch.updates {
+            let share = if payload_total > 0 {
+                bytes_sent as u64 * update.data.len() as u64 / payload_total as u64
+            } else {
+                (bytes_sent / update_count) as u64
+            };
+            self.instance_manager.record_replication(update.object_id, 1, share).await;
         }
     }
 
-    /// Decompresses data using deflate decompression algorithm.
-    /// 
-    /// This method complements the `compress_data` method and is intended for scenarios
-    /// where decompression of previously compressed data is required. While it is not
-    /// currently used in the codebase, it may be useful for debugging, data integrity
-    /// checks, or future features that involve receiving compressed data from external
-    /// sources.
-    /// 
-    /// The `#[allow(dead_code)]` attribute is used to suppress warnings about unused code,
-    /// as this method is retained for potential future use.
-    #[allow(dead_code)]
-    fn decompress_data(&self, compressed_data: &[u8]) -> Result<Vec<u8>, NetworkError> {
-        let mut decoder = DeflateDecoder::new(compressed_data);
-        let mut decompressed = Vec::new();
-        
-        decoder.read_to_end(&mut decompressed)
-            .map_err(|e| NetworkError::SerializationError(format!("Decompression failed: {}", e)))?;
-        
-        Ok(decompressed)
+    /// Compresses serialized batch data with the configured algorithm.
+    ///
+    /// The original data is returned when the algorithm is not a byte-level
+    /// codec or compression would not make the payload smaller.
+    fn compress_data(&self, compression_type: crate::gorc::channels::CompressionType, data: Vec<u8>) -> Result<Vec<u8>, NetworkError> {
+        match compression::compress(compression_type, &data)? {
+            Some(compressed) if compressed.len() < data.len() => Ok(compressed),
+            _ => Ok(data),
+        }
     }
 
     /// Updates global statistics
-    async fn update_stats(&self, batch: &ReplicationBatch, bytes_sent: usize) {
+    async fn update_stats(&self, batch: &ReplicationBatch, raw_size: usize, bytes_sent: usize) {
         let mut stats = self.global_stats.write().await;
         stats.batches_sent += 1;
         stats.updates_sent += batch.updates.len() as u64;
         stats.bytes_transmitted += bytes_sent as u64;
+        stats.raw_bytes += raw_size as u64;
+        if bytes_sent < raw_size {
+            stats.batches_compressed += 1;
+        }
         
         // Update average batch size
         let total_batches = stats.batches_sent as f32;
         stats.avg_batch_size = ((stats.avg_batch_size * (total_batches - 1.0)) + batch.updates.len() as f32) / total_batches;
         
-        // Update compression ratio (simplified)
-        if bytes_sent > 0 {
-            let original_size = batch.updates.iter().map(|u| u.data.len()).sum::<usize>();
-            let compression_ratio = bytes_sent as f32 / original_size as f32;
+        // Update compression ratio (transmitted bytes over serialized bytes)
+        if raw_size > 0 {
+            let compression_ratio = bytes_sent as f32 / raw_size as f32;
             stats.avg_compression_ratio = ((stats.avg_compression_ratio * (total_batches - 1.0)) + compression_ratio) / total_batches;
         }
     }
@@ -3,6 +3,9 @@
 //! This module handles the actual network transmission of replication data,
 //! including batching, compression, prioritization, and delivery guarantees.
 
+pub mod adaptive;
+pub mod capture;
+pub mod compression;
 mod coordinator;
 mod engine;
 mod queue;
@@ -10,8 +13,13 @@ mod types;
 
 // Re-export public types and functions
 pub use coordinator::{ReplicationCoordinator, UpdateScheduler, SchedulerStats};
+pub use adaptive::{AdaptiveFrequencyConfig, LinkQuality};
+pub use capture::{
+    CapturedUpdate, ReplayOptions, ReplayReport, ReplayServerContext, ReplicationRecorder,
+    ReplicationReplay
+};
 pub use engine::NetworkReplicationEngine;
-pub use queue::{PriorityUpdateQueue, PlayerNetworkState, PlayerStats};
+pub use queue::{PriorityUpdateQueue, PlayerNetworkState, PlayerStats, TickSelection};
 pub use types::{
     NetworkConfig, NetworkError, NetworkStats, ReplicationBatch, 
     ReplicationStats, ReplicationUpdate
@@ -1,8 +1,11 @@
 /// Priority queue management for network replication
 use super::types::{ReplicationUpdate, NetworkError};
+use super::adaptive::LinkQuality;
 use crate::gorc::channels::ReplicationPriority;
+use crate::gorc::system::BROADCAST_CHANNEL;
 use crate::types::PlayerId;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::time::Duration;
 use tokio::time::Instant;
 
 /// Priority-based update queue that ensures high-priority updates are sent first
@@ -53,6 +56,17 @@ impl PriorityUpdateQueue {
         }
     }
 
+    /// Returns an update to the front of its priority queue
+    ///
+    /// Used for updates deferred to a later tick so they keep their place
+    /// ahead of newer updates of the same priority.
+    pub fn push_front(&mut self, update: ReplicationUpdate) {
+        if let Some(queue) = self.queues.get_mut(&update.priority) {
+            queue.push_front(update);
+            self.total_updates += 1;
+        }
+    }
+
     /// Pops the highest priority update
     pub fn pop(&mut self) -> Option<ReplicationUpdate> {
         // Check priorities in order: Critical -> High -> Normal -> Low
@@ -120,6 +134,42 @@ impl PriorityUpdateQueue {
     }
 }
 
+/// Lowest channel whose updates are dropped, rather than deferred, when a
+/// player's tick budget is exhausted (channels 2 and 3 are cosmetic and metadata).
+/// The broadcast channel is never dropped.
+pub const DROPPABLE_CHANNEL_START: u8 = 2;
+
+/// Updates selected for a single tick under a player's byte budget
+#[derive(Debug, Default)]
+pub struct TickSelection {
+    /// Updates that fit within the budget, in priority order
+    pub updates: Vec<ReplicationUpdate>,
+    /// Channels of updates dropped because the budget was exhausted
+    pub dropped_channels: Vec<u8>,
+    /// Number of updates returned to the queue for the next tick
+    pub deferred: usize,
+}
+
+/// A reliable update awaiting acknowledgement from the client
+#[derive(Debug, Clone)]
+pub struct PendingReliable {
+    /// The update as last sent
+    pub update: ReplicationUpdate,
+    /// When the update was last sent
+    pub last_sent: Instant,
+    /// Number of times the update has been sent
+    pub attempts: u32,
+}
+
+/// Reliable updates due for another send
+#[derive(Debug, Default)]
+pub struct ResendSelection {
+    /// Updates to send again, in sequence order
+    pub updates: Vec<ReplicationUpdate>,
+    /// Sequence numbers abandoned after exhausting their attempts
+    pub expired: Vec<u32>,
+}
+
 /// Per-player network state
 #[derive(Debug)]
 pub struct PlayerNetworkState {
@@ -139,6 +189,10 @@ pub struct PlayerNetworkState {
     pub sequence_counter: u32,
     /// Network statistics for this player
     pub stats: PlayerStats,
+    /// Link quality and adaptive frequency state
+    pub link: LinkQuality,
+    /// Reliable updates sent but not yet acknowledged, by sequence number
+    pub unacked: BTreeMap<u32, PendingReliable>,
 }
 
 /// Per-player network statistics
@@ -163,11 +217,18 @@ impl PlayerNetworkState {
             batch_start_time: None,
             sequence_counter: 0,
             stats: PlayerStats::default(),
+            link: LinkQuality::default(),
+            unacked: BTreeMap::new(),
         }
     }
 
     /// Checks if the player has bandwidth available
     pub fn has_bandwidth(&mut self, bytes_needed: u32, max_bandwidth: u32) -> bool {
+        bytes_needed <= self.remaining_bandwidth(max_bandwidth)
+    }
+
+    /// Returns the bytes this player may still receive in the current second
+    pub fn remaining_bandwidth(&mut self, max_bandwidth: u32) -> u32 {
         let now = Instant::now();
         
         // Reset bandwidth counter every second
@@ -176,7 +237,48 @@ impl PlayerNetworkState {
             self.last_bandwidth_reset = now;
         }
         
-        self.bytes_sent_this_second + bytes_needed <= max_bandwidth
+        max_bandwidth.saturating_sub(self.bytes_sent_this_second)
+    }
+
+    /// Takes the queued updates that fit within a byte budget for this tick.
+    ///
+    /// Updates are taken in priority order. Once the budget is exhausted,
+    /// updates on channels 2 and 3 are dropped while updates on channels 0
+    /// and 1 and the broadcast channel are deferred to the next tick. The first update of a tick is always
+    /// taken so oversized critical updates cannot starve.
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - Maximum payload bytes to take this tick
+    ///
+    /// # Returns
+    ///
+    /// The selected updates along with drop and deferral counts.
+    pub fn take_within_budget(&mut self, budget: usize) -> TickSelection {
+        let mut selection = TickSelection::default();
+        let mut deferred = Vec::new();
+        let mut used = 0usize;
+
+        while let Some(update) = self.update_queue.pop() {
+            let size = update.data.len();
+            if selection.updates.is_empty() || used + size <= budget {
+                used += size;
+                self.consume_bandwidth(size as u32);
+                selection.updates.push(update);
+            } else if update.channel >= DROPPABLE_CHANNEL_START && update.channel != BROADCAST_CHANNEL {
+                self.stats.updates_dropped += 1;
+                selection.dropped_channels.push(update.channel);
+            } else {
+                deferred.push(update);
+            }
+        }
+
+        selection.deferred = deferred.len();
+        for update in deferred.into_iter().rev() {
+            self.update_queue.push_front(update);
+        }
+        self.stats.updates_sent += selection.updates.len() as u64;
+        selection
     }
 
     /// Records bandwidth usage
@@ -191,6 +293,75 @@ impl PlayerNetworkState {
         self.sequence_counter
     }
 
+    /// Stamps an outgoing update with the player's next sequence number.
+    ///
+    /// Reliable updates are also tracked until the client acknowledges
+    /// the sequence number.
+    pub fn sequence_update(&mut self, update: &mut ReplicationUpdate) {
+        update.sequence = self.next_sequence();
+        if update.reliable {
+            self.unacked.insert(update.sequence, PendingReliable {
+                update: update.clone(),
+                last_sent: Instant::now(),
+                attempts: 1,
+            });
+        }
+    }
+
+    /// Releases acknowledged reliable updates
+    ///
+    /// # Returns
+    ///
+    /// The number of pending updates the acknowledgement released
+    pub fn acknowledge(&mut self, sequences: &[u32]) -> usize {
+        sequences
+            .iter()
+            .filter(|sequence| self.unacked.remove(sequence).is_some())
+            .count()
+    }
+
+    /// Takes the reliable updates whose acknowledgement is overdue.
+    ///
+    /// Updates that have already been sent `max_attempts` times are
+    /// abandoned instead of being resent.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - How long to wait for an acknowledgement before resending
+    /// * `max_attempts` - Sends of an update, including the first, before it is abandoned
+    ///
+    /// # Returns
+    ///
+    /// The updates to resend along with the abandoned sequence numbers.
+    pub fn take_resends(&mut self, timeout: Duration, max_attempts: u32) -> ResendSelection {
+        let now = Instant::now();
+        let mut selection = ResendSelection::default();
+
+        self.unacked.retain(|sequence, pending| {
+            if now.duration_since(pending.last_sent) < timeout {
+                return true;
+            }
+            if pending.attempts >= max_attempts {
+                selection.expired.push(*sequence);
+                return false;
+            }
+            pending.attempts += 1;
+            pending.last_sent = now;
+            selection.updates.push(pending.update.clone());
+            true
+        });
+
+        for update in &selection.updates {
+            self.consume_bandwidth(update.data.len() as u32);
+        }
+        selection
+    }
+
+    /// Gets the number of reliable updates awaiting acknowledgement
+    pub fn unacked_count(&self) -> usize {
+        self.unacked.len()
+    }
+
     /// Adds an update to this player's queue
     pub fn queue_update(&mut self, update: ReplicationUpdate) -> Result<(), NetworkError> {
         if !self.update_queue.push(update) {
@@ -241,4 +412,123 @@ impl PlayerNetworkState {
         
         false
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gorc::channels::CompressionType;
+    use crate::gorc::instance::GorcObjectId;
+
+    fn update(channel: u8, priority: ReplicationPriority, size: usize) -> ReplicationUpdate {
+        ReplicationUpdate {
+            object_id: GorcObjectId::new(),
+            object_type: "Asteroid".to_string(),
+            channel,
+            data: vec![0; size],
+            priority,
+            sequence: 0,
+            timestamp: 0,
+            compression: CompressionType::None,
+            reliable: false,
+            tick: 0,
+            sent_at: 0,
+        }
+    }
+
+    fn queue_sizes() -> HashMap<ReplicationPriority, usize> {
+        [ReplicationPriority::Critical, ReplicationPriority::High, ReplicationPriority::Normal, ReplicationPriority::Low]
+            .into_iter()
+            .map(|priority| (priority, 100))
+            .collect()
+    }
+
+    #[test]
+    fn test_budget_sheds_low_priority_channels_first() {
+        let mut state = PlayerNetworkState::new(PlayerId::new(), queue_sizes());
+        state.queue_update(update(3, ReplicationPriority::Low, 100)).unwrap();
+        state.queue_update(update(2, ReplicationPriority::Normal, 100)).unwrap();
+        state.queue_update(update(1, ReplicationPriority::High, 100)).unwrap();
+        state.queue_update(update(0, ReplicationPriority::Critical, 100)).unwrap();
+        state.queue_update(update(0, ReplicationPriority::Critical, 100)).unwrap();
+
+        let selection = state.take_within_budget(150);
+        assert_eq!(selection.updates.len(), 1);
+        assert_eq!(selection.updates[0].channel, 0);
+        assert_eq!(selection.deferred, 2);
+        assert_eq!(selection.dropped_channels, vec![2, 3]);
+        assert_eq!(state.stats.updates_dropped, 2);
+
+        // Deferred critical data goes out first on the next tick
+        let selection = state.take_within_budget(1000);
+        let channels: Vec<u8> = selection.updates.iter().map(|u| u.channel).collect();
+        assert_eq!(channels, vec![0, 1]);
+        assert!(state.update_queue.is_empty());
+    }
+
+    #[test]
+    fn test_broadcasts_are_deferred_not_dropped() {
+        let mut state = PlayerNetworkState::new(PlayerId::new(), queue_sizes());
+        state.queue_update(update(0, ReplicationPriority::Critical, 100)).unwrap();
+        state.queue_update(update(BROADCAST_CHANNEL, ReplicationPriority::Low, 100)).unwrap();
+
+        let selection = state.take_within_budget(100);
+        assert_eq!(selection.updates.len(), 1);
+        assert_eq!(selection.deferred, 1);
+        assert!(selection.dropped_channels.is_empty());
+
+        let selection = state.take_within_budget(100);
+        assert_eq!(selection.updates[0].channel, BROADCAST_CHANNEL);
+    }
+
+    #[test]
+    fn test_oversized_update_is_not_starved() {
+        let mut state = PlayerNetworkState::new(PlayerId::new(), queue_sizes());
+        state.queue_update(update(0, ReplicationPriority::Critical, 500)).unwrap();
+
+        let selection = state.take_within_budget(100);
+        assert_eq!(selection.updates.len(), 1);
+        assert_eq!(state.bytes_sent_this_second, 500);
+    }
+
+    #[test]
+    fn test_reliable_updates_are_tracked_until_acknowledged() {
+        let mut state = PlayerNetworkState::new(PlayerId::new(), queue_sizes());
+        let mut unreliable = update(1, ReplicationPriority::High, 10);
+        let mut reliable = update(0, ReplicationPriority::Critical, 10);
+        reliable.reliable = true;
+
+        state.sequence_update(&mut unreliable);
+        state.sequence_update(&mut reliable);
+        assert_eq!((unreliable.sequence, reliable.sequence), (1, 2));
+        assert_eq!(state.unacked_count(), 1);
+
+        // Unknown and unreliable sequences release nothing
+        assert_eq!(state.acknowledge(&[1, 99]), 0);
+        assert_eq!(state.acknowledge(&[2]), 1);
+        assert_eq!(state.unacked_count(), 0);
+    }
+
+    #[test]
+    fn test_unacknowledged_updates_are_resent_then_expire() {
+        let mut state = PlayerNetworkState::new(PlayerId::new(), queue_sizes());
+        let mut reliable = update(0, ReplicationPriority::Critical, 10);
+        reliable.reliable = true;
+        state.sequence_update(&mut reliable);
+
+        // Nothing is due before the timeout
+        let selection = state.take_resends(Duration::from_secs(60), 3);
+        assert!(selection.updates.is_empty() && selection.expired.is_empty());
+
+        for _ in 0..2 {
+            let selection = state.take_resends(Duration::ZERO, 3);
+            assert_eq!(selection.updates.len(), 1);
+            assert_eq!(selection.updates[0].sequence, reliable.sequence);
+        }
+        assert_eq!(state.bytes_sent_this_second, 20);
+
+        let selection = state.take_resends(Duration::ZERO, 3);
+        assert!(selection.updates.is_empty());
+        assert_eq!(selection.expired, vec![reliable.sequence]);
+        assert_eq!(state.unacked_count(), 0);
+    }
+}
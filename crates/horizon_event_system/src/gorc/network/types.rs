@@ -2,6 +2,8 @@
 use crate::types::PlayerId;
 use crate::gorc::instance::GorcObjectId;
 use crate::gorc::channels::{ReplicationPriority, CompressionType};
+use crate::gorc::config::NetworkConfig as GorcNetworkConfig;
+use super::adaptive::AdaptiveFrequencyConfig;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -24,6 +26,15 @@ pub struct ReplicationUpdate {
     pub timestamp: u64,
     /// Compression used for the data
     pub compression: CompressionType,
+    /// Whether the client must acknowledge this update's sequence number
+    #[serde(default)]
+    pub reliable: bool,
+    /// Replication tick the update was generated on
+    #[serde(default)]
+    pub tick: u64,
+    /// Send time in milliseconds since the Unix epoch, stamped on each transmission
+    #[serde(default)]
+    pub sent_at: u64,
 }
 
 /// Batch of replication updates for efficient transmission
@@ -52,8 +63,20 @@ pub struct NetworkStats {
     pub batches_sent: u64,
     /// Total bytes transmitted
     pub bytes_transmitted: u64,
+    /// Total serialized bytes before compression
+    pub raw_bytes: u64,
+    /// Batches that were sent compressed
+    pub batches_compressed: u64,
     /// Updates dropped due to bandwidth limits
     pub updates_dropped: u64,
+    /// Updates dropped per channel when a player's tick budget was exhausted
+    pub dropped_by_channel: HashMap<u8, u64>,
+    /// Updates deferred to a later tick when a player's tick budget was exhausted
+    pub updates_deferred: u64,
+    /// Updates skipped because a player's scaled channel frequency was not yet due
+    pub updates_throttled: u64,
+    /// Players currently replicated below their target frequencies
+    pub throttled_players: usize,
     /// Average batch size
     pub avg_batch_size: f32,
     /// Average compression ratio
@@ -62,6 +85,14 @@ pub struct NetworkStats {
     pub network_utilization: f32,
     /// Number of configuration updates applied
     pub config_updates: u64,
+    /// Reliable updates resent because no acknowledgement arrived in time
+    pub reliable_resends: u64,
+    /// Reliable updates acknowledged by clients
+    pub reliable_acked: u64,
+    /// Reliable updates abandoned after exhausting their resend attempts
+    pub reliable_expired: u64,
+    /// Broadcast updates queued, counted once per receiving player
+    pub broadcasts_queued: u64,
 }
 
 /// Configuration for the network replication engine
@@ -69,6 +100,8 @@ pub struct NetworkStats {
 pub struct NetworkConfig {
     /// Maximum bandwidth per player (bytes per second)
     pub max_bandwidth_per_player: u32,
+    /// Maximum update bytes sent to each player per processing tick
+    pub max_bytes_per_tick: u32,
     /// Maximum batch size in updates
     pub max_batch_size: usize,
     /// Maximum batch age before forced transmission
@@ -79,8 +112,16 @@ pub struct NetworkConfig {
     pub compression_enabled: bool,
     /// Minimum compression threshold (don't compress smaller payloads)
     pub compression_threshold: usize,
+    /// Compression algorithm applied to batches above the threshold
+    pub compression: CompressionType,
     /// Priority queue sizes
     pub priority_queue_sizes: HashMap<ReplicationPriority, usize>,
+    /// Link-quality based scaling of the target frequencies
+    pub adaptive_frequency: AdaptiveFrequencyConfig,
+    /// Milliseconds to wait for an acknowledgement before resending a reliable update
+    pub reliable_resend_ms: u64,
+    /// Sends of a reliable update, including the first, before it is abandoned
+    pub reliable_max_attempts: u32,
 }
 
 impl Default for NetworkConfig {
@@ -99,12 +140,36 @@ impl Default for NetworkConfig {
 
         Self {
             max_bandwidth_per_player: 1024 * 1024, // 1MB/s default
+            max_bytes_per_tick: 16 * 1024, // ~1MB/s at 60 ticks per second
             max_batch_size: 50,
             max_batch_age_ms: 16, // ~60 FPS
             target_frequencies,
             compression_enabled: true,
             compression_threshold: 128, // Don't compress < 128 bytes
+            compression: CompressionType::Lz4,
             priority_queue_sizes,
+            adaptive_frequency: AdaptiveFrequencyConfig::default(),
+            reliable_resend_ms: 250,
+            reliable_max_attempts: 5,
+        }
+    }
+}
+
+impl From<&GorcNetworkConfig> for NetworkConfig {
+    /// Builds the engine configuration from the server's GORC network settings
+    fn from(settings: &GorcNetworkConfig) -> Self {
+        let target_frequencies = settings.channel_frequencies
+            .iter()
+            .enumerate()
+            .map(|(channel, frequency)| (channel as u8, *frequency as f32))
+            .collect();
+
+        Self {
+            max_batch_size: settings.max_batch_size,
+            target_frequencies,
+            compression_enabled: settings.enable_compression,
+            compression_threshold: settings.compression_threshold,
+            ..Self::default()
         }
     }
 }
@@ -129,6 +194,9 @@ pub enum NetworkError {
 
     #[error("Invalid configuration: {0}")]
     InvalidConfiguration(String),
+
+    #[error("Replication capture failed: {0}")]
+    CaptureError(String),
 }
 
 /// Replication statistics for monitoring
@@ -0,0 +1,94 @@
+/// World snapshots for persisting GORC objects across server restarts
+use super::instance::{GorcObject, GorcObjectId};
+use crate::types::Vec3;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+/// Current snapshot file format version
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Errors raised while saving or loading world snapshots
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    /// Reading or writing the snapshot file failed
+    #[error("Snapshot I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The snapshot could not be encoded or decoded
+    #[error("Snapshot serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    /// The snapshot was written by an incompatible version
+    #[error("Unsupported snapshot version {0}")]
+    UnsupportedVersion(u32),
+}
+
+/// Rebuilds an object from the state captured by [`GorcObject::snapshot_state`]
+pub type SnapshotRestorer = fn(serde_json::Value) -> Result<Box<dyn GorcObject>, serde_json::Error>;
+
+/// Saved state of a single object
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectSnapshot {
+    /// Object ID, preserved so references to the object survive a restart
+    pub object_id: GorcObjectId,
+    /// Type name used to find the object's restorer
+    pub type_name: String,
+    /// Tracked position of the object
+    pub position: Vec3,
+    /// State returned by the object's snapshot hook
+    pub state: serde_json::Value,
+}
+
+/// Saved state of every snapshot-capable object in the world
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldSnapshot {
+    /// Snapshot file format version
+    pub version: u32,
+    /// Unix timestamp when the snapshot was taken
+    pub timestamp: u64,
+    /// Captured objects
+    pub objects: Vec<ObjectSnapshot>,
+}
+
+impl WorldSnapshot {
+    /// Creates a snapshot of the given objects at the current time
+    pub fn new(objects: Vec<ObjectSnapshot>) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            timestamp: crate::utils::current_timestamp(),
+            objects,
+        }
+    }
+
+    /// Writes the snapshot to disk.
+    ///
+    /// The file is written next to its destination and renamed into place,
+    /// so a crash mid-save never leaves a truncated snapshot behind.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, serde_json::to_vec(self)?)?;
+        std::fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    /// Reads a snapshot from disk
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        let snapshot: Self = serde_json::from_slice(&std::fs::read(path)?)?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(snapshot.version));
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Restorer for objects that deserialize from their own snapshot state
+pub(crate) fn restore_object<T>(state: serde_json::Value) -> Result<Box<dyn GorcObject>, serde_json::Error>
+where
+    T: GorcObject + DeserializeOwned + 'static,
+{
+    Ok(Box::new(serde_json::from_value::<T>(state)?))
+}
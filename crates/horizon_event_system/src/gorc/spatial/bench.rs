@@ -0,0 +1,179 @@
+/// Spatial index micro-benchmark
+///
+/// Builds every [`SpatialIndexKind`] from the same set of positions and
+/// times radius queries against each, so operators can pick the index that
+/// suits their world's object density. Positions can come from a live
+/// instance manager, a world snapshot, or a uniform random fill.
+use super::index::{SpatialIndexConfig, SpatialIndexKind};
+use crate::gorc::instance::GorcInstanceManager;
+use crate::gorc::snapshot::WorldSnapshot;
+use crate::types::{PlayerId, Position, Vec3};
+use std::time::{Duration, Instant};
+
+/// Timings for one index implementation
+#[derive(Debug, Clone)]
+pub struct SpatialBenchResult {
+    /// Index implementation measured
+    pub kind: SpatialIndexKind,
+    /// Number of indexed positions
+    pub objects: usize,
+    /// Time taken to insert every position
+    pub build_time: Duration,
+    /// Mean radius query latency in microseconds
+    pub avg_query_us: f64,
+    /// 99th percentile radius query latency in microseconds
+    pub p99_query_us: f64,
+    /// Mean number of results per query
+    pub avg_hits: f64,
+}
+
+/// Compares spatial index implementations on a fixed set of positions
+#[derive(Debug, Clone)]
+pub struct SpatialBenchmark {
+    /// Positions to index
+    positions: Vec<Vec3>,
+    /// Region bounds handed to the indexes
+    bounds: (Vec3, Vec3),
+    /// Radius of each query
+    query_radius: f64,
+    /// Number of queries per implementation
+    queries: usize,
+    /// Tuning shared by the implementations
+    index: SpatialIndexConfig,
+}
+
+impl SpatialBenchmark {
+    /// Creates a benchmark over the given positions
+    ///
+    /// # Arguments
+    ///
+    /// * `positions` - Positions to index, typically the server's current objects
+    /// * `min` - Minimum corner of the region
+    /// * `max` - Maximum corner of the region
+    pub fn new(positions: Vec<Vec3>, min: Vec3, max: Vec3) -> Self {
+        Self {
+            positions,
+            bounds: (min, max),
+            query_radius: 100.0,
+            queries: 1_000,
+            index: SpatialIndexConfig::default(),
+        }
+    }
+
+    /// Creates a benchmark over `count` positions spread uniformly in the bounds
+    ///
+    /// The fill is deterministic for a given `seed` so runs are comparable.
+    pub fn uniform(count: usize, min: Vec3, max: Vec3, seed: u64) -> Self {
+        let mut state = seed;
+        let mut next = move |low: f64, high: f64| {
+            // splitmix64
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^= z >> 31;
+            low + (high - low) * ((z >> 11) as f64 / (1u64 << 53) as f64)
+        };
+
+        let positions = (0..count)
+            .map(|_| Vec3::new(next(min.x, max.x), next(min.y, max.y), next(min.z, max.z)))
+            .collect();
+        Self::new(positions, min, max)
+    }
+
+    /// Creates a benchmark over the objects saved in a world snapshot
+    pub fn from_snapshot(snapshot: &WorldSnapshot, min: Vec3, max: Vec3) -> Self {
+        Self::new(snapshot.objects.iter().map(|object| object.position).collect(), min, max)
+    }
+
+    /// Creates a benchmark over the players and objects tracked by an instance manager
+    pub async fn from_instance_manager(instances: &GorcInstanceManager, min: Vec3, max: Vec3) -> Self {
+        let mut positions: Vec<Vec3> = instances
+            .get_object_positions()
+            .await
+            .into_iter()
+            .map(|(_, position)| position)
+            .collect();
+        positions.extend(instances.get_player_positions().await.into_iter().map(|(_, position)| position));
+        Self::new(positions, min, max)
+    }
+
+    /// Sets the query radius
+    pub fn with_query_radius(mut self, query_radius: f64) -> Self {
+        self.query_radius = query_radius.max(0.0);
+        self
+    }
+
+    /// Sets the number of queries run against each implementation
+    pub fn with_queries(mut self, queries: usize) -> Self {
+        self.queries = queries.max(1);
+        self
+    }
+
+    /// Sets the octree and loose grid tuning
+    pub fn with_index_config(mut self, index: SpatialIndexConfig) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Number of positions being indexed
+    pub fn object_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Benchmarks every implementation
+    pub fn run(&self) -> Vec<SpatialBenchResult> {
+        SpatialIndexKind::ALL.iter().map(|&kind| self.run_kind(kind)).collect()
+    }
+
+    /// Benchmarks a single implementation
+    pub fn run_kind(&self, kind: SpatialIndexKind) -> SpatialBenchResult {
+        let (min, max) = self.bounds;
+        let mut index = SpatialIndexConfig { kind, ..self.index.clone() }.create(min, max);
+
+        let entries: Vec<(PlayerId, Position)> = self
+            .positions
+            .iter()
+            .map(|&position| (PlayerId::new(), position.into()))
+            .collect();
+
+        let started = Instant::now();
+        for &(player_id, position) in &entries {
+            index.insert_player(player_id, position);
+        }
+        let build_time = started.elapsed();
+
+        let mut latencies = Vec::with_capacity(self.queries);
+        let mut hits = 0usize;
+        for center in self.query_centers() {
+            let started = Instant::now();
+            hits += index.query_radius(center, self.query_radius).len();
+            latencies.push(started.elapsed().as_secs_f64() * 1_000_000.0);
+        }
+
+        latencies.sort_by(f64::total_cmp);
+        let p99_index = (latencies.len() * 99 / 100).min(latencies.len().saturating_sub(1));
+        SpatialBenchResult {
+            kind,
+            objects: entries.len(),
+            build_time,
+            avg_query_us: latencies.iter().sum::<f64>() / latencies.len().max(1) as f64,
+            p99_query_us: latencies.get(p99_index).copied().unwrap_or_default(),
+            avg_hits: hits as f64 / latencies.len().max(1) as f64,
+        }
+    }
+
+    /// Query centers, taken from the indexed positions so queries land where objects are
+    fn query_centers(&self) -> Vec<Position> {
+        if self.positions.is_empty() {
+            let (min, max) = self.bounds;
+            return vec![Position::new((min.x + max.x) / 2.0, (min.y + max.y) / 2.0, (min.z + max.z) / 2.0); self.queries];
+        }
+
+        // Stepping by a large prime visits the positions in a scattered order
+        let step = 7_919 % self.positions.len() + 1;
+        (0..self.queries)
+            .map(|i| self.positions[(i * step) % self.positions.len()].into())
+            .collect()
+    }
+}
@@ -0,0 +1,132 @@
+/// Loose grid spatial index for GORC
+///
+/// Players are bucketed into cubic cells keyed by their integer coordinates.
+/// Only occupied cells are stored, so the grid has no fixed bounds, and moving
+/// a player is a constant-time bucket swap. Queries visit the cells covered
+/// by the query sphere, or every occupied cell when that is fewer.
+use super::index::{match_object, SpatialIndex, SpatialIndexKind};
+use super::query::{QueryResult, SpatialQuery};
+use super::rtree::SpatialObject;
+use crate::types::{PlayerId, Position};
+use std::collections::HashMap;
+
+/// Integer coordinates of a grid cell
+type CellKey = (i64, i64, i64);
+
+/// Sparse uniform grid of players
+#[derive(Debug)]
+pub struct RegionLooseGrid {
+    /// Edge length of a cell
+    cell_size: f64,
+    /// Occupied cells
+    cells: HashMap<CellKey, Vec<SpatialObject>>,
+    /// Cell currently holding each player
+    player_cells: HashMap<PlayerId, CellKey>,
+}
+
+impl RegionLooseGrid {
+    /// Creates an empty grid
+    ///
+    /// # Arguments
+    ///
+    /// * `cell_size` - Edge length of a cell; ideally close to the typical query radius
+    pub fn new(cell_size: f64) -> Self {
+        Self {
+            cell_size: if cell_size.is_finite() && cell_size > 0.0 { cell_size } else { 100.0 },
+            cells: HashMap::new(),
+            player_cells: HashMap::new(),
+        }
+    }
+
+    fn cell_coord(&self, value: f64) -> i64 {
+        (value / self.cell_size).floor() as i64
+    }
+
+    fn cell_of(&self, position: Position) -> CellKey {
+        (self.cell_coord(position.x), self.cell_coord(position.y), self.cell_coord(position.z))
+    }
+}
+
+impl SpatialIndex for RegionLooseGrid {
+    fn kind(&self) -> SpatialIndexKind {
+        SpatialIndexKind::LooseGrid
+    }
+
+    fn insert_player(&mut self, player_id: PlayerId, position: Position) {
+        self.remove_player(player_id);
+        let cell = self.cell_of(position);
+        self.cells.entry(cell).or_default().push(SpatialObject::new(player_id, position));
+        self.player_cells.insert(player_id, cell);
+    }
+
+    fn remove_player(&mut self, player_id: PlayerId) -> usize {
+        let Some(cell) = self.player_cells.remove(&player_id) else {
+            return 0;
+        };
+        let Some(objects) = self.cells.get_mut(&cell) else {
+            return 0;
+        };
+
+        let removed = match objects.iter().position(|object| object.player_id == player_id) {
+            Some(index) => {
+                objects.swap_remove(index);
+                1
+            }
+            None => 0,
+        };
+        if objects.is_empty() {
+            self.cells.remove(&cell);
+        }
+        removed
+    }
+
+    fn query(&mut self, query: SpatialQuery) -> Vec<QueryResult> {
+        let radius = query.radius.max(0.0);
+        let (min_x, max_x) = (self.cell_coord(query.center.x - radius), self.cell_coord(query.center.x + radius));
+        let (min_y, max_y) = (self.cell_coord(query.center.y - radius), self.cell_coord(query.center.y + radius));
+        let (min_z, max_z) = (self.cell_coord(query.center.z - radius), self.cell_coord(query.center.z + radius));
+
+        let span = |min: i64, max: i64| (max.saturating_sub(min) as u128).saturating_add(1);
+        let covered_cells = span(min_x, max_x)
+            .saturating_mul(span(min_y, max_y))
+            .saturating_mul(span(min_z, max_z));
+
+        let mut results = Vec::new();
+        if covered_cells > self.cells.len() as u128 {
+            // Large queries over a sparse grid: scan the occupied cells instead
+            for (&(x, y, z), objects) in &self.cells {
+                if (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y) && (min_z..=max_z).contains(&z) {
+                    results.extend(objects.iter().filter_map(|object| match_object(object, &query)));
+                }
+            }
+        } else {
+            for x in min_x..=max_x {
+                for y in min_y..=max_y {
+                    for z in min_z..=max_z {
+                        if let Some(objects) = self.cells.get(&(x, y, z)) {
+                            results.extend(objects.iter().filter_map(|object| match_object(object, &query)));
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(max_results) = query.filters.max_results {
+            results.truncate(max_results);
+        }
+        results
+    }
+
+    fn object_count(&self) -> usize {
+        self.player_cells.len()
+    }
+
+    fn contains_player(&self, player_id: PlayerId) -> bool {
+        self.player_cells.contains_key(&player_id)
+    }
+
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.player_cells.clear();
+    }
+}
@@ -0,0 +1,147 @@
+/// Spatial queries for plugins
+///
+/// [`SpatialQueryHandle`] answers proximity questions against the positions
+/// tracked by the GORC instance manager, so gameplay plugins (area damage,
+/// proximity triggers) don't need their own position maps. Plugins get a
+/// handle from `ServerContext::spatial()`.
+///
+/// For filtered lookups (object type, channel, tags, result limits), build a
+/// [`SpatialQuery`] and run it with [`SpatialQueryHandle::query_objects`] or
+/// [`SpatialQueryHandle::query_players`].
+use super::query::{ObjectQueryResult, QueryResult, SpatialQuery};
+use crate::gorc::instance::{GorcInstanceManager, GorcObjectId};
+use crate::types::{PlayerId, Vec3};
+use std::sync::Arc;
+
+/// Something found by a spatial query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpatialEntity {
+    /// A connected player
+    Player(PlayerId),
+    /// A registered GORC object
+    Object(GorcObjectId),
+}
+
+/// Which entities a query should consider
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpatialFilter {
+    /// Players only
+    Players,
+    /// GORC objects only
+    Objects,
+    /// Players and objects
+    #[default]
+    All,
+}
+
+/// A query match with its position and distance from the query center
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialHit {
+    /// The matched entity
+    pub entity: SpatialEntity,
+    /// Tracked position of the entity
+    pub position: Vec3,
+    /// Distance from the query center
+    pub distance: f64,
+}
+
+/// Read-only spatial query handle over tracked player and object positions
+#[derive(Debug, Clone)]
+pub struct SpatialQueryHandle {
+    instances: Arc<GorcInstanceManager>,
+}
+
+impl SpatialQueryHandle {
+    /// Creates a query handle over an instance manager
+    pub fn new(instances: Arc<GorcInstanceManager>) -> Self {
+        Self { instances }
+    }
+
+    /// Finds players within `radius` of `center`, nearest first
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Query center
+    /// * `radius` - Maximum distance (inclusive)
+    pub async fn players_in_radius(&self, center: Vec3, radius: f64) -> Vec<PlayerId> {
+        let mut players: Vec<(PlayerId, f64)> = self
+            .instances
+            .get_player_positions()
+            .await
+            .into_iter()
+            .map(|(player_id, position)| (player_id, position.distance(center)))
+            .filter(|&(_, distance)| distance <= radius)
+            .collect();
+        players.sort_by(|a, b| a.1.total_cmp(&b.1));
+        players.into_iter().map(|(player_id, _)| player_id).collect()
+    }
+
+    /// Finds objects inside an axis-aligned bounding box
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - Minimum corner (inclusive)
+    /// * `max` - Maximum corner (inclusive)
+    pub async fn objects_in_aabb(&self, min: Vec3, max: Vec3) -> Vec<GorcObjectId> {
+        let inside = |position: Vec3| {
+            (min.x..=max.x).contains(&position.x)
+                && (min.y..=max.y).contains(&position.y)
+                && (min.z..=max.z).contains(&position.z)
+        };
+        self.instances
+            .get_object_positions()
+            .await
+            .into_iter()
+            .filter(|&(_, position)| inside(position))
+            .map(|(object_id, _)| object_id)
+            .collect()
+    }
+
+    /// Finds the `n` entities nearest to `center`, nearest first
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Query center
+    /// * `n` - Maximum number of results
+    /// * `filter` - Which entities to consider
+    pub async fn nearest_n(&self, center: Vec3, n: usize, filter: SpatialFilter) -> Vec<SpatialHit> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut hits = Vec::new();
+        if filter != SpatialFilter::Objects {
+            hits.extend(self.instances.get_player_positions().await.into_iter().map(|(player_id, position)| {
+                SpatialHit { entity: SpatialEntity::Player(player_id), position, distance: position.distance(center) }
+            }));
+        }
+        if filter != SpatialFilter::Players {
+            hits.extend(self.instances.get_object_positions().await.into_iter().map(|(object_id, position)| {
+                SpatialHit { entity: SpatialEntity::Object(object_id), position, distance: position.distance(center) }
+            }));
+        }
+
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        hits.truncate(n);
+        hits
+    }
+
+    /// Finds objects matching a filtered query
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Query center, radius and filters, e.g.
+    ///   `SpatialQuery::new(center, 500.0).with_object_type("Asteroid").with_max_results(10).sorted_by_distance()`
+    pub async fn query_objects(&self, query: SpatialQuery) -> Vec<ObjectQueryResult> {
+        self.instances.query_objects(query).await
+    }
+
+    /// Finds players matching a filtered query
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Query center, radius and player filters
+    pub async fn query_players(&self, query: SpatialQuery) -> Vec<QueryResult> {
+        self.instances.query_players(query).await
+    }
+}
@@ -0,0 +1,263 @@
+/// Pluggable spatial index backends
+///
+/// [`SpatialPartition`](super::SpatialPartition) stores each region in a
+/// [`SpatialIndex`]. The R*-tree is the default; the octree and loose grid
+/// trade generality for cheaper updates and can be selected through
+/// [`SpatialIndexConfig`] when they suit the world's object density better.
+use super::grid::RegionLooseGrid;
+use super::octree::RegionOctree;
+use super::query::{QueryFilters, QueryResult, SpatialQuery};
+use super::rtree::{RegionRTree, SpatialIndexStats, SpatialObject};
+use crate::types::{PlayerId, Position, Vec3};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Available spatial index implementations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum SpatialIndexKind {
+    /// R*-tree, balanced for any distribution of objects
+    #[default]
+    #[serde(rename = "rtree")]
+    RTree,
+    /// Octree that subdivides the world bounds where objects cluster
+    #[serde(rename = "octree")]
+    Octree,
+    /// Sparse uniform grid, cheapest to update for evenly spread objects
+    #[serde(rename = "loose_grid")]
+    LooseGrid,
+}
+
+impl SpatialIndexKind {
+    /// Every available implementation
+    pub const ALL: [SpatialIndexKind; 3] = [Self::RTree, Self::Octree, Self::LooseGrid];
+
+    /// Configuration name of the implementation
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::RTree => "rtree",
+            Self::Octree => "octree",
+            Self::LooseGrid => "loose_grid",
+        }
+    }
+}
+
+impl fmt::Display for SpatialIndexKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Selects and tunes the spatial index used for each region
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpatialIndexConfig {
+    /// Index implementation
+    pub kind: SpatialIndexKind,
+    /// Objects an octree leaf holds before it splits
+    pub max_objects_per_leaf: usize,
+    /// Edge length of a loose grid cell
+    pub cell_size: f64,
+    /// Insertions and removals an R*-tree absorbs before it needs a bulk rebuild
+    #[serde(default = "default_rebuild_threshold")]
+    pub rebuild_threshold: usize,
+    /// Interval between background maintenance passes in milliseconds (0 disables them)
+    #[serde(default = "default_maintenance_interval_ms")]
+    pub maintenance_interval_ms: u64,
+    /// Most mutations since the previous pass for a tick to count as low-load
+    #[serde(default = "default_low_load_mutations")]
+    pub low_load_mutations: usize,
+    /// Busy passes a due rebuild may be deferred before it runs regardless of load
+    #[serde(default = "default_max_rebuild_deferrals")]
+    pub max_rebuild_deferrals: u32,
+}
+
+fn default_rebuild_threshold() -> usize {
+    5_000
+}
+
+fn default_maintenance_interval_ms() -> u64 {
+    1_000
+}
+
+fn default_low_load_mutations() -> usize {
+    256
+}
+
+fn default_max_rebuild_deferrals() -> u32 {
+    30
+}
+
+impl Default for SpatialIndexConfig {
+    fn default() -> Self {
+        Self {
+            kind: SpatialIndexKind::RTree,
+            max_objects_per_leaf: 64,
+            cell_size: 100.0,
+            rebuild_threshold: default_rebuild_threshold(),
+            maintenance_interval_ms: default_maintenance_interval_ms(),
+            low_load_mutations: default_low_load_mutations(),
+            max_rebuild_deferrals: default_max_rebuild_deferrals(),
+        }
+    }
+}
+
+impl SpatialIndexConfig {
+    /// Creates a configuration for the given implementation with default tuning
+    pub fn new(kind: SpatialIndexKind) -> Self {
+        Self { kind, ..Self::default() }
+    }
+
+    /// Sets the octree leaf capacity
+    pub fn with_max_objects_per_leaf(mut self, max_objects_per_leaf: usize) -> Self {
+        self.max_objects_per_leaf = max_objects_per_leaf.max(1);
+        self
+    }
+
+    /// Sets the loose grid cell size
+    pub fn with_cell_size(mut self, cell_size: f64) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the mutations an R*-tree absorbs before it needs a bulk rebuild
+    pub fn with_rebuild_threshold(mut self, rebuild_threshold: usize) -> Self {
+        self.rebuild_threshold = rebuild_threshold.max(1);
+        self
+    }
+
+    /// Sets the interval between background maintenance passes (0 disables them)
+    pub fn with_maintenance_interval_ms(mut self, maintenance_interval_ms: u64) -> Self {
+        self.maintenance_interval_ms = maintenance_interval_ms;
+        self
+    }
+
+    /// Sets the most mutations per pass for a tick to count as low-load
+    pub fn with_low_load_mutations(mut self, low_load_mutations: usize) -> Self {
+        self.low_load_mutations = low_load_mutations;
+        self
+    }
+
+    /// Builds an empty index covering the given bounds
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - Minimum corner of the region
+    /// * `max` - Maximum corner of the region
+    pub fn create(&self, min: Vec3, max: Vec3) -> Box<dyn SpatialIndex> {
+        match self.kind {
+            SpatialIndexKind::RTree => Box::new(RegionRTree::new(min, max).with_rebuild_threshold(self.rebuild_threshold)),
+            SpatialIndexKind::Octree => Box::new(RegionOctree::new(min, max, self.max_objects_per_leaf)),
+            SpatialIndexKind::LooseGrid => Box::new(RegionLooseGrid::new(self.cell_size)),
+        }
+    }
+}
+
+/// Common interface of the spatial index implementations
+pub trait SpatialIndex: fmt::Debug + Send + Sync {
+    /// Which implementation this is
+    fn kind(&self) -> SpatialIndexKind;
+
+    /// Inserts or moves a player
+    fn insert_player(&mut self, player_id: PlayerId, position: Position);
+
+    /// Removes a player, returning how many entries were removed
+    fn remove_player(&mut self, player_id: PlayerId) -> usize;
+
+    /// Executes a spatial query with optional filters
+    fn query(&mut self, query: SpatialQuery) -> Vec<QueryResult>;
+
+    /// Queries players within a radius
+    fn query_radius(&mut self, center: Position, radius: f64) -> Vec<QueryResult> {
+        self.query(SpatialQuery {
+            center,
+            radius,
+            filters: QueryFilters::default(),
+        })
+    }
+
+    /// Gets the total number of indexed players
+    fn object_count(&self) -> usize;
+
+    /// Checks whether a given player is indexed
+    fn contains_player(&self, player_id: PlayerId) -> bool;
+
+    /// Removes every player
+    fn clear(&mut self);
+
+    /// Checks whether enough mutations have accumulated to warrant a bulk rebuild
+    fn needs_rebuild(&self) -> bool {
+        false
+    }
+
+    /// Rebuilds the index from scratch for better balance
+    fn rebuild(&mut self) {}
+
+    /// Gets maintenance and query statistics, if the index tracks them
+    fn index_stats(&mut self) -> Option<SpatialIndexStats> {
+        None
+    }
+}
+
+impl SpatialIndex for RegionRTree {
+    fn kind(&self) -> SpatialIndexKind {
+        SpatialIndexKind::RTree
+    }
+
+    fn insert_player(&mut self, player_id: PlayerId, position: Position) {
+        RegionRTree::insert_player(self, player_id, position)
+    }
+
+    fn remove_player(&mut self, player_id: PlayerId) -> usize {
+        RegionRTree::remove_player(self, player_id)
+    }
+
+    fn query(&mut self, query: SpatialQuery) -> Vec<QueryResult> {
+        RegionRTree::query(self, query)
+    }
+
+    fn object_count(&self) -> usize {
+        RegionRTree::object_count(self)
+    }
+
+    fn contains_player(&self, player_id: PlayerId) -> bool {
+        RegionRTree::contains_player(self, player_id)
+    }
+
+    fn clear(&mut self) {
+        RegionRTree::clear(self)
+    }
+
+    fn needs_rebuild(&self) -> bool {
+        RegionRTree::needs_rebuild(self)
+    }
+
+    fn rebuild(&mut self) {
+        RegionRTree::rebuild(self)
+    }
+
+    fn index_stats(&mut self) -> Option<SpatialIndexStats> {
+        Some(RegionRTree::get_stats(self))
+    }
+}
+
+/// Squared distance between two positions
+pub(super) fn distance_sq(a: Position, b: Position) -> f64 {
+    let (dx, dy, dz) = (a.x - b.x, a.y - b.y, a.z - b.z);
+    dx * dx + dy * dy + dz * dz
+}
+
+/// Turns an indexed object into a query result if it satisfies the query
+pub(super) fn match_object(object: &SpatialObject, query: &SpatialQuery) -> Option<QueryResult> {
+    let distance_sq = distance_sq(object.position, query.center);
+    if distance_sq > query.radius * query.radius {
+        return None;
+    }
+
+    let distance = distance_sq.sqrt();
+    query.filters.accepts(object.player_id, distance).then(|| QueryResult {
+        player_id: object.player_id,
+        position: object.position,
+        distance,
+        metadata: HashMap::new(),
+    })
+}
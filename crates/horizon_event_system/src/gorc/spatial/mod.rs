@@ -1,15 +1,27 @@
 //! Spatial partitioning and querying for GORC
 //!
 //! This module provides efficient spatial data structures for managing
-//! object positions and proximity queries in the GORC system.
+//! object positions and proximity queries in the GORC system. Regions are
+//! indexed with an R*-tree by default; an octree or loose grid can be
+//! selected through [`SpatialIndexConfig`].
 
+mod bench;
+mod grid;
+mod handle;
+mod index;
+mod octree;
 mod partition;
 mod query;
 mod rtree;
 
 // Re-export public types and functions
-pub use partition::SpatialPartition;
-pub use query::{QueryFilters, QueryResult, SpatialQuery};
+pub use bench::{SpatialBenchResult, SpatialBenchmark};
+pub use grid::RegionLooseGrid;
+pub use handle::{SpatialEntity, SpatialFilter, SpatialHit, SpatialQueryHandle};
+pub use index::{SpatialIndex, SpatialIndexConfig, SpatialIndexKind};
+pub use octree::RegionOctree;
+pub use partition::{SpatialMaintenanceReport, SpatialPartition};
+pub use query::{ObjectQueryResult, QueryFilters, QueryResult, SpatialQuery};
 pub use rtree::{NodeStats, RegionRTree, SpatialIndexStats, SpatialObject};
 
 /// Statistics for spatial queries
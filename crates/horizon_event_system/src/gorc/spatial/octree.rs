@@ -0,0 +1,239 @@
+/// Octree spatial index for GORC
+///
+/// Leaves split into eight octants once they hold more than
+/// `max_objects_per_leaf` players, so dense clusters get fine cells while
+/// empty space stays cheap. Objects outside the region bounds are kept in the
+/// outermost octant, whose bounds grow to cover them.
+use super::index::{match_object, SpatialIndex, SpatialIndexKind};
+use super::query::{QueryResult, SpatialQuery};
+use super::rtree::SpatialObject;
+use crate::types::{PlayerId, Position, Vec3};
+use std::collections::HashMap;
+
+/// Depth at which leaves stop splitting, whatever their size
+const MAX_DEPTH: u8 = 12;
+
+/// Index of the child octant containing `position`
+fn octant(center: Vec3, position: Position) -> usize {
+    (position.x >= center.x) as usize
+        | ((position.y >= center.y) as usize) << 1
+        | ((position.z >= center.z) as usize) << 2
+}
+
+#[derive(Debug)]
+struct OctreeNode {
+    /// Split point of the node's cell
+    center: Vec3,
+    /// Half the edge length of the node's cell on each axis
+    half: Vec3,
+    /// Bounds of everything stored below this node, grown for outliers
+    extent: (Vec3, Vec3),
+    /// Players stored in this node while it is a leaf
+    entries: Vec<SpatialObject>,
+    /// Octants, once the node has split
+    children: Option<Box<[OctreeNode; 8]>>,
+    /// Depth of the node below the root
+    depth: u8,
+    /// Players stored in this subtree
+    len: usize,
+}
+
+impl OctreeNode {
+    fn new(center: Vec3, half: Vec3, depth: u8) -> Self {
+        Self {
+            center,
+            half,
+            extent: (
+                Vec3::new(center.x - half.x, center.y - half.y, center.z - half.z),
+                Vec3::new(center.x + half.x, center.y + half.y, center.z + half.z),
+            ),
+            entries: Vec::new(),
+            children: None,
+            depth,
+            len: 0,
+        }
+    }
+
+    fn grow(&mut self, position: Position) {
+        let (min, max) = &mut self.extent;
+        min.x = min.x.min(position.x);
+        min.y = min.y.min(position.y);
+        min.z = min.z.min(position.z);
+        max.x = max.x.max(position.x);
+        max.y = max.y.max(position.y);
+        max.z = max.z.max(position.z);
+    }
+
+    fn insert(&mut self, object: SpatialObject, max_objects_per_leaf: usize) {
+        self.len += 1;
+        self.grow(object.position);
+
+        if let Some(children) = &mut self.children {
+            let octant = octant(self.center, object.position);
+            children[octant].insert(object, max_objects_per_leaf);
+            return;
+        }
+
+        self.entries.push(object);
+        if self.entries.len() > max_objects_per_leaf && self.depth < MAX_DEPTH {
+            self.split(max_objects_per_leaf);
+        }
+    }
+
+    fn split(&mut self, max_objects_per_leaf: usize) {
+        let half = Vec3::new(self.half.x / 2.0, self.half.y / 2.0, self.half.z / 2.0);
+        let (center, depth) = (self.center, self.depth + 1);
+        let offset = |bit: bool, axis_half: f64| if bit { axis_half } else { -axis_half };
+        let mut children: Box<[OctreeNode; 8]> = Box::new(std::array::from_fn(|octant| {
+            let child_center = Vec3::new(
+                center.x + offset(octant & 1 != 0, half.x),
+                center.y + offset(octant & 2 != 0, half.y),
+                center.z + offset(octant & 4 != 0, half.z),
+            );
+            OctreeNode::new(child_center, half, depth)
+        }));
+
+        for object in self.entries.drain(..) {
+            let octant = octant(self.center, object.position);
+            children[octant].insert(object, max_objects_per_leaf);
+        }
+        self.children = Some(children);
+    }
+
+    fn remove(&mut self, player_id: PlayerId, position: Position, max_objects_per_leaf: usize) -> bool {
+        let removed = match &mut self.children {
+            Some(children) => {
+                let octant = octant(self.center, position);
+                children[octant].remove(player_id, position, max_objects_per_leaf)
+            }
+            None => match self.entries.iter().position(|object| object.player_id == player_id) {
+                Some(index) => {
+                    self.entries.swap_remove(index);
+                    true
+                }
+                None => false,
+            },
+        };
+
+        if removed {
+            self.len -= 1;
+            // Merge back into a leaf well below the split size to avoid churn
+            if self.children.is_some() && self.len <= max_objects_per_leaf / 2 {
+                self.collapse();
+            }
+        }
+        removed
+    }
+
+    fn collapse(&mut self) {
+        if let Some(children) = self.children.take() {
+            for mut child in children.into_iter() {
+                child.collapse();
+                self.entries.append(&mut child.entries);
+            }
+        }
+    }
+
+    fn intersects_sphere(&self, center: Position, radius_sq: f64) -> bool {
+        let (min, max) = &self.extent;
+        let axis = |value: f64, min: f64, max: f64| {
+            let d = value - value.clamp(min, max);
+            d * d
+        };
+        axis(center.x, min.x, max.x) + axis(center.y, min.y, max.y) + axis(center.z, min.z, max.z) <= radius_sq
+    }
+
+    fn query(&self, query: &SpatialQuery, results: &mut Vec<QueryResult>) {
+        if self.len == 0 || !self.intersects_sphere(query.center, query.radius * query.radius) {
+            return;
+        }
+
+        match &self.children {
+            Some(children) => children.iter().for_each(|child| child.query(query, results)),
+            None => results.extend(self.entries.iter().filter_map(|object| match_object(object, query))),
+        }
+    }
+}
+
+/// Octree over a region's bounds
+#[derive(Debug)]
+pub struct RegionOctree {
+    /// Region bounds the root cell covers
+    bounds: (Vec3, Vec3),
+    /// Objects a leaf holds before it splits
+    max_objects_per_leaf: usize,
+    /// Root cell
+    root: OctreeNode,
+    /// Current position of every indexed player
+    positions: HashMap<PlayerId, Position>,
+}
+
+impl RegionOctree {
+    /// Creates an empty octree covering the given bounds
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - Minimum corner of the region
+    /// * `max` - Maximum corner of the region
+    /// * `max_objects_per_leaf` - Leaf size that triggers a split
+    pub fn new(min: Vec3, max: Vec3, max_objects_per_leaf: usize) -> Self {
+        Self {
+            bounds: (min, max),
+            max_objects_per_leaf: max_objects_per_leaf.max(1),
+            root: Self::root_node(min, max),
+            positions: HashMap::new(),
+        }
+    }
+
+    fn root_node(min: Vec3, max: Vec3) -> OctreeNode {
+        let center = Vec3::new((min.x + max.x) / 2.0, (min.y + max.y) / 2.0, (min.z + max.z) / 2.0);
+        let half = Vec3::new(
+            ((max.x - min.x) / 2.0).abs(),
+            ((max.y - min.y) / 2.0).abs(),
+            ((max.z - min.z) / 2.0).abs(),
+        );
+        OctreeNode::new(center, half, 0)
+    }
+}
+
+impl SpatialIndex for RegionOctree {
+    fn kind(&self) -> SpatialIndexKind {
+        SpatialIndexKind::Octree
+    }
+
+    fn insert_player(&mut self, player_id: PlayerId, position: Position) {
+        self.remove_player(player_id);
+        self.root.insert(SpatialObject::new(player_id, position), self.max_objects_per_leaf);
+        self.positions.insert(player_id, position);
+    }
+
+    fn remove_player(&mut self, player_id: PlayerId) -> usize {
+        match self.positions.remove(&player_id) {
+            Some(position) => self.root.remove(player_id, position, self.max_objects_per_leaf) as usize,
+            None => 0,
+        }
+    }
+
+    fn query(&mut self, query: SpatialQuery) -> Vec<QueryResult> {
+        let mut results = Vec::new();
+        self.root.query(&query, &mut results);
+        if let Some(max_results) = query.filters.max_results {
+            results.truncate(max_results);
+        }
+        results
+    }
+
+    fn object_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    fn contains_player(&self, player_id: PlayerId) -> bool {
+        self.positions.contains_key(&player_id)
+    }
+
+    fn clear(&mut self) {
+        let (min, max) = self.bounds;
+        self.root = Self::root_node(min, max);
+        self.positions.clear();
+    }
+}
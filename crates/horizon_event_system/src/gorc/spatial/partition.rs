@@ -1,35 +1,75 @@
 /// Spatial partitioning system
+use super::index::{SpatialIndex, SpatialIndexConfig, SpatialIndexKind};
 use super::query::{QueryResult, SpatialQuery};
-use super::RegionRTree;
+use super::rtree::SpatialIndexStats;
 use crate::types::{PlayerId, Position, Vec3};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+/// Outcome of a single spatial index maintenance pass
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpatialMaintenanceReport {
+    /// Insertions and removals since the previous pass
+    pub mutations: usize,
+    /// Regions whose index was bulk rebuilt
+    pub regions_rebuilt: usize,
+    /// Whether due rebuilds were postponed because the tick was busy
+    pub deferred: bool,
+    /// Time spent rebuilding
+    pub duration: Duration,
+}
+
 /// Main spatial partitioning system
 #[derive(Debug)]
 pub struct SpatialPartition {
     /// Regional spatial indexes for different areas
-    regions: Arc<RwLock<HashMap<String, RegionRTree>>>,
+    regions: Arc<RwLock<HashMap<String, Box<dyn SpatialIndex>>>>,
     /// Player to region mapping
     player_regions: Arc<RwLock<HashMap<PlayerId, String>>>,
+    /// Index implementation used for new regions
+    index: SpatialIndexConfig,
+    /// Insertions and removals since the last maintenance pass
+    pending_mutations: AtomicUsize,
+    /// Consecutive busy passes that postponed a due rebuild
+    deferred_passes: AtomicU32,
 }
 
 impl SpatialPartition {
     /// Creates a new spatial partition system
     pub fn new() -> Self {
+        Self::with_index(SpatialIndexConfig::default())
+    }
+
+    /// Creates a spatial partition whose regions use the given index implementation
+    pub fn with_index(index: SpatialIndexConfig) -> Self {
         Self {
             regions: Arc::new(RwLock::new(HashMap::new())),
             player_regions: Arc::new(RwLock::new(HashMap::new())),
+            index,
+            pending_mutations: AtomicUsize::new(0),
+            deferred_passes: AtomicU32::new(0),
         }
     }
 
+    /// Gets the index selection and maintenance tuning
+    pub fn index_config(&self) -> &SpatialIndexConfig {
+        &self.index
+    }
+
+    /// Gets the index implementation used for regions
+    pub fn index_kind(&self) -> SpatialIndexKind {
+        self.index.kind
+    }
+
     /// Adds a region with specified bounds
     pub async fn add_region(&self, region_id: String, min: Vec3, max: Vec3) {
         let mut regions = self.regions.write().await;
         regions
             .entry(region_id)
-            .or_insert_with(|| RegionRTree::new(min, max));
+            .or_insert_with(|| self.index.create(min, max));
     }
 
     /// Updates a player's position
@@ -40,7 +80,7 @@ impl SpatialPartition {
         let mut regions = self.regions.write().await;
         let region = regions.entry(region_id.clone()).or_insert_with(|| {
             // Default region bounds (large enough for most worlds)
-            RegionRTree::new(
+            self.index.create(
                 Vec3::new(-10_000.0, -10_000.0, -1_000.0),
                 Vec3::new(10_000.0, 10_000.0, 1_000.0),
             )
@@ -48,6 +88,7 @@ impl SpatialPartition {
 
         region.insert_player(player_id, position);
         drop(regions);
+        self.pending_mutations.fetch_add(1, Ordering::Relaxed);
 
         {
             let mut player_regions = self.player_regions.write().await;
@@ -62,7 +103,7 @@ impl SpatialPartition {
         
         // Query all regions (simplified)
         for region in regions.values_mut() {
-            results.extend(region.query_radius(center, radius.into()));
+            results.extend(region.query_radius(center, radius));
         }
         
         results
@@ -92,7 +133,60 @@ impl SpatialPartition {
             if let Some(region) = regions.get_mut(&region_id) {
                 region.remove_player(player_id);
             }
+            self.pending_mutations.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Runs one maintenance pass over every region.
+    ///
+    /// Insertions and removals are applied to the indexes incrementally as
+    /// they happen; this pass only schedules full bulk rebuilds. Regions
+    /// that have absorbed enough mutations are rebuilt when the pass falls on
+    /// a low-load tick, i.e. at most `low_load_mutations` mutations happened
+    /// since the previous pass. On busy ticks the rebuild is deferred, unless
+    /// it has already been deferred `max_rebuild_deferrals` times in a row.
+    ///
+    /// # Returns
+    ///
+    /// What the pass did, including the time spent rebuilding.
+    pub async fn maintain(&self) -> SpatialMaintenanceReport {
+        let mutations = self.pending_mutations.swap(0, Ordering::Relaxed);
+        let mut report = SpatialMaintenanceReport {
+            mutations,
+            ..Default::default()
+        };
+
+        let mut regions = self.regions.write().await;
+        if !regions.values().any(|region| region.needs_rebuild()) {
+            self.deferred_passes.store(0, Ordering::Relaxed);
+            return report;
+        }
+
+        let busy = mutations > self.index.low_load_mutations;
+        if busy && self.deferred_passes.load(Ordering::Relaxed) < self.index.max_rebuild_deferrals {
+            self.deferred_passes.fetch_add(1, Ordering::Relaxed);
+            report.deferred = true;
+            return report;
+        }
+
+        let started = Instant::now();
+        for region in regions.values_mut().filter(|region| region.needs_rebuild()) {
+            region.rebuild();
+            report.regions_rebuilt += 1;
         }
+        report.duration = started.elapsed();
+        self.deferred_passes.store(0, Ordering::Relaxed);
+        report
+    }
+
+    /// Gets the statistics of every region whose index tracks them
+    pub async fn index_stats(&self) -> HashMap<String, SpatialIndexStats> {
+        self.regions
+            .write()
+            .await
+            .iter_mut()
+            .filter_map(|(region_id, region)| Some((region_id.clone(), region.index_stats()?)))
+            .collect()
     }
 
     /// Runs a spatial query with filters
@@ -1,5 +1,6 @@
 /// Spatial query types and utilities
-use crate::types::{PlayerId, Position};
+use crate::gorc::instance::GorcObjectId;
+use crate::types::{PlayerId, Position, Vec3};
 use std::collections::{HashMap, HashSet};
 
 /// Spatial query parameters
@@ -24,6 +25,76 @@ pub struct QueryFilters {
     pub max_results: Option<usize>,
     /// Minimum distance from query center
     pub min_distance: Option<f64>,
+    /// Include only objects of these types (object queries only)
+    pub object_types: Option<HashSet<String>>,
+    /// Include only objects with a zone on this channel (object queries only)
+    pub channel: Option<u8>,
+    /// Include only objects carrying all of these tags, declared or set at runtime (object queries only)
+    pub tags: Option<HashSet<String>>,
+    /// Include only objects whose runtime tags have all of these values (object queries only)
+    pub tag_values: Option<HashMap<String, String>>,
+    /// Return results nearest first; otherwise results are in index order
+    pub sort_by_distance: bool,
+}
+
+impl SpatialQuery {
+    /// Creates an unfiltered query for everything within `radius` of `center`
+    pub fn new(center: impl Into<Position>, radius: f64) -> Self {
+        Self {
+            center: center.into(),
+            radius,
+            filters: QueryFilters::default(),
+        }
+    }
+
+    /// Replaces the query's filters
+    pub fn with_filters(mut self, filters: QueryFilters) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Restricts object results to a type; may be called repeatedly to allow several types
+    pub fn with_object_type(mut self, object_type: impl Into<String>) -> Self {
+        self.filters.object_types.get_or_insert_with(HashSet::new).insert(object_type.into());
+        self
+    }
+
+    /// Restricts object results to objects with a zone on `channel`
+    pub fn with_channel(mut self, channel: u8) -> Self {
+        self.filters.channel = Some(channel);
+        self
+    }
+
+    /// Restricts object results to objects carrying `tag`; may be called repeatedly to require several tags
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.filters.tags.get_or_insert_with(HashSet::new).insert(tag.into());
+        self
+    }
+
+    /// Restricts object results to objects whose runtime tag `key` equals `value`;
+    /// may be called repeatedly to require several values
+    pub fn with_tag_value(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.filters.tag_values.get_or_insert_with(HashMap::new).insert(key.into(), value.into());
+        self
+    }
+
+    /// Limits the number of results
+    pub fn with_max_results(mut self, max_results: usize) -> Self {
+        self.filters.max_results = Some(max_results);
+        self
+    }
+
+    /// Excludes results closer than `min_distance` to the center
+    pub fn with_min_distance(mut self, min_distance: f64) -> Self {
+        self.filters.min_distance = Some(min_distance);
+        self
+    }
+
+    /// Returns results nearest first
+    pub fn sorted_by_distance(mut self) -> Self {
+        self.filters.sort_by_distance = true;
+        self
+    }
 }
 
 /// Result of a spatial query
@@ -37,4 +108,77 @@ pub struct QueryResult {
     pub distance: f64,
     /// Additional metadata
     pub metadata: HashMap<String, String>,
-}
\ No newline at end of file
+}
+
+/// Object found by a spatial query
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectQueryResult {
+    /// The matched object
+    pub object_id: GorcObjectId,
+    /// Type name of the object
+    pub object_type: String,
+    /// Tracked position of the object
+    pub position: Vec3,
+    /// Distance from query center
+    pub distance: f64,
+}
+impl QueryFilters {
+    /// Checks whether a candidate at `distance` passes the include/exclude
+    /// and minimum distance filters
+    pub(crate) fn accepts(&self, player_id: PlayerId, distance: f64) -> bool {
+        if let Some(include) = &self.include_players {
+            if !include.contains(&player_id) {
+                return false;
+            }
+        }
+
+        if let Some(exclude) = &self.exclude_players {
+            if exclude.contains(&player_id) {
+                return false;
+            }
+        }
+
+        self.min_distance.is_none_or(|min_distance| distance >= min_distance)
+    }
+
+    /// Checks whether an object passes the type, channel and tag filters
+    pub(crate) fn accepts_object<'a>(
+        &self,
+        object_type: &str,
+        has_channel: impl FnOnce(u8) -> bool,
+        has_tag: impl Fn(&str) -> bool,
+        tag_value: impl Fn(&str) -> Option<&'a str>,
+    ) -> bool {
+        if let Some(object_types) = &self.object_types {
+            if !object_types.contains(object_type) {
+                return false;
+            }
+        }
+
+        if let Some(channel) = self.channel {
+            if !has_channel(channel) {
+                return false;
+            }
+        }
+
+        if let Some(required) = &self.tags {
+            if !required.iter().all(|tag| has_tag(tag)) {
+                return false;
+            }
+        }
+
+        self.tag_values
+            .as_ref()
+            .is_none_or(|required| required.iter().all(|(key, value)| tag_value(key) == Some(value.as_str())))
+    }
+
+    /// Sorts results nearest first if requested and applies the result limit
+    pub(crate) fn finish<T>(&self, results: &mut Vec<T>, distance: impl Fn(&T) -> f64) {
+        if self.sort_by_distance {
+            results.sort_by(|a, b| distance(a).total_cmp(&distance(b)));
+        }
+        if let Some(max_results) = self.max_results {
+            results.truncate(max_results);
+        }
+    }
+}
@@ -9,6 +9,7 @@ use crate::types::{PlayerId, Position, Vec3};
 use crate::utils::current_timestamp;
 use rstar::{PointDistance, RTree, RTreeObject, AABB};
 use std::collections::HashMap;
+use std::time::Instant;
 
 /// Entry stored inside the R-tree.
 #[derive(Debug, Clone)]
@@ -89,6 +90,12 @@ pub struct SpatialIndexStats {
     pub current_depth: u8,
     pub leaf_nodes: usize,
     pub internal_nodes: usize,
+    /// Insertions and removals since the last bulk rebuild
+    pub mutations_since_rebuild: usize,
+    /// Duration of the last bulk rebuild in microseconds
+    pub last_rebuild_us: u64,
+    /// Combined duration of all bulk rebuilds in microseconds
+    pub total_rebuild_us: u64,
 }
 
 /// Detailed node statistics (approximated for R-tree)
@@ -113,6 +120,8 @@ pub struct RegionRTree {
     object_count: usize,
     /// Performance statistics
     stats: SpatialIndexStats,
+    /// Mutations after which a bulk rebuild is due
+    rebuild_threshold: usize,
 }
 
 impl RegionRTree {
@@ -124,9 +133,16 @@ impl RegionRTree {
             player_entries: HashMap::new(),
             object_count: 0,
             stats: SpatialIndexStats::default(),
+            rebuild_threshold: 5_000,
         }
     }
 
+    /// Sets the number of mutations after which a bulk rebuild is due
+    pub fn with_rebuild_threshold(mut self, rebuild_threshold: usize) -> Self {
+        self.rebuild_threshold = rebuild_threshold.max(1);
+        self
+    }
+
     /// Inserts or updates a player at a position with O(log n) performance
     pub fn insert_player(&mut self, player_id: PlayerId, position: Position) {
         let object = SpatialObject::new(player_id, position);
@@ -147,6 +163,7 @@ impl RegionRTree {
         self.player_entries.insert(player_id, entry);
         self.object_count += 1;
         self.stats.total_insertions += 1;
+        self.stats.mutations_since_rebuild += 1;
     }
 
     /// Queries players within a radius with O(log n) performance
@@ -172,30 +189,14 @@ impl RegionRTree {
             .filter_map(|entry| {
                 let object = &entry.object;
 
-                // Apply include/exclude filters
-                if let Some(include) = &query.filters.include_players {
-                    if !include.contains(&object.player_id) {
-                        return None;
-                    }
-                }
-
-                if let Some(exclude) = &query.filters.exclude_players {
-                    if exclude.contains(&object.player_id) {
-                        return None;
-                    }
-                }
-
                 let distance_sq = entry.distance_2(&center_point);
                 if distance_sq > radius_sq {
                     return None;
                 }
 
                 let distance = distance_sq.sqrt();
-
-                if let Some(min_distance) = query.filters.min_distance {
-                    if distance < min_distance {
-                        return None;
-                    }
+                if !query.filters.accepts(object.player_id, distance) {
+                    return None;
                 }
 
                 Some(QueryResult {
@@ -223,6 +224,7 @@ impl RegionRTree {
             if removed {
                 self.object_count = self.object_count.saturating_sub(1);
                 self.stats.total_removals += 1;
+                self.stats.mutations_since_rebuild += 1;
                 return 1;
             }
         }
@@ -276,14 +278,27 @@ impl RegionRTree {
         self.player_entries.clear();
         self.object_count = 0;
         self.stats.total_clears += 1;
+        self.stats.mutations_since_rebuild = 0;
         self.bounds = (min, max);
     }
 
+    /// Checks whether enough insertions and removals have accumulated since
+    /// the last rebuild to warrant a bulk rebuild
+    pub fn needs_rebuild(&self) -> bool {
+        self.stats.mutations_since_rebuild >= self.rebuild_threshold
+    }
+
     /// Rebuilds the tree for better balance
     pub fn rebuild(&mut self) {
+        let started = Instant::now();
         let entries: Vec<_> = self.player_entries.values().cloned().collect();
         self.tree = RTree::bulk_load(entries);
+
+        let elapsed_us = started.elapsed().as_micros() as u64;
         self.stats.total_rebuilds += 1;
+        self.stats.mutations_since_rebuild = 0;
+        self.stats.last_rebuild_us = elapsed_us;
+        self.stats.total_rebuild_us += elapsed_us;
     }
 
     /// Collects all objects from the tree
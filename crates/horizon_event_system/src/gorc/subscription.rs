@@ -5,6 +5,7 @@
 
 use crate::types::{PlayerId, Position};
 use crate::gorc::channels::ReplicationPriority;
+use crate::gorc::instance::GorcObjectId;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -15,6 +16,11 @@ use tokio::time::Duration;
 const CRITICAL_CHANNEL: u8 = 0;
 const FREQUENCY_THRESHOLD: f32 = 0.8;
 
+// Defaults for facing-based interest
+const DEFAULT_VIEW_HALF_ANGLE: f32 = 60.0;
+const DEFAULT_BEHIND_FREQUENCY_SCALE: f32 = 0.5;
+const MIN_FACING_SPEED: f64 = 0.1;
+
 /// Types of subscription relationships
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SubscriptionType {
@@ -168,6 +174,93 @@ impl RelationshipSubscription {
     }
 }
 
+/// Where a target lies relative to a player's facing direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ViewRelation {
+    /// Inside the view cone
+    InView,
+    /// In front of the player but outside the view cone
+    Peripheral,
+    /// Behind the player
+    Behind,
+    /// The player's facing direction is unknown
+    Unknown,
+}
+
+/// A player's facing direction and view cone
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewCone {
+    /// Unit vector the player is facing
+    pub facing: Position,
+    /// Half-angle of the view cone in degrees
+    pub half_angle: f32,
+    /// Update frequency multiplier for targets behind the player (0.0-1.0)
+    pub behind_frequency_scale: f32,
+}
+
+impl ViewCone {
+    /// Creates a view cone facing `direction`, or `None` if the direction has no length
+    pub fn new(direction: Position) -> Option<Self> {
+        let length = direction.distance(Position::new(0.0, 0.0, 0.0));
+        if length <= f64::EPSILON {
+            return None;
+        }
+
+        Some(Self {
+            facing: Position::new(direction.x / length, direction.y / length, direction.z / length),
+            half_angle: DEFAULT_VIEW_HALF_ANGLE,
+            behind_frequency_scale: DEFAULT_BEHIND_FREQUENCY_SCALE,
+        })
+    }
+
+    /// Creates a view cone from a movement velocity, or `None` if the player is (nearly) stationary
+    pub fn from_velocity(velocity: Position) -> Option<Self> {
+        if velocity.distance(Position::new(0.0, 0.0, 0.0)) < MIN_FACING_SPEED {
+            return None;
+        }
+        Self::new(velocity)
+    }
+
+    /// Sets the half-angle of the view cone in degrees
+    pub fn with_half_angle(mut self, half_angle: f32) -> Self {
+        self.half_angle = half_angle.clamp(0.0, 180.0);
+        self
+    }
+
+    /// Sets the update frequency multiplier for targets behind the player
+    pub fn with_behind_frequency_scale(mut self, scale: f32) -> Self {
+        self.behind_frequency_scale = scale.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Classifies a target seen from `origin`
+    pub fn classify(&self, origin: Position, target: Position) -> ViewRelation {
+        let offset = Position::new(target.x - origin.x, target.y - origin.y, target.z - origin.z);
+        let distance = offset.distance(Position::new(0.0, 0.0, 0.0));
+        if distance <= f64::EPSILON {
+            // Something at the player's own position is always relevant
+            return ViewRelation::InView;
+        }
+
+        let cosine = (offset.x * self.facing.x + offset.y * self.facing.y + offset.z * self.facing.z) / distance;
+        if cosine >= (self.half_angle as f64).to_radians().cos() {
+            ViewRelation::InView
+        } else if cosine >= 0.0 {
+            ViewRelation::Peripheral
+        } else {
+            ViewRelation::Behind
+        }
+    }
+
+    /// Gets the update frequency multiplier for a view relation
+    pub fn frequency_scale(&self, relation: ViewRelation) -> f32 {
+        match relation {
+            ViewRelation::Behind => self.behind_frequency_scale,
+            _ => 1.0,
+        }
+    }
+}
+
 /// Interest-based subscription tracking player focus and activity
 #[derive(Debug, Clone)]
 pub struct InterestSubscription {
@@ -181,6 +274,10 @@ pub struct InterestSubscription {
     pub focus_radius: f32,
     /// Last activity timestamp (using std::time::Instant)
     pub last_activity: Option<std::time::Instant>,
+    /// Facing direction and view cone, if known
+    pub view_cone: Option<ViewCone>,
+    /// Object the client declared as its current focus (e.g. combat target)
+    pub focused_object: Option<GorcObjectId>,
 }
 
 /// Level of interest in an object or area
@@ -209,6 +306,15 @@ impl InterestLevel {
             InterestLevel::VeryHigh => ReplicationPriority::Critical,
         }
     }
+
+    /// Gets the update frequency multiplier for an interest level
+    pub fn frequency_scale(self) -> f32 {
+        match self {
+            InterestLevel::VeryHigh => 2.0,
+            InterestLevel::High => 1.5,
+            InterestLevel::None | InterestLevel::Low | InterestLevel::Medium => 1.0,
+        }
+    }
 }
 
 /// Activity pattern tracking for predictive subscriptions
@@ -265,7 +371,45 @@ impl InterestSubscription {
             focus_position: None,
             focus_radius: 50.0,
             last_activity: None,
+            view_cone: None,
+            focused_object: None,
+        }
+    }
+
+    /// Sets the object the player is focused on, replacing any previous focus.
+    ///
+    /// The focused object is recorded with [`InterestLevel::VeryHigh`] and the
+    /// previous focus loses its recorded interest. Pass `None` to clear the focus.
+    pub fn set_focused_object(&mut self, object_id: Option<GorcObjectId>) {
+        if let Some(previous) = self.focused_object.take() {
+            self.interested_objects.remove(&previous.to_string());
+        }
+        if let Some(object_id) = object_id {
+            self.record_interest(object_id.to_string(), InterestLevel::VeryHigh);
+            self.focused_object = Some(object_id);
+        }
+    }
+
+    /// Updates the player's facing direction, keeping the configured cone shape.
+    ///
+    /// Returns `false` and keeps the previous facing if the direction has no length.
+    pub fn update_facing(&mut self, direction: Position) -> bool {
+        let Some(mut cone) = ViewCone::new(direction) else {
+            return false;
+        };
+        if let Some(previous) = self.view_cone {
+            cone = cone
+                .with_half_angle(previous.half_angle)
+                .with_behind_frequency_scale(previous.behind_frequency_scale);
         }
+        self.view_cone = Some(cone);
+        true
+    }
+
+    /// Classifies a target seen from `origin` against the player's view cone
+    pub fn view_relation(&self, origin: Position, target: Position) -> ViewRelation {
+        self.view_cone
+            .map_or(ViewRelation::Unknown, |cone| cone.classify(origin, target))
     }
 
     /// Updates the player's focus position
@@ -418,6 +562,169 @@ impl SubscriptionManager {
         }
     }
 
+    /// Sets the object a player's client declared as its focus
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The player that changed focus
+    /// * `object_id` - The focused object (e.g. current combat target), or `None` to clear the focus
+    ///
+    /// # Returns
+    ///
+    /// `true` if the focus was updated, `false` for unknown players
+    pub async fn set_focus_target(&self, player_id: PlayerId, object_id: Option<GorcObjectId>) -> bool {
+        let mut interest_subs = self.interest_subs.write().await;
+        let Some(sub) = interest_subs.get_mut(&player_id) else {
+            return false;
+        };
+        sub.set_focused_object(object_id);
+        true
+    }
+
+    /// Gets the object a player is focused on
+    pub async fn get_focus_target(&self, player_id: PlayerId) -> Option<GorcObjectId> {
+        self.interest_subs
+            .read()
+            .await
+            .get(&player_id)
+            .and_then(|sub| sub.focused_object)
+    }
+
+    /// Gets a subscriber's recorded interest in an object
+    pub async fn get_interest_level(&self, subscriber: PlayerId, object_id: &str) -> InterestLevel {
+        self.interest_subs
+            .read()
+            .await
+            .get(&subscriber)
+            .map_or(InterestLevel::None, |sub| sub.get_interest_level(object_id))
+    }
+
+    /// Gets the update frequency multiplier for an object at a position.
+    ///
+    /// Objects the subscriber has high interest in, such as a focused target,
+    /// are updated faster regardless of distance or facing. Other objects use
+    /// the view-based scale from [`Self::get_frequency_scale`].
+    pub async fn get_object_frequency_scale(
+        &self,
+        subscriber: PlayerId,
+        object_id: GorcObjectId,
+        target_position: Position,
+    ) -> f32 {
+        let interest_scale = self.get_interest_level(subscriber, &object_id.to_string()).await.frequency_scale();
+        if interest_scale > 1.0 {
+            interest_scale
+        } else {
+            self.get_frequency_scale(subscriber, target_position).await
+        }
+    }
+
+    /// Gets a subscriber's replication priority for an object at a position.
+    ///
+    /// A focused object is replicated at the priority of its recorded
+    /// interest at any range. Other objects get `base_priority` adjusted by
+    /// [`Self::get_view_priority`].
+    pub async fn get_object_priority(
+        &self,
+        subscriber: PlayerId,
+        object_id: GorcObjectId,
+        target_position: Position,
+        base_priority: ReplicationPriority,
+    ) -> ReplicationPriority {
+        let interest_level = self.get_interest_level(subscriber, &object_id.to_string()).await;
+        let view_priority = self.get_view_priority(subscriber, target_position, base_priority).await;
+        if interest_level == InterestLevel::None {
+            view_priority
+        } else {
+            view_priority.min(interest_level.to_priority())
+        }
+    }
+
+    /// Updates the direction a player is facing
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The player that turned
+    /// * `direction` - Facing direction; it doesn't need to be normalized
+    ///
+    /// # Returns
+    ///
+    /// `true` if the facing was updated, `false` for unknown players or zero-length directions
+    pub async fn update_player_facing(&self, player_id: PlayerId, direction: Position) -> bool {
+        let mut interest_subs = self.interest_subs.write().await;
+        interest_subs
+            .get_mut(&player_id)
+            .is_some_and(|sub| sub.update_facing(direction))
+    }
+
+    /// Updates a player's facing from the velocity in a movement message.
+    ///
+    /// Stationary players keep facing the way they last moved.
+    pub async fn update_player_facing_from_velocity(&self, player_id: PlayerId, velocity: Position) -> bool {
+        if ViewCone::from_velocity(velocity).is_none() {
+            return false;
+        }
+        self.update_player_facing(player_id, velocity).await
+    }
+
+    /// Sets the shape of a player's view cone
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The player to configure
+    /// * `half_angle` - Half-angle of the view cone in degrees
+    /// * `behind_frequency_scale` - Update frequency multiplier for targets behind the player
+    pub async fn set_view_cone(&self, player_id: PlayerId, half_angle: f32, behind_frequency_scale: f32) {
+        let mut interest_subs = self.interest_subs.write().await;
+        if let Some(cone) = interest_subs.get_mut(&player_id).and_then(|sub| sub.view_cone.as_mut()) {
+            *cone = cone
+                .with_half_angle(half_angle)
+                .with_behind_frequency_scale(behind_frequency_scale);
+        }
+    }
+
+    /// Classifies a position against a player's view cone
+    pub async fn get_view_relation(&self, subscriber: PlayerId, target_position: Position) -> ViewRelation {
+        let Some(origin) = self.proximity_subs.read().await.get(&subscriber).map(|sub| sub.position) else {
+            return ViewRelation::Unknown;
+        };
+        self.interest_subs
+            .read()
+            .await
+            .get(&subscriber)
+            .map_or(ViewRelation::Unknown, |sub| sub.view_relation(origin, target_position))
+    }
+
+    /// Adjusts a priority by where the target lies in the subscriber's view.
+    ///
+    /// Targets in the view cone are raised one level and targets behind the
+    /// player are lowered one level. Players without a known facing are unaffected.
+    pub async fn get_view_priority(
+        &self,
+        subscriber: PlayerId,
+        target_position: Position,
+        base_priority: ReplicationPriority,
+    ) -> ReplicationPriority {
+        match self.get_view_relation(subscriber, target_position).await {
+            ViewRelation::InView => raise_priority(base_priority),
+            ViewRelation::Behind => lower_priority(base_priority),
+            ViewRelation::Peripheral | ViewRelation::Unknown => base_priority,
+        }
+    }
+
+    /// Gets the update frequency multiplier for a target position.
+    ///
+    /// Returns the subscriber's `behind_frequency_scale` for targets behind the
+    /// player and `1.0` otherwise.
+    pub async fn get_frequency_scale(&self, subscriber: PlayerId, target_position: Position) -> f32 {
+        let relation = self.get_view_relation(subscriber, target_position).await;
+        self.interest_subs
+            .read()
+            .await
+            .get(&subscriber)
+            .and_then(|sub| sub.view_cone)
+            .map_or(1.0, |cone| cone.frequency_scale(relation))
+    }
+
     /// Gets the combined subscription priority for two players on a specific channel
     pub async fn get_subscription_priority(
         &self,
@@ -429,12 +736,24 @@ impl SubscriptionManager {
         let relationship_priority = self.get_relationship_priority(subscriber, target, channel).await;
         let interest_priority = self.get_interest_priority(subscriber, target, channel).await;
 
-        // Return the highest priority
-        [proximity_priority, relationship_priority, interest_priority]
+        // Take the highest priority
+        let priority = [proximity_priority, relationship_priority, interest_priority]
             .iter()
             .min()
             .copied()
-            .unwrap_or(ReplicationPriority::Low)
+            .unwrap_or(ReplicationPriority::Low);
+
+        // Then favour targets the subscriber is looking at, never dropping
+        // below the subscriber's declared interest in the target
+        let target_position = self.proximity_subs.read().await.get(&target).map(|sub| sub.position);
+        let priority = match target_position {
+            Some(position) => self.get_view_priority(subscriber, position, priority).await,
+            None => priority,
+        };
+        match self.get_interest_level(subscriber, &target.to_string()).await {
+            InterestLevel::None => priority,
+            level => priority.min(level.to_priority()),
+        }
     }
 
     /// Recalculates proximity subscriptions for a player
@@ -509,6 +828,12 @@ impl SubscriptionManager {
         let proximity_subs = self.proximity_subs.read().await;
         
         if let Some(interest) = interest_subs.get(&subscriber) {
+            // Interest recorded for the target itself applies at any range
+            let target_level = interest.get_interest_level(&target.to_string());
+            if target_level != InterestLevel::None {
+                return target_level.to_priority();
+            }
+
             // Check if subscriber has specific interest in target player's object type
             if let Some(interested_level) = interest.interested_objects.get("player") {
                 let base_priority = match interested_level {
@@ -561,6 +886,24 @@ impl SubscriptionManager {
     }
 }
 
+/// Raises a priority by one level
+fn raise_priority(priority: ReplicationPriority) -> ReplicationPriority {
+    match priority {
+        ReplicationPriority::Low => ReplicationPriority::Normal,
+        ReplicationPriority::Normal => ReplicationPriority::High,
+        ReplicationPriority::High | ReplicationPriority::Critical => ReplicationPriority::Critical,
+    }
+}
+
+/// Lowers a priority by one level
+fn lower_priority(priority: ReplicationPriority) -> ReplicationPriority {
+    match priority {
+        ReplicationPriority::Critical => ReplicationPriority::High,
+        ReplicationPriority::High => ReplicationPriority::Normal,
+        ReplicationPriority::Normal | ReplicationPriority::Low => ReplicationPriority::Low,
+    }
+}
+
 impl Default for SubscriptionManager {
     fn default() -> Self {
         Self::new()
@@ -634,4 +977,79 @@ mod tests {
         let stats = manager.get_stats().await;
         assert_eq!(stats.proximity_recalculations, 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_view_cone_classification() {
+        let cone = ViewCone::new(Position::new(1.0, 0.0, 0.0)).unwrap().with_half_angle(45.0);
+        let origin = Position::new(0.0, 0.0, 0.0);
+
+        assert_eq!(cone.classify(origin, Position::new(100.0, 10.0, 0.0)), ViewRelation::InView);
+        assert_eq!(cone.classify(origin, Position::new(10.0, 0.0, 50.0)), ViewRelation::Peripheral);
+        assert_eq!(cone.classify(origin, Position::new(-100.0, 0.0, 0.0)), ViewRelation::Behind);
+        assert!(ViewCone::new(origin).is_none());
+        assert!(ViewCone::from_velocity(Position::new(0.01, 0.0, 0.0)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_facing_adjusts_priority_and_frequency() {
+        let manager = SubscriptionManager::new();
+        let (viewer, ahead, behind) = (PlayerId::new(), PlayerId::new(), PlayerId::new());
+        manager.add_player(viewer, Position::new(0.0, 0.0, 0.0)).await;
+        manager.add_player(ahead, Position::new(120.0, 0.0, 0.0)).await;
+        manager.add_player(behind, Position::new(-120.0, 0.0, 0.0)).await;
+
+        // Without a facing both targets are treated alike
+        assert_eq!(
+            manager.get_subscription_priority(viewer, ahead, 0).await,
+            manager.get_subscription_priority(viewer, behind, 0).await
+        );
+        assert_eq!(manager.get_frequency_scale(viewer, Position::new(-120.0, 0.0, 0.0)).await, 1.0);
+
+        // Moving along +x faces the viewer towards `ahead`
+        assert!(manager.update_player_facing_from_velocity(viewer, Position::new(8.0, 0.0, 0.0)).await);
+        assert!(!manager.update_player_facing_from_velocity(viewer, Position::new(0.0, 0.0, 0.0)).await);
+        assert!(
+            manager.get_subscription_priority(viewer, ahead, 0).await
+                < manager.get_subscription_priority(viewer, behind, 0).await
+        );
+        assert_eq!(manager.get_frequency_scale(viewer, Position::new(-120.0, 0.0, 0.0)).await, 0.5);
+        assert_eq!(manager.get_frequency_scale(viewer, Position::new(120.0, 0.0, 0.0)).await, 1.0);
+
+        // Cone shape survives later facing updates
+        manager.set_view_cone(viewer, 30.0, 0.25).await;
+        manager.update_player_facing(viewer, Position::new(0.0, 0.0, 1.0)).await;
+        assert_eq!(manager.get_view_relation(viewer, Position::new(120.0, 0.0, 0.0)).await, ViewRelation::Peripheral);
+        assert_eq!(manager.get_frequency_scale(viewer, Position::new(0.0, 0.0, -50.0)).await, 0.25);
+    }
+
+    #[tokio::test]
+    async fn test_focus_target_boosts_priority_and_frequency() {
+        let manager = SubscriptionManager::new();
+        let viewer = PlayerId::new();
+        let (target, bystander) = (GorcObjectId::new(), GorcObjectId::new());
+        let far_behind = Position::new(-2000.0, 0.0, 0.0);
+        manager.add_player(viewer, Position::new(0.0, 0.0, 0.0)).await;
+        manager.update_player_facing(viewer, Position::new(1.0, 0.0, 0.0)).await;
+
+        // Behind the viewer, the target starts below its base priority and frequency
+        let base = ReplicationPriority::Normal;
+        assert_eq!(manager.get_object_priority(viewer, target, far_behind, base).await, ReplicationPriority::Low);
+        assert_eq!(manager.get_object_frequency_scale(viewer, target, far_behind).await, 0.5);
+
+        assert!(manager.set_focus_target(viewer, Some(target)).await);
+        assert!(!manager.set_focus_target(PlayerId::new(), Some(target)).await);
+        assert_eq!(manager.get_focus_target(viewer).await, Some(target));
+        assert_eq!(manager.get_object_priority(viewer, target, far_behind, base).await, ReplicationPriority::Critical);
+        assert_eq!(manager.get_object_priority(viewer, bystander, far_behind, base).await, ReplicationPriority::Low);
+        assert_eq!(manager.get_object_frequency_scale(viewer, target, far_behind).await, 2.0);
+
+        // Switching focus drops the boost for the previous target
+        assert!(manager.set_focus_target(viewer, Some(bystander)).await);
+        assert_eq!(manager.get_object_priority(viewer, target, far_behind, base).await, ReplicationPriority::Low);
+        assert_eq!(manager.get_object_priority(viewer, bystander, far_behind, base).await, ReplicationPriority::Critical);
+
+        manager.set_focus_target(viewer, None).await;
+        assert_eq!(manager.get_focus_target(viewer).await, None);
+        assert_eq!(manager.get_interest_level(viewer, &bystander.to_string()).await, InterestLevel::None);
+    }
+}
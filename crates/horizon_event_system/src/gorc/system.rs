@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use super::{
     GorcInstanceManager, NetworkReplicationEngine, ReplicationCoordinator,
-    GorcObjectId, GorcObject, NetworkError, ReplicationStats, utils
+    GorcObjectId, GorcObject, NetworkError, ReplicationPriority, ReplicationStats, utils
 };
 
 /// Current version of the GORC system
@@ -10,6 +10,10 @@ pub const GORC_VERSION: &str = "1.0.0";
 /// Maximum number of replication channels supported
 pub const MAX_CHANNELS: u8 = 4;
 
+/// Channel whose updates reach every connected player regardless of zones,
+/// e.g. server announcements and world boss spawns
+pub const BROADCAST_CHANNEL: u8 = 255;
+
 /// Complete GORC system with all components.
 /// 
 /// This struct provides a high-level interface to the entire GORC system,
@@ -128,6 +132,21 @@ impl CompleteGorcSystem {
     pub async fn tick(&mut self) -> Result<(), NetworkError> {
         self.coordinator.tick().await
     }
+
+    /// Broadcasts an event to every connected player on the broadcast channel.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `event_type` - Name clients receive the event under
+    /// * `data` - Serialized event payload
+    /// * `priority` - Queue priority of the event
+    /// 
+    /// # Returns
+    /// 
+    /// The number of players the event was queued for.
+    pub async fn broadcast(&mut self, event_type: &str, data: Vec<u8>, priority: ReplicationPriority) -> usize {
+        self.coordinator.broadcast(event_type, data, priority).await
+    }
     
     /// Gets comprehensive system statistics.
     /// 
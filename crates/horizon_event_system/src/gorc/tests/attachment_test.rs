@@ -0,0 +1,196 @@
+//! Tests for parent/child object attachments
+//!
+//! Verifies that attached objects follow their parents (including children
+//! of children), that their zones move with them, that cycles are rejected,
+//! and that attach/detach events reach plugins and subscribed clients.
+
+use crate::events::{GorcObjectAttachedEvent, GorcObjectDetachedEvent};
+use crate::gorc::instance::{AttachmentError, GorcInstanceManager, GorcObjectId, ObjectAttachment};
+use crate::system::{ClientResponseSender, EventSystem};
+use crate::types::{PlayerId, Vec3};
+use crate::GorcObject;
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+
+/// Object with a single 50m zone on channel 0
+#[derive(Debug, Clone, GorcObject)]
+struct TestPart {
+    #[gorc(channel = 0, radius = 50.0, frequency = 30.0)]
+    position: Vec3,
+}
+
+#[derive(Debug, Default)]
+struct RecordingSender {
+    sent: tokio::sync::Mutex<Vec<(PlayerId, Vec<u8>)>>,
+}
+
+impl RecordingSender {
+    async fn messages_for(&self, player_id: PlayerId) -> Vec<Value> {
+        self.sent
+            .lock()
+            .await
+            .iter()
+            .filter(|(to, _)| *to == player_id)
+            .filter_map(|(_, data)| serde_json::from_slice::<Value>(data).ok())
+            .collect()
+    }
+}
+
+impl ClientResponseSender for RecordingSender {
+    fn send_to_client(&self, player_id: PlayerId, data: Vec<u8>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + '_>> {
+        Box::pin(async move {
+            self.sent.lock().await.push((player_id, data));
+            Ok(())
+        })
+    }
+
+    fn is_connection_active(&self, _player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = bool> + Send + '_>> {
+        Box::pin(async move { true })
+    }
+
+    fn get_auth_status(&self, _player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<crate::types::AuthenticationStatus>> + Send + '_>> {
+        Box::pin(async move { Some(crate::types::AuthenticationStatus::Authenticated) })
+    }
+
+    fn kick(&self, _player_id: PlayerId, _reason: Option<String>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + '_>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn broadcast_to_all(&self, _data: Vec<u8>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, String>> + Send + '_>> {
+        Box::pin(async move { Ok(0) })
+    }
+}
+
+async fn register_part(manager: &GorcInstanceManager, position: Vec3) -> GorcObjectId {
+    manager.register_object(TestPart { position }, position).await
+}
+
+#[tokio::test]
+async fn test_manager_tracks_attachment_hierarchy() {
+    let manager = GorcInstanceManager::new();
+    let ship = register_part(&manager, Vec3::new(0.0, 0.0, 0.0)).await;
+    let turret = register_part(&manager, Vec3::new(0.0, 0.0, 0.0)).await;
+    let gunner = register_part(&manager, Vec3::new(0.0, 0.0, 0.0)).await;
+
+    let offset = Vec3::new(10.0, 0.0, 0.0);
+    assert_eq!(manager.attach_object(turret, ship, offset).await, Ok(None));
+    assert_eq!(manager.attach_object(gunner, turret, Vec3::new(0.0, 1.0, 0.0)).await, Ok(None));
+    assert_eq!(manager.attach_object(ship, gunner, offset).await, Err(AttachmentError::Cycle { child: ship, parent: gunner }));
+    assert_eq!(manager.attach_object(ship, ship, offset).await, Err(AttachmentError::SelfAttachment(ship)));
+    let unknown = GorcObjectId::new();
+    assert_eq!(manager.attach_object(unknown, ship, offset).await, Err(AttachmentError::UnknownObject(unknown)));
+
+    // Positions are derived down the hierarchy, parents before children
+    manager.update_object_position(ship, Vec3::new(100.0, 0.0, 0.0)).await;
+    assert_eq!(
+        manager.attached_positions(&[ship]).await,
+        vec![(turret, Vec3::new(110.0, 0.0, 0.0)), (gunner, Vec3::new(110.0, 1.0, 0.0))]
+    );
+    assert_eq!(manager.get_attached_children(ship).await, vec![turret]);
+
+    // Unregistering a parent releases its children where they are
+    assert!(manager.unregister_object(turret).await);
+    assert_eq!(manager.get_attachment(gunner).await, None);
+    assert!(manager.get_attached_children(ship).await.is_empty());
+    assert!(manager.attached_positions(&[ship]).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_attached_objects_follow_parent_zones() {
+    let manager = Arc::new(GorcInstanceManager::new());
+    let sender = Arc::new(RecordingSender::default());
+    let mut events = EventSystem::with_gorc(manager.clone());
+    events.set_client_response_sender(sender.clone());
+
+    let attached = Arc::new(Mutex::new(Vec::new()));
+    let detached = Arc::new(Mutex::new(Vec::new()));
+    let (recorded_attached, recorded_detached) = (attached.clone(), detached.clone());
+    events
+        .on_core("gorc_object_attached", move |event: GorcObjectAttachedEvent| {
+            recorded_attached.lock().unwrap().push(event);
+            Ok(())
+        })
+        .await
+        .unwrap();
+    events
+        .on_core("gorc_object_detached", move |event: GorcObjectDetachedEvent| {
+            recorded_detached.lock().unwrap().push(event);
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    let ship = register_part(&manager, Vec3::new(0.0, 0.0, 0.0)).await;
+    let turret = register_part(&manager, Vec3::new(1000.0, 0.0, 0.0)).await;
+    let gunner = register_part(&manager, Vec3::new(-1000.0, 0.0, 0.0)).await;
+    let player = PlayerId::new();
+    events.update_player_position(player, Vec3::new(5.0, 0.0, 0.0)).await.unwrap();
+
+    // Attaching moves the turret onto the ship, into the player's range
+    let offset = Vec3::new(10.0, 0.0, 0.0);
+    assert_eq!(events.attach_object(turret, ship, offset).await.unwrap(), None);
+    assert_eq!(manager.get_object_position(turret).await, Some(offset));
+    assert!(manager.get_object(turret).await.unwrap().is_subscribed(0, player));
+    let messages = sender.messages_for(player).await;
+    let kinds: Vec<&str> = messages
+        .iter()
+        .map(|message| message["type"].as_str().or(message["event_type"].as_str()).unwrap_or_default())
+        .collect();
+    assert_eq!(kinds, vec!["gorc_zone_enter", "gorc_zone_enter", "attached"]);
+    assert_eq!(messages[2]["object_id"], turret.to_string());
+    assert_eq!(messages[2]["data"]["parent_id"], ship.0.to_string());
+    assert_eq!(attached.lock().unwrap()[0].parent_id, ship);
+
+    // Children of children follow single and batch parent moves
+    events.attach_object(gunner, turret, Vec3::new(0.0, 1.0, 0.0)).await.unwrap();
+    assert_eq!(manager.get_object_position(gunner).await, Some(Vec3::new(10.0, 1.0, 0.0)));
+    events.update_object_position(ship, Vec3::new(2000.0, 0.0, 0.0)).await.unwrap();
+    assert_eq!(manager.get_object_position(turret).await, Some(Vec3::new(2010.0, 0.0, 0.0)));
+    assert_eq!(manager.get_object_position(gunner).await, Some(Vec3::new(2010.0, 1.0, 0.0)));
+    assert!(!manager.get_object(turret).await.unwrap().is_subscribed(0, player));
+    events.update_object_positions(&[(ship, Vec3::new(0.0, 0.0, 0.0))]).await.unwrap();
+    assert_eq!(manager.get_object_position(gunner).await, Some(Vec3::new(10.0, 1.0, 0.0)));
+    assert!(manager.get_object(gunner).await.unwrap().is_subscribed(0, player));
+
+    // Detached objects stay behind
+    assert_eq!(events.detach_object(turret).await.unwrap(), Some(ObjectAttachment { parent_id: ship, offset }));
+    assert_eq!(events.detach_object(turret).await.unwrap(), None);
+    assert_eq!(detached.lock().unwrap().len(), 1);
+    assert_eq!(sender.messages_for(player).await.pop().unwrap()["event_type"], "detached");
+    events.update_object_position(ship, Vec3::new(500.0, 0.0, 0.0)).await.unwrap();
+    assert_eq!(manager.get_object_position(turret).await, Some(offset));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_attach_and_position_reads_make_progress() {
+    let manager = Arc::new(GorcInstanceManager::new());
+    let origin = Vec3::new(0.0, 0.0, 0.0);
+    let ship = manager.register_object(TestPart { position: origin }, origin).await;
+    let mut parts = Vec::new();
+    for _ in 0..8 {
+        parts.push(manager.register_object(TestPart { position: origin }, origin).await);
+    }
+
+    // Attaching while positions are derived must never wait on each other forever
+    let attaching = {
+        let manager = manager.clone();
+        tokio::spawn(async move {
+            for round in 0..200 {
+                for &part in &parts {
+                    manager.attach_object(part, ship, Vec3::new(round as f64, 0.0, 0.0)).await.unwrap();
+                }
+            }
+        })
+    };
+    let reading = tokio::spawn(async move {
+        for _ in 0..2000 {
+            manager.attached_positions(&[ship]).await;
+        }
+    });
+    tokio::time::timeout(std::time::Duration::from_secs(10), async {
+        attaching.await.unwrap();
+        reading.await.unwrap();
+    })
+    .await
+    .expect("attaching and reading attachments deadlocked");
+}
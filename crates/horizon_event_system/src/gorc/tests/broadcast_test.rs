@@ -0,0 +1,42 @@
+//! Tests for the global broadcast channel
+//!
+//! Verifies that broadcast events reach every connected player regardless
+//! of zones, through the network engine's queues and batches.
+
+use crate::gorc::channels::ReplicationPriority;
+use crate::gorc::network::ReplayServerContext;
+use crate::gorc::utils::create_complete_gorc_system;
+use crate::types::{PlayerId, Vec3};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_broadcast_reaches_every_player() {
+    let luminal_rt = luminal::Runtime::new().expect("Failed to create luminal runtime");
+    let context = Arc::new(ReplayServerContext::new(luminal_rt.handle().clone()));
+    let mut gorc_system = create_complete_gorc_system(context.clone()).unwrap();
+
+    // Players far apart, with no objects or zones between them
+    let players: Vec<PlayerId> = (0..3).map(|_| PlayerId::new()).collect();
+    for (i, player_id) in players.iter().enumerate() {
+        gorc_system.add_player(*player_id, Vec3::new(i as f64 * 50_000.0, 0.0, 0.0)).await;
+    }
+
+    let announcement = br#"{"boss":"Leviathan"}"#.to_vec();
+    let queued = gorc_system
+        .broadcast("world_boss_spawned", announcement.clone(), ReplicationPriority::High)
+        .await;
+    assert_eq!(queued, 3);
+    assert_eq!(gorc_system.network_engine.get_stats().await.broadcasts_queued, 3);
+
+    for player_id in &players {
+        gorc_system.network_engine.flush_player(*player_id).await.unwrap();
+    }
+    let traffic = context.traffic();
+    for player_id in &players {
+        assert_eq!(traffic[player_id].0, 1, "player {} missed the broadcast", player_id);
+    }
+
+    // Players that left no longer receive broadcasts
+    gorc_system.remove_player(players[0]).await;
+    assert_eq!(gorc_system.broadcast("server_restart", announcement, ReplicationPriority::Critical).await, 2);
+}
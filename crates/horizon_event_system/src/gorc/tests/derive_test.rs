@@ -0,0 +1,129 @@
+//! Tests for `#[derive(GorcObject)]`
+//!
+//! Verifies that the derive builds one layer per tagged channel, serializes
+//! only that channel's fields, and wires the position field through the
+//! instance manager.
+
+use crate::gorc::channels::{CompressionType, ReplicationLayer, ReplicationPriority};
+use crate::gorc::instance::GorcInstanceManager;
+use crate::types::{PlayerId, Vec3};
+use crate::GorcObject;
+
+#[derive(Debug, Clone, GorcObject)]
+#[gorc(type_name = "Freighter")]
+struct TestFreighter {
+    #[gorc(channel = 0)]
+    position: Vec3,
+    #[gorc(channel = 0)]
+    heading: f32,
+    #[gorc(channel = 2, radius = 400.0, frequency = 5)]
+    cargo: Vec<String>,
+    #[gorc(channel = 2)]
+    captain: String,
+    docked_at: Option<PlayerId>,
+}
+
+#[derive(Debug, Clone, GorcObject)]
+struct TestBuoy {
+    #[gorc(position, channel = 1)]
+    anchor: Vec3,
+}
+
+#[derive(Debug, Clone, serde::Serialize, GorcObject)]
+#[gorc(layers = wreck_layers, snapshot)]
+struct TestWreck {
+    #[gorc(channel = 1)]
+    position: Vec3,
+    #[gorc(tags)]
+    tags: Vec<String>,
+}
+
+fn wreck_layers(_wreck: &TestWreck) -> Vec<ReplicationLayer> {
+    vec![ReplicationLayer::new(1, 75.0, 2.0, vec!["position".to_string()], CompressionType::None).with_exit_margin(10.0)]
+}
+
+fn freighter() -> TestFreighter {
+    TestFreighter {
+        position: Vec3::new(10.0, 0.0, 20.0),
+        heading: 90.0,
+        cargo: vec!["ore".to_string()],
+        captain: "Vasquez".to_string(),
+        docked_at: None,
+    }
+}
+
+#[test]
+fn test_derive_builds_layers_per_channel() {
+    let freighter = freighter();
+    assert_eq!(freighter.type_name(), "Freighter");
+    assert_eq!(freighter.position(), Vec3::new(10.0, 0.0, 20.0));
+
+    let layers = freighter.get_layers();
+    assert_eq!(layers.len(), 2);
+
+    // Channel 0 keeps the default zone config
+    let (radius, frequency, compression, _) = crate::__get_default_zone_config(0);
+    assert_eq!(layers[0].channel, 0);
+    assert_eq!((layers[0].radius, layers[0].frequency), (radius, frequency));
+    assert_eq!(layers[0].compression, compression);
+    assert_eq!(layers[0].properties, vec!["position", "heading"]);
+
+    // Overrides on any field apply to the whole channel
+    assert_eq!(layers[1].channel, 2);
+    assert_eq!((layers[1].radius, layers[1].frequency), (400.0, 5.0));
+    assert_eq!(layers[1].compression, CompressionType::Lz4);
+    assert_eq!(layers[1].properties, vec!["cargo", "captain"]);
+
+    assert_eq!(freighter.get_priority(Vec3::new(10.0, 0.0, 70.0)), ReplicationPriority::Critical);
+}
+
+#[test]
+fn test_derive_serializes_only_channel_fields() {
+    let freighter = freighter();
+    let layers = freighter.get_layers();
+
+    let critical: serde_json::Value = serde_json::from_slice(&freighter.serialize_for_layer(&layers[0]).unwrap()).unwrap();
+    assert_eq!(critical["heading"], 90.0);
+    assert_eq!(critical["position"]["x"], 10.0);
+    assert!(critical.get("cargo").is_none());
+    assert!(critical.get("docked_at").is_none() && freighter.docked_at.is_none());
+
+    let detail: serde_json::Value = serde_json::from_slice(&freighter.serialize_for_layer(&layers[1]).unwrap()).unwrap();
+    assert_eq!(detail, serde_json::json!({ "cargo": ["ore"], "captain": "Vasquez" }));
+
+    let mut unknown = layers[0].clone();
+    unknown.channel = 3;
+    assert!(freighter.serialize_for_layer(&unknown).is_err());
+}
+
+#[test]
+fn test_derive_custom_layers_tags_and_snapshot() {
+    let wreck = TestWreck { position: Vec3::new(1.0, 2.0, 3.0), tags: vec!["salvage".to_string()] };
+    let layers = wreck.get_layers();
+    assert_eq!(layers.len(), 1);
+    assert_eq!((layers[0].channel, layers[0].radius, layers[0].exit_margin), (1, 75.0, Some(10.0)));
+    assert_eq!(wreck.tags(), ["salvage"]);
+
+    let payload: serde_json::Value = serde_json::from_slice(&wreck.serialize_for_layer(&layers[0]).unwrap()).unwrap();
+    assert_eq!(payload, serde_json::json!({ "position": { "x": 1.0, "y": 2.0, "z": 3.0 } }));
+    assert_eq!(wreck.snapshot_state().unwrap()["tags"], serde_json::json!(["salvage"]));
+
+    // Objects without the attribute stay out of snapshots
+    assert!(freighter().snapshot_state().is_none());
+    assert!(freighter().tags().is_empty());
+}
+
+#[tokio::test]
+async fn test_derived_object_with_marked_position() {
+    let manager = GorcInstanceManager::new();
+    let anchor = Vec3::new(5.0, 0.0, 5.0);
+    let buoy = manager.register_object(TestBuoy { anchor }, anchor).await;
+
+    let moved = Vec3::new(40.0, 0.0, 5.0);
+    manager.update_object_position(buoy, moved).await.unwrap();
+
+    let instance = manager.get_object(buoy).await.unwrap();
+    assert_eq!(instance.type_name, "TestBuoy");
+    assert_eq!(instance.get_object::<TestBuoy>().unwrap().anchor, moved);
+    assert_eq!(instance.object.get_layers()[0].properties, vec!["anchor"]);
+}
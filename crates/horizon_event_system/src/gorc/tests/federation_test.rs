@@ -0,0 +1,274 @@
+//! Tests for GORC region federation
+//!
+//! Two region servers sharing the x = 0 edge are wired together with an
+//! in-memory link. Objects crossing the edge should be mirrored as ghosts
+//! while in the overlap band and handed off once they leave their region.
+
+use crate::gorc::federation::{FederationError, FederationMessage, NeighborRegion, PlayerSession, RegionFederation, RegionLink};
+use crate::gorc::instance::{GorcInstanceManager, ObjectAuthority};
+use crate::types::{AuthenticationStatus, PlayerId, RegionBounds, Vec3};
+use crate::GorcObject;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, Serialize, Deserialize, GorcObject)]
+#[gorc(snapshot)]
+struct TestCargo {
+    #[gorc(channel = 0, radius = 100.0, frequency = 10.0)]
+    position: Vec3,
+    crates: u32,
+}
+
+/// Link that queues outgoing messages so the test can deliver them
+#[derive(Debug, Default)]
+struct QueuedLink {
+    outbox: Mutex<Vec<(String, FederationMessage)>>,
+}
+
+impl QueuedLink {
+    fn drain(&self) -> Vec<(String, FederationMessage)> {
+        std::mem::take(&mut *self.outbox.lock().unwrap())
+    }
+}
+
+#[async_trait]
+impl RegionLink for QueuedLink {
+    async fn send(&self, neighbor: &str, message: FederationMessage) -> Result<(), FederationError> {
+        self.outbox.lock().unwrap().push((neighbor.to_string(), message));
+        Ok(())
+    }
+}
+
+fn bounds(min_x: f64, max_x: f64) -> RegionBounds {
+    RegionBounds { min_x, max_x, ..RegionBounds::default() }
+}
+
+struct Region {
+    manager: Arc<GorcInstanceManager>,
+    link: Arc<QueuedLink>,
+    federation: RegionFederation,
+}
+
+async fn region(own: RegionBounds, neighbor: &str, neighbor_bounds: RegionBounds) -> Region {
+    let manager = Arc::new(GorcInstanceManager::new());
+    manager.register_snapshot_type::<TestCargo>("TestCargo").await;
+    let link = Arc::new(QueuedLink::default());
+    let federation = RegionFederation::new(
+        own,
+        vec![NeighborRegion { name: neighbor.to_string(), bounds: neighbor_bounds }],
+        manager.clone(),
+        link.clone(),
+    )
+    .with_overlap_band(50.0);
+    Region { manager, link, federation }
+}
+
+/// Delivers everything `from` has queued to `to`
+async fn deliver(from: &Region, from_name: &str, to: &Region) -> usize {
+    let messages = from.link.drain();
+    let count = messages.len();
+    for (_, message) in messages {
+        to.federation.apply(from_name, message).await.unwrap();
+    }
+    count
+}
+
+#[tokio::test]
+async fn test_object_crossing_region_edge() {
+    let west = region(bounds(-1000.0, 0.0), "east", bounds(0.0, 1000.0)).await;
+    let east = region(bounds(0.0, 1000.0), "west", bounds(-1000.0, 0.0)).await;
+
+    let start = Vec3::new(-500.0, 0.0, 0.0);
+    let cargo = west.manager.register_object(TestCargo { position: start, crates: 12 }, start).await;
+
+    // Deep inside the west region nothing is shared
+    let report = west.federation.scan().await;
+    assert_eq!(report.ghost_updates_sent + report.handoffs_sent, 0);
+
+    // Inside the overlap band the east region gets a ghost copy
+    west.manager.update_object_position(cargo, Vec3::new(-20.0, 0.0, 0.0)).await;
+    assert_eq!(west.federation.scan().await.ghost_updates_sent, 1);
+    assert_eq!(deliver(&west, "west", &east).await, 1);
+    assert!(east.federation.is_ghost(cargo).await);
+    assert_eq!(east.manager.get_object_position(cargo).await, Some(Vec3::new(-20.0, 0.0, 0.0)));
+
+    // Crossing the edge hands authority to the east region
+    let pilot = ObjectAuthority::Player(PlayerId::new());
+    west.manager.set_object_authority(cargo, pilot).await;
+    west.manager.update_object_position(cargo, Vec3::new(30.0, 0.0, 0.0)).await;
+    assert_eq!(west.federation.scan().await.handoffs_sent, 1);
+    deliver(&west, "west", &east).await;
+    assert!(!east.federation.is_ghost(cargo).await);
+    assert!(west.federation.is_ghost(cargo).await);
+    assert_eq!(east.manager.get_object_authority(cargo).await, Some(pilot));
+    assert_eq!(
+        east.manager.with_object(cargo, |cargo: &TestCargo| cargo.crates).await,
+        Some(12)
+    );
+
+    // The new owner keeps the west region's ghost in sync while in the band
+    assert_eq!(east.federation.scan().await.ghost_updates_sent, 1);
+    deliver(&east, "east", &west).await;
+    assert_eq!(west.manager.get_object_position(cargo).await, Some(Vec3::new(30.0, 0.0, 0.0)));
+
+    // Leaving the band removes the ghost
+    east.manager.update_object_position(cargo, Vec3::new(300.0, 0.0, 0.0)).await;
+    assert_eq!(east.federation.scan().await.ghost_removals_sent, 1);
+    deliver(&east, "east", &west).await;
+    assert!(west.manager.get_object(cargo).await.is_none());
+    assert!(east.manager.get_object(cargo).await.is_some());
+    assert_eq!(west.federation.get_stats().await.active_ghosts, 0);
+}
+
+#[tokio::test]
+async fn test_ghost_moves_between_neighbor_bands() {
+    let manager = Arc::new(GorcInstanceManager::new());
+    manager.register_snapshot_type::<TestCargo>("TestCargo").await;
+    let link = Arc::new(QueuedLink::default());
+    let center = RegionFederation::new(
+        bounds(0.0, 1000.0),
+        vec![
+            NeighborRegion { name: "west".to_string(), bounds: bounds(-1000.0, 0.0) },
+            NeighborRegion { name: "east".to_string(), bounds: bounds(1000.0, 2000.0) },
+        ],
+        manager.clone(),
+        link.clone(),
+    )
+    .with_overlap_band(50.0);
+
+    let start = Vec3::new(20.0, 0.0, 0.0);
+    let cargo = manager.register_object(TestCargo { position: start, crates: 3 }, start).await;
+    assert_eq!(center.scan().await.ghost_updates_sent, 1);
+    let sent: Vec<String> = link.drain().into_iter().map(|(neighbor, _)| neighbor).collect();
+    assert_eq!(sent, ["west"]);
+
+    // Straight from the west band into the east one
+    manager.update_object_position(cargo, Vec3::new(980.0, 0.0, 0.0)).await;
+    let report = center.scan().await;
+    assert_eq!((report.ghost_updates_sent, report.ghost_removals_sent), (1, 1));
+    let sent = link.drain();
+    assert!(matches!(&sent[0], (neighbor, FederationMessage::GhostUpdate { .. }) if neighbor == "east"));
+    assert!(matches!(&sent[1], (neighbor, FederationMessage::GhostRemove { object_id }) if neighbor == "west" && *object_id == cargo));
+
+    // Staying in the east band only updates the east ghost
+    let report = center.scan().await;
+    assert_eq!((report.ghost_updates_sent, report.ghost_removals_sent), (1, 0));
+}
+
+#[tokio::test]
+async fn test_players_leaving_region_are_reported_once() {
+    let west = region(bounds(-1000.0, 0.0), "east", bounds(0.0, 1000.0)).await;
+    let player = PlayerId::new();
+    west.manager.add_player(player, Vec3::new(-10.0, 0.0, 0.0)).await;
+    assert!(west.federation.scan().await.players_leaving.is_empty());
+
+    let crossed = Vec3::new(10.0, 0.0, 0.0);
+    west.manager.update_player_position(player, crossed).await;
+    assert_eq!(west.federation.scan().await.players_leaving, vec![(player, "east".to_string(), crossed)]);
+    assert!(west.federation.scan().await.players_leaving.is_empty());
+}
+
+#[tokio::test]
+async fn test_player_handoff_moves_session_and_objects() {
+    let west = region(bounds(-1000.0, 0.0), "east", bounds(0.0, 1000.0)).await;
+    let east = region(bounds(0.0, 1000.0), "west", bounds(-1000.0, 0.0)).await;
+
+    let player = PlayerId::new();
+    let crossed = Vec3::new(10.0, 0.0, 0.0);
+    let ship = west.manager.register_object(TestCargo { position: crossed, crates: 3 }, crossed).await;
+    let hold = west.manager.register_object(TestCargo { position: crossed, crates: 40 }, crossed).await;
+    west.manager.register_object_owner(player, ship).await;
+    west.manager.attach_object(hold, ship, Vec3::new(0.0, -2.0, 0.0)).await.unwrap();
+
+    let session = PlayerSession { player_id: player, position: crossed, auth_status: AuthenticationStatus::Authenticated };
+    let ticket = west.federation.hand_off_player("east", session.clone()).await.unwrap();
+    assert_eq!(deliver(&west, "west", &east).await, 1);
+    assert!(west.federation.is_ghost(ship).await && west.federation.is_ghost(hold).await);
+
+    // The neighbor holds the player until its client presents the ticket
+    assert_eq!(east.federation.get_stats().await.pending_arrivals, 1);
+    assert_eq!(east.manager.find_player_object(player).await, Some(ship));
+    assert_eq!(east.manager.get_object_authority(ship).await, Some(ObjectAuthority::Player(player)));
+    assert_eq!(east.manager.get_attachment(hold).await.map(|attachment| attachment.parent_id), Some(ship));
+    assert_eq!(east.manager.with_object(hold, |cargo: &TestCargo| cargo.crates).await, Some(40));
+
+    assert!(east.federation.claim_arrival("unknown").await.is_none());
+    let arrival = east.federation.claim_arrival(&ticket).await.unwrap();
+    assert_eq!(arrival.source, "west");
+    assert_eq!(arrival.session, session);
+    assert_eq!(arrival.player_object, Some(ship));
+    assert!(east.federation.claim_arrival(&ticket).await.is_none());
+}
+
+#[tokio::test]
+async fn test_unclaimed_player_handoffs_expire() {
+    let west = region(bounds(-1000.0, 0.0), "east", bounds(0.0, 1000.0)).await;
+    let mut east = region(bounds(0.0, 1000.0), "west", bounds(-1000.0, 0.0)).await;
+    east.federation = east.federation.with_arrival_window(std::time::Duration::ZERO);
+
+    let player = PlayerId::new();
+    let crossed = Vec3::new(10.0, 0.0, 0.0);
+    let ship = west.manager.register_object(TestCargo { position: crossed, crates: 3 }, crossed).await;
+    west.manager.register_object_owner(player, ship).await;
+    let session = PlayerSession { player_id: player, position: crossed, auth_status: AuthenticationStatus::default() };
+    let ticket = west.federation.hand_off_player("east", session).await.unwrap();
+    deliver(&west, "west", &east).await;
+
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    assert!(east.federation.claim_arrival(&ticket).await.is_none());
+    assert_eq!(east.federation.scan().await.arrivals_expired, 1);
+    assert!(east.manager.get_object(ship).await.is_none());
+    assert_eq!(east.federation.get_stats().await.pending_arrivals, 0);
+}
+
+#[tokio::test]
+async fn test_messages_from_unknown_regions_are_rejected() {
+    let west = region(bounds(-1000.0, 0.0), "east", bounds(0.0, 1000.0)).await;
+    let result = west
+        .federation
+        .apply("north", FederationMessage::GhostRemove { object_id: crate::gorc::GorcObjectId::new() })
+        .await;
+    assert!(matches!(result, Err(FederationError::UnknownNeighbor(_))));
+}
+
+#[tokio::test]
+async fn test_neighbors_cannot_overwrite_objects_they_dont_own() {
+    let manager = Arc::new(GorcInstanceManager::new());
+    manager.register_snapshot_type::<TestCargo>("TestCargo").await;
+    let east = RegionFederation::new(
+        bounds(0.0, 1000.0),
+        vec![
+            NeighborRegion { name: "west".to_string(), bounds: bounds(-1000.0, 0.0) },
+            NeighborRegion { name: "north".to_string(), bounds: bounds(0.0, 1000.0) },
+        ],
+        manager.clone(),
+        Arc::new(QueuedLink::default()),
+    );
+
+    // An object owned by the east region can't be overwritten or taken by a neighbor
+    let position = Vec3::new(500.0, 0.0, 0.0);
+    let owner = ObjectAuthority::Player(PlayerId::new());
+    let cargo = manager.register_object(TestCargo { position, crates: 5 }, position).await;
+    manager.set_object_authority(cargo, owner).await;
+    let mut forged = manager.snapshot_object(cargo).await.unwrap();
+    forged.state = serde_json::json!({ "position": Vec3::new(-5.0, 0.0, 0.0), "crates": 0 });
+    let thief = ObjectAuthority::Player(PlayerId::new());
+
+    east.apply("west", FederationMessage::GhostUpdate { object: forged.clone() }).await.unwrap();
+    east.apply("west", FederationMessage::Handoff { object: forged.clone(), authority: thief }).await.unwrap();
+    assert_eq!(manager.with_object(cargo, |cargo: &TestCargo| cargo.crates).await, Some(5));
+    assert_eq!(manager.get_object_authority(cargo).await, Some(owner));
+    assert!(!east.is_ghost(cargo).await);
+
+    // Ghosts only take state from the neighbor that owns the object
+    let mut ghost = forged.clone();
+    ghost.object_id = crate::gorc::GorcObjectId::new();
+    east.apply("west", FederationMessage::GhostUpdate { object: ghost.clone() }).await.unwrap();
+    assert!(east.is_ghost(ghost.object_id).await);
+    east.apply("north", FederationMessage::Handoff { object: ghost.clone(), authority: thief }).await.unwrap();
+    assert!(east.is_ghost(ghost.object_id).await);
+    east.apply("west", FederationMessage::Handoff { object: ghost.clone(), authority: thief }).await.unwrap();
+    assert!(!east.is_ghost(ghost.object_id).await);
+    assert_eq!(manager.get_object_authority(ghost.object_id).await, Some(thief));
+}
@@ -0,0 +1,98 @@
+//! Tests for zone enter/exit hysteresis
+//!
+//! Verifies that players entering a zone at its radius are only removed once
+//! they pass the exit radius, and that boundary thrash is counted in stats.
+
+use crate::gorc::channels::{CompressionType, ReplicationLayer};
+use crate::gorc::instance::{GorcInstanceManager, GorcObjectId};
+use crate::types::{PlayerId, Vec3};
+use crate::GorcObject;
+
+/// Beacon with a single 100m zone and an optional per-layer exit margin
+#[derive(Debug, Clone, GorcObject)]
+#[gorc(layers = beacon_layers)]
+struct TestBeacon {
+    #[gorc(channel = 0)]
+    position: Vec3,
+    exit_margin: Option<f64>,
+}
+
+fn beacon_layers(beacon: &TestBeacon) -> Vec<ReplicationLayer> {
+    let layer = ReplicationLayer::new(0, 100.0, 30.0, vec!["position".to_string()], CompressionType::None);
+    vec![match beacon.exit_margin {
+        Some(margin) => layer.with_exit_margin(margin),
+        None => layer,
+    }]
+}
+
+async fn register_beacon(manager: &GorcInstanceManager, exit_margin: Option<f64>) -> GorcObjectId {
+    let origin = Vec3::new(0.0, 0.0, 0.0);
+    manager.register_object(TestBeacon { position: origin, exit_margin }, origin).await
+}
+
+async fn is_subscribed(manager: &GorcInstanceManager, object_id: GorcObjectId, player_id: PlayerId) -> bool {
+    manager.get_object(object_id).await.unwrap().is_subscribed(0, player_id)
+}
+
+#[tokio::test]
+async fn test_exit_margin_keeps_subscription() {
+    let manager = GorcInstanceManager::new().with_zone_exit_margin(0.1);
+    let beacon = register_beacon(&manager, None).await;
+    let player = PlayerId::new();
+
+    let (entries, _) = manager.update_player_position(player, Vec3::new(95.0, 0.0, 0.0)).await;
+    assert_eq!(entries, vec![(beacon, 0)]);
+
+    // Between the enter radius (100m) and exit radius (110m) nothing changes
+    for x in [105.0, 109.0, 98.0, 108.0] {
+        let (entries, exits) = manager.update_player_position(player, Vec3::new(x, 0.0, 0.0)).await;
+        assert!(entries.is_empty() && exits.is_empty(), "zone change at {x}m");
+        assert!(is_subscribed(&manager, beacon, player).await);
+    }
+
+    let (_, exits) = manager.update_player_position(player, Vec3::new(111.0, 0.0, 0.0)).await;
+    assert_eq!(exits, vec![(beacon, 0)]);
+    assert!(!is_subscribed(&manager, beacon, player).await);
+
+    // Re-entry still requires crossing the enter radius
+    let (entries, _) = manager.update_player_position(player, Vec3::new(105.0, 0.0, 0.0)).await;
+    assert!(entries.is_empty());
+
+    let stats = manager.get_stats().await;
+    assert_eq!(stats.hysteresis_retained, 3);
+    assert_eq!(stats.zone_thrash_events, 0);
+}
+
+#[tokio::test]
+async fn test_layer_margin_overrides_default_and_counts_thrash() {
+    let manager = GorcInstanceManager::new().with_zone_exit_margin(0.5);
+    let beacon = register_beacon(&manager, Some(0.0)).await;
+    let player = PlayerId::new();
+
+    // Without a margin every boundary crossing is a transition
+    for _ in 0..3 {
+        manager.update_player_position(player, Vec3::new(99.0, 0.0, 0.0)).await;
+        assert!(is_subscribed(&manager, beacon, player).await);
+        manager.update_player_position(player, Vec3::new(101.0, 0.0, 0.0)).await;
+        assert!(!is_subscribed(&manager, beacon, player).await);
+    }
+
+    let stats = manager.get_stats().await;
+    assert_eq!(stats.zone_thrash_events, 2);
+    assert_eq!(stats.hysteresis_retained, 0);
+}
+
+#[tokio::test]
+async fn test_object_movement_respects_exit_margin() {
+    let manager = GorcInstanceManager::new().with_zone_exit_margin(0.1);
+    let beacon = register_beacon(&manager, None).await;
+    let player = PlayerId::new();
+    manager.update_player_position(player, Vec3::new(0.0, 0.0, 0.0)).await;
+
+    let (_, _, changes) = manager.update_object_position(beacon, Vec3::new(105.0, 0.0, 0.0)).await.unwrap();
+    assert!(changes.is_empty());
+    assert!(is_subscribed(&manager, beacon, player).await);
+
+    let (_, _, changes) = manager.update_object_position(beacon, Vec3::new(120.0, 0.0, 0.0)).await.unwrap();
+    assert_eq!(changes, vec![(player, 0, false)]);
+}
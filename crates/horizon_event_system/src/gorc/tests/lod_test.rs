@@ -0,0 +1,203 @@
+//! Tests for LOD-aware payload trimming
+//!
+//! Verifies that layer LOD tiers are selected by subscriber distance, and
+//! that distant subscribers receive trimmed, quantized payloads on the same
+//! channel while nearby subscribers keep the full payload.
+
+use crate::gorc::channels::{CompressionType, LodTier, ReplicationLayer};
+use crate::gorc::instance::GorcInstanceManager;
+use crate::gorc::multicast::LodLevel;
+use crate::system::{ClientResponseSender, EventSystem};
+use crate::types::{PlayerId, Vec3};
+use crate::GorcObject;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, GorcObject)]
+#[gorc(layers = ship_layers)]
+struct Ship {
+    #[gorc(channel = 0)]
+    position: Vec3,
+    #[gorc(channel = 0)]
+    velocity: Vec3,
+    #[gorc(channel = 0)]
+    hull: u32,
+}
+
+fn ship(position: Vec3) -> Ship {
+    Ship { position, velocity: Vec3::new(1.5, 0.0, 0.0), hull: 87 }
+}
+
+fn ship_layers(_ship: &Ship) -> Vec<ReplicationLayer> {
+    vec![ship_layer()]
+}
+
+/// Full detail within 100m, position only (to the nearest 10m) beyond
+fn ship_layer() -> ReplicationLayer {
+    ReplicationLayer::new(0, 500.0, 30.0, vec!["position".to_string(), "velocity".to_string()], CompressionType::None)
+        .with_lod_tier(
+            LodTier::new(LodLevel::Low)
+                .with_min_distance(100.0)
+                .with_properties(["position"])
+                .with_position_precision(10.0),
+        )
+}
+
+#[derive(Debug, Default)]
+struct RecordingSender {
+    sent: tokio::sync::Mutex<Vec<(PlayerId, Vec<u8>)>>,
+}
+
+impl RecordingSender {
+    async fn payloads_for(&self, player_id: PlayerId, kind: &str) -> Vec<Value> {
+        self.sent
+            .lock()
+            .await
+            .iter()
+            .filter(|(to, _)| *to == player_id)
+            .filter_map(|(_, data)| serde_json::from_slice::<Value>(data).ok())
+            .filter_map(|message| match kind {
+                "zone_enter" if message["type"] == "gorc_zone_enter" => Some(message["zone_data"].clone()),
+                "event" if message.get("event_type").is_some() => Some(message["data"].clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl ClientResponseSender for RecordingSender {
+    fn send_to_client(&self, player_id: PlayerId, data: Vec<u8>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + '_>> {
+        Box::pin(async move {
+            self.sent.lock().await.push((player_id, data));
+            Ok(())
+        })
+    }
+
+    fn is_connection_active(&self, _player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = bool> + Send + '_>> {
+        Box::pin(async move { true })
+    }
+
+    fn get_auth_status(&self, _player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<crate::types::AuthenticationStatus>> + Send + '_>> {
+        Box::pin(async move { Some(crate::types::AuthenticationStatus::Authenticated) })
+    }
+
+    fn kick(&self, _player_id: PlayerId, _reason: Option<String>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + '_>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn broadcast_to_all(&self, _data: Vec<u8>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, String>> + Send + '_>> {
+        Box::pin(async move { Ok(0) })
+    }
+}
+
+#[test]
+fn test_tier_selection_and_trimming() {
+    let layer = ReplicationLayer::new(0, 1200.0, 30.0, vec![], CompressionType::None)
+        .with_lod_tier(LodTier::new(LodLevel::Minimal).with_properties(["position"]))
+        .with_lod_tier(LodTier::new(LodLevel::Medium).with_position_precision(0.5));
+
+    // Levels start where the next higher level's radius ends
+    assert!(layer.lod_tier_for_distance(100.0).is_none());
+    assert_eq!(layer.lod_tier_for_distance(200.0).unwrap().level, LodLevel::Medium);
+    assert_eq!(layer.lod_tier_for_distance(700.0).unwrap().level, LodLevel::Minimal);
+
+    let payload = json!({ "position": [1.2, 2.7, -0.3], "hull": 87 });
+    let medium = layer.lod_tier_for_distance(200.0).unwrap().trim(payload.clone());
+    assert_eq!(medium, json!({ "position": [1.0, 2.5, -0.5], "hull": 87 }));
+
+    let minimal = layer.lod_tier_for_distance(700.0).unwrap().trim(payload);
+    assert_eq!(minimal, json!({ "position": [1.2, 2.7, -0.3] }));
+
+    // Payloads that aren't objects have nothing to trim
+    assert_eq!(LodTier::new(LodLevel::Low).with_properties(["x"]).trim(json!(5)), json!(5));
+}
+
+#[tokio::test]
+async fn test_distant_subscribers_receive_trimmed_payloads() {
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new());
+    let sender = Arc::new(RecordingSender::default());
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(sender.clone());
+
+    let object_id = gorc_manager
+        .register_object(ship(Vec3::new(3.0, 4.0, 0.0)), Vec3::new(3.0, 4.0, 0.0))
+        .await;
+
+    let near = PlayerId::new();
+    let far = PlayerId::new();
+    for player_id in [near, far] {
+        gorc_manager.add_player(player_id, Vec3::new(5000.0, 0.0, 0.0)).await;
+    }
+    events.update_player_position(near, Vec3::new(20.0, 0.0, 0.0)).await.unwrap();
+    events.update_player_position(far, Vec3::new(300.0, 0.0, 0.0)).await.unwrap();
+
+    // Zone entry state is trimmed for the distant player only
+    let near_entry = sender.payloads_for(near, "zone_enter").await;
+    let far_entry = sender.payloads_for(far, "zone_enter").await;
+    assert_eq!(near_entry, vec![json!({
+        "position": { "x": 3.0, "y": 4.0, "z": 0.0 },
+        "velocity": { "x": 1.5, "y": 0.0, "z": 0.0 },
+        "hull": 87
+    })]);
+    assert_eq!(far_entry, vec![json!({ "position": { "x": 0.0, "y": 0.0, "z": 0.0 } })]);
+
+    // So are instance events on the same channel
+    let update = json!({ "position": [13.0, 4.0, 0.0], "velocity": [10.0, 0.0, 0.0] });
+    events
+        .emit_gorc_instance(object_id, 0, "move", &update, crate::events::Dest::Client)
+        .await
+        .unwrap();
+    assert_eq!(sender.payloads_for(near, "event").await, vec![update]);
+    assert_eq!(sender.payloads_for(far, "event").await, vec![json!({ "position": [10.0, 0.0, 0.0] })]);
+}
+
+#[tokio::test]
+async fn test_batch_emission_trims_per_subscriber() {
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new());
+    let sender = Arc::new(RecordingSender::default());
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(sender.clone());
+
+    let mut ships = Vec::new();
+    for x in [0.0, 40.0] {
+        let position = Vec3::new(x, 0.0, 0.0);
+        ships.push(gorc_manager.register_object(ship(position), position).await);
+    }
+
+    // Near both ships, and more than 100m from both
+    let near = PlayerId::new();
+    let far = PlayerId::new();
+    for player_id in [near, far] {
+        gorc_manager.add_player(player_id, Vec3::new(5000.0, 0.0, 0.0)).await;
+    }
+    events.update_player_position(near, Vec3::new(20.0, 0.0, 0.0)).await.unwrap();
+    events.update_player_position(far, Vec3::new(300.0, 0.0, 0.0)).await.unwrap();
+
+    let mut sent_before = Vec::new();
+    for ship in &ships {
+        sent_before.push(gorc_manager.get_object(*ship).await.unwrap().stats.updates_sent);
+    }
+
+    let updates = vec![
+        (ships[0], json!({ "position": [1.0, 0.0, 0.0], "velocity": [1.0, 0.0, 0.0] })),
+        (crate::gorc::instance::GorcObjectId::new(), json!({ "position": [0.0, 0.0, 0.0] })),
+        (ships[1], json!({ "position": [44.0, 0.0, 0.0], "velocity": [2.0, 0.0, 0.0] })),
+    ];
+    let emitted = events
+        .emit_gorc_batch(0, "move", &updates, crate::events::Dest::Client)
+        .await
+        .unwrap();
+
+    // The unknown object is skipped; the rest reach every subscriber in order
+    assert_eq!(emitted, 2);
+    assert_eq!(sender.payloads_for(near, "event").await, vec![updates[0].1.clone(), updates[2].1.clone()]);
+    assert_eq!(
+        sender.payloads_for(far, "event").await,
+        vec![json!({ "position": [0.0, 0.0, 0.0] }), json!({ "position": [40.0, 0.0, 0.0] })]
+    );
+    for (ship, before) in ships.into_iter().zip(sent_before) {
+        assert_eq!(gorc_manager.get_object(ship).await.unwrap().stats.updates_sent, before + 2);
+    }
+}
@@ -7,6 +7,17 @@
 //! - Performance benchmarks
 //! - Distance filtering regression tests
 //! - Realistic client movement simulation
+//! - Object ownership registry
+//! - Zone enter/exit hysteresis
+//! - World snapshot and restore
+//! - `#[derive(GorcObject)]` code generation
+//! - Typed object queries
+//! - Cross-region ghosting and handoff
+//! - Plugin spatial queries
+//! - Selectable spatial index implementations
+//! - LOD-aware payload trimming
+//! - Parent/child object attachments
+//! - Global broadcast channel
 
 #[cfg(test)]
 pub mod zone_event_test;
@@ -27,4 +38,37 @@ pub mod virtualization_test;
 pub mod distance_filtering_test;
 
 #[cfg(test)]
-pub mod realistic_movement_test;
\ No newline at end of file
+pub mod realistic_movement_test;
+
+#[cfg(test)]
+pub mod ownership_test;
+
+#[cfg(test)]
+pub mod hysteresis_test;
+
+#[cfg(test)]
+pub mod snapshot_test;
+
+#[cfg(test)]
+pub mod derive_test;
+
+#[cfg(test)]
+pub mod typed_query_test;
+
+#[cfg(test)]
+pub mod federation_test;
+
+#[cfg(test)]
+pub mod spatial_query_test;
+
+#[cfg(test)]
+pub mod spatial_index_test;
+
+#[cfg(test)]
+pub mod lod_test;
+
+#[cfg(test)]
+pub mod attachment_test;
+
+#[cfg(test)]
+pub mod broadcast_test;
@@ -0,0 +1,178 @@
+//! Tests for the GORC object ownership registry
+//!
+//! Verifies that each player resolves to their own object, that ownership
+//! is transferred and cleaned up correctly, and that only an object's
+//! authority can push state for it.
+
+use crate::events::AuthorityChangedEvent;
+use crate::gorc::instance::{GorcInstanceManager, ObjectAuthority};
+use crate::system::EventSystem;
+use crate::types::{PlayerId, Vec3};
+use crate::GorcObject;
+use std::sync::{Arc, Mutex};
+
+/// Minimal player avatar for ownership tests
+#[derive(Debug, Clone, GorcObject)]
+#[gorc(type_name = "GorcPlayer")]
+struct TestAvatar {
+    #[gorc(channel = 0, radius = 50.0, frequency = 30.0)]
+    position: Vec3,
+}
+
+async fn register_avatar(manager: &GorcInstanceManager) -> crate::gorc::instance::GorcObjectId {
+    manager
+        .register_object(TestAvatar { position: Vec3::new(0.0, 0.0, 0.0) }, Vec3::new(0.0, 0.0, 0.0))
+        .await
+}
+
+#[tokio::test]
+async fn test_each_player_finds_own_object() {
+    let manager = GorcInstanceManager::new();
+    let (alice, bob) = (PlayerId::new(), PlayerId::new());
+    let (alice_avatar, bob_avatar) = (register_avatar(&manager).await, register_avatar(&manager).await);
+
+    assert!(manager.register_object_owner(alice, alice_avatar).await);
+    assert!(manager.register_object_owner(bob, bob_avatar).await);
+
+    assert_eq!(manager.find_player_object(alice).await, Some(alice_avatar));
+    assert_eq!(manager.find_player_object(bob).await, Some(bob_avatar));
+    assert_eq!(manager.get_object_owner(bob_avatar).await, Some(bob));
+    assert_eq!(manager.find_player_object(PlayerId::new()).await, None);
+
+    // Unknown objects cannot be owned
+    assert!(!manager.register_object_owner(alice, crate::gorc::instance::GorcObjectId::new()).await);
+    assert_eq!(manager.find_player_object(alice).await, Some(alice_avatar));
+}
+
+#[tokio::test]
+async fn test_transfer_object_owner() {
+    let manager = GorcInstanceManager::new();
+    let (alice, bob) = (PlayerId::new(), PlayerId::new());
+    let ship = register_avatar(&manager).await;
+
+    assert_eq!(manager.transfer_object_owner(ship, alice).await, None);
+    assert_eq!(manager.transfer_object_owner(ship, bob).await, Some(alice));
+
+    assert_eq!(manager.get_object_owner(ship).await, Some(bob));
+    assert_eq!(manager.find_player_object(bob).await, Some(ship));
+    assert_eq!(manager.find_player_object(alice).await, None);
+}
+
+#[tokio::test]
+async fn test_ownership_cleanup() {
+    let manager = GorcInstanceManager::new();
+    let (alice, bob) = (PlayerId::new(), PlayerId::new());
+    let (alice_avatar, bob_avatar) = (register_avatar(&manager).await, register_avatar(&manager).await);
+    manager.register_object_owner(alice, alice_avatar).await;
+    manager.register_object_owner(bob, bob_avatar).await;
+
+    // Disconnecting releases ownership but keeps the object
+    manager.remove_player(alice).await;
+    assert_eq!(manager.find_player_object(alice).await, None);
+    assert_eq!(manager.get_object_owner(alice_avatar).await, None);
+    assert!(manager.get_object(alice_avatar).await.is_some());
+
+    // Unregistering the object clears its owner
+    assert!(manager.unregister_object(bob_avatar).await);
+    assert_eq!(manager.find_player_object(bob).await, None);
+}
+
+#[tokio::test]
+async fn test_owner_holds_authority_until_transferred() {
+    let manager = GorcInstanceManager::new();
+    let (alice, bob) = (PlayerId::new(), PlayerId::new());
+    let ship = register_avatar(&manager).await;
+    assert_eq!(manager.get_object_authority(ship).await, Some(ObjectAuthority::Server));
+
+    manager.register_object_owner(alice, ship).await;
+    assert!(manager.has_authority(ship, alice).await);
+
+    // Bob boards Alice's ship: authority moves, ownership stays
+    assert_eq!(manager.set_object_authority(ship, ObjectAuthority::Player(bob)).await, Some(ObjectAuthority::Player(alice)));
+    assert!(manager.has_authority(ship, bob).await);
+    assert!(!manager.has_authority(ship, alice).await);
+    assert_eq!(manager.get_object_owner(ship).await, Some(alice));
+
+    // A departing pilot hands control back to the server
+    manager.remove_player(bob).await;
+    assert_eq!(manager.get_object_authority(ship).await, Some(ObjectAuthority::Server));
+    assert_eq!(manager.get_stats().await.authority_transfers, 3);
+}
+
+#[tokio::test]
+async fn test_only_authority_can_push_state() {
+    let manager = Arc::new(GorcInstanceManager::new());
+    let events = EventSystem::with_gorc(manager.clone());
+    let (alice, bob) = (PlayerId::new(), PlayerId::new());
+    let ship = register_avatar(&manager).await;
+    manager.register_object_owner(alice, ship).await;
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = changes.clone();
+    events
+        .on_core("authority_changed", move |event: AuthorityChangedEvent| {
+            recorded.lock().unwrap().push(event);
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    let target = Vec3::new(10.0, 0.0, 0.0);
+    assert!(events.update_object_position_as(bob, ship, target).await.is_err());
+    assert_eq!(manager.get_object_position(ship).await, Some(Vec3::new(0.0, 0.0, 0.0)));
+    events.update_object_position_as(alice, ship, target).await.unwrap();
+    assert_eq!(manager.get_object_position(ship).await, Some(target));
+
+    let previous = events.transfer_authority(ship, ObjectAuthority::Player(bob)).await.unwrap();
+    assert_eq!(previous, ObjectAuthority::Player(alice));
+    assert!(events.update_object_position_as(alice, ship, Vec3::new(0.0, 0.0, 0.0)).await.is_err());
+    events.update_object_position_as(bob, ship, Vec3::new(20.0, 0.0, 0.0)).await.unwrap();
+
+    // Re-asserting the current authority is not a change
+    events.transfer_authority(ship, ObjectAuthority::Player(bob)).await.unwrap();
+    let changes = changes.lock().unwrap();
+    assert_eq!(changes.len(), 2);
+    // Registering Alice as owner was queued and goes out first
+    assert_eq!(changes[0].new_authority, ObjectAuthority::Player(alice));
+    assert_eq!(changes[1].object_id, ship);
+    assert_eq!(changes[1].previous_authority, ObjectAuthority::Player(alice));
+    assert_eq!(changes[1].new_authority, ObjectAuthority::Player(bob));
+}
+
+#[tokio::test]
+async fn test_ownership_changes_emit_authority_events() {
+    let manager = Arc::new(GorcInstanceManager::new());
+    let events = EventSystem::with_gorc(manager.clone());
+    let (alice, bob) = (PlayerId::new(), PlayerId::new());
+    let ship = register_avatar(&manager).await;
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = changes.clone();
+    events
+        .on_core("authority_changed", move |event: AuthorityChangedEvent| {
+            recorded.lock().unwrap().push((event.previous_authority, event.new_authority));
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    manager.register_object_owner(alice, ship).await;
+    manager.transfer_object_owner(ship, bob).await;
+    // Bob already holds authority, so registering him again changes nothing
+    manager.register_object_owner(bob, ship).await;
+    manager.remove_player(bob).await;
+
+    assert_eq!(events.emit_authority_changes().await.unwrap(), 3);
+    assert_eq!(
+        *changes.lock().unwrap(),
+        vec![
+            (ObjectAuthority::Server, ObjectAuthority::Player(alice)),
+            (ObjectAuthority::Player(alice), ObjectAuthority::Player(bob)),
+            (ObjectAuthority::Player(bob), ObjectAuthority::Server),
+        ]
+    );
+
+    // Changes are emitted once
+    assert_eq!(events.emit_authority_changes().await.unwrap(), 0);
+    assert!(manager.drain_authority_changes().await.is_empty());
+}
@@ -465,4 +465,49 @@ async fn validate_performance_improvements() {
     println!("Current zone warnings: {}", stats.large_zone_warnings);
 
     println!("✅ All performance improvements validated");
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_sharded_objects_update_concurrently() {
+    let manager = GorcInstanceManager::new().with_object_shards(1);
+    let mut object_ids = Vec::new();
+    for i in 0..200 {
+        let position = Vec3::new(i as f64 * 10.0, 0.0, 0.0);
+        object_ids.push(manager.register_object(PerfTestObject::new(position, format!("obj_{}", i), 1), position).await);
+    }
+
+    // Resharding keeps every registered object
+    let manager = Arc::new(manager.with_object_shards(8));
+    assert_eq!(manager.object_shard_count(), 8);
+    assert_eq!(manager.get_objects_of_type::<PerfTestObject>().await.len(), 200);
+
+    let player_id = PlayerId::new();
+    manager.update_player_position(player_id, Vec3::new(0.0, 5000.0, 0.0)).await;
+
+    // Workers move disjoint sets of objects next to the player at the same time
+    let mut workers = Vec::new();
+    for chunk in object_ids.chunks(25) {
+        let manager = manager.clone();
+        let chunk = chunk.to_vec();
+        workers.push(tokio::spawn(async move {
+            for object_id in chunk {
+                manager.update_object_position(object_id, Vec3::new(0.0, 5050.0, 0.0)).await.unwrap();
+            }
+        }));
+    }
+    for worker in workers {
+        worker.await.unwrap();
+    }
+
+    for &object_id in &object_ids {
+        assert_eq!(manager.get_object_position(object_id).await, Some(Vec3::new(0.0, 5050.0, 0.0)));
+        assert!(manager.get_object(object_id).await.unwrap().is_subscribed(0, player_id));
+    }
+
+    // Batches spanning every shard still report moves in input order
+    let updates: Vec<_> = object_ids.iter().map(|&object_id| (object_id, Vec3::new(0.0, 0.0, 0.0))).collect();
+    let moves = manager.update_positions(&updates).await;
+    let moved: Vec<_> = moves.iter().map(|object_move| object_move.object_id).collect();
+    assert_eq!(moved, object_ids);
+    assert!(moves.iter().all(|object_move| object_move.zone_changes == vec![(player_id, 0, false)]));
+}
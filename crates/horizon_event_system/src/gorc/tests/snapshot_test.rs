@@ -0,0 +1,68 @@
+//! Tests for GORC world snapshots
+//!
+//! Verifies that snapshot-capable objects survive a save/restore round trip
+//! with their IDs, positions and state intact, and that objects without
+//! snapshot state or a registered restorer are skipped.
+
+use crate::gorc::instance::GorcInstanceManager;
+use crate::types::Vec3;
+use crate::GorcObject;
+use serde::{Deserialize, Serialize};
+
+/// Persistent world object that opts in to snapshots
+#[derive(Debug, Clone, Serialize, Deserialize, GorcObject)]
+#[gorc(snapshot)]
+struct TestAsteroid {
+    position: Vec3,
+    #[gorc(channel = 3, radius = 500.0, frequency = 2.0)]
+    ore_remaining: u32,
+}
+
+/// Transient object that keeps the default snapshot hook
+#[derive(Debug, Clone, GorcObject)]
+struct TestProjectile {
+    #[gorc(channel = 0, radius = 50.0, frequency = 30.0)]
+    position: Vec3,
+}
+
+#[tokio::test]
+async fn test_snapshot_round_trip_through_disk() {
+    let manager = GorcInstanceManager::new();
+    let position = Vec3::new(120.0, 0.0, -40.0);
+    let asteroid = manager
+        .register_object(TestAsteroid { position, ore_remaining: 750 }, position)
+        .await;
+    manager.register_object(TestProjectile { position }, position).await;
+
+    let path = std::env::temp_dir()
+        .join(format!("gorc_snapshot_test_{}", uuid::Uuid::new_v4()))
+        .join("world.json");
+    assert_eq!(manager.save_snapshot(&path).await.unwrap(), 1);
+
+    // A fresh manager only restores types it knows how to rebuild
+    let restarted = GorcInstanceManager::new();
+    assert_eq!(restarted.load_snapshot(&path).await.unwrap(), 0);
+    restarted.register_snapshot_type::<TestAsteroid>("TestAsteroid").await;
+    assert_eq!(restarted.load_snapshot(&path).await.unwrap(), 1);
+
+    let instance = restarted.get_object(asteroid).await.unwrap();
+    assert_eq!(instance.get_object::<TestAsteroid>().unwrap().ore_remaining, 750);
+    assert_eq!(restarted.get_object_position(asteroid).await, Some(position));
+    assert!(restarted.get_objects_by_type("TestProjectile").await.is_empty());
+
+    // Restoring again never duplicates existing objects
+    assert_eq!(restarted.load_snapshot(&path).await.unwrap(), 0);
+    assert_eq!(restarted.get_stats().await.total_objects, 1);
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[tokio::test]
+async fn test_load_missing_snapshot_fails() {
+    let manager = GorcInstanceManager::new();
+    let path = std::env::temp_dir().join(format!("gorc_missing_{}.json", uuid::Uuid::new_v4()));
+    assert!(matches!(
+        manager.load_snapshot(&path).await,
+        Err(crate::gorc::SnapshotError::Io(_))
+    ));
+}
@@ -0,0 +1,164 @@
+//! Tests for the selectable spatial index implementations
+//!
+//! Every index kind must return exactly what a brute-force scan returns,
+//! including after players move and leave, so switching
+//! `gorc.spatial.index` never changes replication behaviour. Background
+//! maintenance must only rebuild R*-trees on low-load ticks, unless a due
+//! rebuild has been deferred too many times.
+
+use crate::gorc::instance::GorcInstanceManager;
+use crate::gorc::spatial::{
+    QueryFilters, SpatialBenchmark, SpatialIndexConfig, SpatialIndexKind, SpatialPartition, SpatialQuery,
+};
+use crate::types::{PlayerId, Position, Vec3};
+use std::collections::{HashMap, HashSet};
+
+fn sorted_ids(ids: impl IntoIterator<Item = PlayerId>) -> Vec<String> {
+    let mut ids: Vec<String> = ids.into_iter().map(|id| id.to_string()).collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_index_kinds_match_brute_force() {
+    let min = Vec3::new(-500.0, -500.0, -50.0);
+    let max = Vec3::new(500.0, 500.0, 50.0);
+    let mut state = 11u64;
+    let mut next = |range: f64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * range
+    };
+    let mut positions: HashMap<PlayerId, Position> = (0..600)
+        .map(|_| (PlayerId::new(), Position::new(next(1000.0), next(1000.0), next(100.0))))
+        .collect();
+    // A few players outside the region bounds
+    for _ in 0..5 {
+        positions.insert(PlayerId::new(), Position::new(next(5000.0), next(5000.0), next(500.0)));
+    }
+
+    for kind in SpatialIndexKind::ALL {
+        let config = SpatialIndexConfig::new(kind).with_max_objects_per_leaf(8).with_cell_size(40.0);
+        let mut index = config.create(min, max);
+        assert_eq!(index.kind(), kind);
+
+        let mut expected = positions.clone();
+        for (&player_id, &position) in &expected {
+            index.insert_player(player_id, position);
+        }
+
+        // Move a third of the players and remove another third
+        for (i, player_id) in expected.keys().copied().collect::<Vec<_>>().into_iter().enumerate() {
+            match i % 3 {
+                0 => {
+                    let moved = Position::new(next(1000.0), next(1000.0), next(100.0));
+                    index.insert_player(player_id, moved);
+                    expected.insert(player_id, moved);
+                }
+                1 => {
+                    assert_eq!(index.remove_player(player_id), 1);
+                    expected.remove(&player_id);
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(index.object_count(), expected.len(), "{kind} count");
+
+        for radius in [0.0, 25.0, 120.0, 3000.0] {
+            for center in [Position::new(0.0, 0.0, 0.0), Position::new(450.0, -450.0, 40.0), Position::new(2500.0, 0.0, 0.0)] {
+                let found = sorted_ids(index.query_radius(center, radius).into_iter().map(|r| r.player_id));
+                let brute = sorted_ids(
+                    expected
+                        .iter()
+                        .filter(|(_, position)| position.distance(center) <= radius)
+                        .map(|(&player_id, _)| player_id),
+                );
+                assert_eq!(found, brute, "{kind} radius {radius} around {center:?}");
+            }
+        }
+
+        // Filters behave the same for every implementation
+        let excluded: HashSet<PlayerId> = expected.keys().copied().take(10).collect();
+        let filtered = index.query(SpatialQuery {
+            center: Position::new(0.0, 0.0, 0.0),
+            radius: 3000.0,
+            filters: QueryFilters { exclude_players: Some(excluded.clone()), ..QueryFilters::default() },
+        });
+        assert_eq!(filtered.len(), expected.len() - excluded.len(), "{kind} exclude filter");
+        assert!(filtered.iter().all(|result| !excluded.contains(&result.player_id)));
+
+        index.clear();
+        assert_eq!(index.object_count(), 0);
+        assert!(index.query_radius(Position::new(0.0, 0.0, 0.0), 3000.0).is_empty());
+    }
+}
+
+#[test]
+fn test_benchmark_compares_every_kind() {
+    let benchmark = SpatialBenchmark::uniform(2_000, Vec3::new(-1000.0, -1000.0, -100.0), Vec3::new(1000.0, 1000.0, 100.0), 42)
+        .with_query_radius(150.0)
+        .with_queries(200);
+    let results = benchmark.run();
+
+    assert_eq!(results.iter().map(|result| result.kind).collect::<Vec<_>>(), SpatialIndexKind::ALL.to_vec());
+    assert!(results.iter().all(|result| result.objects == 2_000));
+    // Same positions and query centers, so every index finds the same players
+    assert!(results.windows(2).all(|pair| pair[0].avg_hits == pair[1].avg_hits));
+    assert!(results[0].avg_hits >= 1.0);
+}
+
+#[tokio::test]
+async fn test_instance_manager_uses_selected_index() {
+    let instances = GorcInstanceManager::new()
+        .with_spatial_index(SpatialIndexConfig::new(SpatialIndexKind::LooseGrid));
+    assert_eq!(instances.spatial_index_kind().await, SpatialIndexKind::LooseGrid);
+    assert_eq!(GorcInstanceManager::new().spatial_index_kind().await, SpatialIndexKind::RTree);
+
+    let partition = SpatialPartition::with_index(SpatialIndexConfig::new(SpatialIndexKind::Octree));
+    let near = PlayerId::new();
+    partition.update_player_position(near, Position::new(10.0, 0.0, 0.0)).await;
+    partition.update_player_position(PlayerId::new(), Position::new(900.0, 0.0, 0.0)).await;
+
+    let found = partition.query_radius(Position::new(0.0, 0.0, 0.0), 50.0).await;
+    assert_eq!(found.iter().map(|result| result.player_id).collect::<Vec<_>>(), vec![near]);
+}
+
+#[tokio::test]
+async fn test_maintenance_rebuilds_on_low_load_ticks() {
+    let mut config = SpatialIndexConfig::new(SpatialIndexKind::RTree)
+        .with_rebuild_threshold(10)
+        .with_low_load_mutations(5);
+    config.max_rebuild_deferrals = 2;
+    let partition = SpatialPartition::with_index(config);
+
+    // Below the threshold nothing is due, however busy the tick
+    for x in 0..8 {
+        partition.update_player_position(PlayerId::new(), Position::new(x as f64, 0.0, 0.0)).await;
+    }
+    let report = partition.maintain().await;
+    assert_eq!((report.mutations, report.regions_rebuilt, report.deferred), (8, 0, false));
+
+    // A due rebuild waits for a quiet tick
+    let players: Vec<PlayerId> = (0..8).map(|_| PlayerId::new()).collect();
+    for (x, player_id) in players.iter().enumerate() {
+        partition.update_player_position(*player_id, Position::new(x as f64, 10.0, 0.0)).await;
+    }
+    assert!(partition.maintain().await.deferred);
+    let report = partition.maintain().await;
+    assert_eq!((report.mutations, report.regions_rebuilt), (0, 1));
+
+    let stats = &partition.index_stats().await["default"];
+    assert_eq!(stats.total_rebuilds, 1);
+    assert_eq!(stats.mutations_since_rebuild, 0);
+    assert_eq!(stats.total_rebuild_us, stats.last_rebuild_us);
+    assert_eq!(partition.query_radius(Position::new(0.0, 10.0, 0.0), 3.5).await.len(), 4);
+
+    // Under sustained load the rebuild runs once it has been deferred too often
+    let mut rebuilt = Vec::new();
+    for _ in 0..4 {
+        for player_id in &players {
+            partition.update_player_position(*player_id, Position::new(0.0, 20.0, 0.0)).await;
+        }
+        rebuilt.push(partition.maintain().await.regions_rebuilt);
+    }
+    assert_eq!(rebuilt, vec![0, 0, 0, 1]);
+}
@@ -0,0 +1,159 @@
+//! Tests for the plugin-facing spatial query handle
+//!
+//! Verifies radius, bounding box, nearest-neighbour and filtered queries
+//! against the player and object positions tracked by the instance manager,
+//! including filtering on runtime object tags.
+
+use crate::gorc::instance::GorcInstanceManager;
+use crate::gorc::spatial::{SpatialEntity, SpatialFilter, SpatialQuery, SpatialQueryHandle};
+use crate::types::{PlayerId, Vec3};
+use crate::GorcObject;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, GorcObject)]
+struct TestMine {
+    #[gorc(channel = 0)]
+    position: Vec3,
+}
+
+/// Tagged object with a single zone on channel 1
+#[derive(Debug, Clone, GorcObject)]
+struct Asteroid {
+    #[gorc(channel = 1, radius = 300.0, frequency = 5.0)]
+    position: Vec3,
+    #[gorc(tags)]
+    tags: Vec<String>,
+}
+
+impl Asteroid {
+    fn new(position: Vec3, tags: &[&str]) -> Self {
+        Self { position, tags: tags.iter().map(|tag| tag.to_string()).collect() }
+    }
+}
+
+#[tokio::test]
+async fn test_spatial_queries_use_tracked_positions() {
+    let instances = Arc::new(GorcInstanceManager::new());
+    let spatial = SpatialQueryHandle::new(instances.clone());
+
+    let (near, mid, far) = (PlayerId::new(), PlayerId::new(), PlayerId::new());
+    instances.add_player(far, Vec3::new(500.0, 0.0, 0.0)).await;
+    instances.update_player_position(far, Vec3::new(500.0, 0.0, 0.0)).await;
+    instances.add_player(mid, Vec3::new(30.0, 0.0, 0.0)).await;
+    instances.update_player_position(mid, Vec3::new(30.0, 0.0, 0.0)).await;
+    instances.add_player(near, Vec3::new(5.0, 0.0, 0.0)).await;
+    instances.update_player_position(near, Vec3::new(5.0, 0.0, 0.0)).await;
+
+    let inside = Vec3::new(10.0, 10.0, 0.0);
+    let outside = Vec3::new(-50.0, 0.0, 0.0);
+    let mine = instances.register_object(TestMine { position: inside }, inside).await;
+    instances.register_object(TestMine { position: outside }, outside).await;
+
+    // Radius queries are inclusive and sorted nearest first
+    assert_eq!(spatial.players_in_radius(Vec3::new(0.0, 0.0, 0.0), 30.0).await, vec![near, mid]);
+
+    assert_eq!(
+        spatial.objects_in_aabb(Vec3::new(0.0, 0.0, -1.0), Vec3::new(20.0, 20.0, 1.0)).await,
+        vec![mine]
+    );
+
+    let nearest = spatial.nearest_n(Vec3::new(0.0, 0.0, 0.0), 2, SpatialFilter::All).await;
+    assert_eq!(nearest[0].entity, SpatialEntity::Player(near));
+    assert_eq!(nearest[1].entity, SpatialEntity::Object(mine));
+
+    let nearest_players = spatial.nearest_n(Vec3::new(0.0, 0.0, 0.0), 5, SpatialFilter::Players).await;
+    assert_eq!(nearest_players.len(), 3);
+    assert!(nearest_players.windows(2).all(|pair| pair[0].distance <= pair[1].distance));
+
+    // Moves are visible to later queries
+    instances.update_object_position(mine, Vec3::new(100.0, 100.0, 0.0)).await;
+    assert!(spatial.objects_in_aabb(Vec3::new(0.0, 0.0, -1.0), Vec3::new(20.0, 20.0, 1.0)).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_filtered_queries() {
+    let instances = Arc::new(GorcInstanceManager::new());
+    let spatial = SpatialQueryHandle::new(instances.clone());
+    let origin = Vec3::new(0.0, 0.0, 0.0);
+
+    let mine = instances.register_object(TestMine { position: Vec3::new(5.0, 0.0, 0.0) }, Vec3::new(5.0, 0.0, 0.0)).await;
+    let ore_far = Vec3::new(80.0, 0.0, 0.0);
+    let ore_far = instances.register_object(Asteroid::new(ore_far, &["mineable", "ore"]), ore_far).await;
+    let ore_near = Vec3::new(20.0, 0.0, 0.0);
+    let ore_near = instances.register_object(Asteroid::new(ore_near, &["mineable", "ore"]), ore_near).await;
+    let rock = Vec3::new(40.0, 0.0, 0.0);
+    let rock = instances.register_object(Asteroid::new(rock, &["mineable"]), rock).await;
+    let out_of_range = Vec3::new(900.0, 0.0, 0.0);
+    instances.register_object(Asteroid::new(out_of_range, &["ore"]), out_of_range).await;
+
+    // Unfiltered queries find everything in range
+    let all: HashSet<_> = instances.get_objects_in_range(origin, 100.0).await.into_iter().collect();
+    assert_eq!(all, HashSet::from([mine, ore_far, ore_near, rock]));
+
+    // Type and channel filters
+    let ids = |results: Vec<crate::gorc::spatial::ObjectQueryResult>| results.into_iter().map(|result| result.object_id).collect::<Vec<_>>();
+    let asteroids = spatial.query_objects(SpatialQuery::new(origin, 100.0).with_object_type("Asteroid").sorted_by_distance()).await;
+    assert_eq!(asteroids[0].object_type, "Asteroid");
+    assert_eq!(ids(asteroids), vec![ore_near, rock, ore_far]);
+    assert_eq!(ids(spatial.query_objects(SpatialQuery::new(origin, 100.0).with_channel(0)).await), vec![mine]);
+
+    // Tags must all be present; limits keep the nearest results when sorting
+    let ore = SpatialQuery::new(origin, 100.0).with_tag("mineable").with_tag("ore").sorted_by_distance();
+    assert_eq!(ids(spatial.query_objects(ore.clone()).await), vec![ore_near, ore_far]);
+    assert_eq!(ids(spatial.query_objects(ore.with_max_results(1)).await), vec![ore_near]);
+    assert_eq!(
+        ids(spatial.query_objects(SpatialQuery::new(origin, 100.0).with_min_distance(30.0).sorted_by_distance()).await),
+        vec![rock, ore_far]
+    );
+
+    // Unregistered objects drop out of the index
+    instances.unregister_object(ore_near).await;
+    assert!(!instances.get_objects_in_range(origin, 100.0).await.contains(&ore_near));
+
+    // Player queries honour sorting and limits too
+    let (near, far) = (PlayerId::new(), PlayerId::new());
+    instances.update_player_position(far, Vec3::new(50.0, 0.0, 0.0)).await;
+    instances.update_player_position(near, Vec3::new(10.0, 0.0, 0.0)).await;
+    let players = spatial.query_players(SpatialQuery::new(origin, 100.0).with_max_results(1).sorted_by_distance()).await;
+    assert_eq!(players.len(), 1);
+    assert_eq!(players[0].player_id, near);
+}
+
+#[tokio::test]
+async fn test_runtime_tags() {
+    let instances = Arc::new(GorcInstanceManager::new());
+    let spatial = SpatialQueryHandle::new(instances.clone());
+    let origin = Vec3::new(0.0, 0.0, 0.0);
+
+    let pirate = Vec3::new(10.0, 0.0, 0.0);
+    let pirate = instances.register_object(Asteroid::new(pirate, &[]), pirate).await;
+    let trader = Vec3::new(20.0, 0.0, 0.0);
+    let trader = instances.register_object(Asteroid::new(trader, &["hostile"]), trader).await;
+
+    assert_eq!(instances.set_object_tag(pirate, "faction", "pirates").await, Some(None));
+    assert_eq!(instances.set_object_tag(pirate, "hostile", "true").await, Some(None));
+    assert_eq!(instances.set_object_tag(trader, "faction", "guild").await, Some(None));
+    assert_eq!(instances.set_object_tag(crate::gorc::instance::GorcObjectId::new(), "faction", "none").await, None);
+
+    // Tag filters match runtime tags and tags declared by the object alike
+    let ids = |results: Vec<crate::gorc::spatial::ObjectQueryResult>| results.into_iter().map(|result| result.object_id).collect::<Vec<_>>();
+    let hostile = SpatialQuery::new(origin, 100.0).with_tag("hostile").sorted_by_distance();
+    assert_eq!(ids(spatial.query_objects(hostile).await), vec![pirate, trader]);
+    let pirates = SpatialQuery::new(origin, 100.0).with_tag_value("faction", "pirates");
+    assert_eq!(ids(spatial.query_objects(pirates.clone()).await), vec![pirate]);
+
+    let mut tagged = instances.get_objects_by_tag("hostile", None).await;
+    tagged.sort_by_key(|object_id| object_id.0);
+    let mut expected = vec![pirate, trader];
+    expected.sort_by_key(|object_id| object_id.0);
+    assert_eq!(tagged, expected);
+    assert_eq!(instances.get_objects_by_tag("faction", Some("guild")).await, vec![trader]);
+
+    // Changing a tag is visible to the next query
+    assert_eq!(instances.set_object_tag(pirate, "faction", "guild").await, Some(Some("pirates".to_string())));
+    assert!(spatial.query_objects(pirates).await.is_empty());
+    assert_eq!(instances.remove_object_tag(pirate, "faction").await, Some("guild".to_string()));
+    assert_eq!(instances.get_object_tags(pirate).await.unwrap().get("hostile").map(String::as_str), Some("true"));
+    assert_eq!(instances.inspect_zones().await.object(pirate).unwrap().tags.len(), 1);
+}
@@ -0,0 +1,73 @@
+//! Tests for typed object queries on the instance manager
+//!
+//! Verifies that objects can be listed, read and mutated as their concrete
+//! type, and that moves made through `with_object_mut` update subscriptions.
+
+use crate::gorc::instance::GorcInstanceManager;
+use crate::types::{PlayerId, Vec3};
+use crate::GorcObject;
+
+#[derive(Debug, Clone, GorcObject)]
+struct TestCrate {
+    #[gorc(channel = 0)]
+    position: Vec3,
+    #[gorc(channel = 1)]
+    contents: u32,
+}
+
+#[derive(Debug, Clone, GorcObject)]
+struct TestTurret {
+    #[gorc(channel = 0)]
+    position: Vec3,
+}
+
+#[tokio::test]
+async fn test_get_objects_of_type_filters_by_concrete_type() {
+    let manager = GorcInstanceManager::new();
+    let origin = Vec3::new(0.0, 0.0, 0.0);
+    let first = manager.register_object(TestCrate { position: origin, contents: 3 }, origin).await;
+    let second = manager.register_object(TestCrate { position: origin, contents: 7 }, origin).await;
+    manager.register_object(TestTurret { position: origin }, origin).await;
+
+    let mut crates = manager.get_objects_of_type::<TestCrate>().await;
+    crates.sort_by_key(|(_, object)| object.contents);
+    assert_eq!(crates.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![first, second]);
+    assert_eq!(manager.get_objects_of_type::<TestTurret>().await.len(), 1);
+
+    assert_eq!(manager.with_object(first, |object: &TestCrate| object.contents).await, Some(3));
+    assert_eq!(manager.with_object(first, |object: &TestTurret| object.position).await, None);
+}
+
+#[tokio::test]
+async fn test_with_object_mut_marks_updates_and_applies_moves() {
+    let manager = GorcInstanceManager::new();
+    let origin = Vec3::new(0.0, 0.0, 0.0);
+    let object_id = manager.register_object(TestCrate { position: origin, contents: 1 }, origin).await;
+    let player = PlayerId::new();
+    manager.update_player_position(player, origin).await;
+    let mut instance = manager.get_object(object_id).await.unwrap();
+    instance.mark_updated(1);
+    manager.update_object(object_id, instance).await;
+
+    let contents = manager
+        .with_object_mut(object_id, |object: &mut TestCrate| {
+            object.contents += 4;
+            object.contents
+        })
+        .await;
+    assert_eq!(contents, Some(5));
+    let instance = manager.get_object(object_id).await.unwrap();
+    assert!(instance.needs_channel_update(1));
+    assert!(instance.is_subscribed(0, player));
+
+    // Moving the object out of range goes through the normal movement path
+    let far = Vec3::new(5000.0, 0.0, 0.0);
+    manager
+        .with_object_mut(object_id, |object: &mut TestCrate| object.position = far)
+        .await
+        .unwrap();
+    assert_eq!(manager.get_object_position(object_id).await, Some(far));
+    assert!(!manager.get_object(object_id).await.unwrap().is_subscribed(0, player));
+
+    assert!(manager.with_object_mut(object_id, |_: &mut TestTurret| ()).await.is_none());
+}
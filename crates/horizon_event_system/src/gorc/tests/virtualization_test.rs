@@ -5,9 +5,15 @@
 
 use crate::gorc::instance::{GorcInstanceManager, GorcObject};
 use crate::gorc::channels::{ReplicationLayer, CompressionType};
-use crate::gorc::virtualization::VirtualizationConfig;
-use crate::types::Vec3;
-use std::sync::Arc;
+use crate::events::{GorcVirtualZoneMergedEvent, GorcVirtualZoneSplitEvent};
+use crate::gorc::instance::GorcObjectId;
+use crate::system::EventSystem;
+use crate::gorc::virtualization::{
+    StaticGridStrategy, VirtualizationConfig, VirtualizationManager, VirtualizationStrategyKind,
+};
+use crate::types::{PlayerId, Vec3};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::any::Any;
 use tokio::time::{sleep, Duration};
 
@@ -133,6 +139,7 @@ async fn test_rapid_merge_split_cycles() {
         min_zone_radius: 10.0,
         check_interval_ms: 100,
         max_objects_per_virtual_zone: 10,
+        ..Default::default()
     };
 
     let manager = Arc::new(GorcInstanceManager::new_with_config(config));
@@ -188,6 +195,7 @@ async fn test_overlapping_zone_boundary_conditions() {
         min_zone_radius: 50.0,
         check_interval_ms: 1000,
         max_objects_per_virtual_zone: 20,
+        ..Default::default()
     };
 
     let manager = Arc::new(GorcInstanceManager::new_with_config(config));
@@ -232,6 +240,7 @@ async fn test_massive_zone_handling() {
         min_zone_radius: 50.0,
         check_interval_ms: 1000,
         max_objects_per_virtual_zone: 100,
+        ..Default::default()
     };
 
     let manager = Arc::new(GorcInstanceManager::new_with_config(config));
@@ -276,6 +285,7 @@ async fn test_multi_channel_virtualization() {
         min_zone_radius: 30.0,
         check_interval_ms: 1000,
         max_objects_per_virtual_zone: 15,
+        ..Default::default()
     };
 
     let manager = Arc::new(GorcInstanceManager::new_with_config(config));
@@ -314,6 +324,7 @@ async fn test_virtualization_under_concurrent_load() {
         min_zone_radius: 40.0,
         check_interval_ms: 500,
         max_objects_per_virtual_zone: 25,
+        ..Default::default()
     };
 
     let manager = Arc::new(GorcInstanceManager::new_with_config(config));
@@ -382,6 +393,7 @@ async fn test_zone_splitting_edge_cases() {
         min_zone_radius: 30.0,
         check_interval_ms: 1000,
         max_objects_per_virtual_zone: 5, // Small to force splits
+        ..Default::default()
     };
 
     let manager = Arc::new(GorcInstanceManager::new_with_config(config));
@@ -447,6 +459,7 @@ async fn test_configuration_edge_cases() {
         min_zone_radius: 1.0, // Tiny minimum
         check_interval_ms: 10, // Very frequent checks
         max_objects_per_virtual_zone: 1000,
+        ..Default::default()
     };
 
     let extreme_manager = Arc::new(GorcInstanceManager::new_with_config(extreme_config));
@@ -478,6 +491,7 @@ async fn test_memory_and_performance_under_stress() {
         min_zone_radius: 25.0,
         check_interval_ms: 1000,
         max_objects_per_virtual_zone: 30,
+        ..Default::default()
     };
 
     let manager = Arc::new(GorcInstanceManager::new_with_config(config));
@@ -543,6 +557,7 @@ async fn test_virtualization_accuracy() {
         min_zone_radius: 30.0,
         check_interval_ms: 1000,
         max_objects_per_virtual_zone: 10,
+        ..Default::default()
     };
 
     let manager = Arc::new(GorcInstanceManager::new_with_config(config));
@@ -596,6 +611,7 @@ async fn test_virtualization_consistency() {
         min_zone_radius: 40.0,
         check_interval_ms: 1000,
         max_objects_per_virtual_zone: 20,
+        ..Default::default()
     };
 
     let manager = Arc::new(GorcInstanceManager::new_with_config(config));
@@ -624,4 +640,129 @@ async fn test_virtualization_consistency() {
     assert!(all_same, "Virtual zone count should stabilize after initial processing");
 
     println!("✅ Virtualization consistency test passed");
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_strategies_merge_differently() {
+    // A tightly overlapping pair, and a non-overlapping pair sharing one 500m grid cell
+    let layer = |radius| vec![ReplicationLayer::new(0, radius, 30.0, vec!["position".to_string()], CompressionType::Delta)];
+    let objects: HashMap<GorcObjectId, (Vec3, Vec<ReplicationLayer>)> = [
+        Vec3::new(2010.0, 10.0, 0.0),
+        Vec3::new(2030.0, 10.0, 0.0),
+        Vec3::new(10.0, 10.0, 0.0),
+        Vec3::new(410.0, 10.0, 0.0),
+    ]
+    .into_iter()
+    .map(|position| (GorcObjectId::new(), (position, layer(100.0))))
+    .collect();
+
+    let merges = |strategy, players: Vec<Vec3>| {
+        let manager = VirtualizationManager::new(VirtualizationConfig {
+            enabled: true,
+            strategy,
+            min_players_per_virtual_zone: 2,
+            ..Default::default()
+        });
+        let objects = &objects;
+        async move {
+            manager
+                .analyze_virtualization_opportunities_with_players(objects, &players)
+                .await
+                .merge_recommendations
+                .len()
+        }
+    };
+
+    assert_eq!(merges(VirtualizationStrategyKind::Density, vec![]).await, 1);
+    assert_eq!(merges(VirtualizationStrategyKind::StaticGrid, vec![]).await, 2);
+    assert_eq!(merges(VirtualizationStrategyKind::PlayerCount, vec![]).await, 0);
+    let crowd = vec![Vec3::new(2000.0, 0.0, 0.0), Vec3::new(2050.0, 0.0, 0.0)];
+    assert_eq!(merges(VirtualizationStrategyKind::PlayerCount, crowd).await, 1);
+
+    // Custom strategies replace the configured one
+    let manager = VirtualizationManager::new(VirtualizationConfig::default())
+        .with_strategy(Box::new(StaticGridStrategy));
+    assert_eq!(manager.strategy_name(), "static_grid");
+}
+
+#[tokio::test]
+async fn test_player_count_strategy_uses_tracked_players() {
+    let config = VirtualizationConfig {
+        enabled: true,
+        strategy: VirtualizationStrategyKind::PlayerCount,
+        min_players_per_virtual_zone: 3,
+        ..Default::default()
+    };
+    let manager = Arc::new(GorcInstanceManager::new_with_config(config));
+
+    for i in 0..4 {
+        let position = Vec3::new(i as f64 * 20.0, 0.0, 0.0);
+        manager
+            .register_object(VirtualizationTestObject::new_single(position, 100.0, 0), position)
+            .await;
+    }
+
+    // Nobody around: per-object zones stay precise
+    manager.process_virtualization().await.unwrap();
+    assert_eq!(manager.get_virtualization_stats().await.active_virtual_zones, 0);
+
+    // A crowd gathers inside the cluster
+    for i in 0..3 {
+        manager.update_player_position(PlayerId::new(), Vec3::new(i as f64 * 10.0, 5.0, 0.0)).await;
+    }
+    manager.process_virtualization().await.unwrap();
+    assert_eq!(manager.get_virtualization_stats().await.active_virtual_zones, 1);
+}
+
+#[tokio::test]
+async fn test_merge_and_split_reach_plugins() {
+    let config = VirtualizationConfig { enabled: true, ..Default::default() };
+    let manager = Arc::new(GorcInstanceManager::new_with_config(config));
+    let mut events = EventSystem::new();
+    events.set_gorc_instances(manager.clone());
+
+    let merged = Arc::new(Mutex::new(Vec::new()));
+    let split = Arc::new(Mutex::new(Vec::new()));
+    let recorded_merged = merged.clone();
+    let recorded_split = split.clone();
+    events
+        .on_core("gorc_virtual_zone_merged", move |event: GorcVirtualZoneMergedEvent| {
+            recorded_merged.lock().unwrap().push(event);
+            Ok(())
+        })
+        .await
+        .unwrap();
+    events
+        .on_core("gorc_virtual_zone_split", move |event: GorcVirtualZoneSplitEvent| {
+            recorded_split.lock().unwrap().push(event);
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    let mut object_ids = Vec::new();
+    for i in 0..4 {
+        let position = Vec3::new(i as f64 * 20.0, 0.0, 0.0);
+        object_ids.push(
+            manager
+                .register_object(VirtualizationTestObject::new_single(position, 100.0, 0), position)
+                .await,
+        );
+    }
+
+    assert_eq!(events.process_virtualization().await.unwrap(), 1);
+    let merge = merged.lock().unwrap()[0].clone();
+    assert_eq!(merge.channel, 0);
+    assert_eq!(merge.object_ids.len(), 4);
+    assert!(object_ids.iter().all(|id| merge.object_ids.contains(id)));
+
+    // Leaving the virtual zone splits it
+    events.update_object_position(object_ids[0], Vec3::new(5000.0, 0.0, 0.0)).await.unwrap();
+    let splits = split.lock().unwrap().clone();
+    assert_eq!(splits.len(), 1);
+    assert_eq!(splits[0].virtual_id, merge.virtual_id);
+    assert_eq!(splits[0].object_ids.len(), 4);
+
+    // Nothing is emitted twice
+    assert!(manager.drain_virtual_zone_events().await.is_empty());
+}
@@ -4,6 +4,14 @@
 //! 1. Players move into/out of object zones
 //! 2. Objects move toward/away from stationary players
 //! 3. New objects are created near existing players
+//! 4. Objects despawn when their time-to-live elapses
+//! 5. Objects are moved together in a batch position update
+//!
+//! It also checks that zone changes are emitted as core events for plugins,
+//! that delivered messages are counted in the replication stats, that zone
+//! inspection reports the live subscriptions, that forced subscriptions
+//! survive zone recalculation, and that fast players enter zones at their
+//! dead-reckoned positions between movement updates.
 
 use crate::gorc::instance::{GorcInstanceManager, GorcObject};
 use crate::gorc::channels::{ReplicationLayer, CompressionType};
@@ -222,6 +230,58 @@ async fn test_object_movement_zone_events() {
     assert!(zone_entry_found, "Should have found zone entry event from object movement");
 }
 
+#[tokio::test]
+async fn test_batch_object_movement_zone_events() {
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new());
+    let client_sender = Arc::new(MockClientSender::new());
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(client_sender.clone());
+
+    let far = Vec3::new(1000.0, 1000.0, 0.0);
+    let near_id = gorc_manager.register_object(TestGorcObject::new(far, "near".to_string()), far).await;
+    let mid_id = gorc_manager.register_object(TestGorcObject::new(far, "mid".to_string()), far).await;
+
+    let player_id = PlayerId::new();
+    gorc_manager.add_player(player_id, Vec3::new(0.0, 0.0, 0.0)).await;
+    events.update_player_position(player_id, Vec3::new(0.0, 0.0, 0.0)).await.unwrap();
+
+    // Unknown objects are skipped without failing the batch
+    let moved = events
+        .update_object_positions(&[
+            (near_id, Vec3::new(25.0, 25.0, 0.0)),
+            (crate::gorc::GorcObjectId::new(), Vec3::new(0.0, 0.0, 0.0)),
+            (mid_id, Vec3::new(100.0, 0.0, 0.0)),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(moved, 2);
+    assert_eq!(gorc_manager.get_object_position(near_id).await, Some(Vec3::new(25.0, 25.0, 0.0)));
+    assert_eq!(gorc_manager.get_object_position(mid_id).await, Some(Vec3::new(100.0, 0.0, 0.0)));
+
+    // The near object enters all three zones, the mid-range object only the outer two
+    let entries: Vec<String> = client_sender
+        .get_sent_messages()
+        .await
+        .into_iter()
+        .filter_map(|(_, data)| serde_json::from_slice::<serde_json::Value>(&data).ok())
+        .filter(|event| event.get("type").and_then(|t| t.as_str()) == Some("gorc_zone_enter"))
+        .filter_map(|event| event.get("object_id").and_then(|id| id.as_str()).map(str::to_string))
+        .collect();
+    assert_eq!(entries.iter().filter(|id| **id == near_id.to_string()).count(), 3);
+    assert_eq!(entries.iter().filter(|id| **id == mid_id.to_string()).count(), 2);
+
+    // Moving both away in one batch reports the exits per object
+    let moves = gorc_manager
+        .update_positions(&[(near_id, far), (mid_id, far)])
+        .await;
+    assert_eq!(moves.len(), 2);
+    assert_eq!(moves[0].old_position, Vec3::new(25.0, 25.0, 0.0));
+    assert!(moves[0].zone_changes.iter().all(|&(_, _, is_entry)| !is_entry));
+    assert_eq!(moves[0].zone_changes.len(), 3);
+    assert_eq!(moves[1].zone_changes.len(), 2);
+}
+
 #[tokio::test]
 async fn test_new_object_creation_zone_events() {
     let mut events = EventSystem::new();
@@ -336,4 +396,334 @@ impl GorcObject for TestGorcLargeZoneObject {
     fn clone_object(&self) -> Box<dyn GorcObject> {
         Box::new(self.clone())
     }
-}
\ No newline at end of file
+}
+#[tokio::test]
+async fn test_expired_object_despawns_with_zone_exits() {
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new());
+    let client_sender = Arc::new(MockClientSender::new());
+
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(client_sender.clone());
+
+    let player_id = PlayerId::new();
+    events.update_player_position(player_id, Vec3::new(25.0, 25.0, 0.0)).await.unwrap();
+
+    // A short-lived projectile next to the player, and a permanent object
+    let projectile = TestGorcObject::new(Vec3::new(0.0, 0.0, 0.0), "projectile".to_string());
+    let projectile_id = gorc_manager
+        .register_object_with_ttl(projectile, Vec3::new(0.0, 0.0, 0.0), Duration::from_millis(30))
+        .await;
+    let station = TestGorcObject::new(Vec3::new(5000.0, 0.0, 0.0), "station".to_string());
+    let station_id = gorc_manager.register_object(station, Vec3::new(5000.0, 0.0, 0.0)).await;
+    events.notify_players_for_new_gorc_object(projectile_id).await.unwrap();
+
+    assert!(gorc_manager.get_object_ttl(projectile_id).await.is_some());
+    assert_eq!(events.despawn_expired_gorc_objects().await.unwrap(), 0);
+
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(events.despawn_expired_gorc_objects().await.unwrap(), 1);
+    assert!(gorc_manager.get_object(projectile_id).await.is_none());
+    assert!(gorc_manager.get_object(station_id).await.is_some());
+
+    // The player is told it left every zone it was subscribed to
+    let exits = client_sender
+        .get_sent_messages()
+        .await
+        .into_iter()
+        .filter_map(|(_, data)| serde_json::from_slice::<serde_json::Value>(&data).ok())
+        .filter(|event| event["type"] == "gorc_zone_exit")
+        .collect::<Vec<_>>();
+    assert_eq!(exits.len(), 3);
+    assert!(exits.iter().all(|event| event["object_id"] == projectile_id.to_string()));
+    assert!(exits.iter().all(|event| event["object_type"] == "TestObject"));
+
+    assert_eq!(gorc_manager.get_stats().await.objects_expired, 1);
+    assert_eq!(events.despawn_expired_gorc_objects().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_failed_zone_exit_does_not_stop_despawn() {
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new());
+    let client_sender = Arc::new(MockClientSender::new());
+
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(client_sender.clone());
+
+    let player_id = PlayerId::new();
+    events.update_player_position(player_id, Vec3::new(25.0, 25.0, 0.0)).await.unwrap();
+    let origin = Vec3::new(0.0, 0.0, 0.0);
+    let mut projectiles = Vec::new();
+    for _ in 0..2 {
+        let projectile = TestGorcObject::new(origin, "projectile".to_string());
+        let projectile_id = gorc_manager.register_object_with_ttl(projectile, origin, Duration::from_millis(30)).await;
+        events.notify_players_for_new_gorc_object(projectile_id).await.unwrap();
+        projectiles.push(projectile_id);
+    }
+
+    // Every zone exit event is rejected by its schema
+    events
+        .register_core_schema("gorc_zone_exited", crate::system::EventSchema::json(serde_json::json!({
+            "type": "object",
+            "required": ["never_present"]
+        })))
+        .unwrap();
+
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(events.despawn_expired_gorc_objects().await.unwrap(), 2);
+    for projectile_id in projectiles {
+        assert!(gorc_manager.get_object(projectile_id).await.is_none());
+    }
+
+    // The client is still told about every zone it left
+    let exits = client_sender
+        .get_sent_messages()
+        .await
+        .into_iter()
+        .filter_map(|(_, data)| serde_json::from_slice::<serde_json::Value>(&data).ok())
+        .filter(|event| event["type"] == "gorc_zone_exit")
+        .count();
+    assert_eq!(exits, 6);
+}
+
+#[tokio::test]
+async fn test_object_ttl_can_be_extended_and_cleared() {
+    let gorc_manager = GorcInstanceManager::new();
+    let origin = Vec3::new(0.0, 0.0, 0.0);
+    let object_id = gorc_manager
+        .register_object_with_ttl(TestGorcObject::new(origin, "loot".to_string()), origin, Duration::from_millis(10))
+        .await;
+
+    assert!(gorc_manager.set_object_ttl(object_id, Some(Duration::from_secs(60))).await);
+    sleep(Duration::from_millis(20)).await;
+    assert!(gorc_manager.expired_objects().await.is_empty());
+
+    assert!(gorc_manager.set_object_ttl(object_id, None).await);
+    assert_eq!(gorc_manager.get_object_ttl(object_id).await, None);
+
+    // Manually unregistering before expiry isn't counted as an expiry
+    gorc_manager.set_object_ttl(object_id, Some(Duration::from_secs(60))).await;
+    assert!(gorc_manager.unregister_object(object_id).await);
+    assert!(!gorc_manager.set_object_ttl(object_id, Some(Duration::from_secs(1))).await);
+    assert_eq!(gorc_manager.get_stats().await.objects_expired, 0);
+}
+
+#[tokio::test]
+async fn test_zone_changes_emit_core_events() {
+    use crate::events::{GorcZoneEnteredEvent, GorcZoneExitedEvent};
+
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new());
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(Arc::new(MockClientSender::new()));
+
+    let entered = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let exited = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let entered_log = entered.clone();
+    events.on_core("gorc_zone_entered", move |event: GorcZoneEnteredEvent| {
+        entered_log.lock().unwrap().push(event);
+        Ok(())
+    }).await.unwrap();
+    let exited_log = exited.clone();
+    events.on_core("gorc_zone_exited", move |event: GorcZoneExitedEvent| {
+        exited_log.lock().unwrap().push(event);
+        Ok(())
+    }).await.unwrap();
+
+    let object_id = gorc_manager
+        .register_object(TestGorcObject::new(Vec3::new(0.0, 0.0, 0.0), "asteroid".to_string()), Vec3::new(0.0, 0.0, 0.0))
+        .await;
+    let player_id = PlayerId::new();
+
+    // Walk into all three zones, then pull back out of the inner one
+    events.update_player_position(player_id, Vec3::new(25.0, 25.0, 0.0)).await.unwrap();
+    events.update_player_position(player_id, Vec3::new(100.0, 0.0, 0.0)).await.unwrap();
+
+    // Moving the object away drops the remaining two zones
+    events.update_object_position(object_id, Vec3::new(5000.0, 0.0, 0.0)).await.unwrap();
+
+    let entered = entered.lock().unwrap();
+    let mut entered_channels: Vec<u8> = entered.iter().map(|event| event.channel).collect();
+    entered_channels.sort();
+    assert_eq!(entered_channels, vec![0, 1, 2]);
+    assert!(entered.iter().all(|event| event.player_id == player_id && event.object_id == object_id));
+    assert!(entered.iter().all(|event| event.object_type == "TestObject"));
+
+    let exited = exited.lock().unwrap();
+    let mut exited_channels: Vec<u8> = exited.iter().map(|event| event.channel).collect();
+    assert_eq!(exited_channels[0], 0);
+    exited_channels.sort();
+    assert_eq!(exited_channels, vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn test_delivered_messages_update_replication_stats() {
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new());
+    let client_sender = Arc::new(MockClientSender::new());
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(client_sender.clone());
+
+    let object_id = gorc_manager
+        .register_object(TestGorcObject::new(Vec3::new(0.0, 0.0, 0.0), "beacon".to_string()), Vec3::new(0.0, 0.0, 0.0))
+        .await;
+    let player_id = PlayerId::new();
+    gorc_manager.add_player(player_id, Vec3::new(1000.0, 1000.0, 0.0)).await;
+
+    // Zone entry messages count as replication traffic
+    events.update_player_position(player_id, Vec3::new(10.0, 0.0, 0.0)).await.unwrap();
+    let entry_messages = client_sender.get_sent_messages().await;
+    let entry_bytes: u64 = entry_messages.iter().map(|(_, data)| data.len() as u64).sum();
+    assert!(!entry_messages.is_empty());
+
+    let stats = gorc_manager.get_stats().await;
+    assert_eq!(stats.replication_events_sent, entry_messages.len() as u64);
+    assert_eq!(stats.total_bytes_transmitted, entry_bytes);
+
+    // So do instance events replicated to subscribers
+    let update = serde_json::json!({ "heading": 90 });
+    events
+        .emit_gorc_instance(object_id, 0, "heading", &update, crate::events::Dest::Client)
+        .await
+        .unwrap();
+    let messages = client_sender.get_sent_messages().await;
+    let total_bytes: u64 = messages.iter().map(|(_, data)| data.len() as u64).sum();
+    assert_eq!(messages.len(), entry_messages.len() + 1);
+
+    let stats = gorc_manager.get_stats().await;
+    assert_eq!(stats.replication_events_sent, messages.len() as u64);
+    assert_eq!(stats.total_bytes_transmitted, total_bytes);
+
+    let object_stats = gorc_manager.get_object(object_id).await.unwrap().stats;
+    assert_eq!(object_stats.updates_sent, messages.len() as u64);
+    assert_eq!(object_stats.bytes_transmitted, total_bytes);
+}
+
+#[tokio::test]
+async fn test_zone_inspection_reports_subscriptions() {
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new());
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(Arc::new(MockClientSender::new()));
+
+    let object_id = gorc_manager
+        .register_object(TestGorcObject::new(Vec3::new(0.0, 0.0, 0.0), "station".to_string()), Vec3::new(0.0, 0.0, 0.0))
+        .await;
+    let near = PlayerId::new();
+    let far = PlayerId::new();
+    for player_id in [near, far] {
+        gorc_manager.add_player(player_id, Vec3::new(5000.0, 0.0, 0.0)).await;
+    }
+    events.update_player_position(near, Vec3::new(100.0, 0.0, 0.0)).await.unwrap();
+    events.update_player_position(far, Vec3::new(2000.0, 0.0, 0.0)).await.unwrap();
+
+    let inspection = gorc_manager.inspect_zones().await;
+    let object = inspection.object(object_id).unwrap();
+    assert_eq!(object.object_type, "TestObject");
+    let channels: Vec<u8> = object.zones.iter().map(|zone| zone.channel).collect();
+    assert_eq!(channels, vec![0, 1, 2]);
+    assert!(object.zones[0].subscribers.is_empty());
+    assert_eq!(object.zones[1].subscribers, vec![near]);
+    assert_eq!(object.zones[1].exit_radius, 150.0 * (1.0 + gorc_manager.zone_exit_margin()));
+
+    // Players out of range are listed with no subscriptions
+    let subscriptions = &inspection.player(near).unwrap().subscriptions;
+    assert_eq!(subscriptions.len(), 1);
+    assert_eq!(subscriptions[0].channels, vec![1, 2]);
+    assert_eq!(subscriptions[0].distance, Some(100.0));
+    assert!(inspection.player(far).unwrap().subscriptions.is_empty());
+
+    let filtered = inspection.for_player(far);
+    assert_eq!(filtered.players.len(), 1);
+    assert!(filtered.objects.is_empty());
+}
+
+#[tokio::test]
+async fn test_forced_subscription_survives_recalculation() {
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new());
+    let client_sender = Arc::new(MockClientSender::new());
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(client_sender.clone());
+
+    let object_id = gorc_manager
+        .register_object(TestGorcObject::new(Vec3::new(0.0, 0.0, 0.0), "arena".to_string()), Vec3::new(0.0, 0.0, 0.0))
+        .await;
+    let spectator = PlayerId::new();
+    gorc_manager.add_player(spectator, Vec3::new(5000.0, 0.0, 0.0)).await;
+    events.update_player_position(spectator, Vec3::new(5000.0, 0.0, 0.0)).await.unwrap();
+
+    // Pinning a distant player subscribes them and sends the zone entry
+    assert!(events.force_subscribe(spectator, object_id, 0).await.unwrap());
+    assert!(!events.force_subscribe(spectator, object_id, 0).await.unwrap());
+    assert!(events.force_subscribe(spectator, object_id, 9).await.is_err());
+    let messages = client_sender.get_sent_messages().await;
+    assert_eq!(messages.len(), 1);
+    let entry: serde_json::Value = serde_json::from_slice(&messages[0].1).unwrap();
+    assert_eq!(entry["type"], "gorc_zone_enter");
+
+    // Neither player nor object movement drops the pin
+    events.update_player_position(spectator, Vec3::new(6000.0, 0.0, 0.0)).await.unwrap();
+    events.update_object_position(object_id, Vec3::new(-3000.0, 0.0, 0.0)).await.unwrap();
+    events.update_object_positions(&[(object_id, Vec3::new(-4000.0, 0.0, 0.0))]).await.unwrap();
+    let instance = gorc_manager.get_object(object_id).await.unwrap();
+    assert!(instance.is_subscribed(0, spectator));
+    assert!(!instance.is_subscribed(1, spectator));
+    assert!(gorc_manager.is_subscription_pinned(spectator, object_id, 0).await);
+    let inspection = gorc_manager.inspect_zones().await;
+    assert_eq!(inspection.object(object_id).unwrap().zones[0].pinned, vec![spectator]);
+
+    // Releasing the pin out of range unsubscribes the player
+    assert!(events.force_unsubscribe(spectator, object_id, 0).await.unwrap());
+    assert!(events.force_unsubscribe(spectator, object_id, 0).await.is_err());
+    assert!(!gorc_manager.get_object(object_id).await.unwrap().is_subscribed(0, spectator));
+
+    // Releasing it in range keeps the normal subscription
+    events.update_player_position(spectator, Vec3::new(-4010.0, 0.0, 0.0)).await.unwrap();
+    assert_eq!(gorc_manager.force_subscribe(spectator, object_id, 0).await, Some(false));
+    assert_eq!(gorc_manager.force_unsubscribe(spectator, object_id, 0).await, Some(false));
+    assert!(gorc_manager.get_object(object_id).await.unwrap().is_subscribed(0, spectator));
+}
+
+#[tokio::test]
+async fn test_dead_reckoning_enters_zones_between_updates() {
+    let mut events = EventSystem::new();
+    let gorc_manager = Arc::new(GorcInstanceManager::new().with_dead_reckoning_horizon(Duration::from_millis(100)));
+    let client_sender = Arc::new(MockClientSender::new());
+    events.set_gorc_instances(gorc_manager.clone());
+    events.set_client_response_sender(client_sender.clone());
+
+    let object_id = gorc_manager
+        .register_object(TestGorcObject::new(Vec3::new(0.0, 0.0, 0.0), "station".to_string()), Vec3::new(0.0, 0.0, 0.0))
+        .await;
+    let ship = PlayerId::new();
+    events
+        .update_player_motion(ship, Vec3::new(400.0, 0.0, 0.0), Vec3::new(-2000.0, 0.0, 0.0))
+        .await
+        .unwrap();
+    assert_eq!(events.update_predicted_zones().await.unwrap(), 0);
+
+    // Extrapolation stops at the horizon: 400m - 2000m/s * 0.1s = 200m
+    sleep(Duration::from_millis(150)).await;
+    assert_eq!(gorc_manager.get_predicted_player_position(ship).await, Some(Vec3::new(200.0, 0.0, 0.0)));
+    assert_eq!(gorc_manager.get_player_position(ship).await, Some(Vec3::new(400.0, 0.0, 0.0)));
+    assert_eq!(events.update_predicted_zones().await.unwrap(), 1);
+    assert_eq!(events.update_predicted_zones().await.unwrap(), 0);
+    let instance = gorc_manager.get_object(object_id).await.unwrap();
+    assert!(instance.is_subscribed(2, ship));
+    assert!(!instance.is_subscribed(1, ship));
+    let messages = client_sender.get_sent_messages().await;
+    assert_eq!(messages.len(), 1);
+    let entry: serde_json::Value = serde_json::from_slice(&messages[0].1).unwrap();
+    assert_eq!(entry["type"], "gorc_zone_enter");
+
+    // Without a horizon zones only follow reported positions
+    let manager = GorcInstanceManager::new();
+    manager.register_object(TestGorcObject::new(Vec3::new(0.0, 0.0, 0.0), "station".to_string()), Vec3::new(0.0, 0.0, 0.0)).await;
+    manager.update_player_velocity(ship, Vec3::new(-2000.0, 0.0, 0.0)).await;
+    manager.update_player_position(ship, Vec3::new(400.0, 0.0, 0.0)).await;
+    sleep(Duration::from_millis(50)).await;
+    assert!(manager.refresh_predicted_zones().await.is_empty());
+    assert_eq!(manager.get_predicted_player_position(ship).await, Some(Vec3::new(400.0, 0.0, 0.0)));
+}
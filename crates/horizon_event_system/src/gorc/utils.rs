@@ -4,8 +4,8 @@
 //! validation tools, and performance monitoring capabilities.
 
 use super::{
-    defaults, CompleteGorcSystem, GorcError, GorcInstanceManager, GorcPerformanceReport,
-    NetworkReplicationEngine, ReplicationCoordinator,
+    defaults, CompleteGorcSystem, GorcError, GorcInstanceManager, GorcNetworkConfig,
+    GorcPerformanceReport, NetworkConfig, NetworkReplicationEngine, ReplicationCoordinator,
 };
 use crate::context::ServerContext;
 use std::sync::Arc;
@@ -42,9 +42,38 @@ use std::sync::Arc;
 pub fn create_complete_gorc_system(
     server_context: Arc<dyn ServerContext>
 ) -> Result<CompleteGorcSystem, GorcError> {
-    let instance_manager = Arc::new(GorcInstanceManager::new());
-    let network_config = defaults::default_network_config();
-    
+    build_gorc_system(server_context, Arc::new(GorcInstanceManager::new()), defaults::default_network_config())
+}
+
+/// Creates a complete GORC system using the server's GORC network settings.
+/// 
+/// Network settings such as batch size, channel frequencies and the
+/// compression threshold are taken from `network`. Objects are replicated
+/// from `instance_manager`, so a server can share the instance manager its
+/// event system already uses.
+/// 
+/// # Arguments
+/// 
+/// * `server_context` - Server context for network communication
+/// * `instance_manager` - Instance manager holding the replicated objects
+/// * `network` - Server GORC network settings
+/// 
+/// # Returns
+/// 
+/// A complete GORC system ready for use, or a `GorcError` if initialization failed.
+pub fn create_complete_gorc_system_with_config(
+    server_context: Arc<dyn ServerContext>,
+    instance_manager: Arc<GorcInstanceManager>,
+    network: &GorcNetworkConfig,
+) -> Result<CompleteGorcSystem, GorcError> {
+    build_gorc_system(server_context, instance_manager, NetworkConfig::from(network))
+}
+
+fn build_gorc_system(
+    server_context: Arc<dyn ServerContext>,
+    instance_manager: Arc<GorcInstanceManager>,
+    network_config: NetworkConfig,
+) -> Result<CompleteGorcSystem, GorcError> {
     let network_engine = Arc::new(NetworkReplicationEngine::new(
         network_config,
         instance_manager.clone(),
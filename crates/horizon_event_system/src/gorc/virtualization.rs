@@ -10,12 +10,14 @@
 //! - Dynamic spatial index optimization
 //! - Configurable density thresholds and merge criteria
 //! - Event-driven merge/split notifications
+//! - Pluggable [`VirtualizationStrategy`] deciding which zones merge and when they split
 
 use crate::types::Vec3;
 use crate::gorc::instance::GorcObjectId;
 use crate::gorc::channels::ReplicationLayer;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, info};
@@ -37,6 +39,23 @@ pub struct VirtualizationConfig {
     pub check_interval_ms: u64,
     /// Maximum number of objects in a virtual zone before forcing split
     pub max_objects_per_virtual_zone: usize,
+    /// Heuristic deciding which zones merge and when virtual zones split
+    #[serde(default)]
+    pub strategy: VirtualizationStrategyKind,
+    /// Cell size of the static grid strategy
+    #[serde(default = "default_grid_cell_size")]
+    pub grid_cell_size: f64,
+    /// Minimum number of players near a cluster for the player-count strategy to merge it
+    #[serde(default = "default_min_players_per_virtual_zone")]
+    pub min_players_per_virtual_zone: usize,
+}
+
+fn default_grid_cell_size() -> f64 {
+    500.0
+}
+
+fn default_min_players_per_virtual_zone() -> usize {
+    10
 }
 
 impl Default for VirtualizationConfig {
@@ -49,10 +68,357 @@ impl Default for VirtualizationConfig {
             min_zone_radius: 50.0,
             check_interval_ms: 1000, // Check every second
             max_objects_per_virtual_zone: 50,
+            strategy: VirtualizationStrategyKind::default(),
+            grid_cell_size: default_grid_cell_size(),
+            min_players_per_virtual_zone: default_min_players_per_virtual_zone(),
+        }
+    }
+}
+
+/// A zone considered for virtualization: owning object, center and radius
+pub type ZoneCandidate = (GorcObjectId, Vec3, f64);
+
+/// Built-in virtualization strategies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum VirtualizationStrategyKind {
+    /// Merges clusters of heavily overlapping zones
+    #[default]
+    #[serde(rename = "density")]
+    Density,
+    /// Merges overlapping zones only where enough players are nearby to make
+    /// per-object zone checks expensive
+    #[serde(rename = "player_count")]
+    PlayerCount,
+    /// Merges all zones whose centers share a fixed grid cell
+    #[serde(rename = "static_grid")]
+    StaticGrid,
+}
+
+impl VirtualizationStrategyKind {
+    /// Every built-in strategy
+    pub const ALL: [VirtualizationStrategyKind; 3] = [Self::Density, Self::PlayerCount, Self::StaticGrid];
+
+    /// Configuration name of the strategy
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Density => "density",
+            Self::PlayerCount => "player_count",
+            Self::StaticGrid => "static_grid",
+        }
+    }
+
+    /// Creates the strategy
+    pub fn build(&self) -> Box<dyn VirtualizationStrategy> {
+        match self {
+            Self::Density => Box::new(DensityStrategy),
+            Self::PlayerCount => Box::new(PlayerCountStrategy),
+            Self::StaticGrid => Box::new(StaticGridStrategy),
+        }
+    }
+}
+
+impl fmt::Display for VirtualizationStrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Heuristic deciding which zones collapse into virtual zones.
+///
+/// The [`VirtualizationManager`] enforces the configured object count and
+/// radius limits itself; strategies only decide what is worth merging and
+/// when a virtual zone no longer pays off.
+pub trait VirtualizationStrategy: Send + Sync + fmt::Debug {
+    /// Name of the strategy, used in logs
+    fn name(&self) -> &str;
+
+    /// Groups one channel's zones into clusters that should each become a virtual zone
+    ///
+    /// # Arguments
+    ///
+    /// * `zones` - Zones on the channel at or above the minimum zone radius
+    /// * `players` - Current player positions
+    /// * `config` - Virtualization configuration
+    fn find_merge_clusters(
+        &self,
+        zones: &[ZoneCandidate],
+        players: &[Vec3],
+        config: &VirtualizationConfig,
+    ) -> Vec<Vec<ZoneCandidate>>;
+
+    /// Checks whether an existing virtual zone should be split
+    ///
+    /// # Arguments
+    ///
+    /// * `zone` - The virtual zone
+    /// * `positions` - Current positions of the zone's objects
+    /// * `players` - Current player positions
+    /// * `config` - Virtualization configuration
+    fn should_split(
+        &self,
+        zone: &VirtualZone,
+        positions: &[Vec3],
+        players: &[Vec3],
+        config: &VirtualizationConfig,
+    ) -> bool;
+}
+
+/// Merges clusters of overlapping zones whose average overlap meets the density threshold
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DensityStrategy;
+
+impl VirtualizationStrategy for DensityStrategy {
+    fn name(&self) -> &str {
+        VirtualizationStrategyKind::Density.name()
+    }
+
+    fn find_merge_clusters(
+        &self,
+        zones: &[ZoneCandidate],
+        _players: &[Vec3],
+        config: &VirtualizationConfig,
+    ) -> Vec<Vec<ZoneCandidate>> {
+        find_overlapping_clusters(zones, config.overlap_threshold)
+            .into_iter()
+            .filter(|cluster| {
+                let density = calculate_cluster_density(cluster);
+                debug!("🔍 Cluster of {} zones density: {:.3} (threshold: {:.3})",
+                       cluster.len(), density, config.density_threshold);
+                density >= config.density_threshold
+            })
+            .collect()
+    }
+
+    fn should_split(
+        &self,
+        zone: &VirtualZone,
+        positions: &[Vec3],
+        _players: &[Vec3],
+        _config: &VirtualizationConfig,
+    ) -> bool {
+        // If objects are spread beyond the original virtual zone radius, consider splitting
+        max_spread(positions) > zone.radius * 1.5
+    }
+}
+
+/// Merges overlapping zones only where enough players are close by.
+///
+/// Sparse areas keep their precise per-object zones; crowded areas, where every
+/// player would otherwise be checked against every zone, collapse into virtual zones.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlayerCountStrategy;
+
+impl VirtualizationStrategy for PlayerCountStrategy {
+    fn name(&self) -> &str {
+        VirtualizationStrategyKind::PlayerCount.name()
+    }
+
+    fn find_merge_clusters(
+        &self,
+        zones: &[ZoneCandidate],
+        players: &[Vec3],
+        config: &VirtualizationConfig,
+    ) -> Vec<Vec<ZoneCandidate>> {
+        find_overlapping_clusters(zones, config.overlap_threshold)
+            .into_iter()
+            .filter(|cluster| {
+                cluster_players(cluster, players) >= config.min_players_per_virtual_zone
+            })
+            .collect()
+    }
+
+    fn should_split(
+        &self,
+        zone: &VirtualZone,
+        positions: &[Vec3],
+        players: &[Vec3],
+        config: &VirtualizationConfig,
+    ) -> bool {
+        // Split at half the merge threshold so zones don't flap around it
+        let nearby = players.iter().filter(|player| player.distance(zone.center) <= zone.radius).count();
+        nearby < config.min_players_per_virtual_zone.div_ceil(2) || max_spread(positions) > zone.radius * 1.5
+    }
+}
+
+/// Merges every zone whose center falls in the same fixed grid cell.
+///
+/// Virtual zones line up with the grid regardless of overlap, which makes their
+/// layout predictable for level designers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StaticGridStrategy;
+
+impl StaticGridStrategy {
+    fn cell(position: Vec3, cell_size: f64) -> (i64, i64, i64) {
+        (
+            (position.x / cell_size).floor() as i64,
+            (position.y / cell_size).floor() as i64,
+            (position.z / cell_size).floor() as i64,
+        )
+    }
+}
+
+impl VirtualizationStrategy for StaticGridStrategy {
+    fn name(&self) -> &str {
+        VirtualizationStrategyKind::StaticGrid.name()
+    }
+
+    fn find_merge_clusters(
+        &self,
+        zones: &[ZoneCandidate],
+        _players: &[Vec3],
+        config: &VirtualizationConfig,
+    ) -> Vec<Vec<ZoneCandidate>> {
+        let mut cells: HashMap<(i64, i64, i64), Vec<ZoneCandidate>> = HashMap::new();
+        for &zone in zones {
+            cells.entry(Self::cell(zone.1, config.grid_cell_size)).or_default().push(zone);
         }
+        cells.into_values().filter(|cluster| cluster.len() >= 2).collect()
+    }
+
+    fn should_split(
+        &self,
+        zone: &VirtualZone,
+        positions: &[Vec3],
+        _players: &[Vec3],
+        config: &VirtualizationConfig,
+    ) -> bool {
+        let home = zone
+            .original_zones
+            .first()
+            .map(|original| Self::cell(original.center, config.grid_cell_size));
+        positions.iter().any(|&position| Some(Self::cell(position, config.grid_cell_size)) != home)
     }
 }
 
+/// Groups zones into clusters where each zone overlaps at least one other member
+fn find_overlapping_clusters(objects: &[ZoneCandidate], overlap_threshold: f64) -> Vec<Vec<ZoneCandidate>> {
+    debug!("🔍 find_overlapping_clusters called with {} objects", objects.len());
+    let mut clusters = Vec::new();
+    let mut visited = HashSet::new();
+
+    for (i, &(object_id, position, radius)) in objects.iter().enumerate() {
+        if visited.contains(&i) {
+            continue;
+        }
+
+        let mut cluster = vec![(object_id, position, radius)];
+        visited.insert(i);
+
+        // Find all objects that overlap with any object in the current cluster
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for (j, &(other_id, other_pos, other_radius)) in objects.iter().enumerate() {
+                if visited.contains(&j) {
+                    continue;
+                }
+
+                // Check if this object overlaps with any object in the cluster
+                for &(_, cluster_pos, cluster_radius) in &cluster {
+                    let distance = other_pos.distance(cluster_pos);
+                    let overlap_distance = cluster_radius + other_radius;
+                    let overlap_ratio = (overlap_distance - distance) / overlap_distance.min(cluster_radius.min(other_radius) * 2.0);
+
+                    if overlap_ratio >= overlap_threshold {
+                        cluster.push((other_id, other_pos, other_radius));
+                        visited.insert(j);
+                        changed = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        if cluster.len() >= 2 {
+            clusters.push(cluster);
+        }
+    }
+
+    clusters
+}
+
+/// Average pairwise overlap ratio of a cluster's zones
+fn calculate_cluster_density(cluster: &[ZoneCandidate]) -> f64 {
+    if cluster.is_empty() {
+        return 0.0;
+    }
+
+    // For virtualization, density should represent how much the zones overlap
+    // Calculate average overlap ratio within the cluster
+    let mut total_overlap = 0.0;
+    let mut comparison_count = 0;
+
+    for (i, &(_, pos1, radius1)) in cluster.iter().enumerate() {
+        for &(_, pos2, radius2) in cluster.iter().skip(i + 1) {
+            let distance = pos1.distance(pos2);
+            let max_possible_distance = radius1 + radius2;
+            let overlap_ratio = if distance < max_possible_distance {
+                (max_possible_distance - distance) / max_possible_distance
+            } else {
+                0.0
+            };
+            total_overlap += overlap_ratio;
+            comparison_count += 1;
+        }
+    }
+
+    if comparison_count == 0 {
+        return 0.0;
+    }
+
+    total_overlap / comparison_count as f64
+}
+
+/// Number of players inside any zone of a cluster
+fn cluster_players(cluster: &[ZoneCandidate], players: &[Vec3]) -> usize {
+    players
+        .iter()
+        .filter(|player| cluster.iter().any(|&(_, center, radius)| player.distance(center) <= radius))
+        .count()
+}
+
+/// Largest distance between any two positions
+fn max_spread(positions: &[Vec3]) -> f64 {
+    let mut max_distance: f64 = 0.0;
+    for i in 0..positions.len() {
+        for j in i + 1..positions.len() {
+            max_distance = max_distance.max(positions[i].distance(positions[j]));
+        }
+    }
+    max_distance
+}
+
+/// Merge or split performed by the virtualization manager
+#[derive(Debug, Clone, PartialEq)]
+pub enum VirtualZoneEvent {
+    /// Zones were merged into a new virtual zone
+    Merged {
+        /// The new virtual zone
+        virtual_id: VirtualZoneId,
+        /// Replication channel of the merged zones
+        channel: u8,
+        /// Center of the virtual zone
+        center: Vec3,
+        /// Radius of the virtual zone
+        radius: f64,
+        /// Objects whose zones were merged
+        object_ids: Vec<GorcObjectId>,
+    },
+    /// A virtual zone was split back into its objects' own zones
+    Split {
+        /// The removed virtual zone
+        virtual_id: VirtualZoneId,
+        /// Replication channel of the virtual zone
+        channel: u8,
+        /// Objects that were members of the virtual zone
+        object_ids: Vec<GorcObjectId>,
+    },
+}
+
+/// Largest number of merge/split events kept until they are drained
+const MAX_PENDING_EVENTS: usize = 1024;
+
 /// Represents a virtualized zone that encompasses multiple overlapping GORC zones
 #[derive(Debug, Clone)]
 pub struct VirtualZone {
@@ -111,6 +477,8 @@ pub struct VirtualZoneStats {
 pub struct VirtualizationManager {
     /// Configuration settings
     config: VirtualizationConfig,
+    /// Heuristic deciding which zones merge and split
+    strategy: Box<dyn VirtualizationStrategy>,
     /// Active virtual zones by channel
     virtual_zones: Arc<RwLock<HashMap<u8, HashMap<VirtualZoneId, VirtualZone>>>>,
     /// Mapping from object to virtual zones it participates in
@@ -121,6 +489,8 @@ pub struct VirtualizationManager {
     stats: Arc<RwLock<VirtualizationStats>>,
     /// Next virtual zone ID
     next_virtual_id: Arc<RwLock<u64>>,
+    /// Merges and splits not yet drained, oldest first
+    pending_events: Arc<RwLock<VecDeque<VirtualZoneEvent>>>,
 }
 
 /// Tracks object density in spatial regions
@@ -155,19 +525,46 @@ impl VirtualizationManager {
     /// Creates a new virtualization manager with the given configuration
     pub fn new(config: VirtualizationConfig) -> Self {
         Self {
+            strategy: config.strategy.build(),
             config,
             virtual_zones: Arc::new(RwLock::new(HashMap::new())),
             object_to_virtual: Arc::new(RwLock::new(HashMap::new())),
             density_tracker: Arc::new(RwLock::new(DensityTracker::new())),
             stats: Arc::new(RwLock::new(VirtualizationStats::default())),
             next_virtual_id: Arc::new(RwLock::new(1)),
+            pending_events: Arc::new(RwLock::new(VecDeque::new())),
         }
     }
 
+    /// Replaces the strategy selected by the configuration with a custom one
+    pub fn with_strategy(mut self, strategy: Box<dyn VirtualizationStrategy>) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Gets the name of the active virtualization strategy
+    pub fn strategy_name(&self) -> &str {
+        self.strategy.name()
+    }
+
     /// Analyzes the given objects and returns merge/split recommendations
     pub async fn analyze_virtualization_opportunities(
         &self,
         objects: &HashMap<GorcObjectId, (Vec3, Vec<ReplicationLayer>)>
+    ) -> VirtualizationRecommendations {
+        self.analyze_virtualization_opportunities_with_players(objects, &[]).await
+    }
+
+    /// Analyzes the given objects and player positions and returns merge/split recommendations
+    ///
+    /// # Arguments
+    ///
+    /// * `objects` - Positions and replication layers of every object
+    /// * `players` - Current player positions, used by player-aware strategies
+    pub async fn analyze_virtualization_opportunities_with_players(
+        &self,
+        objects: &HashMap<GorcObjectId, (Vec3, Vec<ReplicationLayer>)>,
+        players: &[Vec3],
     ) -> VirtualizationRecommendations {
         if !self.config.enabled {
             return VirtualizationRecommendations::default();
@@ -180,7 +577,7 @@ impl VirtualizationManager {
         self.update_density_tracking(objects).await;
 
         // Group objects by channel for analysis
-        let mut objects_by_channel: HashMap<u8, Vec<(GorcObjectId, Vec3, f64)>> = HashMap::new();
+        let mut objects_by_channel: HashMap<u8, Vec<ZoneCandidate>> = HashMap::new();
 
         for (object_id, (position, layers)) in objects {
             for layer in layers {
@@ -201,13 +598,13 @@ impl VirtualizationManager {
 
         // Analyze each channel for virtualization opportunities
         for (channel, channel_objects) in objects_by_channel {
-            let channel_recommendations = self.analyze_channel_virtualization(channel, &channel_objects).await;
+            let channel_recommendations = self.analyze_channel_virtualization(channel, &channel_objects, players);
             recommendations.merge_recommendations.extend(channel_recommendations.merge_recommendations);
             recommendations.split_recommendations.extend(channel_recommendations.split_recommendations);
         }
 
         // Check existing virtual zones for split conditions
-        let split_recommendations = self.check_virtual_zones_for_splits(objects).await;
+        let split_recommendations = self.check_virtual_zones_for_splits(objects, players).await;
         recommendations.split_recommendations.extend(split_recommendations);
 
         let analysis_time = start_time.elapsed();
@@ -296,6 +693,20 @@ impl VirtualizationManager {
         info!("🔗 Created virtual zone {} covering {} objects on channel {} (radius: {:.1})",
               virtual_id.0, merge_request.zones.len(), merge_request.channel, radius);
 
+        let mut object_ids = Vec::with_capacity(merge_request.zones.len());
+        for zone_info in &merge_request.zones {
+            if !object_ids.contains(&zone_info.object_id) {
+                object_ids.push(zone_info.object_id);
+            }
+        }
+        self.record_event(VirtualZoneEvent::Merged {
+            virtual_id,
+            channel: merge_request.channel,
+            center,
+            radius,
+            object_ids,
+        }).await;
+
         Ok(virtual_id)
     }
 
@@ -352,6 +763,12 @@ impl VirtualizationManager {
         info!("✂️ Split virtual zone {} - liberated {} objects",
               virtual_id.0, liberated_objects.len());
 
+        self.record_event(VirtualZoneEvent::Split {
+            virtual_id,
+            channel: virtual_zone.channel,
+            object_ids: liberated_objects.clone(),
+        }).await;
+
         Ok(liberated_objects)
     }
 
@@ -445,145 +862,72 @@ impl VirtualizationManager {
         self.stats.read().await.clone()
     }
 
+    /// Takes the merges and splits performed since the last call, oldest first.
+    ///
+    /// Only the most recent events are kept if nothing drains them.
+    pub async fn drain_events(&self) -> Vec<VirtualZoneEvent> {
+        self.pending_events.write().await.drain(..).collect()
+    }
+
     // Private helper methods
 
+    async fn record_event(&self, event: VirtualZoneEvent) {
+        let mut pending_events = self.pending_events.write().await;
+        if pending_events.len() >= MAX_PENDING_EVENTS {
+            pending_events.pop_front();
+        }
+        pending_events.push_back(event);
+    }
+
     async fn update_density_tracking(&self, objects: &HashMap<GorcObjectId, (Vec3, Vec<ReplicationLayer>)>) {
         let mut density_tracker = self.density_tracker.write().await;
         density_tracker.update_density(objects);
     }
 
-    async fn analyze_channel_virtualization(
+    fn analyze_channel_virtualization(
         &self,
         channel: u8,
-        objects: &[(GorcObjectId, Vec3, f64)]
+        objects: &[ZoneCandidate],
+        players: &[Vec3],
     ) -> VirtualizationRecommendations {
         let mut recommendations = VirtualizationRecommendations::default();
 
-        debug!("🔍 Analyzing channel {} with {} objects for virtualization", channel, objects.len());
+        debug!("🔍 Analyzing channel {} with {} objects for virtualization ({} strategy)",
+               channel, objects.len(), self.strategy.name());
 
-        // Find clusters of overlapping zones
-        let clusters = self.find_overlapping_clusters(objects).await;
+        let clusters = self.strategy.find_merge_clusters(objects, players, &self.config);
         debug!("🔍 Found {} clusters for channel {}", clusters.len(), channel);
 
-        for (i, cluster) in clusters.iter().enumerate() {
-            debug!("🔍 Cluster {}: {} objects", i, cluster.len());
-            if cluster.len() >= 2 {
-                // Check density threshold
-                let density = self.calculate_cluster_density(&cluster).await;
-                debug!("🔍 Cluster {} density: {:.3} (threshold: {:.3})", i, density, self.config.density_threshold);
-
-                if density >= self.config.density_threshold {
-                    let merge_request = ZoneMergeRequest {
+        for cluster in clusters.into_iter().filter(|cluster| cluster.len() >= 2) {
+            let merge_request = ZoneMergeRequest {
+                channel,
+                zones: cluster.iter().map(|(object_id, position, radius)| {
+                    ZoneInfo {
+                        object_id: *object_id,
                         channel,
-                        zones: cluster.iter().map(|(object_id, position, radius)| {
-                            ZoneInfo {
-                                object_id: *object_id,
-                                channel,
-                                center: *position,
-                                radius: *radius,
-                            }
-                        }).collect(),
-                    };
-
-                    recommendations.merge_recommendations.push(merge_request);
-                }
-            }
-        }
-
-        recommendations
-    }
-
-    async fn find_overlapping_clusters(&self, objects: &[(GorcObjectId, Vec3, f64)]) -> Vec<Vec<(GorcObjectId, Vec3, f64)>> {
-        debug!("🔍 find_overlapping_clusters called with {} objects", objects.len());
-        let mut clusters = Vec::new();
-        let mut visited = HashSet::new();
-
-        for (i, &(object_id, position, radius)) in objects.iter().enumerate() {
-            if visited.contains(&i) {
-                continue;
-            }
-
-            let mut cluster = vec![(object_id, position, radius)];
-            let mut cluster_indices = vec![i];
-            visited.insert(i);
-
-            // Find all objects that overlap with any object in the current cluster
-            let mut changed = true;
-            while changed {
-                changed = false;
-
-                for (j, &(other_id, other_pos, other_radius)) in objects.iter().enumerate() {
-                    if visited.contains(&j) {
-                        continue;
+                        center: *position,
+                        radius: *radius,
                     }
+                }).collect(),
+            };
 
-                    // Check if this object overlaps with any object in the cluster
-                    for &(_, cluster_pos, cluster_radius) in &cluster {
-                        let distance = other_pos.distance(cluster_pos);
-                        let overlap_distance = cluster_radius + other_radius;
-                        let overlap_ratio = (overlap_distance - distance) / overlap_distance.min(cluster_radius.min(other_radius) * 2.0);
-
-                        // Debug logging can be enabled if needed
-                        // debug!("🔍 Checking overlap: distance={:.1}, overlap_ratio={:.3}, threshold={:.3}",
-                        //        distance, overlap_ratio, self.config.overlap_threshold);
-
-                        if overlap_ratio >= self.config.overlap_threshold {
-                            cluster.push((other_id, other_pos, other_radius));
-                            cluster_indices.push(j);
-                            visited.insert(j);
-                            changed = true;
-                            break;
-                        }
-                    }
-                }
-            }
-
-            if cluster.len() >= 2 {
-                clusters.push(cluster);
-            }
-        }
-
-        clusters
-    }
-
-    async fn calculate_cluster_density(&self, cluster: &[(GorcObjectId, Vec3, f64)]) -> f64 {
-        if cluster.is_empty() {
-            return 0.0;
-        }
-
-        // For virtualization, density should represent how much the zones overlap
-        // Calculate average overlap ratio within the cluster
-        let mut total_overlap = 0.0;
-        let mut comparison_count = 0;
-
-        for (i, &(_, pos1, radius1)) in cluster.iter().enumerate() {
-            for &(_, pos2, radius2) in cluster.iter().skip(i + 1) {
-                let distance = pos1.distance(pos2);
-                let max_possible_distance = radius1 + radius2;
-                let overlap_ratio = if distance < max_possible_distance {
-                    (max_possible_distance - distance) / max_possible_distance
-                } else {
-                    0.0
-                };
-                total_overlap += overlap_ratio;
-                comparison_count += 1;
-            }
-        }
-
-        if comparison_count == 0 {
-            return 0.0;
+            recommendations.merge_recommendations.push(merge_request);
         }
 
-        total_overlap / comparison_count as f64
+        recommendations
     }
 
-    async fn check_virtual_zones_for_splits(&self, objects: &HashMap<GorcObjectId, (Vec3, Vec<ReplicationLayer>)>) -> Vec<ZoneSplitRequest> {
+    async fn check_virtual_zones_for_splits(
+        &self,
+        objects: &HashMap<GorcObjectId, (Vec3, Vec<ReplicationLayer>)>,
+        players: &[Vec3],
+    ) -> Vec<ZoneSplitRequest> {
         let mut split_requests = Vec::new();
         let virtual_zones = self.virtual_zones.read().await;
 
         for (_, channel_zones) in virtual_zones.iter() {
             for (virtual_id, virtual_zone) in channel_zones {
-                let should_split = self.should_split_virtual_zone(virtual_zone, objects).await;
+                let should_split = self.should_split_virtual_zone(virtual_zone, objects, players);
 
                 if should_split {
                     split_requests.push(ZoneSplitRequest {
@@ -597,7 +941,12 @@ impl VirtualizationManager {
         split_requests
     }
 
-    async fn should_split_virtual_zone(&self, virtual_zone: &VirtualZone, objects: &HashMap<GorcObjectId, (Vec3, Vec<ReplicationLayer>)>) -> bool {
+    fn should_split_virtual_zone(
+        &self,
+        virtual_zone: &VirtualZone,
+        objects: &HashMap<GorcObjectId, (Vec3, Vec<ReplicationLayer>)>,
+        players: &[Vec3],
+    ) -> bool {
         // Check if too many objects
         if virtual_zone.included_objects.len() > self.config.max_objects_per_virtual_zone {
             return true;
@@ -608,20 +957,11 @@ impl VirtualizationManager {
             return true;
         }
 
-        // Check if objects have moved too far apart
-        let mut max_distance: f64 = 0.0;
+        // Let the strategy decide whether the zone still pays off
         let positions: Vec<Vec3> = virtual_zone.original_zones.iter()
             .filter_map(|zone| objects.get(&zone.object_id).map(|(pos, _)| *pos))
             .collect();
-
-        for i in 0..positions.len() {
-            for j in i + 1..positions.len() {
-                max_distance = max_distance.max(positions[i].distance(positions[j]));
-            }
-        }
-
-        // If objects are spread beyond the original virtual zone radius, consider splitting
-        max_distance > virtual_zone.radius * 1.5
+        self.strategy.should_split(virtual_zone, &positions, players, &self.config)
     }
 
     async fn should_split_due_to_spread(&self, virtual_zone: &VirtualZone) -> bool {
@@ -634,15 +974,8 @@ impl VirtualizationManager {
             return false;
         }
 
-        let mut max_distance: f64 = 0.0;
-        for i in 0..positions.len() {
-            for j in i + 1..positions.len() {
-                max_distance = max_distance.max(positions[i].distance(positions[j]));
-            }
-        }
-
         // Split if objects are now too spread out
-        max_distance > virtual_zone.radius * 2.0
+        max_spread(&positions) > virtual_zone.radius * 2.0
     }
 
     async fn calculate_optimal_bounding_circle(&self, zones: &[ZoneInfo]) -> Result<(Vec3, f64), VirtualizationError> {
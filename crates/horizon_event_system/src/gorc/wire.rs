@@ -0,0 +1,610 @@
+//! Wire formats for GORC replication messages.
+//!
+//! Replication messages (instance events, zone enter and zone exit) are sent
+//! either as JSON or as a compact binary frame. Every client starts on JSON
+//! and may switch to binary by negotiating with the server; JSON remains
+//! available as a human-readable debug format.
+//!
+//! Every message carries the server tick it was produced on and its send
+//! time in milliseconds, so clients can interpolate between snapshots and
+//! correlate events to ticks.
+//!
+//! ## Binary frame layout (version 3)
+//!
+//! | Offset | Size     | Field                                                 |
+//! |--------|----------|-------------------------------------------------------|
+//! | 0      | 1        | Magic byte `0xB7` (never valid UTF-8 at frame start)  |
+//! | 1      | 1        | Frame version (`3`)                                   |
+//! | 2      | 1        | Message kind (0 event, 1 zone enter, 2 zone exit)     |
+//! | 3      | 1        | Replication channel                                   |
+//! | 4      | 1        | Payload encoding (0 bincode, 1 JSON, 2 empty)         |
+//! | 5      | 16       | Object id (UUID bytes)                                |
+//! | 21     | 1-10     | Server tick (LEB128 varint)                           |
+//! | ..     | 1-10     | Send time in milliseconds (LEB128 varint)             |
+//! | ..     | 1 + n    | Name length and UTF-8 name (event name or object type)|
+//! | ..     | rest     | Payload                                               |
+//!
+//! The timestamp in seconds is the send time divided by 1000. Version 2
+//! frames carry it as a varint before the tick; version 1 frames carry only
+//! the timestamp and decode with the tick and send time set to zero.
+//!
+//! Bincode payloads use bincode's default options (varint integers, little
+//! endian), so clients decode them with the event's schema. Position fields
+//! marked `#[serde(with = "horizon_event_system::gorc::wire::compact_vec3")]`
+//! are read as three `f32`s; every other [`Vec3`](crate::types::Vec3) as
+//! three `f64`s. Events emitted as
+//! untyped `serde_json::Value`, and objects without a binary layer encoding,
+//! carry a JSON payload inside the binary frame instead.
+
+use crate::gorc::instance::GorcObjectId;
+use crate::types::PlayerId;
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use uuid::Uuid;
+
+/// First byte of every binary frame.
+pub const BINARY_FRAME_MAGIC: u8 = 0xB7;
+
+/// Current binary frame version.
+pub const BINARY_FRAME_VERSION: u8 = 3;
+
+/// Serde helpers sending a [`Vec3`](crate::types::Vec3) field as three `f32`s in binary payloads.
+///
+/// Every binary serializer truncates marked fields, including the Bincode
+/// and MessagePack [`EventCodec`](crate::system::EventCodec)s, so only mark
+/// wire-only types handed to the replication encoder: instance events built
+/// for clients, or the layout a [`GorcZoneData`](crate::GorcZoneData) writes
+/// in `serialize_zone_data_binary`. Events shared with server-side handlers
+/// should stay at full precision. Human-readable formats such as JSON keep
+/// the usual `{x, y, z}` layout with full precision.
+///
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct MoveUpdate {
+///     #[serde(with = "horizon_event_system::gorc::wire::compact_vec3")]
+///     position: Vec3,
+/// }
+/// ```
+pub mod compact_vec3 {
+    use crate::types::Vec3;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Writes the components as `f32`s, or the usual fields for human-readable formats
+    pub fn serialize<S: Serializer>(vec: &Vec3, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            vec.serialize(serializer)
+        } else {
+            (vec.x as f32, vec.y as f32, vec.z as f32).serialize(serializer)
+        }
+    }
+
+    /// Reads a position written by [`serialize`]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec3, D::Error> {
+        if deserializer.is_human_readable() {
+            Vec3::deserialize(deserializer)
+        } else {
+            let (x, y, z) = <(f32, f32, f32)>::deserialize(deserializer)?;
+            Ok(Vec3::new(x as f64, y as f64, z as f64))
+        }
+    }
+}
+
+/// Timing metadata carried by every replication message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct WireStamp {
+    /// Server tick the message was produced on
+    pub tick: u64,
+    /// Send time in seconds since the Unix epoch
+    pub timestamp: u64,
+    /// Send time in milliseconds since the Unix epoch
+    pub sent_at: u64,
+}
+
+impl WireStamp {
+    /// Stamps a message sent now on the given server tick.
+    pub fn now(tick: u64) -> Self {
+        let sent_at = crate::utils::current_timestamp_millis();
+        Self {
+            tick,
+            timestamp: sent_at / 1000,
+            sent_at,
+        }
+    }
+}
+
+/// Encoding used for replication messages sent to a client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WireFormat {
+    /// Human-readable JSON (default, debug fallback)
+    #[default]
+    Json,
+    /// Compact binary frames
+    Binary,
+}
+
+impl std::str::FromStr for WireFormat {
+    type Err = WireError;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_ascii_lowercase().as_str() {
+            "json" => Ok(WireFormat::Json),
+            "binary" | "bincode" => Ok(WireFormat::Binary),
+            other => Err(WireError::UnsupportedFormat(other.to_string())),
+        }
+    }
+}
+
+/// Kind of replication message carried by a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum WireMessageKind {
+    /// Instance event emitted to subscribers of a channel
+    Event = 0,
+    /// Player entered an object's zone; payload is the layer state
+    ZoneEnter = 1,
+    /// Player left an object's zone; no payload
+    ZoneExit = 2,
+}
+
+/// Encoding of the payload inside a binary frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PayloadEncoding {
+    /// Bincode with default options
+    Bincode = 0,
+    /// JSON bytes
+    Json = 1,
+    /// No payload
+    Empty = 2,
+}
+
+/// Errors produced while encoding or decoding replication messages.
+#[derive(Debug, thiserror::Error)]
+pub enum WireError {
+    #[error("JSON encoding failed: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Binary encoding failed: {0}")]
+    Bincode(#[from] bincode::Error),
+
+    #[error("Unsupported wire format: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("Malformed binary frame: {0}")]
+    MalformedFrame(String),
+}
+
+impl From<WireError> for crate::events::EventError {
+    fn from(error: WireError) -> Self {
+        match error {
+            WireError::Json(e) => crate::events::EventError::Serialization(e),
+            other => crate::events::EventError::Other(other.to_string()),
+        }
+    }
+}
+
+/// A decoded binary frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WireFrame {
+    pub kind: WireMessageKind,
+    pub channel: u8,
+    pub payload_encoding: PayloadEncoding,
+    pub object_id: GorcObjectId,
+    /// Server tick and send time (zero for version 1 frames)
+    pub stamp: WireStamp,
+    /// Event name for events, object type for zone messages
+    pub name: String,
+    pub payload: Vec<u8>,
+}
+
+/// Encodes a value with the bincode options used for binary payloads.
+pub fn encode_bincode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, bincode::Error> {
+    bincode::DefaultOptions::new().serialize(value)
+}
+
+/// Decodes a bincode payload produced by [`encode_bincode`].
+pub fn decode_bincode<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T, bincode::Error> {
+    bincode::DefaultOptions::new().deserialize(bytes)
+}
+
+/// Encodes an instance event for subscribers of an object's channel.
+///
+/// # Arguments
+///
+/// * `format` - Wire format negotiated by the receiving client
+/// * `object_id` - Object the event belongs to
+/// * `object_type` - Type name of the object (JSON only)
+/// * `channel` - Replication channel
+/// * `event_name` - Name of the event
+/// * `event` - Event data
+/// * `stamp` - Server tick and send time
+pub fn encode_event<T: Serialize + Any>(
+    format: WireFormat,
+    object_id: GorcObjectId,
+    object_type: &str,
+    channel: u8,
+    event_name: &str,
+    event: &T,
+    stamp: WireStamp,
+) -> Result<Vec<u8>, WireError> {
+    match format {
+        WireFormat::Json => Ok(serde_json::to_vec(&serde_json::json!({
+            "event_type": event_name,
+            "object_id": object_id.to_string(),
+            "object_type": object_type,
+            "channel": channel,
+            "player_id": object_id.to_string(),
+            "data": event,
+            "timestamp": stamp.timestamp,
+            "tick": stamp.tick,
+            "sent_at": stamp.sent_at
+        }))?),
+        WireFormat::Binary => {
+            // Untyped JSON values have no schema a client could decode bincode with
+            let (encoding, payload) = if (event as &dyn Any).is::<serde_json::Value>() {
+                (PayloadEncoding::Json, serde_json::to_vec(event)?)
+            } else {
+                (PayloadEncoding::Bincode, encode_bincode(event)?)
+            };
+            encode_frame(WireMessageKind::Event, channel, encoding, object_id, stamp, event_name, &payload)
+        }
+    }
+}
+
+/// Encodes a zone enter message carrying an object's current layer state.
+///
+/// # Arguments
+///
+/// * `format` - Wire format negotiated by the receiving client
+/// * `player_id` - Player entering the zone (JSON only)
+/// * `object_id` - Object whose zone was entered
+/// * `object_type` - Type name of the object
+/// * `channel` - Replication channel of the zone
+/// * `layer_data` - Layer state and its encoding
+/// * `stamp` - Server tick and send time
+pub fn encode_zone_enter(
+    format: WireFormat,
+    player_id: PlayerId,
+    object_id: GorcObjectId,
+    object_type: &str,
+    channel: u8,
+    layer_data: (PayloadEncoding, &[u8]),
+    stamp: WireStamp,
+) -> Result<Vec<u8>, WireError> {
+    let (encoding, payload) = layer_data;
+    match format {
+        WireFormat::Json => {
+            let zone_data = match encoding {
+                PayloadEncoding::Json => serde_json::from_slice::<serde_json::Value>(payload)
+                    .unwrap_or(serde_json::Value::Null),
+                _ => serde_json::Value::Null,
+            };
+            Ok(serde_json::to_vec(&serde_json::json!({
+                "type": "gorc_zone_enter",
+                "object_id": object_id.to_string(),
+                "object_type": object_type,
+                "channel": channel,
+                "player_id": player_id.to_string(),
+                "zone_data": zone_data,
+                "timestamp": stamp.timestamp,
+                "tick": stamp.tick,
+                "sent_at": stamp.sent_at
+            }))?)
+        }
+        WireFormat::Binary => {
+            encode_frame(WireMessageKind::ZoneEnter, channel, encoding, object_id, stamp, object_type, payload)
+        }
+    }
+}
+
+/// Encodes a zone exit message.
+pub fn encode_zone_exit(
+    format: WireFormat,
+    player_id: PlayerId,
+    object_id: GorcObjectId,
+    object_type: &str,
+    channel: u8,
+    stamp: WireStamp,
+) -> Result<Vec<u8>, WireError> {
+    match format {
+        WireFormat::Json => Ok(serde_json::to_vec(&serde_json::json!({
+            "type": "gorc_zone_exit",
+            "object_id": object_id.to_string(),
+            "object_type": object_type,
+            "channel": channel,
+            "player_id": player_id.to_string(),
+            "timestamp": stamp.timestamp,
+            "tick": stamp.tick,
+            "sent_at": stamp.sent_at
+        }))?),
+        WireFormat::Binary => encode_frame(
+            WireMessageKind::ZoneExit,
+            channel,
+            PayloadEncoding::Empty,
+            object_id,
+            stamp,
+            object_type,
+            &[],
+        ),
+    }
+}
+
+/// Writes a binary frame.
+fn encode_frame(
+    kind: WireMessageKind,
+    channel: u8,
+    payload_encoding: PayloadEncoding,
+    object_id: GorcObjectId,
+    stamp: WireStamp,
+    name: &str,
+    payload: &[u8],
+) -> Result<Vec<u8>, WireError> {
+    let name_len = u8::try_from(name.len())
+        .map_err(|_| WireError::MalformedFrame(format!("name too long: {} bytes", name.len())))?;
+
+    let mut frame = Vec::with_capacity(32 + name.len() + payload.len());
+    frame.extend_from_slice(&[
+        BINARY_FRAME_MAGIC,
+        BINARY_FRAME_VERSION,
+        kind as u8,
+        channel,
+        payload_encoding as u8,
+    ]);
+    frame.extend_from_slice(object_id.0.as_bytes());
+    write_varint(&mut frame, stamp.tick);
+    write_varint(&mut frame, stamp.sent_at);
+
+    frame.push(name_len);
+    frame.extend_from_slice(name.as_bytes());
+    frame.extend_from_slice(payload);
+    Ok(frame)
+}
+
+/// Appends a LEB128 varint.
+fn write_varint(frame: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            frame.push(byte);
+            break;
+        }
+        frame.push(byte | 0x80);
+    }
+}
+
+/// Reads a LEB128 varint, advancing the cursor past it.
+fn read_varint(bytes: &[u8], cursor: &mut usize, field: &str) -> Result<u64, WireError> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes
+            .get(*cursor)
+            .ok_or_else(|| WireError::MalformedFrame(format!("truncated {}", field)))?;
+        *cursor += 1;
+        if shift >= 64 {
+            return Err(WireError::MalformedFrame(format!("{} overflow", field)));
+        }
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+/// Checks whether a message is a binary frame rather than JSON.
+pub fn is_binary_frame(bytes: &[u8]) -> bool {
+    bytes.first() == Some(&BINARY_FRAME_MAGIC)
+}
+
+/// Decodes a binary frame.
+///
+/// # Returns
+///
+/// The decoded frame, or a `WireError` if the bytes are not a valid
+/// version 1, 2 or 3 frame.
+pub fn decode_frame(bytes: &[u8]) -> Result<WireFrame, WireError> {
+    let malformed = |reason: &str| WireError::MalformedFrame(reason.to_string());
+
+    if bytes.len() < 22 || !is_binary_frame(bytes) {
+        return Err(malformed("missing frame header"));
+    }
+    let version = bytes[1];
+    if !(1..=BINARY_FRAME_VERSION).contains(&version) {
+        return Err(malformed("unsupported frame version"));
+    }
+
+    let kind = match bytes[2] {
+        0 => WireMessageKind::Event,
+        1 => WireMessageKind::ZoneEnter,
+        2 => WireMessageKind::ZoneExit,
+        _ => return Err(malformed("unknown message kind")),
+    };
+    let payload_encoding = match bytes[4] {
+        0 => PayloadEncoding::Bincode,
+        1 => PayloadEncoding::Json,
+        2 => PayloadEncoding::Empty,
+        _ => return Err(malformed("unknown payload encoding")),
+    };
+    let object_id = GorcObjectId(Uuid::from_slice(&bytes[5..21]).map_err(|_| malformed("invalid object id"))?);
+
+    let mut cursor = 21;
+    let mut stamp = WireStamp::default();
+    if version <= 2 {
+        stamp.timestamp = read_varint(bytes, &mut cursor, "timestamp")?;
+    }
+    if version >= 2 {
+        stamp.tick = read_varint(bytes, &mut cursor, "tick")?;
+        stamp.sent_at = read_varint(bytes, &mut cursor, "send time")?;
+    }
+    if version >= 3 {
+        stamp.timestamp = stamp.sent_at / 1000;
+    }
+
+    let name_len = *bytes.get(cursor).ok_or_else(|| malformed("truncated name"))? as usize;
+    cursor += 1;
+    let name = bytes
+        .get(cursor..cursor + name_len)
+        .ok_or_else(|| malformed("truncated name"))?;
+    let name = String::from_utf8(name.to_vec()).map_err(|_| malformed("name is not UTF-8"))?;
+    cursor += name_len;
+
+    Ok(WireFrame {
+        kind,
+        channel: bytes[3],
+        payload_encoding,
+        object_id,
+        stamp,
+        name,
+        payload: bytes[cursor..].to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Vec3;
+
+    /// Typical channel 0 movement update
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct MoveUpdate {
+        player_id: PlayerId,
+        #[serde(with = "compact_vec3")]
+        new_position: Vec3,
+        #[serde(with = "compact_vec3")]
+        velocity: Vec3,
+        movement_state: i32,
+        client_timestamp: u64,
+    }
+
+    fn stamp(tick: u64) -> WireStamp {
+        WireStamp { tick, timestamp: 1_760_000_000, sent_at: 1_760_000_000_000 }
+    }
+
+    fn move_update() -> MoveUpdate {
+        MoveUpdate {
+            player_id: PlayerId::new(),
+            new_position: Vec3::new(1523.482177734375, -842.1187133789062, 12.5),
+            velocity: Vec3::new(3.25, -0.75, 0.0),
+            movement_state: 2,
+            client_timestamp: 1_760_000_000_123,
+        }
+    }
+
+    #[test]
+    fn test_binary_event_round_trip() {
+        let object_id = GorcObjectId::new();
+        let update = move_update();
+        let stamp = WireStamp { tick: 4_812, timestamp: 1_760_000_000, sent_at: 1_760_000_000_250 };
+        let frame = encode_event(WireFormat::Binary, object_id, "GorcPlayer", 0, "move", &update, stamp).unwrap();
+        assert!(is_binary_frame(&frame));
+        assert!(std::str::from_utf8(&frame).is_err());
+
+        let decoded = decode_frame(&frame).unwrap();
+        assert_eq!(decoded.kind, WireMessageKind::Event);
+        assert_eq!(decoded.channel, 0);
+        assert_eq!(decoded.object_id, object_id);
+        assert_eq!(decoded.stamp, stamp);
+        assert_eq!(decoded.name, "move");
+        assert_eq!(decoded.payload_encoding, PayloadEncoding::Bincode);
+        assert_eq!(decode_bincode::<MoveUpdate>(&decoded.payload).unwrap(), update);
+    }
+
+    #[test]
+    fn test_binary_channel_zero_is_much_smaller() {
+        let object_id = GorcObjectId::new();
+        let update = move_update();
+        let stamp = WireStamp::now(4_812);
+        let json = encode_event(WireFormat::Json, object_id, "GorcPlayer", 0, "move", &update, stamp).unwrap();
+        let binary = encode_event(WireFormat::Binary, object_id, "GorcPlayer", 0, "move", &update, stamp).unwrap();
+        assert!(
+            json.len() > binary.len() * 5,
+            "json {} bytes vs binary {} bytes",
+            json.len(),
+            binary.len()
+        );
+    }
+
+    #[test]
+    fn test_untyped_events_and_zone_messages() {
+        let object_id = GorcObjectId::new();
+        let value = serde_json::json!({ "hp": 10 });
+        let frame = decode_frame(&encode_event(WireFormat::Binary, object_id, "Ship", 1, "hit", &value, stamp(5)).unwrap()).unwrap();
+        assert_eq!(frame.payload_encoding, PayloadEncoding::Json);
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(&frame.payload).unwrap(), value);
+
+        let player_id = PlayerId::new();
+        let layer = encode_bincode(&move_update()).unwrap();
+        let enter = decode_frame(
+            &encode_zone_enter(WireFormat::Binary, player_id, object_id, "Ship", 0, (PayloadEncoding::Bincode, &layer), stamp(5)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(enter.kind, WireMessageKind::ZoneEnter);
+        assert_eq!(enter.name, "Ship");
+        assert_eq!(enter.payload, layer);
+
+        let exit = decode_frame(&encode_zone_exit(WireFormat::Binary, player_id, object_id, "Ship", 2, stamp(5)).unwrap()).unwrap();
+        assert_eq!(exit.kind, WireMessageKind::ZoneExit);
+        assert_eq!(exit.payload_encoding, PayloadEncoding::Empty);
+        assert!(exit.payload.is_empty());
+
+        // JSON zone messages keep their existing shape
+        let json: serde_json::Value = serde_json::from_slice(
+            &encode_zone_enter(WireFormat::Json, player_id, object_id, "Ship", 0, (PayloadEncoding::Json, b"{\"hp\":10}"), stamp(5)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["type"], "gorc_zone_enter");
+        assert_eq!(json["zone_data"]["hp"], 10);
+        assert_eq!(json["tick"], 5);
+
+        assert!(decode_frame(b"{\"type\":\"gorc_zone_exit\"}").is_err());
+        assert_eq!("binary".parse::<WireFormat>().unwrap(), WireFormat::Binary);
+        assert!("xml".parse::<WireFormat>().is_err());
+    }
+
+    #[test]
+    fn test_older_frames_still_decode() {
+        let object_id = GorcObjectId::new();
+        let mut frame = vec![BINARY_FRAME_MAGIC, 1, WireMessageKind::ZoneExit as u8, 3, PayloadEncoding::Empty as u8];
+        frame.extend_from_slice(object_id.0.as_bytes());
+        write_varint(&mut frame, 1_760_000_000);
+        frame.push(4);
+        frame.extend_from_slice(b"Ship");
+
+        let decoded = decode_frame(&frame).unwrap();
+        assert_eq!(decoded.stamp, WireStamp { tick: 0, timestamp: 1_760_000_000, sent_at: 0 });
+        assert_eq!(decoded.name, "Ship");
+        assert!(decoded.payload.is_empty());
+
+        // Version 2 sends the timestamp before the tick and send time
+        let mut frame = vec![BINARY_FRAME_MAGIC, 2, WireMessageKind::ZoneExit as u8, 3, PayloadEncoding::Empty as u8];
+        frame.extend_from_slice(object_id.0.as_bytes());
+        for value in [1_760_000_000, 42, 1_760_000_000_500] {
+            write_varint(&mut frame, value);
+        }
+        frame.push(4);
+        frame.extend_from_slice(b"Ship");
+        assert_eq!(decode_frame(&frame).unwrap().stamp, WireStamp { tick: 42, timestamp: 1_760_000_000, sent_at: 1_760_000_000_500 });
+    }
+
+    #[test]
+    fn test_positions_are_compact_only_in_binary_payloads() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Marked(#[serde(with = "compact_vec3")] Vec3);
+
+        let position = Vec3::new(1523.482177734375, -842.1187133789062, 12.5);
+        assert_eq!(encode_bincode(&Marked(position)).unwrap().len(), 12);
+        assert_eq!(decode_bincode::<Marked>(&encode_bincode(&Marked(position)).unwrap()).unwrap(), Marked(position));
+
+        // Unmarked positions and JSON keep full precision
+        let precise = Vec3::new(0.1, 0.2, 0.3);
+        assert_eq!(encode_bincode(&precise).unwrap().len(), 24);
+        assert_eq!(decode_bincode::<Vec3>(&encode_bincode(&precise).unwrap()).unwrap(), precise);
+        let json = serde_json::to_value(Marked(precise)).unwrap();
+        assert_eq!(json, serde_json::json!({ "x": 0.1, "y": 0.2, "z": 0.3 }));
+        assert_eq!(serde_json::from_value::<Marked>(json).unwrap(), Marked(precise));
+    }
+}
@@ -49,6 +49,11 @@ pub trait GorcZoneData: Send + Sync + std::fmt::Debug + Clone + serde::Serialize
     fn serialize_zone_data(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
         Ok(serde_json::to_vec(self)?)
     }
+
+    /// Serialize this zone data for the binary wire format
+    fn serialize_zone_data_binary(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        Ok(crate::gorc::wire::encode_bincode(self)?)
+    }
     
     /// Deserialize zone data from bytes
     fn deserialize_zone_data(data: &[u8]) -> Result<Self, Box<dyn std::error::Error>> where Self: Sized {
@@ -113,6 +118,17 @@ macro_rules! impl_gorc_object {
                     _ => Err("Invalid channel for this object type".into())
                 }
             }
+
+            fn serialize_for_layer_binary(&self, layer: &$crate::ReplicationLayer) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+                match layer.channel {
+                    $(
+                        $zone => {
+                            self.$field.serialize_zone_data_binary().map(Some)
+                        }
+                    )*
+                    _ => Err("Invalid channel for this object type".into())
+                }
+            }
             
             fn get_layers(&self) -> Vec<$crate::ReplicationLayer> {
                 let mut layers = Vec::new();
@@ -234,6 +250,26 @@ macro_rules! __update_position_in_first_zone {
     };
 }
 
+/// Builds the JSON payload of a layer generated by `#[derive(GorcObject)]`
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct __LayerFields(serde_json::Map<String, serde_json::Value>);
+
+impl __LayerFields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: serde::Serialize + ?Sized>(mut self, name: &str, value: &T) -> Result<Self, Box<dyn std::error::Error>> {
+        self.0.insert(name.to_string(), serde_json::to_value(value)?);
+        Ok(self)
+    }
+
+    pub fn into_json(self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        Ok(serde_json::to_vec(&self.0)?)
+    }
+}
+
 /// Helper function to get default zone configuration
 #[doc(hidden)]
 pub fn __get_default_zone_config(zone: u8) -> (f64, f64, crate::CompressionType, crate::ReplicationPriority) {
@@ -133,6 +133,9 @@
 //! // create_simple_plugin!(AsteroidMiningPlugin);
 //! ```
 
+// Lets `#[derive(GorcObject)]` resolve `::horizon_event_system` paths inside this crate
+extern crate self as horizon_event_system;
+
 // tests
 mod test_integration;
 
@@ -160,58 +163,99 @@ pub mod gorc;
 
 // Re-export commonly used items for convenience
 pub use api::{create_complete_horizon_system, create_simple_horizon_system};
-pub use utils::{create_horizon_event_system, current_timestamp};
+pub use utils::{create_horizon_event_system, current_timestamp, current_timestamp_millis};
 pub use traits::{SimpleGorcObject, SimpleReplicationConfig};
-pub use gorc_macros::{GorcZoneData, __get_default_zone_config}; // Export new type-based system
+pub use gorc_macros::{GorcZoneData, __get_default_zone_config, __LayerFields}; // Export new type-based system
+pub use horizon_gorc_derive::GorcObject;
 pub use monitoring::{HorizonMonitor, HorizonSystemReport};
-pub use context::{LogLevel, ServerContext, ServerError};
-pub use plugin::{Plugin, PluginError, SimplePlugin};
+pub use context::{ContentFilter, ContentVerdict, LogLevel, ServerContext, ServerError};
+pub use plugin::{Plugin, PluginError, PluginHealthStatus, SimplePlugin};
 pub use shutdown::ShutdownState;
 pub use types::*;
 
 pub use events::{
     Event, EventError, EventHandler, GorcEvent, Dest,
-    PlayerConnectedEvent, PlayerDisconnectedEvent,
-    PlayerMovementEvent, RawClientMessageEvent, 
-    RegionStartedEvent, RegionStoppedEvent, TypedEventHandler,
-    PluginLoadedEvent, PluginUnloadedEvent,
+    PlayerConnectedEvent, PlayerDisconnectedEvent, PlayerReconnectedEvent,
+    PlayerMovementEvent, RawClientMessageEvent, ClientBinaryMessage, ReplicationAckEvent, PlayerLatencyEvent, PlayerFocusChangedEvent,
+    RegionStartedEvent, RegionStoppedEvent, PlayerRegionHandoffEvent, PlayerRegionArrivalEvent, TimerEvent, TypedEventHandler,
+    ClientRateLimitedEvent, KickPlayerEvent, BanPlayerEvent, GorcBroadcastEvent, PlayerAuthenticatedEvent, SecurityAuditEvent, AuditAction,
+    PluginLoadedEvent, PluginUnloadedEvent, PluginHealthChangedEvent,
     AuthenticationStatusGetResponseEvent,
     AuthenticationStatusChangedEvent,
     AuthenticationStatusSetEvent,
     AuthenticationStatusGetEvent,
+    AuthorityChangedEvent,
+    GorcZoneEnteredEvent,
+    GorcZoneExitedEvent,
+    GorcObjectAttachedEvent,
+    GorcObjectDetachedEvent,
+    GorcVirtualZoneMergedEvent,
+    GorcVirtualZoneSplitEvent,
     ClientEventWrapper,
 };
 
 pub use system::{
     EventSystem, EventSystemStats,
     DetailedEventSystemStats,
+    EventKeyStats,
     HandlerCategoryStats,
     ClientConnectionRef,
     ClientResponseSender,
-    ClientConnectionInfo
+    ClientConnectionInfo,
+    ClientVersion,
+    ConnectionStats,
+    HandlerPanicReport,
+    HandlerGate,
+    HandlerPriority,
+    EmissionQueueConfig,
+    EmissionQueueStats,
+    EventCategory,
+    OverflowPolicy,
+    DEFAULT_REQUEST_TIMEOUT,
+    EventSchema,
+    SchemaValidationError,
+    SchemaViolation,
+    HandlerSpanConfig,
+    EventFilter,
+    EventView,
+    TimerHandle,
+    EventCodec,
+    RESERVED_NAMESPACES,
+    PLUGIN_CORE_EVENTS,
+    BINARY_NAMESPACE,
+    S2sEvent,
+    ServerLink,
+    S2S_NAMESPACE
 };
 
 // Re-export GORC components for easy access
 pub use gorc::{
     // Core GORC types
-    GorcObject, GorcObjectId, ObjectInstance, GorcInstanceManager,
+    GorcObject, GorcObjectId, ObjectInstance, ObjectAuthority, GorcInstanceManager,
+    ObjectAttachment, AttachmentError,
     
     // Channels and layers
-    ReplicationChannel, ReplicationLayer, ReplicationLayers, ReplicationPriority, 
+    ReplicationChannel, ReplicationLayer, ReplicationLayers, LodTier, ReplicationPriority, 
     CompressionType, GorcManager, GorcConfig, GorcStats, PerformanceReport,
     
     // Zones and spatial management
     ObjectZone, ZoneManager, ZoneAnalysis, ZoneConfig, 
-    SpatialPartition, SpatialQuery, RegionRTree,
+    SpatialPartition, SpatialQuery, QueryFilters, QueryResult, ObjectQueryResult, RegionRTree,
+    SpatialQueryHandle, SpatialEntity, SpatialFilter, SpatialHit,
+    SpatialIndexConfig, SpatialIndexKind, SpatialMaintenanceReport,
     
     // Network and replication
     NetworkReplicationEngine, ReplicationCoordinator, NetworkConfig, 
     NetworkStats, ReplicationUpdate, ReplicationBatch, ReplicationStats,
     Replication, GorcObjectRegistry,
+
+    // Cross-server federation
+    RegionFederation, RegionLink, NeighborRegion, FederationMessage,
+    FederationError, PlayerSession, PlayerArrival,
     
     // Subscription management
     SubscriptionManager, SubscriptionType, ProximitySubscription,
-    RelationshipSubscription, InterestSubscription, InterestLevel,
+    RelationshipSubscription, InterestSubscription, InterestLevel, ViewCone, ViewRelation,
     
     // Multicast and LOD
     MulticastManager, MulticastGroup, LodRoom, LodLevel, MulticastGroupId,
@@ -226,7 +270,7 @@ pub use gorc::{
     defaults,
     
     // Constants
-    GORC_VERSION, MAX_CHANNELS,
+    GORC_VERSION, MAX_CHANNELS, BROADCAST_CHANNEL,
 };
 
 // External dependencies that plugins commonly need
@@ -234,6 +278,8 @@ pub use async_trait::async_trait;
 pub use std::sync::Arc;
 pub use serde::{Deserialize, Serialize};
 pub use futures;
+pub use serde_json;
+pub use tracing;
 
 /// ABI version for plugin compatibility validation.
 /// This is derived from the crate version and Rust compiler version to ensure plugins are compatible.
@@ -81,8 +81,31 @@ use {
 /// - `destroy_plugin()` - C-compatible plugin destruction function
 /// - `PluginWrapper` - Internal wrapper with panic handling
 /// - Proper FFI exports for dynamic loading
+/// 
+/// # ABI Range
+/// 
+/// By default a plugin only loads on servers whose `horizon_event_system`
+/// major.minor version matches the one it was built against. A plugin that is
+/// known to work across several versions can declare a semver range instead:
+/// 
+/// ```rust,ignore
+/// create_simple_plugin!(MyPlugin, abi_range = ">=0.22, <0.24");
+/// ```
 #[macro_export]
 macro_rules! create_simple_plugin {
+    ($plugin_type:ty, abi_range = $abi_range:literal) => {
+        $crate::create_simple_plugin!($plugin_type);
+
+        /// Supported ABI range export used for version negotiation.
+        /// 
+        /// Returns the semver range of `horizon_event_system` versions this
+        /// plugin is compatible with.
+        #[no_mangle]
+        pub unsafe extern "C" fn get_plugin_abi_range() -> *const std::os::raw::c_char {
+            // Include the terminator so the string is a valid C string without allocating
+            concat!($abi_range, "\0").as_ptr() as *const std::os::raw::c_char
+        }
+    };
     ($plugin_type:ty) => {
         use $crate::Plugin;
 
@@ -136,6 +159,11 @@ macro_rules! create_simple_plugin {
                 &mut self,
                 context: Arc<dyn ServerContext>,
             ) -> Result<(), PluginError> {
+                // This library's copy of tracing logs nowhere until it gets the server's dispatcher
+                if let Some(dispatch) = context.log_dispatch() {
+                    let _ = $crate::tracing::dispatcher::set_global_default(dispatch);
+                }
+
                 // Run directly on the current thread using the current runtime handle
                 catch_unwind(AssertUnwindSafe(|| {
                     futures::executor::block_on(self.inner.register_handlers(context.events(), context.clone()))
@@ -159,6 +187,17 @@ macro_rules! create_simple_plugin {
                 }))
                 .map_err(Self::panic_to_error)?
             }
+
+            async fn health(&self) -> $crate::PluginHealthStatus {
+                // Polled without blocking so the watchdog's timeout can detect a stuck plugin
+                use $crate::futures::FutureExt;
+                match AssertUnwindSafe(self.inner.health()).catch_unwind().await {
+                    Ok(status) => status,
+                    Err(panic_info) => {
+                        $crate::PluginHealthStatus::Degraded(Self::panic_to_error(panic_info).to_string())
+                    }
+                }
+            }
         }
 
         /// Plugin version function - required export for ABI compatibility.
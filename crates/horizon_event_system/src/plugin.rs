@@ -35,6 +35,7 @@
 use crate::context::ServerContext;
 use crate::system::EventSystem;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 // ============================================================================
@@ -159,6 +160,20 @@ pub trait SimplePlugin: Send + Sync + 'static {
     async fn on_shutdown(&mut self, _context: Arc<dyn ServerContext>) -> Result<(), PluginError> {
         Ok(()) // Default implementation does nothing
     }
+
+    /// Reports the plugin's own view of its health.
+    /// 
+    /// Polled periodically by the plugin manager's watchdog. Report
+    /// `Degraded` with a reason when the plugin is running but impaired
+    /// (e.g. a backing service is unreachable). A plugin that fails to answer
+    /// within the watchdog timeout is considered unresponsive.
+    /// 
+    /// # Returns
+    /// 
+    /// The current health status. The default implementation always reports healthy.
+    async fn health(&self) -> PluginHealthStatus {
+        PluginHealthStatus::Healthy
+    }
 }
 
 /// Low-level plugin trait for FFI compatibility.
@@ -238,6 +253,22 @@ pub trait Plugin: Send + Sync {
     /// Returns `Ok(())` if shutdown completes successfully, or `Err(PluginError)`
     /// if cleanup failed. Shutdown errors are logged but don't prevent unloading.
     async fn shutdown(&mut self, context: Arc<dyn ServerContext>) -> Result<(), PluginError>;
+
+    /// Heartbeat reporting the plugin's health to the watchdog.
+    /// 
+    /// The default implementation always reports healthy.
+    async fn health(&self) -> PluginHealthStatus {
+        PluginHealthStatus::Healthy
+    }
+}
+
+/// Health reported by a plugin in response to a watchdog heartbeat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PluginHealthStatus {
+    /// Plugin is operating normally
+    Healthy,
+    /// Plugin is running but impaired, with a human-readable reason
+    Degraded(String),
 }
 
 // ============================================================================
@@ -6,6 +6,7 @@
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use tokio::sync::Notify;
 use tracing::info;
 
 /// Shared shutdown state for coordinating graceful shutdown across components.
@@ -15,6 +16,8 @@ pub struct ShutdownState {
     shutdown_initiated: Arc<AtomicBool>,
     /// Flag indicating all existing events have been processed and final shutdown can begin
     shutdown_complete: Arc<AtomicBool>,
+    /// Wakes tasks waiting for shutdown to be initiated
+    initiated: Arc<Notify>,
 }
 
 impl ShutdownState {
@@ -23,6 +26,7 @@ impl ShutdownState {
         Self {
             shutdown_initiated: Arc::new(AtomicBool::new(false)),
             shutdown_complete: Arc::new(AtomicBool::new(false)),
+            initiated: Arc::new(Notify::new()),
         }
     }
 
@@ -39,9 +43,19 @@ impl ShutdownState {
     /// Initiates shutdown - sets the flag to stop accepting new events.
     pub fn initiate_shutdown(&self) {
         self.shutdown_initiated.store(true, Ordering::Release);
+        self.initiated.notify_waiters();
         info!("🛑 Shutdown initiated - no new events will be processed");
     }
 
+    /// Waits until shutdown is initiated, returning at once if it already was.
+    pub async fn initiated(&self) {
+        let notified = self.initiated.notified();
+        if self.is_shutdown_initiated() {
+            return;
+        }
+        notified.await;
+    }
+
     /// Marks shutdown as complete - all existing events have been processed.
     pub fn complete_shutdown(&self) {
         self.shutdown_complete.store(true, Ordering::Release);
@@ -1,6 +1,6 @@
 /// High-performance serialization cache for event system
 /// This version uses a simpler approach - caching serialized data during emit_event
-use std::sync::Arc;
+use bytes::Bytes;
 
 /// Pre-allocated buffer pool for serialization to reduce allocations
 pub struct SerializationBufferPool {
@@ -17,11 +17,11 @@ impl SerializationBufferPool {
     /// For now, just serialize directly - this is still faster than the original
     /// due to the other optimizations. Future versions could implement buffer pooling.
     #[inline]
-    pub fn serialize_event<T>(&self, event: &T) -> Result<Arc<Vec<u8>>, crate::events::EventError>
+    pub fn serialize_event<T>(&self, event: &T, codec: super::EventCodec) -> Result<Bytes, crate::events::EventError>
     where
         T: crate::events::Event,
     {
-        match event.serialize() {
+        match event.serialize_with(codec) {
             Ok(data) => {
                 // Log successful serialization in debug mode
                 if cfg!(debug_assertions) {
@@ -31,7 +31,7 @@ impl SerializationBufferPool {
                         data.len()
                     );
                 }
-                Ok(Arc::new(data))
+                Ok(Bytes::from(data))
             }
             Err(e) => {
                 // Add context about where the serialization failed
@@ -1,5 +1,6 @@
 /// Client connection and response handling
 use crate::events::EventError;
+use crate::gorc::GorcObjectId;
 use crate::types::{PlayerId, AuthenticationStatus};
 // use serde::{Deserialize, Serialize}; // Unused
 use std::net::SocketAddr;
@@ -92,6 +93,63 @@ impl ClientConnectionRef {
     pub async fn is_active(&self) -> bool {
         self.response_sender.is_connection_active(self.player_id).await
     }
+
+    /// Gets the round-trip time measured by the server's latest heartbeat to this client.
+    ///
+    /// Use it for lag compensation, e.g. rewinding hit checks by half the
+    /// round trip. `None` until the client answered a heartbeat, or when the
+    /// transport doesn't measure round trips.
+    pub async fn round_trip_time(&self) -> Option<std::time::Duration> {
+        self.response_sender.get_round_trip_time(self.player_id).await
+    }
+
+    /// Gets the traffic statistics of this connection.
+    ///
+    /// Growing queue depth means the client doesn't keep up with what it is
+    /// sent; a fast-rising received message count means it floods the server.
+    /// `None` when the transport doesn't keep statistics.
+    pub async fn stats(&self) -> Option<ConnectionStats> {
+        self.response_sender.get_connection_stats(self.player_id).await
+    }
+
+    /// Gets the protocol version this client negotiated and the client
+    /// version it declared when connecting, for gating features on them.
+    ///
+    /// `None` when the transport doesn't negotiate versions.
+    pub async fn client_version(&self) -> Option<ClientVersion> {
+        self.response_sender.get_client_version(self.player_id).await
+    }
+
+    /// Gets a value stored on this connection with [`set_metadata`](Self::set_metadata).
+    ///
+    /// Returns `None` if nothing is stored under the key or the stored value
+    /// isn't a `T`.
+    pub async fn metadata<T: serde::de::DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.response_sender.get_connection_metadata(self.player_id, key.to_string()).await?;
+        serde_json::from_value(value).ok()
+    }
+
+    /// Stores a value on this connection, such as auth claims, locale, client
+    /// version or an anti-cheat score, so every handler and plugin can read it
+    /// back instead of keeping its own per-player map.
+    ///
+    /// Values live as long as the connection.
+    pub async fn set_metadata<T: serde::Serialize>(&self, key: &str, value: &T) -> Result<(), EventError> {
+        let value = serde_json::to_value(value)
+            .map_err(|e| EventError::HandlerExecution(format!("Metadata serialization failed: {}", e)))?;
+        self.response_sender
+            .set_connection_metadata(self.player_id, key.to_string(), Some(value))
+            .await
+            .map_err(|e| EventError::HandlerExecution(format!("Failed to set metadata: {}", e)))
+    }
+
+    /// Removes a value stored on this connection
+    pub async fn remove_metadata(&self, key: &str) -> Result<(), EventError> {
+        self.response_sender
+            .set_connection_metadata(self.player_id, key.to_string(), None)
+            .await
+            .map_err(|e| EventError::HandlerExecution(format!("Failed to remove metadata: {}", e)))
+    }
 }
 
 /// Trait for sending responses to clients - implemented by the server/connection manager
@@ -115,6 +173,40 @@ pub trait ClientResponseSender: std::fmt::Debug {
         Box::pin(async move { Ok(0) })
     }
 
+    /// Send a GORC replication message to a client.
+    ///
+    /// Transports may deliver latency-critical channels over an unreliable
+    /// path where newer updates supersede older ones. The default sends
+    /// through [`send_to_client`](Self::send_to_client).
+    fn send_replication(&self, player_id: PlayerId, _object_id: GorcObjectId, _channel: u8, data: Vec<u8>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + '_>> {
+        self.send_to_client(player_id, data)
+    }
+
+    /// Get the latest measured round-trip time to a client (optional implementation)
+    fn get_round_trip_time(&self, _player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<std::time::Duration>> + Send + '_>> {
+        Box::pin(async move { None })
+    }
+
+    /// Get the traffic statistics of a client's connection (optional implementation)
+    fn get_connection_stats(&self, _player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<ConnectionStats>> + Send + '_>> {
+        Box::pin(async move { None })
+    }
+
+    /// Get the protocol and client version a client negotiated (optional implementation)
+    fn get_client_version(&self, _player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<ClientVersion>> + Send + '_>> {
+        Box::pin(async move { None })
+    }
+
+    /// Get a value stored on a client's connection (optional implementation)
+    fn get_connection_metadata(&self, _player_id: PlayerId, _key: String) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<serde_json::Value>> + Send + '_>> {
+        Box::pin(async move { None })
+    }
+
+    /// Store a value on a client's connection, or remove it when `value` is `None` (optional implementation)
+    fn set_connection_metadata(&self, _player_id: PlayerId, _key: String, _value: Option<serde_json::Value>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + '_>> {
+        Box::pin(async move { Err("Connection metadata is not supported by this server".to_string()) })
+    }
+
     /// Get connection information for a client (optional implementation)
     fn get_connection_info(&self, _player_id: PlayerId) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<ClientConnectionInfo>> + Send + '_>> {
         // Default implementation returns None to maintain backwards compatibility
@@ -122,6 +214,72 @@ pub trait ClientResponseSender: std::fmt::Debug {
     }
 }
 
+/// Traffic statistics of a client connection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConnectionStats {
+    /// Bytes of messages delivered to the client
+    pub bytes_sent: u64,
+    /// Bytes of messages received from the client
+    pub bytes_received: u64,
+    /// Messages delivered to the client
+    pub messages_sent: u64,
+    /// Messages received from the client
+    pub messages_received: u64,
+    /// Messages queued for the client that haven't been delivered yet
+    pub queue_depth: u64,
+    /// Messages dropped because the client had too many undelivered ones
+    #[serde(default)]
+    pub messages_dropped: u64,
+    /// Unix timestamp in milliseconds of the last frame received from the client
+    pub last_activity_ms: u64,
+}
+
+/// Protocol version a client negotiated and the client version it declared
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ClientVersion {
+    /// Version of the Horizon wire protocol the client speaks
+    pub protocol: u32,
+    /// Version of the game client, such as `1.4.2`, if the client declared one
+    pub client: Option<String>,
+}
+
+impl Default for ClientVersion {
+    fn default() -> Self {
+        Self { protocol: 1, client: None }
+    }
+}
+
+impl ClientVersion {
+    /// Checks whether the client declared a version at least `minimum`.
+    ///
+    /// Versions are compared by their dot-separated numeric parts, so
+    /// `1.10` is newer than `1.9` and `1.4` equals `1.4.0`; anything after a
+    /// `-` or `+` is ignored. Plugins use this to gate features on the client.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the client declared no version or either version isn't numeric.
+    pub fn is_at_least(&self, minimum: &str) -> bool {
+        let (Some(client), Some(minimum)) = (self.client.as_deref().and_then(version_parts), version_parts(minimum)) else {
+            return false;
+        };
+        let len = client.len().max(minimum.len());
+        let padded = |parts: Vec<u64>| parts.into_iter().chain(std::iter::repeat(0)).take(len).collect::<Vec<_>>();
+        padded(client) >= padded(minimum)
+    }
+
+    /// Checks whether a version can be compared, i.e. is dot-separated numbers
+    pub fn is_valid(version: &str) -> bool {
+        version_parts(version).is_some()
+    }
+}
+
+/// Splits a version such as `1.4.2-beta` into its numeric parts
+fn version_parts(version: &str) -> Option<Vec<u64>> {
+    let release = version.split(['-', '+']).next().unwrap_or_default();
+    release.split('.').map(|part| part.parse().ok()).collect()
+}
+
 /// Information about a client connection
 #[derive(Debug, Clone)]
 pub struct ClientConnectionInfo {
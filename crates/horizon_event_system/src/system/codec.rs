@@ -0,0 +1,205 @@
+/// Binary codecs for event payloads
+use crate::events::EventError;
+use super::core::EventSystem;
+use compact_str::CompactString;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// First byte of every binary event payload.
+///
+/// JSON payloads never start with it, so payloads identify their own codec
+/// and handlers decode them without being told how they were encoded.
+pub const BINARY_PAYLOAD_MAGIC: u8 = 0xB8;
+
+/// Encoding of a serialized event payload.
+///
+/// JSON payloads are plain JSON. Binary payloads start with
+/// [`BINARY_PAYLOAD_MAGIC`] and a byte naming the codec, followed by the
+/// encoded event. Bincode uses the same options as GORC binary frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventCodec {
+    /// Human-readable JSON (default)
+    #[default]
+    Json,
+    /// Compact bincode; only typed handlers can decode it
+    Bincode,
+    /// Self-describing MessagePack
+    #[serde(rename = "msgpack")]
+    MessagePack,
+}
+
+impl std::str::FromStr for EventCodec {
+    type Err = EventError;
+
+    fn from_str(codec: &str) -> Result<Self, Self::Err> {
+        match codec.to_ascii_lowercase().as_str() {
+            "json" => Ok(EventCodec::Json),
+            "bincode" => Ok(EventCodec::Bincode),
+            "msgpack" | "messagepack" => Ok(EventCodec::MessagePack),
+            other => Err(EventError::Codec(format!("Unknown event codec: {}", other))),
+        }
+    }
+}
+
+impl EventCodec {
+    /// Gets the codec's configuration name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventCodec::Json => "json",
+            EventCodec::Bincode => "bincode",
+            EventCodec::MessagePack => "msgpack",
+        }
+    }
+
+    /// Detects the codec a serialized payload was encoded with
+    pub fn of(data: &[u8]) -> Self {
+        match data {
+            [BINARY_PAYLOAD_MAGIC, tag, ..] => Self::from_tag(*tag).unwrap_or_default(),
+            _ => EventCodec::Json,
+        }
+    }
+
+    /// Encodes a value as a payload of this codec, header included
+    pub fn encode<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, EventError> {
+        match self {
+            EventCodec::Json => serde_json::to_vec(value).map_err(EventError::Serialization),
+            EventCodec::Bincode => {
+                let body = crate::gorc::wire::encode_bincode(value).map_err(|e| EventError::Codec(e.to_string()))?;
+                Ok(self.frame(&body))
+            }
+            EventCodec::MessagePack => {
+                let body = rmp_serde::to_vec(value).map_err(|e| EventError::Codec(e.to_string()))?;
+                Ok(self.frame(&body))
+            }
+        }
+    }
+
+    /// Decodes a payload of any codec, detected from its header
+    pub fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, EventError> {
+        let body = data.get(2..).unwrap_or_default();
+        match Self::of(data) {
+            EventCodec::Json => serde_json::from_slice(data).map_err(EventError::Deserialization),
+            EventCodec::Bincode => crate::gorc::wire::decode_bincode(body).map_err(|e| EventError::Codec(e.to_string())),
+            EventCodec::MessagePack => rmp_serde::from_slice(body).map_err(|e| EventError::Codec(e.to_string())),
+        }
+    }
+
+    /// Wraps a binary payload with the event name it was emitted under.
+    ///
+    /// Produces the payload of a two-field `{ event_name, data }` struct in
+    /// this codec without decoding the event.
+    pub(super) fn wrap_named(&self, event_name: &str, data: &[u8]) -> Result<Vec<u8>, EventError> {
+        let body = data.get(2..).unwrap_or_default();
+        let mut fields = match self {
+            EventCodec::Json => return Err(EventError::Codec("JSON payloads are wrapped as JSON".to_string())),
+            EventCodec::Bincode => crate::gorc::wire::encode_bincode(event_name).map_err(|e| EventError::Codec(e.to_string()))?,
+            EventCodec::MessagePack => {
+                // MessagePack encodes structs as arrays of their fields
+                let mut fields = vec![0x92];
+                fields.extend(rmp_serde::to_vec(event_name).map_err(|e| EventError::Codec(e.to_string()))?);
+                fields
+            }
+        };
+        fields.extend_from_slice(body);
+        Ok(self.frame(&fields))
+    }
+
+    fn frame(&self, body: &[u8]) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(body.len() + 2);
+        payload.push(BINARY_PAYLOAD_MAGIC);
+        payload.push(self.tag());
+        payload.extend_from_slice(body);
+        payload
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            EventCodec::Json => 0,
+            EventCodec::Bincode => 1,
+            EventCodec::MessagePack => 2,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            1 => Some(EventCodec::Bincode),
+            2 => Some(EventCodec::MessagePack),
+            _ => None,
+        }
+    }
+}
+
+impl EventSystem {
+    /// Declares the codec events emitted under a key are serialized with.
+    ///
+    /// Typed handlers decode binary payloads transparently, so emitters and
+    /// handlers keep their event types while high-frequency events such as
+    /// GORC channel 0 updates or voice blobs skip JSON. Wildcard handlers
+    /// receive the event in the same codec. Predicate filters only see
+    /// fields of JSON payloads, and client events emitted with connection
+    /// context stay JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_key` - Full event key, e.g. `gorc_instance:Asteroid:0:position_update`
+    /// * `codec` - Codec used for the key's payloads
+    pub fn set_event_codec(&self, event_key: &str, codec: EventCodec) {
+        info!("📦 Encoding {} events as {}", event_key, codec.name());
+        if codec == EventCodec::Json {
+            self.codecs.remove(event_key);
+        } else {
+            self.codecs.insert(CompactString::new(event_key), codec);
+        }
+    }
+
+    /// Gets the codec events emitted under a key are serialized with
+    pub fn event_codec(&self, event_key: &str) -> EventCodec {
+        if self.codecs.is_empty() {
+            return EventCodec::Json;
+        }
+        self.codecs.get(event_key).map(|codec| *codec).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::Event;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct VoiceChunk {
+        speaker: u32,
+        samples: Vec<u8>,
+    }
+
+    #[test]
+    fn test_codecs_round_trip() {
+        let chunk = VoiceChunk { speaker: 7, samples: vec![1, 2, 3, 250] };
+        for codec in [EventCodec::Json, EventCodec::Bincode, EventCodec::MessagePack] {
+            let payload = chunk.serialize_with(codec).unwrap();
+            assert_eq!(EventCodec::of(&payload), codec);
+            assert_eq!(<VoiceChunk as Event>::deserialize(&payload).unwrap(), chunk);
+        }
+        assert!(chunk.serialize_with(EventCodec::Bincode).unwrap().len() < Event::serialize(&chunk).unwrap().len());
+        assert_eq!("MessagePack".parse::<EventCodec>().unwrap(), EventCodec::MessagePack);
+        assert!("yaml".parse::<EventCodec>().is_err());
+    }
+
+    #[test]
+    fn test_wrapped_payloads_decode_as_named_structs() {
+        #[derive(Debug, Deserialize)]
+        struct Named {
+            event_name: String,
+            data: VoiceChunk,
+        }
+
+        let chunk = VoiceChunk { speaker: 3, samples: vec![9; 16] };
+        for codec in [EventCodec::Bincode, EventCodec::MessagePack] {
+            let wrapped = codec.wrap_named("voice:chunk", &codec.encode(&chunk).unwrap()).unwrap();
+            let named: Named = EventCodec::decode(&wrapped).unwrap();
+            assert_eq!((named.event_name.as_str(), named.data), ("voice:chunk", VoiceChunk { speaker: 3, samples: vec![9; 16] }));
+        }
+    }
+}
@@ -0,0 +1,151 @@
+/// Per-event-key limits on concurrently dispatched emissions
+use super::core::EventSystem;
+use super::queue::EmissionPermit;
+use crate::events::EventError;
+use compact_str::CompactString;
+use dashmap::DashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::thread::ThreadId;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::{debug, info};
+
+/// Slots an emission holds while it dispatches to handlers
+#[derive(Debug)]
+pub(super) struct Admission {
+    _permit: Option<EmissionPermit>,
+    _key_slot: Option<OwnedSemaphorePermit>,
+}
+
+/// Future that marks the emissions a handler makes while it is polled as nested.
+///
+/// Like [`OwnerScope`](super::OwnerScope) the mark is kept per thread for
+/// the duration of each poll, so it holds across plugin library boundaries.
+pub(super) struct DispatchScope<'a, F> {
+    dispatching: &'a DashMap<ThreadId, usize>,
+    inner: Pin<Box<F>>,
+}
+
+impl<'a, F> DispatchScope<'a, F> {
+    pub(super) fn new(dispatching: &'a DashMap<ThreadId, usize>, future: F) -> Self {
+        Self {
+            dispatching,
+            inner: Box::pin(future),
+        }
+    }
+}
+
+impl<F: Future> Future for DispatchScope<'_, F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let thread = std::thread::current().id();
+        *self.dispatching.entry(thread).or_insert(0) += 1;
+        let poll = self.inner.as_mut().poll(cx);
+        self.dispatching.remove_if_mut(&thread, |_, depth| {
+            *depth -= 1;
+            *depth == 0
+        });
+        poll
+    }
+}
+
+/// Concurrency limit configured for one event key
+#[derive(Debug)]
+pub(super) struct KeyLimit {
+    limit: usize,
+    slots: Arc<Semaphore>,
+}
+
+impl EventSystem {
+    /// Limits how many emissions of an event key dispatch to handlers at once.
+    ///
+    /// Emissions over the limit wait for a running one to finish and then
+    /// proceed in the order they arrived. A limit of 1 serializes the key, so
+    /// order-sensitive handlers such as inventory mutations see events one at
+    /// a time and in emission order without locking on their own. Emissions
+    /// that are already waiting keep the limit they started with. Emissions a
+    /// handler makes while it runs dispatch right away, even for the key it
+    /// is handling, rather than waiting on the slot their own emission holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_key` - Full event key, e.g. `plugin:inventory:add_item`
+    /// * `limit` - Emissions dispatched at the same time (at least 1)
+    pub fn set_concurrency_limit(&self, event_key: &str, limit: usize) {
+        let limit = limit.max(1);
+        info!("🚦 Limiting {} to {} concurrent emission(s)", event_key, limit);
+        self.key_limits.insert(
+            CompactString::new(event_key),
+            KeyLimit {
+                limit,
+                slots: Arc::new(Semaphore::new(limit)),
+            },
+        );
+    }
+
+    /// Removes the concurrency limit of an event key
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key had a limit.
+    pub fn clear_concurrency_limit(&self, event_key: &str) -> bool {
+        self.key_limits.remove(event_key).is_some()
+    }
+
+    /// Gets the concurrency limit of an event key, if it has one
+    pub fn concurrency_limit(&self, event_key: &str) -> Option<usize> {
+        self.key_limits.get(event_key).map(|key_limit| key_limit.limit)
+    }
+
+    /// Waits for an emission's slot in its category's queue and, if the key
+    /// is limited, for one of the key's dispatch slots.
+    ///
+    /// Emissions made by a handler while it runs are nested in the emission
+    /// that is running it. They dispatch on that emission's slots instead of
+    /// waiting for new ones, which a full [`Block`](super::OverflowPolicy::Block)
+    /// queue or a key limited to 1 would never hand out while the outer
+    /// emission holds them. Nested emissions therefore bypass both the queue
+    /// and the key limit. Tasks spawned by handlers are not nested.
+    ///
+    /// # Returns
+    ///
+    /// The slots to hold until the emission finishes, `None` if the emission
+    /// was discarded by the drop-oldest policy, or
+    /// [`EventError::QueueFull`] if it was rejected.
+    pub(super) async fn admit_emission(&self, event_key: &str) -> Result<Option<Admission>, EventError> {
+        if self.in_dispatch() {
+            return Ok(Some(Admission { _permit: None, _key_slot: None }));
+        }
+        let Some(permit) = self.emission_queue(event_key).admit().await? else {
+            debug!("🗑️ Dropped {} to make room in its emission queue", event_key);
+            return Ok(None);
+        };
+        Ok(Some(Admission {
+            _permit: Some(permit),
+            _key_slot: self.acquire_key_slot(event_key).await,
+        }))
+    }
+
+    /// Checks whether the current thread is polling a handler
+    fn in_dispatch(&self) -> bool {
+        !self.dispatching.is_empty() && self.dispatching.contains_key(&std::thread::current().id())
+    }
+
+    /// Waits for a dispatch slot of a limited event key.
+    ///
+    /// # Returns
+    ///
+    /// The slot, held until the emission finishes, or `None` if the key is
+    /// unlimited.
+    async fn acquire_key_slot(&self, event_key: &str) -> Option<OwnedSemaphorePermit> {
+        if self.key_limits.is_empty() {
+            return None;
+        }
+        let slots = self.key_limits.get(event_key)?.slots.clone();
+        // The semaphore is never closed, so acquiring only fails if it were
+        slots.acquire_owned().await.ok()
+    }
+}
@@ -1,15 +1,31 @@
 /// Core EventSystem implementation
 use crate::events::EventHandler;
 use crate::gorc::instance::GorcInstanceManager;
+use crate::gorc::multicast::MulticastManager;
+use crate::gorc::wire::WireFormat;
+use crate::types::PlayerId;
 use super::client::ClientResponseSender;
-use super::stats::EventSystemStats;
+use super::codec::EventCodec;
+use super::concurrency::KeyLimit;
+use super::stats::{EventSystemStats, KeyMetrics};
 use super::path_router::PathRouter;
+use bytes::Bytes;
 use std::sync::Arc;
-use dashmap::DashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use dashmap::{DashMap, DashSet};
 // use smallvec::SmallVec;
 use compact_str::CompactString;
 use super::cache::SerializationBufferPool;
-use tokio::sync::RwLock;
+use super::supervision::{HandlerGate, HandlerPanicReport};
+use super::queue::{EmissionQueue, EmissionQueueConfig, EventCategory};
+use super::rpc::RpcOutcome;
+use super::schema::RegisteredSchema;
+use super::spans::HandlerSpanConfig;
+use super::timers::ScheduledTimer;
+use tokio::sync::{broadcast, oneshot, RwLock};
+
+/// Number of unread panic reports buffered per supervisor
+const PANIC_REPORT_CAPACITY: usize = 64;
 
 /// The core event system that manages event routing and handler execution.
 /// 
@@ -17,12 +33,18 @@ use tokio::sync::RwLock;
 /// type-safe event registration and emission with support for different event
 /// categories (core, client, plugin, and GORC instance events).
 /// 
+/// Handlers registered for the same event run one at a time, highest
+/// [`HandlerPriority`](super::HandlerPriority) first and in registration
+/// order among equal priorities.
+/// 
 /// Uses DashMap for lock-free concurrent access to handlers, significantly improving
 /// performance under high concurrency by eliminating reader-writer lock contention.
 /// Uses SmallVec to eliminate heap allocations for the common case of 1-4 handlers per event.
 pub struct EventSystem {
     /// Lock-free map of event keys to their registered handlers (optimized with SmallVec + CompactString)  
     pub(super) handlers: DashMap<CompactString, Vec<Arc<dyn EventHandler>>>,
+    /// Keys of handlers registered with `*` wildcards, matched against every emitted key
+    pub(super) pattern_keys: DashSet<CompactString>,
     /// Path-based router for efficient similarity searches and hierarchical organization
     pub(super) path_router: RwLock<PathRouter>,
     /// System statistics for monitoring (kept as RwLock for atomic updates)
@@ -33,6 +55,59 @@ pub struct EventSystem {
     pub(super) gorc_instances: Option<Arc<GorcInstanceManager>>,
     /// Client response sender for connection-aware handlers
     pub(super) client_response_sender: Option<Arc<dyn ClientResponseSender + Send + Sync>>,
+    /// Link server-to-server events are sent to other servers over
+    pub(super) server_link: Option<Arc<dyn super::s2s::ServerLink>>,
+    /// Gate deciding whether plugin-owned handlers may run
+    pub(super) handler_gate: Option<Arc<dyn HandlerGate>>,
+    /// Owner (usually a plugin name) of each registered handler, keyed by handler identity
+    pub(super) handler_owners: DashMap<usize, CompactString>,
+    /// Execution priority of each handler registered with a non-default one, keyed by handler identity
+    pub(super) handler_priorities: DashMap<usize, super::handlers::HandlerPriority>,
+    /// Event key of each one-shot handler still waiting for its event, keyed by handler identity
+    pub(super) once_handlers: DashMap<usize, CompactString>,
+    /// Owner that handlers registered on each thread are attributed to, while
+    /// a [`register_as`](EventSystem::register_as) scope is polled there
+    pub(super) registrants: DashMap<std::thread::ThreadId, CompactString>,
+    /// Channel used to report handler panics to supervisors
+    pub(super) panic_sender: broadcast::Sender<HandlerPanicReport>,
+    /// Replication wire format negotiated by each client (JSON when absent)
+    pub(super) wire_formats: DashMap<PlayerId, WireFormat>,
+    /// Multicast groups plugins can fan events out to
+    pub(super) multicast: Arc<MulticastManager>,
+    /// Latest server tick, stamped on outgoing replication messages
+    pub(super) server_tick: AtomicU64,
+    /// Bounded admission queue per event category, indexed by [`EventCategory`]
+    pub(super) emission_queues: [EmissionQueue; 4],
+    /// Callers waiting for a response to a request, keyed by request ID
+    pub(super) pending_requests: Arc<DashMap<u64, oneshot::Sender<RpcOutcome>>>,
+    /// ID given to the next request
+    pub(super) next_request_id: AtomicU64,
+    /// Payload schemas events are validated against, keyed by event key
+    pub(super) schemas: DashMap<CompactString, RegisteredSchema>,
+    /// Which handler invocations are wrapped in tracing spans
+    pub(super) handler_spans: HandlerSpanConfig,
+    /// Handler invocations considered for span sampling so far
+    pub(super) handler_span_invocations: AtomicU64,
+    /// Handler invocation statistics per event key
+    pub(super) key_metrics: DashMap<CompactString, KeyMetrics>,
+    /// Events dispatched to at least one handler, readable without taking the stats lock
+    pub(super) events_processed: AtomicU64,
+    /// Dispatched events for which at least one handler failed or panicked
+    pub(super) events_failed: AtomicU64,
+    /// Latched event keys and the last value emitted under each
+    pub(super) latched: DashMap<CompactString, Option<Bytes>>,
+    /// Event keys whose emissions may only dispatch a limited number at a time
+    pub(super) key_limits: DashMap<CompactString, KeyLimit>,
+    /// Running interval timers, keyed by timer name
+    pub(super) timers: DashMap<CompactString, ScheduledTimer>,
+    /// Codec declared for event keys whose payloads aren't JSON
+    pub(super) codecs: DashMap<CompactString, EventCodec>,
+    /// Event namespaces each plugin declared in its manifest
+    pub(super) namespace_grants: DashMap<CompactString, Vec<CompactString>>,
+    /// Plugin that emissions on each thread are currently attributed to
+    pub(super) emitters: DashMap<std::thread::ThreadId, CompactString>,
+    /// Handlers being polled on each thread, so their emissions are known to be nested
+    pub(super) dispatching: DashMap<std::thread::ThreadId, usize>,
 }
 
 impl std::fmt::Debug for EventSystem {
@@ -42,6 +117,7 @@ impl std::fmt::Debug for EventSystem {
             .field("stats", &"[stats]")
             .field("gorc_instances", &self.gorc_instances.is_some())
             .field("client_response_sender", &self.client_response_sender.is_some())
+            .field("server_link", &self.server_link.is_some())
             .finish()
     }
 }
@@ -51,11 +127,38 @@ impl EventSystem {
     pub fn new() -> Self {
         Self {
             handlers: DashMap::new(),
+            pattern_keys: DashSet::new(),
             path_router: RwLock::new(PathRouter::new()),
             stats: tokio::sync::RwLock::new(EventSystemStats::default()),
             serialization_pool: SerializationBufferPool::default(),
             gorc_instances: None,
             client_response_sender: None,
+            server_link: None,
+            handler_gate: None,
+            handler_owners: DashMap::new(),
+            handler_priorities: DashMap::new(),
+            once_handlers: DashMap::new(),
+            registrants: DashMap::new(),
+            panic_sender: broadcast::channel(PANIC_REPORT_CAPACITY).0,
+            wire_formats: DashMap::new(),
+            multicast: Arc::new(MulticastManager::new()),
+            server_tick: AtomicU64::new(0),
+            emission_queues: emission_queues(&EmissionQueueConfig::default()),
+            pending_requests: Arc::new(DashMap::new()),
+            next_request_id: AtomicU64::new(1),
+            schemas: DashMap::new(),
+            handler_spans: HandlerSpanConfig::default(),
+            handler_span_invocations: AtomicU64::new(0),
+            key_metrics: DashMap::new(),
+            events_processed: AtomicU64::new(0),
+            events_failed: AtomicU64::new(0),
+            latched: DashMap::new(),
+            key_limits: DashMap::new(),
+            timers: DashMap::new(),
+            codecs: DashMap::new(),
+            namespace_grants: DashMap::new(),
+            emitters: DashMap::new(),
+            dispatching: DashMap::new(),
         }
     }
 
@@ -63,19 +166,73 @@ impl EventSystem {
     pub fn with_gorc(gorc_instances: Arc<GorcInstanceManager>) -> Self {
         Self {
             handlers: DashMap::new(),
+            pattern_keys: DashSet::new(),
             path_router: RwLock::new(PathRouter::new()),
             stats: tokio::sync::RwLock::new(EventSystemStats::default()),
             serialization_pool: SerializationBufferPool::default(),
             gorc_instances: Some(gorc_instances),
             client_response_sender: None,
+            server_link: None,
+            handler_gate: None,
+            handler_owners: DashMap::new(),
+            handler_priorities: DashMap::new(),
+            once_handlers: DashMap::new(),
+            registrants: DashMap::new(),
+            panic_sender: broadcast::channel(PANIC_REPORT_CAPACITY).0,
+            wire_formats: DashMap::new(),
+            multicast: Arc::new(MulticastManager::new()),
+            server_tick: AtomicU64::new(0),
+            emission_queues: emission_queues(&EmissionQueueConfig::default()),
+            pending_requests: Arc::new(DashMap::new()),
+            next_request_id: AtomicU64::new(1),
+            schemas: DashMap::new(),
+            handler_spans: HandlerSpanConfig::default(),
+            handler_span_invocations: AtomicU64::new(0),
+            key_metrics: DashMap::new(),
+            events_processed: AtomicU64::new(0),
+            events_failed: AtomicU64::new(0),
+            latched: DashMap::new(),
+            key_limits: DashMap::new(),
+            timers: DashMap::new(),
+            codecs: DashMap::new(),
+            namespace_grants: DashMap::new(),
+            emitters: DashMap::new(),
+            dispatching: DashMap::new(),
         }
     }
 
+    /// Replaces the emission queue of every event category.
+    ///
+    /// Emissions already waiting in the old queues still complete.
+    pub fn set_emission_queue_config(&mut self, config: EmissionQueueConfig) {
+        self.emission_queues = emission_queues(&config);
+    }
+
+    /// Replaces the emission queue of one event category
+    pub fn set_category_queue_config(&mut self, category: EventCategory, config: EmissionQueueConfig) {
+        self.emission_queues[category.index()] = EmissionQueue::new(category, config);
+    }
+
+    /// Gets the emission queue an event key is admitted through
+    pub(super) fn emission_queue(&self, event_key: &str) -> &EmissionQueue {
+        &self.emission_queues[EventCategory::from_key(event_key).index()]
+    }
+
     /// Sets the GORC instance manager for this event system
     pub fn set_gorc_instances(&mut self, gorc_instances: Arc<GorcInstanceManager>) {
         self.gorc_instances = Some(gorc_instances);
     }
 
+    /// Shares an existing multicast manager with this event system
+    pub fn set_multicast_manager(&mut self, multicast: Arc<MulticastManager>) {
+        self.multicast = multicast;
+    }
+
+    /// Gets the multicast manager backing the group APIs
+    pub fn get_multicast_manager(&self) -> Arc<MulticastManager> {
+        self.multicast.clone()
+    }
+
     /// Sets the client response sender for connection-aware handlers
     pub fn set_client_response_sender(&mut self, sender: Arc<dyn ClientResponseSender + Send + Sync>) {
         self.client_response_sender = Some(sender);
@@ -91,17 +248,69 @@ impl EventSystem {
     /// Gets the current event system statistics
     #[inline]
     pub async fn get_stats(&self) -> EventSystemStats {
-        self.stats.read().await.clone()
+        let mut stats = self.stats.read().await.clone();
+        stats.events_failed = self.events_failed();
+        stats.emission_queues = self.emission_queues.iter().map(EmissionQueue::stats).collect();
+        stats
     }
     
+    /// Gets the number of events dispatched to at least one handler.
+    ///
+    /// Lock-free, so health checks and metrics scrapes can call it often.
+    pub fn events_processed(&self) -> u64 {
+        self.events_processed.load(Ordering::Relaxed)
+    }
+
+    /// Gets the number of dispatched events for which at least one handler
+    /// returned an error or panicked
+    pub fn events_failed(&self) -> u64 {
+        self.events_failed.load(Ordering::Relaxed)
+    }
+
     /// Gets access to the GORC instances manager (if available)
     pub fn get_gorc_instances(&self) -> Option<Arc<crate::gorc::instance::GorcInstanceManager>> {
         self.gorc_instances.clone()
     }
+
+    /// Sets the wire format used for GORC replication messages sent to a player
+    pub fn set_wire_format(&self, player_id: PlayerId, format: WireFormat) {
+        if format == WireFormat::Json {
+            self.wire_formats.remove(&player_id);
+        } else {
+            self.wire_formats.insert(player_id, format);
+        }
+    }
+
+    /// Gets the wire format negotiated by a player, defaulting to JSON
+    #[inline]
+    pub fn wire_format(&self, player_id: PlayerId) -> WireFormat {
+        self.wire_formats.get(&player_id).map(|f| *f).unwrap_or_default()
+    }
+
+    /// Forgets the wire format of a disconnected player
+    pub fn clear_wire_format(&self, player_id: PlayerId) {
+        self.wire_formats.remove(&player_id);
+    }
+
+    /// Records the server tick that replication messages are stamped with
+    pub fn set_server_tick(&self, tick: u64) {
+        self.server_tick.store(tick, Ordering::Relaxed);
+    }
+
+    /// Gets the latest server tick
+    #[inline]
+    pub fn server_tick(&self) -> u64 {
+        self.server_tick.load(Ordering::Relaxed)
+    }
 }
 
 impl Default for EventSystem {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+/// Builds one emission queue per event category from a shared configuration
+fn emission_queues(config: &EmissionQueueConfig) -> [EmissionQueue; 4] {
+    EventCategory::ALL.map(|category| EmissionQueue::new(category, config.clone()))
+}
@@ -1,13 +1,87 @@
 /// Event emission methods
-use crate::events::{Event, EventError};
-use crate::gorc::instance::GorcObjectId;
+use crate::events::{
+    Event, EventError, EventHandler, GorcObjectAttachedEvent, GorcObjectDetachedEvent,
+    GorcVirtualZoneMergedEvent, GorcVirtualZoneSplitEvent, GorcZoneEnteredEvent, GorcZoneExitedEvent,
+    PlayerMovementEvent,
+};
+use crate::gorc::instance::{
+    AttachmentError, GorcInstanceManager, GorcObjectId, ObjectAttachment, ObjectAuthority, ObjectInstance, ObjectMove,
+};
+use crate::gorc::virtualization::VirtualZoneEvent;
+use crate::gorc::wire::{self, PayloadEncoding, WireFormat, WireStamp};
 use crate::{PlayerId, Vec3};
+use super::client::ClientResponseSender;
+use super::codec::EventCodec;
+use super::concurrency::DispatchScope;
 use super::core::EventSystem;
-use super::stats::{DetailedEventSystemStats, HandlerCategoryStats};
-use futures::{self, stream::{FuturesUnordered, StreamExt}};
-use tracing::{debug, error, info, warn};
+use super::path_router::{matches_pattern, PatternMatch};
+use super::stats::{DetailedEventSystemStats, EventKeyStats, HandlerCategoryStats};
+use super::supervision::{panic_message, HandlerPanicReport};
+use bytes::Bytes;
+use futures::FutureExt;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{debug, error, info, warn, Instrument};
 use compact_str::CompactString;
 
+/// A handler paired with the serialized data it is dispatched with.
+///
+/// Every handler of an emission shares one reference-counted buffer; each
+/// handler deserializes it into its own event type only when it runs.
+type HandlerDispatch = (Arc<dyn EventHandler>, Bytes);
+
+/// Handlers an event key routes to, resolved once so a batch can reuse them
+struct ResolvedRoute {
+    /// Handlers registered for the exact key, in priority order
+    exact: Vec<Arc<dyn EventHandler>>,
+    /// Wildcard handlers whose pattern matches the key
+    patterns: Vec<Arc<dyn EventHandler>>,
+}
+
+impl ResolvedRoute {
+    fn is_empty(&self) -> bool {
+        self.exact.is_empty() && self.patterns.is_empty()
+    }
+}
+
+/// Positions LOD tiers are chosen by, fetched on first use
+#[derive(Default)]
+struct LodPositions {
+    /// Fetch every object position on first use instead of one per object
+    batch: bool,
+    players: Option<HashMap<PlayerId, Vec3>>,
+    objects: Option<HashMap<GorcObjectId, Vec3>>,
+}
+
+impl LodPositions {
+    fn for_batch() -> Self {
+        Self { batch: true, ..Self::default() }
+    }
+
+    /// Gets the object's position along with every player's position
+    async fn resolve(
+        &mut self,
+        gorc_instances: &GorcInstanceManager,
+        object_id: GorcObjectId,
+    ) -> Option<(Vec3, &HashMap<PlayerId, Vec3>)> {
+        if self.players.is_none() {
+            self.players = Some(gorc_instances.get_player_positions().await.into_iter().collect());
+        }
+        let object_position = if self.batch {
+            if self.objects.is_none() {
+                self.objects = Some(gorc_instances.get_object_positions().await.into_iter().collect());
+            }
+            self.objects.as_ref().and_then(|objects| objects.get(&object_id).copied())
+        } else {
+            gorc_instances.get_object_position(object_id).await
+        }?;
+        self.players.as_ref().map(|players| (object_position, players))
+    }
+}
 
 impl EventSystem {
     /// Emits a core server event to all registered handlers.
@@ -17,9 +91,34 @@ impl EventSystem {
         T: Event,
     {
         let event_key = CompactString::new_inline("core:") + event_name;
+        self.check_emitter(&event_key)?;
+        self.validate_payload(&event_key, event)?;
         self.emit_event(&event_key, event).await
     }
 
+    /// Emits several core events of the same name in one pass.
+    ///
+    /// The handlers for the event are looked up once and every event is
+    /// serialized up front, then the events are dispatched in order. Use it
+    /// on hot paths that would otherwise call [`emit_core`](Self::emit_core)
+    /// in a loop. If any event fails schema validation, none are emitted.
+    ///
+    /// # Returns
+    ///
+    /// The number of events delivered to handlers (0 if there are none).
+    pub async fn emit_core_batch<T>(&self, event_name: &str, events: &[T]) -> Result<usize, EventError>
+    where
+        T: Event,
+    {
+        let event_key = CompactString::new_inline("core:") + event_name;
+        self.check_emitter(&event_key)?;
+        for event in events {
+            self.validate_payload(&event_key, event)?;
+        }
+        let events: Vec<&T> = events.iter().collect();
+        self.emit_event_batch(&event_key, &events).await
+    }
+
     /// Emits a client event to all registered handlers.
     #[inline]
     pub async fn emit_client<T>(
@@ -32,6 +131,8 @@ impl EventSystem {
         T: Event,
     {
         let event_key = CompactString::new_inline("client:") + namespace + ":" + event_name;
+        self.check_emitter(&event_key)?;
+        self.validate_payload(&event_key, event)?;
         self.emit_event(&event_key, event).await
     }
 
@@ -56,14 +157,33 @@ impl EventSystem {
     where
         T: Event + serde::Serialize,
     {
-        // Create a wrapper that includes the player context
-        let context_event = serde_json::json!({
-            "player_id": player_id,
-            "data": event
-        });
-        
+        // Validate the client's payload itself, not the context wrapped around it
         let event_key = CompactString::new_inline("client:") + namespace + ":" + event_name;
-        self.emit_event(&event_key, &context_event).await
+        self.check_emitter(&event_key)?;
+        self.validate_payload(&event_key, event)?;
+
+        // Serialize the player context around the payload in one pass,
+        // without building an intermediate JSON value
+        let context_event = crate::events::ClientEventWrapper::new(player_id, event);
+        self.emit_encoded(&event_key, || {
+            serde_json::to_vec(&context_event)
+                .map(Bytes::from)
+                .map_err(EventError::Serialization)
+        })
+        .await
+    }
+
+    /// Emits a raw binary client message to the handlers of its channel.
+    ///
+    /// The message is emitted under `client:binary:<channel>` and always
+    /// encoded with bincode so the payload isn't inflated into a JSON array;
+    /// handlers registered with [`on_client_binary`](Self::on_client_binary)
+    /// decode it transparently.
+    pub async fn emit_client_binary(&self, message: &crate::events::ClientBinaryMessage) -> Result<(), EventError> {
+        let event_key = CompactString::new_inline("client:") + super::BINARY_NAMESPACE + ":" + &message.channel.to_string();
+        self.check_emitter(&event_key)?;
+        self.emit_encoded(&event_key, || EventCodec::Bincode.encode(message).map(Bytes::from))
+            .await
     }
 
     /// Emits a plugin event to all registered handlers.
@@ -77,7 +197,10 @@ impl EventSystem {
     where
         T: Event,
     {
+        self.check_plugin_namespace(plugin_name)?;
         let event_key = CompactString::new_inline("plugin:") + plugin_name + ":" + event_name;
+        self.check_emitter(&event_key)?;
+        self.validate_payload(&event_key, event)?;
         self.emit_event(&event_key, event).await
     }
 
@@ -171,6 +294,95 @@ impl EventSystem {
         }
     }
     
+    /// Emits the same GORC instance event for many objects in one pass.
+    ///
+    /// Intended for per-tick fan-out such as position updates. The objects
+    /// are fetched with one lock acquisition per object shard, server-side
+    /// handlers are resolved once per object type, and replication stats are
+    /// recorded together at the end. Unknown objects, and objects that don't
+    /// define `channel`, are skipped with a warning instead of failing the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - Replication channel for the events
+    /// * `event_name` - Name of the event
+    /// * `events` - Objects paired with their event data
+    /// * `dest` - Whether to deliver to server handlers, subscribed clients, or both
+    ///
+    /// # Returns
+    ///
+    /// The number of events emitted for known objects.
+    pub async fn emit_gorc_batch<T>(
+        &self,
+        channel: u8,
+        event_name: &str,
+        events: &[(GorcObjectId, T)],
+        dest: crate::events::Dest,
+    ) -> Result<usize, EventError>
+    where
+        T: Event + serde::Serialize,
+    {
+        use crate::events::Dest;
+
+        if dest == Dest::None || events.is_empty() {
+            return Ok(0);
+        }
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        let object_ids: Vec<GorcObjectId> = events.iter().map(|(object_id, _)| *object_id).collect();
+        let instances = gorc_instances.get_objects(&object_ids).await;
+        let known: Vec<(&ObjectInstance, &T)> = events
+            .iter()
+            .filter_map(|(object_id, event)| match instances.get(object_id) {
+                Some(instance) => Some((instance, event)),
+                None => {
+                    warn!("Skipping GORC batch event {} for unknown object {}", event_name, object_id);
+                    None
+                }
+            })
+            .collect();
+
+        if dest == Dest::Server || dest == Dest::Both {
+            // Group by object type, keeping emission order within each type
+            let mut by_type: Vec<(&str, Vec<&T>)> = Vec::new();
+            for (instance, event) in &known {
+                match by_type.iter_mut().find(|(type_name, _)| *type_name == instance.type_name) {
+                    Some((_, type_events)) => type_events.push(*event),
+                    None => by_type.push((&instance.type_name, vec![*event])),
+                }
+            }
+            for (type_name, type_events) in by_type {
+                let instance_key = CompactString::new_inline("gorc_instance:") + type_name + ":" + &channel.to_string() + ":" + event_name;
+                if let Err(e) = self.emit_event_batch(&instance_key, &type_events).await {
+                    warn!("Failed to emit instance events: {}", e);
+                }
+            }
+        }
+
+        if dest == Dest::Client || dest == Dest::Both {
+            let sender = self.client_response_sender.as_ref().ok_or_else(|| {
+                EventError::HandlerExecution("Client response sender not configured for GORC emission".to_string())
+            })?;
+            let stamp = WireStamp::now(self.server_tick());
+            let mut positions = LodPositions::for_batch();
+            let mut replications = Vec::with_capacity(known.len());
+            for (instance, event) in &known {
+                match self
+                    .send_to_gorc_subscribers(sender, gorc_instances, instance, channel, event_name, *event, stamp, &mut positions)
+                    .await
+                {
+                    Ok((sent_count, sent_bytes)) => replications.push((instance.object_id, sent_count, sent_bytes)),
+                    Err(e) => warn!("Skipping GORC batch event {} for object {}: {}", event_name, instance.object_id, e),
+                }
+            }
+            gorc_instances.record_replications(&replications).await;
+        }
+
+        Ok(known.len())
+    }
+
     /// Emits event data directly to clients subscribed to the object's channel
     async fn emit_to_gorc_subscribers<T>(
         &self,
@@ -197,6 +409,38 @@ impl EventSystem {
             EventError::HandlerNotFound(format!("Object instance {} not found", object_id))
         })?;
         
+        let stamp = WireStamp::now(self.server_tick());
+        let mut positions = LodPositions::default();
+        let (sent_count, sent_bytes) = self
+            .send_to_gorc_subscribers(sender, gorc_instances, &instance, channel, event_name, event, stamp, &mut positions)
+            .await?;
+        gorc_instances.record_replication(object_id, sent_count, sent_bytes).await;
+        
+        Ok(())
+    }
+
+    /// Sends an event to the players subscribed to one of an object's channels
+    ///
+    /// # Returns
+    ///
+    /// The number of messages and bytes sent.
+    #[allow(clippy::too_many_arguments)]
+    async fn send_to_gorc_subscribers<T>(
+        &self,
+        sender: &Arc<dyn ClientResponseSender + Send + Sync>,
+        gorc_instances: &GorcInstanceManager,
+        instance: &ObjectInstance,
+        channel: u8,
+        event_name: &str,
+        event: &T,
+        stamp: WireStamp,
+        positions: &mut LodPositions,
+    ) -> Result<(u64, u64), EventError>
+    where
+        T: Event + serde::Serialize,
+    {
+        let object_id = instance.object_id;
+
         // Get the replication layer for this channel
         let layers = instance.object.get_layers();
         let layer = layers.iter().find(|l| l.channel == channel).ok_or_else(|| {
@@ -208,40 +452,58 @@ impl EventSystem {
         let subscribers: Vec<PlayerId> = instance.subscribers
             .get(&channel)
             .map(|subs| subs.iter().copied().collect())
-            .unwrap_or_else(Vec::new);
+            .unwrap_or_default();
         
         debug!("📡 GORC EMIT: Object {} channel {} has {} subscribers", 
                object_id, channel, subscribers.len());
         
-        // Create the event message for clients - just use the event_name directly
-        let client_event = serde_json::json!({
-            "event_type": event_name,
-            "object_id": object_id.to_string(),
-            "object_type": instance.type_name,
-            "channel": channel,
-            "player_id": object_id.to_string(),
-            "data": event,
-            "timestamp": crate::utils::current_timestamp()
-        });
+        // Distances decide which LOD tier each subscriber receives
+        let lod_positions = if layer.lod.is_empty() {
+            None
+        } else {
+            positions.resolve(gorc_instances, object_id).await
+        };
         
-        // Serialize the event data
-        let data = serde_json::to_vec(&client_event)
-            .map_err(|e| EventError::Serialization(e))?;
+        // Encode the event once per wire format and LOD tier in use by the subscribers
+        let mut encoded: Vec<(WireFormat, Option<usize>, Bytes)> = Vec::with_capacity(1);
         
         // Send to all subscribers
         let mut sent_count = 0;
+        let mut sent_bytes = 0;
         for player_id in subscribers {
-            if let Err(e) = sender.send_to_client(player_id, data.clone()).await {
+            let format = self.wire_format(player_id);
+            let tier = lod_positions.as_ref().and_then(|(object_pos, player_positions)| {
+                let distance = player_positions.get(&player_id)?.distance(*object_pos);
+                let tier = layer.lod_tier_for_distance(distance)?;
+                layer.lod.iter().position(|t| t == tier)
+            });
+            let data = match encoded.iter().find(|(f, t, _)| *f == format && *t == tier) {
+                Some((_, _, data)) => data.clone(),
+                None => {
+                    let data = Bytes::from(match tier {
+                        Some(index) => {
+                            let trimmed = layer.lod[index].trim(serde_json::to_value(event)?);
+                            wire::encode_event(format, object_id, &instance.type_name, channel, event_name, &trimmed, stamp)?
+                        }
+                        None => wire::encode_event(format, object_id, &instance.type_name, channel, event_name, event, stamp)?,
+                    });
+                    encoded.push((format, tier, data.clone()));
+                    data
+                }
+            };
+            let size = data.len() as u64;
+            if let Err(e) = sender.send_replication(player_id, object_id, channel, data.to_vec()).await {
                 warn!("Failed to send GORC event to player {}: {}", player_id, e);
             } else {
                 sent_count += 1;
+                sent_bytes += size;
             }
         }
         
         debug!("📡 GORC: Sent {} event to {} clients on channel {} for object {}", 
                event_name, sent_count, channel, object_id);
         
-        Ok(())
+        Ok((sent_count, sent_bytes))
     }
     
     /// Update player position and handle zone membership changes (event-driven GORC)
@@ -261,18 +523,77 @@ impl EventSystem {
         // Handle zone entries - send zone entry messages with current layer state
         for (object_id, channel) in zone_entries {
             debug!("🎮 EVENT DEBUG: Sending zone entry message for object {} channel {}", object_id, channel);
+            self.emit_zone_change(player_id, object_id, channel, true).await?;
             self.send_zone_entry_message(player_id, object_id, channel).await?;
         }
 
         // Handle zone exits - send zone exit messages to inform client
         for (object_id, channel) in zone_exits {
             debug!("🎮 EVENT DEBUG: Sending zone exit message for object {} channel {}", object_id, channel);
+            self.emit_zone_change(player_id, object_id, channel, false).await?;
             self.send_zone_exit_message(player_id, object_id, channel).await?;
         }
 
         Ok(())
     }
 
+    /// Update a player's position and velocity, and handle zone membership changes.
+    ///
+    /// The velocity lets zone checks dead-reckon the player between movement
+    /// updates; see [`update_predicted_zones`](Self::update_predicted_zones).
+    /// The movement is then emitted as the `player_movement` core event, from
+    /// which the server derives the direction the player is facing.
+    pub async fn update_player_motion(&self, player_id: PlayerId, new_position: Vec3, velocity: Vec3) -> Result<(), EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        let old_position = gorc_instances.get_player_position(player_id).await;
+        gorc_instances.update_player_velocity(player_id, velocity).await;
+        self.update_player_position(player_id, new_position).await?;
+
+        self.emit_core("player_movement", &PlayerMovementEvent {
+            player_id,
+            old_position,
+            new_position,
+            velocity: Some(velocity),
+            timestamp: crate::utils::current_timestamp(),
+        }).await
+    }
+
+    /// Re-evaluates zones at dead-reckoned player positions and sends the
+    /// resulting zone entry and exit messages.
+    ///
+    /// Call it once per server tick. It does nothing unless the GORC instance
+    /// manager has a dead-reckoning horizon.
+    ///
+    /// # Returns
+    ///
+    /// The number of zone entries and exits handled.
+    pub async fn update_predicted_zones(&self) -> Result<usize, EventError> {
+        let Some(gorc_instances) = self.gorc_instances.as_ref() else {
+            return Ok(0);
+        };
+
+        let mut handled = 0;
+        for (player_id, zone_entries, zone_exits) in gorc_instances.refresh_predicted_zones().await {
+            for (object_id, channel) in zone_entries {
+                debug!("🎮 GORC: Predicted zone entry - player {} enters object {} channel {}", player_id, object_id, channel);
+                self.emit_zone_change(player_id, object_id, channel, true).await?;
+                self.send_zone_entry_message(player_id, object_id, channel).await?;
+                handled += 1;
+            }
+            for (object_id, channel) in zone_exits {
+                debug!("🎮 GORC: Predicted zone exit - player {} leaves object {} channel {}", player_id, object_id, channel);
+                self.emit_zone_change(player_id, object_id, channel, false).await?;
+                self.send_zone_exit_message(player_id, object_id, channel).await?;
+                handled += 1;
+            }
+        }
+
+        Ok(handled)
+    }
+
     /// Update object position and handle zone membership changes for stationary players
     pub async fn update_object_position(&self, object_id: GorcObjectId, new_position: Vec3) -> Result<(), EventError> {
         // Get the GORC instances manager
@@ -287,6 +608,7 @@ impl EventSystem {
 
             // Handle zone changes caused by object movement
             for (player_id, channel, is_entry) in zone_changes {
+                self.emit_zone_change(player_id, object_id, channel, is_entry).await?;
                 if is_entry {
                     debug!("🎮 GORC Object Movement: Sending zone entry message for object {} channel {} to player {}",
                            object_id, channel, player_id);
@@ -297,11 +619,433 @@ impl EventSystem {
                     self.send_zone_exit_message(player_id, object_id, channel).await?;
                 }
             }
+
+            self.emit_virtual_zone_events().await?;
+            self.move_attached_objects(gorc_instances, &[object_id]).await?;
         }
 
         Ok(())
     }
 
+    /// Moves a batch of objects and handles their zone membership changes.
+    ///
+    /// Equivalent to calling [`update_object_position`](Self::update_object_position)
+    /// for each object, but the instance manager takes its locks once for the
+    /// whole batch. Objects attached to the moved objects follow them.
+    ///
+    /// # Arguments
+    ///
+    /// * `updates` - Objects and their new positions
+    ///
+    /// # Returns
+    ///
+    /// The number of objects that were moved
+    pub async fn update_object_positions(&self, updates: &[(GorcObjectId, Vec3)]) -> Result<usize, EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        let moves = gorc_instances.update_positions(updates).await;
+        debug!("🎯 GORC Object Movement: Batch moved {} of {} object(s)", moves.len(), updates.len());
+        self.handle_object_moves(&moves).await?;
+
+        let moved: Vec<GorcObjectId> = moves.iter().map(|object_move| object_move.object_id).collect();
+        self.move_attached_objects(gorc_instances, &moved).await?;
+
+        Ok(moves.len())
+    }
+
+    /// Sends the zone changes of moved objects
+    async fn handle_object_moves(&self, moves: &[ObjectMove]) -> Result<(), EventError> {
+        for object_move in moves {
+            for &(player_id, channel, is_entry) in &object_move.zone_changes {
+                self.emit_zone_change(player_id, object_move.object_id, channel, is_entry).await?;
+                if is_entry {
+                    self.send_zone_entry_message(player_id, object_move.object_id, channel).await?;
+                } else {
+                    self.send_zone_exit_message(player_id, object_move.object_id, channel).await?;
+                }
+            }
+        }
+        self.emit_virtual_zone_events().await?;
+        Ok(())
+    }
+
+    /// Runs a zone virtualization pass and emits its merges and splits.
+    ///
+    /// Each merge is emitted as a `GorcVirtualZoneMergedEvent` on the
+    /// `gorc_virtual_zone_merged` core event and each split as a
+    /// `GorcVirtualZoneSplitEvent` on `gorc_virtual_zone_split`, including
+    /// the member object IDs.
+    ///
+    /// # Returns
+    ///
+    /// The number of merge and split events emitted.
+    pub async fn process_virtualization(&self) -> Result<usize, EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        gorc_instances
+            .process_virtualization()
+            .await
+            .map_err(|e| EventError::HandlerExecution(e.to_string()))?;
+        self.emit_virtual_zone_events().await
+    }
+
+    /// Emits the virtual zone merges and splits the instance manager performed
+    async fn emit_virtual_zone_events(&self) -> Result<usize, EventError> {
+        let Some(gorc_instances) = self.gorc_instances.as_ref() else {
+            return Ok(0);
+        };
+
+        let events = gorc_instances.drain_virtual_zone_events().await;
+        let emitted = events.len();
+        for event in events {
+            let timestamp = crate::utils::current_timestamp();
+            match event {
+                VirtualZoneEvent::Merged { virtual_id, channel, center, radius, object_ids } => {
+                    debug!("🔗 GORC: Virtual zone {} merged {} object(s) on channel {}", virtual_id.0, object_ids.len(), channel);
+                    let event = GorcVirtualZoneMergedEvent { virtual_id, channel, center, radius, object_ids, timestamp };
+                    self.emit_core("gorc_virtual_zone_merged", &event).await?;
+                }
+                VirtualZoneEvent::Split { virtual_id, channel, object_ids } => {
+                    debug!("✂️ GORC: Virtual zone {} split into {} object(s) on channel {}", virtual_id.0, object_ids.len(), channel);
+                    let event = GorcVirtualZoneSplitEvent { virtual_id, channel, object_ids, timestamp };
+                    self.emit_core("gorc_virtual_zone_split", &event).await?;
+                }
+            }
+        }
+        Ok(emitted)
+    }
+
+    /// Moves every object attached below `parents` to its derived position
+    async fn move_attached_objects(&self, gorc_instances: &GorcInstanceManager, parents: &[GorcObjectId]) -> Result<(), EventError> {
+        let attached = gorc_instances.attached_positions(parents).await;
+        if attached.is_empty() {
+            return Ok(());
+        }
+
+        let moves = gorc_instances.update_positions(&attached).await;
+        debug!("🔗 GORC: Moved {} attached object(s) with their parents", moves.len());
+        self.handle_object_moves(&moves).await
+    }
+
+    /// Attaches an object to a parent and replicates the attachment.
+    ///
+    /// The child is moved to the parent's position plus `offset`, handling
+    /// the resulting zone changes, and from then on follows the parent when
+    /// it moves through this event system. A `GorcObjectAttachedEvent` is
+    /// emitted as the `gorc_object_attached` core event and sent to the
+    /// child's subscribers as its `attached` event on its lowest channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `child_id` - Object to attach, e.g. a turret or a player's avatar
+    /// * `parent_id` - Object to attach it to, e.g. a ship or a vehicle
+    /// * `offset` - Child position relative to the parent
+    ///
+    /// # Returns
+    ///
+    /// The previous attachment, or an error if either object is unknown or
+    /// the attachment would create a cycle.
+    pub async fn attach_object(
+        &self,
+        child_id: GorcObjectId,
+        parent_id: GorcObjectId,
+        offset: Vec3,
+    ) -> Result<Option<ObjectAttachment>, EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        let previous = gorc_instances
+            .attach_object(child_id, parent_id, offset)
+            .await
+            .map_err(|e| match e {
+                AttachmentError::UnknownObject(_) => EventError::HandlerNotFound(e.to_string()),
+                _ => EventError::HandlerExecution(e.to_string()),
+            })?;
+
+        // Snap the child (and anything attached to it) onto the parent
+        let mut placed = gorc_instances.attached_positions(&[parent_id]).await;
+        placed.retain(|(object_id, _)| *object_id == child_id);
+        let moves = gorc_instances.update_positions(&placed).await;
+        self.handle_object_moves(&moves).await?;
+        self.move_attached_objects(gorc_instances, &[child_id]).await?;
+
+        info!("🔗 GORC: Attached object {} to {}", child_id, parent_id);
+        let event = GorcObjectAttachedEvent {
+            object_id: child_id,
+            parent_id,
+            offset,
+            timestamp: crate::utils::current_timestamp(),
+        };
+        self.emit_core("gorc_object_attached", &event).await?;
+        self.replicate_attachment_event(gorc_instances, child_id, "attached", &event).await?;
+
+        Ok(previous)
+    }
+
+    /// Detaches an object from its parent and replicates the detachment.
+    ///
+    /// The object stays where it is. A `GorcObjectDetachedEvent` is emitted
+    /// as the `gorc_object_detached` core event and sent to the object's
+    /// subscribers as its `detached` event on its lowest channel.
+    ///
+    /// # Returns
+    ///
+    /// The removed attachment, or `None` if the object wasn't attached.
+    pub async fn detach_object(&self, child_id: GorcObjectId) -> Result<Option<ObjectAttachment>, EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        let Some(attachment) = gorc_instances.detach_object(child_id).await else {
+            return Ok(None);
+        };
+
+        info!("🔗 GORC: Detached object {} from {}", child_id, attachment.parent_id);
+        let event = GorcObjectDetachedEvent {
+            object_id: child_id,
+            parent_id: attachment.parent_id,
+            timestamp: crate::utils::current_timestamp(),
+        };
+        self.emit_core("gorc_object_detached", &event).await?;
+        self.replicate_attachment_event(gorc_instances, child_id, "detached", &event).await?;
+
+        Ok(Some(attachment))
+    }
+
+    /// Sends an attachment change to the object's subscribers on its lowest channel
+    async fn replicate_attachment_event<T>(
+        &self,
+        gorc_instances: &GorcInstanceManager,
+        object_id: GorcObjectId,
+        event_name: &str,
+        event: &T,
+    ) -> Result<(), EventError>
+    where
+        T: Event + serde::Serialize,
+    {
+        if self.client_response_sender.is_none() {
+            return Ok(());
+        }
+
+        let channel = gorc_instances
+            .get_object(object_id)
+            .await
+            .and_then(|instance| instance.object.get_layers().iter().map(|layer| layer.channel).min());
+        match channel {
+            Some(channel) => self.emit_gorc_instance(object_id, channel, event_name, event, crate::events::Dest::Client).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Update an object's position on behalf of a player.
+    ///
+    /// Only the object's authority may push state for it, so the update is
+    /// rejected unless `player_id` currently holds authority.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The player pushing the update
+    /// * `object_id` - The object being moved
+    /// * `new_position` - The object's new position
+    pub async fn update_object_position_as(
+        &self,
+        player_id: PlayerId,
+        object_id: GorcObjectId,
+        new_position: Vec3,
+    ) -> Result<(), EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        if !gorc_instances.has_authority(object_id, player_id).await {
+            warn!("🛑 GORC: Player {} is not the authority for object {}", player_id, object_id);
+            return Err(EventError::HandlerExecution(format!(
+                "Player {} is not the authority for object {}",
+                player_id, object_id
+            )));
+        }
+
+        self.update_object_position(object_id, new_position).await
+    }
+
+    /// Transfers authority over an object and emits an `AuthorityChangedEvent`.
+    ///
+    /// The event is emitted as the `authority_changed` core event, and only
+    /// when the authority actually changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `object_id` - The object whose authority moves
+    /// * `new_authority` - The server or the player taking control
+    ///
+    /// # Returns
+    ///
+    /// The previous authority, or an error if the object is not registered.
+    pub async fn transfer_authority(
+        &self,
+        object_id: GorcObjectId,
+        new_authority: ObjectAuthority,
+    ) -> Result<ObjectAuthority, EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        let previous_authority = gorc_instances
+            .set_object_authority(object_id, new_authority)
+            .await
+            .ok_or_else(|| EventError::HandlerNotFound(format!("Target object {} not found", object_id)))?;
+
+        if previous_authority != new_authority {
+            info!("🔑 GORC: Authority for object {} moved from {:?} to {:?}",
+                  object_id, previous_authority, new_authority);
+        }
+        self.emit_authority_changes().await?;
+
+        Ok(previous_authority)
+    }
+
+    /// Emits an `authority_changed` core event for every authority change the
+    /// instance manager recorded since the last call.
+    ///
+    /// Covers changes made directly on the instance manager, such as
+    /// registering or transferring an owner or a player disconnecting. The
+    /// server calls this every tick.
+    ///
+    /// # Returns
+    ///
+    /// The number of events emitted.
+    pub async fn emit_authority_changes(&self) -> Result<usize, EventError> {
+        let Some(gorc_instances) = self.gorc_instances.as_ref() else {
+            return Ok(0);
+        };
+
+        let changes = gorc_instances.drain_authority_changes().await;
+        let emitted = changes.len();
+        for change in changes {
+            self.emit_core("authority_changed", &change).await?;
+        }
+        Ok(emitted)
+    }
+
+    /// Pins a player's subscription to an object's channel regardless of distance.
+    ///
+    /// A newly subscribed player receives the zone entry message with the
+    /// channel's current state, as if they had walked into the zone. See
+    /// [`GorcInstanceManager::force_subscribe`](crate::gorc::GorcInstanceManager::force_subscribe).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player was newly subscribed, or an error if the object
+    /// or channel doesn't exist.
+    pub async fn force_subscribe(&self, player_id: PlayerId, object_id: GorcObjectId, channel: u8) -> Result<bool, EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        let added = gorc_instances
+            .force_subscribe(player_id, object_id, channel)
+            .await
+            .ok_or_else(|| EventError::HandlerNotFound(format!("Channel {} of object {} not found", channel, object_id)))?;
+
+        if added {
+            self.emit_zone_change(player_id, object_id, channel, true).await?;
+            self.send_zone_entry_message(player_id, object_id, channel).await?;
+        }
+        Ok(added)
+    }
+
+    /// Releases a pinned subscription, sending a zone exit if the player is out of range.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the player was unsubscribed, or an error if the subscription
+    /// wasn't pinned.
+    pub async fn force_unsubscribe(&self, player_id: PlayerId, object_id: GorcObjectId, channel: u8) -> Result<bool, EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        let removed = gorc_instances
+            .force_unsubscribe(player_id, object_id, channel)
+            .await
+            .ok_or_else(|| EventError::HandlerNotFound(format!(
+                "Player {} has no pinned subscription to channel {} of object {}",
+                player_id, channel, object_id
+            )))?;
+
+        if removed {
+            self.emit_zone_change(player_id, object_id, channel, false).await?;
+            self.send_zone_exit_message(player_id, object_id, channel).await?;
+        }
+        Ok(removed)
+    }
+
+    /// Despawn a GORC object, telling every subscribed player it left their zones
+    ///
+    /// A zone exit that can't be delivered is logged and skipped, so the
+    /// object is always removed and the remaining players still hear of it.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the object was registered and has been removed.
+    pub async fn despawn_gorc_object(&self, object_id: GorcObjectId) -> Result<bool, EventError> {
+        let gorc_instances = self.gorc_instances.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("GORC instance manager not available".to_string())
+        })?;
+
+        let Some(instance) = gorc_instances.get_object(object_id).await else {
+            return Ok(false);
+        };
+
+        // Zone exits must go out before unregistering so the object type is still known
+        for (channel, subscribers) in &instance.subscribers {
+            for player_id in subscribers {
+                if let Err(e) = self.emit_zone_change(*player_id, object_id, *channel, false).await {
+                    warn!("⏳ GORC: Failed to emit zone exit of player {} from despawned object {}: {}", player_id, object_id, e);
+                }
+                if self.client_response_sender.is_some() {
+                    if let Err(e) = self.send_zone_exit_message(*player_id, object_id, *channel).await {
+                        warn!("⏳ GORC: Failed to tell player {} that object {} despawned: {}", player_id, object_id, e);
+                    }
+                }
+            }
+        }
+
+        Ok(gorc_instances.unregister_object(object_id).await)
+    }
+
+    /// Despawn every GORC object whose time-to-live has elapsed
+    ///
+    /// An object that fails to despawn is logged and doesn't stop the others.
+    ///
+    /// # Returns
+    ///
+    /// The number of objects despawned.
+    pub async fn despawn_expired_gorc_objects(&self) -> Result<usize, EventError> {
+        let Some(gorc_instances) = self.gorc_instances.as_ref() else {
+            return Ok(0);
+        };
+
+        let mut despawned = 0;
+        for object_id in gorc_instances.expired_objects().await {
+            match self.despawn_gorc_object(object_id).await {
+                Ok(true) => despawned += 1,
+                Ok(false) => {}
+                Err(e) => warn!("⏳ GORC: Failed to despawn expired object {}: {}", object_id, e),
+            }
+        }
+
+        if despawned > 0 {
+            debug!("⏳ GORC: Despawned {} expired object(s)", despawned);
+        }
+        Ok(despawned)
+    }
+
     /// Notify existing players when a new GORC object is created
     pub async fn notify_players_for_new_gorc_object(&self, object_id: GorcObjectId) -> Result<(), EventError> {
         // Get the GORC instances manager
@@ -319,12 +1063,41 @@ impl EventSystem {
         for (player_id, channel) in zone_entries {
             debug!("🎮 GORC New Object: Sending zone entry message for new object {} channel {} to player {}",
                    object_id, channel, player_id);
+            self.emit_zone_change(player_id, object_id, channel, true).await?;
             self.send_zone_entry_message(player_id, object_id, channel).await?;
         }
 
         Ok(())
     }
     
+    /// Emit the `gorc_zone_entered` or `gorc_zone_exited` core event for a zone change
+    async fn emit_zone_change(&self, player_id: PlayerId, object_id: GorcObjectId, channel: u8, entered: bool) -> Result<(), EventError> {
+        let object_type = match self.gorc_instances.as_ref() {
+            Some(gorc_instances) => gorc_instances.get_object_type(object_id).await,
+            None => None,
+        }
+        .unwrap_or_else(|| "Unknown".to_string());
+        let timestamp = crate::utils::current_timestamp();
+
+        if entered {
+            self.emit_core("gorc_zone_entered", &GorcZoneEnteredEvent {
+                player_id,
+                object_id,
+                object_type,
+                channel,
+                timestamp,
+            }).await
+        } else {
+            self.emit_core("gorc_zone_exited", &GorcZoneExitedEvent {
+                player_id,
+                object_id,
+                object_type,
+                channel,
+                timestamp,
+            }).await
+        }
+    }
+
     /// Send zone entry message with current object state for a specific layer to a player
     async fn send_zone_entry_message(&self, player_id: PlayerId, object_id: GorcObjectId, channel: u8) -> Result<(), EventError> {
         // Get the client response sender
@@ -342,27 +1115,40 @@ impl EventSystem {
             EventError::HandlerNotFound(format!("Object instance {} not found", object_id))
         })?;
         
-        // Get current state for this layer
-        if let Some(layer_data) = gorc_instances.get_object_state_for_layer(object_id, channel).await {
+        // Get current state for this layer in the player's wire format,
+        // trimmed to the player's LOD tier when they are far from the object
+        let format = self.wire_format(player_id);
+        let lod_tier = gorc_instances.lod_tier_for_player(object_id, channel, player_id).await;
+        let layer_data = match (format, lod_tier) {
+            (_, Some(tier)) => gorc_instances
+                .get_object_state_for_layer(object_id, channel)
+                .await
+                .and_then(|data| tier.trim_bytes(&data).ok())
+                .map(|data| (PayloadEncoding::Json, data)),
+            (WireFormat::Json, None) => gorc_instances
+                .get_object_state_for_layer(object_id, channel)
+                .await
+                .map(|data| (PayloadEncoding::Json, data)),
+            (WireFormat::Binary, None) => gorc_instances.get_object_state_for_layer_binary(object_id, channel).await,
+        };
+        
+        if let Some((encoding, layer_data)) = layer_data {
             // Create zone entry message with proper format
-            let zone_entry_event = serde_json::json!({
-                "type": "gorc_zone_enter",
-                "object_id": object_id.to_string(),
-                "object_type": instance.type_name,
-                "channel": channel,
-                "player_id": player_id.to_string(),
-                "zone_data": serde_json::from_slice::<serde_json::Value>(&layer_data)
-                    .unwrap_or(serde_json::Value::Null),
-                "timestamp": crate::utils::current_timestamp()
-            });
-            
-            // Serialize and send
-            let data = serde_json::to_vec(&zone_entry_event)
-                .map_err(|e| EventError::Serialization(e))?;
+            let data = wire::encode_zone_enter(
+                format,
+                player_id,
+                object_id,
+                &instance.type_name,
+                channel,
+                (encoding, &layer_data),
+                WireStamp::now(self.server_tick()),
+            )?;
             
+            let size = data.len() as u64;
             if let Err(e) = sender.send_to_client(player_id, data).await {
                 warn!("❌ Failed to send zone entry message to player {}: {}", player_id, e);
             } else {
+                gorc_instances.record_replication(object_id, 1, size).await;
                 info!("🔔 GORC: Player {} entered zone {} of object {} ({})", 
                       player_id, channel, object_id, instance.type_name);
             }
@@ -393,22 +1179,20 @@ impl EventSystem {
         };
         
         // Create zone exit message
-        let zone_exit_event = serde_json::json!({
-            "type": "gorc_zone_exit",
-            "object_id": object_id.to_string(),
-            "object_type": object_type,
-            "channel": channel,
-            "player_id": player_id.to_string(),
-            "timestamp": crate::utils::current_timestamp()
-        });
-        
-        // Serialize and send
-        let data = serde_json::to_vec(&zone_exit_event)
-            .map_err(|e| EventError::Serialization(e))?;
+        let data = wire::encode_zone_exit(
+            self.wire_format(player_id),
+            player_id,
+            object_id,
+            &object_type,
+            channel,
+            WireStamp::now(self.server_tick()),
+        )?;
         
+        let size = data.len() as u64;
         if let Err(e) = sender.send_to_client(player_id, data).await {
             warn!("❌ Failed to send zone exit message to player {}: {}", player_id, e);
         } else {
+            gorc_instances.record_replication(object_id, 1, size).await;
             info!("🚪 GORC: Player {} exited zone {} of object {} ({})", 
                   player_id, channel, object_id, object_type);
         }
@@ -444,6 +1228,7 @@ impl EventSystem {
             
             // Create the event key for client-to-server GORC events
             let event_key = CompactString::new_inline("gorc_client:") + object_type + ":" + &channel.to_string() + ":" + event_name;
+            self.validate_payload(&event_key, event)?;
             
             // Wrap the event with player context for the handler
             let client_event = serde_json::json!({
@@ -515,10 +1300,10 @@ impl EventSystem {
         })?;
 
         // Serialize the event data using our serialization pool
-        let data = self.serialization_pool.serialize_event(event)?;
+        let data = self.serialization_pool.serialize_event(event, EventCodec::Json)?;
         
-        // Convert Arc<Vec<u8>> to Vec<u8> for the broadcast method
-        let broadcast_data = (*data).clone();
+        // The buffer isn't shared yet, so this takes it over without copying
+        let broadcast_data = Vec::from(data);
         
         // Send to all clients via the client response sender
         match sender.broadcast_to_all(broadcast_data).await {
@@ -543,68 +1328,284 @@ impl EventSystem {
     /// Internal emit implementation that handles the actual event dispatch.
     /// Optimized for high throughput (500k messages/sec target).
     /// Now uses lock-free DashMap + serialization pool for maximum performance.
-    async fn emit_event<T>(&self, event_key: &str, event: &T) -> Result<(), EventError>
+    /// Each emission is first admitted through its category's bounded queue,
+    /// which applies the configured [`OverflowPolicy`](super::OverflowPolicy).
+    pub(super) async fn emit_event<T>(&self, event_key: &str, event: &T) -> Result<(), EventError>
     where
         T: Event,
     {
         // Use serialization pool for better performance and shared data
-        let data = self.serialization_pool.serialize_event(event)?;
+        let codec = self.event_codec(event_key);
+        self.emit_encoded(event_key, || self.serialization_pool.serialize_event(event, codec)).await
+    }
+
+    /// Dispatches an event that `encode` serializes once it has been admitted
+    async fn emit_encoded<F>(&self, event_key: &str, encode: F) -> Result<(), EventError>
+    where
+        F: FnOnce() -> Result<Bytes, EventError>,
+    {
+        // Wait for room in the category's queue before doing any work
+        let Some(_admission) = self.admit_emission(event_key).await? else {
+            return Ok(());
+        };
+
+        let data = encode()?;
+        self.latch_emitted(event_key, &data);
         
         // Lock-free read from DashMap - no contention!
-        let event_handlers = self.handlers.get(event_key).map(|entry| entry.value().clone());
+        let route = self.resolve_route(event_key);
+        if route.is_empty() {
+            self.warn_unhandled(event_key).await;
+            return Ok(());
+        }
 
-        if let Some(event_handlers) = event_handlers {
-            // Only log debug info if handlers exist to reduce overhead
-            if event_handlers.len() > 0 {
-                if cfg!(debug_assertions) {
-                    debug!("📤 Emitting {} to {} handlers", event_key, event_handlers.len());
-                }
+        let event_handlers = self.dispatch_list(&route, event_key, &data)?;
+        if cfg!(debug_assertions) {
+            debug!("📤 Emitting {} to {} handlers", event_key, event_handlers.len());
+        }
+        let panics = self.run_handlers(event_key, &event_handlers).await;
+        let retired = self.retire_once_handlers(event_handlers.iter().map(|(handler, _)| handler));
 
-                // Use FuturesUnordered for better memory efficiency and concurrency
-                let mut futures = FuturesUnordered::new();
-                
-                for handler in event_handlers.iter() {
-                    let data_arc = data.clone(); // Clone the Arc, not the data for speed
-                    let handler_name = handler.handler_name();
-                    let handler_clone = handler.clone();
-                    
-                    futures.push(async move {
-                        if let Err(e) = handler_clone.handle(&data_arc).await {
-                            error!("❌ Handler {} failed: {}", handler_name, e);
-                        }
-                    });
+        self.record_emissions(event_key, 1, panics, retired).await;
+        Ok(())
+    }
+
+    /// Emits several events under one key, resolving its handlers only once.
+    ///
+    /// # Returns
+    ///
+    /// The number of events delivered to handlers (0 if there are none).
+    async fn emit_event_batch<T>(&self, event_key: &str, events: &[&T]) -> Result<usize, EventError>
+    where
+        T: Event,
+    {
+        if events.is_empty() {
+            return Ok(0);
+        }
+
+        // The whole batch takes a single slot in the category's queue
+        let Some(_admission) = self.admit_emission(event_key).await? else {
+            return Ok(0);
+        };
+
+        let codec = self.event_codec(event_key);
+        let serialized = events
+            .iter()
+            .map(|event| self.serialization_pool.serialize_event(*event, codec))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(last) = serialized.last() {
+            self.latch_emitted(event_key, last);
+        }
+
+        let route = self.resolve_route(event_key);
+        if route.is_empty() {
+            self.warn_unhandled(event_key).await;
+            return Ok(0);
+        }
+
+        if cfg!(debug_assertions) {
+            debug!("📤 Emitting a batch of {} {} events", serialized.len(), event_key);
+        }
+        let (mut panics, mut retired) = (0, 0);
+        for data in &serialized {
+            let event_handlers = self.dispatch_list(&route, event_key, data)?;
+            panics += self.run_handlers(event_key, &event_handlers).await;
+            retired += self.retire_once_handlers(event_handlers.iter().map(|(handler, _)| handler));
+        }
+
+        self.record_emissions(event_key, serialized.len() as u64, panics, retired).await;
+        Ok(serialized.len())
+    }
+
+    /// Looks up the exact and wildcard handlers for an event key
+    fn resolve_route(&self, event_key: &str) -> ResolvedRoute {
+        let exact = self
+            .handlers
+            .get(event_key)
+            .map(|entry| entry.value().clone())
+            .unwrap_or_default();
+
+        let mut patterns = Vec::new();
+        if !self.pattern_keys.is_empty() {
+            let matching: Vec<CompactString> = self
+                .pattern_keys
+                .iter()
+                .filter(|pattern| matches_pattern(pattern, event_key))
+                .map(|pattern| pattern.clone())
+                .collect();
+            for pattern in matching {
+                if let Some(handlers) = self.handlers.get(&pattern) {
+                    patterns.extend(handlers.iter().cloned());
                 }
+            }
+        }
+
+        ResolvedRoute { exact, patterns }
+    }
 
-                // Execute all handlers concurrently with better memory usage
-                while let Some(_) = futures.next().await {};
+    /// Pairs every handler of a route with the data it is dispatched with.
+    ///
+    /// Pattern handlers receive the event wrapped with the name it was
+    /// emitted under; the wrapper is only built if some pattern matches.
+    /// Exact handlers come before pattern handlers of the same priority.
+    fn dispatch_list(
+        &self,
+        route: &ResolvedRoute,
+        event_key: &str,
+        data: &Bytes,
+    ) -> Result<Vec<HandlerDispatch>, EventError> {
+        let mut event_handlers: Vec<HandlerDispatch> =
+            route.exact.iter().map(|handler| (handler.clone(), data.clone())).collect();
+        if route.patterns.is_empty() {
+            return Ok(event_handlers);
+        }
+
+        let event_name = event_key.split_once(':').map_or(event_key, |(_, name)| name);
+        let wrapped = Bytes::from(PatternMatch::encode(event_name, data)?);
+        event_handlers.extend(route.patterns.iter().map(|handler| (handler.clone(), wrapped.clone())));
+
+        // Exact handlers are already ordered; the stable sort interleaves the
+        // pattern handlers without reordering handlers of equal priority
+        event_handlers.sort_by_key(|(handler, _)| Reverse(self.handler_priority(handler)));
+        Ok(event_handlers)
+    }
+
+    /// Runs handlers one at a time in priority order, reporting panics.
+    ///
+    /// # Returns
+    ///
+    /// The number of handlers that panicked.
+    pub(super) async fn run_handlers(&self, event_key: &str, event_handlers: &[HandlerDispatch]) -> u64 {
+        let mut panics = Vec::new();
+        let mut outcomes = Vec::with_capacity(event_handlers.len());
+
+        // Handlers run one at a time in priority order so that, e.g., an
+        // anti-cheat handler always sees an event before gameplay handlers
+        for (handler, data) in event_handlers.iter() {
+            // Filtered handlers decline events without deserializing them
+            if !handler.accepts(data) {
+                continue;
             }
 
-            // Batch stats updates to reduce lock contention
-            let mut stats = self.stats.write().await;
-            stats.events_emitted += 1;
-            
-            // Update GORC-specific stats with branch prediction optimization
-            if event_key.as_bytes().get(0) == Some(&b'g') && event_key.starts_with("gorc") {
-                stats.gorc_events_emitted += 1;
+            let owner = self.handler_owner(handler);
+            if let (Some(gate), Some(owner)) = (&self.handler_gate, &owner) {
+                if !gate.allow(owner, event_key).await {
+                    debug!("⛔ Skipped {} for {}: its plugin's handler is short-circuited", handler.handler_name(), event_key);
+                    continue;
+                }
             }
-        } else {
-            // Show debugging info for missing handlers (except server_tick spam)
-            if event_key != "core:server_tick" && event_key != "core:raw_client_message" {
-                // Use PathRouter for efficient similarity search instead of expensive linear scan
-                let similar_paths = {
-                    let path_router = self.path_router.read().await;
-                    path_router.find_similar_paths(event_key, 5)
-                };
-                
-                if !similar_paths.is_empty() {
-                    warn!("⚠️ No handlers for event: {} (similar keys available: {:?})", event_key, similar_paths);
-                } else {
-                    warn!("⚠️ No handlers for event: {} (no similar handlers found)", event_key);
+
+            // Emissions made by a plugin's handler are held to the plugin's namespaces
+            let handled = match &owner {
+                Some(owner) => self.attribute_emissions(owner, handler.handle(data)).boxed(),
+                None => handler.handle(data),
+            };
+            let handled = DispatchScope::new(&self.dispatching, handled);
+
+            // Catch panics at the dispatch boundary so one rogue handler
+            // cannot take down the emitter or its sibling handlers
+            let invocation = AssertUnwindSafe(handled).catch_unwind();
+            let span = self.handler_span(event_key, handler);
+            let started = Instant::now();
+            let outcome = match &span {
+                Some(span) => invocation.instrument(span.clone()).await,
+                None => invocation.await,
+            };
+            let elapsed = started.elapsed();
+            if let Some(span) = span {
+                span.record("duration_us", elapsed.as_micros() as u64);
+            }
+
+            let error = match outcome {
+                Ok(Ok(())) => None,
+                Ok(Err(e)) => {
+                    error!("❌ Handler {} failed: {}", handler.handler_name(), e);
+                    Some(e.to_string())
+                }
+                Err(payload) => {
+                    let message = panic_message(payload.as_ref());
+                    panics.push((handler.clone(), message.clone()));
+                    Some(format!("panicked: {}", message))
                 }
+            };
+            if let (Some(gate), Some(owner)) = (&self.handler_gate, &owner) {
+                gate.record(owner, event_key, error.is_none()).await;
             }
+            outcomes.push((elapsed, error));
         }
+        if outcomes.is_empty() {
+            return 0;
+        }
+        self.events_processed.fetch_add(1, Ordering::Relaxed);
+        if outcomes.iter().any(|(_, error)| error.is_some()) {
+            self.events_failed.fetch_add(1, Ordering::Relaxed);
+        }
+        self.record_key_metrics(event_key, outcomes);
 
-        Ok(())
+        for (handler, message) in &panics {
+            let owner = self.handler_owner(handler);
+            error!(
+                "💥 Handler {} panicked while handling {} (owner: {}): {}",
+                handler.handler_name(),
+                event_key,
+                owner.as_deref().unwrap_or("unknown"),
+                message
+            );
+            self.report_handler_panic(HandlerPanicReport {
+                event_key: event_key.to_string(),
+                handler_name: handler.handler_name().to_string(),
+                owner,
+                message: message.clone(),
+                occurred_at: Instant::now(),
+            });
+        }
+
+        panics.len() as u64
+    }
+
+    /// Records the run time and outcome of each handler invocation for an event key
+    fn record_key_metrics(&self, event_key: &str, outcomes: Vec<(std::time::Duration, Option<String>)>) {
+        let mut metrics = match self.key_metrics.get_mut(event_key) {
+            Some(metrics) => metrics,
+            None => self.key_metrics.entry(CompactString::new(event_key)).or_default(),
+        };
+        for (latency, error) in outcomes {
+            metrics.record(latency, error);
+        }
+    }
+
+    /// Records dispatched events in the system statistics
+    async fn record_emissions(&self, event_key: &str, emitted: u64, panics: u64, retired: usize) {
+        // Batch stats updates to reduce lock contention
+        let mut stats = self.stats.write().await;
+        stats.events_emitted += emitted;
+        stats.handler_panics += panics;
+        stats.total_handlers = stats.total_handlers.saturating_sub(retired);
+        
+        // Update GORC-specific stats with branch prediction optimization
+        if event_key.as_bytes().first() == Some(&b'g') && event_key.starts_with("gorc") {
+            stats.gorc_events_emitted += emitted;
+        }
+    }
+
+    /// Logs an event nobody handles, suggesting similar keys
+    async fn warn_unhandled(&self, event_key: &str) {
+        // Show debugging info for missing handlers (except server_tick spam)
+        if event_key == "core:server_tick" || event_key == "core:raw_client_message" {
+            return;
+        }
+
+        // Use PathRouter for efficient similarity search instead of expensive linear scan
+        let similar_paths = {
+            let path_router = self.path_router.read().await;
+            path_router.find_similar_paths(event_key, 5)
+        };
+        
+        if !similar_paths.is_empty() {
+            warn!("⚠️ No handlers for event: {} (similar keys available: {:?})", event_key, similar_paths);
+        } else {
+            warn!("⚠️ No handlers for event: {} (no similar handlers found)", event_key);
+        }
     }
 
     /// Gets detailed statistics including GORC instance information
@@ -622,9 +1623,26 @@ impl EventSystem {
             base: base_stats,
             handler_count_by_category,
             gorc_instance_stats,
+            event_keys: self.get_event_key_stats(),
         }
     }
 
+    /// Gets handler invocation statistics of every emitted event key, ordered by key
+    pub fn get_event_key_stats(&self) -> Vec<EventKeyStats> {
+        let mut event_keys: Vec<EventKeyStats> = self
+            .key_metrics
+            .iter()
+            .map(|entry| entry.value().snapshot(entry.key()))
+            .collect();
+        event_keys.sort_by(|a, b| a.event_key.cmp(&b.event_key));
+        event_keys
+    }
+
+    /// Gets handler invocation statistics of one event key, if it was ever dispatched
+    pub fn get_stats_for_key(&self, event_key: &str) -> Option<EventKeyStats> {
+        self.key_metrics.get(event_key).map(|metrics| metrics.snapshot(event_key))
+    }
+
     /// Gets handler count breakdown by event category using lock-free DashMap
     async fn get_handler_count_by_category(&self) -> HandlerCategoryStats {
         let mut core_handlers = 0;
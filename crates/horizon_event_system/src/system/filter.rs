@@ -0,0 +1,275 @@
+/// Predicate filters that let handlers skip events before deserializing them
+use crate::events::{Event, EventError, EventHandler};
+use crate::types::PlayerId;
+use super::client::ClientConnectionRef;
+use super::core::EventSystem;
+use super::handlers::HandlerPriority;
+use async_trait::async_trait;
+use compact_str::CompactString;
+use serde::de::{DeserializeOwned, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserializer, Serialize};
+use std::any::TypeId;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Serialized event as seen by an [`EventFilter`].
+///
+/// Only the fields a filter asks for are deserialized, so rejecting an event
+/// costs far less than decoding it into the handler's event type.
+#[derive(Debug, Clone, Copy)]
+pub struct EventView<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> EventView<'a> {
+    /// Creates a view of serialized event data
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Gets the raw serialized event
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Reads one top-level field of the event.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the event isn't an object, lacks the field, or the field
+    /// doesn't deserialize as `V`.
+    pub fn field<V: DeserializeOwned>(&self, name: &str) -> Option<V> {
+        let mut deserializer = serde_json::Deserializer::from_slice(self.data);
+        deserializer
+            .deserialize_map(FieldSeek { name, _value: PhantomData })
+            .ok()
+            .flatten()
+    }
+}
+
+/// Walks an event object, deserializing only the named field
+struct FieldSeek<'n, V> {
+    name: &'n str,
+    _value: PhantomData<V>,
+}
+
+impl<'de, V: DeserializeOwned> Visitor<'de> for FieldSeek<'_, V> {
+    type Value = Option<V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an event object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut found = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if found.is_none() && key == self.name {
+                found = Some(map.next_value::<V>()?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(found)
+    }
+}
+
+/// Predicate deciding which events a handler is invoked for.
+///
+/// The predicate runs on the serialized event before the handler's event
+/// type is deserialized, so plugins interested in a small subset of traffic
+/// don't pay for decoding the rest.
+///
+/// # Examples
+///
+/// ```rust
+/// use horizon_event_system::{EventFilter, PlayerId};
+///
+/// let player = PlayerId::new();
+/// let mine = EventFilter::player(player);
+/// let large = EventFilter::new(|event| event.field::<u32>("amount").is_some_and(|amount| amount > 100));
+/// # let _ = (mine, large);
+/// ```
+#[derive(Clone)]
+pub struct EventFilter {
+    predicate: Arc<dyn Fn(&EventView<'_>) -> bool + Send + Sync>,
+}
+
+impl fmt::Debug for EventFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventFilter").finish_non_exhaustive()
+    }
+}
+
+impl EventFilter {
+    /// Creates a filter from a predicate over the serialized event
+    pub fn new<F>(predicate: F) -> Self
+    where
+        F: Fn(&EventView<'_>) -> bool + Send + Sync + 'static,
+    {
+        Self { predicate: Arc::new(predicate) }
+    }
+
+    /// Accepts events whose top-level `field` equals `value`
+    pub fn field_eq<V>(field: &str, value: V) -> Self
+    where
+        V: DeserializeOwned + PartialEq + Send + Sync + 'static,
+    {
+        let field = field.to_string();
+        Self::new(move |event| event.field::<V>(&field).is_some_and(|found| found == value))
+    }
+
+    /// Accepts events about one player, read from their `player_id` field.
+    ///
+    /// Client events emitted with context carry the sender's `player_id`, so
+    /// this also selects the client events sent by that player.
+    pub fn player(player_id: PlayerId) -> Self {
+        Self::field_eq("player_id", player_id)
+    }
+
+    /// Accepts events about one kind of object, read from their `object_type` field
+    pub fn object_type(object_type: &str) -> Self {
+        Self::field_eq("object_type", object_type.to_string())
+    }
+
+    /// Checks whether a serialized event passes the filter
+    pub fn matches(&self, data: &[u8]) -> bool {
+        (self.predicate)(&EventView::new(data))
+    }
+}
+
+/// Handler that is only invoked for events its filter accepts
+#[derive(Debug)]
+struct FilteredHandler {
+    inner: Arc<dyn EventHandler>,
+    filter: EventFilter,
+}
+
+#[async_trait]
+impl EventHandler for FilteredHandler {
+    async fn handle(&self, data: &[u8]) -> Result<(), EventError> {
+        self.inner.handle(data).await
+    }
+
+    fn expected_type_id(&self) -> TypeId {
+        self.inner.expected_type_id()
+    }
+
+    fn handler_name(&self) -> &str {
+        self.inner.handler_name()
+    }
+
+    fn accepts(&self, data: &[u8]) -> bool {
+        self.filter.matches(data)
+    }
+}
+
+impl EventSystem {
+    /// Registers a core event handler that is only invoked for events the filter accepts.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_name` - Name of the core event
+    /// * `filter` - Predicate evaluated before the event is deserialized
+    /// * `handler` - Function called for accepted events
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use horizon_event_system::{EventFilter, EventSystem, PlayerId};
+    ///
+    /// async fn example(events: &EventSystem, player: PlayerId) -> Result<(), Box<dyn std::error::Error>> {
+    ///     events.on_core_filtered("player_disconnected", EventFilter::player(player), |event: serde_json::Value| {
+    ///         println!("Our player left: {}", event);
+    ///         Ok(())
+    ///     }).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn on_core_filtered<T, F>(&self, event_name: &str, filter: EventFilter, handler: F) -> Result<(), EventError>
+    where
+        T: Event + 'static,
+        F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        let event_key = CompactString::new_inline("core:") + event_name;
+        self.register_filtered_handler(event_key, filter, handler).await
+    }
+
+    /// Registers a connection-aware client event handler that is only invoked for events the filter accepts.
+    ///
+    /// The filter sees the event as emitted, including the `player_id`
+    /// context added by [`emit_client_with_context`](Self::emit_client_with_context).
+    ///
+    /// See [`on_core_filtered`](Self::on_core_filtered).
+    pub async fn on_client_filtered<T, F>(
+        &self,
+        namespace: &str,
+        event_name: &str,
+        filter: EventFilter,
+        handler: F,
+    ) -> Result<(), EventError>
+    where
+        T: Event + Serialize + 'static,
+        F: Fn(T, PlayerId, ClientConnectionRef) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        let event_key = CompactString::new_inline("client:") + namespace + ":" + event_name;
+        let inner = self.connection_aware_handler(&event_key, handler);
+        let handler_arc: Arc<dyn EventHandler> = Arc::new(FilteredHandler { inner, filter });
+        self.install_handler(&event_key, handler_arc, HandlerPriority::NORMAL).await?;
+        Ok(())
+    }
+
+    /// Registers a plugin event handler that is only invoked for events the filter accepts.
+    ///
+    /// See [`on_core_filtered`](Self::on_core_filtered).
+    pub async fn on_plugin_filtered<T, F>(
+        &self,
+        plugin_name: &str,
+        event_name: &str,
+        filter: EventFilter,
+        handler: F,
+    ) -> Result<(), EventError>
+    where
+        T: Event + 'static,
+        F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        self.check_plugin_namespace(plugin_name)?;
+        let event_key = CompactString::new_inline("plugin:") + plugin_name + ":" + event_name;
+        self.register_filtered_handler(event_key, filter, handler).await
+    }
+
+    async fn register_filtered_handler<T, F>(&self, event_key: CompactString, filter: EventFilter, handler: F) -> Result<(), EventError>
+    where
+        T: Event + 'static,
+        F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        let handler_name = format!("{}::{}", event_key, T::type_name());
+        let inner: Arc<dyn EventHandler> = Arc::new(crate::events::TypedEventHandler::new(handler_name, handler));
+        let handler_arc: Arc<dyn EventHandler> = Arc::new(FilteredHandler { inner, filter });
+        self.install_handler(&event_key, handler_arc, HandlerPriority::NORMAL).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_filters() {
+        let player = PlayerId::new();
+        let event = serde_json::to_vec(&serde_json::json!({
+            "data": { "player_id": PlayerId::new() },
+            "player_id": player,
+            "object_type": "Asteroid",
+        }))
+        .unwrap();
+
+        assert!(EventFilter::player(player).matches(&event));
+        assert!(!EventFilter::player(PlayerId::new()).matches(&event));
+        assert!(EventFilter::object_type("Asteroid").matches(&event));
+        assert!(!EventFilter::field_eq("object_type", 7).matches(&event));
+        assert!(!EventFilter::object_type("Asteroid").matches(b"[1, 2]"));
+        assert_eq!(EventView::new(&event).field::<String>("missing"), None);
+    }
+}
@@ -2,10 +2,124 @@
 use crate::events::{Event, EventHandler, TypedEventHandler, EventError, GorcEvent};
 use crate::gorc::instance::{GorcObjectId, ObjectInstance};
 use super::core::EventSystem;
-use super::client::ClientConnectionRef;
+use super::client::{ClientConnectionRef, ClientResponseSender};
+use super::filter::EventView;
+use async_trait::async_trait;
+use std::any::TypeId;
+use std::marker::PhantomData;
 use std::sync::Arc;
 use tracing::{error, info};
 use compact_str::CompactString;
+use serde::{de::DeserializeOwned, Serialize};
+use super::path_router::PatternMatch;
+use super::supervision::handler_identity;
+
+/// Takes a one-shot handler out of its slot; only the first caller gets it.
+fn take_once<F>(slot: &std::sync::Mutex<Option<F>>) -> Option<F> {
+    slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).take()
+}
+
+/// Execution priority of an event handler.
+///
+/// Handlers for the same event key run one at a time, highest priority
+/// first; handlers with equal priority run in registration order. Use a
+/// high priority for handlers that must observe an event before gameplay
+/// logic does, such as anti-cheat validation of movement requests.
+/// Each emission runs the handlers registered when it was dispatched, so
+/// handlers added or removed concurrently never reorder one in flight.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandlerPriority(pub i32);
+
+impl HandlerPriority {
+    /// Runs before every other built-in priority
+    pub const HIGHEST: Self = Self(200);
+    /// Runs before normal handlers
+    pub const HIGH: Self = Self(100);
+    /// Priority of handlers registered without one
+    pub const NORMAL: Self = Self(0);
+    /// Runs after normal handlers
+    pub const LOW: Self = Self(-100);
+    /// Runs after every other built-in priority
+    pub const LOWEST: Self = Self(-200);
+}
+
+/// Client event handler that is also given the sending player and their connection.
+///
+/// The player ID is read straight from the serialized event's `player_id`
+/// field, so the event is deserialized only once, into the handler's type.
+struct ConnectionAwareHandler<T, F> {
+    name: String,
+    handler: F,
+    client_response_sender: Option<Arc<dyn ClientResponseSender + Send + Sync>>,
+    _event: PhantomData<fn() -> T>,
+}
+
+impl<T, F> std::fmt::Debug for ConnectionAwareHandler<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionAwareHandler")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl<T, F> EventHandler for ConnectionAwareHandler<T, F>
+where
+    T: Event + 'static,
+    F: Fn(T, crate::types::PlayerId, ClientConnectionRef) -> Result<(), EventError> + Send + Sync + 'static,
+{
+    async fn handle(&self, data: &[u8]) -> Result<(), EventError> {
+        let event = match T::deserialize(data) {
+            Ok(event) => event,
+            Err(e) => {
+                tracing::warn!(
+                    "🟡 EventHandler '{}' (expects type '{}'): Deserialization failed - {}. The handler will be skipped.",
+                    self.name,
+                    std::any::type_name::<T>(),
+                    e
+                );
+                return Ok(());
+            }
+        };
+        let sender = self.client_response_sender.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("Client response sender not configured".to_string())
+        })?;
+
+        // Events wrapped by emit_client_with_context carry the sender's player ID
+        let player_id = match EventView::new(data).field::<crate::types::PlayerId>("player_id") {
+            Some(player_id) => {
+                tracing::debug!("🔧 ConnectionAwareHandler: Extracted player ID: {}", player_id);
+                player_id
+            }
+            None => {
+                tracing::warn!("🔧 ConnectionAwareHandler: No valid player_id field found, using new ID");
+                crate::types::PlayerId::new()
+            }
+        };
+
+        // Create client connection ref with extracted player ID
+        // For now, use default values for other fields - these could be made async in the future
+        let default_addr = std::net::SocketAddr::from(([0, 0, 0, 0], 0));
+        let client_ref = ClientConnectionRef::new(
+            player_id,
+            default_addr, // Default unknown address
+            format!("conn_{}", player_id.0),    // Connection ID based on player ID
+            crate::utils::current_timestamp(),
+            crate::types::AuthenticationStatus::default(),
+            sender.clone(),
+        );
+
+        (self.handler)(event, player_id, client_ref)
+    }
+
+    fn expected_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn handler_name(&self) -> &str {
+        &self.name
+    }
+}
 
 impl EventSystem {
     /// Registers a handler for core server events.
@@ -15,8 +129,9 @@ impl EventSystem {
         F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
     {
         let event_key = CompactString::new_inline("core:") + event_name;
-        self.register_typed_handler(event_key, event_name, handler)
+        self.register_typed_handler(event_key, event_name, handler, HandlerPriority::NORMAL)
             .await
+            .map(|_| ())
     }
 
     /// Registers a handler for client events with namespace.
@@ -74,8 +189,9 @@ impl EventSystem {
         F: Fn(T, crate::types::PlayerId, ClientConnectionRef) -> Result<(), EventError> + Send + Sync + Clone + 'static,
     {
         let event_key = CompactString::new_inline("client:") + namespace + ":" + event_name;
-        self.register_connection_aware_handler(event_key, event_name, handler)
+        self.register_connection_aware_handler(event_key, event_name, handler, HandlerPriority::NORMAL)
             .await
+            .map(|_| ())
     }
 
 
@@ -129,7 +245,7 @@ impl EventSystem {
         F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
     {
         let event_key = CompactString::new_inline("client:") + namespace + ":" + event_name;
-        self.register_async_handler(event_key, event_name, handler)
+        self.register_async_handler(event_key, event_name, handler, HandlerPriority::NORMAL)
             .await
     }
 
@@ -144,9 +260,277 @@ impl EventSystem {
         T: Event + 'static,
         F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
     {
+        self.check_plugin_namespace(plugin_name)?;
         let event_key = CompactString::new_inline("plugin:") + plugin_name + ":" + event_name;
-        self.register_typed_handler(event_key, event_name, handler)
+        self.register_typed_handler(event_key, event_name, handler, HandlerPriority::NORMAL)
             .await
+            .map(|_| ())
+    }
+
+    /// Registers a handler for core server events with an execution priority.
+    ///
+    /// # Execution order
+    ///
+    /// Handlers registered for the same event run sequentially, never
+    /// concurrently, in descending priority. Handlers with equal priority run
+    /// in the order they were registered, and wildcard handlers run after
+    /// exact handlers of the same priority. A failing or panicking handler
+    /// does not stop the handlers after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_name` - Core event to handle
+    /// * `priority` - Where the handler runs relative to other handlers of the event
+    /// * `handler` - Function that receives the event
+    pub async fn on_core_with_priority<T, F>(
+        &self,
+        event_name: &str,
+        priority: HandlerPriority,
+        handler: F,
+    ) -> Result<(), EventError>
+    where
+        T: Event + 'static,
+        F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        let event_key = CompactString::new_inline("core:") + event_name;
+        self.register_typed_handler(event_key, event_name, handler, priority)
+            .await
+            .map(|_| ())
+    }
+
+    /// Registers a connection-aware client event handler with an execution priority.
+    ///
+    /// Ordering follows [`on_core_with_priority`](Self::on_core_with_priority).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use horizon_event_system::{EventSystem, HandlerPriority, PlayerId, ClientConnectionRef};
+    ///
+    /// async fn example(events: &EventSystem) -> Result<(), Box<dyn std::error::Error>> {
+    ///     // Inspect movement before the player plugin applies it
+    ///     events.on_client_with_priority("movement", "move", HandlerPriority::HIGH,
+    ///         |event: serde_json::Value, player_id: PlayerId, _connection: ClientConnectionRef| {
+    ///             println!("Validating move from {}: {}", player_id, event);
+    ///             Ok(())
+    ///         }
+    ///     ).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn on_client_with_priority<T, F>(
+        &self,
+        namespace: &str,
+        event_name: &str,
+        priority: HandlerPriority,
+        handler: F,
+    ) -> Result<(), EventError>
+    where
+        T: Event + serde::Serialize + 'static,
+        F: Fn(T, crate::types::PlayerId, ClientConnectionRef) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        let event_key = CompactString::new_inline("client:") + namespace + ":" + event_name;
+        self.register_connection_aware_handler(event_key, event_name, handler, priority)
+            .await
+            .map(|_| ())
+    }
+
+    /// Registers a handler for raw binary client messages on a channel.
+    ///
+    /// Clients send these as binary frames starting with a `0x00` marker and
+    /// the channel as a big-endian `u16`; the rest of the frame is handed to
+    /// the handler untouched, without any JSON parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use horizon_event_system::{EventSystem, ClientBinaryMessage};
+    ///
+    /// async fn example(events: &EventSystem) -> Result<(), Box<dyn std::error::Error>> {
+    ///     // Voice frames arrive on channel 7
+    ///     events.on_client_binary(7, |message: ClientBinaryMessage| {
+    ///         println!("{} bytes of voice from {}", message.data.len(), message.player_id);
+    ///         Ok(())
+    ///     }).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn on_client_binary<F>(&self, channel: u16, handler: F) -> Result<(), EventError>
+    where
+        F: Fn(crate::events::ClientBinaryMessage) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        let event_key = CompactString::new_inline("client:") + super::BINARY_NAMESPACE + ":" + &channel.to_string();
+        self.register_typed_handler(event_key, "binary", handler, HandlerPriority::NORMAL)
+            .await
+            .map(|_| ())
+    }
+
+    /// Registers a handler for plugin-to-plugin events with an execution priority.
+    ///
+    /// Ordering follows [`on_core_with_priority`](Self::on_core_with_priority).
+    pub async fn on_plugin_with_priority<T, F>(
+        &self,
+        plugin_name: &str,
+        event_name: &str,
+        priority: HandlerPriority,
+        handler: F,
+    ) -> Result<(), EventError>
+    where
+        T: Event + 'static,
+        F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        self.check_plugin_namespace(plugin_name)?;
+        let event_key = CompactString::new_inline("plugin:") + plugin_name + ":" + event_name;
+        self.register_typed_handler(event_key, event_name, handler, priority)
+            .await
+            .map(|_| ())
+    }
+
+    /// Registers a core event handler that runs for the first matching event only.
+    ///
+    /// The handler is unregistered as soon as that event has been
+    /// dispatched, which suits request/response flows such as waiting for a
+    /// single confirmation. Because it runs at most once, the handler may
+    /// consume captured state, e.g. a `tokio::sync::oneshot::Sender`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use horizon_event_system::EventSystem;
+    ///
+    /// async fn example(events: &EventSystem) -> Result<(), Box<dyn std::error::Error>> {
+    ///     let (ready_tx, ready_rx) = tokio::sync::oneshot::channel();
+    ///     events.on_core_once("inventory_ready", move |event: serde_json::Value| {
+    ///         let _ = ready_tx.send(event);
+    ///         Ok(())
+    ///     }).await?;
+    ///     let _confirmation = ready_rx.await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn on_core_once<T, F>(&self, event_name: &str, handler: F) -> Result<(), EventError>
+    where
+        T: Event + 'static,
+        F: FnOnce(T) -> Result<(), EventError> + Send + 'static,
+    {
+        let event_key = CompactString::new_inline("core:") + event_name;
+        let slot = Arc::new(std::sync::Mutex::new(Some(handler)));
+        let pending = slot.clone();
+        let handler = move |event: T| take_once(&slot).map_or(Ok(()), |handler| handler(event));
+        let handler_arc = self
+            .register_typed_handler(event_key.clone(), event_name, handler, HandlerPriority::NORMAL)
+            .await?;
+        self.track_once_handler(event_key, &handler_arc, &pending).await;
+        Ok(())
+    }
+
+    /// Registers a connection-aware client event handler that runs for the first matching event only.
+    ///
+    /// See [`on_core_once`](Self::on_core_once).
+    pub async fn on_client_once<T, F>(&self, namespace: &str, event_name: &str, handler: F) -> Result<(), EventError>
+    where
+        T: Event + serde::Serialize + 'static,
+        F: FnOnce(T, crate::types::PlayerId, ClientConnectionRef) -> Result<(), EventError> + Send + 'static,
+    {
+        let event_key = CompactString::new_inline("client:") + namespace + ":" + event_name;
+        let slot = Arc::new(std::sync::Mutex::new(Some(handler)));
+        let handler = move |event: T, player_id, connection| {
+            take_once(&slot).map_or(Ok(()), |handler| handler(event, player_id, connection))
+        };
+        let handler_arc = self
+            .register_connection_aware_handler(event_key.clone(), event_name, handler, HandlerPriority::NORMAL)
+            .await?;
+        self.once_handlers.insert(handler_identity(&handler_arc), event_key);
+        Ok(())
+    }
+
+    /// Registers a plugin event handler that runs for the first matching event only.
+    ///
+    /// See [`on_core_once`](Self::on_core_once).
+    pub async fn on_plugin_once<T, F>(&self, plugin_name: &str, event_name: &str, handler: F) -> Result<(), EventError>
+    where
+        T: Event + 'static,
+        F: FnOnce(T) -> Result<(), EventError> + Send + 'static,
+    {
+        self.check_plugin_namespace(plugin_name)?;
+        let event_key = CompactString::new_inline("plugin:") + plugin_name + ":" + event_name;
+        let slot = Arc::new(std::sync::Mutex::new(Some(handler)));
+        let pending = slot.clone();
+        let handler = move |event: T| take_once(&slot).map_or(Ok(()), |handler| handler(event));
+        let handler_arc = self
+            .register_typed_handler(event_key.clone(), event_name, handler, HandlerPriority::NORMAL)
+            .await?;
+        self.track_once_handler(event_key, &handler_arc, &pending).await;
+        Ok(())
+    }
+
+    /// Starts tracking a one-shot handler so its first event retires it.
+    ///
+    /// A latched event may already have been replayed to the handler while it
+    /// was registered; it is retired right away in that case.
+    async fn track_once_handler<F>(
+        &self,
+        event_key: CompactString,
+        handler: &Arc<dyn EventHandler>,
+        pending: &std::sync::Mutex<Option<F>>,
+    ) {
+        self.once_handlers.insert(handler_identity(handler), event_key);
+        let fired = pending.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).is_none();
+        if fired {
+            let retired = self.retire_once_handlers([handler]);
+            let mut stats = self.stats.write().await;
+            stats.total_handlers = stats.total_handlers.saturating_sub(retired);
+        }
+    }
+
+    /// Unregisters the one-shot handlers among those just dispatched.
+    ///
+    /// # Returns
+    ///
+    /// The number of handlers that were removed.
+    pub(super) fn retire_once_handlers<'a>(&self, dispatched: impl IntoIterator<Item = &'a Arc<dyn EventHandler>>) -> usize {
+        if self.once_handlers.is_empty() {
+            return 0;
+        }
+
+        let mut retired = 0;
+        for handler in dispatched {
+            let id = handler_identity(handler);
+            let Some((_, event_key)) = self.once_handlers.remove(&id) else {
+                continue;
+            };
+            if let Some(mut handlers) = self.handlers.get_mut(&event_key) {
+                handlers.retain(|registered| handler_identity(registered) != id);
+            }
+            self.handlers.remove_if(&event_key, |_, handlers| handlers.is_empty());
+            self.handler_owners.remove(&id);
+            self.handler_priorities.remove(&id);
+            retired += 1;
+        }
+        retired
+    }
+
+    /// Gets the execution priority of a registered handler.
+    pub fn handler_priority(&self, handler: &Arc<dyn EventHandler>) -> HandlerPriority {
+        self.handler_priorities
+            .get(&handler_identity(handler))
+            .map(|priority| *priority.value())
+            .unwrap_or_default()
+    }
+
+    /// Inserts a handler behind every handler of equal or higher priority for the key.
+    fn insert_handler(&self, event_key: &CompactString, handler: Arc<dyn EventHandler>, priority: HandlerPriority) {
+        if priority != HandlerPriority::NORMAL {
+            self.handler_priorities.insert(handler_identity(&handler), priority);
+        }
+
+        // Lock-free insertion using DashMap; the list stays sorted by descending priority
+        let mut handlers = self.handlers.entry(event_key.clone()).or_default();
+        let position = handlers
+            .iter()
+            .position(|existing| self.handler_priority(existing) < priority)
+            .unwrap_or(handlers.len());
+        handlers.insert(position, handler);
     }
 
 
@@ -165,10 +549,82 @@ impl EventSystem {
         F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
     {
         let event_key = CompactString::new_inline("core:") + event_name;
-        self.register_async_handler(event_key, event_name, handler)
+        self.register_async_handler(event_key, event_name, handler, HandlerPriority::NORMAL)
             .await
     }
 
+    /// Registers a handler for every core event whose name matches a wildcard pattern.
+    ///
+    /// `*` matches any run of characters, so `"player_*"` receives
+    /// `player_connected`, `player_disconnected` and any `player_` event
+    /// added later. Events matching several patterns reach each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Event name pattern, e.g. `"player_*"` or `"*"`
+    /// * `handler` - Function that receives the matched event name and the event
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use horizon_event_system::EventSystem;
+    ///
+    /// async fn example(events: &EventSystem) -> Result<(), Box<dyn std::error::Error>> {
+    ///     events.on_core_pattern("player_*", |event_name: String, event: serde_json::Value| {
+    ///         println!("{}: {}", event_name, event);
+    ///         Ok(())
+    ///     }).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn on_core_pattern<T, F>(&self, pattern: &str, handler: F) -> Result<(), EventError>
+    where
+        T: Event + Serialize + DeserializeOwned + 'static,
+        F: Fn(String, T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        let pattern_key = CompactString::new_inline("core:") + pattern;
+        self.register_pattern_handler(pattern_key, handler).await
+    }
+
+    /// Registers a handler for every client event matching namespace and event name patterns.
+    ///
+    /// The handler receives the matched `namespace:event_name` and the event
+    /// as emitted, including the player context added by
+    /// [`emit_client_with_context`](Self::emit_client_with_context).
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - Namespace pattern, e.g. `"chat"` or `"*"`
+    /// * `event_name` - Event name pattern within the namespace
+    /// * `handler` - Function that receives the matched event name and the event
+    pub async fn on_client_pattern<T, F>(&self, namespace: &str, event_name: &str, handler: F) -> Result<(), EventError>
+    where
+        T: Event + Serialize + DeserializeOwned + 'static,
+        F: Fn(String, T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        let pattern_key = CompactString::new_inline("client:") + namespace + ":" + event_name;
+        self.register_pattern_handler(pattern_key, handler).await
+    }
+
+    /// Registers a handler for every plugin event matching plugin and event name patterns.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin_name` - Plugin name pattern, e.g. `"greeter"` or `"*"`
+    /// * `event_name` - Event name pattern within the plugin
+    /// * `handler` - Function that receives the matched `plugin:event_name` and the event
+    pub async fn on_plugin_pattern<T, F>(&self, plugin_name: &str, event_name: &str, handler: F) -> Result<(), EventError>
+    where
+        T: Event + Serialize + DeserializeOwned + 'static,
+        F: Fn(String, T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        if !plugin_name.contains('*') {
+            self.check_plugin_namespace(plugin_name)?;
+        }
+        let pattern_key = CompactString::new_inline("plugin:") + plugin_name + ":" + event_name;
+        self.register_pattern_handler(pattern_key, handler).await
+    }
+
     /// Registers a handler for client-initiated GORC events targeting server objects.
     /// 
     /// **NEW UNIFIED API**: All GORC client handlers now receive connection context by default.
@@ -293,37 +749,80 @@ impl EventSystem {
     }
 
     /// Internal helper for registering typed handlers.
-    async fn register_typed_handler<T, F>(
+    ///
+    /// Returns the registered handler so callers can track it.
+    pub(super) async fn register_typed_handler<T, F>(
         &self,
         event_key: CompactString,
         _event_name: &str,
         handler: F,
-    ) -> Result<(), EventError>
+        priority: HandlerPriority,
+    ) -> Result<Arc<dyn EventHandler>, EventError>
     where
         T: Event + 'static,
         F: Fn(T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
     {
         let handler_name = format!("{}::{}", event_key, T::type_name());
-        let typed_handler = TypedEventHandler::new(handler_name, handler);
-        let handler_arc: Arc<dyn EventHandler> = Arc::new(typed_handler);
+        let handler_arc: Arc<dyn EventHandler> = Arc::new(TypedEventHandler::new(handler_name, handler));
+        self.install_handler(&event_key, handler_arc, priority).await
+    }
+
+    /// Adds a built handler to the routing tables and delivers the latched values it missed.
+    ///
+    /// Returns the registered handler so callers can track it.
+    pub(super) async fn install_handler(
+        &self,
+        event_key: &CompactString,
+        handler_arc: Arc<dyn EventHandler>,
+        priority: HandlerPriority,
+    ) -> Result<Arc<dyn EventHandler>, EventError> {
+        self.tag_handler_owner(&handler_arc);
 
-        // Lock-free insertion using DashMap with SmallVec optimization
-        self.handlers
-            .entry(event_key.clone())
-            .or_insert_with(Vec::new)
-            .push(handler_arc.clone());
+        self.insert_handler(event_key, handler_arc.clone(), priority);
 
         // Also register with path router for efficient similarity searches
         {
             let mut path_router = self.path_router.write().await;
-            path_router.register_handler(&event_key, handler_arc);
+            path_router.register_handler(event_key, handler_arc.clone());
         }
 
         // Update stats atomically
-        let mut stats = self.stats.write().await;
-        stats.total_handlers += 1;
+        self.stats.write().await.total_handlers += 1;
 
         info!("📝 Registered handler for {}", event_key);
+        self.replay_latched(event_key, &handler_arc).await?;
+        Ok(handler_arc)
+    }
+
+    /// Internal helper for registering wildcard handlers.
+    ///
+    /// Pattern handlers live in the regular handler map under their pattern
+    /// key, so ownership tracking and removal treat them like any other
+    /// handler; the key is also recorded so emits can find it. Patterns must
+    /// contain a `*`; use the exact registration methods otherwise.
+    async fn register_pattern_handler<T, F>(&self, pattern_key: CompactString, handler: F) -> Result<(), EventError>
+    where
+        T: Event + Serialize + DeserializeOwned + 'static,
+        F: Fn(String, T) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        // Without a wildcard the key would collide with the exact handlers for that event
+        if !pattern_key.contains('*') {
+            return Err(EventError::InvalidPattern(format!("{} contains no '*' wildcard", pattern_key)));
+        }
+
+        let handler_name = format!("{}::{}", pattern_key, T::type_name());
+        let unwrap_match = move |matched: PatternMatch<T>| handler(matched.event_name, matched.data);
+        let typed_handler = TypedEventHandler::new(handler_name, unwrap_match);
+        let handler_arc: Arc<dyn EventHandler> = Arc::new(typed_handler);
+        self.tag_handler_owner(&handler_arc);
+
+        self.insert_handler(&pattern_key, handler_arc.clone(), HandlerPriority::NORMAL);
+        self.pattern_keys.insert(pattern_key.clone());
+
+        self.stats.write().await.total_handlers += 1;
+
+        info!("📝 Registered pattern handler for {}", pattern_key);
+        self.replay_latched(&pattern_key, &handler_arc).await?;
         Ok(())
     }
 
@@ -336,6 +835,7 @@ impl EventSystem {
         event_key: CompactString,
         _event_name: &str,
         handler: F,
+        priority: HandlerPriority,
     ) -> Result<(), EventError>
     where
         T: Event + 'static,
@@ -358,114 +858,55 @@ impl EventSystem {
         
         let typed_handler = TypedEventHandler::new(handler_name, async_wrapper);
         let handler_arc: Arc<dyn EventHandler> = Arc::new(typed_handler);
+        self.tag_handler_owner(&handler_arc);
 
-        // Lock-free insertion using DashMap with SmallVec optimization
-        self.handlers
-            .entry(event_key.clone())
-            .or_insert_with(Vec::new)
-            .push(handler_arc.clone());
+        self.insert_handler(&event_key, handler_arc.clone(), priority);
 
         // Also register with path router for efficient similarity searches
         {
             let mut path_router = self.path_router.write().await;
-            path_router.register_handler(&event_key, handler_arc);
+            path_router.register_handler(&event_key, handler_arc.clone());
         }
 
         // Update stats atomically
-        let mut stats = self.stats.write().await;
-        stats.total_handlers += 1;
+        self.stats.write().await.total_handlers += 1;
 
         info!("📝 Registered async handler for {}", event_key);
+        self.replay_latched(&event_key, &handler_arc).await?;
         Ok(())
     }
 
     /// Internal helper for registering connection-aware handlers.
     /// **UPDATED**: Now supports the unified API signature with player_id parameter.
+    ///
+    /// Returns the registered handler so callers can track it.
     async fn register_connection_aware_handler<T, F>(
         &self,
         event_key: CompactString,
         _event_name: &str,
         handler: F,
-    ) -> Result<(), EventError>
+        priority: HandlerPriority,
+    ) -> Result<Arc<dyn EventHandler>, EventError>
     where
         T: Event + serde::Serialize + 'static,
         F: Fn(T, crate::types::PlayerId, ClientConnectionRef) -> Result<(), EventError> + Send + Sync + Clone + 'static,
     {
-        let handler_name = format!("{}::{}", event_key, T::type_name());
-        let client_response_sender = self.client_response_sender.clone();
-        
-        // Create a wrapper that extracts connection info and calls the connection-aware handler
-        let conn_aware_wrapper = move |event: T| -> Result<(), EventError> {
-            let sender = client_response_sender.as_ref().ok_or_else(|| {
-                EventError::HandlerExecution("Client response sender not configured".to_string())
-            })?;
-            
-            // Extract player ID from the event data by attempting to serialize/deserialize
-            // This works for events that have a player_id field (wrapped by emit_client_with_context)
-            let player_id = match serde_json::to_value(&event) {
-                Ok(json_value) => {
-                    if let Some(player_id_value) = json_value.get("player_id") {
-                        if let Ok(player_id) = serde_json::from_value::<crate::types::PlayerId>(player_id_value.clone()) {
-                            tracing::debug!("🔧 ConnectionAwareHandler: Extracted player ID: {}", player_id);
-                            player_id
-                        } else {
-                            tracing::warn!("🔧 ConnectionAwareHandler: Failed to deserialize player_id, using new ID");
-                            // Fallback to new ID if deserialization fails
-                            crate::types::PlayerId::new()
-                        }
-                    } else {
-                        tracing::warn!("🔧 ConnectionAwareHandler: No player_id field found, using new ID");
-                        // Event doesn't have player_id field, use new ID
-                        crate::types::PlayerId::new()
-                    }
-                }
-                Err(_) => {
-                    tracing::warn!("🔧 ConnectionAwareHandler: Event is not serializable, using new ID");
-                    // Event is not serializable, use new ID
-                    crate::types::PlayerId::new()
-                }
-            };
-            
-            // Create client connection ref with extracted player ID
-            // For now, use default values for other fields - these could be made async in the future
-            const UNSPECIFIED_ADDR: &str = "0.0.0.0:0"; // Placeholder for unspecified address
-            let default_addr = UNSPECIFIED_ADDR.parse()
-                .unwrap_or_else(|_| std::net::SocketAddr::from(([0, 0, 0, 0], 0)));
-            
-            let client_ref = ClientConnectionRef::new(
-                player_id,
-                default_addr, // Default unknown address
-                format!("conn_{}", player_id.0),    // Connection ID based on player ID
-                crate::utils::current_timestamp(),
-                crate::types::AuthenticationStatus::default(),
-                sender.clone(),
-            );
-            
-            // Call the sync handler directly with both player_id and connection - no async spawning needed
-            handler(event, player_id, client_ref)
-        };
-        
-        let typed_handler = TypedEventHandler::new(handler_name, conn_aware_wrapper);
-        let handler_arc: Arc<dyn EventHandler> = Arc::new(typed_handler);
-
-        // Lock-free insertion using DashMap with SmallVec optimization
-        self.handlers
-            .entry(event_key.clone())
-            .or_insert_with(Vec::new)
-            .push(handler_arc.clone());
-
-        // Also register with path router for efficient similarity searches
-        {
-            let mut path_router = self.path_router.write().await;
-            path_router.register_handler(&event_key, handler_arc);
-        }
-
-        // Update stats atomically
-        let mut stats = self.stats.write().await;
-        stats.total_handlers += 1;
+        let handler_arc = self.connection_aware_handler(&event_key, handler);
+        self.install_handler(&event_key, handler_arc, priority).await
+    }
 
-        info!("📝 Registered connection-aware handler for {}", event_key);
-        Ok(())
+    /// Wraps a handler so it is called with the sending player and their connection.
+    pub(super) fn connection_aware_handler<T, F>(&self, event_key: &str, handler: F) -> Arc<dyn EventHandler>
+    where
+        T: Event + serde::Serialize + 'static,
+        F: Fn(T, crate::types::PlayerId, ClientConnectionRef) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        Arc::new(ConnectionAwareHandler {
+            name: format!("{}::{}", event_key, T::type_name()),
+            handler,
+            client_response_sender: self.client_response_sender.clone(),
+            _event: PhantomData,
+        })
     }
 
     /// Internal helper for registering GORC instance handlers.
@@ -520,12 +961,9 @@ impl EventSystem {
         });
 
         let handler_arc: Arc<dyn EventHandler> = Arc::new(gorc_handler);
+        self.tag_handler_owner(&handler_arc);
 
-        // Lock-free insertion using DashMap with SmallVec optimization
-        self.handlers
-            .entry(event_key.clone())
-            .or_insert_with(Vec::new)
-            .push(handler_arc.clone());
+        self.insert_handler(&event_key, handler_arc.clone(), HandlerPriority::NORMAL);
 
         // Also register with path router for efficient similarity searches
         {
@@ -669,12 +1107,9 @@ impl EventSystem {
         });
 
         let handler_arc: Arc<dyn EventHandler> = Arc::new(gorc_client_handler);
+        self.tag_handler_owner(&handler_arc);
 
-        // Lock-free insertion using DashMap with SmallVec optimization
-        self.handlers
-            .entry(event_key.clone())
-            .or_insert_with(Vec::new)
-            .push(handler_arc.clone());
+        self.insert_handler(&event_key, handler_arc.clone(), HandlerPriority::NORMAL);
 
         // Also register with path router for efficient similarity searches
         {
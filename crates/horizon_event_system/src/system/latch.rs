@@ -0,0 +1,116 @@
+/// Latched events whose last value is replayed to handlers registered later
+use crate::events::{EventError, EventHandler};
+use super::codec::EventCodec;
+use super::core::EventSystem;
+use super::path_router::{matches_pattern, PatternMatch};
+use bytes::Bytes;
+use compact_str::CompactString;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+impl EventSystem {
+    /// Latches a core event so late subscribers still receive it.
+    ///
+    /// The system keeps the last value emitted under a latched key, and every
+    /// handler registered for the key afterwards is called with that value
+    /// right away, before it sees any new emission. Wildcard handlers whose
+    /// pattern matches the key are replayed the value as well. Latch the key
+    /// before it is first emitted; earlier emissions are not remembered.
+    ///
+    /// Use it for state-like events such as `region_started`, so plugins that
+    /// load after the region came up still learn its bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_name` - Name of the core event to latch
+    pub fn latch_core(&self, event_name: &str) {
+        self.latch(CompactString::new_inline("core:") + event_name);
+    }
+
+    /// Latches a plugin event so late subscribers still receive it.
+    ///
+    /// See [`latch_core`](Self::latch_core).
+    pub fn latch_plugin(&self, plugin_name: &str, event_name: &str) {
+        self.latch(CompactString::new_inline("plugin:") + plugin_name + ":" + event_name);
+    }
+
+    /// Stops latching an event key, e.g. `core:region_started`, and forgets its value
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key was latched.
+    pub fn unlatch(&self, event_key: &str) -> bool {
+        self.latched.remove(event_key).is_some()
+    }
+
+    /// Checks whether an event key is latched
+    pub fn is_latched(&self, event_key: &str) -> bool {
+        self.latched.contains_key(event_key)
+    }
+
+    /// Gets the last value emitted under a latched event key.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the key isn't latched or hasn't been emitted since it was.
+    pub fn latched_value<T: DeserializeOwned>(&self, event_key: &str) -> Result<Option<T>, EventError> {
+        let Some(data) = self.latched.get(event_key).and_then(|latched| latched.clone()) else {
+            return Ok(None);
+        };
+        EventCodec::decode(&data).map(Some)
+    }
+
+    fn latch(&self, event_key: CompactString) {
+        info!("📌 Latching {}", event_key);
+        self.latched.entry(event_key).or_default();
+    }
+
+    /// Remembers an emitted value if its key is latched
+    pub(super) fn latch_emitted(&self, event_key: &str, data: &Bytes) {
+        if self.latched.is_empty() {
+            return;
+        }
+        if let Some(mut latched) = self.latched.get_mut(event_key) {
+            *latched = Some(data.clone());
+        }
+    }
+
+    /// Delivers the latched values a newly registered handler would have missed.
+    ///
+    /// # Returns
+    ///
+    /// The number of values delivered.
+    pub(super) async fn replay_latched(&self, event_key: &str, handler: &Arc<dyn EventHandler>) -> Result<usize, EventError> {
+        if self.latched.is_empty() {
+            return Ok(0);
+        }
+
+        let mut replays = Vec::new();
+        if self.pattern_keys.contains(event_key) {
+            // Wildcard handlers receive every latched value their pattern matches
+            let matching: Vec<(CompactString, Bytes)> = self
+                .latched
+                .iter()
+                .filter(|latched| matches_pattern(event_key, latched.key()))
+                .filter_map(|latched| latched.value().clone().map(|data| (latched.key().clone(), data)))
+                .collect();
+            for (latched_key, data) in matching {
+                let event_name = latched_key.split_once(':').map_or(latched_key.as_str(), |(_, name)| name);
+                replays.push((latched_key.clone(), Bytes::from(PatternMatch::encode(event_name, &data)?)));
+            }
+        } else if let Some(data) = self.latched.get(event_key).and_then(|latched| latched.clone()) {
+            replays.push((CompactString::new(event_key), data));
+        }
+
+        let mut panics = 0;
+        for (latched_key, data) in &replays {
+            debug!("📌 Replaying latched {} to {}", latched_key, handler.handler_name());
+            panics += self.run_handlers(latched_key, &[(handler.clone(), data.clone())]).await;
+        }
+        if panics > 0 {
+            self.stats.write().await.handler_panics += panics;
+        }
+        Ok(replays.len())
+    }
+}
@@ -80,11 +80,17 @@ impl EventSystem {
                         
                         if let Ok(message_bytes) = serde_json::to_vec(&gorc_message) {
                             // Send to each subscriber individually
+                            let mut delivered = 0;
                             for subscriber_id in &subscribers {
                                 if let Err(e) = client_sender.send_to_client(*subscriber_id, message_bytes.clone()).await {
                                     warn!("Failed to send GORC event to subscriber {}: {}", subscriber_id, e);
+                                } else {
+                                    delivered += 1;
                                 }
                             }
+                            gorc_instances
+                                .record_replication(object_id, delivered, delivered * message_bytes.len() as u64)
+                                .await;
                             
                             debug!(
                                 "📡 Sent serialized GORC event {} to {} subscribers for object {}",
@@ -126,9 +132,16 @@ impl EventSystem {
             }
         }
 
-        // Remove the matching keys
+        // Remove the matching keys along with any recorded ownership
         for key in keys_to_remove {
-            self.handlers.remove(&key);
+            if let Some((_, handlers)) = self.handlers.remove(&key) {
+                for handler in &handlers {
+                    let id = super::supervision::handler_identity(handler);
+                    self.handler_owners.remove(&id);
+                    self.handler_priorities.remove(&id);
+                    self.once_handlers.remove(&id);
+                }
+            }
         }
 
         if removed_count > 0 {
@@ -1,21 +1,44 @@
 /// Event system module - broken down into manageable components
 mod client;
+mod codec;
 mod core;
+mod concurrency;
 mod emitters;
+mod filter;
 mod handlers;
+mod latch;
 mod management;
+mod multicast;
+mod namespaces;
 mod stats;
 mod cache;
 mod tests;
 mod path_router;
+mod queue;
+mod rpc;
+mod s2s;
+mod schema;
+mod spans;
+mod supervision;
+mod timers;
 
 // Re-export all public items from submodules
-pub use client::{ClientConnectionRef, ClientResponseSender, ClientConnectionInfo};
+pub use client::{ClientConnectionRef, ClientResponseSender, ClientConnectionInfo, ClientVersion, ConnectionStats};
 pub use core::EventSystem;
 pub use emitters::*;
 pub use handlers::*;
-pub use stats::{EventSystemStats, DetailedEventSystemStats, HandlerCategoryStats};
+pub use stats::{EventSystemStats, DetailedEventSystemStats, EventKeyStats, HandlerCategoryStats};
 pub use path_router::PathRouter;
+pub use supervision::{HandlerGate, HandlerPanicReport};
+pub use rpc::DEFAULT_REQUEST_TIMEOUT;
+pub use schema::{EventSchema, SchemaValidationError, SchemaViolation};
+pub use spans::HandlerSpanConfig;
+pub use filter::{EventFilter, EventView};
+pub use timers::TimerHandle;
+pub use codec::{EventCodec, BINARY_PAYLOAD_MAGIC};
+pub use namespaces::{OwnerScope, BINARY_NAMESPACE, PLUGIN_CORE_EVENTS, RESERVED_NAMESPACES};
+pub use s2s::{S2sEvent, ServerLink, S2S_NAMESPACE};
+pub use queue::{EmissionQueueConfig, EmissionQueueStats, EventCategory, OverflowPolicy};
 
 // Re-export utility functions
 use crate::gorc::instance::GorcInstanceManager;
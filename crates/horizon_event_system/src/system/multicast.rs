@@ -0,0 +1,120 @@
+/// Multicast group APIs for plugins
+///
+/// Plugins create named groups (raid channels, arena instances), manage
+/// their membership and emit events to every member in one call. The event
+/// is serialized once and fanned out through the client response sender.
+use crate::events::{Event, EventError};
+use crate::gorc::channels::ReplicationPriority;
+use crate::gorc::multicast::{MulticastError, MulticastGroupId};
+use crate::types::PlayerId;
+use super::core::EventSystem;
+use std::collections::HashSet;
+use tracing::{debug, warn};
+
+impl From<MulticastError> for EventError {
+    fn from(error: MulticastError) -> Self {
+        match error {
+            MulticastError::GroupNotFound { .. } => EventError::HandlerNotFound(error.to_string()),
+            other => EventError::HandlerExecution(other.to_string()),
+        }
+    }
+}
+
+impl EventSystem {
+    /// Creates a multicast group that plugins can add players to.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Human-readable group name, e.g. `"raid:molten_core"`
+    ///
+    /// # Returns
+    ///
+    /// The ID used to manage and emit to the group.
+    pub async fn create_group(&self, name: impl Into<String>) -> MulticastGroupId {
+        let group_id = self
+            .multicast
+            .create_group(name.into(), HashSet::new(), ReplicationPriority::Normal)
+            .await;
+        debug!("📢 Created multicast group {:?}", group_id);
+        group_id
+    }
+
+    /// Adds a player to a group.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(true)` if the player joined, `Ok(false)` if they were already a
+    /// member, or an error if the group doesn't exist or is full.
+    pub async fn add_group_member(&self, group_id: MulticastGroupId, player_id: PlayerId) -> Result<bool, EventError> {
+        Ok(self.multicast.add_player_to_group(player_id, group_id).await?)
+    }
+
+    /// Removes a player from a group.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(true)` if the player was a member, or an error if the group doesn't exist.
+    pub async fn remove_group_member(&self, group_id: MulticastGroupId, player_id: PlayerId) -> Result<bool, EventError> {
+        Ok(self.multicast.remove_player_from_group(player_id, group_id).await?)
+    }
+
+    /// Gets the members of a group, or `None` if the group doesn't exist.
+    pub async fn get_group_members(&self, group_id: MulticastGroupId) -> Option<Vec<PlayerId>> {
+        self.multicast.get_group_members(group_id).await
+    }
+
+    /// Destroys a group and drops all of its memberships.
+    pub async fn destroy_group(&self, group_id: MulticastGroupId) -> Result<(), EventError> {
+        Ok(self.multicast.destroy_group(group_id).await?)
+    }
+
+    /// Emits an event to every member of a group.
+    ///
+    /// Members receive a JSON message of type `group_event` carrying the
+    /// group ID, event name and event data.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - Group to emit to
+    /// * `event_name` - Name clients use to dispatch the event
+    /// * `event` - Event data
+    ///
+    /// # Returns
+    ///
+    /// The number of members the event was delivered to.
+    pub async fn emit_to_group<T>(&self, group_id: MulticastGroupId, event_name: &str, event: &T) -> Result<usize, EventError>
+    where
+        T: Event + serde::Serialize,
+    {
+        let sender = self.client_response_sender.as_ref().ok_or_else(|| {
+            EventError::HandlerExecution("Client response sender not configured for group emission".to_string())
+        })?;
+
+        let members = self
+            .multicast
+            .get_group_members(group_id)
+            .await
+            .ok_or(MulticastError::GroupNotFound { id: group_id })?;
+
+        let data = serde_json::to_vec(&serde_json::json!({
+            "type": "group_event",
+            "group_id": group_id.0,
+            "event_type": event_name,
+            "data": event,
+            "timestamp": crate::utils::current_timestamp()
+        }))?;
+
+        let mut delivered = 0;
+        for player_id in members {
+            if let Err(e) = sender.send_to_client(player_id, data.clone()).await {
+                warn!("📢 Failed to send group event {} to player {}: {}", event_name, player_id, e);
+            } else {
+                delivered += 1;
+            }
+        }
+
+        self.multicast.broadcast_to_group(group_id, &data).await?;
+        debug!("📢 Sent group event {} to {} members of group {:?}", event_name, delivered, group_id);
+        Ok(delivered)
+    }
+}
@@ -0,0 +1,162 @@
+/// Namespace rules that keep plugins from spoofing core and each other's events
+use crate::events::EventError;
+use super::core::EventSystem;
+use compact_str::CompactString;
+use std::future::Future;
+use dashmap::DashMap;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::thread::ThreadId;
+use tracing::{info, warn};
+
+/// Namespaces owned by the server that plugins can't use as their own
+pub const RESERVED_NAMESPACES: &[&str] = &["core", "gorc", "s2s"];
+
+/// Core events plugins may emit despite `core` being reserved.
+///
+/// These are requests the server carries out on a plugin's behalf, such as
+/// moderation plugins asking for a player to be kicked or banned, or game
+/// plugins broadcasting an announcement to every player.
+pub const PLUGIN_CORE_EVENTS: &[&str] = &["kick_player", "ban_player", "gorc_broadcast"];
+
+/// Client namespace raw binary messages are emitted under, as `client:binary:<channel>`
+pub const BINARY_NAMESPACE: &str = "binary";
+
+/// Future that attributes what it does while it is polled to a plugin.
+///
+/// The owner is recorded per thread for the duration of each poll, which
+/// works across plugin library boundaries where task-locals don't, and keeps
+/// plugins polled at the same time on different threads apart.
+pub struct OwnerScope<'a, F> {
+    owners: &'a DashMap<ThreadId, CompactString>,
+    owner: CompactString,
+    inner: Pin<Box<F>>,
+}
+
+impl<'a, F> OwnerScope<'a, F> {
+    pub(super) fn new(owners: &'a DashMap<ThreadId, CompactString>, owner: &str, future: F) -> Self {
+        Self {
+            owners,
+            owner: CompactString::new(owner),
+            inner: Box::pin(future),
+        }
+    }
+}
+
+impl<F: Future> Future for OwnerScope<'_, F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let thread = std::thread::current().id();
+        let previous = self.owners.insert(thread, self.owner.clone());
+        let poll = self.inner.as_mut().poll(cx);
+        match previous {
+            Some(previous) => {
+                self.owners.insert(thread, previous);
+            }
+            None => {
+                self.owners.remove(&thread);
+            }
+        }
+        poll
+    }
+}
+
+impl EventSystem {
+    /// Records the namespaces a plugin declared in its manifest.
+    ///
+    /// Emissions attributed to the plugin may then only use its own plugin
+    /// namespace and the declared ones, for both `plugin:` and `client:`
+    /// events. Plugins without declared namespaces are only kept out of the
+    /// [reserved namespaces](RESERVED_NAMESPACES).
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin_name` - Name of the plugin
+    /// * `namespaces` - Event namespaces from the plugin's manifest
+    pub fn set_plugin_namespaces(&self, plugin_name: &str, namespaces: &[String]) {
+        info!("🏷️ Plugin {} may emit under namespaces {:?}", plugin_name, namespaces);
+        self.namespace_grants.insert(
+            CompactString::new(plugin_name),
+            namespaces.iter().map(CompactString::new).collect(),
+        );
+    }
+
+    /// Forgets the namespaces declared by a plugin
+    ///
+    /// # Returns
+    ///
+    /// `true` if the plugin had declared namespaces.
+    pub fn clear_plugin_namespaces(&self, plugin_name: &str) -> bool {
+        self.namespace_grants.remove(plugin_name).is_some()
+    }
+
+    /// Gets the namespaces a plugin declared, if it declared any
+    pub fn plugin_namespaces(&self, plugin_name: &str) -> Option<Vec<String>> {
+        self.namespace_grants
+            .get(plugin_name)
+            .map(|namespaces| namespaces.iter().map(|namespace| namespace.to_string()).collect())
+    }
+
+    /// Attributes the emissions a future makes to a plugin.
+    ///
+    /// The plugin manager wraps plugin lifecycle calls in this, and handlers
+    /// owned by a plugin run inside it, so namespace rules apply to what the
+    /// plugin emits. Tasks the plugin spawns are not attributed.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin_name` - Plugin the emissions are attributed to
+    /// * `future` - Plugin code to run
+    pub fn attribute_emissions<F: Future>(&self, plugin_name: &str, future: F) -> OwnerScope<'_, F> {
+        OwnerScope::new(&self.emitters, plugin_name, future)
+    }
+
+    /// Rejects plugin namespaces that are reserved or malformed
+    pub(super) fn check_plugin_namespace(&self, namespace: &str) -> Result<(), EventError> {
+        if RESERVED_NAMESPACES.contains(&namespace) {
+            return Err(EventError::NamespaceViolation(format!(
+                "'{}' is reserved and can't be used as a plugin namespace",
+                namespace
+            )));
+        }
+        if namespace.is_empty() || namespace.contains(':') {
+            return Err(EventError::NamespaceViolation(format!("Invalid plugin namespace '{}'", namespace)));
+        }
+        Ok(())
+    }
+
+    /// Checks that the plugin an emission is attributed to may emit under its key
+    pub(super) fn check_emitter(&self, event_key: &str) -> Result<(), EventError> {
+        if self.emitters.is_empty() {
+            return Ok(());
+        }
+        let Some(emitter) = self.emitters.get(&std::thread::current().id()).map(|owner| owner.clone()) else {
+            return Ok(());
+        };
+
+        let (category, rest) = event_key.split_once(':').unwrap_or((event_key, ""));
+        let namespace = rest.split(':').next().unwrap_or_default();
+        let allowed = match category {
+            "core" => PLUGIN_CORE_EVENTS.contains(&rest),
+            _ if RESERVED_NAMESPACES.contains(&category) => false,
+            "plugin" | "client" => match self.namespace_grants.get(emitter.as_str()) {
+                Some(grants) => {
+                    (category == "plugin" && namespace == emitter)
+                        || grants.iter().any(|granted| *granted == namespace)
+                }
+                None => true,
+            },
+            _ => true,
+        };
+        if allowed {
+            return Ok(());
+        }
+
+        warn!("🚫 Plugin {} tried to emit {} outside its namespaces", emitter, event_key);
+        Err(EventError::NamespaceViolation(format!(
+            "Plugin {} may not emit {}",
+            emitter, event_key
+        )))
+    }
+}
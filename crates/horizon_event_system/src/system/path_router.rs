@@ -1,9 +1,71 @@
 /// Path-based event routing system for efficient hierarchical event lookups
-use crate::events::EventHandler;
+use crate::events::{EventError, EventHandler};
+use super::codec::EventCodec;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use compact_str::CompactString;
 
+/// Checks whether an event key matches a wildcard pattern.
+///
+/// `*` matches any run of characters, including `:` separators, so
+/// `core:player_*` matches `core:player_connected` and `client:*:*` matches
+/// every client event. All other characters must match exactly.
+pub fn matches_pattern(pattern: &str, key: &str) -> bool {
+    let (pattern, key) = (pattern.as_bytes(), key.as_bytes());
+    let (mut p, mut k) = (0, 0);
+    // Position after the last `*` and the key position it is currently matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while k < key.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            p += 1;
+            backtrack = Some((p, k));
+        } else if p < pattern.len() && pattern[p] == key[k] {
+            p += 1;
+            k += 1;
+        } else if let Some((star_p, star_k)) = backtrack {
+            // Let the last `*` swallow one more character
+            p = star_p;
+            k = star_k + 1;
+            backtrack = Some((star_p, star_k + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+/// Event delivered to wildcard handlers, tagged with the name it was emitted under
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct PatternMatch<T> {
+    /// Emitted event key without its category prefix
+    pub event_name: String,
+    /// The event itself
+    pub data: T,
+}
+
+impl PatternMatch<()> {
+    /// Wraps already serialized event data for delivery to wildcard handlers.
+    ///
+    /// Binary payloads are wrapped in their own codec.
+    pub(super) fn encode(event_name: &str, data: &[u8]) -> Result<Vec<u8>, EventError> {
+        let codec = EventCodec::of(data);
+        if codec != EventCodec::Json {
+            return codec.wrap_named(event_name, data);
+        }
+        let event_name = serde_json::to_vec(event_name)?;
+        let mut encoded = Vec::with_capacity(data.len() + event_name.len() + 24);
+        encoded.extend_from_slice(b"{\"event_name\":");
+        encoded.extend_from_slice(&event_name);
+        encoded.extend_from_slice(b",\"data\":");
+        encoded.extend_from_slice(data);
+        encoded.push(b'}');
+        Ok(encoded)
+    }
+}
+
 /// A node in the event path tree
 #[derive(Debug, Default)]
 pub struct PathNode {
@@ -21,7 +83,7 @@ pub struct PathNode {
 /// This enables:
 /// 1. Faster lookups by following the path tree
 /// 2. Efficient similarity searches for debugging
-/// 3. Wildcard/pattern matching (future feature)
+/// 3. Wildcard/pattern matching (see [`matches_pattern`])
 /// 4. Better namespace organization
 #[derive(Debug, Default)]
 pub struct PathRouter {
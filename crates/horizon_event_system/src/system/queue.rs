@@ -0,0 +1,358 @@
+/// Bounded per-category emission queues that apply backpressure to `emit_*`
+use crate::events::EventError;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{oneshot, Notify};
+
+/// What happens to an emission when its category's queue is full
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Wait until the queue has room, slowing the emitter down.
+    ///
+    /// Emissions made by handlers while they run never wait; they dispatch
+    /// on the slot of the emission running the handler.
+    #[default]
+    Block,
+    /// Discard the longest-waiting emission to make room
+    DropOldest,
+    /// Fail the new emission with [`EventError::QueueFull`]
+    Reject,
+}
+
+/// Limits for the emission queue of one event category
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmissionQueueConfig {
+    /// Emissions dispatched to handlers at the same time
+    pub max_in_flight: usize,
+    /// Emissions allowed to wait for a dispatch slot
+    pub capacity: usize,
+    /// Behaviour once `capacity` emissions are waiting
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for EmissionQueueConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 256,
+            capacity: 4096,
+            overflow: OverflowPolicy::Block,
+        }
+    }
+}
+
+impl EmissionQueueConfig {
+    /// Creates a queue configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `max_in_flight` - Emissions dispatched at the same time (at least 1)
+    /// * `capacity` - Emissions allowed to wait for a dispatch slot
+    /// * `overflow` - Behaviour once `capacity` emissions are waiting
+    pub fn new(max_in_flight: usize, capacity: usize, overflow: OverflowPolicy) -> Self {
+        Self {
+            max_in_flight: max_in_flight.max(1),
+            capacity,
+            overflow,
+        }
+    }
+}
+
+/// Event categories that each get their own emission queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventCategory {
+    /// Core server events
+    Core,
+    /// Client events
+    Client,
+    /// Plugin-to-plugin and server-to-server events
+    Plugin,
+    /// GORC and GORC instance events
+    Gorc,
+}
+
+impl EventCategory {
+    /// Every category, in queue order
+    pub const ALL: [EventCategory; 4] = [Self::Core, Self::Client, Self::Plugin, Self::Gorc];
+
+    /// Gets the category an event key belongs to.
+    ///
+    /// Requests (`rpc:` keys) share the queue of the event they target.
+    pub fn from_key(event_key: &str) -> Self {
+        let event_key = event_key.strip_prefix("rpc:").unwrap_or(event_key);
+        match event_key.split(':').next() {
+            Some("core") => Self::Core,
+            Some("client") => Self::Client,
+            Some("plugin" | "s2s") => Self::Plugin,
+            _ => Self::Gorc,
+        }
+    }
+
+    /// Position of the category's queue
+    pub(super) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Point-in-time state of one category's emission queue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmissionQueueStats {
+    /// Category the queue belongs to
+    pub category: EventCategory,
+    /// Emissions currently dispatching or waiting
+    pub depth: usize,
+    /// Emissions currently dispatching
+    pub in_flight: usize,
+    /// Deepest the queue has been
+    pub peak_depth: usize,
+    /// Emissions discarded by the drop-oldest policy
+    pub dropped: u64,
+    /// Emissions refused by the reject policy
+    pub rejected: u64,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    in_flight: usize,
+    waiting: VecDeque<oneshot::Sender<EmissionPermit>>,
+}
+
+#[derive(Debug)]
+struct QueueInner {
+    category: EventCategory,
+    config: EmissionQueueConfig,
+    state: Mutex<QueueState>,
+    /// Signalled whenever a dispatch slot or waiting slot frees up
+    room: Notify,
+    peak_depth: AtomicUsize,
+    dropped: AtomicU64,
+    rejected: AtomicU64,
+}
+
+/// Admission control for the emissions of one event category.
+///
+/// Up to `max_in_flight` emissions dispatch at once; later ones wait in FIFO
+/// order and take over the slot of the emission that finishes first.
+#[derive(Debug)]
+pub(super) struct EmissionQueue {
+    inner: Arc<QueueInner>,
+}
+
+/// Dispatch slot held for the duration of an emission.
+///
+/// Dropping the permit passes the slot to the oldest waiting emission, even
+/// if the permit was never received because that emission was cancelled.
+#[derive(Debug)]
+pub(super) struct EmissionPermit {
+    queue: Option<Arc<QueueInner>>,
+}
+
+impl EmissionQueue {
+    pub(super) fn new(category: EventCategory, config: EmissionQueueConfig) -> Self {
+        Self {
+            inner: Arc::new(QueueInner {
+                category,
+                config,
+                state: Mutex::new(QueueState::default()),
+                room: Notify::new(),
+                peak_depth: AtomicUsize::new(0),
+                dropped: AtomicU64::new(0),
+                rejected: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Waits for a dispatch slot.
+    ///
+    /// # Returns
+    ///
+    /// A permit to dispatch, `None` if the emission was discarded by the
+    /// drop-oldest policy, or [`EventError::QueueFull`] if it was rejected.
+    pub(super) async fn admit(&self) -> Result<Option<EmissionPermit>, EventError> {
+        let queue = &self.inner;
+        loop {
+            let room = queue.room.notified();
+            tokio::pin!(room);
+
+            let waiter = {
+                let mut state = queue.lock();
+                if state.waiting.is_empty() && state.in_flight < queue.config.max_in_flight.max(1) {
+                    state.in_flight += 1;
+                    queue.record_depth(&state);
+                    return Ok(Some(EmissionPermit { queue: Some(queue.clone()) }));
+                }
+
+                if state.waiting.len() >= queue.config.capacity {
+                    match queue.config.overflow {
+                        OverflowPolicy::Block => {
+                            // Register for the wakeup before releasing the lock so a
+                            // slot freed in between isn't missed
+                            room.as_mut().enable();
+                            None
+                        }
+                        OverflowPolicy::Reject => {
+                            queue.rejected.fetch_add(1, Ordering::Relaxed);
+                            return Err(EventError::QueueFull(format!("{:?} emission queue", queue.category)));
+                        }
+                        OverflowPolicy::DropOldest => {
+                            queue.dropped.fetch_add(1, Ordering::Relaxed);
+                            // Dropping the sender wakes the oldest emission empty-handed
+                            if state.waiting.pop_front().is_none() {
+                                // Nothing may wait at all, so the new emission is the oldest
+                                return Ok(None);
+                            }
+                            Some(queue.enqueue(&mut state))
+                        }
+                    }
+                } else {
+                    Some(queue.enqueue(&mut state))
+                }
+            };
+
+            match waiter {
+                Some(waiter) => return Ok(waiter.await.ok()),
+                None => room.await,
+            }
+        }
+    }
+
+    /// Gets the current state of the queue
+    pub(super) fn stats(&self) -> EmissionQueueStats {
+        let queue = &self.inner;
+        let state = queue.lock();
+        EmissionQueueStats {
+            category: queue.category,
+            depth: state.in_flight + state.waiting.len(),
+            in_flight: state.in_flight,
+            peak_depth: queue.peak_depth.load(Ordering::Relaxed),
+            dropped: queue.dropped.load(Ordering::Relaxed),
+            rejected: queue.rejected.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl QueueInner {
+    fn enqueue(&self, state: &mut QueueState) -> oneshot::Receiver<EmissionPermit> {
+        let (sender, receiver) = oneshot::channel();
+        state.waiting.push_back(sender);
+        self.record_depth(state);
+        receiver
+    }
+
+    fn record_depth(&self, state: &QueueState) {
+        self.peak_depth.fetch_max(state.in_flight + state.waiting.len(), Ordering::Relaxed);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for EmissionPermit {
+    fn drop(&mut self) {
+        let Some(queue) = self.queue.take() else {
+            return;
+        };
+
+        // Hand the slot to the oldest emission that is still waiting for it
+        loop {
+            let waiter = {
+                let mut state = queue.lock();
+                match state.waiting.pop_front() {
+                    Some(waiter) => waiter,
+                    None => {
+                        state.in_flight = state.in_flight.saturating_sub(1);
+                        break;
+                    }
+                }
+            };
+            match waiter.send(EmissionPermit { queue: Some(queue.clone()) }) {
+                Ok(()) => break,
+                // That emission was cancelled; defuse the returned permit and try the next
+                Err(mut permit) => permit.queue = None,
+            }
+        }
+        queue.room.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(capacity: usize, overflow: OverflowPolicy) -> EmissionQueue {
+        EmissionQueue::new(EventCategory::Client, EmissionQueueConfig::new(1, capacity, overflow))
+    }
+
+    #[test]
+    fn test_categories_from_keys() {
+        assert_eq!(EventCategory::from_key("core:player_connected"), EventCategory::Core);
+        assert_eq!(EventCategory::from_key("client:chat:message"), EventCategory::Client);
+        assert_eq!(EventCategory::from_key("plugin:greeter:startup"), EventCategory::Plugin);
+        assert_eq!(EventCategory::from_key("gorc_instance:Ship:0:move"), EventCategory::Gorc);
+        assert_eq!(EventCategory::from_key("rpc:plugin:inventory:get_items"), EventCategory::Plugin);
+    }
+
+    #[tokio::test]
+    async fn test_reject_when_full() {
+        let queue = queue(1, OverflowPolicy::Reject);
+        let running = queue.admit().await.unwrap().unwrap();
+        let waiting = tokio::spawn({
+            let queue = EmissionQueue { inner: queue.inner.clone() };
+            async move { queue.admit().await.map(|permit| permit.is_some()) }
+        });
+        tokio::task::yield_now().await;
+        while queue.stats().depth < 2 {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(matches!(queue.admit().await, Err(EventError::QueueFull(_))));
+        drop(running);
+        assert!(waiting.await.unwrap().unwrap());
+
+        let stats = queue.stats();
+        assert_eq!((stats.depth, stats.peak_depth, stats.rejected), (0, 2, 1));
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_discards_longest_waiting() {
+        let queue = queue(1, OverflowPolicy::DropOldest);
+        let running = queue.admit().await.unwrap().unwrap();
+        let spawn_waiter = || {
+            let queue = EmissionQueue { inner: queue.inner.clone() };
+            tokio::spawn(async move { queue.admit().await.unwrap().is_some() })
+        };
+
+        let oldest = spawn_waiter();
+        while queue.stats().depth < 2 {
+            tokio::task::yield_now().await;
+        }
+        let newest = spawn_waiter();
+
+        // The oldest waiter is discarded as soon as the newest one arrives
+        assert!(!oldest.await.unwrap());
+        drop(running);
+        assert!(newest.await.unwrap());
+        assert_eq!(queue.stats().dropped, 1);
+    }
+
+    #[tokio::test]
+    async fn test_block_waits_for_room() {
+        let queue = queue(0, OverflowPolicy::Block);
+        let running = queue.admit().await.unwrap().unwrap();
+        let blocked = tokio::spawn({
+            let queue = EmissionQueue { inner: queue.inner.clone() };
+            async move { queue.admit().await.unwrap().is_some() }
+        });
+
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert!(!blocked.is_finished());
+        drop(running);
+        assert!(blocked.await.unwrap());
+        assert_eq!(queue.stats().in_flight, 0);
+    }
+}
@@ -0,0 +1,151 @@
+/// Request/response calls between plugins over the event system
+use crate::events::{Event, EventError};
+use super::core::EventSystem;
+use super::handlers::HandlerPriority;
+use compact_str::CompactString;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tracing::{debug, warn};
+
+/// How long [`EventSystem::request`] waits for a response
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Outcome a responder reports back to the waiting caller
+pub(super) type RpcOutcome = Result<serde_json::Value, String>;
+
+/// Request envelope carrying the ID the response is correlated by
+#[derive(Debug, Serialize, Deserialize)]
+struct RpcRequest<T> {
+    request_id: u64,
+    payload: T,
+}
+
+/// Builds the event key requests for `method` on `target` are emitted under.
+///
+/// Requests live in their own `rpc:` namespace so that handlers registered
+/// with `on_plugin` for the same names keep receiving plain events.
+fn request_key(target: &str, method: &str) -> CompactString {
+    CompactString::new_inline("rpc:") + target + ":" + method
+}
+
+impl EventSystem {
+    /// Sends a request and waits for its response.
+    ///
+    /// The request is delivered to the responder registered with
+    /// [`on_request`](Self::on_request) for the same target and method. If
+    /// several responders are registered, the first response wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Receiver of the request, e.g. `"plugin:inventory"`
+    /// * `method` - Operation to invoke, e.g. `"get_items"`
+    /// * `request` - Request payload
+    ///
+    /// # Returns
+    ///
+    /// The response, `HandlerNotFound` if nothing responds to the method,
+    /// `HandlerExecution` if the responder failed, or `Timeout` if no response
+    /// arrived within [`DEFAULT_REQUEST_TIMEOUT`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use horizon_event_system::EventSystem;
+    /// use serde_json::{json, Value};
+    ///
+    /// async fn example(events: &EventSystem) -> Result<(), Box<dyn std::error::Error>> {
+    ///     let items: Value = events
+    ///         .request::<Value, Value>("plugin:inventory", "get_items", json!({ "player": 7 }))
+    ///         .await?;
+    ///     println!("Inventory: {}", items);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn request<Req, Resp>(&self, target: &str, method: &str, request: Req) -> Result<Resp, EventError>
+    where
+        Req: Serialize + Send + Sync + 'static,
+        Resp: DeserializeOwned,
+    {
+        self.request_with_timeout(target, method, request, DEFAULT_REQUEST_TIMEOUT).await
+    }
+
+    /// Sends a request and waits up to `timeout` for its response.
+    ///
+    /// See [`request`](Self::request).
+    pub async fn request_with_timeout<Req, Resp>(
+        &self,
+        target: &str,
+        method: &str,
+        request: Req,
+        timeout: Duration,
+    ) -> Result<Resp, EventError>
+    where
+        Req: Serialize + Send + Sync + 'static,
+        Resp: DeserializeOwned,
+    {
+        let event_key = request_key(target, method);
+        if !self.handlers.contains_key(&event_key) {
+            return Err(EventError::HandlerNotFound(format!("No responder for {}", event_key)));
+        }
+
+        let request_id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = oneshot::channel();
+        self.pending_requests.insert(request_id, sender);
+
+        let envelope = serde_json::json!({ "request_id": request_id, "payload": request });
+        if let Err(e) = self.emit_event(&event_key, &envelope).await {
+            self.pending_requests.remove(&request_id);
+            return Err(e);
+        }
+
+        let outcome = match tokio::time::timeout(timeout, receiver).await {
+            Ok(Ok(outcome)) => outcome,
+            Ok(Err(_)) => Err(format!("{} was dropped without a response", event_key)),
+            Err(_) => {
+                self.pending_requests.remove(&request_id);
+                warn!("⏱️ Request {} to {} timed out after {:?}", request_id, event_key, timeout);
+                return Err(EventError::Timeout(format!("{} did not respond within {:?}", event_key, timeout)));
+            }
+        };
+
+        let response = outcome.map_err(EventError::HandlerExecution)?;
+        serde_json::from_value(response).map_err(EventError::Deserialization)
+    }
+
+    /// Registers a responder for requests sent with [`request`](Self::request).
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Receiver name callers address, e.g. `"plugin:inventory"`
+    /// * `method` - Operation the responder handles, e.g. `"get_items"`
+    /// * `handler` - Function turning a request into a response; an error is
+    ///   returned to the caller as `HandlerExecution`
+    pub async fn on_request<Req, Resp, F>(&self, target: &str, method: &str, handler: F) -> Result<(), EventError>
+    where
+        Req: Event + Serialize + DeserializeOwned + 'static,
+        Resp: Serialize + 'static,
+        F: Fn(Req) -> Result<Resp, EventError> + Send + Sync + Clone + 'static,
+    {
+        let event_key = request_key(target, method);
+        let pending_requests = self.pending_requests.clone();
+        let respond = move |request: RpcRequest<Req>| {
+            let outcome = handler(request.payload)
+                .and_then(|response| serde_json::to_value(response).map_err(EventError::Serialization))
+                .map_err(|e| e.to_string());
+            match pending_requests.remove(&request.request_id) {
+                // The caller may have stopped waiting; that's not the responder's failure
+                Some((_, sender)) => {
+                    let _ = sender.send(outcome);
+                }
+                None => debug!("📭 Response to request {} arrived after the caller gave up", request.request_id),
+            }
+            Ok(())
+        };
+
+        self.register_typed_handler(event_key.clone(), method, respond, HandlerPriority::NORMAL)
+            .await
+            .map(|_| ())
+    }
+}
@@ -0,0 +1,144 @@
+/// Server-to-server events between Horizon servers
+///
+/// Region servers, matchmakers and chat services built on Horizon exchange
+/// typed events over the authenticated [`ServerLink`] the host installs.
+/// Events sent with [`send_s2s`](EventSystem::send_s2s) arrive on the other
+/// server under `s2s:<event_name>`, where handlers registered with
+/// [`on_s2s`](EventSystem::on_s2s) receive them along with the name of the
+/// server that sent them. The namespace is reserved so plugins can't pose as
+/// another server by emitting into it locally.
+use crate::events::{Event, EventError};
+use crate::utils::current_timestamp;
+use super::core::EventSystem;
+use super::handlers::HandlerPriority;
+use async_trait::async_trait;
+use compact_str::CompactString;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::debug;
+
+/// Namespace server-to-server events are delivered under, as `s2s:<event_name>`
+pub const S2S_NAMESPACE: &str = "s2s";
+
+/// An event another server sent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S2sEvent<T> {
+    /// Name the sending server authenticated as
+    pub source: String,
+    /// The event itself
+    pub data: T,
+    /// Unix timestamp when the event was sent
+    pub timestamp: u64,
+}
+
+impl<T> S2sEvent<T> {
+    /// Creates an event sent now by the named server
+    pub fn new(source: impl Into<String>, data: T) -> Self {
+        Self { source: source.into(), data, timestamp: current_timestamp() }
+    }
+}
+
+/// Transport that carries server-to-server events to other servers.
+///
+/// The host installs one with [`EventSystem::set_server_link`]; it
+/// authenticates both ends and hands events it receives to
+/// [`EventSystem::emit_s2s`].
+#[async_trait]
+pub trait ServerLink: Send + Sync {
+    /// Sends an event to a server.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - Name of the server to send to
+    /// * `event_name` - Name the event is delivered under on that server
+    /// * `data` - The event, as JSON
+    async fn send(&self, server: &str, event_name: &str, data: serde_json::Value) -> Result<(), EventError>;
+
+    /// Gets the names of the servers currently connected
+    async fn connected_servers(&self) -> Vec<String>;
+}
+
+impl EventSystem {
+    /// Sets the link server-to-server events are sent over
+    pub fn set_server_link(&mut self, link: Arc<dyn ServerLink>) {
+        self.server_link = Some(link);
+    }
+
+    /// Gets the link server-to-server events are sent over, if one is set
+    pub fn get_server_link(&self) -> Option<Arc<dyn ServerLink>> {
+        self.server_link.clone()
+    }
+
+    /// Sends a typed event to another server.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - Name of the server to send to
+    /// * `event_name` - Name its `on_s2s` handlers are registered for
+    /// * `event` - The event
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` once the event is handed to the link, or an error if no link
+    /// is set, the server is unknown or it can't be reached.
+    pub async fn send_s2s<T: Serialize>(&self, server: &str, event_name: &str, event: &T) -> Result<(), EventError> {
+        let link = self.server_link()?;
+        link.send(server, event_name, serde_json::to_value(event)?).await
+    }
+
+    /// Sends a typed event to every connected server.
+    ///
+    /// # Returns
+    ///
+    /// The number of servers the event was sent to.
+    pub async fn broadcast_s2s<T: Serialize>(&self, event_name: &str, event: &T) -> Result<usize, EventError> {
+        let link = self.server_link()?;
+        let data = serde_json::to_value(event)?;
+        let mut sent = 0;
+        for server in link.connected_servers().await {
+            match link.send(&server, event_name, data.clone()).await {
+                Ok(()) => sent += 1,
+                Err(e) => debug!("🔗 Failed to send {} to server {}: {}", event_name, server, e),
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Delivers an event another server sent to the local `on_s2s` handlers.
+    ///
+    /// Server links call this for every event they receive. Plugins can't,
+    /// as the `s2s` namespace is reserved.
+    pub async fn emit_s2s<T>(&self, event_name: &str, event: &S2sEvent<T>) -> Result<(), EventError>
+    where
+        S2sEvent<T>: Event,
+    {
+        let event_key = CompactString::new_inline("s2s:") + event_name;
+        self.check_emitter(&event_key)?;
+        self.validate_payload(&event_key, event)?;
+        self.emit_event(&event_key, event).await
+    }
+
+    /// Registers a handler for events other servers send under a name.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_name` - Name the other servers send the event under
+    /// * `handler` - Called with each event and the server it came from
+    pub async fn on_s2s<T, F>(&self, event_name: &str, handler: F) -> Result<(), EventError>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync + std::fmt::Debug + 'static,
+        F: Fn(S2sEvent<T>) -> Result<(), EventError> + Send + Sync + Clone + 'static,
+    {
+        let event_key = CompactString::new_inline("s2s:") + event_name;
+        self.register_typed_handler(event_key, event_name, handler, HandlerPriority::NORMAL)
+            .await
+            .map(|_| ())
+    }
+
+    fn server_link(&self) -> Result<Arc<dyn ServerLink>, EventError> {
+        self.server_link
+            .clone()
+            .ok_or_else(|| EventError::RuntimeError("No server link is configured".to_string()))
+    }
+}
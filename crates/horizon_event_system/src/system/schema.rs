@@ -0,0 +1,455 @@
+/// Payload schemas plugins register for the events they own
+use crate::events::{Event, EventError};
+use super::core::EventSystem;
+use compact_str::CompactString;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// A single way in which a payload breaks its event's schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaViolation {
+    /// Location of the offending value, e.g. `$.items[2].count`
+    pub path: String,
+    /// What is wrong with the value
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// A payload that was rejected by its event's schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[error("{event_key} payload violates its schema: {}", render_violations(.violations))]
+pub struct SchemaValidationError {
+    /// Event key the payload was emitted under
+    pub event_key: String,
+    /// Everything wrong with the payload
+    pub violations: Vec<SchemaViolation>,
+}
+
+fn render_violations(violations: &[SchemaViolation]) -> String {
+    violations.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+}
+
+#[derive(Clone)]
+enum SchemaKind {
+    /// Payload must deserialize into a Rust type
+    Typed {
+        type_name: &'static str,
+        check: fn(&Value) -> Result<(), String>,
+    },
+    /// Payload must satisfy a JSON Schema document
+    Json(Arc<Value>),
+}
+
+/// Shape a payload must have to be emitted under an event key.
+///
+/// Schemas come either from a serde type ([`EventSchema::of`]) or from a
+/// JSON Schema document ([`EventSchema::json`]). JSON Schema support covers
+/// the keywords event payloads need: `type`, `enum`, `const`, `properties`,
+/// `required`, `additionalProperties`, `items`, `minItems`/`maxItems`,
+/// `minLength`/`maxLength` and `minimum`/`maximum`.
+#[derive(Clone)]
+pub struct EventSchema {
+    kind: SchemaKind,
+}
+
+impl fmt::Debug for EventSchema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            SchemaKind::Typed { type_name, .. } => f.debug_tuple("EventSchema::Typed").field(type_name).finish(),
+            SchemaKind::Json(schema) => f.debug_tuple("EventSchema::Json").field(schema).finish(),
+        }
+    }
+}
+
+impl EventSchema {
+    /// Creates a schema accepting payloads that deserialize into `T`
+    pub fn of<T: DeserializeOwned>() -> Self {
+        fn check<T: DeserializeOwned>(payload: &Value) -> Result<(), String> {
+            T::deserialize(payload).map(|_| ()).map_err(|e| e.to_string())
+        }
+
+        Self {
+            kind: SchemaKind::Typed {
+                type_name: std::any::type_name::<T>(),
+                check: check::<T>,
+            },
+        }
+    }
+
+    /// Creates a schema from a JSON Schema document
+    pub fn json(schema: Value) -> Self {
+        Self {
+            kind: SchemaKind::Json(Arc::new(schema)),
+        }
+    }
+
+    /// Validates a payload against the schema.
+    ///
+    /// # Returns
+    ///
+    /// Every violation found; empty if the payload is valid.
+    pub fn validate(&self, payload: &Value) -> Vec<SchemaViolation> {
+        match &self.kind {
+            SchemaKind::Typed { type_name, check } => match check(payload) {
+                Ok(()) => Vec::new(),
+                Err(message) => vec![SchemaViolation {
+                    path: "$".to_string(),
+                    message: format!("not a valid {}: {}", type_name, message),
+                }],
+            },
+            SchemaKind::Json(schema) => {
+                let mut violations = Vec::new();
+                validate_json(schema, payload, "$", &mut violations);
+                violations
+            }
+        }
+    }
+}
+
+/// Checks whether a value has the given JSON Schema type
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match expected {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0),
+        _ => false,
+    }
+}
+
+fn validate_json(schema: &Value, value: &Value, path: &str, violations: &mut Vec<SchemaViolation>) {
+    let mut violation = |message: String| {
+        violations.push(SchemaViolation {
+            path: path.to_string(),
+            message,
+        })
+    };
+
+    // `true` accepts anything, `false` accepts nothing
+    let schema = match schema {
+        Value::Bool(true) => return,
+        Value::Bool(false) => return violation("no value is allowed here".to_string()),
+        Value::Object(schema) => schema,
+        _ => return,
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|name| type_matches(name, value)) {
+            // Nested keywords would only repeat the mismatch
+            return violation(format!("expected {}, got {}", allowed.join(" or "), value));
+        }
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            violation(format!("{} is not one of {}", value, Value::Array(options.clone())));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            violation(format!("expected {}, got {}", expected, value));
+        }
+    }
+
+    match value {
+        Value::Number(number) => {
+            let number = number.as_f64().unwrap_or_default();
+            if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64) {
+                if number < minimum {
+                    violation(format!("{} is less than the minimum of {}", number, minimum));
+                }
+            }
+            if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64) {
+                if number > maximum {
+                    violation(format!("{} is greater than the maximum of {}", number, maximum));
+                }
+            }
+        }
+        Value::String(text) => {
+            let length = text.chars().count() as u64;
+            if let Some(min_length) = schema.get("minLength").and_then(Value::as_u64) {
+                if length < min_length {
+                    violation(format!("shorter than {} characters", min_length));
+                }
+            }
+            if let Some(max_length) = schema.get("maxLength").and_then(Value::as_u64) {
+                if length > max_length {
+                    violation(format!("longer than {} characters", max_length));
+                }
+            }
+        }
+        Value::Array(items) => {
+            let count = items.len() as u64;
+            if let Some(min_items) = schema.get("minItems").and_then(Value::as_u64) {
+                if count < min_items {
+                    violation(format!("fewer than {} items", min_items));
+                }
+            }
+            if let Some(max_items) = schema.get("maxItems").and_then(Value::as_u64) {
+                if count > max_items {
+                    violation(format!("more than {} items", max_items));
+                }
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_json(item_schema, item, &format!("{}[{}]", path, index), violations);
+                }
+            }
+        }
+        Value::Object(fields) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !fields.contains_key(name) {
+                        violation(format!("missing required field '{}'", name));
+                    }
+                }
+            }
+
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (name, field) in fields {
+                let field_path = format!("{}.{}", path, name);
+                match properties.and_then(|properties| properties.get(name)) {
+                    Some(field_schema) => validate_json(field_schema, field, &field_path, violations),
+                    None => match schema.get("additionalProperties") {
+                        Some(Value::Bool(false)) => violations.push(SchemaViolation {
+                            path: field_path,
+                            message: "unexpected field".to_string(),
+                        }),
+                        Some(extra_schema @ Value::Object(_)) => {
+                            validate_json(extra_schema, field, &field_path, violations)
+                        }
+                        _ => {}
+                    },
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Schema registered for an event key, with the owner that registered it
+#[derive(Debug, Clone)]
+pub(super) struct RegisteredSchema {
+    schema: EventSchema,
+    owner: Option<CompactString>,
+}
+
+impl EventSystem {
+    /// Registers the payload schema for a core event.
+    ///
+    /// See [`register_plugin_schema`](Self::register_plugin_schema).
+    pub fn register_core_schema(&self, event_name: &str, schema: EventSchema) -> Result<(), EventError> {
+        self.register_schema(CompactString::new_inline("core:") + event_name, schema)
+    }
+
+    /// Registers the payload schema for a client event.
+    ///
+    /// Client messages that don't match the schema are rejected before any
+    /// handler runs; the schema describes the message's `data`, not the
+    /// connection context handlers receive around it.
+    ///
+    /// See [`register_plugin_schema`](Self::register_plugin_schema).
+    pub fn register_client_schema(&self, namespace: &str, event_name: &str, schema: EventSchema) -> Result<(), EventError> {
+        self.register_schema(CompactString::new_inline("client:") + namespace + ":" + event_name, schema)
+    }
+
+    /// Registers the payload schema for client events targeting GORC objects.
+    ///
+    /// Native GORC events a client sends to an object of `object_type` on
+    /// `channel` are rejected before any `on_gorc_client` handler runs if
+    /// their `data` doesn't match the schema.
+    ///
+    /// See [`register_plugin_schema`](Self::register_plugin_schema).
+    pub fn register_gorc_client_schema(&self, object_type: &str, channel: u8, event_name: &str, schema: EventSchema) -> Result<(), EventError> {
+        self.register_schema(
+            CompactString::new_inline("gorc_client:") + object_type + ":" + &channel.to_string() + ":" + event_name,
+            schema,
+        )
+    }
+
+    /// Registers the payload schema for a plugin event.
+    ///
+    /// Once registered, emitting a payload that doesn't match the schema fails
+    /// with [`EventError::SchemaViolation`] and no handler runs. A schema
+    /// belongs to the owner of the [`register_as`](Self::register_as) scope
+    /// it was registered in; that owner may replace it, other owners may not. Schemas are removed
+    /// together with their owner's handlers.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin_name` - Plugin namespace of the event
+    /// * `event_name` - Name of the event
+    /// * `schema` - Shape the event's payloads must have
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use horizon_event_system::{EventSchema, EventSystem};
+    /// use serde_json::json;
+    ///
+    /// fn register(events: &EventSystem) -> Result<(), horizon_event_system::EventError> {
+    ///     events.register_plugin_schema("inventory", "pickup_item", EventSchema::json(json!({
+    ///         "type": "object",
+    ///         "required": ["item_id", "item_count"],
+    ///         "properties": {
+    ///             "item_id": { "type": "integer" },
+    ///             "item_count": { "type": "integer", "minimum": 1 }
+    ///         }
+    ///     })))
+    /// }
+    /// ```
+    pub fn register_plugin_schema(&self, plugin_name: &str, event_name: &str, schema: EventSchema) -> Result<(), EventError> {
+        self.check_plugin_namespace(plugin_name)?;
+        self.register_schema(CompactString::new_inline("plugin:") + plugin_name + ":" + event_name, schema)
+    }
+
+    /// Removes the schema of an event key, e.g. `plugin:inventory:pickup_item`
+    ///
+    /// # Returns
+    ///
+    /// `true` if a schema was removed.
+    pub fn remove_schema(&self, event_key: &str) -> bool {
+        self.schemas.remove(event_key).is_some()
+    }
+
+    /// Gets the schema registered for an event key
+    pub fn schema(&self, event_key: &str) -> Option<EventSchema> {
+        self.schemas.get(event_key).map(|registered| registered.schema.clone())
+    }
+
+    fn register_schema(&self, event_key: CompactString, schema: EventSchema) -> Result<(), EventError> {
+        let owner = self.registration_owner();
+
+        if let Some(existing) = self.schemas.get(&event_key) {
+            if existing.owner.is_some() && existing.owner != owner {
+                return Err(EventError::SchemaConflict(format!(
+                    "{} is owned by '{}'",
+                    event_key,
+                    existing.owner.as_deref().unwrap_or_default()
+                )));
+            }
+        }
+
+        info!("📐 Registered schema for {}", event_key);
+        self.schemas.insert(event_key, RegisteredSchema { schema, owner });
+        Ok(())
+    }
+
+    /// Removes every schema registered by the given owner
+    pub(super) fn remove_schemas_owned_by(&self, owner: &str) -> usize {
+        let before = self.schemas.len();
+        self.schemas
+            .retain(|_, registered| registered.owner.as_deref() != Some(owner));
+        before - self.schemas.len()
+    }
+
+    /// Checks the data of a client message against the schema registered
+    /// for its namespace and event, without dispatching it.
+    ///
+    /// The server calls this before routing a client message anywhere, so
+    /// no handler, including `raw_client_message` listeners, sees a payload
+    /// that violates its schema.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the data matches or no schema is registered, or
+    /// [`EventError::SchemaViolation`] listing what's wrong with it.
+    pub fn validate_client_message(&self, namespace: &str, event_name: &str, data: &serde_json::Value) -> Result<(), EventError> {
+        if self.schemas.is_empty() {
+            return Ok(());
+        }
+        self.validate_value(&(CompactString::new_inline("client:") + namespace + ":" + event_name), data)
+    }
+
+    /// Checks a payload against the schema of its event key, if there is one
+    pub(super) fn validate_payload<T>(&self, event_key: &str, payload: &T) -> Result<(), EventError>
+    where
+        T: Event,
+    {
+        if self.schemas.is_empty() || !self.schemas.contains_key(event_key) {
+            return Ok(());
+        }
+        self.validate_value(event_key, &serde_json::from_slice(&payload.serialize()?).map_err(EventError::Deserialization)?)
+    }
+
+    /// Checks a decoded payload against the schema of its event key, if there is one
+    fn validate_value(&self, event_key: &str, payload: &serde_json::Value) -> Result<(), EventError> {
+        let Some(registered) = self.schemas.get(event_key) else {
+            return Ok(());
+        };
+
+        let violations = registered.schema.validate(payload);
+        if violations.is_empty() {
+            return Ok(());
+        }
+
+        debug!("🚫 Rejected {} payload: {}", event_key, render_violations(&violations));
+        Err(EventError::SchemaViolation(SchemaValidationError {
+            event_key: event_key.to_string(),
+            violations,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn paths(violations: Vec<SchemaViolation>) -> Vec<String> {
+        violations.into_iter().map(|violation| violation.path).collect()
+    }
+
+    #[test]
+    fn test_json_schema_reports_every_violation() {
+        let schema = EventSchema::json(json!({
+            "type": "object",
+            "required": ["item_id", "slots"],
+            "additionalProperties": false,
+            "properties": {
+                "item_id": { "type": "integer", "minimum": 1 },
+                "rarity": { "enum": ["common", "rare"] },
+                "slots": { "type": "array", "maxItems": 2, "items": { "type": "string", "minLength": 1 } }
+            }
+        }));
+
+        assert!(schema.validate(&json!({ "item_id": 4, "slots": ["head"] })).is_empty());
+        assert_eq!(
+            paths(schema.validate(&json!({ "item_id": 0, "rarity": "epic", "slots": ["", 3], "extra": true }))),
+            ["$.extra", "$.item_id", "$.rarity", "$.slots[0]", "$.slots[1]"]
+        );
+        assert_eq!(paths(schema.validate(&json!({ "slots": [] }))), ["$"]);
+        assert_eq!(schema.validate(&json!([1]))[0].message, "expected object, got [1]");
+    }
+
+    #[test]
+    fn test_typed_schema() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Pickup {
+            item_id: u32,
+        }
+
+        let schema = EventSchema::of::<Pickup>();
+        assert!(schema.validate(&json!({ "item_id": 4 })).is_empty());
+        let violations = schema.validate(&json!({ "item_id": "four" }));
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("Pickup"));
+    }
+}
@@ -0,0 +1,114 @@
+/// Tracing spans around event handler invocations
+use crate::events::EventHandler;
+use super::core::EventSystem;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tracing::Span;
+
+/// Which handler invocations are wrapped in a tracing span.
+///
+/// Each sampled invocation gets an `event_handler` span carrying the event
+/// key, handler name, owning plugin and the handler's run time in
+/// microseconds (`duration_us`), so traces and flame graphs show where event
+/// time goes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HandlerSpanConfig {
+    /// Whether handler invocations get spans at all
+    pub enabled: bool,
+    /// Fraction of invocations that get a span, from 0.0 (none) to 1.0 (all)
+    pub sample_rate: f64,
+}
+
+impl Default for HandlerSpanConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sample_rate: 1.0,
+        }
+    }
+}
+
+impl HandlerSpanConfig {
+    /// Creates a configuration that traces the given fraction of invocations
+    pub fn sampled(sample_rate: f64) -> Self {
+        Self {
+            enabled: true,
+            sample_rate: sample_rate.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Creates a configuration that never creates handler spans
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            sample_rate: 0.0,
+        }
+    }
+}
+
+impl EventSystem {
+    /// Sets which handler invocations are wrapped in tracing spans.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Whether to create spans and what fraction to sample
+    pub fn set_handler_span_config(&mut self, config: HandlerSpanConfig) {
+        self.handler_spans = config;
+    }
+
+    /// Gets which handler invocations are wrapped in tracing spans
+    pub fn handler_span_config(&self) -> &HandlerSpanConfig {
+        &self.handler_spans
+    }
+
+    /// Creates the span for one handler invocation, if it is sampled.
+    ///
+    /// Sampling is deterministic: with a rate of 0.25, every fourth
+    /// invocation gets a span.
+    pub(super) fn handler_span(&self, event_key: &str, handler: &Arc<dyn EventHandler>) -> Option<Span> {
+        let config = &self.handler_spans;
+        if !config.enabled || config.sample_rate <= 0.0 {
+            return None;
+        }
+        if config.sample_rate < 1.0 {
+            let invocation = self.handler_span_invocations.fetch_add(1, Ordering::Relaxed) as f64;
+            if ((invocation + 1.0) * config.sample_rate).floor() <= (invocation * config.sample_rate).floor() {
+                return None;
+            }
+        }
+
+        let owner = self.handler_owner(handler);
+        Some(tracing::info_span!(
+            "event_handler",
+            event_key,
+            handler = handler.handler_name(),
+            plugin = owner.as_deref(),
+            duration_us = tracing::field::Empty,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sampled_spans(config: HandlerSpanConfig, invocations: usize) -> usize {
+        let mut events = EventSystem::new();
+        events.set_handler_span_config(config);
+        let handler: Arc<dyn EventHandler> =
+            Arc::new(crate::events::TypedEventHandler::new("sampled".to_string(), |_: serde_json::Value| Ok(())));
+        (0..invocations)
+            .filter(|_| events.handler_span("core:tick", &handler).is_some())
+            .count()
+    }
+
+    #[test]
+    fn test_span_sampling() {
+        assert_eq!(sampled_spans(HandlerSpanConfig::default(), 10), 10);
+        assert_eq!(sampled_spans(HandlerSpanConfig::sampled(0.25), 100), 25);
+        assert_eq!(sampled_spans(HandlerSpanConfig::sampled(0.0), 10), 0);
+        assert_eq!(sampled_spans(HandlerSpanConfig::disabled(), 10), 0);
+    }
+}
@@ -1,5 +1,10 @@
 /// Statistics tracking for the event system
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Number of recent handler latencies per event key that percentiles are computed over
+const LATENCY_WINDOW: usize = 1024;
 
 /// Core event system statistics for monitoring performance
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -14,6 +19,15 @@ pub struct EventSystemStats {
     pub avg_events_per_second: f64,
     /// Peak events per second recorded
     pub peak_events_per_second: f64,
+    /// Total number of handler panics caught during dispatch
+    #[serde(default)]
+    pub handler_panics: u64,
+    /// Emitted events for which at least one handler failed or panicked
+    #[serde(default)]
+    pub events_failed: u64,
+    /// Current state of each category's emission queue
+    #[serde(default)]
+    pub emission_queues: Vec<super::queue::EmissionQueueStats>,
 }
 
 /// Detailed statistics including category breakdowns
@@ -25,6 +39,81 @@ pub struct DetailedEventSystemStats {
     pub handler_count_by_category: HandlerCategoryStats,
     /// GORC instance manager statistics
     pub gorc_instance_stats: Option<crate::gorc::instance::InstanceManagerStats>,
+    /// Handler invocation statistics of every emitted event key, ordered by key
+    #[serde(default)]
+    pub event_keys: Vec<EventKeyStats>,
+}
+
+/// Handler invocation statistics of one event key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventKeyStats {
+    /// Event key the statistics belong to (e.g. `client:chat:message`)
+    pub event_key: String,
+    /// Handler invocations for the key since system start
+    pub invocations: u64,
+    /// Invocations that returned an error or panicked
+    pub failures: u64,
+    /// Mean handler run time in microseconds
+    pub mean_latency_us: f64,
+    /// Median handler run time over recent invocations, in microseconds
+    pub p50_latency_us: u64,
+    /// 99th percentile handler run time over recent invocations, in microseconds
+    pub p99_latency_us: u64,
+    /// Most recent handler error or panic message
+    pub last_error: Option<String>,
+}
+
+/// Running handler statistics of one event key
+#[derive(Debug, Default)]
+pub(super) struct KeyMetrics {
+    invocations: u64,
+    failures: u64,
+    total_latency_us: u64,
+    recent_latencies_us: VecDeque<u64>,
+    last_error: Option<String>,
+}
+
+impl KeyMetrics {
+    /// Records one handler invocation
+    pub(super) fn record(&mut self, latency: Duration, error: Option<String>) {
+        let latency_us = latency.as_micros().min(u64::MAX as u128) as u64;
+        self.invocations += 1;
+        self.total_latency_us = self.total_latency_us.saturating_add(latency_us);
+        if self.recent_latencies_us.len() == LATENCY_WINDOW {
+            self.recent_latencies_us.pop_front();
+        }
+        self.recent_latencies_us.push_back(latency_us);
+        if error.is_some() {
+            self.failures += 1;
+            self.last_error = error;
+        }
+    }
+
+    /// Builds a snapshot of the statistics
+    pub(super) fn snapshot(&self, event_key: &str) -> EventKeyStats {
+        let mut latencies: Vec<u64> = self.recent_latencies_us.iter().copied().collect();
+        latencies.sort_unstable();
+        let percentile = |p: f64| {
+            latencies
+                .get(((latencies.len() as f64 * p).ceil() as usize).saturating_sub(1))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        EventKeyStats {
+            event_key: event_key.to_string(),
+            invocations: self.invocations,
+            failures: self.failures,
+            mean_latency_us: if self.invocations == 0 {
+                0.0
+            } else {
+                self.total_latency_us as f64 / self.invocations as f64
+            },
+            p50_latency_us: percentile(0.5),
+            p99_latency_us: percentile(0.99),
+            last_error: self.last_error.clone(),
+        }
+    }
 }
 
 /// Handler count breakdown by event category
@@ -40,4 +129,32 @@ pub struct HandlerCategoryStats {
     pub gorc_handlers: usize,
     /// Number of GORC instance event handlers
     pub gorc_instance_handlers: usize,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_metrics_percentiles() {
+        let mut metrics = KeyMetrics::default();
+        for latency_us in 1..=100 {
+            let error = (latency_us % 25 == 0).then(|| format!("failed after {}us", latency_us));
+            metrics.record(Duration::from_micros(latency_us), error);
+        }
+
+        let stats = metrics.snapshot("core:tick");
+        assert_eq!((stats.invocations, stats.failures), (100, 4));
+        assert_eq!((stats.p50_latency_us, stats.p99_latency_us), (50, 99));
+        assert_eq!(stats.mean_latency_us, 50.5);
+        assert_eq!(stats.last_error.as_deref(), Some("failed after 100us"));
+
+        // Percentiles only cover the most recent invocations
+        for _ in 0..LATENCY_WINDOW {
+            metrics.record(Duration::from_micros(7), None);
+        }
+        let stats = metrics.snapshot("core:tick");
+        assert_eq!((stats.p50_latency_us, stats.p99_latency_us), (7, 7));
+        assert_eq!(stats.invocations, 100 + LATENCY_WINDOW as u64);
+    }
+}
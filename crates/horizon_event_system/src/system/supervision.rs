@@ -0,0 +1,164 @@
+/// Handler ownership tracking and panic reporting for plugin supervision
+use crate::events::EventHandler;
+use super::core::EventSystem;
+use compact_str::CompactString;
+use std::any::Any;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::broadcast;
+use tracing::info;
+
+/// Report emitted whenever an event handler panics during dispatch.
+///
+/// The panic is caught at the dispatch boundary so the remaining handlers for
+/// the event still run. Supervisors (such as the plugin manager) subscribe to
+/// these reports to decide whether the owning plugin should be restarted.
+#[derive(Debug, Clone)]
+pub struct HandlerPanicReport {
+    /// Event key that was being dispatched (e.g. `client:chat:message`)
+    pub event_key: String,
+    /// Name of the handler that panicked
+    pub handler_name: String,
+    /// Owner of the handler (usually the plugin name), if known
+    pub owner: Option<String>,
+    /// Panic payload rendered as a string
+    pub message: String,
+    /// When the panic was caught
+    pub occurred_at: Instant,
+}
+
+/// Decides whether plugin-owned handlers may run, e.g. to short-circuit failing ones.
+///
+/// The event system asks the gate before invoking a handler that has an
+/// owner and reports how the invocation ended afterwards. Handlers without
+/// an owner always run.
+pub trait HandlerGate: std::fmt::Debug + Send + Sync {
+    /// Checks whether the plugin's handler for an event key may run
+    fn allow<'a>(&'a self, plugin: &'a str, event_key: &'a str) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>>;
+
+    /// Records whether the plugin's handler for an event key succeeded
+    fn record<'a>(
+        &'a self,
+        plugin: &'a str,
+        event_key: &'a str,
+        succeeded: bool,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+}
+
+/// Returns a stable identity for a registered handler.
+#[inline]
+pub(super) fn handler_identity(handler: &Arc<dyn EventHandler>) -> usize {
+    Arc::as_ptr(handler) as *const () as usize
+}
+
+/// Renders a panic payload into a readable message.
+pub(super) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+impl EventSystem {
+    /// Attributes the handlers, schemas and timers a future registers to an owner.
+    ///
+    /// The plugin manager wraps a plugin's lifecycle calls in this so that
+    /// panics can later be traced back to the plugin, and its handlers
+    /// removed when it is unloaded. Registrations made outside such a scope
+    /// have no owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Owner (usually a plugin name) registrations are attributed to
+    /// * `future` - Code doing the registering
+    pub fn register_as<F: std::future::Future>(&self, owner: &str, future: F) -> super::namespaces::OwnerScope<'_, F> {
+        super::namespaces::OwnerScope::new(&self.registrants, owner, future)
+    }
+
+    /// Sets the gate consulted before plugin-owned handlers run
+    pub fn set_handler_gate(&mut self, gate: Arc<dyn HandlerGate>) {
+        self.handler_gate = Some(gate);
+    }
+
+    /// Gets the owner of a registered handler, if one was recorded.
+    pub fn handler_owner(&self, handler: &Arc<dyn EventHandler>) -> Option<String> {
+        self.handler_owners
+            .get(&handler_identity(handler))
+            .map(|owner| owner.value().to_string())
+    }
+
+    /// Subscribes to panic reports from handler dispatch.
+    pub fn subscribe_handler_panics(&self) -> broadcast::Receiver<HandlerPanicReport> {
+        self.panic_sender.subscribe()
+    }
+
+    /// Removes every handler attributed to the given owner.
+    ///
+    /// Interval timers attributed to the owner are cancelled as well.
+    ///
+    /// # Returns
+    ///
+    /// The number of handlers that were removed.
+    pub async fn remove_handlers_owned_by(&self, owner: &str) -> usize {
+        let mut removed_count = 0;
+
+        for mut entry in self.handlers.iter_mut() {
+            let before = entry.value().len();
+            entry.value_mut().retain(|handler| {
+                let id = handler_identity(handler);
+                let owned = self
+                    .handler_owners
+                    .get(&id)
+                    .is_some_and(|handler_owner| handler_owner.value().as_str() == owner);
+                if owned {
+                    self.handler_owners.remove(&id);
+                    self.handler_priorities.remove(&id);
+                    self.once_handlers.remove(&id);
+                }
+                !owned
+            });
+            removed_count += before - entry.value().len();
+        }
+
+        self.handlers.retain(|_, handlers| !handlers.is_empty());
+        self.remove_schemas_owned_by(owner);
+        let cancelled_timers = self.cancel_intervals_owned_by(owner);
+        if cancelled_timers > 0 {
+            info!("⏲️ Cancelled {} timers owned by '{}'", cancelled_timers, owner);
+        }
+
+        if removed_count > 0 {
+            let mut stats = self.stats.write().await;
+            stats.total_handlers = stats.total_handlers.saturating_sub(removed_count);
+            info!("🗑️ Removed {} handlers owned by '{}'", removed_count, owner);
+        }
+
+        removed_count
+    }
+
+    /// Gets the owner registrations on this thread are currently attributed to
+    pub(super) fn registration_owner(&self) -> Option<CompactString> {
+        if self.registrants.is_empty() {
+            return None;
+        }
+        self.registrants.get(&std::thread::current().id()).map(|owner| owner.clone())
+    }
+
+    /// Records the current registration owner for a freshly registered handler.
+    pub(super) fn tag_handler_owner(&self, handler: &Arc<dyn EventHandler>) {
+        if let Some(owner) = self.registration_owner() {
+            self.handler_owners.insert(handler_identity(handler), owner);
+        }
+    }
+
+    /// Publishes a panic report to all subscribed supervisors.
+    pub(super) fn report_handler_panic(&self, report: HandlerPanicReport) {
+        // No subscribers is not an error - the panic has already been logged
+        let _ = self.panic_sender.send(report);
+    }
+}
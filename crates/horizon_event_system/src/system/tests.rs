@@ -252,4 +252,838 @@ mod tests {
         let final_stats = events.get_stats().await;
         assert_eq!(final_stats.total_handlers, 1);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_handler_panic_is_isolated() {
+        let events = EventSystem::new();
+        let mut panic_reports = events.subscribe_handler_panics();
+        let calls = Arc::new(Mutex::new(0));
+
+        events.register_as("rogue_plugin", events.on_core("player_connected", |_: PlayerConnectedEvent| -> Result<(), crate::events::EventError> {
+            panic!("rogue handler");
+        })).await.unwrap();
+
+        let calls_clone = calls.clone();
+        events.on_core("player_connected", move |_: PlayerConnectedEvent| {
+            *calls_clone.lock().unwrap() += 1;
+            Ok(())
+        }).await.unwrap();
+
+        let player_event = PlayerConnectedEvent {
+            player_id: PlayerId::new(),
+            connection_id: "test_conn".to_string(),
+            remote_addr: "127.0.0.1:8080".to_string(),
+            timestamp: crate::utils::current_timestamp(),
+        };
+
+        // The panic must not escape the emitter and siblings must still run
+        assert!(events.emit_core("player_connected", &player_event).await.is_ok());
+        assert_eq!(*calls.lock().unwrap(), 1);
+
+        let report = panic_reports.try_recv().expect("panic should be reported");
+        assert_eq!(report.event_key, "core:player_connected");
+        assert_eq!(report.owner.as_deref(), Some("rogue_plugin"));
+        assert_eq!(report.message, "rogue handler");
+
+        let stats = events.get_stats().await;
+        assert_eq!(stats.handler_panics, 1);
+        assert_eq!(stats.events_emitted, 1);
+    }
+
+    #[tokio::test]
+    async fn test_remove_handlers_owned_by() {
+        let events = EventSystem::new();
+
+        events.register_as("plugin_a", async {
+            events.on_core("shared", |_: PlayerConnectedEvent| Ok(())).await.unwrap();
+            events.on_plugin("plugin_a", "only_a", |_: PlayerConnectedEvent| Ok(())).await.unwrap();
+        }).await;
+        events.register_as("plugin_b", events.on_core("shared", |_: PlayerConnectedEvent| Ok(()))).await.unwrap();
+
+        assert_eq!(events.remove_handlers_owned_by("plugin_a").await, 2);
+        assert_eq!(events.get_handler_count("core:shared").await, 1);
+        assert!(!events.has_handlers("plugin:plugin_a:only_a").await);
+        assert_eq!(events.get_stats().await.total_handlers, 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_registrations_keep_their_owners() {
+        let events = EventSystem::new();
+        let events = &events;
+        let register = |owner: &'static str| {
+            events.register_as(owner, async move {
+                for index in 0..10 {
+                    events.on_core(&format!("{owner}_{index}"), |_: PlayerConnectedEvent| Ok(())).await.unwrap();
+                    tokio::task::yield_now().await;
+                }
+            })
+        };
+
+        // The two scopes are polled in turn, each registration landing while the other is suspended
+        tokio::join!(register("plugin_a"), register("plugin_b"));
+        assert_eq!(events.remove_handlers_owned_by("plugin_a").await, 10);
+        assert!(events.has_handlers("core:plugin_b_0").await);
+        assert!(!events.has_handlers("core:plugin_a_9").await);
+        assert_eq!(events.remove_handlers_owned_by("plugin_b").await, 10);
+    }
+
+    #[tokio::test]
+    async fn test_emit_to_group() {
+        let mut events = EventSystem::new();
+        let mock_sender = Arc::new(MockResponseSender::new());
+        events.set_client_response_sender(mock_sender.clone());
+
+        let (tank, healer, outsider) = (PlayerId::new(), PlayerId::new(), PlayerId::new());
+        let raid = events.create_group("raid:molten_core").await;
+        assert!(events.add_group_member(raid, tank).await.unwrap());
+        assert!(events.add_group_member(raid, healer).await.unwrap());
+        assert!(!events.add_group_member(raid, healer).await.unwrap());
+
+        let pull = serde_json::json!({ "boss": "ragnaros" });
+        assert_eq!(events.emit_to_group(raid, "boss_pulled", &pull).await.unwrap(), 2);
+
+        let sent = mock_sender.get_sent_messages();
+        let recipients: Vec<PlayerId> = sent.iter().map(|(player_id, _)| *player_id).collect();
+        assert!(recipients.contains(&tank) && recipients.contains(&healer));
+        assert!(!recipients.contains(&outsider));
+        let message: serde_json::Value = serde_json::from_slice(&sent[0].1).unwrap();
+        assert_eq!(message["type"], "group_event");
+        assert_eq!(message["event_type"], "boss_pulled");
+        assert_eq!(message["data"]["boss"], "ragnaros");
+
+        assert!(events.remove_group_member(raid, tank).await.unwrap());
+        assert_eq!(events.get_group_members(raid).await, Some(vec![healer]));
+        assert_eq!(events.get_multicast_manager().get_stats().await.messages_sent, 1);
+
+        events.destroy_group(raid).await.unwrap();
+        assert!(matches!(
+            events.emit_to_group(raid, "boss_pulled", &pull).await,
+            Err(crate::events::EventError::HandlerNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_pattern_handlers() {
+        let events = EventSystem::new();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let core_seen = seen.clone();
+        events.on_core_pattern("player_*", move |event_name: String, _event: serde_json::Value| {
+            core_seen.lock().unwrap().push(event_name);
+            Ok(())
+        }).await.unwrap();
+        let client_seen = seen.clone();
+        events.on_client_pattern("*", "*", move |event_name: String, event: serde_json::Value| {
+            client_seen.lock().unwrap().push(format!("{} {}", event_name, event["text"]));
+            Ok(())
+        }).await.unwrap();
+        assert!(matches!(
+            events.on_plugin_pattern("greeter", "startup", |_: String, _: serde_json::Value| Ok(())).await,
+            Err(crate::events::EventError::InvalidPattern(_))
+        ));
+
+        let player_event = PlayerConnectedEvent {
+            player_id: PlayerId::new(),
+            connection_id: "test_conn".to_string(),
+            remote_addr: "127.0.0.1:8080".to_string(),
+            timestamp: crate::utils::current_timestamp(),
+        };
+        events.emit_core("player_connected", &player_event).await.unwrap();
+        events.emit_core("server_started", &player_event).await.unwrap();
+        events.emit_client("chat", "message", &serde_json::json!({ "text": "hi" })).await.unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), vec!["player_connected".to_string(), "chat:message \"hi\"".to_string()]);
+        assert_eq!(events.get_stats().await.events_emitted, 2);
+
+        // Pattern handlers are removed like any other handler
+        assert_eq!(events.remove_handlers("core:player_*").await, 1);
+        events.emit_core("player_connected", &player_event).await.unwrap();
+        assert_eq!(seen.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_handler_priority_order() {
+        use crate::HandlerPriority;
+
+        let events = EventSystem::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let record = |label: &'static str| {
+            let order = order.clone();
+            move |_: serde_json::Value| {
+                order.lock().unwrap().push(label);
+                Ok(())
+            }
+        };
+
+        events.on_core_with_priority("move", HandlerPriority::LOW, record("low")).await.unwrap();
+        events.on_core("move", record("normal")).await.unwrap();
+        events.on_core_with_priority("move", HandlerPriority::HIGH, record("anti_cheat")).await.unwrap();
+        events.on_core_with_priority("move", HandlerPriority::HIGH, record("audit")).await.unwrap();
+        let pattern_order = order.clone();
+        events.on_core_pattern("mo*", move |_: String, _: serde_json::Value| {
+            pattern_order.lock().unwrap().push("pattern");
+            Ok(())
+        }).await.unwrap();
+
+        for _ in 0..2 {
+            events.emit_core("move", &serde_json::json!({ "x": 1 })).await.unwrap();
+        }
+        let expected = ["anti_cheat", "audit", "normal", "pattern", "low"];
+        assert_eq!(*order.lock().unwrap(), [expected, expected].concat());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_priority_order_holds_under_concurrent_churn() {
+        use crate::HandlerPriority;
+
+        let events = Arc::new(EventSystem::new());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let record = |label: &'static str| {
+            let seen = seen.clone();
+            move |event: serde_json::Value| {
+                seen.lock().unwrap().push((event["n"].as_u64().unwrap(), label));
+                Ok(())
+            }
+        };
+        let rank = |label: &str| match label {
+            "anti_cheat" | "churn_high" => HandlerPriority::HIGH,
+            "low" | "churn_low" => HandlerPriority::LOW,
+            _ => HandlerPriority::NORMAL,
+        };
+
+        events.on_core_with_priority("move", HandlerPriority::HIGH, record("anti_cheat")).await.unwrap();
+        events.on_core("move", record("normal")).await.unwrap();
+        events.on_core_with_priority("move", HandlerPriority::LOW, record("low")).await.unwrap();
+
+        // One task keeps adding and removing handlers in every tier while others emit
+        let churn = {
+            let events = events.clone();
+            let (high, normal, low) = (record("churn_high"), record("churn_normal"), record("churn_low"));
+            tokio::spawn(async move {
+                for _ in 0..200 {
+                    events.register_as("churn", async {
+                        events.on_core_with_priority("move", HandlerPriority::HIGH, high.clone()).await.unwrap();
+                        events.on_core("move", normal.clone()).await.unwrap();
+                        events.on_core_with_priority("move", HandlerPriority::LOW, low.clone()).await.unwrap();
+                    }).await;
+                    tokio::task::yield_now().await;
+                    assert_eq!(events.remove_handlers_owned_by("churn").await, 3);
+                }
+            })
+        };
+        let emitters: Vec<_> = (0..4u64)
+            .map(|task| {
+                let events = events.clone();
+                tokio::spawn(async move {
+                    for i in 0..100u64 {
+                        events.emit_core("move", &serde_json::json!({ "n": task * 100 + i })).await.unwrap();
+                    }
+                })
+            })
+            .collect();
+        churn.await.unwrap();
+        for emitter in emitters {
+            emitter.await.unwrap();
+        }
+
+        // Every emission saw its handlers in priority order, registered ones in registration order
+        let seen = seen.lock().unwrap();
+        assert!(seen.iter().any(|(_, label)| label.starts_with("churn")), "no emission overlapped the churn");
+        for n in 0..400 {
+            let labels: Vec<&str> = seen.iter().filter(|(event, _)| *event == n).map(|(_, label)| *label).collect();
+            assert!(labels.windows(2).all(|pair| rank(pair[0]) >= rank(pair[1])), "emission {n} ran out of order: {labels:?}");
+            let stable: Vec<&str> = labels.iter().copied().filter(|label| !label.starts_with("churn")).collect();
+            assert_eq!(stable, ["anti_cheat", "normal", "low"], "emission {n}");
+            if labels.contains(&"churn_normal") {
+                assert!(labels.iter().position(|l| *l == "normal") < labels.iter().position(|l| *l == "churn_normal"));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_once_handlers() {
+        let events = EventSystem::new();
+        let (ready_tx, mut ready_rx) = tokio::sync::oneshot::channel();
+        events.on_core_once("inventory_ready", move |event: serde_json::Value| {
+            ready_tx.send(event).map_err(|_| crate::events::EventError::HandlerExecution("receiver gone".to_string()))
+        }).await.unwrap();
+        events.on_core("inventory_ready", |_: serde_json::Value| Ok(())).await.unwrap();
+        assert_eq!(events.get_handler_count("core:inventory_ready").await, 2);
+
+        events.emit_core("inventory_ready", &serde_json::json!({ "slots": 20 })).await.unwrap();
+        assert_eq!(ready_rx.try_recv().unwrap()["slots"], 20);
+        assert_eq!(events.get_handler_count("core:inventory_ready").await, 1);
+        assert_eq!(events.get_stats().await.total_handlers, 1);
+
+        // Later events only reach the permanent handler
+        events.emit_core("inventory_ready", &serde_json::json!({ "slots": 30 })).await.unwrap();
+        assert_eq!(events.get_handler_count("core:inventory_ready").await, 1);
+
+        events.on_plugin_once("quests", "accepted", |_: serde_json::Value| Ok(())).await.unwrap();
+        events.emit_plugin("quests", "accepted", &serde_json::json!({})).await.unwrap();
+        assert!(!events.has_handlers("plugin:quests:accepted").await);
+    }
+
+    #[tokio::test]
+    async fn test_emission_queue_backpressure() {
+        use crate::{EmissionQueueConfig, EventCategory, OverflowPolicy};
+
+        let mut events = EventSystem::new();
+        events.set_category_queue_config(EventCategory::Client, EmissionQueueConfig::new(1, 0, OverflowPolicy::Reject));
+        events.on_client_async("chat", "message", |_: serde_json::Value| Ok(())).await.unwrap();
+
+        // While another client emission holds the only slot, new ones are refused
+        let busy = events.emission_queue("client:chat:message").admit().await.unwrap();
+        let message = serde_json::json!({ "text": "hi" });
+        assert!(matches!(
+            events.emit_client("chat", "message", &message).await,
+            Err(crate::events::EventError::QueueFull(_))
+        ));
+        events.emit_core("server_started", &message).await.unwrap();
+
+        drop(busy);
+        events.emit_client("chat", "message", &message).await.unwrap();
+
+        let stats = events.get_stats().await;
+        let client = stats.emission_queues.iter().find(|queue| queue.category == EventCategory::Client).unwrap();
+        assert_eq!((client.depth, client.peak_depth, client.rejected), (0, 1, 1));
+        assert_eq!(stats.emission_queues.len(), EventCategory::ALL.len());
+    }
+
+    #[tokio::test]
+    async fn test_nested_emissions_dont_wait_on_their_own_slot() {
+        use crate::{EmissionQueueConfig, OverflowPolicy};
+
+        // A single slot, nothing may wait, and waiting blocks
+        let mut events = EventSystem::new();
+        events.set_emission_queue_config(EmissionQueueConfig::new(1, 0, OverflowPolicy::Block));
+        events.set_concurrency_limit("plugin:inventory:add_item", 1);
+        let events = Arc::new(events);
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let emitter = events.clone();
+        let record = received.clone();
+        events
+            .on_plugin("inventory", "add_item", move |event: serde_json::Value| {
+                record.lock().unwrap().push(event["item"].as_str().unwrap_or_default().to_string());
+                // Picking up a bundle adds its contents through the same key and category
+                if event["item"] == "bundle" {
+                    futures::executor::block_on(emitter.emit_plugin("inventory", "add_item", &serde_json::json!({ "item": "arrow" })))?;
+                    futures::executor::block_on(emitter.emit_plugin("inventory", "bundle_opened", &serde_json::json!({})))?;
+                }
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            events.emit_plugin("inventory", "add_item", &serde_json::json!({ "item": "bundle" })),
+        )
+        .await
+        .expect("nested emissions should not wait for the slot their emission holds")
+        .unwrap();
+        assert_eq!(*received.lock().unwrap(), ["bundle", "arrow"]);
+
+        // Every slot and dispatch mark is released afterwards
+        let stats = events.get_stats().await;
+        assert!(stats.emission_queues.iter().all(|queue| queue.in_flight == 0));
+        assert!(events.dispatching.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_request_response() {
+        use crate::events::EventError;
+        use std::time::Duration;
+
+        let events = EventSystem::new();
+        events.on_request("plugin:inventory", "get_items", |request: serde_json::Value| {
+            match request["player"].as_u64() {
+                Some(player) => Ok(serde_json::json!({ "player": player, "items": ["sword"] })),
+                None => Err(EventError::HandlerExecution("missing player".to_string())),
+            }
+        }).await.unwrap();
+
+        let items: serde_json::Value = events
+            .request("plugin:inventory", "get_items", serde_json::json!({ "player": 7 }))
+            .await
+            .unwrap();
+        assert_eq!(items, serde_json::json!({ "player": 7, "items": ["sword"] }));
+
+        let failed = events.request::<_, serde_json::Value>("plugin:inventory", "get_items", serde_json::json!({})).await;
+        assert!(matches!(failed, Err(EventError::HandlerExecution(message)) if message.contains("missing player")));
+
+        let unknown = events.request::<_, serde_json::Value>("plugin:inventory", "drop_items", serde_json::json!({})).await;
+        assert!(matches!(unknown, Err(EventError::HandlerNotFound(_))));
+
+        // A responder that never answers leaves the caller to time out
+        events.on_request("plugin:inventory", "stuck", |_: serde_json::Value| -> Result<serde_json::Value, EventError> {
+            panic!("responder crashed")
+        }).await.unwrap();
+        let stuck = events
+            .request_with_timeout::<_, serde_json::Value>("plugin:inventory", "stuck", serde_json::json!({}), Duration::from_millis(20))
+            .await;
+        assert!(matches!(stuck, Err(EventError::Timeout(_))));
+        assert!(events.pending_requests.is_empty());
+
+        // Requests don't reach plain plugin handlers for the same names
+        assert!(!events.has_handlers("plugin:inventory:get_items").await);
+    }
+
+    #[tokio::test]
+    async fn test_schema_validation() {
+        use crate::events::EventError;
+        use crate::EventSchema;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let events = EventSystem::new();
+        let received = Arc::new(AtomicUsize::new(0));
+        let counter = received.clone();
+        events.on_plugin("inventory", "pickup_item", move |_: serde_json::Value| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }).await.unwrap();
+
+        events.register_as("inventory", async {
+            events.register_plugin_schema("inventory", "pickup_item", EventSchema::json(serde_json::json!({
+                "type": "object",
+                "required": ["item_id"],
+                "properties": { "item_count": { "type": "integer", "minimum": 1 } }
+            }))).unwrap();
+            events.register_client_schema("chat", "message", EventSchema::json(serde_json::json!({
+                "type": "object",
+                "required": ["text"]
+            }))).unwrap();
+        }).await;
+
+        events.emit_plugin("inventory", "pickup_item", &serde_json::json!({ "item_id": 42, "item_count": 5 })).await.unwrap();
+        let rejected = events.emit_plugin("inventory", "pickup_item", &serde_json::json!({ "item_count": 0 })).await;
+        match rejected {
+            Err(EventError::SchemaViolation(rejection)) => {
+                assert_eq!(rejection.event_key, "plugin:inventory:pickup_item");
+                let paths: Vec<&str> = rejection.violations.iter().map(|violation| violation.path.as_str()).collect();
+                assert_eq!(paths, ["$", "$.item_count"]);
+            }
+            other => panic!("expected a schema violation, got {:?}", other),
+        }
+        assert_eq!(received.load(Ordering::SeqCst), 1);
+
+        // Client schemas describe the message data, not the context wrapped around it
+        let player_id = PlayerId::new();
+        events.emit_client_with_context("chat", "message", player_id, &serde_json::json!({ "text": "hi" })).await.unwrap();
+        assert!(matches!(
+            events.emit_client_with_context("chat", "message", player_id, &serde_json::json!({})).await,
+            Err(EventError::SchemaViolation(_))
+        ));
+        assert!(events.validate_client_message("chat", "message", &serde_json::json!({ "text": "hi" })).is_ok());
+        assert!(events.validate_client_message("chat", "message", &serde_json::json!([])).is_err());
+        assert!(events.validate_client_message("chat", "emote", &serde_json::json!([])).is_ok());
+
+        // Only the owning plugin may replace its schema, and unloading it drops the schema
+        let replaced = events
+            .register_as("intruder", async {
+                events.register_plugin_schema("inventory", "pickup_item", EventSchema::json(serde_json::json!(true)))
+            })
+            .await;
+        assert!(matches!(replaced, Err(EventError::SchemaConflict(_))));
+        events.remove_handlers_owned_by("inventory").await;
+        assert!(events.schema("plugin:inventory:pickup_item").is_none());
+        events.emit_plugin("inventory", "pickup_item", &serde_json::json!({})).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_emit_core_batch() {
+        let events = EventSystem::new();
+        let ticks = Arc::new(Mutex::new(Vec::new()));
+        let recorded = ticks.clone();
+        events.on_core("position_tick", move |event: serde_json::Value| {
+            recorded.lock().unwrap().push(event["tick"].as_u64().unwrap());
+            Ok(())
+        }).await.unwrap();
+        let (first_tx, mut first_rx) = tokio::sync::oneshot::channel();
+        events.on_core_once("position_tick", move |event: serde_json::Value| {
+            let _ = first_tx.send(event["tick"].as_u64().unwrap());
+            Ok(())
+        }).await.unwrap();
+
+        let batch: Vec<serde_json::Value> = (1..=3).map(|tick| serde_json::json!({ "tick": tick })).collect();
+        assert_eq!(events.emit_core_batch("position_tick", &batch).await.unwrap(), 3);
+        assert_eq!(*ticks.lock().unwrap(), [1, 2, 3]);
+
+        // The one-shot handler only sees the first event of the batch
+        assert_eq!(first_rx.try_recv().unwrap(), 1);
+        let stats = events.get_stats().await;
+        assert_eq!((stats.events_emitted, stats.total_handlers), (3, 1));
+
+        assert_eq!(events.emit_core_batch("unhandled", &batch).await.unwrap(), 0);
+        assert_eq!(events.emit_core_batch::<serde_json::Value>("position_tick", &[]).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_event_key_stats() {
+        use crate::events::EventError;
+
+        let events = EventSystem::new();
+        events.on_core("trade", |event: serde_json::Value| {
+            match event["gold"].as_u64() {
+                Some(_) => Ok(()),
+                None => Err(EventError::HandlerExecution("missing gold".to_string())),
+            }
+        }).await.unwrap();
+        events.on_core("trade", |_: serde_json::Value| Ok(())).await.unwrap();
+
+        events.emit_core("trade", &serde_json::json!({ "gold": 5 })).await.unwrap();
+        events.emit_core("trade", &serde_json::json!({})).await.unwrap();
+
+        let trade = events.get_stats_for_key("core:trade").unwrap();
+        assert_eq!((trade.invocations, trade.failures), (4, 1));
+        assert!(trade.last_error.unwrap().contains("missing gold"));
+        assert!(trade.p99_latency_us >= trade.p50_latency_us);
+
+        // Keys without handlers are never dispatched, so they have no stats
+        events.emit_core("unhandled", &serde_json::json!({})).await.unwrap();
+        let detailed = events.get_detailed_stats().await;
+        let keys: Vec<&str> = detailed.event_keys.iter().map(|key| key.event_key.as_str()).collect();
+        assert_eq!(keys, ["core:trade"]);
+    }
+
+    #[tokio::test]
+    async fn test_latched_events_reach_late_subscribers() {
+        use std::sync::{Arc, Mutex};
+
+        let events = EventSystem::new();
+        events.latch_core("region_started");
+        assert!(events.is_latched("core:region_started"));
+        events.emit_core("region_started", &serde_json::json!({ "size": 1 })).await.unwrap();
+        events.emit_core("region_started", &serde_json::json!({ "size": 2 })).await.unwrap();
+        events.emit_core("tick", &serde_json::json!({ "size": 3 })).await.unwrap();
+
+        // A handler registered afterwards receives the last latched value right away
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        events.on_core("region_started", move |event: serde_json::Value| {
+            sink.lock().unwrap().push(event["size"].as_u64().unwrap());
+            Ok(())
+        }).await.unwrap();
+        assert_eq!(*seen.lock().unwrap(), [2]);
+        let latched: Option<serde_json::Value> = events.latched_value("core:region_started").unwrap();
+        assert_eq!(latched.unwrap()["size"], 2);
+
+        // Keys that aren't latched are not replayed
+        let ticks = Arc::new(Mutex::new(0));
+        let sink = ticks.clone();
+        events.on_core("tick", move |_: serde_json::Value| {
+            *sink.lock().unwrap() += 1;
+            Ok(())
+        }).await.unwrap();
+        assert_eq!(*ticks.lock().unwrap(), 0);
+
+        // A one-shot handler is used up by the replay
+        let (once_tx, mut once_rx) = tokio::sync::oneshot::channel();
+        events.on_core_once("region_started", move |event: serde_json::Value| {
+            let _ = once_tx.send(event["size"].as_u64().unwrap());
+            Ok(())
+        }).await.unwrap();
+        assert_eq!(once_rx.try_recv().unwrap(), 2);
+        assert_eq!(events.get_stats().await.total_handlers, 2);
+
+        // Wildcard handlers receive the latched values their pattern matches
+        let matched = Arc::new(Mutex::new(Vec::new()));
+        let sink = matched.clone();
+        events.on_core_pattern("region_*", move |name: String, _: serde_json::Value| {
+            sink.lock().unwrap().push(name);
+            Ok(())
+        }).await.unwrap();
+        assert_eq!(*matched.lock().unwrap(), ["region_started"]);
+
+        // Once unlatched, late handlers only see new emissions
+        assert!(events.unlatch("core:region_started"));
+        assert!(!events.unlatch("core:region_started"));
+        let late = Arc::new(Mutex::new(0));
+        let sink = late.clone();
+        events.on_core("region_started", move |_: serde_json::Value| {
+            *sink.lock().unwrap() += 1;
+            Ok(())
+        }).await.unwrap();
+        assert_eq!(*late.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_filtered_handlers() {
+        use crate::{EventFilter, PlayerId};
+        use std::sync::{Arc, Mutex};
+
+        let events = EventSystem::new();
+        let player = PlayerId::new();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        events.on_core_filtered("player_moved", EventFilter::player(player), move |event: serde_json::Value| {
+            sink.lock().unwrap().push(event["x"].as_i64().unwrap());
+            Ok(())
+        }).await.unwrap();
+
+        events.emit_core("player_moved", &serde_json::json!({ "player_id": PlayerId::new(), "x": 1 })).await.unwrap();
+        events.emit_core("player_moved", &serde_json::json!({ "player_id": player, "x": 2 })).await.unwrap();
+        events.emit_core("player_moved", &serde_json::json!({ "x": 3 })).await.unwrap();
+        assert_eq!(*seen.lock().unwrap(), [2]);
+
+        // Declined events don't count as handler invocations
+        assert_eq!(events.get_stats_for_key("core:player_moved").unwrap().invocations, 1);
+        assert_eq!(events.events_processed(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrency_limit_serializes_key() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let events = Arc::new(EventSystem::new());
+        events.set_concurrency_limit("plugin:inventory:add_item", 1);
+        assert_eq!(events.concurrency_limit("plugin:inventory:add_item"), Some(1));
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (running_in, peak_in) = (running.clone(), peak.clone());
+        events.on_plugin("inventory", "add_item", move |_: serde_json::Value| {
+            let now = running_in.fetch_add(1, Ordering::SeqCst) + 1;
+            peak_in.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            running_in.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        }).await.unwrap();
+
+        let emits: Vec<_> = (0..8)
+            .map(|item| {
+                let events = events.clone();
+                tokio::spawn(async move {
+                    events.emit_plugin("inventory", "add_item", &serde_json::json!({ "item": item })).await
+                })
+            })
+            .collect();
+        for emit in emits {
+            emit.await.unwrap().unwrap();
+        }
+
+        assert_eq!(peak.load(Ordering::SeqCst), 1);
+        assert_eq!(events.events_processed(), 8);
+        assert!(events.clear_concurrency_limit("plugin:inventory:add_item"));
+        assert_eq!(events.concurrency_limit("plugin:inventory:add_item"), None);
+    }
+
+    #[tokio::test]
+    async fn test_client_context_reaches_every_handler() {
+        let mut events = EventSystem::new();
+        events.set_client_response_sender(Arc::new(MockResponseSender::new()));
+        let events = Arc::new(events);
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        for handler in 0..2 {
+            let seen = seen.clone();
+            events.on_client("chat", "message", move |event: serde_json::Value, player_id: PlayerId, client: ClientConnectionRef| {
+                assert_eq!(client.player_id, player_id);
+                seen.lock().unwrap().push((handler, player_id, event["data"]["text"].clone()));
+                Ok(())
+            }).await.unwrap();
+        }
+
+        let player_id = PlayerId::new();
+        events.emit_client_with_context("chat", "message", player_id, &serde_json::json!({ "text": "hi" })).await.unwrap();
+
+        let mut seen = seen.lock().unwrap().clone();
+        seen.sort_by_key(|(handler, _, _)| *handler);
+        assert_eq!(seen, vec![
+            (0, player_id, serde_json::json!("hi")),
+            (1, player_id, serde_json::json!("hi")),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_binary_codec_events() {
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct VoiceChunk {
+            speaker: PlayerId,
+            samples: Vec<u8>,
+        }
+
+        let events = EventSystem::new();
+        events.set_event_codec("plugin:voice:chunk", crate::EventCodec::Bincode);
+        events.latch_plugin("voice", "chunk");
+        assert_eq!(events.event_codec("plugin:voice:chunk"), crate::EventCodec::Bincode);
+        assert_eq!(events.event_codec("plugin:voice:other"), crate::EventCodec::Json);
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let exact = received.clone();
+        events.on_plugin("voice", "chunk", move |chunk: VoiceChunk| {
+            exact.lock().unwrap().push(("exact".to_string(), chunk));
+            Ok(())
+        }).await.unwrap();
+        let wildcard = received.clone();
+        events.on_plugin_pattern("voice", "*", move |event_name: String, chunk: VoiceChunk| {
+            wildcard.lock().unwrap().push((event_name, chunk));
+            Ok(())
+        }).await.unwrap();
+
+        let chunk = VoiceChunk { speaker: PlayerId::new(), samples: vec![0, 127, 255] };
+        events.emit_plugin("voice", "chunk", &chunk).await.unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec![
+            ("exact".to_string(), chunk.clone()),
+            ("voice:chunk".to_string(), chunk.clone()),
+        ]);
+        assert_eq!(events.latched_value::<VoiceChunk>("plugin:voice:chunk").unwrap(), Some(chunk));
+    }
+
+    #[tokio::test]
+    async fn test_namespace_enforcement() {
+        use crate::events::EventError;
+
+        let events = EventSystem::new();
+        let payload = serde_json::json!({ "text": "hi" });
+
+        // Reserved namespaces can't be registered or emitted as plugin namespaces
+        let registration = events.on_plugin("core", "player_connected", |_: serde_json::Value| Ok(())).await;
+        assert!(matches!(registration, Err(EventError::NamespaceViolation(_))));
+        assert!(events.on_plugin_pattern("gorc", "*", |_: String, _: serde_json::Value| Ok(())).await.is_err());
+        assert!(events.on_plugin_pattern("*", "*", |_: String, _: serde_json::Value| Ok(())).await.is_ok());
+        assert!(events.emit_plugin("gorc", "zone_entered", &payload).await.is_err());
+
+        // Unattributed emissions, e.g. from the host, are not restricted
+        events.set_plugin_namespaces("chat", &["guild".to_string()]);
+        assert_eq!(events.plugin_namespaces("chat"), Some(vec!["guild".to_string()]));
+        events.emit_core("player_connected", &payload).await.unwrap();
+        events.emit_plugin("inventory", "add_item", &payload).await.unwrap();
+
+        // Attributed emissions are held to the plugin's own and declared namespaces
+        let results = events
+            .attribute_emissions("chat", async {
+                vec![
+                    events.emit_plugin("chat", "message", &payload).await.is_ok(),
+                    events.emit_plugin("guild", "message", &payload).await.is_ok(),
+                    events.emit_client("guild", "message", &payload).await.is_ok(),
+                    events.emit_plugin("inventory", "add_item", &payload).await.is_ok(),
+                    events.emit_client("chat", "message", &payload).await.is_ok(),
+                    events.emit_core("player_connected", &payload).await.is_ok(),
+                ]
+            })
+            .await;
+        assert_eq!(results, vec![true, true, true, false, false, false]);
+
+        // Without declared namespaces a plugin is only kept out of reserved ones
+        assert!(events.clear_plugin_namespaces("chat"));
+        let results = events
+            .attribute_emissions("chat", async {
+                vec![
+                    events.emit_plugin("inventory", "add_item", &payload).await.is_ok(),
+                    events.emit_core("player_connected", &payload).await.is_ok(),
+                ]
+            })
+            .await;
+        assert_eq!(results, vec![true, false]);
+        events.emit_core("player_connected", &payload).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_plugins_may_request_kicks() {
+        use crate::events::{EventError, KickPlayerEvent};
+
+        let events = Arc::new(EventSystem::new());
+        let kicked = Arc::new(Mutex::new(Vec::new()));
+        let received = kicked.clone();
+        events
+            .on_core("kick_player", move |event: KickPlayerEvent| {
+                received.lock().unwrap().push(event.player_id);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        // A plugin-owned handler asks for a kick, and may not emit other core events
+        let player_id = PlayerId::new();
+        let emitter = events.clone();
+        let outcomes = Arc::new(Mutex::new(Vec::new()));
+        let recorded = outcomes.clone();
+        events
+            .register_as("anti_cheat", events.on_plugin("anti_cheat", "speed_violation", move |event: PlayerConnectedEvent| {
+                let kick = KickPlayerEvent {
+                    player_id: event.player_id,
+                    reason_code: "speed_hack".to_string(),
+                    message: None,
+                    requested_by: "anti_cheat".to_string(),
+                    timestamp: crate::utils::current_timestamp(),
+                };
+                let spoofed = futures::executor::block_on(emitter.emit_core("player_connected", &event));
+                let requested = futures::executor::block_on(emitter.emit_core("kick_player", &kick));
+                recorded.lock().unwrap().push((requested.is_ok(), matches!(spoofed, Err(EventError::NamespaceViolation(_)))));
+                Ok(())
+            }))
+            .await
+            .unwrap();
+
+        let violation = PlayerConnectedEvent {
+            player_id,
+            connection_id: "conn".to_string(),
+            remote_addr: "127.0.0.1:9000".to_string(),
+            timestamp: 0,
+        };
+        events.emit_plugin("anti_cheat", "speed_violation", &violation).await.unwrap();
+        assert_eq!(*outcomes.lock().unwrap(), vec![(true, true)]);
+        assert_eq!(*kicked.lock().unwrap(), vec![player_id]);
+    }
+
+    #[tokio::test]
+    async fn test_server_to_server_events() {
+        use crate::events::EventError;
+        use crate::{S2sEvent, ServerLink};
+
+        #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct MatchFound {
+            match_id: u32,
+        }
+
+        // Hands every event straight to the other server's event system
+        struct LoopbackLink {
+            peer: Arc<EventSystem>,
+        }
+
+        #[async_trait::async_trait]
+        impl ServerLink for LoopbackLink {
+            async fn send(&self, server: &str, event_name: &str, data: serde_json::Value) -> Result<(), EventError> {
+                if server != "matchmaker" {
+                    return Err(EventError::HandlerNotFound(format!("Unknown server {server}")));
+                }
+                self.peer.emit_s2s(event_name, &S2sEvent::new("region-east", data)).await
+            }
+
+            async fn connected_servers(&self) -> Vec<String> {
+                vec!["matchmaker".to_string()]
+            }
+        }
+
+        let matchmaker = Arc::new(EventSystem::new());
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        matchmaker
+            .on_s2s("match_found", move |event: S2sEvent<MatchFound>| {
+                sink.lock().unwrap().push((event.source, event.data));
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let mut region = EventSystem::new();
+        let found = MatchFound { match_id: 7 };
+        assert!(region.send_s2s("matchmaker", "match_found", &found).await.is_err());
+        region.set_server_link(Arc::new(LoopbackLink { peer: matchmaker.clone() }));
+        region.send_s2s("matchmaker", "match_found", &found).await.unwrap();
+        assert_eq!(region.broadcast_s2s("match_found", &found).await.unwrap(), 1);
+        assert!(region.send_s2s("chat", "match_found", &found).await.is_err());
+        assert_eq!(received.lock().unwrap().clone(), vec![("region-east".to_string(), found.clone()); 2]);
+
+        // Plugins can't pose as another server
+        let spoofed = matchmaker
+            .attribute_emissions("cheat", async { matchmaker.emit_s2s("match_found", &S2sEvent::new("region-east", found)).await })
+            .await;
+        assert!(matches!(spoofed, Err(EventError::NamespaceViolation(_))));
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+}
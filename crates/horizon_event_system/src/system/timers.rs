@@ -0,0 +1,175 @@
+/// Named interval timers that emit core events on their own schedule
+use crate::events::{EventError, TimerEvent};
+use crate::utils::current_timestamp;
+use super::core::EventSystem;
+use compact_str::CompactString;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::{Instant, MissedTickBehavior};
+use tracing::{info, warn};
+
+/// Handle to a running interval timer.
+///
+/// Dropping the handle does not stop the timer; call [`cancel`](Self::cancel)
+/// or [`EventSystem::cancel_interval`] with the timer's name.
+#[derive(Debug, Clone)]
+pub struct TimerHandle {
+    name: CompactString,
+    state: Arc<TimerState>,
+}
+
+#[derive(Debug, Default)]
+struct TimerState {
+    cancelled: AtomicBool,
+    wake: Notify,
+}
+
+impl TimerHandle {
+    /// Gets the name the timer was scheduled under
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Stops the timer. It emits no further events, even if one is due.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Release);
+        // Stores a permit, so the timer task wakes even if it isn't waiting yet
+        self.state.wake.notify_one();
+    }
+
+    /// Checks whether the timer has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::Acquire)
+    }
+}
+
+/// Interval timer registered with the event system
+#[derive(Debug)]
+pub(super) struct ScheduledTimer {
+    handle: TimerHandle,
+    owner: Option<CompactString>,
+}
+
+impl EventSystem {
+    /// Emits a [`TimerEvent`] as a core event every `period`, independent of the server tick rate.
+    ///
+    /// The first event is emitted one period after scheduling. Scheduling a
+    /// name that is already in use replaces the earlier timer. Timers
+    /// scheduled while a plugin registers its handlers are attributed to the
+    /// plugin and cancelled together with its handlers.
+    ///
+    /// The timer runs on the calling tokio runtime, so plugins should schedule
+    /// through [`ServerContext::schedule_interval`](crate::ServerContext::schedule_interval),
+    /// which runs on the host's runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Unique name of the timer, carried in each event
+    /// * `period` - Time between events (must be non-zero)
+    /// * `event_name` - Core event the timer emits
+    ///
+    /// # Returns
+    ///
+    /// A handle that cancels the timer, or an error if the period is zero or
+    /// no tokio runtime is running.
+    pub fn schedule_interval(self: &Arc<Self>, name: &str, period: Duration, event_name: &str) -> Result<TimerHandle, EventError> {
+        if period.is_zero() {
+            return Err(EventError::Other(format!("Timer '{}' needs a non-zero period", name)));
+        }
+        let runtime = tokio::runtime::Handle::try_current()
+            .map_err(|e| EventError::RuntimeError(format!("Cannot schedule timer '{}': {}", name, e)))?;
+
+        let handle = TimerHandle {
+            name: CompactString::new(name),
+            state: Arc::new(TimerState::default()),
+        };
+        let owner = self.registration_owner();
+        let replaced = self.timers.insert(
+            handle.name.clone(),
+            ScheduledTimer { handle: handle.clone(), owner },
+        );
+        if let Some(replaced) = replaced {
+            replaced.handle.cancel();
+        }
+
+        info!("⏲️ Scheduled timer '{}' emitting core:{} every {:?}", name, event_name, period);
+        runtime.spawn(run_timer(Arc::downgrade(self), handle.clone(), period, CompactString::new(event_name)));
+        Ok(handle)
+    }
+
+    /// Cancels an interval timer by name
+    ///
+    /// # Returns
+    ///
+    /// `true` if a timer with that name was running.
+    pub fn cancel_interval(&self, name: &str) -> bool {
+        match self.timers.remove(name) {
+            Some((_, timer)) => {
+                timer.handle.cancel();
+                info!("⏲️ Cancelled timer '{}'", name);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Gets the names of all running interval timers
+    pub fn active_intervals(&self) -> Vec<String> {
+        self.timers.iter().map(|timer| timer.key().to_string()).collect()
+    }
+
+    /// Cancels every interval timer attributed to the given owner
+    pub(super) fn cancel_intervals_owned_by(&self, owner: &str) -> usize {
+        let mut cancelled = 0;
+        self.timers.retain(|_, timer| {
+            let owned = timer.owner.as_deref() == Some(owner);
+            if owned {
+                timer.handle.cancel();
+                cancelled += 1;
+            }
+            !owned
+        });
+        cancelled
+    }
+}
+
+/// Emits timer events until the timer is cancelled or the event system is dropped
+async fn run_timer(events: std::sync::Weak<EventSystem>, handle: TimerHandle, period: Duration, event_name: CompactString) {
+    let mut ticker = tokio::time::interval_at(Instant::now() + period, period);
+    // A stalled runtime delays the next event instead of emitting a burst
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let period_ms = period.as_millis() as u64;
+    let mut tick = 0;
+
+    loop {
+        tokio::select! {
+            _ = handle.state.wake.notified() => {}
+            _ = ticker.tick() => {}
+        }
+        if handle.is_cancelled() {
+            break;
+        }
+        let Some(events) = events.upgrade() else {
+            break;
+        };
+
+        tick += 1;
+        let event = TimerEvent {
+            name: handle.name.to_string(),
+            tick,
+            period_ms,
+            timestamp: current_timestamp(),
+        };
+        if let Err(e) = events.emit_core(&event_name, &event).await {
+            warn!("⏲️ Timer '{}' failed to emit core:{}: {}", handle.name, event_name, e);
+        }
+    }
+
+    if let Some(events) = events.upgrade() {
+        events
+            .timers
+            .remove_if(handle.name.as_str(), |_, timer| Arc::ptr_eq(&timer.handle.state, &handle.state));
+    }
+}
@@ -324,6 +324,27 @@ impl Default for RegionBounds {
     }
 }
 
+impl RegionBounds {
+    /// Checks whether a position lies inside the region (inclusive)
+    pub fn contains(&self, position: Vec3) -> bool {
+        (self.min_x..=self.max_x).contains(&position.x)
+            && (self.min_y..=self.max_y).contains(&position.y)
+            && (self.min_z..=self.max_z).contains(&position.z)
+    }
+
+    /// Returns the region grown by `margin` on every side
+    pub fn expanded(&self, margin: f64) -> Self {
+        Self {
+            min_x: self.min_x - margin,
+            max_x: self.max_x + margin,
+            min_y: self.min_y - margin,
+            max_y: self.max_y + margin,
+            min_z: self.min_z - margin,
+            max_z: self.max_z + margin,
+        }
+    }
+}
+
 /// Enumeration of possible disconnection reasons.
 /// 
 /// This provides structured information about why a player disconnected,
@@ -356,6 +377,8 @@ pub enum AuthenticationStatus {
     Authenticated,
     /// Player authentication failed
     AuthenticationFailed,
+    /// Player is playing as an anonymous guest and may upgrade to an account in-session
+    Guest,
 }
 
 impl Default for AuthenticationStatus {
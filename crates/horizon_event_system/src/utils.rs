@@ -44,6 +44,21 @@ pub fn current_timestamp() -> u64 {
         .as_secs()
 }
 
+/// Returns the current Unix timestamp in milliseconds.
+///
+/// Used where second resolution is too coarse, such as the send time
+/// clients interpolate replication snapshots with.
+///
+/// # Panics
+///
+/// Panics if the system clock is set to a time before the Unix epoch.
+pub fn current_timestamp_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_millis() as u64
+}
+
 /// Creates a new Horizon event system instance.
 /// 
 /// This is the primary factory function for creating event system instances.
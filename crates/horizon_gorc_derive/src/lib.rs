@@ -0,0 +1,322 @@
+//! # Horizon GORC Derive
+//!
+//! Provides `#[derive(GorcObject)]`, which implements
+//! `horizon_event_system::GorcObject` for a struct whose replicated fields are
+//! tagged with the channel they belong to:
+//!
+//! ```rust,ignore
+//! use horizon_event_system::{GorcObject, PlayerId, Vec3};
+//!
+//! #[derive(Clone, Debug, GorcObject)]
+//! #[gorc(type_name = "Asteroid")]
+//! struct Asteroid {
+//!     #[gorc(channel = 0)]
+//!     position: Vec3,
+//!     #[gorc(channel = 0)]
+//!     velocity: Vec3,
+//!     #[gorc(channel = 3, radius = 2000.0)]
+//!     ore_remaining: u32,
+//!     // Untagged fields stay on the server
+//!     last_mined_by: Option<PlayerId>,
+//! }
+//! ```
+//!
+//! Every channel used by a field becomes a `ReplicationLayer` with the default
+//! zone configuration for that channel, unless `radius` or `frequency` override
+//! it. A layer's payload is a JSON object holding that channel's fields. The
+//! object position is read from the field named `position`, or from the field
+//! marked `#[gorc(position)]`.
+//!
+//! Layers that need more than a radius and frequency (LOD tiers, exit margins)
+//! can come from a function instead, with `#[gorc(layers = my_layers)]` on the
+//! struct; `#[gorc(snapshot)]` opts the object in to world snapshots and
+//! `#[gorc(tags)]` marks the `Vec<String>` field spatial queries filter on.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use std::collections::BTreeMap;
+use syn::meta::ParseNestedMeta;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Lit, LitStr};
+
+/// Number of replication channels supported by GORC (`MAX_CHANNELS`)
+const MAX_CHANNELS: u8 = 4;
+
+/// Fields and overrides collected for a single channel
+#[derive(Default)]
+struct ChannelConfig {
+    fields: Vec<Ident>,
+    radius: Option<f64>,
+    frequency: Option<f64>,
+}
+
+/// Derives `GorcObject` from `#[gorc(...)]` field attributes.
+///
+/// # Field attributes
+///
+/// * `channel = N` - Replicate the field on channel `N`
+/// * `radius = R` - Override the zone radius of the field's channel
+/// * `frequency = F` - Override the update frequency of the field's channel
+/// * `position` - Use this `Vec3` field as the object position
+///
+/// * `tags` - Use this `Vec<String>` field as the object's spatial query tags
+///
+/// # Container attributes
+///
+/// * `type_name = "Name"` - Type name used for GORC events (defaults to the struct name)
+/// * `layers = path` - Build the layers with `fn(&Self) -> Vec<ReplicationLayer>`
+///   instead of from the field attributes; payloads still follow the field channels
+/// * `snapshot` - Include the object in world snapshots, serialized with serde
+#[proc_macro_derive(GorcObject, attributes(gorc))]
+pub fn derive_gorc_object(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+
+    let mut type_name = LitStr::new(&name.to_string(), name.span());
+    let mut layers_fn: Option<syn::Path> = None;
+    let mut snapshot = false;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("gorc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("type_name") {
+                type_name = meta.value()?.parse()?;
+            } else if meta.path.is_ident("layers") {
+                layers_fn = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("snapshot") {
+                snapshot = true;
+            } else {
+                return Err(meta.error("unsupported gorc attribute, expected `type_name`, `layers` or `snapshot`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(name, "GorcObject can only be derived for structs"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(name, "GorcObject can only be derived for structs with named fields"));
+    };
+
+    let mut channels: BTreeMap<u8, ChannelConfig> = BTreeMap::new();
+    let mut position_field: Option<Ident> = None;
+    let mut position_marked = false;
+    let mut tags_field: Option<Ident> = None;
+
+    for field in &fields.named {
+        let ident = field.ident.clone().expect("named fields always have an ident");
+        let mut channel = None;
+        let mut radius = None;
+        let mut frequency = None;
+        let mut is_position = false;
+
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("gorc")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("channel") {
+                    let lit: syn::LitInt = meta.value()?.parse()?;
+                    let value = lit.base10_parse::<u8>()?;
+                    if value >= MAX_CHANNELS {
+                        return Err(Error::new(lit.span(), format!("GORC channels must be below {}", MAX_CHANNELS)));
+                    }
+                    channel = Some(value);
+                } else if meta.path.is_ident("radius") {
+                    radius = Some(parse_positive(&meta)?);
+                } else if meta.path.is_ident("frequency") {
+                    frequency = Some(parse_positive(&meta)?);
+                } else if meta.path.is_ident("position") {
+                    is_position = true;
+                } else if meta.path.is_ident("tags") {
+                    if tags_field.is_some() {
+                        return Err(meta.error("only one field can be marked #[gorc(tags)]"));
+                    }
+                    tags_field = Some(ident.clone());
+                } else {
+                    return Err(meta.error(
+                        "unsupported gorc field attribute, expected `channel`, `radius`, `frequency`, `position` or `tags`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+
+        if is_position {
+            if position_marked {
+                return Err(Error::new_spanned(&ident, "only one field can be marked #[gorc(position)]"));
+            }
+            position_field = Some(ident.clone());
+            position_marked = true;
+        } else if !position_marked && ident == "position" {
+            position_field = Some(ident.clone());
+        }
+
+        let Some(channel) = channel else {
+            if radius.is_some() || frequency.is_some() {
+                return Err(Error::new_spanned(&ident, "`radius` and `frequency` require a `channel`"));
+            }
+            continue;
+        };
+
+        let config = channels.entry(channel).or_default();
+        merge_override(&mut config.radius, radius, "radius", channel, &ident)?;
+        merge_override(&mut config.frequency, frequency, "frequency", channel, &ident)?;
+        config.fields.push(ident);
+    }
+
+    if channels.is_empty() {
+        return Err(Error::new_spanned(name, "GorcObject requires at least one field with #[gorc(channel = N)]"));
+    }
+    let Some(position_field) = position_field else {
+        return Err(Error::new_spanned(
+            name,
+            "GorcObject requires a `position` field or a field marked #[gorc(position)]",
+        ));
+    };
+
+    let krate = quote!(::horizon_event_system);
+
+    let layers = channels.iter().map(|(channel, config)| {
+        let properties = config.fields.iter().map(|field| field.to_string());
+        let radius = config.radius.map_or_else(|| quote!(radius), |radius| quote!(#radius));
+        let frequency = config.frequency.map_or_else(|| quote!(frequency), |frequency| quote!(#frequency));
+        quote! {
+            {
+                #[allow(unused_variables)]
+                let (radius, frequency, compression, _priority) = #krate::__get_default_zone_config(#channel);
+                #krate::ReplicationLayer::new(
+                    #channel,
+                    #radius,
+                    #frequency,
+                    vec![#(#properties.to_string()),*],
+                    compression,
+                )
+            }
+        }
+    });
+
+    let serialize_arms = channels.iter().map(|(channel, config)| {
+        let names = config.fields.iter().map(|field| field.to_string());
+        let fields = &config.fields;
+        quote! {
+            #channel => #krate::__LayerFields::new()
+                #(.field(#names, &self.#fields)?)*
+                .into_json(),
+        }
+    });
+
+    let get_layers = match &layers_fn {
+        Some(layers_fn) => quote!(#layers_fn(self)),
+        None => quote!(vec![#(#layers),*]),
+    };
+
+    let tags = tags_field.map(|field| {
+        quote! {
+            fn tags(&self) -> &[::std::string::String] {
+                &self.#field
+            }
+        }
+    });
+
+    let snapshot_state = snapshot.then(|| {
+        quote! {
+            fn snapshot_state(&self) -> ::std::option::Option<#krate::serde_json::Value> {
+                #krate::serde_json::to_value(self).ok()
+            }
+        }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::GorcObject for #name #ty_generics #where_clause {
+            fn type_name(&self) -> &str {
+                #type_name
+            }
+
+            fn position(&self) -> #krate::Vec3 {
+                self.#position_field
+            }
+
+            fn get_priority(&self, observer_pos: #krate::Vec3) -> #krate::ReplicationPriority {
+                let distance = self.position().distance(observer_pos);
+                match distance {
+                    d if d < 100.0 => #krate::ReplicationPriority::Critical,
+                    d if d < 300.0 => #krate::ReplicationPriority::High,
+                    d if d < 1000.0 => #krate::ReplicationPriority::Normal,
+                    _ => #krate::ReplicationPriority::Low,
+                }
+            }
+
+            fn serialize_for_layer(
+                &self,
+                layer: &#krate::ReplicationLayer,
+            ) -> ::std::result::Result<::std::vec::Vec<u8>, ::std::boxed::Box<dyn ::std::error::Error>> {
+                match layer.channel {
+                    #(#serialize_arms)*
+                    _ => Err("Invalid channel for this object type".into()),
+                }
+            }
+
+            fn get_layers(&self) -> ::std::vec::Vec<#krate::ReplicationLayer> {
+                #get_layers
+            }
+
+            #tags
+
+            #snapshot_state
+
+            fn update_position(&mut self, new_position: #krate::Vec3) {
+                self.#position_field = new_position;
+            }
+
+            fn as_any(&self) -> &dyn ::std::any::Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn ::std::any::Any {
+                self
+            }
+
+            fn clone_object(&self) -> ::std::boxed::Box<dyn #krate::GorcObject> {
+                ::std::boxed::Box::new(self.clone())
+            }
+        }
+    })
+}
+
+/// Parses a positive numeric attribute value, accepting integer or float literals
+fn parse_positive(meta: &ParseNestedMeta) -> syn::Result<f64> {
+    let lit: Lit = meta.value()?.parse()?;
+    let value = match &lit {
+        Lit::Float(float) => float.base10_parse::<f64>()?,
+        Lit::Int(int) => int.base10_parse::<f64>()?,
+        _ => return Err(Error::new(lit.span(), "expected a number")),
+    };
+    if value <= 0.0 {
+        return Err(Error::new(lit.span(), "value must be positive"));
+    }
+    Ok(value)
+}
+
+/// Applies a per-field channel override, rejecting conflicting values
+fn merge_override(
+    current: &mut Option<f64>,
+    value: Option<f64>,
+    setting: &str,
+    channel: u8,
+    field: &Ident,
+) -> syn::Result<()> {
+    match (*current, value) {
+        (Some(existing), Some(value)) if existing != value => Err(Error::new(
+            field.span(),
+            format!("conflicting {} for channel {}: {} and {}", setting, channel, existing, value),
+        )),
+        (None, Some(value)) => {
+            *current = Some(value);
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
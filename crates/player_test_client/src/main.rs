@@ -15,7 +15,7 @@ use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use tokio::time::{interval, sleep};
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_tungstenite::{connect_async, tungstenite::{client::IntoClientRequest, Message}};
 use tracing::{info, warn, error};
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -61,6 +61,37 @@ struct Args {
     /// Log file path for JSON messages
     #[arg(long, default_value = "horizon_messages.log")]
     log_file: String,
+    
+    /// Encoding of messages sent to the server
+    #[arg(long, value_enum, default_value = "json")]
+    encoding: Encoding,
+}
+
+/// Wire encoding of client-to-server messages, negotiated as a WebSocket subprotocol
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq)]
+enum Encoding {
+    /// JSON text frames
+    Json,
+    /// MessagePack binary frames
+    Msgpack,
+}
+
+impl Encoding {
+    /// Subprotocol offered to the server, if the encoding needs one
+    fn subprotocol(self) -> Option<&'static str> {
+        match self {
+            Encoding::Json => None,
+            Encoding::Msgpack => Some("horizon.msgpack"),
+        }
+    }
+
+    /// Builds the frame carrying a message, given its JSON form
+    fn frame(self, message: &GorcClientMessage, json: String) -> Result<Message, Box<dyn std::error::Error + Send + Sync>> {
+        match self {
+            Encoding::Json => Ok(Message::Text(json)),
+            Encoding::Msgpack => Ok(Message::Binary(rmp_serde::to_vec_named(message)?)),
+        }
+    }
 }
 
 /// GORC event message format for client-to-server communication
@@ -94,6 +125,8 @@ struct GorcReplicationValidator {
     missing_events: Vec<String>,
     /// Events that were received but shouldn't have been
     extra_events: Vec<String>,
+    /// First and latest server tick stamped on received replication messages
+    tick_range: Option<(u64, u64)>,
 }
 
 impl GorcReplicationValidator {
@@ -104,9 +137,18 @@ impl GorcReplicationValidator {
             player_positions: std::collections::HashMap::new(),
             missing_events: Vec::new(),
             extra_events: Vec::new(),
+            tick_range: None,
         }
     }
 
+    /// Record the server tick stamped on a received replication message
+    fn observe_tick(&mut self, tick: u64) {
+        self.tick_range = Some(match self.tick_range {
+            Some((first, last)) => (first.min(tick), last.max(tick)),
+            None => (tick, tick),
+        });
+    }
+
     /// Update a player's position for distance-based validation
     fn update_player_position(&mut self, player_id: PlayerId, position: Vec3) {
         self.player_positions.insert(player_id, position);
@@ -162,15 +204,20 @@ impl GorcReplicationValidator {
         let total_received = self.received_events.values().sum::<u32>();
         let missing_count = self.missing_events.len();
         let extra_count = self.extra_events.len();
+        let ticks = match self.tick_range {
+            Some((first, last)) => format!("{}..={}", first, last),
+            None => "none".to_string(),
+        };
 
         format!(
             "🧪 GORC Replication Test Results for Player {}:\n\
              📊 Total Expected: {}, Total Received: {}\n\
              ❌ Missing Events: {} | ➕ Extra Events: {}\n\
+             🕒 Server Ticks Observed: {}\n\
              📋 Missing Details: {:#?}\n\
              📋 Extra Details: {:#?}",
             player_id, total_expected, total_received, missing_count, extra_count,
-            self.missing_events, self.extra_events
+            ticks, self.missing_events, self.extra_events
         )
     }
 }
@@ -321,6 +368,7 @@ impl SimulatedPlayer {
             message: message.to_string(),
             channel: "local_space".to_string(), // Space MMO context
             target_player: None,
+            locale: None,
         };
 
         Some(GorcClientMessage {
@@ -444,8 +492,12 @@ async fn simulate_player(
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     info!("🎮 Player {} starting simulation at {:?}", player_id, spawn_position);
     
-    // Connect to WebSocket server
-    let (ws_stream, _) = connect_async(&ws_url).await?;
+    // Connect to WebSocket server, offering the binary encoding if one was chosen
+    let mut request = ws_url.as_str().into_client_request()?;
+    if let Some(subprotocol) = args.encoding.subprotocol() {
+        request.headers_mut().insert("Sec-WebSocket-Protocol", subprotocol.parse()?);
+    }
+    let (ws_stream, _) = connect_async(request).await?;
     let (mut ws_sender, mut ws_receiver) = ws_stream.split();
     
     let mut player = SimulatedPlayer::new(player_id, spawn_position);
@@ -495,6 +547,11 @@ async fn simulate_player(
                                         Ok(json) => {
                                             info!("📋 Player {} parsed JSON structure: {:#}", player_id, json);
 
+                                            // Replication messages carry the server tick they were produced on
+                                            if let Some(tick) = json.get("tick").and_then(|v| v.as_u64()) {
+                                                player.replication_validator.observe_tick(tick);
+                                            }
+
                                             // Check message type
                                             if let Some(msg_type) = json.get("type").and_then(|v| v.as_str()) {
                                                 match msg_type {
@@ -643,7 +700,7 @@ async fn simulate_player(
                         // Log outgoing message details  
                         info!("📤 Player {} sending movement (event #{}) to server: {}", player_id, sent_events + 1, json);
                         
-                        if let Err(e) = ws_sender.send(Message::Text(json)).await {
+                        if let Err(e) = ws_sender.send(args.encoding.frame(&move_msg, json)?).await {
                             error!("❌ Player {} failed to send movement: {}", player_id, e);
                             break;
                         }
@@ -675,7 +732,7 @@ async fn simulate_player(
                     // Log outgoing message to file
                     message_logger.log_sent_message(player_id, &json).await;
                     
-                    if let Err(e) = ws_sender.send(Message::Text(json)).await {
+                    if let Err(e) = ws_sender.send(args.encoding.frame(&chat_msg, json)?).await {
                         error!("❌ Player {} failed to send chat: {}", player_id, e);
                         break;
                     }
@@ -692,7 +749,7 @@ async fn simulate_player(
                     // Log outgoing message to file
                     message_logger.log_sent_message(player_id, &json).await;
                     
-                    if let Err(e) = ws_sender.send(Message::Text(json)).await {
+                    if let Err(e) = ws_sender.send(args.encoding.frame(&attack_msg, json)?).await {
                         error!("❌ Player {} failed to send combat action: {}", player_id, e);
                         break;
                     }
@@ -709,7 +766,7 @@ async fn simulate_player(
                     // Log outgoing message to file
                     message_logger.log_sent_message(player_id, &json).await;
                     
-                    if let Err(e) = ws_sender.send(Message::Text(json)).await {
+                    if let Err(e) = ws_sender.send(args.encoding.frame(&scan_msg, json)?).await {
                         error!("❌ Player {} failed to send ship scan: {}", player_id, e);
                         break;
                     }
@@ -769,6 +826,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     info!("   • Weapon Fire: {:.1} shots/min", args.attack_freq);
     info!("   • Mission Duration: {} seconds", args.duration);
     info!("   • Control Server: {}", args.url);
+    info!("   • Message Encoding: {:?}", args.encoding);
     
     if args.log_messages {
         info!("📄 JSON Message logging enabled: {}", args.log_file);
@@ -797,6 +855,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
             world_size: args.world_size,
             log_messages: args.log_messages,
             log_file: args.log_file.clone(),
+            encoding: args.encoding,
         };
         
         let logger_clone = message_logger.clone();
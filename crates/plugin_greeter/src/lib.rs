@@ -6,7 +6,7 @@ use horizon_event_system::{
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tracing::{info, debug};
+use tracing::{info, debug, warn};
 
 // ============================================================================
 // Sample Plugin 1: Greeter Plugin
@@ -152,17 +152,21 @@ impl SimplePlugin for GreeterPlugin {
 
         info!("Setting up inventory!");
 
-        events
-            .emit_plugin(
-                "InventorySystem",
+        // Inventory setup is optional for the greeter, so report the outcome without failing init
+        match events
+            .request::<_, serde_json::Value>(
+                "plugin:InventorySystem",
                 "SetupInventory",
-                &serde_json::json!({
+                serde_json::json!({
                     "slot_count": 8,
                     "inventory_count": 2
                 }),
             )
             .await
-            .map_err(|e| PluginError::InitializationFailed(e.to_string()))?;
+        {
+            Ok(inventory) => info!("🎒 Inventory ready: {}", inventory),
+            Err(e) => warn!("⚠️ Inventory setup failed: {}", e),
+        }
 
         {
             let time = Utc::now();
@@ -1,10 +1,14 @@
 use async_trait::async_trait;
 use horizon_event_system::{
     create_simple_plugin, current_timestamp, ClientEventWrapper, EventSystem, LogLevel, PlayerId,
-    PlayerMovementEvent, PluginError, Position, ServerContext, SimplePlugin,
+    PlayerMovementEvent, PluginError, Position, ServerContext, SimplePlugin, TimerEvent,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
+
+/// How often the logger emits its periodic activity summary
+const SUMMARY_INTERVAL: Duration = Duration::from_secs(30);
 
 // Define PlayerChatEvent and PlayerJumpEvent for simulation/demo purposes
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -199,6 +203,7 @@ impl SimplePlugin for LoggerPlugin {
                                 player_id: wrapper.player_id,
                                 old_position: None,
                                 new_position,
+                                velocity: None,
                                 timestamp: current_timestamp(),
                             };
 
@@ -263,13 +268,13 @@ impl SimplePlugin for LoggerPlugin {
                 PluginError::ExecutionError(e.to_string())
             })?;
 
-        // Listen to any plugin events (wildcard-style)
+        // Listen to every event sent to the logger, whatever its name
         let context_clone = context.clone();
         events
-            .on_plugin("logger", "activity", move |event: serde_json::Value| {
+            .on_plugin_pattern("logger", "*", move |event_name: String, event: serde_json::Value| {
                 context_clone.log(
                     LogLevel::Info,
-                    format!("📝 LoggerPlugin: 🌐 GENERAL ACTIVITY - {:?}", event).as_str(),
+                    format!("📝 LoggerPlugin: 🌐 GENERAL ACTIVITY ({}) - {:?}", event_name, event).as_str(),
                 );
                 Ok(())
             })
@@ -326,46 +331,38 @@ impl SimplePlugin for LoggerPlugin {
             "📝 LoggerPlugin: ✅ Now monitoring all server events!",
         );
 
-        // Set up a periodic summary using async event emission with tokio handle from context
-        let events_clone = context.events();
-        let events_ref = events_clone.clone();
+        // Emit a periodic summary from a timer running independently of the tick rate
+        let events_ref = context.events();
         let luminal_handle = context.luminal_handle();
         let context_clone = context.clone();
 
-        use std::sync::atomic::{AtomicU32, Ordering};
-        use std::sync::Arc;
-        let tick_counter = Arc::new(AtomicU32::new(0));
-        let tick_counter_clone = tick_counter.clone();
-
-        events_clone
-            .on_core_async("server_tick", move |_event: serde_json::Value| {
-                context_clone.log(LogLevel::Trace, "📝 LoggerPlugin: 🕒 Server tick received, updating activity log...");
+        events
+            .on_core_async("logger_summary_due", move |event: TimerEvent| {
                 let events_inner = events_ref.clone();
-                let tick_counter = tick_counter_clone.clone();
                 let context_inner = context_clone.clone();
 
-                // Use the tokio runtime handle passed from the main process via context
+                // Use the runtime handle passed from the main process via context
                 luminal_handle.spawn(async move {
-                    // Emit periodic summary every 30 server ticks (assuming ~1 tick per second)
-                    let tick = tick_counter.fetch_add(1, Ordering::SeqCst) + 1;
-                    if tick % 2 == 0 {
-                        let summary_count = tick / 30;
-                        let _ = events_inner.emit_plugin("logger", "activity_logged", &serde_json::json!({
-                                "activity_type": "periodic_summary",
-                                "details": format!("Summary #{} - Logger still active", summary_count),
-                                "timestamp": current_timestamp()
-                            })).await;
-                            context_inner.log(LogLevel::Trace, format!("📝 LoggerPlugin: 📊 Periodic Summary #{} - Still logging events...", summary_count).as_str());
-                        }
-                    });
-                    Ok(())
-                })
-                .await.unwrap();
+                    let _ = events_inner.emit_plugin("logger", "activity_logged", &serde_json::json!({
+                        "activity_type": "periodic_summary",
+                        "details": format!("Summary #{} - Logger still active", event.tick),
+                        "timestamp": current_timestamp()
+                    })).await;
+                    context_inner.log(LogLevel::Trace, format!("📝 LoggerPlugin: 📊 Periodic Summary #{} - Still logging events...", event.tick).as_str());
+                });
+                Ok(())
+            })
+            .await
+            .map_err(|e| PluginError::InitializationFailed(e.to_string()))?;
+        context
+            .schedule_interval("logger_summary", SUMMARY_INTERVAL, "logger_summary_due")
+            .map_err(|e| PluginError::InitializationFailed(e.to_string()))?;
         Ok(())
     }
 
     async fn on_shutdown(&mut self, context: Arc<dyn ServerContext>) -> Result<(), PluginError> {
         let uptime = self.start_time.elapsed().unwrap_or_default();
+        context.cancel_interval("logger_summary");
 
         context.log(
             LogLevel::Info,
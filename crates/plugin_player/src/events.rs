@@ -12,6 +12,7 @@
 //! ### Movement Events (Channel 0)
 //! High-frequency position and velocity updates for real-time movement:
 //! - [`PlayerMoveRequest`] - Player movement and position updates
+//! - [`PlayerMoveUpdate`] - Position updates replicated to nearby players
 //!
 //! ### Combat Events (Channel 1)  
 //! Weapon firing and attack coordination:
@@ -74,6 +75,7 @@
 //!     message: "Hello, fellow pilots!".to_string(),
 //!     channel: "general".to_string(),
 //!     target_player: None,
+//!     locale: None,
 //! };
 //! ```
 
@@ -146,6 +148,39 @@ pub struct PlayerMoveRequest {
     pub client_timestamp: DateTime<Utc>,
 }
 
+/// Position update replicated to nearby players on GORC channel 0.
+///
+/// Wire-only form of a [`PlayerMoveRequest`]: binary clients receive the
+/// position and velocity as `f32`s, while JSON clients see the same fields
+/// as the request. Server-side code keeps using the full-precision request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerMoveUpdate {
+    /// ID of the player that moved
+    pub player_id: PlayerId,
+    /// New position in world coordinates
+    #[serde(with = "horizon_event_system::gorc::wire::compact_vec3")]
+    pub new_position: Vec3,
+    /// Current velocity vector for prediction
+    #[serde(with = "horizon_event_system::gorc::wire::compact_vec3")]
+    pub velocity: Vec3,
+    /// Current movement state (0=idle, 1=walking, 2=running, etc.)
+    pub movement_state: i32,
+    /// Time the server accepted the movement
+    pub client_timestamp: DateTime<Utc>,
+}
+
+impl From<&PlayerMoveRequest> for PlayerMoveUpdate {
+    fn from(request: &PlayerMoveRequest) -> Self {
+        Self {
+            player_id: request.player_id,
+            new_position: request.new_position,
+            velocity: request.velocity,
+            movement_state: request.movement_state,
+            client_timestamp: request.client_timestamp,
+        }
+    }
+}
+
 /// Player attack request event for GORC channel 1.
 ///
 /// This structure represents a client request to perform a combat action, such as
@@ -269,6 +304,7 @@ pub struct PlayerAttackRequest {
 ///     message: "Looking for trading partners near Station Alpha".to_string(),
 ///     channel: "trade".to_string(),
 ///     target_player: None,
+///     locale: None,
 /// };
 ///
 /// // Direct private message
@@ -277,6 +313,7 @@ pub struct PlayerAttackRequest {
 ///     message: "Meet me at the asteroid belt".to_string(),
 ///     channel: "private".to_string(),
 ///     target_player: Some(PlayerId(17)),
+///     locale: None,
 /// };
 ///
 /// // Emergency distress signal
@@ -285,6 +322,7 @@ pub struct PlayerAttackRequest {
 ///     message: "MAYDAY! Under attack at coordinates 120,50,30!".to_string(),
 ///     channel: "emergency".to_string(),
 ///     target_player: None,
+///     locale: None,
 /// };
 /// ```
 ///
@@ -303,6 +341,10 @@ pub struct PlayerChatRequest {
     pub channel: String,
     /// Target player for direct messages (None for broadcast)
     pub target_player: Option<PlayerId>,
+    /// Language of the message, such as "en" or "pt-BR", picking the content
+    /// filter's word lists (None applies every locale's)
+    #[serde(default)]
+    pub locale: Option<String>,
 }
 
 /// Player block change request event for GORC channel 1.
@@ -389,4 +431,43 @@ pub struct PlayerBlockChangeRequest {
     pub new_tile: u8,
     /// Client-side timestamp when the change was initiated
     pub client_timestamp: DateTime<Utc>,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use horizon_event_system::EventCodec;
+
+    fn move_request() -> PlayerMoveRequest {
+        PlayerMoveRequest {
+            player_id: PlayerId::new(),
+            new_position: Vec3::new(1_000_000.123456789, -42.000000001, 0.1),
+            velocity: Vec3::new(8.000000001, 0.0, -4.25),
+            movement_state: 2,
+            client_timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_move_request_keeps_full_precision_through_bincode() {
+        let request = move_request();
+        let encoded = EventCodec::Bincode.encode(&request).unwrap();
+        let decoded: PlayerMoveRequest = EventCodec::decode(&encoded).unwrap();
+
+        assert_eq!(decoded.new_position, request.new_position);
+        assert_eq!(decoded.velocity, request.velocity);
+    }
+
+    #[test]
+    fn test_move_update_is_compact_on_the_wire() {
+        let request = move_request();
+        let update = PlayerMoveUpdate::from(&request);
+        let full = horizon_event_system::gorc::wire::encode_bincode(&request).unwrap();
+        let compact = horizon_event_system::gorc::wire::encode_bincode(&update).unwrap();
+        assert_eq!(full.len() - compact.len(), 6 * 4);
+
+        // JSON clients see the same fields as the request
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(json["new_position"]["x"], request.new_position.x);
+    }
+}
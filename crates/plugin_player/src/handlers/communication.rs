@@ -31,13 +31,13 @@
 //! 
 //! - **Player Ownership**: Players can only send messages as themselves
 //! - **Rate Limiting**: Prevents spam and message flooding (future enhancement)
-//! - **Content Filtering**: Basic profanity and abuse prevention (future enhancement)
+//! - **Content Filtering**: The server's content filter censors or drops messages and mutes abusive players
 //! - **Message Length**: Enforced maximum message length for network efficiency
 
 use std::sync::Arc;
 use horizon_event_system::{
     EventSystem, PlayerId, GorcEvent, GorcObjectId, ClientConnectionRef, ObjectInstance,
-    EventError,
+    EventError, ContentFilter, ContentVerdict,
 };
 use tracing::{debug, error};
 use serde_json;
@@ -57,6 +57,7 @@ use crate::events::PlayerChatRequest;
 /// - `_object_instance`: Player's object instance (available for position-based features)
 /// - `events`: Event system for broadcasting communication events
 /// - `luminal_handle`: Async runtime handle for background processing
+/// - `content_filter`: The server's content filter, if it has one
 /// 
 /// # Returns
 /// 
@@ -99,6 +100,7 @@ pub async fn handle_communication_request(
     _object_instance: &mut ObjectInstance,
     events: Arc<EventSystem>,
     luminal_handle: luminal::Handle,
+    content_filter: Option<&dyn ContentFilter>,
 ) -> Result<(), EventError> {
     debug!("📡 GORC: Received client communication request from ship {}: {:?}", 
         client_player, gorc_event);
@@ -133,6 +135,7 @@ pub async fn handle_communication_request(
         error!("📡 GORC: ❌ Message validation failed: {}", reason);
         return Err(EventError::HandlerExecution(reason));
     }
+    let chat_data = apply_content_filter(chat_data, content_filter)?;
     
     // Broadcast communication to nearby ships
     let chat_data_owned = chat_data.clone();
@@ -157,6 +160,7 @@ pub fn handle_communication_request_sync(
     _object_instance: &mut ObjectInstance,
     events: Arc<EventSystem>,
     luminal_handle: luminal::Handle,
+    content_filter: Option<&dyn ContentFilter>,
 ) -> Result<(), EventError> {
     debug!("📡 GORC: Received client communication request from ship {}: {:?}", 
         client_player, gorc_event);
@@ -191,6 +195,7 @@ pub fn handle_communication_request_sync(
         error!("📡 GORC: ❌ Message validation failed: {}", reason);
         return Err(EventError::HandlerExecution(reason));
     }
+    let chat_data = apply_content_filter(chat_data, content_filter)?;
     
     // Broadcast communication to nearby ships
     let object_id_str = gorc_event.object_id.clone();
@@ -288,12 +293,47 @@ async fn broadcast_communication(
     }
 }
 
+/// Runs a chat message through the server's content filter.
+/// 
+/// # Parameters
+/// 
+/// - `chat_data`: The validated chat request
+/// - `content_filter`: The server's content filter, if it has one
+/// 
+/// # Returns
+/// 
+/// The request to broadcast, with its message censored if the filter asked
+/// for it, or an `EventError` if the message was dropped or its sender is muted.
+pub fn apply_content_filter(
+    mut chat_data: PlayerChatRequest,
+    content_filter: Option<&dyn ContentFilter>,
+) -> Result<PlayerChatRequest, EventError> {
+    let Some(content_filter) = content_filter else {
+        return Ok(chat_data);
+    };
+
+    match content_filter.check(chat_data.player_id, &chat_data.message, chat_data.locale.as_deref()) {
+        ContentVerdict::Allow => Ok(chat_data),
+        ContentVerdict::Censor(message) => {
+            chat_data.message = message;
+            Ok(chat_data)
+        }
+        ContentVerdict::Drop { rule } => {
+            debug!("📡 GORC: Dropped message from ship {} breaking content rule '{}'", chat_data.player_id, rule);
+            Err(EventError::HandlerExecution(format!("Message breaks content rule '{rule}'")))
+        }
+        ContentVerdict::Mute { rule, until } => {
+            debug!("📡 GORC: Dropped message from ship {}, muted until {} for content rule '{}'", chat_data.player_id, until, rule);
+            Err(EventError::HandlerExecution(format!("Player is muted until {until} for content rule '{rule}'")))
+        }
+    }
+}
+
 /// Validates message content for appropriate communication.
 /// 
 /// This function performs content validation and filtering:
 /// - Message length limits
 /// - Channel-appropriate content validation
-/// - Basic profanity filtering (future enhancement)
 /// - Spam detection (future enhancement)
 /// 
 /// # Parameters
@@ -328,7 +368,6 @@ pub fn validate_message_content(message: &str, channel: &str) -> Result<(), Stri
     }
     
     // Future enhancements:
-    // - Profanity filtering
     // - Spam detection and rate limiting
     // - Content moderation and reporting
     // - Language detection and translation
@@ -112,6 +112,9 @@ pub async fn handle_player_connected(
         
         // Register the player object with GORC spatial system
         let gorc_id = gorc_instances.register_object(player, spawn_position).await;
+
+        // Record ownership so GORC can resolve this player's object for message routing
+        gorc_instances.register_object_owner(event.player_id, gorc_id).await;
         
         // Store the GORC ID for future operations (movement, cleanup, etc.)
         players_clone.insert(event.player_id, gorc_id);
@@ -16,6 +16,7 @@
 //! All movement requests undergo strict validation:
 //! - **Authentication**: Only authenticated connections can request movement
 //! - **Ownership**: Players can only move their own ships  
+//! - **Authority**: Only the ship's current authority can push its state
 //! - **Bounds Checking**: Movement deltas are validated for reasonable values
 //! - **Anti-Cheat**: Large teleportation attempts are rejected
 //! 
@@ -42,7 +43,7 @@ use horizon_event_system::{
 use luminal::Handle;
 use tracing::{debug, error};
 use serde_json;
-use crate::events::PlayerMoveRequest;
+use crate::events::{PlayerMoveRequest, PlayerMoveUpdate};
 
 /// Handles incoming player movement requests from GORC clients on channel 0.
 /// 
@@ -126,6 +127,15 @@ pub async fn handle_movement_request(
         ));
     }
     
+    // SECURITY: Only the object's authority may push state for it
+    if !object_instance.has_authority(client_player) {
+        error!("🚀 GORC: ❌ Player {} is not the authority for ship {}",
+            client_player, gorc_event.object_id);
+        return Err(EventError::HandlerExecution(
+            "Player is not the authority for this object".to_string()
+        ));
+    }
+    
     // Update the object instance position directly (this is the authoritative update)
     object_instance.object.update_position(move_data.new_position);
     debug!("🚀 GORC: ✅ Updated ship position for {} to {:?}", 
@@ -194,6 +204,16 @@ pub fn handle_movement_request_sync(
     }
     debug!("🚀 STEP 6: ✅ Player ownership validated");
 
+    // SECURITY: Only the object's authority may push state for it
+    if !object_instance.has_authority(client_player) {
+        error!("🚀 STEP 6: ❌ Player {} is not the authority for ship {}",
+            client_player, gorc_event.object_id);
+        return Err(EventError::HandlerExecution(
+            "Player is not the authority for this object".to_string()
+        ));
+    }
+    debug!("🚀 STEP 6: ✅ Player authority validated");
+
     // Update the object instance position locally (for immediate response)
     object_instance.object.update_position(move_data.new_position);
     debug!("🚀 STEP 7: ✅ Updated local ship position for {} to {:?}",
@@ -205,14 +225,13 @@ pub fn handle_movement_request_sync(
     let object_id_str = gorc_event.object_id.clone();
     debug!("🚀 STEP 9: Using object ID: {}", object_id_str);
 
-    let position_update = serde_json::json!({
-        "player_id": client_player,
-        "new_position": move_data.new_position,
-        "velocity": move_data.velocity,
-        "movement_state": move_data.movement_state,
-        "client_timestamp": chrono::Utc::now()
-    });
-    debug!("🚀 STEP 10: Created position update payload: {}", position_update);
+    // Wire-only payload so binary clients receive a compact bincode encoding
+    let position_update = PlayerMoveUpdate {
+        player_id: client_player,
+        client_timestamp: chrono::Utc::now(),
+        ..PlayerMoveUpdate::from(&move_data)
+    };
+    debug!("🚀 STEP 10: Created position update payload: {:?}", position_update);
     
     luminal_handle.spawn(async move {
         debug!("🚀 STEP 11: Inside async broadcast task");
@@ -220,8 +239,8 @@ pub fn handle_movement_request_sync(
         // CRITICAL FIX: Update BOTH player position AND object position in GORC tracking
         // This ensures the spatial tracking has the correct positions for distance calculations
         
-        // Update player position in GORC tracking
-        if let Err(e) = events.update_player_position(client_player, move_data.new_position).await {
+        // Update player position and velocity in GORC tracking
+        if let Err(e) = events.update_player_motion(client_player, move_data.new_position, move_data.velocity).await {
             error!("🚀 STEP 11.5: ❌ Failed to update GORC player tracking: {}", e);
         } else {
             debug!("🚀 STEP 11.5: ✅ Updated GORC player tracking for {} at {:?}",
@@ -232,7 +251,7 @@ pub fn handle_movement_request_sync(
         if let Ok(gorc_id) = GorcObjectId::from_str(&object_id_str) {
             debug!("🚀 STEP 12: Parsed GORC ID successfully: {:?}", gorc_id);
             
-            if let Err(e) = events.update_object_position(gorc_id, move_data.new_position).await {
+            if let Err(e) = events.update_object_position_as(client_player, gorc_id, move_data.new_position).await {
                 error!("🚀 STEP 12.5: ❌ Failed to update GORC object tracking: {}", e);
             } else {
                 debug!("🚀 STEP 12.5: ✅ Updated GORC object tracking for {:?} at {:?}",
@@ -304,13 +323,11 @@ async fn broadcast_position_update(
     events: Arc<EventSystem>,
 ) {
     // Create position update payload for nearby clients
-    let position_update = serde_json::json!({
-        "player_id": player_id,
-        "new_position": move_data.new_position,
-        "velocity": move_data.velocity,
-        "movement_state": move_data.movement_state,
-        "client_timestamp": chrono::Utc::now()
-    });
+    let position_update = PlayerMoveUpdate {
+        player_id,
+        client_timestamp: chrono::Utc::now(),
+        ..PlayerMoveUpdate::from(move_data)
+    };
     
     // Parse the GORC object ID and emit the update
     if let Ok(gorc_id) = GorcObjectId::from_str(object_id_str) {
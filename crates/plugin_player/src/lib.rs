@@ -60,6 +60,7 @@ use async_trait::async_trait;
 use dashmap::DashMap;
 use horizon_event_system::{
     create_simple_plugin,
+    ContentFilter,
     EventSystem,
     GorcObjectId,
     LogLevel,
@@ -226,7 +227,7 @@ impl SimplePlugin for PlayerPlugin {
         // Register GORC client event handlers for real-time gameplay
         self.register_movement_handler(Arc::clone(&events), luminal_handle.clone()).await?;
         self.register_combat_handler(Arc::clone(&events), luminal_handle.clone()).await?;
-        self.register_communication_handler(Arc::clone(&events), luminal_handle.clone()).await?;
+        self.register_communication_handler(Arc::clone(&events), luminal_handle.clone(), context.content_filter()).await?;
         self.register_scanning_handler(Arc::clone(&events), luminal_handle.clone()).await?;
 
         context.log(
@@ -497,6 +498,7 @@ impl PlayerPlugin {
     ///
     /// - `events`: Event system reference for handler registration
     /// - `luminal_handle`: Async runtime handle for background operations
+    /// - `content_filter`: The server's content filter, if it has one
     ///
     /// # Returns
     ///
@@ -504,7 +506,8 @@ impl PlayerPlugin {
     async fn register_communication_handler(
         &self,
         events: Arc<EventSystem>,
-        luminal_handle: luminal::Handle
+        luminal_handle: luminal::Handle,
+        content_filter: Option<Arc<dyn ContentFilter>>,
     ) -> Result<(), PluginError> {
         debug!("🎮 PlayerPlugin: Registering GORC channel 2 (communication) handler");
 
@@ -524,7 +527,8 @@ impl PlayerPlugin {
                         connection,
                         object_instance,
                         events_for_chat.clone(),
-                        luminal_handle_chat.clone()
+                        luminal_handle_chat.clone(),
+                        content_filter.as_deref(),
                     )
                 }
             ).await
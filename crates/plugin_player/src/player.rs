@@ -79,7 +79,7 @@ use chrono::{DateTime, Utc};
 /// # Performance Notes
 ///
 /// This data structure is optimized for minimal serialization overhead:
-/// - Uses native f32 types for efficient network transmission
+/// - Binary replication sends position and velocity as f32s
 /// - Designed for frequent updates without allocation pressure
 /// - Automatically managed by GORC spatial replication system
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -92,11 +92,30 @@ pub struct PlayerCriticalData {
     pub health: f32,
 }
 
+/// Binary replication layout of [`PlayerCriticalData`].
+#[derive(Serialize)]
+struct PlayerCriticalWire {
+    #[serde(with = "horizon_event_system::gorc::wire::compact_vec3")]
+    position: Vec3,
+    #[serde(with = "horizon_event_system::gorc::wire::compact_vec3")]
+    velocity: Vec3,
+    health: f32,
+}
+
 impl GorcZoneData for PlayerCriticalData {
     /// Returns the type identifier for GORC zone data serialization.
     fn zone_type_name() -> &'static str {
         "PlayerCriticalData"
     }
+
+    /// Sends position and velocity as f32s to binary clients.
+    fn serialize_zone_data_binary(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        Ok(horizon_event_system::gorc::wire::encode_bincode(&PlayerCriticalWire {
+            position: self.position,
+            velocity: self.velocity,
+            health: self.health,
+        })?)
+    }
 }
 
 /// Detailed player data for medium-frequency replication (GORC Zone 1).
@@ -517,4 +536,28 @@ impl_gorc_object! {
         1 => detailed_data: PlayerDetailedData,  // 100m range, 30Hz - level, movement_state  
         2 => social_data: PlayerSocialData,      // 200m range, 15Hz - chat_bubble, name
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use horizon_event_system::EventCodec;
+
+    #[test]
+    fn test_critical_data_keeps_full_precision_through_bincode() {
+        let data = PlayerCriticalData {
+            position: Vec3::new(1_000_000.123456789, -42.000000001, 0.1),
+            velocity: Vec3::new(8.000000001, 0.0, -4.25),
+            health: 100.0,
+        };
+        let encoded = EventCodec::Bincode.encode(&data).unwrap();
+        let decoded: PlayerCriticalData = EventCodec::decode(&encoded).unwrap();
+        assert_eq!(decoded.position, data.position);
+        assert_eq!(decoded.velocity, data.velocity);
+
+        // Only the replication encoding is compact
+        let full = horizon_event_system::gorc::wire::encode_bincode(&data).unwrap();
+        let compact = data.serialize_zone_data_binary().unwrap();
+        assert_eq!(full.len() - compact.len(), 6 * 4);
+    }
+}
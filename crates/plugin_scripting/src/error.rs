@@ -0,0 +1,21 @@
+//! Error types for the scripting host.
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ScriptError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Script compile error in {script}: {message}")]
+    CompileError { script: String, message: String },
+
+    #[error("Script runtime error in {script}: {message}")]
+    RuntimeError { script: String, message: String },
+
+    #[error("Script handler registration failed in {script}: {message}")]
+    RegistrationError { script: String, message: String },
+
+    #[error("Script already loaded: {0}")]
+    ScriptAlreadyLoaded(String),
+}
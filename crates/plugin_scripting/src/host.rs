@@ -0,0 +1,462 @@
+//! Rhai script host binding scripts to the Horizon event system.
+//!
+//! Each script is compiled once and its top level is run at load time. While
+//! it runs, the script registers handlers by function name:
+//!
+//! ```rhai
+//! on_core("player_connected", "greet");
+//! on_client("chat", "message", "chat_message");
+//! on_plugin("greeter", "startup", "greeter_started");
+//!
+//! fn greet(event) {
+//!     log(`player connected: ${event.player_id}`);
+//!     emit_plugin("scripts", "greeted", #{ player_id: event.player_id });
+//! }
+//!
+//! fn chat_message(event, player_id) {
+//!     log(`${player_id} said ${event.message}`);
+//! }
+//! ```
+//!
+//! Events reach scripts as object maps converted from their JSON form, and
+//! data passed to the `emit_*` functions is converted back to JSON. Emits are
+//! dispatched asynchronously on the current Tokio runtime.
+
+use crate::error::ScriptError;
+use horizon_event_system::{ClientConnectionRef, EventError, EventSystem, PlayerId};
+use rhai::{CallFnOptions, Dynamic, Engine, EvalAltResult, Scope, AST};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tracing::{error, info, warn};
+
+/// Maximum number of operations a single script call may perform.
+///
+/// Scripts run inline on the event dispatch path, so runaway loops are cut
+/// off instead of stalling every other handler.
+pub const MAX_SCRIPT_OPERATIONS: u64 = 1_000_000;
+
+/// File extension of scripts loaded from a directory.
+pub const SCRIPT_EXTENSION: &str = "rhai";
+
+/// Summary of a loaded script.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptInfo {
+    /// Script name (file stem for scripts loaded from disk)
+    pub name: String,
+    /// Number of event handlers the script registered
+    pub handler_count: usize,
+}
+
+/// Handler registration requested by a script while it was loading.
+#[derive(Debug, Clone)]
+enum Registration {
+    Core { event: String, handler: String },
+    Client { namespace: String, event: String, handler: String },
+    Plugin { plugin: String, event: String, handler: String },
+}
+
+/// A compiled script and the engine it runs on.
+struct Script {
+    name: String,
+    engine: Engine,
+    ast: AST,
+}
+
+impl Script {
+    /// Calls a script function without re-running the script's top level.
+    fn call(&self, handler: &str, args: impl rhai::FuncArgs) -> Result<(), EventError> {
+        let options = CallFnOptions::new().eval_ast(false);
+        self.engine
+            .call_fn_with_options::<Dynamic>(options, &mut Scope::new(), &self.ast, handler, args)
+            .map(|_| ())
+            .map_err(|e| EventError::HandlerExecution(format!("Script {} handler {} failed: {}", self.name, handler, e)))
+    }
+}
+
+/// Loads Rhai scripts and registers their handlers with the event system.
+pub struct ScriptHost {
+    events: Arc<EventSystem>,
+    scripts: Vec<ScriptInfo>,
+}
+
+impl ScriptHost {
+    /// Creates a script host bound to an event system.
+    pub fn new(events: Arc<EventSystem>) -> Self {
+        Self {
+            events,
+            scripts: Vec::new(),
+        }
+    }
+
+    /// Gets the scripts loaded so far.
+    pub fn scripts(&self) -> &[ScriptInfo] {
+        &self.scripts
+    }
+
+    /// Loads every `.rhai` script in a directory, in file name order.
+    ///
+    /// Scripts that fail to load are logged and skipped so one broken script
+    /// does not take the others down with it.
+    ///
+    /// # Returns
+    ///
+    /// The scripts that loaded successfully, or a `ScriptError` if the
+    /// directory could not be read.
+    pub async fn load_directory<P: AsRef<Path>>(&mut self, directory: P) -> Result<Vec<ScriptInfo>, ScriptError> {
+        let directory = directory.as_ref();
+        if !directory.exists() {
+            warn!("📜 Script directory does not exist: {}", directory.display());
+            return Ok(Vec::new());
+        }
+
+        let mut script_files: Vec<_> = std::fs::read_dir(directory)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == SCRIPT_EXTENSION))
+            .collect();
+        script_files.sort();
+
+        let mut loaded = Vec::new();
+        for path in script_files {
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+
+            let result = match std::fs::read_to_string(&path) {
+                Ok(source) => self.load_script(&name, &source).await,
+                Err(e) => Err(ScriptError::IoError(e)),
+            };
+
+            match result {
+                Ok(info) => loaded.push(info),
+                Err(e) => error!("❌ Failed to load script {}: {}", path.display(), e),
+            }
+        }
+
+        Ok(loaded)
+    }
+
+    /// Compiles a script, runs its top level and registers its handlers.
+    ///
+    /// Registration is all or nothing: if one handler can't be registered,
+    /// the ones registered before it are removed again.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Unique script name used in logs and errors
+    /// * `source` - Rhai source code
+    ///
+    /// # Returns
+    ///
+    /// A summary of the loaded script, or a `ScriptError` if it failed to
+    /// compile, run, or register its handlers.
+    pub async fn load_script(&mut self, name: &str, source: &str) -> Result<ScriptInfo, ScriptError> {
+        if self.scripts.iter().any(|script| script.name == name) {
+            return Err(ScriptError::ScriptAlreadyLoaded(name.to_string()));
+        }
+
+        let registrations = Arc::new(Mutex::new(Vec::new()));
+        let engine = build_engine(name, self.events.clone(), registrations.clone());
+
+        let ast = engine.compile(source).map_err(|e| ScriptError::CompileError {
+            script: name.to_string(),
+            message: e.to_string(),
+        })?;
+        engine.run_ast(&ast).map_err(|e| ScriptError::RuntimeError {
+            script: name.to_string(),
+            message: e.to_string(),
+        })?;
+
+        let registrations = std::mem::take(&mut *registrations.lock().unwrap_or_else(|p| p.into_inner()));
+        let script = Arc::new(Script {
+            name: name.to_string(),
+            engine,
+            ast,
+        });
+
+        // Handlers are owned by the script so a failed load can take back
+        // the ones registered before the failure
+        let owner = script_owner(name);
+        let registered = self
+            .events
+            .register_as(&owner, async {
+                for registration in &registrations {
+                    self.register(&script, registration).await?;
+                }
+                Ok::<(), EventError>(())
+            })
+            .await;
+        if let Err(e) = registered {
+            let removed = self.events.remove_handlers_owned_by(&owner).await;
+            warn!("📜 Rolled back {} handler(s) of script {}", removed, name);
+            return Err(ScriptError::RegistrationError {
+                script: name.to_string(),
+                message: e.to_string(),
+            });
+        }
+
+        let info = ScriptInfo {
+            name: name.to_string(),
+            handler_count: registrations.len(),
+        };
+        info!("📜 Loaded script {} with {} handler(s)", name, info.handler_count);
+        self.scripts.push(info.clone());
+        Ok(info)
+    }
+
+    /// Registers one script handler with the event system.
+    async fn register(&self, script: &Arc<Script>, registration: &Registration) -> Result<(), EventError> {
+        match registration {
+            Registration::Core { event, handler } => {
+                let (script, handler) = (script.clone(), handler.clone());
+                self.events
+                    .on_core(event, move |event: serde_json::Value| {
+                        script.call(&handler, (json_to_dynamic(&event)?,))
+                    })
+                    .await
+            }
+            Registration::Client { namespace, event, handler } => {
+                let (script, handler) = (script.clone(), handler.clone());
+                self.events
+                    .on_client(
+                        namespace,
+                        event,
+                        move |event: serde_json::Value, player_id: PlayerId, _connection: ClientConnectionRef| {
+                            script.call(&handler, (json_to_dynamic(&event)?, player_id.to_string()))
+                        },
+                    )
+                    .await
+            }
+            Registration::Plugin { plugin, event, handler } => {
+                let (script, handler) = (script.clone(), handler.clone());
+                self.events
+                    .on_plugin(plugin, event, move |event: serde_json::Value| {
+                        script.call(&handler, (json_to_dynamic(&event)?,))
+                    })
+                    .await
+            }
+        }
+    }
+}
+
+/// Gets the owner a script's handlers are registered under.
+fn script_owner(script_name: &str) -> String {
+    format!("script:{}", script_name)
+}
+
+/// Builds the engine for one script with the Horizon bindings registered.
+fn build_engine(script_name: &str, events: Arc<EventSystem>, registrations: Arc<Mutex<Vec<Registration>>>) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_SCRIPT_OPERATIONS);
+
+    let record = move |registration: Registration| {
+        registrations
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(registration);
+    };
+
+    let register = record.clone();
+    engine.register_fn("on_core", move |event: &str, handler: &str| {
+        register(Registration::Core {
+            event: event.to_string(),
+            handler: handler.to_string(),
+        })
+    });
+
+    let register = record.clone();
+    engine.register_fn("on_client", move |namespace: &str, event: &str, handler: &str| {
+        register(Registration::Client {
+            namespace: namespace.to_string(),
+            event: event.to_string(),
+            handler: handler.to_string(),
+        })
+    });
+
+    let register = record;
+    engine.register_fn("on_plugin", move |plugin: &str, event: &str, handler: &str| {
+        register(Registration::Plugin {
+            plugin: plugin.to_string(),
+            event: event.to_string(),
+            handler: handler.to_string(),
+        })
+    });
+
+    let core_events = events.clone();
+    engine.register_fn("emit_core", move |event: &str, data: Dynamic| -> Result<(), Box<EvalAltResult>> {
+        let (events, event, data) = (core_events.clone(), event.to_string(), dynamic_to_json(&data)?);
+        spawn_emit(async move { events.emit_core(&event, &data).await });
+        Ok(())
+    });
+
+    let client_events = events.clone();
+    engine.register_fn(
+        "emit_client",
+        move |namespace: &str, event: &str, data: Dynamic| -> Result<(), Box<EvalAltResult>> {
+            let events = client_events.clone();
+            let (namespace, event, data) = (namespace.to_string(), event.to_string(), dynamic_to_json(&data)?);
+            spawn_emit(async move { events.emit_client(&namespace, &event, &data).await });
+            Ok(())
+        },
+    );
+
+    let plugin_events = events;
+    engine.register_fn(
+        "emit_plugin",
+        move |plugin: &str, event: &str, data: Dynamic| -> Result<(), Box<EvalAltResult>> {
+            let events = plugin_events.clone();
+            let (plugin, event, data) = (plugin.to_string(), event.to_string(), dynamic_to_json(&data)?);
+            spawn_emit(async move { events.emit_plugin(&plugin, &event, &data).await });
+            Ok(())
+        },
+    );
+
+    let log_name = script_name.to_string();
+    engine.register_fn("log", move |message: &str| {
+        info!("📜 [{}] {}", log_name, message);
+    });
+
+    engine
+}
+
+/// Dispatches an emit from a script on the current Tokio runtime.
+fn spawn_emit<F>(emit: F)
+where
+    F: std::future::Future<Output = Result<(), EventError>> + Send + 'static,
+{
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => {
+            handle.spawn(async move {
+                if let Err(e) = emit.await {
+                    warn!("⚠️ Script emit failed: {}", e);
+                }
+            });
+        }
+        Err(_) => warn!("⚠️ Script emit dropped: no Tokio runtime available"),
+    }
+}
+
+/// Converts an event into a script value.
+fn json_to_dynamic(value: &serde_json::Value) -> Result<Dynamic, EventError> {
+    rhai::serde::to_dynamic(value).map_err(|e| EventError::HandlerExecution(format!("Event conversion failed: {}", e)))
+}
+
+/// Converts a script value into JSON for emitting.
+fn dynamic_to_json(value: &Dynamic) -> Result<serde_json::Value, Box<EvalAltResult>> {
+    rhai::serde::from_dynamic(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+
+    const GREETER_SCRIPT: &str = r#"
+        on_core("player_connected", "greet");
+        on_client("chat", "message", "chat_message");
+
+        fn greet(event) {
+            emit_plugin("scripts", "greeted", #{ player: event.player, count: 1 });
+        }
+
+        fn chat_message(event, player_id) {
+            if event.message == "" { throw "empty message"; }
+        }
+    "#;
+
+    #[tokio::test]
+    async fn test_script_handlers_and_emits() {
+        let events = Arc::new(EventSystem::new());
+        let greeted = Arc::new(AtomicUsize::new(0));
+        let greeted_clone = greeted.clone();
+        events
+            .on_plugin("scripts", "greeted", move |event: serde_json::Value| {
+                assert_eq!(event["player"], "alice");
+                greeted_clone.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let mut host = ScriptHost::new(events.clone());
+        let info = host.load_script("greeter", GREETER_SCRIPT).await.unwrap();
+        assert_eq!(info.handler_count, 2);
+
+        events
+            .emit_core("player_connected", &serde_json::json!({ "player": "alice" }))
+            .await
+            .unwrap();
+
+        // Script emits are dispatched on the runtime
+        for _ in 0..100 {
+            if greeted.load(Ordering::SeqCst) > 0 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        assert_eq!(greeted.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_script_errors() {
+        let events = Arc::new(EventSystem::new());
+        let mut host = ScriptHost::new(events);
+
+        assert!(matches!(
+            host.load_script("broken", "fn (").await,
+            Err(ScriptError::CompileError { .. })
+        ));
+        assert!(matches!(
+            host.load_script("failing", "throw \"boom\";").await,
+            Err(ScriptError::RuntimeError { .. })
+        ));
+
+        // Runaway top-level loops are cut off
+        assert!(matches!(
+            host.load_script("runaway", "loop {}").await,
+            Err(ScriptError::RuntimeError { .. })
+        ));
+
+        host.load_script("ok", "on_core(\"tick\", \"noop\"); fn noop(event) {}").await.unwrap();
+        assert!(matches!(
+            host.load_script("ok", "").await,
+            Err(ScriptError::ScriptAlreadyLoaded(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failed_registration_rolls_back_handlers() {
+        let events = Arc::new(EventSystem::new());
+        let mut host = ScriptHost::new(events.clone());
+
+        // The reserved plugin namespace fails after the core handler was registered
+        let result = host
+            .load_script(
+                "partial",
+                "on_core(\"player_connected\", \"noop\"); on_plugin(\"core\", \"startup\", \"noop\"); fn noop(event) {}",
+            )
+            .await;
+        assert!(matches!(result, Err(ScriptError::RegistrationError { .. })));
+        assert_eq!(events.get_stats().await.total_handlers, 0);
+        assert!(host.scripts().is_empty());
+
+        // The name is free again once the failed load is rolled back
+        host.load_script("partial", "on_core(\"player_connected\", \"noop\"); fn noop(event) {}")
+            .await
+            .unwrap();
+        assert_eq!(events.get_stats().await.total_handlers, 1);
+    }
+
+    #[tokio::test]
+    async fn test_load_directory() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a_welcome.rhai"), "on_core(\"player_connected\", \"hi\"); fn hi(e) {}").unwrap();
+        std::fs::write(dir.path().join("b_broken.rhai"), "fn (").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "not a script").unwrap();
+
+        let mut host = ScriptHost::new(Arc::new(EventSystem::new()));
+        let loaded = host.load_directory(dir.path()).await.unwrap();
+        assert_eq!(loaded, vec![ScriptInfo { name: "a_welcome".to_string(), handler_count: 1 }]);
+    }
+}
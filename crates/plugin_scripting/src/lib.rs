@@ -0,0 +1,93 @@
+//! Scripting plugin for Horizon.
+//!
+//! Loads Rhai scripts as lightweight plugins so gameplay logic can be written
+//! without building a native plugin library. Scripts are read from the
+//! directory named by `HORIZON_SCRIPT_DIR` (default `scripts`) and bind to the
+//! event system through `on_core`, `on_client`, `on_plugin` and the matching
+//! `emit_*` functions. See [`host`] for the script API.
+
+use async_trait::async_trait;
+use horizon_event_system::{create_simple_plugin, EventSystem, LogLevel, PluginError, ServerContext, SimplePlugin};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::info;
+
+pub mod error;
+pub mod host;
+
+pub use error::ScriptError;
+pub use host::{ScriptHost, ScriptInfo};
+
+/// Environment variable overriding the script directory.
+pub const SCRIPT_DIR_ENV: &str = "HORIZON_SCRIPT_DIR";
+
+/// Script directory used when `HORIZON_SCRIPT_DIR` is not set.
+pub const DEFAULT_SCRIPT_DIR: &str = "scripts";
+
+/// Plugin hosting Rhai scripts
+pub struct ScriptingPlugin {
+    name: String,
+    script_dir: PathBuf,
+    scripts: Vec<ScriptInfo>,
+}
+
+impl ScriptingPlugin {
+    pub fn new() -> Self {
+        let script_dir = std::env::var(SCRIPT_DIR_ENV).unwrap_or_else(|_| DEFAULT_SCRIPT_DIR.to_string());
+        Self::with_script_dir(script_dir)
+    }
+
+    /// Creates the plugin loading scripts from a specific directory.
+    pub fn with_script_dir<P: Into<PathBuf>>(script_dir: P) -> Self {
+        Self {
+            name: "scripting".to_string(),
+            script_dir: script_dir.into(),
+            scripts: Vec::new(),
+        }
+    }
+}
+
+impl Default for ScriptingPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SimplePlugin for ScriptingPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    async fn register_handlers(&mut self, events: Arc<EventSystem>, _context: Arc<dyn ServerContext>) -> Result<(), PluginError> {
+        info!("📜 ScriptingPlugin: Loading scripts from {}", self.script_dir.display());
+
+        let mut host = ScriptHost::new(events);
+        self.scripts = host
+            .load_directory(&self.script_dir)
+            .await
+            .map_err(|e| PluginError::InitializationFailed(e.to_string()))?;
+
+        info!("✅ ScriptingPlugin: {} script(s) loaded", self.scripts.len());
+        Ok(())
+    }
+
+    async fn on_init(&mut self, context: Arc<dyn ServerContext>) -> Result<(), PluginError> {
+        context.log(
+            LogLevel::Info,
+            &format!("📜 ScriptingPlugin: Hosting {} script(s)", self.scripts.len()),
+        );
+        Ok(())
+    }
+
+    async fn on_shutdown(&mut self, context: Arc<dyn ServerContext>) -> Result<(), PluginError> {
+        context.log(LogLevel::Info, "📜 ScriptingPlugin: Shutting down");
+        Ok(())
+    }
+}
+
+create_simple_plugin!(ScriptingPlugin);
@@ -27,4 +27,13 @@ pub enum PluginSystemError {
     
     #[error("Plugin version mismatch: {0}")]
     VersionMismatch(String),
+
+    #[error("Plugin signature error: {0}")]
+    SignatureError(String),
+
+    #[error("Plugin manifest error: {0}")]
+    ManifestError(String),
+
+    #[error("Plugin is not whitelisted: {0}")]
+    NotWhitelisted(String),
 }
\ No newline at end of file
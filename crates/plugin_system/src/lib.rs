@@ -6,9 +6,17 @@
 
 mod manager;
 mod error;
+mod signature;
+mod manifest;
+mod watcher;
 
-pub use manager::{PluginManager, PluginSafetyConfig};
+pub use manager::{
+    PluginManager, PluginSafetyConfig, PluginRestartPolicy, PluginHealth, PluginHealthSnapshot,
+    PluginWatchdogConfig,
+};
 pub use error::PluginSystemError;
+pub use signature::{PluginVerifier, StagedLibrary};
+pub use manifest::PluginManifest;
 
 
 /// Re-export commonly used types for plugin development
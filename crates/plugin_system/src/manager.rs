@@ -1,12 +1,21 @@
 //! Plugin manager implementation for loading and managing dynamic plugins.
 
 use crate::error::PluginSystemError;
+use crate::manifest::PluginManifest;
+use crate::signature::{PluginVerifier, StagedLibrary};
 use dashmap::DashMap;
 use horizon_event_system::plugin::Plugin;
-use horizon_event_system::{EventSystem, context::ServerContext, LogLevel};
+use horizon_event_system::{
+    current_timestamp, AuditAction, EventSystem, HandlerPanicReport, PluginHealthChangedEvent, PluginHealthStatus,
+    PluginLoadedEvent, SecurityAuditEvent, context::ServerContext, LogLevel,
+};
 use libloading::{Library, Symbol};
+use std::future::Future;
+use std::collections::VecDeque;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
 use tracing::{error, info, warn};
 
 /// Configuration for plugin loading safety checks.
@@ -25,11 +34,145 @@ pub struct PluginSafetyConfig {
     /// Require exact version matching including patch digits.
     /// When false, only major.minor must match (ignoring patch).
     pub strict_versioning: bool,
+
+    /// Supervision policy applied when a plugin's handlers panic.
+    #[serde(default)]
+    pub restart_policy: PluginRestartPolicy,
+
+    /// Heartbeat polling applied to every loaded plugin.
+    #[serde(default)]
+    pub watchdog: PluginWatchdogConfig,
+
+    /// Require every plugin library to carry a valid Ed25519 signature (`<library>.sig`).
+    /// Manifests then need their own signature as well (`<library>.plugin.toml.sig`).
+    /// Unsigned or invalid plugins are refused unless `allow_unsafe_plugins` is set.
+    #[serde(default)]
+    pub require_signatures: bool,
+
+    /// Base64-encoded Ed25519 public keys trusted to sign plugins.
+    #[serde(default)]
+    pub trusted_keys: Vec<String>,
+
+    /// Refuse plugins that do not ship a manifest (`<library>.plugin.toml`).
+    #[serde(default)]
+    pub require_manifest: bool,
+
+    /// Capabilities plugins may declare in their manifest. Empty allows any.
+    #[serde(default)]
+    pub allowed_capabilities: Vec<String>,
+
+    /// Plugins allowed to load, by library name (`plugin_greeter`) or manifest
+    /// name (`greeter`). Empty allows any.
+    #[serde(default)]
+    pub whitelist: Vec<String>,
+
+    /// Watch the plugin directory and load newly added libraries automatically.
+    #[serde(default)]
+    pub watch_directory: bool,
+
+    /// Plugins to load and initialize first, in order, by library name
+    /// (`plugin_auth`) or plugin name (`auth`). Unlisted plugins follow in
+    /// alphabetical order and shutdown runs in reverse.
+    #[serde(default)]
+    pub load_order: Vec<String>,
+}
+
+/// Supervised restart policy for plugins whose handlers panic.
+///
+/// A panicking plugin is restarted at most `max_restarts` times within any
+/// `window_secs` window. Once the budget is exhausted the plugin is marked
+/// [`PluginHealth::Failed`] and its handlers are removed from the event system.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PluginRestartPolicy {
+    /// Maximum number of restarts allowed within the window (0 disables restarts)
+    pub max_restarts: u32,
+    /// Length of the sliding restart window in seconds
+    pub window_secs: u64,
+}
+
+impl Default for PluginRestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: 3,
+            window_secs: 60,
+        }
+    }
+}
+
+impl PluginRestartPolicy {
+    /// Checks whether another restart fits in the budget and records it if so.
+    ///
+    /// Restarts older than the window are pruned from `history` first.
+    pub fn try_consume(&self, history: &mut VecDeque<Instant>, now: Instant) -> bool {
+        let window = Duration::from_secs(self.window_secs);
+        while let Some(oldest) = history.front() {
+            if now.duration_since(*oldest) >= window {
+                history.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if history.len() as u32 >= self.max_restarts {
+            return false;
+        }
+
+        history.push_back(now);
+        true
+    }
+}
+
+/// Heartbeat watchdog settings.
+///
+/// Every `interval_secs` the watchdog calls each plugin's `health()` method;
+/// a plugin that does not answer within `timeout_ms` is marked
+/// [`PluginHealth::Unresponsive`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PluginWatchdogConfig {
+    /// Seconds between heartbeat rounds
+    pub interval_secs: u64,
+    /// Milliseconds a plugin has to answer a heartbeat
+    pub timeout_ms: u64,
+}
+
+impl Default for PluginWatchdogConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 10,
+            timeout_ms: 2000,
+        }
+    }
+}
+
+/// Health of a loaded plugin as seen by the supervisor and watchdog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum PluginHealth {
+    /// Plugin is running normally
+    Healthy,
+    /// A handler panicked or the plugin reported itself impaired
+    Degraded,
+    /// The plugin did not answer its last heartbeat in time
+    Unresponsive,
+    /// Restart budget exhausted - plugin handlers have been removed
+    Failed,
+}
+
+/// Point-in-time health of a single plugin.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PluginHealthSnapshot {
+    /// Name of the plugin
+    pub name: String,
+    /// Current health state
+    pub health: PluginHealth,
+    /// Reason for a non-healthy state, if known
+    pub detail: Option<String>,
 }
 
 
-//TODO: provide real region and player communication.
-/// Minimal server context for plugin initialization and testing.
+/// Fallback server context used when the host does not provide one.
+///
+/// Player messaging is unavailable; hosts such as the game server supply a
+/// connection-backed context through [`PluginManager::with_server_context`].
 #[derive(Clone)]
 struct BasicServerContext {
     event_system: Arc<EventSystem>,
@@ -132,17 +275,123 @@ impl ServerContext for BasicServerContext {
     fn gorc_instance_manager(&self) -> Option<Arc<horizon_event_system::gorc::GorcInstanceManager>> {
         self.gorc_instance_manager.clone()
     }
+
+    fn schedule_interval(&self, name: &str, period: std::time::Duration, event_name: &str) -> Result<horizon_event_system::TimerHandle, horizon_event_system::context::ServerError> {
+        self.event_system
+            .schedule_interval(name, period, event_name)
+            .map_err(|e| horizon_event_system::context::ServerError::Internal(e.to_string()))
+    }
+}
+
+/// Server context scoped to a single plugin.
+///
+/// Delegates to the host context, but logs with the plugin's name as the
+/// target so per-plugin log routes apply, both for `context.log` and for the
+/// plugin's own `tracing` macros.
+#[derive(Debug)]
+struct PluginContext {
+    plugin_name: String,
+    inner: Arc<dyn ServerContext>,
+}
+
+#[async_trait::async_trait]
+impl ServerContext for PluginContext {
+    fn events(&self) -> Arc<EventSystem> {
+        self.inner.events()
+    }
+
+    fn log(&self, level: LogLevel, message: &str) {
+        let async_logger = horizon_event_system::async_logging::global_async_logger();
+        async_logger.log_with_target(level, message, Some(&self.plugin_name));
+    }
+
+    fn log_dispatch(&self) -> Option<tracing::Dispatch> {
+        Some(horizon_event_system::async_logging::PluginSubscriber::dispatch(&self.plugin_name))
+    }
+
+    fn region_id(&self) -> horizon_event_system::types::RegionId {
+        self.inner.region_id()
+    }
+
+    async fn send_to_player(&self, player_id: horizon_event_system::types::PlayerId, data: &[u8]) -> Result<(), horizon_event_system::context::ServerError> {
+        self.inner.send_to_player(player_id, data).await
+    }
+
+    async fn broadcast(&self, data: &[u8]) -> Result<(), horizon_event_system::context::ServerError> {
+        self.inner.broadcast(data).await
+    }
+
+    async fn broadcast_to_players(&self, players: &[horizon_event_system::types::PlayerId], data: &[u8]) -> Result<usize, horizon_event_system::context::ServerError> {
+        self.inner.broadcast_to_players(players, data).await
+    }
+
+    async fn broadcast_except(&self, excluded: horizon_event_system::types::PlayerId, data: &[u8]) -> Result<usize, horizon_event_system::context::ServerError> {
+        self.inner.broadcast_except(excluded, data).await
+    }
+
+    async fn broadcast_to_radius(&self, center: horizon_event_system::types::Vec3, radius: f64, data: &[u8]) -> Result<usize, horizon_event_system::context::ServerError> {
+        self.inner.broadcast_to_radius(center, radius, data).await
+    }
+
+    fn luminal_handle(&self) -> luminal::Handle {
+        self.inner.luminal_handle()
+    }
+
+    fn gorc_instance_manager(&self) -> Option<Arc<horizon_event_system::gorc::GorcInstanceManager>> {
+        self.inner.gorc_instance_manager()
+    }
+
+    fn schedule_interval(&self, name: &str, period: std::time::Duration, event_name: &str) -> Result<horizon_event_system::TimerHandle, horizon_event_system::context::ServerError> {
+        self.inner.schedule_interval(name, period, event_name)
+    }
+
+    fn cancel_interval(&self, name: &str) -> bool {
+        self.inner.cancel_interval(name)
+    }
+
+    async fn connection_stats(&self, player_id: horizon_event_system::types::PlayerId) -> Option<horizon_event_system::ConnectionStats> {
+        self.inner.connection_stats(player_id).await
+    }
+
+    async fn client_version(&self, player_id: horizon_event_system::types::PlayerId) -> Option<horizon_event_system::ClientVersion> {
+        self.inner.client_version(player_id).await
+    }
+
+    async fn connection_metadata(&self, player_id: horizon_event_system::types::PlayerId, key: &str) -> Option<serde_json::Value> {
+        self.inner.connection_metadata(player_id, key).await
+    }
+
+    async fn set_connection_metadata(&self, player_id: horizon_event_system::types::PlayerId, key: &str, value: Option<serde_json::Value>) -> Result<(), horizon_event_system::context::ServerError> {
+        self.inner.set_connection_metadata(player_id, key, value).await
+    }
+
+    fn content_filter(&self) -> Option<Arc<dyn horizon_event_system::ContentFilter>> {
+        self.inner.content_filter()
+    }
 }
 
 /// Information about a loaded plugin
 pub struct LoadedPlugin {
     /// The name of the plugin
-    #[allow(dead_code)]
     pub name: String,
+    /// Path the library was loaded from
+    pub library_path: PathBuf,
     /// The loaded library
     pub library: Library,
-    /// The plugin instance (boxed for dynamic dispatch)
-    pub plugin: Box<dyn Plugin + Send + Sync>,
+    /// The plugin instance (boxed for dynamic dispatch), shared so health
+    /// checks can await it without holding the plugin map
+    pub plugin: Arc<tokio::sync::RwLock<Box<dyn Plugin + Send + Sync>>>,
+    /// Current supervision health of the plugin
+    pub health: PluginHealth,
+    /// Reason for the current health state, if it is not healthy
+    pub health_detail: Option<String>,
+    /// Timestamps of recent supervised restarts
+    pub restart_history: VecDeque<Instant>,
+    /// Manifest shipped with the plugin, if any
+    pub manifest: Option<PluginManifest>,
+    /// Verified copy the library was loaded from when signatures are
+    /// required, kept until the plugin is dropped
+    _staged_library: Option<StagedLibrary>,
 }
 
 /// Plugin manager for loading and managing dynamic plugins.
@@ -162,6 +411,10 @@ pub struct PluginManager {
     safety_config: PluginSafetyConfig,
     /// Optional GORC instance manager for object replication
     gorc_instance_manager: Option<Arc<horizon_event_system::gorc::GorcInstanceManager>>,
+    /// Signature verifier built from the trusted keys in the safety configuration
+    verifier: PluginVerifier,
+    /// Host-provided context handed to plugins, if any
+    server_context: Option<Arc<dyn ServerContext>>,
 }
 
 impl PluginManager {
@@ -176,11 +429,14 @@ impl PluginManager {
     ///
     /// A new `PluginManager` instance ready to load plugins.
     pub fn new(event_system: Arc<EventSystem>, safety_config: PluginSafetyConfig) -> Self {
+        let verifier = Self::build_verifier(&safety_config);
         Self {
             event_system,
             loaded_plugins: DashMap::new(),
             safety_config,
             gorc_instance_manager: None,
+            verifier,
+            server_context: None,
         }
     }
 
@@ -200,11 +456,46 @@ impl PluginManager {
         safety_config: PluginSafetyConfig,
         gorc_instance_manager: Arc<horizon_event_system::gorc::GorcInstanceManager>
     ) -> Self {
+        let verifier = Self::build_verifier(&safety_config);
         Self {
             event_system,
             loaded_plugins: DashMap::new(),
             safety_config,
             gorc_instance_manager: Some(gorc_instance_manager),
+            verifier,
+            server_context: None,
+        }
+    }
+
+    /// Uses a host-provided server context for every plugin lifecycle call.
+    ///
+    /// Without one, plugins receive a basic context that cannot message
+    /// players.
+    ///
+    /// # Arguments
+    ///
+    /// * `server_context` - Context backed by the host's connection handling
+    pub fn with_server_context(mut self, server_context: Arc<dyn ServerContext>) -> Self {
+        self.server_context = Some(server_context);
+        self
+    }
+
+    /// Builds the signature verifier from the configured trusted keys.
+    ///
+    /// Malformed keys are reported and ignored, which means plugins signed
+    /// with them will be rejected rather than silently trusted.
+    fn build_verifier(safety_config: &PluginSafetyConfig) -> PluginVerifier {
+        match PluginVerifier::from_base64_keys(&safety_config.trusted_keys) {
+            Ok(verifier) => {
+                if safety_config.require_signatures && verifier.trusted_key_count() == 0 {
+                    warn!("🔏 Plugin signatures are required but no trusted keys are configured");
+                }
+                verifier
+            }
+            Err(e) => {
+                error!("❌ Invalid trusted plugin key configuration: {}", e);
+                PluginVerifier::default()
+            }
         }
     }
 
@@ -242,8 +533,9 @@ impl PluginManager {
 
         info!("🔌 Loading plugins from: {}", dir_path.display());
 
-        // Phase 1: Discover and load plugin files
-        let plugin_files = self.discover_plugin_files(dir_path)?;
+        // Phase 1: Discover and load plugin files, highest priority first
+        let mut plugin_files = self.discover_plugin_files(dir_path)?;
+        plugin_files.sort_by_cached_key(|path| (self.load_priority(None, path), path.clone()));
         
         if plugin_files.is_empty() {
             info!("📂 No plugin files found in directory");
@@ -261,6 +553,13 @@ impl PluginManager {
                     info!("✅ Successfully loaded plugin: {}", plugin_name);
                     loaded_count += 1;
                 }
+                Err(PluginSystemError::NotWhitelisted(_)) => {
+                    error!(
+                        "🚫 Skipped plugin {}: {} is not in the plugin whitelist",
+                        plugin_file.display(),
+                        Self::library_name(plugin_file)
+                    );
+                }
                 Err(e) => {
                     error!("❌ Failed to load plugin from {}: {}", plugin_file.display(), e);
                     // Continue loading other plugins even if one fails
@@ -299,30 +598,87 @@ impl PluginManager {
             let entry = entry?;
             let path = entry.path();
             
-            if path.is_file() {
-                if let Some(extension) = path.extension() {
-                    let ext_str = extension.to_string_lossy().to_lowercase();
-                    
-                    // Check for platform-specific dynamic library extensions
-                    #[cfg(target_os = "windows")]
-                    let is_plugin = ext_str == "dll";
-                    
-                    #[cfg(target_os = "macos")]
-                    let is_plugin = ext_str == "dylib";
-                    
-                    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
-                    let is_plugin = ext_str == "so";
-                    
-                    if is_plugin {
-                        plugin_files.push(path);
-                    }
-                }
+            if path.is_file() && Self::is_plugin_file(&path) {
+                plugin_files.push(path);
             }
         }
         
         Ok(plugin_files)
     }
 
+    /// Checks whether a path has the platform's dynamic library extension
+    /// (.dll on Windows, .so on Unix-like systems, .dylib on macOS).
+    pub(crate) fn is_plugin_file(path: &Path) -> bool {
+        let Some(extension) = path.extension() else {
+            return false;
+        };
+        let ext_str = extension.to_string_lossy().to_lowercase();
+
+        #[cfg(target_os = "windows")]
+        let is_plugin = ext_str == "dll";
+
+        #[cfg(target_os = "macos")]
+        let is_plugin = ext_str == "dylib";
+
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        let is_plugin = ext_str == "so";
+
+        is_plugin
+    }
+
+    /// Gets the library name of a plugin file: its file stem without the `lib` prefix.
+    fn library_name(path: &Path) -> String {
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        stem.strip_prefix("lib").map(str::to_string).unwrap_or(stem)
+    }
+
+    /// Gets the position of a plugin in the configured load order.
+    ///
+    /// Plugins not listed in `load_order` share the lowest priority.
+    fn load_priority(&self, plugin_name: Option<&str>, library_path: &Path) -> usize {
+        let library_name = Self::library_name(library_path);
+        self.safety_config
+            .load_order
+            .iter()
+            .position(|entry| *entry == library_name || Some(entry.as_str()) == plugin_name)
+            .unwrap_or(self.safety_config.load_order.len())
+    }
+
+    /// Gets the names of loaded plugins sorted by load order, then by name.
+    fn ordered_plugin_names(&self) -> Vec<String> {
+        let mut plugins: Vec<(usize, String)> = self
+            .loaded_plugins
+            .iter()
+            .map(|entry| (self.load_priority(Some(entry.key()), &entry.library_path), entry.key().clone()))
+            .collect();
+        plugins.sort();
+        plugins.into_iter().map(|(_, name)| name).collect()
+    }
+
+    /// Checks a plugin library against the configured whitelist.
+    ///
+    /// A plugin is allowed if the whitelist is empty, or lists either its
+    /// library name (file stem without the `lib` prefix) or its manifest name.
+    fn check_whitelist(&self, path: &Path, manifest: Option<&PluginManifest>) -> Result<(), PluginSystemError> {
+        let whitelist = &self.safety_config.whitelist;
+        if whitelist.is_empty() {
+            return Ok(());
+        }
+
+        let library_name = Self::library_name(path);
+        let allowed = whitelist.iter().any(|entry| {
+            *entry == library_name || manifest.is_some_and(|manifest| *entry == manifest.name)
+        });
+        if allowed {
+            Ok(())
+        } else {
+            Err(PluginSystemError::NotWhitelisted(path.display().to_string()))
+        }
+    }
+
     /// Loads a single plugin from the specified file.
     ///
     /// # Arguments
@@ -340,9 +696,17 @@ impl PluginManager {
         
         info!("🔄 Loading plugin from: {}", path.display());
 
-        // Load the dynamic library
+        // Verify the library's and manifest's signatures and check the manifest
+        // against policy before the library gets a chance to run any code
+        let (staged_library, signature_override) = self.verify_plugin_signature(path)?;
+        let (manifest, manifest_override) = self.load_plugin_manifest(path)?;
+        let mut overrides: Vec<String> = signature_override.into_iter().chain(manifest_override).collect();
+        self.check_whitelist(path, manifest.as_ref())?;
+
+        // Load the dynamic library, from the verified copy if there is one
+        let load_path = staged_library.as_ref().map_or(path, StagedLibrary::path);
         let library = unsafe {
-            Library::new(path).map_err(|e| {
+            Library::new(load_path).map_err(|e| {
                 PluginSystemError::LibraryError(format!("Failed to load library: {}", e))
             })?
         };
@@ -382,42 +746,66 @@ impl PluginManager {
             }
         };
 
+        // Plugins may declare a range of compatible server versions
+        let abi_range = Self::read_abi_range(&library)?;
+
         // Parse versions and validate compatibility
         let expected_version = horizon_event_system::ABI_VERSION;
-        self.validate_plugin_compatibility(&plugin_version, expected_version)?;
-
-        // Look for the plugin creation function
-        let create_plugin: Symbol<unsafe extern "C" fn() -> *mut dyn Plugin> = unsafe {
-            library.get(b"create_plugin").map_err(|e| {
-                PluginSystemError::LoadingError(format!(
-                    "Plugin does not export 'create_plugin' function: {}", e
-                ))
-            })?
-        };
-
-        // Create the plugin instance
-        let plugin_ptr = unsafe { create_plugin() };
-        if plugin_ptr.is_null() {
-            return Err(PluginSystemError::LoadingError(
-                "Plugin creation function returned null".to_string(),
-            ));
+        overrides.extend(self.validate_plugin_compatibility(&plugin_version, expected_version, abi_range.as_deref())?);
+        for detail in overrides {
+            let event = SecurityAuditEvent::new(AuditAction::UnsafePluginOverride, "plugin_system")
+                .with_target(path.display())
+                .with_detail(detail);
+            if let Err(e) = self.event_system.emit_core("security_audit", &event).await {
+                warn!("Failed to emit security_audit event: {}", e);
+            }
         }
 
-        let plugin = unsafe { Box::from_raw(plugin_ptr) };
+        // Create the plugin instance
+        let plugin = Self::instantiate_plugin(&library)?;
         
         // Get plugin name for registration
         let plugin_name = plugin.name().to_string();
 
+        // Only the plugin knows its name, so this check runs after the library
+        // was loaded and created the plugin; the mismatched plugin is dropped
+        // without being initialized or registered
+        if let Some(manifest) = &manifest {
+            if manifest.name != plugin_name {
+                return Err(PluginSystemError::ManifestError(format!(
+                    "Manifest declares plugin '{}' but the library reports '{}'",
+                    manifest.name, plugin_name
+                )));
+            }
+            if manifest.version != plugin.version() {
+                warn!(
+                    "⚠️ Manifest version {} for plugin {} differs from reported version {}",
+                    manifest.version, plugin_name, plugin.version()
+                );
+            }
+        }
+
         // Check if plugin already exists
         if self.loaded_plugins.contains_key(&plugin_name) {
             return Err(PluginSystemError::PluginAlreadyExists(plugin_name));
         }
 
+        // Hold the plugin's emissions to the namespaces it declared
+        if let Some(manifest) = &manifest {
+            self.event_system.set_plugin_namespaces(&plugin_name, &manifest.event_namespaces);
+        }
+
         // Store the loaded plugin
         let loaded_plugin = LoadedPlugin {
             name: plugin_name.clone(),
+            library_path: path.to_path_buf(),
             library,
-            plugin,
+            plugin: Arc::new(tokio::sync::RwLock::new(plugin)),
+            health: PluginHealth::Healthy,
+            health_detail: None,
+            restart_history: VecDeque::new(),
+            manifest,
+            _staged_library: staged_library,
         };
 
         self.loaded_plugins.insert(plugin_name.clone(), loaded_plugin);
@@ -425,6 +813,158 @@ impl PluginManager {
         Ok(plugin_name)
     }
 
+    /// Reads the optional `get_plugin_abi_range` export of a plugin library.
+    ///
+    /// # Returns
+    ///
+    /// The declared semver range (e.g. `>=0.22, <0.24`), `None` if the plugin
+    /// does not export one, or a `PluginSystemError` if the export is invalid.
+    fn read_abi_range(library: &Library) -> Result<Option<String>, PluginSystemError> {
+        let get_plugin_abi_range: Symbol<unsafe extern "C" fn() -> *const std::os::raw::c_char> =
+            match unsafe { library.get(b"get_plugin_abi_range") } {
+                Ok(symbol) => symbol,
+                Err(_) => return Ok(None),
+            };
+
+        let range_ptr = unsafe { get_plugin_abi_range() };
+        if range_ptr.is_null() {
+            return Err(PluginSystemError::LoadingError(
+                "Plugin returned null ABI range string".to_string(),
+            ));
+        }
+
+        let range = unsafe { std::ffi::CStr::from_ptr(range_ptr) }
+            .to_string_lossy()
+            .to_string();
+        Ok(Some(range))
+    }
+
+    /// Checks the plugin's detached signature when signatures are required.
+    ///
+    /// Unsigned or invalid plugins are refused unless the
+    /// `--danger-allow-unsafe-plugins` override is active.
+    ///
+    /// # Returns
+    ///
+    /// The verified copy of the library to load, and why the override was
+    /// needed if the plugin was only let through by it.
+    fn verify_plugin_signature(&self, path: &Path) -> Result<(Option<StagedLibrary>, Option<String>), PluginSystemError> {
+        if !self.safety_config.require_signatures {
+            return Ok((None, None));
+        }
+
+        // Read the library once, so the bytes checked are the bytes loaded
+        let library_bytes = std::fs::read(path)?;
+        match self.verifier.verify_bytes(path, &library_bytes) {
+            Ok(()) => {
+                info!("🔏 Verified signature for plugin: {}", path.display());
+                Ok((Some(StagedLibrary::write(path, &library_bytes)?), None))
+            }
+            Err(e) if self.safety_config.allow_unsafe_plugins => {
+                warn!("Loading plugin with missing or invalid signature (override enabled): {}", e);
+                Ok((None, Some(format!("missing or invalid signature: {e}"))))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Loads the plugin's manifest and validates it against server policy.
+    ///
+    /// When signatures are required the manifest must carry its own detached
+    /// signature, checked the same way as the library's.
+    ///
+    /// # Returns
+    ///
+    /// The manifest, or `None` for plugins without one unless manifests are
+    /// required by the safety configuration, and why the unsafe override was
+    /// needed if the manifest was only let through by it.
+    fn load_plugin_manifest(&self, path: &Path) -> Result<(Option<PluginManifest>, Option<String>), PluginSystemError> {
+        let manifest_path = PluginManifest::manifest_path(path);
+        if !manifest_path.exists() {
+            if self.safety_config.require_manifest {
+                return Err(PluginSystemError::ManifestError(format!(
+                    "Plugin has no manifest: {} not found",
+                    manifest_path.display()
+                )));
+            }
+            return Ok((None, None));
+        }
+
+        // Read the manifest once, so the bytes checked are the bytes parsed
+        let contents = std::fs::read(&manifest_path)?;
+        let signature_override = if self.safety_config.require_signatures {
+            match self.verifier.verify_bytes(&manifest_path, &contents) {
+                Ok(()) => None,
+                Err(e) if self.safety_config.allow_unsafe_plugins => {
+                    warn!("Loading plugin manifest with missing or invalid signature (override enabled): {}", e);
+                    Some(format!("missing or invalid manifest signature: {e}"))
+                }
+                Err(e) => return Err(e),
+            }
+        } else {
+            None
+        };
+        let contents = std::str::from_utf8(&contents)
+            .map_err(|e| PluginSystemError::ManifestError(format!("Plugin manifest is not UTF-8: {}", e)))?;
+        let manifest = PluginManifest::from_toml(contents)?;
+
+        manifest.validate(&self.safety_config.allowed_capabilities)?;
+        info!(
+            "📜 Plugin manifest for {} v{}: capabilities {:?}, namespaces {:?}",
+            manifest.name, manifest.version, manifest.capabilities, manifest.event_namespaces
+        );
+
+        Ok((Some(manifest), signature_override))
+    }
+
+    /// Creates a fresh plugin instance from an already loaded library.
+    ///
+    /// # Arguments
+    ///
+    /// * `library` - The loaded plugin library exporting `create_plugin`
+    ///
+    /// # Returns
+    ///
+    /// The boxed plugin instance, or a `PluginSystemError` if creation failed.
+    fn instantiate_plugin(library: &Library) -> Result<Box<dyn Plugin + Send + Sync>, PluginSystemError> {
+        // Look for the plugin creation function
+        let create_plugin: Symbol<unsafe extern "C" fn() -> *mut dyn Plugin> = unsafe {
+            library.get(b"create_plugin").map_err(|e| {
+                PluginSystemError::LoadingError(format!(
+                    "Plugin does not export 'create_plugin' function: {}", e
+                ))
+            })?
+        };
+
+        let plugin_ptr = unsafe { create_plugin() };
+        if plugin_ptr.is_null() {
+            return Err(PluginSystemError::LoadingError(
+                "Plugin creation function returned null".to_string(),
+            ));
+        }
+
+        Ok(unsafe { Box::from_raw(plugin_ptr) })
+    }
+
+    /// Creates the server context handed to plugins during lifecycle calls.
+    fn create_context(&self) -> Arc<dyn ServerContext> {
+        if let Some(server_context) = &self.server_context {
+            server_context.clone()
+        } else if let Some(gorc_manager) = &self.gorc_instance_manager {
+            Arc::new(BasicServerContext::with_gorc(self.event_system.clone(), gorc_manager.clone()))
+        } else {
+            Arc::new(BasicServerContext::new(self.event_system.clone()))
+        }
+    }
+
+    /// Scopes a server context to one plugin so its logs carry the plugin's name as target.
+    fn plugin_context(context: &Arc<dyn ServerContext>, plugin_name: &str) -> Arc<dyn ServerContext> {
+        Arc::new(PluginContext {
+            plugin_name: plugin_name.to_string(),
+            inner: context.clone(),
+        })
+    }
+
     /// Initializes all loaded plugins.
     ///
     /// This method calls the initialization methods on all loaded plugins
@@ -432,20 +972,22 @@ impl PluginManager {
     async fn initialize_plugins(&self) -> Result<(), PluginSystemError> {
         info!("🔧 Initializing {} loaded plugins", self.loaded_plugins.len());
 
-        let context = if let Some(gorc_manager) = &self.gorc_instance_manager {
-            Arc::new(BasicServerContext::with_gorc(self.event_system.clone(), gorc_manager.clone()))
-        } else {
-            Arc::new(BasicServerContext::new(self.event_system.clone()))
-        };
+        let context = self.create_context();
 
-        // Phase 1: Pre-initialization (register handlers)
-        let plugin_names: Vec<String> = self.loaded_plugins.iter().map(|entry| entry.key().clone()).collect();
+        // Phase 1: Pre-initialization (register handlers), in configured load order
+        let plugin_names = self.ordered_plugin_names();
         
         for plugin_name in &plugin_names {
             info!("🔧 Pre-initializing plugin: {}", plugin_name);
 
-            if let Some(mut loaded_plugin) = self.loaded_plugins.get_mut(plugin_name) {
-                match loaded_plugin.plugin.pre_init(context.clone()).await {
+            if let Some(plugin) = self.plugin_handle(plugin_name) {
+                // Attribute handlers to the plugin so panics can be supervised
+                let plugin_context = Self::plugin_context(&context, plugin_name);
+                let result = self
+                    .run_as_plugin(plugin_name, async move { plugin.write().await.pre_init(plugin_context).await })
+                    .await;
+
+                match result {
                     Ok(_) => {
                         info!("📡 Event handlers registered for plugin: {}", plugin_name);
                     }
@@ -461,10 +1003,26 @@ impl PluginManager {
         for plugin_name in &plugin_names {
             info!("🔧 Initializing plugin: {}", plugin_name);
 
-            if let Some(mut loaded_plugin) = self.loaded_plugins.get_mut(plugin_name) {
-                match loaded_plugin.plugin.init(context.clone()).await {
+            if let Some(plugin) = self.plugin_handle(plugin_name) {
+                let plugin_context = Self::plugin_context(&context, plugin_name);
+                let result = self
+                    .run_as_plugin(plugin_name, {
+                        let plugin = plugin.clone();
+                        async move { plugin.write().await.init(plugin_context).await }
+                    })
+                    .await;
+
+                match result {
                     Ok(_) => {
                         info!("✅ Plugin initialized successfully: {}", plugin_name);
+                        let version = plugin.read().await.version().to_string();
+                        let loaded_event = self
+                            .loaded_plugins
+                            .get(plugin_name)
+                            .map(|loaded_plugin| Self::plugin_loaded_event(&loaded_plugin, version));
+                        if let Some(loaded_event) = loaded_event {
+                            self.announce_plugin_loaded(loaded_event).await;
+                        }
                     }
                     Err(e) => {
                         error!("❌ Plugin initialization failed for {}: {:?}", plugin_name, e);
@@ -477,6 +1035,74 @@ impl PluginManager {
         Ok(())
     }
 
+    /// Loads and fully initializes a single plugin while the server is running.
+    ///
+    /// The library goes through the same whitelist, signature, manifest and
+    /// ABI checks as plugins loaded at startup, then through `pre_init` and
+    /// `init`. A plugin that fails to initialize is unloaded again.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin_path` - Path to the plugin library file
+    ///
+    /// # Returns
+    ///
+    /// The name of the loaded plugin, or a `PluginSystemError` if loading failed.
+    pub async fn load_plugin<P: AsRef<Path>>(&self, plugin_path: P) -> Result<String, PluginSystemError> {
+        let plugin_name = self.load_single_plugin(plugin_path).await?;
+        let context = Self::plugin_context(&self.create_context(), &plugin_name);
+
+        let Some(plugin) = self.plugin_handle(&plugin_name) else {
+            return Err(PluginSystemError::PluginNotFound(plugin_name));
+        };
+        let result = self
+            .run_as_plugin(&plugin_name, {
+                let plugin = plugin.clone();
+                async move {
+                    let mut plugin = plugin.write().await;
+                    plugin.pre_init(context.clone()).await?;
+                    plugin.init(context).await
+                }
+            })
+            .await;
+        let result = match result {
+            Ok(()) => {
+                let version = plugin.read().await.version().to_string();
+                match self.loaded_plugins.get(&plugin_name) {
+                    Some(loaded_plugin) => Ok(Self::plugin_loaded_event(&loaded_plugin, version)),
+                    None => return Err(PluginSystemError::PluginNotFound(plugin_name)),
+                }
+            }
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(loaded_event) => {
+                info!("✅ Plugin initialized successfully: {}", plugin_name);
+                self.announce_plugin_loaded(loaded_event).await;
+                Ok(plugin_name)
+            }
+            Err(e) => {
+                self.event_system.remove_handlers_owned_by(&plugin_name).await;
+                self.event_system.clear_plugin_namespaces(&plugin_name);
+                if let Some((_, loaded_plugin)) = self.loaded_plugins.remove(&plugin_name) {
+                    // Drop the instance before its library, and leak the library since
+                    // the plugin may have left tasks running that still use its code
+                    drop(loaded_plugin.plugin);
+                    std::mem::forget(loaded_plugin.library);
+                }
+                Err(PluginSystemError::InitializationError(format!("{}: {:?}", plugin_name, e)))
+            }
+        }
+    }
+
+    /// Checks whether a library file has already been loaded as a plugin.
+    pub fn is_library_loaded(&self, library_path: &Path) -> bool {
+        self.loaded_plugins
+            .iter()
+            .any(|entry| entry.library_path == library_path)
+    }
+
     /// Shuts down all loaded plugins and cleans up resources.
     ///
     /// This method should be called when the server is shutting down to ensure
@@ -484,21 +1110,23 @@ impl PluginManager {
     pub async fn shutdown(&self) -> Result<(), PluginSystemError> {
         info!("🛑 Shutting down {} plugins", self.loaded_plugins.len());
 
-        let context = if let Some(gorc_manager) = &self.gorc_instance_manager {
-            Arc::new(BasicServerContext::with_gorc(self.event_system.clone(), gorc_manager.clone()))
-        } else {
-            Arc::new(BasicServerContext::new(self.event_system.clone()))
-        };
+        let context = self.create_context();
 
-        // Call shutdown on all plugins and collect libraries for controlled cleanup
-        let plugin_names: Vec<String> = self.loaded_plugins.iter().map(|entry| entry.key().clone()).collect();
+        // Call shutdown on all plugins in reverse load order and collect libraries for controlled cleanup
+        let mut plugin_names = self.ordered_plugin_names();
+        plugin_names.reverse();
         let mut libraries_to_unload = Vec::new();
         
         for plugin_name in &plugin_names {
             info!("🛑 Shutting down plugin: {}", plugin_name);
 
-            if let Some(mut loaded_plugin) = self.loaded_plugins.get_mut(plugin_name) {
-                match loaded_plugin.plugin.shutdown(context.clone()).await {
+            if let Some(plugin) = self.plugin_handle(plugin_name) {
+                let plugin_context = Self::plugin_context(&context, plugin_name);
+                let result = self
+                    .event_system
+                    .attribute_emissions(plugin_name, async move { plugin.write().await.shutdown(plugin_context).await })
+                    .await;
+                match result {
                     Ok(_) => {
                         info!("✅ Plugin shutdown completed: {}", plugin_name);
                     }
@@ -514,6 +1142,7 @@ impl PluginManager {
         for plugin_name in &plugin_names {
             if let Some((_, loaded_plugin)) = self.loaded_plugins.remove(plugin_name) {
                 info!("🔌 Dropping plugin instance for: {}", plugin_name);
+                self.event_system.clear_plugin_namespaces(plugin_name);
                 // Drop the plugin instance first (this drops the Box<dyn Plugin>)
                 drop(loaded_plugin.plugin);
                 
@@ -526,8 +1155,7 @@ impl PluginManager {
         // Give some time for any remaining references to be cleaned up
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
 
-        // Now unload libraries in reverse order (LIFO)
-        libraries_to_unload.reverse();
+        // Libraries were collected in reverse load order, so unloading them as-is is LIFO
         
         info!("📚 Unloading {} plugin libraries...", libraries_to_unload.len());
         
@@ -585,6 +1213,298 @@ impl PluginManager {
         self.loaded_plugins.contains_key(plugin_name)
     }
 
+    /// Gets the manifest a loaded plugin shipped with, if any.
+    pub fn plugin_manifest(&self, plugin_name: &str) -> Option<PluginManifest> {
+        self.loaded_plugins
+            .get(plugin_name)
+            .and_then(|entry| entry.manifest.clone())
+    }
+
+    /// Gets the supervision health of a loaded plugin.
+    pub fn plugin_health(&self, plugin_name: &str) -> Option<PluginHealth> {
+        self.loaded_plugins.get(plugin_name).map(|entry| entry.health)
+    }
+
+    /// Gets the health of every loaded plugin, as last observed.
+    pub fn plugin_health_snapshots(&self) -> Vec<PluginHealthSnapshot> {
+        let mut snapshots: Vec<PluginHealthSnapshot> = self
+            .loaded_plugins
+            .iter()
+            .map(|entry| PluginHealthSnapshot {
+                name: entry.key().clone(),
+                health: entry.health,
+                detail: entry.health_detail.clone(),
+            })
+            .collect();
+        snapshots.sort_by(|a, b| a.name.cmp(&b.name));
+        snapshots
+    }
+
+    /// Starts the watchdog task that polls plugin heartbeats on a timer.
+    pub fn start_watchdog(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let manager = Arc::clone(self);
+        let interval = Duration::from_secs(self.safety_config.watchdog.interval_secs.max(1));
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                manager.check_plugin_health().await;
+            }
+        })
+    }
+
+    /// Runs one heartbeat round over all loaded plugins.
+    ///
+    /// Each plugin's `health()` is awaited with the configured timeout. Health
+    /// changes are recorded, logged, and emitted as `core:plugin_health_changed`.
+    /// Failed plugins are not polled.
+    ///
+    /// # Returns
+    ///
+    /// The health of every loaded plugin after the round.
+    pub async fn check_plugin_health(&self) -> Vec<PluginHealthSnapshot> {
+        let timeout = Duration::from_millis(self.safety_config.watchdog.timeout_ms);
+
+        for plugin_name in self.plugin_names() {
+            // Clone the plugin out so the map isn't locked while it answers
+            let plugin = {
+                let Some(loaded_plugin) = self.loaded_plugins.get(&plugin_name) else {
+                    continue;
+                };
+                if loaded_plugin.health == PluginHealth::Failed {
+                    continue;
+                }
+                loaded_plugin.plugin.clone()
+            };
+            let heartbeat = tokio::time::timeout(timeout, async { plugin.read().await.health().await }).await;
+
+            let (health, detail) = match heartbeat {
+                Ok(PluginHealthStatus::Healthy) => (PluginHealth::Healthy, None),
+                Ok(PluginHealthStatus::Degraded(reason)) => (PluginHealth::Degraded, Some(reason)),
+                Err(_) => (
+                    PluginHealth::Unresponsive,
+                    Some(format!("No heartbeat within {}ms", timeout.as_millis())),
+                ),
+            };
+
+            let previous = {
+                let Some(mut loaded_plugin) = self.loaded_plugins.get_mut(&plugin_name) else {
+                    continue;
+                };
+                let previous = loaded_plugin.health;
+                // The supervisor may have failed the plugin while we were waiting
+                if previous == PluginHealth::Failed || (previous == health && loaded_plugin.health_detail == detail) {
+                    continue;
+                }
+                loaded_plugin.health = health;
+                loaded_plugin.health_detail = detail.clone();
+                previous
+            };
+
+            match health {
+                PluginHealth::Healthy => info!("💚 Plugin {} recovered ({:?} → Healthy)", plugin_name, previous),
+                _ => warn!(
+                    "💔 Plugin {} is {:?}: {}",
+                    plugin_name,
+                    health,
+                    detail.as_deref().unwrap_or("no reason given")
+                ),
+            }
+
+            let event = PluginHealthChangedEvent {
+                plugin_name: plugin_name.clone(),
+                previous: format!("{:?}", previous),
+                current: format!("{:?}", health),
+                detail,
+                timestamp: current_timestamp(),
+            };
+            if let Err(e) = self.event_system.emit_core("plugin_health_changed", &event).await {
+                warn!("⚠️ Failed to emit plugin_health_changed for {}: {}", plugin_name, e);
+            }
+        }
+
+        self.plugin_health_snapshots()
+    }
+
+    /// Starts the supervisor task that reacts to plugin handler panics.
+    ///
+    /// The event system catches handler panics at the dispatch boundary and
+    /// reports them; the supervisor applies the configured
+    /// [`PluginRestartPolicy`] to the owning plugin.
+    pub fn start_supervisor(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let manager = Arc::clone(self);
+        let mut panic_reports = self.event_system.subscribe_handler_panics();
+
+        tokio::spawn(async move {
+            loop {
+                match panic_reports.recv().await {
+                    Ok(report) => manager.handle_plugin_panic(&report).await,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("⚠️ Plugin supervisor skipped {} panic reports", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    /// Applies the restart policy to the plugin that owns a panicking handler.
+    ///
+    /// The plugin is marked [`PluginHealth::Degraded`] and restarted while the
+    /// restart budget allows it. Once exhausted, the plugin is marked
+    /// [`PluginHealth::Failed`] and its handlers are removed.
+    pub async fn handle_plugin_panic(&self, report: &HandlerPanicReport) {
+        let Some(plugin_name) = report.owner.as_deref() else {
+            return;
+        };
+
+        let should_restart = {
+            let Some(mut loaded_plugin) = self.loaded_plugins.get_mut(plugin_name) else {
+                return;
+            };
+
+            // Ignore failed plugins and panics raised before the latest restart
+            if loaded_plugin.health == PluginHealth::Failed
+                || loaded_plugin.restart_history.back().is_some_and(|restarted| *restarted > report.occurred_at)
+            {
+                return;
+            }
+
+            loaded_plugin.health = PluginHealth::Degraded;
+            loaded_plugin.health_detail = Some(format!("Handler {} panicked: {}", report.handler_name, report.message));
+            warn!(
+                "🩹 Plugin {} degraded after handler {} panicked: {}",
+                plugin_name, report.handler_name, report.message
+            );
+
+            self.safety_config
+                .restart_policy
+                .try_consume(&mut loaded_plugin.restart_history, Instant::now())
+        };
+
+        if !should_restart {
+            self.fail_plugin(plugin_name, "Restart budget exhausted").await;
+            return;
+        }
+
+        match self.restart_plugin(plugin_name).await {
+            Ok(()) => {
+                if let Some(mut loaded_plugin) = self.loaded_plugins.get_mut(plugin_name) {
+                    loaded_plugin.health = PluginHealth::Healthy;
+                    loaded_plugin.health_detail = None;
+                }
+                info!("♻️ Plugin {} restarted after panic", plugin_name);
+            }
+            Err(e) => {
+                self.fail_plugin(plugin_name, &format!("Restart failed: {e}")).await;
+            }
+        }
+    }
+
+    /// Restarts a loaded plugin with a fresh instance from its library.
+    ///
+    /// The old instance is shut down, its handlers are removed, and the new
+    /// instance goes through `pre_init` and `init` again.
+    pub async fn restart_plugin(&self, plugin_name: &str) -> Result<(), PluginSystemError> {
+        let (_, mut loaded_plugin) = self
+            .loaded_plugins
+            .remove(plugin_name)
+            .ok_or_else(|| PluginSystemError::PluginNotFound(plugin_name.to_string()))?;
+
+        info!("♻️ Restarting plugin: {}", plugin_name);
+        let context = Self::plugin_context(&self.create_context(), plugin_name);
+
+        let plugin = loaded_plugin.plugin.clone();
+        let shutdown_context = context.clone();
+        let result = self
+            .event_system
+            .attribute_emissions(plugin_name, async move { plugin.write().await.shutdown(shutdown_context).await })
+            .await;
+        if let Err(e) = result {
+            warn!("⚠️ Shutdown of plugin {} before restart failed: {:?}", plugin_name, e);
+        }
+        self.event_system.remove_handlers_owned_by(plugin_name).await;
+
+        let result = match Self::instantiate_plugin(&loaded_plugin.library) {
+            Ok(plugin) => {
+                loaded_plugin.plugin = Arc::new(tokio::sync::RwLock::new(plugin));
+
+                let plugin = loaded_plugin.plugin.clone();
+                let result = self
+                    .run_as_plugin(plugin_name, async move {
+                        let mut plugin = plugin.write().await;
+                        plugin.pre_init(context.clone()).await?;
+                        plugin.init(context).await
+                    })
+                    .await;
+
+                result.map_err(|e| PluginSystemError::InitializationError(format!("{:?}", e)))
+            }
+            Err(e) => Err(e),
+        };
+
+        let version = loaded_plugin.plugin.read().await.version().to_string();
+        let loaded_event = Self::plugin_loaded_event(&loaded_plugin, version);
+        self.loaded_plugins.insert(plugin_name.to_string(), loaded_plugin);
+        if result.is_ok() {
+            self.announce_plugin_loaded(loaded_event).await;
+        }
+        result
+    }
+
+    /// Clones out a plugin's handle so it can be called without locking the plugin map.
+    fn plugin_handle(&self, plugin_name: &str) -> Option<Arc<tokio::sync::RwLock<Box<dyn Plugin + Send + Sync>>>> {
+        self.loaded_plugins
+            .get(plugin_name)
+            .map(|loaded_plugin| loaded_plugin.plugin.clone())
+    }
+
+    /// Builds the `plugin_loaded` event for a plugin, taking capabilities from its manifest.
+    fn plugin_loaded_event(loaded_plugin: &LoadedPlugin, version: String) -> PluginLoadedEvent {
+        PluginLoadedEvent {
+            plugin_name: loaded_plugin.name.clone(),
+            version,
+            capabilities: loaded_plugin
+                .manifest
+                .as_ref()
+                .map(|manifest| manifest.capabilities.clone())
+                .unwrap_or_default(),
+            timestamp: current_timestamp(),
+        }
+    }
+
+    /// Emits `core:plugin_loaded` so other plugins can react to the new plugin.
+    async fn announce_plugin_loaded(&self, event: PluginLoadedEvent) {
+        if let Err(e) = self.event_system.emit_core("plugin_loaded", &event).await {
+            warn!("⚠️ Failed to emit plugin_loaded for {}: {}", event.plugin_name, e);
+        }
+    }
+
+    /// Marks a plugin as failed and detaches its handlers from the event system.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin_name` - Name of the plugin to disable
+    /// * `cause` - Why it is disabled, reported as its health detail
+    async fn fail_plugin(&self, plugin_name: &str, cause: &str) {
+        if let Some(mut loaded_plugin) = self.loaded_plugins.get_mut(plugin_name) {
+            loaded_plugin.health = PluginHealth::Failed;
+            loaded_plugin.health_detail = Some(cause.to_string());
+        }
+
+        let removed = self.event_system.remove_handlers_owned_by(plugin_name).await;
+        error!("🚫 Plugin {} was disabled: {} ({} handlers removed)", plugin_name, cause, removed);
+    }
+
+    /// Runs plugin code with the handlers it registers and the events it
+    /// emits attributed to the plugin
+    fn run_as_plugin<'a, F: Future + 'a>(&'a self, plugin_name: &str, future: F) -> impl Future<Output = F::Output> + 'a {
+        self.event_system
+            .register_as(plugin_name, self.event_system.attribute_emissions(plugin_name, future))
+    }
+
     /// Validates plugin compatibility based on ABI version string.
     /// 
     /// ABI version format: "crate_version:rust_version" (e.g., "0.10.0:1.75.0")
@@ -600,8 +1520,18 @@ impl PluginManager {
     /// Validates plugin compatibility using ABI version strings.
     /// 
     /// Checks both crate version and Rust compiler version for safety.
-    /// Can be overridden with CLI safety flags.
-    fn validate_plugin_compatibility(&self, plugin_version: &str, expected_version: &str) -> Result<(), PluginSystemError> {
+    /// If the plugin declares a supported ABI range (e.g. `>=0.10, <0.12`), the
+    /// server's crate version is matched against that range instead of the
+    /// plugin's own version, unless `--strict-versioning` demands an exact match.
+    /// A range that does not parse is treated as a mismatch.
+    /// Can be overridden with CLI safety flags, in which case the mismatches
+    /// the overrides let through are returned.
+    fn validate_plugin_compatibility(
+        &self,
+        plugin_version: &str,
+        expected_version: &str,
+        abi_range: Option<&str>,
+    ) -> Result<Vec<String>, PluginSystemError> {
         // Parse both versions
         let plugin_parts: Vec<&str> = plugin_version.split(':').collect();
         let expected_parts: Vec<&str> = expected_version.split(':').collect();
@@ -619,15 +1549,49 @@ impl PluginManager {
         let expected_rust_version = expected_parts[1];
         
         // Check crate version compatibility (can be overridden with --danger-allow-abi-mismatch)
+        let mut range_error = None;
         let versions_compatible = if self.safety_config.strict_versioning {
             // Strict: exact version match required
             plugin_crate_version == expected_crate_version
+        } else if let Some(range) = abi_range {
+            // Negotiated: the server version must fall inside the plugin's declared range.
+            // A range that cannot be evaluated counts as a mismatch, so the override covers it too.
+            match (semver::VersionReq::parse(range), semver::Version::parse(expected_crate_version)) {
+                (Ok(requirement), Ok(server_version)) => {
+                    let in_range = requirement.matches(&server_version);
+                    if in_range {
+                        info!("🤝 Plugin ABI range '{}' accepts server v{}", range, expected_crate_version);
+                    }
+                    in_range
+                }
+                (Err(e), _) => {
+                    range_error = Some(format!("Invalid plugin ABI range '{}': {}", range, e));
+                    false
+                }
+                (_, Err(e)) => {
+                    range_error = Some(format!("Invalid server version '{}': {}", expected_crate_version, e));
+                    false
+                }
+            }
         } else {
             // Relaxed: only major.minor must match (ignore patch)
             self.versions_major_minor_compatible(plugin_crate_version, expected_crate_version)
         };
         
         if !versions_compatible && !self.safety_config.allow_abi_mismatch {
+            if let Some(range_error) = range_error {
+                return Err(PluginSystemError::VersionMismatch(format!(
+                    "{}. Fix the plugin's declared range, or use --danger-allow-abi-mismatch to override (NOT RECOMMENDED).",
+                    range_error
+                )));
+            }
+            if let (Some(range), false) = (abi_range, self.safety_config.strict_versioning) {
+                return Err(PluginSystemError::VersionMismatch(format!(
+                    "ABI version mismatch: plugin supports horizon_event_system '{}', but server uses v{}. \
+                    Recompile the plugin with a range covering the server version, or use --danger-allow-abi-mismatch to override (NOT RECOMMENDED).",
+                    range, expected_crate_version
+                )));
+            }
             let comparison_type = if self.safety_config.strict_versioning { "exact" } else { "major.minor" };
             return Err(PluginSystemError::VersionMismatch(format!(
                 "ABI version mismatch: plugin compiled against horizon_event_system v{}, but server uses v{} ({} matching required when flag --strict-versioning is {}). \
@@ -651,9 +1615,14 @@ impl PluginManager {
         }
         
         // Log warnings if safety overrides are in use
-        if self.safety_config.allow_abi_mismatch && plugin_crate_version != expected_crate_version {
+        let mut overrides = Vec::new();
+        if let (true, Some(range_error)) = (self.safety_config.allow_abi_mismatch, &range_error) {
+            warn!("Loading plugin with unusable ABI range (override enabled): {}", range_error);
+            overrides.push(range_error.clone());
+        } else if self.safety_config.allow_abi_mismatch && plugin_crate_version != expected_crate_version {
             warn!("Loading plugin with ABI version mismatch (override enabled): plugin v{} != server v{}", 
                   plugin_crate_version, expected_crate_version);
+            overrides.push(format!("ABI version mismatch: plugin v{plugin_crate_version} != server v{expected_crate_version}"));
         }
         
         if self.safety_config.allow_unsafe_plugins && 
@@ -662,9 +1631,10 @@ impl PluginManager {
            expected_rust_version != "unknown" {
             warn!("Loading plugin with Rust compiler version mismatch (override enabled): plugin {} != server {}", 
                   plugin_rust_version, expected_rust_version);
+            overrides.push(format!("Rust compiler version mismatch: plugin {plugin_rust_version} != server {expected_rust_version}"));
         }
         
-        Ok(())
+        Ok(overrides)
     }
     
     /// Checks if two version strings are compatible using major.minor comparison.
@@ -771,16 +1741,16 @@ mod tests {
         
         // Test exact match - should pass
         let manager_strict = PluginManager::new(event_system.clone(), PluginSafetyConfig::default());
-        assert!(manager_strict.validate_plugin_compatibility("0.10.0:1.75.0", "0.10.0:1.75.0").is_ok());
+        assert!(manager_strict.validate_plugin_compatibility("0.10.0:1.75.0", "0.10.0:1.75.0", None).is_ok());
         
         // Test crate version mismatch - should fail with strict config
-        let result = manager_strict.validate_plugin_compatibility("0.9.0:1.75.0", "0.10.0:1.75.0");
+        let result = manager_strict.validate_plugin_compatibility("0.9.0:1.75.0", "0.10.0:1.75.0", None);
         assert!(result.is_err());
         let error = result.unwrap_err();
         assert!(matches!(error, PluginSystemError::VersionMismatch(_)));
         
         // Test Rust version mismatch - should fail with strict config
-        let result = manager_strict.validate_plugin_compatibility("0.10.0:1.74.0", "0.10.0:1.75.0");
+        let result = manager_strict.validate_plugin_compatibility("0.10.0:1.74.0", "0.10.0:1.75.0", None);
         assert!(result.is_err());
         let error = result.unwrap_err();
         assert!(matches!(error, PluginSystemError::VersionMismatch(_)));
@@ -790,20 +1760,21 @@ mod tests {
             allow_unsafe_plugins: true,
             allow_abi_mismatch: true,
             strict_versioning: false,
+            ..Default::default()
         });
         
         // Should pass with overrides
-        assert!(manager_unsafe.validate_plugin_compatibility("0.9.0:1.74.0", "0.10.0:1.75.0").is_ok());
+        assert!(manager_unsafe.validate_plugin_compatibility("0.9.0:1.74.0", "0.10.0:1.75.0", None).is_ok());
         
         // Test unknown Rust version - should pass (one side unknown)
-        assert!(manager_strict.validate_plugin_compatibility("0.10.0:unknown", "0.10.0:1.75.0").is_ok());
-        assert!(manager_strict.validate_plugin_compatibility("0.10.0:1.75.0", "0.10.0:unknown").is_ok());
+        assert!(manager_strict.validate_plugin_compatibility("0.10.0:unknown", "0.10.0:1.75.0", None).is_ok());
+        assert!(manager_strict.validate_plugin_compatibility("0.10.0:1.75.0", "0.10.0:unknown", None).is_ok());
         
         // Test both unknown Rust versions - should pass
-        assert!(manager_strict.validate_plugin_compatibility("0.10.0:unknown", "0.10.0:unknown").is_ok());
+        assert!(manager_strict.validate_plugin_compatibility("0.10.0:unknown", "0.10.0:unknown", None).is_ok());
         
         // Test invalid format - should fail
-        let result = manager_strict.validate_plugin_compatibility("invalid", "0.10.0:1.75.0");
+        let result = manager_strict.validate_plugin_compatibility("invalid", "0.10.0:1.75.0", None);
         assert!(result.is_err());
         let error = result.unwrap_err();
         assert!(matches!(error, PluginSystemError::VersionMismatch(_)));
@@ -818,18 +1789,19 @@ mod tests {
             allow_unsafe_plugins: false,
             allow_abi_mismatch: false,
             strict_versioning: false, // Relaxed versioning
+            ..Default::default()
         });
         
         // Same major.minor, different patch - should pass with relaxed versioning
-        assert!(manager_relaxed.validate_plugin_compatibility("0.11.2:1.75.0", "0.11.0:1.75.0").is_ok());
-        assert!(manager_relaxed.validate_plugin_compatibility("0.11.0:1.75.0", "0.11.5:1.75.0").is_ok());
+        assert!(manager_relaxed.validate_plugin_compatibility("0.11.2:1.75.0", "0.11.0:1.75.0", None).is_ok());
+        assert!(manager_relaxed.validate_plugin_compatibility("0.11.0:1.75.0", "0.11.5:1.75.0", None).is_ok());
         
         // Different major version - should fail even with relaxed versioning
-        let result = manager_relaxed.validate_plugin_compatibility("1.11.0:1.75.0", "0.11.0:1.75.0");
+        let result = manager_relaxed.validate_plugin_compatibility("1.11.0:1.75.0", "0.11.0:1.75.0", None);
         assert!(result.is_err());
         
         // Different minor version - should fail even with relaxed versioning
-        let result = manager_relaxed.validate_plugin_compatibility("0.10.0:1.75.0", "0.11.0:1.75.0");
+        let result = manager_relaxed.validate_plugin_compatibility("0.10.0:1.75.0", "0.11.0:1.75.0", None);
         assert!(result.is_err());
         
         // Test strict versioning (strict_versioning = true)
@@ -837,14 +1809,15 @@ mod tests {
             allow_unsafe_plugins: false,
             allow_abi_mismatch: false,
             strict_versioning: true, // Strict versioning
+            ..Default::default()
         });
         
         // Same major.minor, different patch - should fail with strict versioning
-        let result = manager_strict.validate_plugin_compatibility("0.11.2:1.75.0", "0.11.0:1.75.0");
+        let result = manager_strict.validate_plugin_compatibility("0.11.2:1.75.0", "0.11.0:1.75.0", None);
         assert!(result.is_err());
         
         // Exact match - should pass with strict versioning
-        assert!(manager_strict.validate_plugin_compatibility("0.11.0:1.75.0", "0.11.0:1.75.0").is_ok());
+        assert!(manager_strict.validate_plugin_compatibility("0.11.0:1.75.0", "0.11.0:1.75.0", None).is_ok());
     }
     
     #[test]
@@ -863,4 +1836,334 @@ mod tests {
         assert!(!manager.versions_major_minor_compatible("invalid", "1.2.0"));
         assert!(!manager.versions_major_minor_compatible("1.2.0", "invalid"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_restart_policy_budget() {
+        let policy = PluginRestartPolicy { max_restarts: 2, window_secs: 60 };
+        let mut history = VecDeque::new();
+        let start = Instant::now();
+
+        // Two restarts fit in the window, the third is refused
+        assert!(policy.try_consume(&mut history, start));
+        assert!(policy.try_consume(&mut history, start + Duration::from_secs(1)));
+        assert!(!policy.try_consume(&mut history, start + Duration::from_secs(2)));
+
+        // Once the oldest restart leaves the window there is room again
+        assert!(policy.try_consume(&mut history, start + Duration::from_secs(60)));
+        assert_eq!(history.len(), 2);
+
+        // A zero budget disables restarts entirely
+        let disabled = PluginRestartPolicy { max_restarts: 0, window_secs: 60 };
+        assert!(!disabled.try_consume(&mut VecDeque::new(), start));
+    }
+
+    #[tokio::test]
+    async fn test_panic_for_unknown_plugin_is_ignored() {
+        let event_system = Arc::new(EventSystem::new());
+        let manager = PluginManager::new(event_system, PluginSafetyConfig::default());
+
+        let report = HandlerPanicReport {
+            event_key: "core:test".to_string(),
+            handler_name: "core:test::Handler".to_string(),
+            owner: Some("missing_plugin".to_string()),
+            message: "boom".to_string(),
+            occurred_at: Instant::now(),
+        };
+
+        manager.handle_plugin_panic(&report).await;
+        assert_eq!(manager.plugin_health("missing_plugin"), None);
+        assert_eq!(manager.plugin_count(), 0);
+    }
+
+    #[test]
+    fn test_signature_enforcement() {
+        let temp_dir = TempDir::new().unwrap();
+        let plugin_file = temp_dir.path().join("libunsigned_plugin.so");
+        fs::write(&plugin_file, "dummy content").unwrap();
+        let event_system = Arc::new(EventSystem::new());
+
+        // Signatures are optional by default
+        let manager = PluginManager::new(event_system.clone(), PluginSafetyConfig::default());
+        assert!(manager.verify_plugin_signature(&plugin_file).is_ok());
+
+        // Unsigned plugins are refused once signatures are required
+        let manager_signed = PluginManager::new(event_system.clone(), PluginSafetyConfig {
+            require_signatures: true,
+            ..Default::default()
+        });
+        let result = manager_signed.verify_plugin_signature(&plugin_file);
+        assert!(matches!(result, Err(PluginSystemError::SignatureError(_))));
+
+        // ...unless the unsafe override is given
+        let manager_override = PluginManager::new(event_system, PluginSafetyConfig {
+            require_signatures: true,
+            allow_unsafe_plugins: true,
+            ..Default::default()
+        });
+        assert!(manager_override.verify_plugin_signature(&plugin_file).is_ok());
+    }
+
+    #[test]
+    fn test_manifest_policy() {
+        let temp_dir = TempDir::new().unwrap();
+        let plugin_file = temp_dir.path().join("libplugin_chat.so");
+        fs::write(&plugin_file, "dummy content").unwrap();
+        let event_system = Arc::new(EventSystem::new());
+
+        // Manifests are optional by default
+        let manager = PluginManager::new(event_system.clone(), PluginSafetyConfig::default());
+        assert_eq!(manager.load_plugin_manifest(&plugin_file).unwrap(), (None, None));
+
+        let manager_required = PluginManager::new(event_system.clone(), PluginSafetyConfig {
+            require_manifest: true,
+            ..Default::default()
+        });
+        let result = manager_required.load_plugin_manifest(&plugin_file);
+        assert!(matches!(result, Err(PluginSystemError::ManifestError(_))));
+
+        fs::write(
+            PluginManifest::manifest_path(&plugin_file),
+            "name = \"chat\"\nversion = \"1.0.0\"\ncapabilities = [\"chat\", \"persistence\"]\n",
+        )
+        .unwrap();
+        let manifest = manager_required.load_plugin_manifest(&plugin_file).unwrap().0.unwrap();
+        assert_eq!(manifest.capabilities, vec!["chat".to_string(), "persistence".to_string()]);
+
+        // Capabilities outside the server policy are refused
+        let manager_restricted = PluginManager::new(event_system, PluginSafetyConfig {
+            allowed_capabilities: vec!["chat".to_string()],
+            ..Default::default()
+        });
+        let result = manager_restricted.load_plugin_manifest(&plugin_file);
+        assert!(matches!(result, Err(PluginSystemError::ManifestError(_))));
+    }
+
+    #[test]
+    fn test_manifest_signature_enforcement() {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let temp_dir = TempDir::new().unwrap();
+        let plugin_file = temp_dir.path().join("libplugin_chat.so");
+        let manifest_path = PluginManifest::manifest_path(&plugin_file);
+        let manifest = "name = \"chat\"\nversion = \"1.0.0\"\ncapabilities = [\"chat\"]\n";
+        fs::write(&manifest_path, manifest).unwrap();
+
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let config = PluginSafetyConfig {
+            require_signatures: true,
+            trusted_keys: vec![STANDARD.encode(key.verifying_key().to_bytes())],
+            ..Default::default()
+        };
+        let manager = PluginManager::new(Arc::new(EventSystem::new()), config.clone());
+
+        // Unsigned manifests are refused once signatures are required
+        let result = manager.load_plugin_manifest(&plugin_file);
+        assert!(matches!(result, Err(PluginSystemError::SignatureError(_))));
+
+        let signature_path = PluginVerifier::signature_path(&manifest_path);
+        fs::write(&signature_path, STANDARD.encode(key.sign(manifest.as_bytes()).to_bytes())).unwrap();
+        let (loaded, signature_override) = manager.load_plugin_manifest(&plugin_file).unwrap();
+        assert_eq!(loaded.unwrap().name, "chat");
+        assert_eq!(signature_override, None);
+
+        // Widening the capabilities after signing breaks the signature
+        fs::write(&manifest_path, manifest.replace("[\"chat\"]", "[\"chat\", \"persistence\"]")).unwrap();
+        let result = manager.load_plugin_manifest(&plugin_file);
+        assert!(matches!(result, Err(PluginSystemError::SignatureError(_))));
+
+        // ...unless the unsafe override is given, which is reported
+        let manager_override = PluginManager::new(Arc::new(EventSystem::new()), PluginSafetyConfig {
+            allow_unsafe_plugins: true,
+            ..config
+        });
+        let (_, signature_override) = manager_override.load_plugin_manifest(&plugin_file).unwrap();
+        assert!(signature_override.is_some());
+    }
+
+    /// Test plugin whose heartbeat answer is fixed at construction.
+    #[cfg(unix)]
+    struct HeartbeatPlugin {
+        name: String,
+        status: Option<PluginHealthStatus>,
+    }
+
+    #[cfg(unix)]
+    #[async_trait::async_trait]
+    impl Plugin for HeartbeatPlugin {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        async fn pre_init(&mut self, _context: Arc<dyn ServerContext>) -> Result<(), horizon_event_system::PluginError> {
+            Ok(())
+        }
+
+        async fn init(&mut self, _context: Arc<dyn ServerContext>) -> Result<(), horizon_event_system::PluginError> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self, _context: Arc<dyn ServerContext>) -> Result<(), horizon_event_system::PluginError> {
+            Ok(())
+        }
+
+        async fn health(&self) -> PluginHealthStatus {
+            match &self.status {
+                Some(status) => status.clone(),
+                // Simulate a stuck plugin
+                None => std::future::pending().await,
+            }
+        }
+    }
+
+    #[cfg(unix)]
+    fn insert_heartbeat_plugin(manager: &PluginManager, name: &str, status: Option<PluginHealthStatus>) {
+        manager.loaded_plugins.insert(name.to_string(), LoadedPlugin {
+            name: name.to_string(),
+            library_path: PathBuf::from(format!("lib{}.so", name)),
+            library: Library::from(libloading::os::unix::Library::this()),
+            plugin: Arc::new(tokio::sync::RwLock::new(Box::new(HeartbeatPlugin { name: name.to_string(), status }))),
+            health: PluginHealth::Healthy,
+            health_detail: None,
+            restart_history: VecDeque::new(),
+            manifest: None,
+            _staged_library: None,
+        });
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_watchdog_health_states() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let event_system = Arc::new(EventSystem::new());
+        let changes = Arc::new(AtomicUsize::new(0));
+        let changes_clone = changes.clone();
+        event_system
+            .on_core("plugin_health_changed", move |_event: PluginHealthChangedEvent| {
+                changes_clone.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let manager = PluginManager::new(event_system, PluginSafetyConfig {
+            watchdog: PluginWatchdogConfig { interval_secs: 1, timeout_ms: 50 },
+            ..Default::default()
+        });
+        insert_heartbeat_plugin(&manager, "healthy", Some(PluginHealthStatus::Healthy));
+        insert_heartbeat_plugin(&manager, "impaired", Some(PluginHealthStatus::Degraded("db offline".to_string())));
+        insert_heartbeat_plugin(&manager, "stuck", None);
+
+        let snapshots = manager.check_plugin_health().await;
+        let health_of = |name: &str| snapshots.iter().find(|s| s.name == name).unwrap().clone();
+        assert_eq!(health_of("healthy").health, PluginHealth::Healthy);
+        assert_eq!(health_of("impaired").health, PluginHealth::Degraded);
+        assert_eq!(health_of("impaired").detail.as_deref(), Some("db offline"));
+        assert_eq!(health_of("stuck").health, PluginHealth::Unresponsive);
+
+        // Only actual changes are emitted, and repeated rounds are quiet
+        assert_eq!(changes.load(Ordering::SeqCst), 2);
+        manager.check_plugin_health().await;
+        assert_eq!(changes.load(Ordering::SeqCst), 2);
+
+        // The plugin map stays writable while a plugin is slow to answer
+        let (_, writable) = tokio::join!(manager.check_plugin_health(), async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            manager.loaded_plugins.get_mut("stuck").is_some()
+        });
+        assert!(writable);
+    }
+
+    #[test]
+    fn test_whitelist() {
+        let event_system = Arc::new(EventSystem::new());
+        let library = Path::new("plugins/libplugin_greeter.so");
+
+        let manager = PluginManager::new(event_system.clone(), PluginSafetyConfig::default());
+        assert!(manager.check_whitelist(library, None).is_ok());
+
+        let manager = PluginManager::new(event_system, PluginSafetyConfig {
+            whitelist: vec!["plugin_greeter".to_string(), "chat".to_string()],
+            ..Default::default()
+        });
+        assert!(manager.check_whitelist(library, None).is_ok());
+        assert!(matches!(
+            manager.check_whitelist(Path::new("plugins/libplugin_other.so"), None),
+            Err(PluginSystemError::NotWhitelisted(_))
+        ));
+
+        // Manifest names are accepted too
+        let manifest = PluginManifest {
+            name: "chat".to_string(),
+            version: "1.0.0".to_string(),
+            capabilities: vec![],
+            event_namespaces: vec![],
+        };
+        assert!(manager.check_whitelist(Path::new("plugins/libplugin_chat.so"), Some(&manifest)).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_order() {
+        let manager = PluginManager::new(Arc::new(EventSystem::new()), PluginSafetyConfig {
+            load_order: vec!["plugin_persistence".to_string(), "auth".to_string()],
+            ..Default::default()
+        });
+
+        // Library names and plugin names are both accepted
+        assert_eq!(manager.load_priority(None, Path::new("libplugin_persistence.so")), 0);
+        assert_eq!(manager.load_priority(Some("auth"), Path::new("libplugin_auth.so")), 1);
+        assert_eq!(manager.load_priority(None, Path::new("libplugin_combat.so")), 2);
+
+        for name in ["combat", "auth", "persistence", "chat"] {
+            insert_heartbeat_plugin(&manager, name, Some(PluginHealthStatus::Healthy));
+        }
+        // Test plugins are registered as lib<name>.so, so "persistence" is matched by plugin name only
+        manager.loaded_plugins.get_mut("persistence").unwrap().library_path = PathBuf::from("libplugin_persistence.so");
+
+        assert_eq!(manager.ordered_plugin_names(), vec!["persistence", "auth", "chat", "combat"]);
+    }
+
+    #[test]
+    fn test_abi_range_negotiation() {
+        let event_system = Arc::new(EventSystem::new());
+        let manager = PluginManager::new(event_system.clone(), PluginSafetyConfig::default());
+
+        // A plugin built against 0.10 that declares support up to 0.12 runs on 0.11
+        let range = Some(">=0.10, <0.12");
+        assert!(manager.validate_plugin_compatibility("0.10.0:1.75.0", "0.11.3:1.75.0", range).is_ok());
+        let result = manager.validate_plugin_compatibility("0.10.0:1.75.0", "0.12.0:1.75.0", range);
+        assert!(matches!(result, Err(PluginSystemError::VersionMismatch(_))));
+
+        // The range does not relax the compiler check
+        let result = manager.validate_plugin_compatibility("0.10.0:1.74.0", "0.11.0:1.75.0", range);
+        assert!(matches!(result, Err(PluginSystemError::VersionMismatch(_))));
+
+        // Malformed ranges are rejected
+        let result = manager.validate_plugin_compatibility("0.10.0:1.75.0", "0.10.0:1.75.0", Some("not a range"));
+        assert!(matches!(result, Err(PluginSystemError::VersionMismatch(_))));
+
+        // ...unless the ABI override is enabled, which reports the bad range instead
+        let manager_override = PluginManager::new(event_system.clone(), PluginSafetyConfig {
+            allow_abi_mismatch: true,
+            ..Default::default()
+        });
+        let overrides = manager_override
+            .validate_plugin_compatibility("0.10.0:1.75.0", "0.10.0:1.75.0", Some("not a range"))
+            .unwrap();
+        assert_eq!(overrides.len(), 1);
+        assert!(overrides[0].contains("Invalid plugin ABI range"));
+
+        // Strict versioning still requires an exact match
+        let manager_strict = PluginManager::new(event_system, PluginSafetyConfig {
+            strict_versioning: true,
+            ..Default::default()
+        });
+        assert!(manager_strict.validate_plugin_compatibility("0.10.0:1.75.0", "0.11.0:1.75.0", range).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+//! Plugin manifests declaring identity, capabilities and event namespaces.
+//!
+//! A manifest is a small TOML file shipped next to the plugin library
+//! (`libplugin_greeter.so` → `libplugin_greeter.plugin.toml`). Plugin crates
+//! keep it as `plugin.toml` in the crate root and copy it alongside the built
+//! library. It is parsed and checked against server policy before the library
+//! is loaded, so a plugin asking for more than the server allows never gets to
+//! run any code. When plugin signatures are required, the manifest needs a
+//! detached signature of its own (`libplugin_greeter.plugin.toml.sig`), so
+//! its capabilities can't be edited after the plugin was signed.
+//!
+//! The declared name can only be compared with the name the plugin reports
+//! once the library has been loaded and has created the plugin, so a
+//! mismatched name is refused after the library's code has run.
+//!
+//! ```toml
+//! name = "greeter"
+//! version = "1.0.0"
+//! capabilities = ["chat"]
+//! event_namespaces = ["chat", "movement"]
+//! ```
+
+use crate::error::PluginSystemError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Extension used for manifests installed next to a plugin library.
+pub const MANIFEST_EXTENSION: &str = "plugin.toml";
+
+/// Declared metadata for a plugin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginManifest {
+    /// Plugin name; must match the name reported by the plugin itself
+    pub name: String,
+    /// Plugin version
+    pub version: String,
+    /// Capabilities the plugin provides or requires (e.g. `chat`, `persistence`)
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+    /// Client event namespaces the plugin handles (e.g. `chat` for `client:chat:*`)
+    #[serde(default)]
+    pub event_namespaces: Vec<String>,
+}
+
+impl PluginManifest {
+    /// Returns the manifest path for a plugin library.
+    pub fn manifest_path(library_path: &Path) -> PathBuf {
+        library_path.with_extension(MANIFEST_EXTENSION)
+    }
+
+    /// Parses a manifest from TOML.
+    pub fn from_toml(contents: &str) -> Result<Self, PluginSystemError> {
+        toml::from_str(contents)
+            .map_err(|e| PluginSystemError::ManifestError(format!("Invalid plugin manifest: {}", e)))
+    }
+
+    /// Loads the manifest installed next to a plugin library.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` if the library has no manifest, the parsed manifest if it
+    /// does, or a `PluginSystemError` if the manifest cannot be read or parsed.
+    pub fn load_for_library(library_path: &Path) -> Result<Option<Self>, PluginSystemError> {
+        let manifest_path = Self::manifest_path(library_path);
+        if !manifest_path.exists() {
+            return Ok(None);
+        }
+
+        let contents = std::fs::read_to_string(&manifest_path)?;
+        Self::from_toml(&contents).map(Some)
+    }
+
+    /// Validates the manifest against server policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowed_capabilities` - Capabilities the server permits; empty allows any
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the manifest is well-formed and within policy, or a
+    /// `PluginSystemError` describing the first violation.
+    pub fn validate(&self, allowed_capabilities: &[String]) -> Result<(), PluginSystemError> {
+        if self.name.trim().is_empty() {
+            return Err(PluginSystemError::ManifestError("Manifest name cannot be empty".to_string()));
+        }
+
+        if let Some(namespace) = self
+            .event_namespaces
+            .iter()
+            .find(|namespace| {
+                namespace.is_empty()
+                    || namespace.contains(':')
+                    || horizon_event_system::RESERVED_NAMESPACES.contains(&namespace.as_str())
+            })
+        {
+            return Err(PluginSystemError::ManifestError(format!(
+                "Plugin {} declares invalid event namespace '{}'",
+                self.name, namespace
+            )));
+        }
+
+        if !allowed_capabilities.is_empty() {
+            if let Some(capability) = self
+                .capabilities
+                .iter()
+                .find(|capability| !allowed_capabilities.contains(capability))
+            {
+                return Err(PluginSystemError::ManifestError(format!(
+                    "Plugin {} requests capability '{}' which is not allowed by server policy",
+                    self.name, capability
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const GREETER_MANIFEST: &str = r#"
+        name = "greeter"
+        version = "1.0.0"
+        capabilities = ["chat"]
+        event_namespaces = ["chat", "movement"]
+    "#;
+
+    #[test]
+    fn test_manifest_path() {
+        let path = PluginManifest::manifest_path(Path::new("plugins/libplugin_greeter.so"));
+        assert_eq!(path, PathBuf::from("plugins/libplugin_greeter.plugin.toml"));
+    }
+
+    #[test]
+    fn test_load_for_library() {
+        let temp_dir = TempDir::new().unwrap();
+        let library_path = temp_dir.path().join("libplugin_greeter.so");
+        assert_eq!(PluginManifest::load_for_library(&library_path).unwrap(), None);
+
+        fs::write(PluginManifest::manifest_path(&library_path), GREETER_MANIFEST).unwrap();
+        let manifest = PluginManifest::load_for_library(&library_path).unwrap().unwrap();
+        assert_eq!(manifest.name, "greeter");
+        assert_eq!(manifest.capabilities, vec!["chat".to_string()]);
+        assert_eq!(manifest.event_namespaces.len(), 2);
+
+        fs::write(PluginManifest::manifest_path(&library_path), "name = ").unwrap();
+        assert!(matches!(
+            PluginManifest::load_for_library(&library_path),
+            Err(PluginSystemError::ManifestError(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_against_policy() {
+        let manifest = PluginManifest::from_toml(GREETER_MANIFEST).unwrap();
+        assert!(manifest.validate(&[]).is_ok());
+        assert!(manifest.validate(&["chat".to_string()]).is_ok());
+        assert!(manifest.validate(&["persistence".to_string()]).is_err());
+
+        let bad_namespace = PluginManifest {
+            event_namespaces: vec!["client:chat".to_string()],
+            ..manifest.clone()
+        };
+        assert!(bad_namespace.validate(&[]).is_err());
+
+        let reserved_namespace = PluginManifest {
+            event_namespaces: vec!["core".to_string()],
+            ..manifest
+        };
+        assert!(reserved_namespace.validate(&[]).is_err());
+    }
+}
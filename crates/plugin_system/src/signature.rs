@@ -0,0 +1,257 @@
+//! Ed25519 signature verification for plugin binaries.
+//!
+//! Each plugin library may ship with a detached signature stored next to it
+//! (`libmy_plugin.so` → `libmy_plugin.so.sig`). The signature file contains the
+//! base64-encoded Ed25519 signature of the complete library file, and is
+//! checked against the trusted public keys from the server configuration
+//! before the library is ever loaded into the process.
+//!
+//! The library is read once and the bytes that were verified are staged in
+//! a private subdirectory of the plugin directory, see [`StagedLibrary`], and
+//! loaded from there, so the file can't be swapped between the check and the
+//! load. Staging next to the plugins rather than in the system temp directory
+//! keeps loading working on hosts that mount `/tmp` noexec.
+
+use crate::error::PluginSystemError;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use std::path::{Path, PathBuf};
+
+/// File extension appended to a plugin library path to locate its signature.
+pub const SIGNATURE_EXTENSION: &str = "sig";
+
+/// Verifies plugin libraries against a set of trusted Ed25519 public keys.
+#[derive(Debug, Clone, Default)]
+pub struct PluginVerifier {
+    trusted_keys: Vec<VerifyingKey>,
+}
+
+impl PluginVerifier {
+    /// Creates a verifier from base64-encoded Ed25519 public keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - Trusted public keys, each the base64 encoding of 32 raw key bytes
+    ///
+    /// # Returns
+    ///
+    /// A `PluginVerifier`, or a `PluginSystemError` if any key is malformed.
+    pub fn from_base64_keys(keys: &[String]) -> Result<Self, PluginSystemError> {
+        let trusted_keys = keys
+            .iter()
+            .map(|key| {
+                let bytes = STANDARD.decode(key.trim()).map_err(|e| {
+                    PluginSystemError::SignatureError(format!("Trusted key is not valid base64: {}", e))
+                })?;
+                let bytes: [u8; 32] = bytes.try_into().map_err(|_| {
+                    PluginSystemError::SignatureError("Trusted key must be exactly 32 bytes".to_string())
+                })?;
+                VerifyingKey::from_bytes(&bytes).map_err(|e| {
+                    PluginSystemError::SignatureError(format!("Trusted key is not a valid Ed25519 key: {}", e))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { trusted_keys })
+    }
+
+    /// Returns the number of trusted keys.
+    pub fn trusted_key_count(&self) -> usize {
+        self.trusted_keys.len()
+    }
+
+    /// Returns the expected signature path for a plugin library.
+    pub fn signature_path(library_path: &Path) -> PathBuf {
+        let mut path = library_path.as_os_str().to_owned();
+        path.push(".");
+        path.push(SIGNATURE_EXTENSION);
+        PathBuf::from(path)
+    }
+
+    /// Verifies that a plugin library is signed by one of the trusted keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `library_path` - Path to the plugin library file
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if a trusted key produced the signature, or a `PluginSystemError`
+    /// if the signature is missing, malformed, or not trusted.
+    pub fn verify(&self, library_path: &Path) -> Result<(), PluginSystemError> {
+        self.verify_bytes(library_path, &std::fs::read(library_path)?)
+    }
+
+    /// Verifies that library contents already read are signed by one of the trusted keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `library_path` - Path the library was read from, next to its signature
+    /// * `library_bytes` - Contents of the library
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if a trusted key signed exactly these bytes, or a
+    /// `PluginSystemError` if the signature is missing, malformed, or not trusted.
+    pub fn verify_bytes(&self, library_path: &Path, library_bytes: &[u8]) -> Result<(), PluginSystemError> {
+        let signature_path = Self::signature_path(library_path);
+        if !signature_path.exists() {
+            return Err(PluginSystemError::SignatureError(format!(
+                "Plugin is unsigned: {} not found",
+                signature_path.display()
+            )));
+        }
+
+        let encoded = std::fs::read_to_string(&signature_path)?;
+        let signature_bytes = STANDARD.decode(encoded.trim()).map_err(|e| {
+            PluginSystemError::SignatureError(format!("Signature is not valid base64: {}", e))
+        })?;
+        let signature = Signature::from_slice(&signature_bytes).map_err(|e| {
+            PluginSystemError::SignatureError(format!("Malformed signature: {}", e))
+        })?;
+
+        if self
+            .trusted_keys
+            .iter()
+            .any(|key| key.verify(library_bytes, &signature).is_ok())
+        {
+            Ok(())
+        } else {
+            Err(PluginSystemError::SignatureError(format!(
+                "Signature for {} does not match any trusted key",
+                library_path.display()
+            )))
+        }
+    }
+}
+
+/// Private copy of a verified plugin library, deleted once dropped
+#[derive(Debug)]
+pub struct StagedLibrary {
+    _dir: tempfile::TempDir,
+    path: PathBuf,
+}
+
+impl StagedLibrary {
+    /// Writes verified library bytes to a new directory only the server's user can access.
+    ///
+    /// The directory is created next to the library, in the plugin directory,
+    /// and removed again once the copy is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `library_path` - Path the library was read from, whose file name the copy keeps
+    /// * `library_bytes` - Verified contents of the library
+    ///
+    /// # Returns
+    ///
+    /// The staged copy, or a `PluginSystemError` if it could not be written.
+    pub fn write(library_path: &Path, library_bytes: &[u8]) -> Result<Self, PluginSystemError> {
+        let plugin_dir = library_path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let dir = tempfile::Builder::new().prefix(".staged-").tempdir_in(plugin_dir)?;
+        let path = dir.path().join(library_path.file_name().unwrap_or(library_path.as_os_str()));
+        std::fs::write(&path, library_bytes)?;
+        Ok(Self { _dir: dir, path })
+    }
+
+    /// Gets the path of the staged copy
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn verifier_for(key: &SigningKey) -> PluginVerifier {
+        let encoded = STANDARD.encode(key.verifying_key().to_bytes());
+        PluginVerifier::from_base64_keys(&[encoded]).unwrap()
+    }
+
+    fn write_plugin(dir: &TempDir, contents: &[u8], signer: Option<&SigningKey>) -> PathBuf {
+        let library_path = dir.path().join("libtest_plugin.so");
+        fs::write(&library_path, contents).unwrap();
+        if let Some(signer) = signer {
+            let signature = signer.sign(contents);
+            fs::write(
+                PluginVerifier::signature_path(&library_path),
+                STANDARD.encode(signature.to_bytes()),
+            )
+            .unwrap();
+        }
+        library_path
+    }
+
+    #[test]
+    fn test_signature_path() {
+        let path = PluginVerifier::signature_path(Path::new("plugins/libgreeter.so"));
+        assert_eq!(path, PathBuf::from("plugins/libgreeter.so.sig"));
+    }
+
+    #[test]
+    fn test_valid_signature_is_accepted() {
+        let dir = TempDir::new().unwrap();
+        let key = signing_key(7);
+        let library_path = write_plugin(&dir, b"plugin bytes", Some(&key));
+
+        assert!(verifier_for(&key).verify(&library_path).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_unsigned_tampered_and_untrusted() {
+        let dir = TempDir::new().unwrap();
+        let key = signing_key(7);
+        let verifier = verifier_for(&key);
+
+        // Unsigned
+        let library_path = write_plugin(&dir, b"plugin bytes", None);
+        assert!(matches!(verifier.verify(&library_path), Err(PluginSystemError::SignatureError(_))));
+
+        // Tampered after signing
+        let library_path = write_plugin(&dir, b"plugin bytes", Some(&key));
+        fs::write(&library_path, b"tampered bytes").unwrap();
+        assert!(matches!(verifier.verify(&library_path), Err(PluginSystemError::SignatureError(_))));
+
+        // Signed by a key the server does not trust
+        let library_path = write_plugin(&dir, b"plugin bytes", Some(&signing_key(9)));
+        assert!(matches!(verifier.verify(&library_path), Err(PluginSystemError::SignatureError(_))));
+    }
+
+    #[test]
+    fn test_staged_copy_holds_the_verified_bytes() {
+        let dir = TempDir::new().unwrap();
+        let key = signing_key(7);
+        let library_path = write_plugin(&dir, b"plugin bytes", Some(&key));
+        let verified = fs::read(&library_path).unwrap();
+        verifier_for(&key).verify_bytes(&library_path, &verified).unwrap();
+
+        // Swapping the file after the check doesn't change what gets loaded
+        fs::write(&library_path, b"swapped bytes").unwrap();
+        let staged = StagedLibrary::write(&library_path, &verified).unwrap();
+        assert_eq!(staged.path().file_name(), library_path.file_name());
+        assert_eq!(fs::read(staged.path()).unwrap(), b"plugin bytes");
+
+        // The copy is staged inside the plugin directory, not the system temp directory
+        let staged_dir = staged.path().parent().unwrap().to_path_buf();
+        assert_eq!(staged_dir.parent(), library_path.parent());
+        drop(staged);
+        assert!(!staged_dir.exists());
+    }
+
+    #[test]
+    fn test_invalid_trusted_key() {
+        assert!(PluginVerifier::from_base64_keys(&["not base64!".to_string()]).is_err());
+        assert!(PluginVerifier::from_base64_keys(&[STANDARD.encode([1u8; 16])]).is_err());
+    }
+}
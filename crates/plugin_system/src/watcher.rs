@@ -0,0 +1,140 @@
+//! Plugin directory watcher for automatic plugin discovery.
+//!
+//! When enabled, the plugin directory is watched for new libraries. A library
+//! is loaded once it has stopped changing for [`SETTLE_DELAY`], so a plugin
+//! that is still being copied into place is never opened half-written. Newly
+//! dropped plugins go through the normal whitelist, signature, manifest and
+//! ABI checks before they are initialized.
+
+use crate::error::PluginSystemError;
+use crate::manager::PluginManager;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tracing::{error, info, warn};
+
+/// How long a library must go without changes before it is loaded.
+pub const SETTLE_DELAY: Duration = Duration::from_millis(1000);
+
+/// How often pending libraries are checked against the settle delay.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+impl PluginManager {
+    /// Watches a plugin directory and loads libraries dropped into it.
+    ///
+    /// # Arguments
+    ///
+    /// * `plugin_directory` - Directory to watch (not recursive)
+    ///
+    /// # Returns
+    ///
+    /// The handle of the watcher task, or a `PluginSystemError` if the
+    /// directory could not be watched.
+    pub fn start_directory_watcher<P: AsRef<Path>>(
+        self: &Arc<Self>,
+        plugin_directory: P,
+    ) -> Result<tokio::task::JoinHandle<()>, PluginSystemError> {
+        let directory = plugin_directory.as_ref().to_path_buf();
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+            match result {
+                Ok(event) => {
+                    // A closed channel means the watcher task has stopped
+                    let _ = sender.send(event);
+                }
+                Err(e) => warn!("⚠️ Plugin directory watch error: {}", e),
+            }
+        })
+        .map_err(|e| PluginSystemError::LoadingError(format!("Failed to create directory watcher: {}", e)))?;
+
+        watcher
+            .watch(&directory, RecursiveMode::NonRecursive)
+            .map_err(|e| {
+                PluginSystemError::LoadingError(format!("Failed to watch {}: {}", directory.display(), e))
+            })?;
+
+        info!("👀 Watching plugin directory: {}", directory.display());
+        let manager = Arc::clone(self);
+
+        Ok(tokio::spawn(async move {
+            // The watcher stops delivering events once dropped, so it lives in the task
+            let _watcher = watcher;
+            let mut pending: HashMap<PathBuf, Instant> = HashMap::new();
+            let mut ticker = tokio::time::interval(POLL_INTERVAL);
+
+            loop {
+                tokio::select! {
+                    event = receiver.recv() => {
+                        let Some(event) = event else { break };
+                        if matches!(event.kind, EventKind::Remove(_) | EventKind::Access(_)) {
+                            continue;
+                        }
+                        for path in event.paths {
+                            if PluginManager::is_plugin_file(&path) {
+                                pending.insert(path, Instant::now());
+                            }
+                        }
+                    }
+                    _ = ticker.tick() => {
+                        for path in take_settled(&mut pending, Instant::now()) {
+                            manager.load_discovered_plugin(&path).await;
+                        }
+                    }
+                }
+            }
+        }))
+    }
+
+    /// Loads a library found by the directory watcher, logging the outcome.
+    async fn load_discovered_plugin(&self, path: &Path) {
+        if !path.is_file() || self.is_library_loaded(path) {
+            return;
+        }
+
+        info!("🆕 New plugin library detected: {}", path.display());
+        match self.load_plugin(path).await {
+            Ok(plugin_name) => info!("✅ Hot-loaded plugin: {}", plugin_name),
+            Err(e) => error!("❌ Failed to load plugin from {}: {}", path.display(), e),
+        }
+    }
+}
+
+/// Removes and returns the pending paths that have not changed for [`SETTLE_DELAY`].
+fn take_settled(pending: &mut HashMap<PathBuf, Instant>, now: Instant) -> Vec<PathBuf> {
+    let settled: Vec<PathBuf> = pending
+        .iter()
+        .filter(|(_, last_change)| now.duration_since(**last_change) >= SETTLE_DELAY)
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    for path in &settled {
+        pending.remove(path);
+    }
+
+    settled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_settled() {
+        let start = Instant::now();
+        let mut pending = HashMap::new();
+        pending.insert(PathBuf::from("libold.so"), start);
+        pending.insert(PathBuf::from("libnew.so"), start + Duration::from_millis(800));
+
+        let settled = take_settled(&mut pending, start + SETTLE_DELAY);
+        assert_eq!(settled, vec![PathBuf::from("libold.so")]);
+        assert_eq!(pending.len(), 1);
+
+        let settled = take_settled(&mut pending, start + SETTLE_DELAY * 2);
+        assert_eq!(settled, vec![PathBuf::from("libnew.so")]);
+        assert!(pending.is_empty());
+    }
+}